@@ -12,6 +12,8 @@ from __future__ import annotations
 import json
 import os
 import re
+import sys
+import traceback
 
 """AI Processor
 
@@ -488,8 +490,25 @@ def execute_ai_query(api_key: str, payload_json: str) -> str:  # noqa: D401
         
         return json.dumps(payload_out, ensure_ascii=False)
     except Exception as e:  # pragma: no cover
+        # Traceback goes to stderr, which the Rust side captures and appends to the error
+        traceback.print_exc(file=sys.stderr)
         return make_err(f"Unhandled exception: {e}")
 
+def validate_api_key(api_key: str) -> str:
+    """Check that an API key is accepted by listing one model (no generation cost).
+
+    Returns JSON with shape: { success: bool, error?: str }
+    """
+    if not api_key:
+        return json.dumps({"success": False, "error": "API key is empty"}, ensure_ascii=False)
+    try:
+        client = genai.Client(api_key=api_key)
+        next(iter(client.models.list(config={"page_size": 1})), None)
+        return json.dumps({"success": True}, ensure_ascii=False)
+    except Exception as e:
+        traceback.print_exc(file=sys.stderr)
+        return json.dumps({"success": False, "error": str(e)}, ensure_ascii=False)
+
 def extract_first_json(text: str) -> str:
     """Extract the first balanced top-level JSON array or object.
 
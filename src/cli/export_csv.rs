@@ -0,0 +1,100 @@
+// src/cli/export_csv.rs
+// Export one table of a category database to an RFC 4180 CSV file
+
+use crate::sheets::database::{
+    connection::DbConnection, daemon_client::DaemonClient, daemon_manager::get_daemon_path,
+    reader::DbReader, schema::queries::table_exists,
+};
+use crate::sheets::definitions::ColumnValidator;
+use crate::sheets::systems::io::csv::push_csv_record;
+use crate::sheets::systems::logic::update_column_validator::structure_naming::structure_child_table_name;
+use rusqlite::Connection;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+pub fn run(db_path: PathBuf, table: String, output: PathBuf) -> Result<(), String> {
+    if !db_path.is_file() {
+        return Err(format!("Database not found: {}", db_path.display()));
+    }
+    let conn = DbConnection::open_existing(&db_path)
+        .map_err(|e| format!("Cannot open '{}': {}", db_path.display(), e))?;
+    if !table_exists(&conn, &table).map_err(|e| e.to_string())? {
+        return Err(format!(
+            "Table '{}' not found in {}",
+            table,
+            db_path.display()
+        ));
+    }
+
+    let daemon_client = DaemonClient::new(None, get_daemon_path().to_string_lossy().to_string());
+    let db_name = db_path.file_name().and_then(|n| n.to_str());
+    let sheet = DbReader::read_sheet(&conn, &table, &daemon_client, db_name)
+        .map_err(|e| format!("Failed to read table '{}': {}", table, e))?;
+    let metadata = sheet
+        .metadata
+        .as_ref()
+        .ok_or_else(|| format!("Table '{}' has no metadata", table))?;
+
+    let headers = metadata.get_headers();
+    let live_columns: Vec<usize> = (0..metadata.columns.len())
+        .filter(|&i| !metadata.columns[i].deleted)
+        .collect();
+
+    // Structure cells export their child-row count, as shown in the grid
+    let mut child_counts: HashMap<usize, HashMap<String, usize>> = HashMap::new();
+    for &col_idx in &live_columns {
+        let col = &metadata.columns[col_idx];
+        if matches!(col.validator, Some(ColumnValidator::Structure)) {
+            let child_table = structure_child_table_name(&table, &col.header);
+            let counts = count_children_by_parent(&conn, &child_table, &daemon_client, db_name)?;
+            child_counts.insert(col_idx, counts);
+        }
+    }
+
+    let mut out = String::new();
+    let header_row: Vec<&str> = live_columns.iter().map(|&i| headers[i].as_str()).collect();
+    push_csv_record(&mut out, &header_row);
+    for row in &sheet.grid {
+        let parent_row_index = row.first().map(String::as_str).unwrap_or_default();
+        let record: Vec<String> = live_columns
+            .iter()
+            .map(|&col_idx| match child_counts.get(&col_idx) {
+                Some(counts) => counts.get(parent_row_index).copied().unwrap_or(0).to_string(),
+                None => row.get(col_idx).cloned().unwrap_or_default(),
+            })
+            .collect();
+        push_csv_record(&mut out, &record);
+    }
+
+    std::fs::write(&output, out)
+        .map_err(|e| format!("Failed to write '{}': {}", output.display(), e))?;
+    println!(
+        "Exported {} rows x {} columns from '{}' to {}",
+        sheet.grid.len(),
+        live_columns.len(),
+        table,
+        output.display()
+    );
+    Ok(())
+}
+
+/// Child rows per parent row_index (children store it in their parent_key column)
+fn count_children_by_parent(
+    conn: &Connection,
+    child_table: &str,
+    daemon_client: &DaemonClient,
+    db_name: Option<&str>,
+) -> Result<HashMap<String, usize>, String> {
+    let mut counts = HashMap::new();
+    if !table_exists(conn, child_table).map_err(|e| e.to_string())? {
+        return Ok(counts);
+    }
+    let child = DbReader::read_sheet(conn, child_table, daemon_client, db_name)
+        .map_err(|e| format!("Failed to read structure table '{}': {}", child_table, e))?;
+    for row in &child.grid {
+        if let Some(parent_key) = row.get(1) {
+            *counts.entry(parent_key.clone()).or_insert(0) += 1;
+        }
+    }
+    Ok(counts)
+}
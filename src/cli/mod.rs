@@ -8,9 +8,13 @@ pub mod list_columns;
 pub mod sync_column_names;
 pub mod restore_columns;
 pub mod check_structure_columns;
+pub mod export_csv;
+pub mod vacuum;
+pub mod validate_structure_links;
 
-use clap::{Parser, Subcommand};
-use std::path::PathBuf;
+use clap::{Args, Parser, Subcommand};
+use std::io::{IsTerminal, Write};
+use std::path::{Path, PathBuf};
 
 #[derive(Parser)]
 #[command(name = "skylinedb")]
@@ -22,10 +26,12 @@ pub struct Cli {
 
 #[derive(Subcommand)]
 pub enum Commands {
-    /// Repair corrupted metadata tables (fixes column_index type issues)
+    /// Repair corrupted metadata tables (fixes column_index type issues, duplicates and gaps)
     RepairMetadata {
         /// Path to the SkylineDB data directory
         path: PathBuf,
+        #[command(flatten)]
+        safety: DestructiveArgs,
     },
     
     /// Diagnose metadata table issues
@@ -50,12 +56,16 @@ pub enum Commands {
     SyncColumnNames {
         /// Path to the database file
         path: PathBuf,
+        #[command(flatten)]
+        safety: DestructiveArgs,
     },
     
     /// Restore missing columns to physical table from metadata
     RestoreColumns {
         /// Path to the database file
         path: PathBuf,
+        #[command(flatten)]
+        safety: DestructiveArgs,
     },
     
     /// Check which columns are Structure validators
@@ -63,4 +73,116 @@ pub enum Commands {
         /// Path to the database file (optional, defaults to SkylineDB/Tactical Frontlines.db)
         path: Option<PathBuf>,
     },
+
+    /// Export a table to an RFC 4180 CSV file (structure columns export their child-row count)
+    ExportCsv {
+        /// Path to the database file
+        path: PathBuf,
+        /// Table (sheet) name to export
+        table: String,
+        /// Output CSV file
+        output: PathBuf,
+    },
+
+    /// Compact a database file (VACUUM + REINDEX) and report the size before and after
+    Vacuum {
+        /// Path to the database file
+        path: PathBuf,
+    },
+
+    /// Find structure child rows whose parent_key matches no parent row_index
+    ValidateStructureLinks {
+        /// Path to the database file
+        path: PathBuf,
+        /// Point orphans whose parent_key is a parent's key value at that parent row
+        #[arg(long)]
+        relink: bool,
+        /// Delete orphans that could not be re-linked (and their own structure rows, which become orphans)
+        #[arg(long)]
+        delete_orphans: bool,
+        #[command(flatten)]
+        safety: DestructiveArgs,
+    },
+}
+
+/// Safety flags of commands that modify databases in place
+#[derive(Args, Clone, Copy, Debug, Default)]
+pub struct DestructiveArgs {
+    /// Do not copy each database to `{name}.bak-{timestamp}.db` before changing it
+    #[arg(long)]
+    pub no_backup: bool,
+    /// Do not ask for confirmation
+    #[arg(long, short = 'y')]
+    pub yes: bool,
+}
+
+/// True for backups written by `backup_database` (`{name}.bak-{timestamp}.db`), which
+/// database scans must not treat as categories of their own
+pub fn is_backup_file(path: &Path) -> bool {
+    path.file_stem()
+        .and_then(|s| s.to_str())
+        .is_some_and(|stem| stem.contains(".bak-"))
+}
+
+/// Copy `db_path` (and its pending WAL, if any) next to itself as `{name}.bak-{timestamp}.db`
+pub fn backup_database(db_path: &Path) -> std::io::Result<PathBuf> {
+    let stem = db_path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::InvalidInput, "database path has no file name"))?;
+    let timestamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+    let backup_path = db_path.with_file_name(format!("{}.bak-{}.db", stem, timestamp));
+    std::fs::copy(db_path, &backup_path)?;
+    let wal_path = PathBuf::from(format!("{}-wal", db_path.display()));
+    if wal_path.is_file() {
+        std::fs::copy(&wal_path, format!("{}-wal", backup_path.display()))?;
+    }
+    Ok(backup_path)
+}
+
+/// Confirmation and backup step run before a destructive command touches `targets`.
+/// Asks on an interactive stdin unless `--yes` was passed, then backs up every target
+/// unless `--no-backup` was passed. Returns the backup paths, or None when the user declined.
+pub fn confirm_and_backup(
+    action: &str,
+    targets: &[PathBuf],
+    safety: DestructiveArgs,
+) -> std::io::Result<Option<Vec<PathBuf>>> {
+    if targets.is_empty() {
+        return Ok(Some(Vec::new()));
+    }
+    if !safety.yes && std::io::stdin().is_terminal() {
+        println!("{} will modify:", action);
+        for target in targets {
+            println!("  {}", target.display());
+        }
+        if safety.no_backup {
+            println!("No backup will be made (--no-backup).");
+        }
+        print!("Continue? [y/N] ");
+        std::io::stdout().flush()?;
+        let mut answer = String::new();
+        std::io::stdin().read_line(&mut answer)?;
+        if !matches!(answer.trim().to_lowercase().as_str(), "y" | "yes") {
+            println!("Aborted, nothing was changed.");
+            return Ok(None);
+        }
+    }
+    if safety.no_backup {
+        return Ok(Some(Vec::new()));
+    }
+    let mut backups = Vec::with_capacity(targets.len());
+    for target in targets {
+        let backup = backup_database(target)?;
+        println!("Backed up {} -> {}", target.display(), backup.display());
+        backups.push(backup);
+    }
+    Ok(Some(backups))
+}
+
+/// Printed after a destructive command succeeded
+pub fn report_backups(backups: &[PathBuf]) {
+    for backup in backups {
+        println!("Backup kept at: {}", backup.display());
+    }
 }
@@ -34,13 +34,16 @@ pub fn run(data_path: PathBuf) -> Result<()> {
     Ok(())
 }
 
-fn find_db_files(path: &PathBuf) -> Result<Vec<PathBuf>> {
+pub fn find_db_files(path: &PathBuf) -> Result<Vec<PathBuf>> {
     let mut db_files = Vec::new();
     
     if let Ok(entries) = std::fs::read_dir(path) {
         for entry in entries.flatten() {
             let path = entry.path();
-            if path.is_file() && path.extension().and_then(|s| s.to_str()) == Some("db") {
+            if path.is_file()
+                && path.extension().and_then(|s| s.to_str()) == Some("db")
+                && !super::is_backup_file(&path)
+            {
                 db_files.push(path);
             }
         }
@@ -97,17 +100,144 @@ fn repair_metadata_table(conn: &Connection, table_name: &str) -> Result<()> {
     
     if bad_count == 0 {
         println!("      ✓ OK (all column_index values are INTEGER)");
+    } else {
+        println!("      ⚠ CORRUPTED ({} row(s) with TEXT in column_index) - repairing...", bad_count);
+        
+        // Perform repair
+        repair_corrupted_table(conn, table_name)?;
+        
+        println!("      ✓ REPAIRED");
+    }
+    
+    // Duplicate or gapped indices break column ordering even when all values are INTEGER
+    let issues = validate_column_indices(conn, table_name)?;
+    if !issues.has_issues() {
+        println!("      ✓ OK (column_index values are a contiguous 0..{} sequence)", issues.total_columns);
         return Ok(());
     }
     
-    println!("      ⚠ CORRUPTED ({} row(s) with TEXT in column_index) - repairing...", bad_count);
+    println!("      ⚠ {} - renumbering...", issues.summary());
+    let changed = renumber_column_indices(conn, table_name)?;
+    println!("      ✓ RENUMBERED ({} row(s) updated)", changed);
     
-    // Perform repair
-    repair_corrupted_table(conn, table_name)?;
+    Ok(())
+}
+
+/// Result of checking a `<Table>_Metadata` table for a clean 0..N `column_index` sequence
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ColumnIndexIssues {
+    pub total_columns: i64,
+    /// Index values used by more than one metadata row
+    pub duplicate_indices: Vec<i64>,
+    /// Index values missing from the 0..N sequence
+    pub missing_indices: Vec<i64>,
+    /// Index values at or above N (or negative) that fall outside the sequence
+    pub out_of_range_indices: Vec<i64>,
+}
+
+impl ColumnIndexIssues {
+    pub fn has_issues(&self) -> bool {
+        !self.duplicate_indices.is_empty()
+            || !self.missing_indices.is_empty()
+            || !self.out_of_range_indices.is_empty()
+    }
+
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        if !self.duplicate_indices.is_empty() {
+            parts.push(format!("duplicate indices {:?}", self.duplicate_indices));
+        }
+        if !self.missing_indices.is_empty() {
+            parts.push(format!("missing indices {:?}", self.missing_indices));
+        }
+        if !self.out_of_range_indices.is_empty() {
+            parts.push(format!("out-of-range indices {:?}", self.out_of_range_indices));
+        }
+        format!("{} column(s), {}", self.total_columns, parts.join(", "))
+    }
+}
+
+/// Metadata rows as (rowid, column_index) in the order the reader presents them:
+/// by numeric index, ties broken by insertion order.
+fn load_ordered_column_indices(conn: &Connection, table_name: &str) -> Result<Vec<(i64, i64)>> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT rowid, CAST(column_index AS INTEGER) FROM \"{}\" ORDER BY CAST(column_index AS INTEGER), rowid",
+        table_name
+    ))?;
+    let rows = stmt
+        .query_map([], |row| Ok((row.get::<_, i64>(0)?, row.get::<_, i64>(1)?)))?
+        .collect::<Result<Vec<_>>>()?;
+    Ok(rows)
+}
+
+/// Detect duplicate, missing or out-of-range `column_index` values (deleted columns included).
+pub fn validate_column_indices(conn: &Connection, table_name: &str) -> Result<ColumnIndexIssues> {
+    let rows = load_ordered_column_indices(conn, table_name)?;
+    let total = rows.len() as i64;
+    let mut issues = ColumnIndexIssues {
+        total_columns: total,
+        ..Default::default()
+    };
+    let mut seen = std::collections::BTreeMap::<i64, usize>::new();
+    for (_, idx) in &rows {
+        *seen.entry(*idx).or_insert(0) += 1;
+    }
+    for (idx, count) in &seen {
+        if *count > 1 {
+            issues.duplicate_indices.push(*idx);
+        }
+        if *idx < 0 || *idx >= total {
+            issues.out_of_range_indices.push(*idx);
+        }
+    }
+    issues.missing_indices = (0..total).filter(|i| !seen.contains_key(i)).collect();
+    Ok(issues)
+}
+
+/// Renumber `column_index` to 0..N, preserving the current relative order.
+/// Returns the number of rows whose index changed.
+///
+/// Runtime column positions come from this same order, so links and structure
+/// references that use column positions stay valid.
+pub fn renumber_column_indices(conn: &Connection, table_name: &str) -> Result<usize> {
+    let rows = load_ordered_column_indices(conn, table_name)?;
+    let changes: Vec<(i64, i64)> = rows
+        .iter()
+        .enumerate()
+        .filter(|(new_idx, (_, old_idx))| *new_idx as i64 != *old_idx)
+        .map(|(new_idx, (rowid, _))| (*rowid, new_idx as i64))
+        .collect();
+    if changes.is_empty() {
+        return Ok(0);
+    }
     
-    println!("      ✓ REPAIRED");
+    conn.execute("BEGIN TRANSACTION", [])?;
+    let result = (|| -> Result<()> {
+        // Two passes through unique negative placeholders so the UNIQUE constraint never trips.
+        // The second pass matches on the placeholder because column_index may alias rowid.
+        for (rowid, new_idx) in &changes {
+            conn.execute(
+                &format!("UPDATE \"{}\" SET column_index = ?1 WHERE rowid = ?2", table_name),
+                rusqlite::params![-(new_idx + 1), rowid],
+            )?;
+        }
+        for (_, new_idx) in &changes {
+            conn.execute(
+                &format!("UPDATE \"{}\" SET column_index = ?1 WHERE column_index = ?2", table_name),
+                rusqlite::params![new_idx, -(new_idx + 1)],
+            )?;
+        }
+        Ok(())
+    })();
+    match result {
+        Ok(()) => conn.execute("COMMIT", [])?,
+        Err(e) => {
+            let _ = conn.execute("ROLLBACK", []);
+            return Err(e);
+        }
+    };
     
-    Ok(())
+    Ok(changes.len())
 }
 
 fn repair_corrupted_table(conn: &Connection, table_name: &str) -> Result<()> {
@@ -209,3 +339,81 @@ fn repair_corrupted_table(conn: &Connection, table_name: &str) -> Result<()> {
     
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_metadata_table(conn: &Connection) {
+        // Legacy schema without the UNIQUE constraint on column_index
+        conn.execute(
+            "CREATE TABLE \"Items_Metadata\" (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                column_index INTEGER NOT NULL,
+                column_name TEXT NOT NULL UNIQUE,
+                deleted INTEGER DEFAULT 0
+            )",
+            [],
+        )
+        .unwrap();
+    }
+
+    fn names_in_order(conn: &Connection) -> Vec<(i64, String)> {
+        let mut stmt = conn
+            .prepare("SELECT column_index, column_name FROM \"Items_Metadata\" ORDER BY column_index")
+            .unwrap();
+        stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+            .unwrap()
+            .collect::<Result<Vec<_>>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn renumbers_duplicate_and_gapped_indices_preserving_order() {
+        let conn = Connection::open_in_memory().unwrap();
+        create_metadata_table(&conn);
+        for (idx, name) in [(0, "Name"), (1, "Cost"), (1, "Weight"), (4, "Notes"), (7, "Tags")] {
+            conn.execute(
+                "INSERT INTO \"Items_Metadata\" (column_index, column_name) VALUES (?1, ?2)",
+                rusqlite::params![idx, name],
+            )
+            .unwrap();
+        }
+
+        let issues = validate_column_indices(&conn, "Items_Metadata").unwrap();
+        assert!(issues.has_issues());
+        assert_eq!(issues.total_columns, 5);
+        assert_eq!(issues.duplicate_indices, vec![1]);
+        assert_eq!(issues.missing_indices, vec![2, 3]);
+        assert_eq!(issues.out_of_range_indices, vec![7]);
+
+        let changed = renumber_column_indices(&conn, "Items_Metadata").unwrap();
+        assert_eq!(changed, 3);
+        assert_eq!(
+            names_in_order(&conn),
+            vec![
+                (0, "Name".to_string()),
+                (1, "Cost".to_string()),
+                (2, "Weight".to_string()),
+                (3, "Notes".to_string()),
+                (4, "Tags".to_string()),
+            ]
+        );
+        assert!(!validate_column_indices(&conn, "Items_Metadata").unwrap().has_issues());
+    }
+
+    #[test]
+    fn clean_sequence_is_left_untouched() {
+        let conn = Connection::open_in_memory().unwrap();
+        create_metadata_table(&conn);
+        for (idx, name) in [(0, "A"), (1, "B"), (2, "C")] {
+            conn.execute(
+                "INSERT INTO \"Items_Metadata\" (column_index, column_name) VALUES (?1, ?2)",
+                rusqlite::params![idx, name],
+            )
+            .unwrap();
+        }
+        assert!(!validate_column_indices(&conn, "Items_Metadata").unwrap().has_issues());
+        assert_eq!(renumber_column_indices(&conn, "Items_Metadata").unwrap(), 0);
+    }
+}
@@ -0,0 +1,18 @@
+// src/cli/vacuum.rs
+// Compact a category database (VACUUM + REINDEX) and report the size change
+
+use crate::sheets::database::maintenance::measure_compaction;
+use rusqlite::Connection;
+use std::path::PathBuf;
+
+pub fn run(db_path: PathBuf) -> Result<(), String> {
+    // No app (and so no daemon or migration) runs alongside the CLI; a direct connection is enough
+    let report = measure_compaction(&db_path, || {
+        let conn = Connection::open(&db_path)
+            .map_err(|e| format!("Cannot open '{}': {}", db_path.display(), e))?;
+        conn.execute_batch("VACUUM; REINDEX;")
+            .map_err(|e| format!("Compaction failed: {}", e))
+    })?;
+    println!("{}", report.summary(&db_path));
+    Ok(())
+}
@@ -0,0 +1,71 @@
+// src/cli/validate_structure_links.rs
+// Find structure child rows whose parent_key matches no parent row, optionally repairing them
+
+use crate::sheets::database::daemon_client::Statement;
+use crate::sheets::database::error::DbResult;
+use crate::sheets::database::validation::{validate_structure_links, OrphanFix};
+use rusqlite::Connection;
+use std::path::PathBuf;
+
+pub fn run(db_path: PathBuf, fix: OrphanFix) -> Result<(), String> {
+    if !db_path.is_file() {
+        return Err(format!("Database not found: {}", db_path.display()));
+    }
+    // No app (and so no daemon) runs alongside the CLI; a direct connection is enough
+    let conn = Connection::open(&db_path)
+        .map_err(|e| format!("Cannot open '{}': {}", db_path.display(), e))?;
+    let reports = validate_structure_links(&conn, fix, |statements| apply_directly(&conn, statements))
+        .map_err(|e| e.to_string())?;
+
+    println!("=== Structure links: {} ===\n", db_path.display());
+    if reports.is_empty() {
+        println!("No structure tables.");
+        return Ok(());
+    }
+    let mut orphan_total = 0;
+    for report in &reports {
+        println!("{}", report.summary());
+        for orphan in &report.orphans {
+            let hint = match orphan.relink_to {
+                Some(parent) => format!(" -> matches parent row {}", parent),
+                None => String::new(),
+            };
+            println!(
+                "    id={} row_index={} parent_key='{}'{}",
+                orphan.id, orphan.row_index, orphan.parent_key, hint
+            );
+        }
+        orphan_total += report.orphans.len();
+    }
+
+    println!();
+    if orphan_total == 0 {
+        println!("✓ All structure rows are linked to a parent.");
+    } else if !fix.relink && !fix.delete {
+        println!(
+            "⚠ {} orphaned row(s). Re-run with --relink and/or --delete-orphans to repair.",
+            orphan_total
+        );
+    } else {
+        let relinked: usize = reports.iter().map(|r| r.relinked).sum();
+        let deleted: usize = reports.iter().map(|r| r.deleted).sum();
+        println!(
+            "{} orphaned row(s): {} re-linked, {} deleted, {} left as they were.",
+            orphan_total,
+            relinked,
+            deleted,
+            orphan_total - relinked - deleted
+        );
+    }
+    Ok(())
+}
+
+/// Run repair statements on the CLI's own connection, all or nothing
+fn apply_directly(conn: &Connection, statements: Vec<Statement>) -> DbResult<()> {
+    let tx = conn.unchecked_transaction()?;
+    for statement in statements {
+        tx.execute(&statement.sql, rusqlite::params_from_iter(statement.params.iter()))?;
+    }
+    tx.commit()?;
+    Ok(())
+}
@@ -49,6 +49,8 @@ pub fn create_example_items_metadata() -> SheetMetadata {
         random_picker: None,
         structure_parent: None,
         hidden: false,
+        ai_key_column: None,
+        color: None,
     }
 }
 
@@ -90,5 +92,7 @@ pub fn create_simple_config_metadata() -> SheetMetadata {
         random_picker: None,
         structure_parent: None,
         hidden: false,
+        ai_key_column: None,
+        color: None,
     }
 }
@@ -8,12 +8,15 @@ use crate::ui::elements::editor::state::{EditorWindowState, FpsSetting};
 use bevy::{
     log::LogPlugin,
     prelude::*,
-    window::{PrimaryWindow, WindowPlugin},
+    window::{
+        Monitor, MonitorSelection, PrimaryWindow, WindowCloseRequested, WindowPlugin,
+        WindowPosition, WindowResolution,
+    },
     winit::{UpdateMode, WinitSettings},
 };
 use bevy_framepace::Limiter;
 use std::time::Duration;
-use winit::window::UserAttentionType;
+use winit::window::{UserAttentionType, WindowLevel};
 
 use image::ImageFormat as CrateImageFormat;
 use winit::window::Icon as WinitIcon;
@@ -66,8 +69,12 @@ fn main() {
 
 fn run_cli_command(command: cli::Commands) -> Result<(), Box<dyn std::error::Error>> {
     match command {
-        cli::Commands::RepairMetadata { path } => {
-            cli::repair_metadata::run(path)?;
+        cli::Commands::RepairMetadata { path, safety } => {
+            let targets = cli::repair_metadata::find_db_files(&path)?;
+            if let Some(backups) = cli::confirm_and_backup("Metadata repair", &targets, safety)? {
+                cli::repair_metadata::run(path)?;
+                cli::report_backups(&backups);
+            }
         }
         cli::Commands::DiagnoseMetadata { path } => {
             cli::diagnose_metadata::run(path)?;
@@ -78,15 +85,41 @@ fn run_cli_command(command: cli::Commands) -> Result<(), Box<dyn std::error::Err
         cli::Commands::ListColumns { path } => {
             cli::list_columns::run(path)?;
         }
-        cli::Commands::SyncColumnNames { path } => {
-            cli::sync_column_names::run(path)?;
+        cli::Commands::SyncColumnNames { path, safety } => {
+            let targets = [path.clone()];
+            if let Some(backups) = cli::confirm_and_backup("Column name sync", &targets, safety)? {
+                cli::sync_column_names::run(path)?;
+                cli::report_backups(&backups);
+            }
         }
-        cli::Commands::RestoreColumns { path } => {
-            cli::restore_columns::run(path)?;
+        cli::Commands::RestoreColumns { path, safety } => {
+            let targets = [path.clone()];
+            if let Some(backups) = cli::confirm_and_backup("Column restore", &targets, safety)? {
+                cli::restore_columns::run(path)?;
+                cli::report_backups(&backups);
+            }
         }
         cli::Commands::CheckStructureColumns { path } => {
             cli::check_structure_columns::run(path)?;
         }
+        cli::Commands::ExportCsv { path, table, output } => {
+            cli::export_csv::run(path, table, output)?;
+        }
+        cli::Commands::Vacuum { path } => {
+            cli::vacuum::run(path)?;
+        }
+        cli::Commands::ValidateStructureLinks { path, relink, delete_orphans, safety } => {
+            let fix = sheets::database::validation::OrphanFix { relink, delete: delete_orphans };
+            if !relink && !delete_orphans {
+                cli::validate_structure_links::run(path, fix)?;
+            } else {
+                let targets = [path.clone()];
+                if let Some(backups) = cli::confirm_and_backup("Structure link repair", &targets, safety)? {
+                    cli::validate_structure_links::run(path, fix)?;
+                    cli::report_backups(&backups);
+                }
+            }
+        }
     }
     Ok(())
 }
@@ -107,25 +140,20 @@ fn run_gui_app() {
     // Start IPC listener to receive signals from other instances
     let ipc_receiver = single_instance::start_ipc_listener();
 
-    // --- Always write the Python script at startup to ensure it's up to date ---
-    const AI_PROCESSOR_PY: &str = include_str!("../script/ai_processor.py");
-    let script_path = std::path::Path::new("script/ai_processor.py");
-    if let Some(parent) = script_path.parent() {
-        if let Err(e) = std::fs::create_dir_all(parent) {
-            eprintln!("Failed to create script directory: {e}");
+    // AI features can be switched off in Settings; load that choice before touching Python
+    let mut app_settings =
+        settings::io::load_settings_from_file::<settings::AppSettings>().unwrap_or_default();
+    // The data folder must be settled before the daemon or any database is touched
+    apply_data_directory_setting(&mut app_settings);
+    sheets::systems::ai::script_file::set_configured_script_path(app_settings.ai_script_path.clone());
+    if app_settings.ai_features_enabled {
+        if let Err(reason) = prepare_ai_runtime() {
+            eprintln!("AI features unavailable: {reason}");
         }
-    }
-    if let Err(e) = std::fs::write(script_path, AI_PROCESSOR_PY) {
-        eprintln!("Failed to write ai_processor.py: {e}");
     } else {
-        println!("ai_processor.py written to script/ directory.");
+        println!("AI features disabled in settings; skipping Python runtime setup.");
     }
 
-    // --- ADD THIS LINE ---
-    // This initializes the Python interpreter for use in multiple threads,
-    // which is necessary for the background tasks that call the Python script.
-    pyo3::prepare_freethreaded_python();
-
     match dotenv() {
         Ok(path) => info!("Loaded .env file from: {:?}", path),
         Err(_) => info!(
@@ -133,6 +161,19 @@ fn run_gui_app() {
         ),
     }
 
+    let mut primary_window = Window {
+        title: "SkylineDB - Spreadsheet Editor".into(),
+        ..default()
+    };
+    // Restore the last geometry; `keep_window_on_monitor` recenters it if that spot is gone
+    if let Some(geometry) = app_settings.window_geometry {
+        primary_window.resolution = WindowResolution::new(geometry.width, geometry.height);
+        primary_window.position = WindowPosition::At(IVec2::new(geometry.x, geometry.y));
+        if geometry.maximized {
+            primary_window.set_maximized(true);
+        }
+    }
+
     App::new()
         .insert_resource(WinitSettings {
             focused_mode: UpdateMode::Continuous,
@@ -145,10 +186,7 @@ fn run_gui_app() {
         .add_plugins(
             DefaultPlugins
                 .set(WindowPlugin {
-                    primary_window: Some(Window {
-                        title: "SkylineDB - Spreadsheet Editor".into(),
-                        ..default()
-                    }),
+                    primary_window: Some(primary_window),
                     ..default()
                 })
                 .set(LogPlugin {
@@ -166,12 +204,14 @@ fn run_gui_app() {
         .add_plugins(EditorUiPlugin)
         .add_plugins(VisualCopierPlugin)
         .add_systems(Startup, (
-            initialize_api_key_status_startup,
-            set_window_icon,
             load_app_settings_startup,
+            check_ai_runtime_startup.after(load_app_settings_startup),
+            initialize_api_key_status_startup.after(check_ai_runtime_startup),
+            set_window_icon,
         ))
         .add_systems(Update, fps_limit)
         .add_systems(Update, handle_ipc_focus_request)
+        .add_systems(Update, (keep_window_on_monitor, persist_window_geometry, apply_window_level))
         .add_systems(Update, poll_director_results)
         .run();
     
@@ -179,6 +219,65 @@ fn run_gui_app() {
     drop(_instance_guard);
 }
 
+/// Use the data folder chosen in Settings. A switch chosen last session is carried out first:
+/// a daemon still serving the old folder is stopped and, if requested, the old contents are
+/// moved over. When the move fails the old folder stays in use.
+fn apply_data_directory_setting(app_settings: &mut settings::AppSettings) {
+    use sheets::systems::io::data_dir;
+
+    data_dir::set_configured_data_dir(app_settings.data_directory.clone());
+    let Some(change) = app_settings.pending_data_directory_change.take() else {
+        return;
+    };
+    let target = app_settings
+        .data_directory
+        .clone()
+        .unwrap_or_else(data_dir::default_data_dir);
+    if sheets::database::daemon_manager::shutdown_running_daemon() {
+        println!("Stopped the database daemon serving {}", change.from.display());
+        // Give it a moment to release the database files
+        std::thread::sleep(std::time::Duration::from_millis(500));
+    }
+    if change.move_contents {
+        match data_dir::move_data_dir_contents(&change.from, &target) {
+            Ok(moved) => println!(
+                "Moved {} entries of the data folder from {} to {}",
+                moved,
+                change.from.display(),
+                target.display()
+            ),
+            Err(e) => {
+                eprintln!("Data folder not moved, still using {}: {}", change.from.display(), e);
+                app_settings.data_directory =
+                    (change.from != data_dir::default_data_dir()).then_some(change.from);
+                data_dir::set_configured_data_dir(app_settings.data_directory.clone());
+            }
+        }
+    }
+    if let Err(e) = settings::io::save_settings_to_file(&*app_settings) {
+        eprintln!("Failed to save AppSettings: {}", e);
+    }
+}
+
+/// Writes the bundled AI script and initializes the Python interpreter.
+/// Safe to call again (e.g. when AI features are re-enabled from Settings).
+/// Returns what to install when the interpreter or its packages are unavailable.
+pub(crate) fn prepare_ai_runtime() -> Result<(), String> {
+    // Make sure the Python script on disk matches this build
+    match sheets::systems::ai::script_file::ensure_ai_script() {
+        Ok(path) => println!("AI script: {}", path.display()),
+        Err(e) => eprintln!("{e}"),
+    }
+
+    // This initializes the Python interpreter for use in multiple threads,
+    // which is necessary for the background tasks that call the Python script.
+    // The check (and its result) is done once per session.
+    sheets::systems::ai::python_runtime::python_runtime_status()
+        .as_ref()
+        .map(|_| ())
+        .map_err(Clone::clone)
+}
+
 fn fps_limit(
     mut settings: ResMut<bevy_framepace::FramepaceSettings>,
     state: Res<EditorWindowState>,
@@ -188,6 +287,12 @@ fn fps_limit(
     match state.fps_setting {
         FpsSetting::Thirty => settings.limiter = Limiter::from_framerate(30.0),
         FpsSetting::Sixty => settings.limiter = Limiter::from_framerate(60.0),
+        FpsSetting::Custom(hz) => {
+            settings.limiter = match FpsSetting::custom_framerate(hz) {
+                Some(framerate) => Limiter::from_framerate(framerate),
+                None => Limiter::Off,
+            };
+        }
         FpsSetting::ScreenHz => {
             // Try to read monitor refresh rate from the primary winit window; fall back to no limiter (0.0) which lets the OS/compositor decide.
             let mut applied = false;
@@ -218,11 +323,10 @@ fn fps_limit(
 fn load_app_settings_startup(mut state: ResMut<EditorWindowState>) {
     // Best-effort: Load persisted AppSettings and populate UI state
     if let Ok(loaded) = settings::io::load_settings_from_file::<settings::AppSettings>() {
-        state.fps_setting = loaded.fps_setting;
-        state.show_hidden_sheets = loaded.show_hidden_sheets;
+        loaded.apply_to_state(&mut state);
         info!(
-            "Loaded app settings: fps_setting={:?}, show_hidden_sheets={}",
-            state.fps_setting, state.show_hidden_sheets
+            "Loaded app settings: fps_setting={:?}, show_hidden_sheets={}, max_loaded_sheets={}",
+            state.fps_setting, state.show_hidden_sheets, state.max_loaded_sheets
         );
     } else {
         info!("No persisted app settings found; using defaults.");
@@ -248,11 +352,115 @@ fn handle_ipc_focus_request(
     }
 }
 
+/// Once monitors are known, recenters a restored window on the primary monitor when its
+/// saved position is not on any connected monitor (e.g. a disconnected second screen).
+fn keep_window_on_monitor(
+    mut done: Local<bool>,
+    monitors: Query<&Monitor>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    if *done || monitors.is_empty() {
+        return;
+    }
+    *done = true;
+    let Ok(mut window) = windows.single_mut() else {
+        return;
+    };
+    let WindowPosition::At(pos) = window.position else {
+        return;
+    };
+    // The title bar area must be reachable, not just any corner of the window
+    let grab_point = pos + IVec2::new(40, 10);
+    let visible = monitors.iter().any(|m| {
+        IRect::from_corners(
+            m.physical_position,
+            m.physical_position + IVec2::new(m.physical_width as i32, m.physical_height as i32),
+        )
+        .contains(grab_point)
+    });
+    if !visible {
+        info!("Saved window position {:?} is off all monitors; centering on the primary monitor.", pos);
+        window.position = WindowPosition::Centered(MonitorSelection::Primary);
+    }
+}
+
+/// Tracks the main window geometry and saves it once it has settled (and on close).
+/// While maximized only the flag changes, so the restored size/position is remembered.
+fn persist_window_geometry(
+    mut last_change: Local<Option<f64>>,
+    time: Res<Time>,
+    mut state: ResMut<EditorWindowState>,
+    mut close_requests: EventReader<WindowCloseRequested>,
+    primary_window_query: Query<Entity, With<PrimaryWindow>>,
+    windows: NonSend<bevy::winit::WinitWindows>,
+) {
+    const SETTLE_SECONDS: f64 = 1.0;
+
+    let winit_window = primary_window_query
+        .single()
+        .ok()
+        .and_then(|entity| windows.get_window(entity));
+    if let Some(winit_window) = winit_window {
+        let maximized = winit_window.is_maximized();
+        let minimized = winit_window.is_minimized().unwrap_or(false);
+        let mut geometry = state.window_geometry;
+        if maximized || minimized {
+            // Minimizing keeps the flag as it was; maximizing sets it
+            if let (Some(g), true) = (geometry.as_mut(), maximized) {
+                g.maximized = true;
+            }
+        } else if let Ok(position) = winit_window.outer_position() {
+            let size = winit_window
+                .inner_size()
+                .to_logical::<f32>(winit_window.scale_factor());
+            geometry = Some(settings::WindowGeometry {
+                width: size.width,
+                height: size.height,
+                x: position.x,
+                y: position.y,
+                maximized: false,
+            });
+        }
+        if geometry != state.window_geometry {
+            state.window_geometry = geometry;
+            *last_change = Some(time.elapsed_secs_f64());
+        }
+    }
+
+    let closing = close_requests.read().count() > 0;
+    let settled = last_change.is_some_and(|t| time.elapsed_secs_f64() - t >= SETTLE_SECONDS);
+    if last_change.is_some() && (settled || closing) {
+        *last_change = None;
+        if let Err(e) = settings::io::save_settings_to_file(&settings::AppSettings::from_state(&state)) {
+            warn!("Failed to save window geometry: {}", e);
+        }
+    }
+}
+
+/// Turns AI features off for this session (with a one-time notice) when Python is unusable.
+/// The persisted setting is left alone so AI comes back once Python is installed.
+fn check_ai_runtime_startup(mut state: ResMut<EditorWindowState>) {
+    if !state.ai_features_enabled {
+        return;
+    }
+    if let Err(reason) = sheets::systems::ai::python_runtime::python_runtime_status() {
+        warn!("AI features disabled for this session: {}", reason);
+        state.ai_runtime_error = Some(reason.clone());
+        state.show_ai_runtime_notice = true;
+    }
+}
+
 // Functions initialize_api_key_status_startup and set_window_icon remain the same
 fn initialize_api_key_status_startup(
     mut api_key_status_res: ResMut<ApiKeyDisplayStatus>,
     mut session_api_key: ResMut<SessionApiKey>,
+    state: Res<EditorWindowState>,
 ) {
+    if !state.ai_available() {
+        api_key_status_res.status = "AI disabled".to_string();
+        info!("AI features disabled or unavailable; skipping API key lookup.");
+        return;
+    }
     // Only try to load from Windows Credential Manager
     // Use the same keyring service as Settings & Python (consistency avoids needing to open Settings once)
     if let Ok(keyring) = keyring::Entry::new("GoogleGeminiAPI", whoami::username().as_str()) {
@@ -275,6 +483,33 @@ fn initialize_api_key_status_startup(
     }
 }
 
+/// Applies the "Keep window on top" setting to the winit window whenever it changes
+/// (the first run applies the value loaded at startup).
+fn apply_window_level(
+    mut applied: Local<Option<bool>>,
+    state: Res<EditorWindowState>,
+    primary_window_query: Query<Entity, With<PrimaryWindow>>,
+    windows: NonSend<bevy::winit::WinitWindows>,
+) {
+    if *applied == Some(state.always_on_top) {
+        return;
+    }
+    let Some(winit_window) = primary_window_query
+        .single()
+        .ok()
+        .and_then(|entity| windows.get_window(entity))
+    else {
+        return;
+    };
+    winit_window.set_window_level(if state.always_on_top {
+        WindowLevel::AlwaysOnTop
+    } else {
+        WindowLevel::Normal
+    });
+    info!("Window always-on-top: {}", state.always_on_top);
+    *applied = Some(state.always_on_top);
+}
+
 fn set_window_icon(
     primary_window_query: Query<Entity, With<PrimaryWindow>>,
     windows: NonSend<bevy::winit::WinitWindows>,
@@ -1,7 +1,8 @@
 pub mod io;
 
-use crate::ui::elements::editor::state::FpsSetting;
+use crate::ui::elements::editor::state::{EditorWindowState, FpsSetting, StructureTableNaming};
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct AppSettings {
@@ -9,6 +10,22 @@ pub struct AppSettings {
     /// Whether to show hidden sheets (override metadata.hidden) in lists
     /// Default: false (respect hidden flags)
     pub show_hidden_sheets: bool,
+    /// Copy ranges as an A1-style reference (e.g. "B2:D10") for formulas in external tools
+    /// Default: false (copy the values as TSV)
+    #[serde(default)]
+    pub copy_formula_reference: bool,
+    /// Show a faint placeholder glyph in empty text cells
+    /// Default: false (empty cells stay blank)
+    #[serde(default)]
+    pub show_empty_cell_placeholder: bool,
+    /// Show technical columns (row_index, parent_key) in the grid as read-only labels
+    /// Default: false
+    #[serde(default)]
+    pub show_technical_columns: bool,
+    /// How a new structure column is renamed when its child table name is already taken
+    /// Default: numeric suffix
+    #[serde(default)]
+    pub structure_table_naming: StructureTableNaming,
     /// AI depth limit: how many levels of structure tables to process
     /// Default: 2
     #[serde(default = "default_ai_depth_limit")]
@@ -17,6 +34,89 @@ pub struct AppSettings {
     /// Default: 32
     #[serde(default = "default_ai_width_limit")]
     pub ai_width_limit: usize,
+    /// AI character budget: batches are split so the cells sent stay under this many characters.
+    /// A single larger row is still sent on its own. 0 = no character limit.
+    /// Default: 24000
+    #[serde(default = "default_ai_char_budget")]
+    pub ai_char_budget: usize,
+    /// How often a failed AI request is retried (rate limits, server errors, timeouts only)
+    /// Default: 3
+    #[serde(default = "default_ai_max_retries")]
+    pub ai_max_retries: u32,
+    /// Wait before the first retry in milliseconds; doubled for every further retry
+    /// Default: 2000
+    #[serde(default = "default_ai_retry_backoff_ms")]
+    pub ai_retry_backoff_ms: u64,
+    /// Minimum milliseconds between the starts of two AI requests
+    /// Default: 1000
+    #[serde(default = "default_ai_min_request_interval_ms")]
+    pub ai_min_request_interval_ms: u64,
+    /// Estimated tokens in one AI request above which the AI panel warns; keep it a bit
+    /// under the model's context window
+    /// Default: 800000
+    #[serde(default = "default_ai_token_warning_threshold")]
+    pub ai_token_warning_threshold: usize,
+    /// How many DB-backed sheets keep their grid data in memory (LRU).
+    /// Older sheets keep metadata only and reload from the database on demand.
+    /// 0 = unlimited. Default: 12
+    #[serde(default = "default_max_loaded_sheets")]
+    pub max_loaded_sheets: usize,
+    /// Master switch for AI features. When false the Python runtime, the AI script
+    /// and the key store are left alone at startup. Default: true
+    #[serde(default = "default_ai_features_enabled")]
+    pub ai_features_enabled: bool,
+    /// Where the bundled `ai_processor.py` is written and loaded from
+    /// Default: none (`script/` next to the executable, else the user data directory)
+    #[serde(default)]
+    pub ai_script_path: Option<PathBuf>,
+    /// Treat near-identical rows as duplicates when reviewing AI suggestions
+    /// Default: false (exact, case-insensitive matching)
+    #[serde(default)]
+    pub ai_duplicate_fuzzy: bool,
+    /// Similarity threshold for fuzzy duplicate matching (0.0..=1.0)
+    /// Default: 0.85
+    #[serde(default = "default_ai_duplicate_similarity")]
+    pub ai_duplicate_similarity: f32,
+    /// Show the read-only SQL console (developer tool)
+    /// Default: false
+    #[serde(default)]
+    pub sql_console_enabled: bool,
+    /// Last main window geometry, restored at startup
+    /// Default: none (platform default size and position)
+    #[serde(default)]
+    pub window_geometry: Option<WindowGeometry>,
+    /// Keep the main window above other windows
+    /// Default: false
+    #[serde(default)]
+    pub always_on_top: bool,
+    /// Data folder for databases and JSON sheets, used from the next start
+    /// Default: none (Documents/SkylineDB)
+    #[serde(default)]
+    pub data_directory: Option<PathBuf>,
+    /// Data folder switch chosen in Settings, carried out at the next start
+    /// Default: none
+    #[serde(default)]
+    pub pending_data_directory_change: Option<PendingDataDirectoryChange>,
+}
+
+/// Switch away from the data folder in use, applied at startup before the daemon runs
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct PendingDataDirectoryChange {
+    /// Folder in use when the change was chosen
+    pub from: PathBuf,
+    /// Move its contents to the new folder; otherwise start with the new folder as it is
+    pub move_contents: bool,
+}
+
+/// Size and position of the main window. Size is logical, position physical (outer top-left).
+/// While maximized the last restored size/position is kept so un-maximizing returns to it.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub struct WindowGeometry {
+    pub width: f32,
+    pub height: f32,
+    pub x: i32,
+    pub y: i32,
+    pub maximized: bool,
 }
 
 fn default_ai_depth_limit() -> usize {
@@ -27,13 +127,127 @@ fn default_ai_width_limit() -> usize {
     32
 }
 
+fn default_ai_char_budget() -> usize {
+    24_000
+}
+
+fn default_ai_max_retries() -> u32 {
+    3
+}
+
+fn default_ai_retry_backoff_ms() -> u64 {
+    2_000
+}
+
+fn default_ai_min_request_interval_ms() -> u64 {
+    1_000
+}
+
+fn default_ai_token_warning_threshold() -> usize {
+    800_000
+}
+
+fn default_max_loaded_sheets() -> usize {
+    12
+}
+
+fn default_ai_features_enabled() -> bool {
+    true
+}
+
+fn default_ai_duplicate_similarity() -> f32 {
+    0.85
+}
+
 impl Default for AppSettings {
     fn default() -> Self {
         Self {
             fps_setting: FpsSetting::default(),
             show_hidden_sheets: false,
+            copy_formula_reference: false,
+            show_empty_cell_placeholder: false,
+            show_technical_columns: false,
+            structure_table_naming: StructureTableNaming::default(),
             ai_depth_limit: default_ai_depth_limit(),
             ai_width_limit: default_ai_width_limit(),
+            ai_char_budget: default_ai_char_budget(),
+            ai_max_retries: default_ai_max_retries(),
+            ai_retry_backoff_ms: default_ai_retry_backoff_ms(),
+            ai_min_request_interval_ms: default_ai_min_request_interval_ms(),
+            ai_token_warning_threshold: default_ai_token_warning_threshold(),
+            max_loaded_sheets: default_max_loaded_sheets(),
+            ai_features_enabled: default_ai_features_enabled(),
+            ai_script_path: None,
+            ai_duplicate_fuzzy: false,
+            ai_duplicate_similarity: default_ai_duplicate_similarity(),
+            sql_console_enabled: false,
+            window_geometry: None,
+            always_on_top: false,
+            data_directory: None,
+            pending_data_directory_change: None,
         }
     }
 }
+
+impl AppSettings {
+    /// Snapshot the persisted settings from the editor state
+    pub fn from_state(state: &EditorWindowState) -> Self {
+        Self {
+            fps_setting: state.fps_setting,
+            show_hidden_sheets: state.show_hidden_sheets,
+            copy_formula_reference: state.copy_formula_reference,
+            show_empty_cell_placeholder: state.show_empty_cell_placeholder,
+            show_technical_columns: state.show_technical_columns,
+            structure_table_naming: state.structure_table_naming,
+            ai_depth_limit: state.ai_depth_limit,
+            ai_width_limit: state.ai_width_limit,
+            ai_char_budget: state.ai_char_budget,
+            ai_max_retries: state.ai_max_retries,
+            ai_retry_backoff_ms: state.ai_retry_backoff_ms,
+            ai_min_request_interval_ms: state.ai_min_request_interval_ms,
+            ai_token_warning_threshold: state.ai_token_warning_threshold,
+            max_loaded_sheets: state.max_loaded_sheets,
+            ai_features_enabled: state.ai_features_enabled,
+            ai_script_path: state.ai_script_path.clone(),
+            ai_duplicate_fuzzy: state.ai_duplicate_fuzzy,
+            ai_duplicate_similarity: state.ai_duplicate_similarity,
+            sql_console_enabled: state.sql_console_enabled,
+            window_geometry: state.window_geometry,
+            always_on_top: state.always_on_top,
+            data_directory: state.data_directory.clone(),
+            pending_data_directory_change: state.pending_data_directory_change.clone(),
+        }
+    }
+
+    /// Copy persisted settings into the editor state
+    pub fn apply_to_state(&self, state: &mut EditorWindowState) {
+        state.fps_setting = self.fps_setting;
+        state.show_hidden_sheets = self.show_hidden_sheets;
+        state.copy_formula_reference = self.copy_formula_reference;
+        state.show_empty_cell_placeholder = self.show_empty_cell_placeholder;
+        state.show_technical_columns = self.show_technical_columns;
+        state.structure_table_naming = self.structure_table_naming;
+        state.ai_depth_limit = self.ai_depth_limit;
+        state.ai_width_limit = self.ai_width_limit;
+        state.ai_char_budget = self.ai_char_budget;
+        state.ai_max_retries = self.ai_max_retries;
+        state.ai_retry_backoff_ms = self.ai_retry_backoff_ms;
+        state.ai_min_request_interval_ms = self.ai_min_request_interval_ms;
+        state.ai_token_warning_threshold = self.ai_token_warning_threshold;
+        state.max_loaded_sheets = self.max_loaded_sheets;
+        state.ai_features_enabled = self.ai_features_enabled;
+        state.ai_script_path = self.ai_script_path.clone();
+        state.ai_script_path_input = self
+            .ai_script_path
+            .as_ref()
+            .map(|p| p.display().to_string())
+            .unwrap_or_default();
+        state.ai_duplicate_fuzzy = self.ai_duplicate_fuzzy;
+        state.ai_duplicate_similarity = self.ai_duplicate_similarity.clamp(0.0, 1.0);
+        state.sql_console_enabled = self.sql_console_enabled;
+        state.window_geometry = self.window_geometry;
+        state.always_on_top = self.always_on_top;
+        state.data_directory = self.data_directory.clone();
+        state.pending_data_directory_change = self.pending_data_directory_change.clone();
+    }
+}
@@ -17,5 +17,10 @@ pub struct AiSchemaGroup {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct AiSchemaGroupStructureOverride {
     pub path: Vec<usize>,
-    pub allow_add_rows: bool,
+    /// Row generation for this structure; `None` follows the general setting
+    #[serde(default)]
+    pub allow_add_rows: Option<bool>,
+    /// AI processing skips this structure table and everything below it
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub skip: bool,
 }
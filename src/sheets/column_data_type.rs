@@ -14,6 +14,8 @@ pub enum ColumnDataType {
     F64,
     /// Link type - displays as clickable URL that opens in browser, with edit mode toggle
     Link,
+    /// Calendar date stored as ISO-8601 text (YYYY-MM-DD)
+    Date,
 }
 
 impl fmt::Display for ColumnDataType {
@@ -59,6 +61,7 @@ pub fn parse_column_data_type(s: &str) -> Option<ColumnDataType> {
         "F64" | "f64" | "Float" | "float" | "OptionF64" | "optionf64" | "Option<Float>"
         | "Option<float>" => Some(ColumnDataType::F64),
         "Link" | "link" | "URL" | "url" | "Url" => Some(ColumnDataType::Link),
+        "Date" | "date" => Some(ColumnDataType::Date),
         // Legacy integer widths map to I64
         "U8" | "u8" | "U16" | "u16" | "U32" | "u32" | "U64" | "u64" | "I8" | "i8" | "I16"
         | "i16" | "I32" | "i32" => Some(ColumnDataType::I64),
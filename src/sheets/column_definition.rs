@@ -31,8 +31,27 @@ pub struct ColumnDefinition {
     /// Marked as hidden (for technical columns that shouldn't be shown by default)
     #[serde(default)]
     pub hidden: bool,
-    // Legacy width accepted but never serialized (feature removed)
-    #[serde(default, skip_serializing)]
+    /// Display-only: render numeric values without insignificant trailing zeros
+    /// (e.g. `1.50000` -> `1.5`). Stored values keep full precision.
+    #[serde(default)]
+    pub trim_numeric_zeros: bool,
+    /// Sent to the AI as context, but AI suggestions for this column are never applied
+    #[serde(default)]
+    pub ai_read_only: bool,
+    /// Long text: the grid shows a one-line preview and a button opening a multi-line editor
+    #[serde(default)]
+    pub multiline: bool,
+    /// Enum columns: values outside the allowed list are added to it instead of rejected
+    #[serde(default)]
+    pub enum_auto_add: bool,
+    /// Inclusive (min, max) bounds for I64/F64 values; either side may be open-ended
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub numeric_range: Option<(Option<f64>, Option<f64>)>,
+    /// Value pre-filled into new rows; Date columns may use `@today`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_value: Option<String>,
+    /// UI width set by resizing the header; travels with the column on reorder
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub width: Option<f32>,
     #[serde(default)]
     pub structure_schema: Option<Vec<StructureFieldDefinition>>, // Only when validator == Some(Structure)
@@ -87,6 +106,12 @@ impl ColumnDefinition {
             ai_include_in_send: None,
             deleted: false,
             hidden: false,
+            trim_numeric_zeros: false,
+            ai_read_only: false,
+            multiline: false,
+            enum_auto_add: false,
+            numeric_range: None,
+            default_value: None,
             width: None,
             structure_schema: None,
             structure_column_order: None,
@@ -95,11 +120,27 @@ impl ColumnDefinition {
         }
     }
 
+    /// Name shown in the UI: the custom display name when set, otherwise the physical header.
+    /// Queries and links always use `header`.
+    pub fn display_name(&self) -> &str {
+        match self.display_header.as_deref() {
+            Some(name) if !name.trim().is_empty() => name,
+            _ => &self.header,
+        }
+    }
+
+    /// True for columns whose values are integers or floats
+    pub fn is_numeric(&self) -> bool {
+        matches!(self.data_type, ColumnDataType::I64 | ColumnDataType::F64)
+    }
+
     pub fn ensure_type_consistency(&mut self) -> bool {
         let expected_type = match &self.validator {
             Some(ColumnValidator::Basic(t)) => *t,
+            Some(ColumnValidator::Unique { data_type, .. }) => *data_type,
             Some(ColumnValidator::Linked { .. }) => ColumnDataType::String,
             Some(ColumnValidator::Structure) => ColumnDataType::String,
+            Some(ColumnValidator::Enum(_)) => ColumnDataType::String,
             None => ColumnDataType::String,
         };
         if self.data_type != expected_type {
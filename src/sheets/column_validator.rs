@@ -16,6 +16,13 @@ pub enum ColumnValidator {
     },
     // Schema B: Structure validator (schema embedded elsewhere, no indices here)
     Structure,
+    // Basic type whose non-empty values must not repeat within the column
+    Unique {
+        data_type: ColumnDataType,
+        case_sensitive: bool,
+    },
+    // Text column limited to a fixed list of allowed values (rendered as a dropdown)
+    Enum(Vec<String>),
 }
 
 // Custom Deserialize for backward compatibility (accept legacy Structure { source_column_indices: [...] })
@@ -72,6 +79,32 @@ impl<'de> Deserialize<'de> for ColumnValidator {
                     "Structure" => {
                         return Ok(ColumnValidator::Structure);
                     }
+                    "Unique" => {
+                        #[derive(Deserialize)]
+                        struct UniqueHelper {
+                            data_type: ColumnDataType,
+                            #[serde(default)]
+                            case_sensitive: bool,
+                        }
+                        let helper: UniqueHelper =
+                            serde_json::from_value(inner.clone()).map_err(|e| {
+                                de::Error::custom(format!(
+                                    "Invalid Unique validator payload: {}",
+                                    e
+                                ))
+                            })?;
+                        return Ok(ColumnValidator::Unique {
+                            data_type: helper.data_type,
+                            case_sensitive: helper.case_sensitive,
+                        });
+                    }
+                    "Enum" => {
+                        let values: Vec<String> =
+                            serde_json::from_value(inner.clone()).map_err(|e| {
+                                de::Error::custom(format!("Invalid Enum validator payload: {}", e))
+                            })?;
+                        return Ok(ColumnValidator::Enum(values));
+                    }
                     _ => {}
                 }
             }
@@ -97,6 +130,15 @@ impl fmt::Display for ColumnValidator {
                 )
             }
             ColumnValidator::Structure => write!(f, "Structure"),
+            ColumnValidator::Unique {
+                data_type,
+                case_sensitive,
+            } => write!(
+                f,
+                "Unique{{data_type: {}, case_sensitive: {}}}",
+                data_type, case_sensitive
+            ),
+            ColumnValidator::Enum(values) => write!(f, "Enum{:?}", values),
         }
     }
 }
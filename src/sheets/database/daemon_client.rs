@@ -57,6 +57,18 @@ impl DaemonClient {
         self.database_name.as_deref()
     }
 
+    /// A client for the same daemon whose default database is `db_name`, for writes
+    /// that go through helpers which do not take a database name
+    pub fn for_database(&self, db_name: &str) -> Self {
+        Self {
+            pipe_name: self.pipe_name.clone(),
+            daemon_exe_path: self.daemon_exe_path.clone(),
+            database_name: Some(db_name.to_string()),
+            #[cfg(test)]
+            is_mock: self.is_mock,
+        }
+    }
+
     /// Get database name or extract from path
     fn get_db_name(&self, db_path: Option<&str>) -> Result<String, String> {
         // If explicitly provided, use that
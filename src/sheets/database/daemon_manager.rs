@@ -9,7 +9,7 @@ const DAEMON_RELEASE_URL: &str = "https://github.com/sergeidart/sqlite_daemon/re
 const DAEMON_EXE_NAME: &str = "skylinedb-daemon.exe";
 
 /// Get the path where daemon executable should be located
-/// Stored in Documents/SkylineDB/daemon/skylinedb-daemon.exe
+/// Stored in <data folder>/daemon/skylinedb-daemon.exe
 pub fn get_daemon_path() -> PathBuf {
     crate::sheets::systems::io::get_default_data_base_path()
         .join("daemon")
@@ -83,6 +83,16 @@ pub fn is_daemon_running() -> bool {
     client.ping(None)  // Ping the router daemon without specifying a database
 }
 
+/// Stop a daemon left running by an earlier session; it keeps serving the data folder it
+/// was started with. Never starts one. Returns whether a daemon was stopped.
+pub fn shutdown_running_daemon() -> bool {
+    use super::daemon_client::DaemonClient;
+
+    // No executable path, so connecting cannot auto-start a daemon just to stop it
+    let client = DaemonClient::new(None, String::new());
+    client.ping(None) && client.shutdown_daemon().is_ok()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -11,6 +11,8 @@ pub enum DbError {
     TableNotFound(String),
     InvalidMetadata(String),
     MigrationFailed(String),
+    /// The user aborted a long-running operation
+    Cancelled,
     Other(String),
 }
 
@@ -26,6 +28,7 @@ impl fmt::Display for DbError {
             DbError::TableNotFound(name) => write!(f, "Table not found: {}", name),
             DbError::InvalidMetadata(msg) => write!(f, "Invalid metadata: {}", msg),
             DbError::MigrationFailed(msg) => write!(f, "Migration failed: {}", msg),
+            DbError::Cancelled => write!(f, "Cancelled"),
             DbError::Other(msg) => write!(f, "{}", msg),
         }
     }
@@ -50,3 +53,21 @@ impl From<serde_json::Error> for DbError {
         DbError::SerdeJson(e)
     }
 }
+
+impl From<zip::result::ZipError> for DbError {
+    fn from(e: zip::result::ZipError) -> Self {
+        DbError::Other(format!("Archive error: {}", e))
+    }
+}
+
+impl From<arrow::error::ArrowError> for DbError {
+    fn from(e: arrow::error::ArrowError) -> Self {
+        DbError::Other(format!("Arrow error: {}", e))
+    }
+}
+
+impl From<parquet::errors::ParquetError> for DbError {
+    fn from(e: parquet::errors::ParquetError) -> Self {
+        DbError::Other(format!("Parquet error: {}", e))
+    }
+}
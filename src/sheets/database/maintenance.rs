@@ -0,0 +1,129 @@
+// src/sheets/database/maintenance.rs
+//! Database compaction (VACUUM + REINDEX) shared by the `vacuum` CLI command and Settings
+
+use super::daemon_client::{DaemonClient, Statement, TransactionMode};
+use rusqlite::Connection;
+use std::path::{Path, PathBuf};
+
+/// File sizes around a compaction, in bytes (main file plus its WAL)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompactReport {
+    pub size_before: u64,
+    pub size_after: u64,
+}
+
+impl CompactReport {
+    pub fn summary(&self, db_path: &Path) -> String {
+        let db_name = db_path.file_name().map_or_else(
+            || db_path.display().to_string(),
+            |n| n.to_string_lossy().to_string(),
+        );
+        format!(
+            "Compacted {}: {:.1} KB -> {:.1} KB ({:.1} KB freed)",
+            db_name,
+            self.size_before as f64 / 1024.0,
+            self.size_after as f64 / 1024.0,
+            self.size_before.saturating_sub(self.size_after) as f64 / 1024.0
+        )
+    }
+}
+
+fn wal_path(db_path: &Path) -> PathBuf {
+    let mut name = db_path.as_os_str().to_owned();
+    name.push("-wal");
+    PathBuf::from(name)
+}
+
+/// On-disk size of a database: the main file plus any pending WAL
+pub fn database_size(db_path: &Path) -> u64 {
+    [db_path.to_path_buf(), wal_path(db_path)]
+        .iter()
+        .filter_map(|p| std::fs::metadata(p).ok())
+        .map(|m| m.len())
+        .sum()
+}
+
+/// Rebuild the database file and its indexes through the daemon, which owns the write
+/// connection while the app runs. VACUUM cannot run inside a transaction, so the statements
+/// are not wrapped in one.
+pub fn compact_database(db_path: &Path, daemon_client: &DaemonClient) -> Result<CompactReport, String> {
+    measure_compaction(db_path, || {
+        let statements = ["VACUUM", "REINDEX"]
+            .iter()
+            .map(|sql| Statement {
+                sql: sql.to_string(),
+                params: vec![],
+            })
+            .collect();
+        let response = daemon_client.exec_batch_with_mode(
+            statements,
+            db_path.file_name().and_then(|n| n.to_str()),
+            TransactionMode::NoTransaction,
+        )?;
+        match response.error {
+            Some(err) => Err(format!("Compaction failed: {}", err)),
+            None => Ok(()),
+        }
+    })
+}
+
+/// Run `compact` (VACUUM + REINDEX on `db_path` by whatever connection the caller owns) and
+/// report the file size before and after. The CLI passes a direct connection, since no
+/// daemon runs alongside it.
+pub fn measure_compaction(
+    db_path: &Path,
+    compact: impl FnOnce() -> Result<(), String>,
+) -> Result<CompactReport, String> {
+    if !db_path.is_file() {
+        return Err(format!("Database not found: {}", db_path.display()));
+    }
+    let size_before = database_size(db_path);
+
+    compact()?;
+
+    // In WAL mode the rebuilt pages sit in the WAL until checkpointed; fold them back so
+    // the reported size is the real one. Best effort: a busy reader only delays it.
+    if let Ok(conn) = Connection::open(db_path) {
+        let _ = conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(()));
+    }
+
+    Ok(CompactReport {
+        size_before,
+        size_after: database_size(db_path),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sheets::database::writer::test_helpers::exec_fixture_sql;
+
+    #[test]
+    fn compaction_shrinks_a_database_after_deletes() {
+        let db_path =
+            std::env::temp_dir().join(format!("skylinedb_vacuum_test_{}.db", uuid::Uuid::new_v4()));
+        {
+            let conn = Connection::open(&db_path).unwrap();
+            // 500 rows of 1000 characters, then deleted again
+            exec_fixture_sql(
+                &conn,
+                "CREATE TABLE Items (name TEXT); CREATE INDEX idx_items_name ON Items(name);
+                 WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < 500)
+                 INSERT INTO Items (name) SELECT replace(hex(zeroblob(500)), '0', 'x') FROM n;
+                 DELETE FROM Items;",
+            );
+        }
+
+        let report = measure_compaction(&db_path, || {
+            let conn = Connection::open(&db_path).map_err(|e| e.to_string())?;
+            exec_fixture_sql(&conn, "VACUUM; REINDEX;");
+            Ok(())
+        })
+        .unwrap();
+        assert!(report.size_after < report.size_before, "{:?}", report);
+        assert!(report.summary(&db_path).contains("freed"));
+
+        std::fs::remove_file(&db_path).unwrap();
+        assert!(measure_compaction(&db_path, || Ok(())).is_err());
+    }
+}
@@ -0,0 +1,189 @@
+// src/sheets/database/migration/csv_migration.rs
+
+use bevy::prelude::*;
+use rusqlite::Connection;
+use std::collections::HashSet;
+use std::path::Path;
+
+use super::super::daemon_client::DaemonClient;
+use super::super::error::{DbError, DbResult};
+use super::super::{schema, writer::DbWriter};
+use crate::sheets::definitions::{ColumnDataType, ColumnDefinition, SheetMetadata};
+use crate::sheets::systems::io::csv::{parse_csv, sniff_csv_delimiter};
+
+pub struct CsvMigration;
+
+impl CsvMigration {
+    /// Import a CSV/TSV file (first row = headers) as a new table with inferred column types
+    pub fn migrate_sheet_from_csv(
+        conn: &mut Connection,
+        csv_path: &Path,
+        table_name: &str,
+        daemon_client: &DaemonClient,
+    ) -> DbResult<()> {
+        info!("Importing sheet '{}' from CSV '{}'...", table_name, csv_path.display());
+
+        if schema::queries::table_exists(conn, table_name)? {
+            return Err(DbError::MigrationFailed(format!(
+                "Table '{}' already exists",
+                table_name
+            )));
+        }
+
+        let text = std::fs::read_to_string(csv_path)?;
+        let mut records = parse_csv(&text, sniff_csv_delimiter(&text));
+        if records.is_empty() {
+            return Err(DbError::MigrationFailed(format!(
+                "'{}' has no header row",
+                csv_path.display()
+            )));
+        }
+        let raw_headers = records.remove(0);
+        let width = used_column_count(&raw_headers, &records);
+        if width == 0 {
+            return Err(DbError::MigrationFailed(format!(
+                "'{}' has no columns",
+                csv_path.display()
+            )));
+        }
+        let grid: Vec<Vec<String>> = records
+            .into_iter()
+            .map(|mut row| {
+                row.resize(width, String::new());
+                row
+            })
+            .collect();
+
+        let columns: Vec<ColumnDefinition> = unique_headers(&raw_headers[..width.min(raw_headers.len())], width)
+            .into_iter()
+            .enumerate()
+            .map(|(idx, header)| {
+                ColumnDefinition::new_basic(header, infer_column_type(grid.iter().map(|r| r[idx].as_str())))
+            })
+            .collect();
+
+        let db_name = conn
+            .path()
+            .and_then(|p| Path::new(p).file_name())
+            .and_then(|n| n.to_str())
+            .map(str::to_string);
+        let category = db_name
+            .as_deref()
+            .and_then(|n| Path::new(n).file_stem())
+            .and_then(|s| s.to_str())
+            .map(str::to_string);
+        let metadata = SheetMetadata {
+            columns,
+            ..SheetMetadata::create_generic(
+                table_name.to_string(),
+                format!("{}.json", table_name),
+                width,
+                category,
+            )
+        };
+
+        let tx = conn.transaction()?;
+        schema::ensure_global_metadata_table(&tx, daemon_client)?;
+        schema::create_data_table(table_name, &metadata.columns, daemon_client, db_name.as_deref())?;
+        schema::create_metadata_table(table_name, &metadata, daemon_client, db_name.as_deref())?;
+        schema::create_ai_groups_table(&tx, table_name, &metadata, daemon_client)?;
+        schema::insert_table_metadata(table_name, &metadata, None, daemon_client)?;
+        DbWriter::insert_grid_data_with_progress(&tx, table_name, &grid, &metadata, |_| {}, None, daemon_client)?;
+        tx.commit()?;
+
+        info!(
+            "Imported {} rows x {} columns into '{}' from CSV",
+            grid.len(),
+            width,
+            table_name
+        );
+        Ok(())
+    }
+}
+
+/// Number of leading columns to keep: trailing columns with a blank header and no values are dropped
+fn used_column_count(headers: &[String], rows: &[Vec<String>]) -> usize {
+    let width = rows.iter().map(Vec::len).chain(std::iter::once(headers.len())).max().unwrap_or(0);
+    (0..width)
+        .rev()
+        .find(|&col| {
+            headers.get(col).map_or(false, |h| !h.trim().is_empty())
+                || rows.iter().any(|r| r.get(col).map_or(false, |v| !v.trim().is_empty()))
+        })
+        .map_or(0, |last| last + 1)
+}
+
+/// Trimmed, non-empty, case-insensitively unique headers; blanks become "Column N" and
+/// repeats (including the reserved `id`/`row_index`) get a numeric suffix
+fn unique_headers(raw: &[String], width: usize) -> Vec<String> {
+    let mut seen: HashSet<String> = ["id", "row_index"].iter().map(|s| s.to_string()).collect();
+    (0..width)
+        .map(|idx| {
+            let base = raw
+                .get(idx)
+                .map(|h| h.trim().to_string())
+                .filter(|h| !h.is_empty())
+                .unwrap_or_else(|| format!("Column {}", idx + 1));
+            let mut candidate = base.clone();
+            let mut n = 2;
+            while !seen.insert(candidate.to_lowercase()) {
+                candidate = format!("{}_{}", base, n);
+                n += 1;
+            }
+            candidate
+        })
+        .collect()
+}
+
+/// I64 when every non-empty value parses as an integer, F64 when every one parses as a
+/// number, otherwise String (also for all-empty columns)
+fn infer_column_type<'a>(values: impl Iterator<Item = &'a str>) -> ColumnDataType {
+    let mut any = false;
+    let mut all_int = true;
+    let mut all_float = true;
+    for value in values.map(str::trim).filter(|v| !v.is_empty()) {
+        any = true;
+        all_int &= value.parse::<i64>().is_ok();
+        all_float &= value.parse::<f64>().is_ok();
+        if !all_float {
+            break;
+        }
+    }
+    match (any, all_int, all_float) {
+        (true, true, _) => ColumnDataType::I64,
+        (true, false, true) => ColumnDataType::F64,
+        _ => ColumnDataType::String,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn infers_narrowest_numeric_type() {
+        assert_eq!(infer_column_type(["1", "", "-3"].into_iter()), ColumnDataType::I64);
+        assert_eq!(infer_column_type(["1", "2.5"].into_iter()), ColumnDataType::F64);
+        assert_eq!(infer_column_type(["1", "x"].into_iter()), ColumnDataType::String);
+        assert_eq!(infer_column_type(["", " "].into_iter()), ColumnDataType::String);
+    }
+
+    #[test]
+    fn headers_are_deduplicated_with_suffixes() {
+        let headers = unique_headers(&strings(&["Name", "name", "", "id", "Name"]), 5);
+        assert_eq!(headers, strings(&["Name", "name_2", "Column 3", "id_2", "Name_3"]));
+    }
+
+    #[test]
+    fn empty_trailing_columns_are_dropped() {
+        let headers = strings(&["A", "B", "", ""]);
+        let rows = vec![strings(&["1", "", "", ""]), strings(&["2", "", "x"])];
+        assert_eq!(used_column_count(&headers, &rows), 3);
+        let rows = vec![strings(&["1", "", "", ""])];
+        assert_eq!(used_column_count(&headers, &rows), 2);
+    }
+}
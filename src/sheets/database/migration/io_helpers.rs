@@ -1,14 +1,24 @@
 // src/sheets/database/migration/io_helpers.rs
 
+use arrow::array::{ArrayRef, Float64Builder, Int64Builder, StringBuilder};
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::record_batch::RecordBatch;
 use bevy::prelude::*;
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::file::properties::WriterProperties;
 use rusqlite::Connection;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 use super::super::error::{DbError, DbResult};
 use super::super::reader::DbReader;
+use super::super::schema::queries::table_exists;
 use super::dependency_handler::DependencyHandler;
-use crate::sheets::definitions::SheetMetadata;
+use crate::sheets::definitions::{ColumnDataType, ColumnValidator, SheetMetadata};
+use crate::sheets::systems::io::csv::push_csv_record;
+use crate::sheets::systems::logic::update_column_validator::structure_naming::structure_child_table_name;
 
 #[derive(Debug, Clone)]
 pub struct JsonSheetPair {
@@ -98,6 +108,279 @@ impl IoHelpers {
         Ok(())
     }
 
+    /// Export sheet from database to newline-delimited JSON (`{table}.jsonl`): one object
+    /// per row keyed by header. Structure columns carry their child row count.
+    pub fn export_sheet_to_jsonl(
+        conn: &Connection,
+        table_name: &str,
+        output_folder: &Path,
+        daemon_client: &super::super::daemon_client::DaemonClient,
+    ) -> DbResult<PathBuf> {
+        let sheet_data = DbReader::read_sheet(conn, table_name, daemon_client, None)?;
+        let metadata = sheet_data
+            .metadata
+            .ok_or_else(|| DbError::InvalidMetadata("No metadata found".into()))?;
+
+        let child_counts = Self::structure_child_counts(conn, table_name, &metadata)?;
+        let mut out = String::new();
+        for row in &sheet_data.grid {
+            out.push_str(&Self::jsonl_record(&metadata, row, &child_counts)?);
+            out.push('\n');
+        }
+        let data_path = output_folder.join(format!("{}.jsonl", table_name));
+        std::fs::write(&data_path, out)?;
+
+        info!(
+            "Exported '{}' to JSONL ({} rows)",
+            table_name,
+            sheet_data.grid.len()
+        );
+        Ok(data_path)
+    }
+
+    /// Export sheet from database to CSV (`{table}.csv`): a header row, then one record per
+    /// row. Structure columns carry their child row count, as in the JSONL export.
+    pub fn export_sheet_to_csv(
+        conn: &Connection,
+        table_name: &str,
+        output_folder: &Path,
+        daemon_client: &super::super::daemon_client::DaemonClient,
+    ) -> DbResult<PathBuf> {
+        let sheet_data = DbReader::read_sheet(conn, table_name, daemon_client, None)?;
+        let metadata = sheet_data
+            .metadata
+            .ok_or_else(|| DbError::InvalidMetadata("No metadata found".into()))?;
+
+        let child_counts = Self::structure_child_counts(conn, table_name, &metadata)?;
+        let out = Self::csv_text(&metadata, &sheet_data.grid, &child_counts);
+        let data_path = output_folder.join(format!("{}.csv", table_name));
+        std::fs::write(&data_path, out)?;
+
+        info!(
+            "Exported '{}' to CSV ({} rows)",
+            table_name,
+            sheet_data.grid.len()
+        );
+        Ok(data_path)
+    }
+
+    /// CSV text for a sheet grid: live (non-deleted) columns only, header row first
+    fn csv_text(
+        metadata: &SheetMetadata,
+        grid: &[Vec<String>],
+        child_counts: &HashMap<usize, HashMap<String, usize>>,
+    ) -> String {
+        let headers = metadata.get_headers();
+        let live_columns: Vec<usize> = (0..metadata.columns.len())
+            .filter(|&i| !metadata.columns[i].deleted)
+            .collect();
+        let mut out = String::new();
+        let header_row: Vec<&str> = live_columns.iter().map(|&i| headers[i].as_str()).collect();
+        push_csv_record(&mut out, &header_row);
+        for row in grid {
+            let record: Vec<String> = live_columns
+                .iter()
+                .map(|&idx| {
+                    if matches!(metadata.columns[idx].validator, Some(ColumnValidator::Structure)) {
+                        Self::structure_cell_count(child_counts, idx, row).to_string()
+                    } else {
+                        row.get(idx).cloned().unwrap_or_default()
+                    }
+                })
+                .collect();
+            push_csv_record(&mut out, &record);
+        }
+        out
+    }
+
+    /// Child rows per parent row_index of every structure column of `table_name`, keyed by
+    /// column index (children store their parent's row_index in parent_key)
+    pub fn structure_child_counts(
+        conn: &Connection,
+        table_name: &str,
+        metadata: &SheetMetadata,
+    ) -> DbResult<HashMap<usize, HashMap<String, usize>>> {
+        let mut counts = HashMap::new();
+        for (idx, col) in metadata.columns.iter().enumerate() {
+            if col.deleted || !matches!(col.validator, Some(ColumnValidator::Structure)) {
+                continue;
+            }
+            let child_table = structure_child_table_name(table_name, &col.header);
+            counts.insert(idx, Self::count_children_by_parent(conn, &child_table)?);
+        }
+        Ok(counts)
+    }
+
+    /// Child rows of `child_table` per parent_key; empty when the table does not exist
+    pub fn count_children_by_parent(
+        conn: &Connection,
+        child_table: &str,
+    ) -> DbResult<HashMap<String, usize>> {
+        if !table_exists(conn, child_table)? {
+            return Ok(HashMap::new());
+        }
+        let mut stmt = conn.prepare(&format!(
+            "SELECT CAST(parent_key AS TEXT), COUNT(*) FROM \"{}\" GROUP BY parent_key",
+            child_table
+        ))?;
+        let counts = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, Option<String>>(0)?.unwrap_or_default(),
+                    row.get::<_, i64>(1)? as usize,
+                ))
+            })?
+            .collect::<Result<HashMap<_, _>, _>>()?;
+        Ok(counts)
+    }
+
+    /// Child row count of a structure cell: looked up by the row's row_index (first cell)
+    fn structure_cell_count(
+        child_counts: &HashMap<usize, HashMap<String, usize>>,
+        col_idx: usize,
+        row: &[String],
+    ) -> usize {
+        let parent_row_index = row.first().map(|v| v.trim()).unwrap_or_default();
+        child_counts
+            .get(&col_idx)
+            .and_then(|counts| counts.get(parent_row_index))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// One JSONL line (without the newline), keys in column order. serde_json handles
+    /// quote and control character escaping; non-ASCII text stays as UTF-8, which JSON permits.
+    fn jsonl_record(
+        metadata: &SheetMetadata,
+        row: &[String],
+        child_counts: &HashMap<usize, HashMap<String, usize>>,
+    ) -> DbResult<String> {
+        let mut fields = Vec::with_capacity(metadata.columns.len());
+        for (idx, (header, col)) in metadata
+            .get_headers()
+            .into_iter()
+            .zip(metadata.columns.iter())
+            .enumerate()
+        {
+            if col.deleted {
+                continue;
+            }
+            let value = if matches!(col.validator, Some(ColumnValidator::Structure)) {
+                serde_json::Value::from(Self::structure_cell_count(child_counts, idx, row))
+            } else {
+                serde_json::Value::String(row.get(idx).cloned().unwrap_or_default())
+            };
+            fields.push(format!(
+                "{}:{}",
+                serde_json::to_string(&header)?,
+                serde_json::to_string(&value)?
+            ));
+        }
+        Ok(format!("{{{}}}", fields.join(",")))
+    }
+
+    /// Export sheet from database to Apache Parquet (`{table}.parquet`, Snappy compressed);
+    /// returns the written file path. Deleted columns are left out; all fields are nullable.
+    ///
+    /// Type mapping:
+    /// - `ColumnDataType::I64` → Int64; empty or non-integer cells are written as null
+    /// - `ColumnDataType::F64` → Float64; empty or non-numeric cells are written as null
+    /// - Structure columns → Int64 holding the row's child row count (0 without children)
+    /// - everything else (String, Bool, Date, Link, linked columns) → Utf8, text as stored,
+    ///   empty cells stay ""
+    ///
+    /// Bool and Date columns are kept as their stored text ("true"/"false", ISO dates) so the
+    /// file matches what the editor shows; cast them downstream if needed.
+    pub fn export_sheet_to_parquet(
+        conn: &Connection,
+        table_name: &str,
+        output_folder: &Path,
+        daemon_client: &super::super::daemon_client::DaemonClient,
+    ) -> DbResult<PathBuf> {
+        let sheet_data = DbReader::read_sheet(conn, table_name, daemon_client, None)?;
+        let metadata = sheet_data
+            .metadata
+            .ok_or_else(|| DbError::InvalidMetadata("No metadata found".into()))?;
+
+        let child_counts = Self::structure_child_counts(conn, table_name, &metadata)?;
+        let batch = Self::parquet_record_batch(&metadata, &sheet_data.grid, &child_counts)?;
+        let data_path = output_folder.join(format!("{}.parquet", table_name));
+        let file = std::fs::File::create(&data_path)?;
+        let props = WriterProperties::builder()
+            .set_compression(Compression::SNAPPY)
+            .build();
+        let mut writer = ArrowWriter::try_new(file, batch.schema(), Some(props))?;
+        writer.write(&batch)?;
+        writer.close()?;
+
+        info!(
+            "Exported '{}' to Parquet ({} rows)",
+            table_name,
+            sheet_data.grid.len()
+        );
+        Ok(data_path)
+    }
+
+    /// Typed Arrow batch for a sheet grid, following the mapping documented on
+    /// `export_sheet_to_parquet`
+    fn parquet_record_batch(
+        metadata: &SheetMetadata,
+        grid: &[Vec<String>],
+        child_counts: &HashMap<usize, HashMap<String, usize>>,
+    ) -> DbResult<RecordBatch> {
+        let mut fields = Vec::with_capacity(metadata.columns.len());
+        let mut arrays: Vec<ArrayRef> = Vec::with_capacity(metadata.columns.len());
+        for (idx, (header, col)) in metadata
+            .get_headers()
+            .into_iter()
+            .zip(metadata.columns.iter())
+            .enumerate()
+        {
+            if col.deleted {
+                continue;
+            }
+            let cells = grid
+                .iter()
+                .map(|row| row.get(idx).map(String::as_str).unwrap_or("").trim());
+            let (data_type, array): (DataType, ArrayRef) =
+                if matches!(col.validator, Some(ColumnValidator::Structure)) {
+                    let mut builder = Int64Builder::with_capacity(grid.len());
+                    for row in grid {
+                        builder.append_value(Self::structure_cell_count(child_counts, idx, row) as i64);
+                    }
+                    (DataType::Int64, Arc::new(builder.finish()))
+                } else {
+                    match col.data_type {
+                        ColumnDataType::I64 => {
+                            let mut builder = Int64Builder::with_capacity(grid.len());
+                            for cell in cells {
+                                builder.append_option(cell.parse::<i64>().ok());
+                            }
+                            (DataType::Int64, Arc::new(builder.finish()))
+                        }
+                        ColumnDataType::F64 => {
+                            let mut builder = Float64Builder::with_capacity(grid.len());
+                            for cell in cells {
+                                builder.append_option(cell.parse::<f64>().ok());
+                            }
+                            (DataType::Float64, Arc::new(builder.finish()))
+                        }
+                        _ => {
+                            let mut builder = StringBuilder::with_capacity(grid.len(), 0);
+                            for row in grid {
+                                builder.append_value(row.get(idx).map(String::as_str).unwrap_or(""));
+                            }
+                            (DataType::Utf8, Arc::new(builder.finish()))
+                        }
+                    }
+                };
+            fields.push(Field::new(header, data_type, true));
+            arrays.push(array);
+        }
+        let batch = RecordBatch::try_new(Arc::new(Schema::new(fields)), arrays)?;
+        Ok(batch)
+    }
+
     /// Load JSON metadata from a file
     pub fn load_metadata(meta_path: &Path) -> DbResult<SheetMetadata> {
         let meta_content = std::fs::read_to_string(meta_path)?;
@@ -112,3 +395,126 @@ impl IoHelpers {
         Ok(grid)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sheets::database::writer::test_helpers::exec_fixture_sql;
+
+    #[test]
+    fn jsonl_record_escapes_text_and_counts_structures() {
+        let mut metadata = SheetMetadata::create_generic("T".into(), "T.json".into(), 4, None);
+        metadata.columns[0].header = "row_index".into();
+        metadata.columns[1].header = "Name".into();
+        metadata.columns[2].header = "Items".into();
+        metadata.columns[2].validator = Some(ColumnValidator::Structure);
+        metadata.columns[3].deleted = true;
+        let row = vec!["5".to_string(), "Say \"hé\"\n".to_string(), String::new(), "gone".to_string()];
+        let child_counts = HashMap::from([(2, HashMap::from([("5".to_string(), 3)]))]);
+        let line = IoHelpers::jsonl_record(&metadata, &row, &child_counts).unwrap();
+        assert_eq!(line, r#"{"row_index":"5","Name":"Say \"hé\"\n","Items":3}"#);
+    }
+
+    #[test]
+    fn csv_text_quotes_fields_and_counts_structures() {
+        let mut metadata = SheetMetadata::create_generic("T".into(), "T.json".into(), 4, None);
+        metadata.columns[0].header = "row_index".into();
+        metadata.columns[1].header = "Name".into();
+        metadata.columns[2].header = "Items".into();
+        metadata.columns[2].validator = Some(ColumnValidator::Structure);
+        metadata.columns[3].deleted = true;
+        let grid = vec![vec!["5".to_string(), "Say \"hi\", twice".to_string(), "2 rows".to_string(), "gone".to_string()]];
+        let child_counts = HashMap::from([(2, HashMap::from([("5".to_string(), 2)]))]);
+        assert_eq!(
+            IoHelpers::csv_text(&metadata, &grid, &child_counts),
+            "row_index,Name,Items\r\n5,\"Say \"\"hi\"\", twice\",2\r\n"
+        );
+    }
+
+    #[test]
+    fn structure_counts_follow_parent_key_of_a_read_grid() {
+        let conn = Connection::open_in_memory().unwrap();
+        exec_fixture_sql(
+            &conn,
+            "CREATE TABLE \"Items\" (id INTEGER PRIMARY KEY, row_index INTEGER, \"Name\" TEXT);
+             INSERT INTO \"Items\" (row_index, \"Name\") VALUES (4, 'Sword'), (7, 'Shield');
+             CREATE TABLE \"Items_Parts\" (id INTEGER PRIMARY KEY, row_index INTEGER, parent_key TEXT, \"Part\" TEXT);
+             INSERT INTO \"Items_Parts\" (row_index, parent_key, \"Part\") VALUES (0, '4', 'Blade'), (1, '4', 'Hilt'), (2, '9', 'Stray');",
+        );
+        let mut metadata = SheetMetadata::create_generic("Items".into(), "Items.json".into(), 3, None);
+        metadata.columns[0].header = "row_index".into();
+        metadata.columns[1].header = "Name".into();
+        metadata.columns[2].header = "Parts".into();
+        metadata.columns[2].validator = Some(ColumnValidator::Structure);
+        let (grid, _) = DbReader::read_grid_data(&conn, "Items", &metadata).unwrap();
+
+        let child_counts = IoHelpers::structure_child_counts(&conn, "Items", &metadata).unwrap();
+        let lines: Vec<String> = grid
+            .iter()
+            .map(|row| IoHelpers::jsonl_record(&metadata, row, &child_counts).unwrap())
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                r#"{"row_index":"7","Name":"Shield","Parts":0}"#,
+                r#"{"row_index":"4","Name":"Sword","Parts":2}"#,
+            ]
+        );
+
+        use arrow::array::Int64Array;
+        let batch = IoHelpers::parquet_record_batch(&metadata, &grid, &child_counts).unwrap();
+        let parts = batch.column(2).as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!((parts.value(0), parts.value(1)), (0, 2));
+    }
+
+    #[test]
+    fn parquet_batch_types_numeric_columns_and_nulls_empty_cells() {
+        use arrow::array::{Array, Float64Array, Int64Array, StringArray};
+
+        let mut metadata = SheetMetadata::create_generic("T".into(), "T.json".into(), 5, None);
+        let names = ["Name", "Level", "Weight", "Items", "Old"];
+        for (col, name) in metadata.columns.iter_mut().zip(names) {
+            col.header = name.into();
+        }
+        metadata.columns[1].data_type = ColumnDataType::I64;
+        metadata.columns[2].data_type = ColumnDataType::F64;
+        metadata.columns[3].validator = Some(ColumnValidator::Structure);
+        metadata.columns[4].deleted = true;
+        let grid: Vec<Vec<String>> = vec![
+            vec!["Sword", "3", "1.5", "2", "x"],
+            vec!["", "", "abc", "", "y"],
+        ]
+        .into_iter()
+        .map(|row| row.into_iter().map(String::from).collect())
+        .collect();
+
+        // Rows are keyed by their first cell; "Sword" has two children
+        let child_counts = HashMap::from([(3, HashMap::from([("Sword".to_string(), 2)]))]);
+        let batch = IoHelpers::parquet_record_batch(&metadata, &grid, &child_counts).unwrap();
+        let schema = batch.schema();
+        let types: Vec<(&str, &DataType)> = schema
+            .fields()
+            .iter()
+            .map(|f| (f.name().as_str(), f.data_type()))
+            .collect();
+        assert_eq!(
+            types,
+            vec![
+                ("Name", &DataType::Utf8),
+                ("Level", &DataType::Int64),
+                ("Weight", &DataType::Float64),
+                ("Items", &DataType::Int64),
+            ]
+        );
+        let names = batch.column(0).as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!((names.value(0), names.value(1), names.null_count()), ("Sword", "", 0));
+        let levels = batch.column(1).as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(levels.value(0), 3);
+        assert!(levels.is_null(1));
+        let weights = batch.column(2).as_any().downcast_ref::<Float64Array>().unwrap();
+        assert_eq!(weights.value(0), 1.5);
+        assert!(weights.is_null(1));
+        let items = batch.column(3).as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!((items.value(0), items.value(1)), (2, 0));
+    }
+}
@@ -2,8 +2,9 @@
 
 use bevy::prelude::*;
 use rusqlite::Connection;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
 // Use modern Base64 engine API (deprecated base64::encode replaced)
 use base64::Engine as _;
 
@@ -19,12 +20,21 @@ pub struct MigrationReport {
     pub sheets_failed: usize,
     pub failed_sheets: Vec<(String, String)>, // (sheet_name, error_message)
     pub linked_sheets_found: Vec<String>,
+    /// Data rows written (project import only)
+    pub rows_migrated: usize,
+    /// Archive files that were not imported (project import only)
+    pub skipped_files: Vec<String>,
+    /// The run was cancelled; sheets after the cancelled one were not attempted
+    pub cancelled: bool,
 }
 
 pub struct JsonMigration;
 
 impl JsonMigration {
-    /// Migrate a single sheet from JSON files to database
+    /// Migrate a single sheet from JSON files to database.
+    /// When `cancel` is set between row chunks the partially written table is dropped
+    /// and `DbError::Cancelled` is returned. `db_filename` names the database to write
+    /// into when the caller is not working on the client's current one.
     pub fn migrate_sheet_from_json(
         conn: &mut Connection,
         json_data_path: &Path,
@@ -32,20 +42,37 @@ impl JsonMigration {
         table_name: &str,
         display_order: Option<i32>,
         mut on_rows_chunk: Option<&mut dyn FnMut(usize)>,
+        cancel: Option<&AtomicBool>,
+        db_filename: Option<&str>,
         daemon_client: &DaemonClient,
     ) -> DbResult<()> {
         info!("Migrating sheet '{}' from JSON files...", table_name);
 
+        // An explicit database also becomes the default for the helpers that take no name
+        let scoped_client;
+        let daemon_client = match db_filename {
+            Some(db) => {
+                scoped_client = daemon_client.for_database(db);
+                &scoped_client
+            }
+            None => daemon_client,
+        };
+
         // 1. Load JSON metadata and grid
         let metadata = IoHelpers::load_metadata(json_meta_path)?;
         let grid = IoHelpers::load_grid_data(json_data_path)?;
 
+        // A cancelled run must not drop tables that were already there before it started
+        let preexisting = Self::existing_sheet_tables(conn, table_name, &metadata)?;
+
         // 2. Create schema
         let tx = conn.transaction()?;
 
-        let db_name = json_data_path
-            .file_stem()
-            .and_then(|s| s.to_str());
+        let db_name = db_filename.or_else(|| {
+            json_data_path
+                .file_stem()
+                .and_then(|s| s.to_str())
+        });
 
         schema::ensure_global_metadata_table(&tx, daemon_client)?;
         schema::create_data_table(table_name, &metadata.columns, daemon_client, db_name)?;
@@ -126,31 +153,38 @@ impl JsonMigration {
             }
         }
 
-        // 4. Insert data for main table (with per-1k row chunk callback)
+        // 4. Insert data for main table (callback after each chunk, including the final one,
+        //    so small sheets (<1000 rows) still report progress)
         let mut maybe_cb = on_rows_chunk.as_mut();
-        DbWriter::insert_grid_data_with_progress(&tx, table_name, &grid, &metadata, |rows_done| {
+        let mut inserted = DbWriter::insert_grid_data_with_progress(&tx, table_name, &grid, &metadata, |rows_done| {
             if let Some(cb) = maybe_cb.as_deref_mut() {
                 cb(rows_done);
             }
-        }, daemon_client)?;
-        // Always emit a final main-progress tick so small sheets (<1000 rows) still report progress
-        if let Some(cb) = maybe_cb.as_deref_mut() {
-            cb(grid.len());
-        }
+        }, cancel, daemon_client);
 
         // 5. Extract inline JSON from structure columns and populate structure tables
-        if !structure_fields_by_col.is_empty() {
-            Self::migrate_structure_data(
+        if inserted.is_ok() && !structure_fields_by_col.is_empty() {
+            inserted = Self::migrate_structure_data(
                 &tx,
                 table_name,
                 &grid,
                 &metadata,
                 &structure_fields_by_col,
                 maybe_cb,
+                cancel,
                 daemon_client,
-            )?;
+            );
         }
 
+        if let Err(DbError::Cancelled) = inserted {
+            // Roll back the local transaction and drop what the daemon already wrote
+            drop(tx);
+            Self::discard_partial_table(table_name, &metadata, &preexisting, daemon_client);
+            info!("Migration of '{}' cancelled; partial table removed", table_name);
+            return Err(DbError::Cancelled);
+        }
+        inserted?;
+
         tx.commit()?;
 
         info!("Successfully migrated sheet '{}'", table_name);
@@ -165,6 +199,7 @@ impl JsonMigration {
         metadata: &SheetMetadata,
         structure_fields_by_col: &HashMap<usize, Vec<crate::sheets::definitions::StructureFieldDefinition>>,
         mut maybe_cb: Option<&mut &mut dyn FnMut(usize)>,
+        cancel: Option<&AtomicBool>,
         daemon_client: &DaemonClient,
     ) -> DbResult<()> {
         // Track aggregate count of inserted structure rows to emit per-1k updates
@@ -336,6 +371,9 @@ impl JsonMigration {
                             // Report combined progress: main rows + aggregated structure rows
                             cb(main_total_rows + struct_total_inserted);
                         }
+                        if cancel.is_some_and(|flag| flag.load(Ordering::Relaxed)) {
+                            return Err(DbError::Cancelled);
+                        }
                     }
                 }
             }
@@ -350,4 +388,103 @@ impl JsonMigration {
 
         Ok(())
     }
+
+    /// The sheet's main and structure tables, in the order the discard walks them
+    fn sheet_tables(table_name: &str, metadata: &SheetMetadata) -> Vec<String> {
+        let mut tables = vec![table_name.to_string()];
+        tables.extend(
+            metadata
+                .columns
+                .iter()
+                .filter(|c| matches!(c.validator, Some(ColumnValidator::Structure)))
+                .map(|c| format!("{}_{}", table_name, c.header)),
+        );
+        tables
+    }
+
+    fn existing_sheet_tables(
+        conn: &Connection,
+        table_name: &str,
+        metadata: &SheetMetadata,
+    ) -> DbResult<HashSet<String>> {
+        let mut existing = HashSet::new();
+        for table in Self::sheet_tables(table_name, metadata) {
+            if schema::queries::table_exists(conn, &table)? {
+                existing.insert(table);
+            }
+        }
+        Ok(existing)
+    }
+
+    /// Drops a table created by this run together with its sidecar tables and _Metadata row;
+    /// tables listed in `preexisting` are kept
+    fn discard_statements(
+        table_name: &str,
+        metadata: &SheetMetadata,
+        preexisting: &HashSet<String>,
+    ) -> Vec<Statement> {
+        let mut statements = Vec::new();
+        for table in Self::sheet_tables(table_name, metadata)
+            .into_iter()
+            .filter(|t| !preexisting.contains(t))
+        {
+            for name in [
+                table.clone(),
+                format!("{}_Metadata", table),
+                format!("{}_AIGroups", table),
+            ] {
+                statements.push(Statement {
+                    sql: format!("DROP TABLE IF EXISTS \"{}\"", name),
+                    params: vec![],
+                });
+            }
+            statements.push(Statement {
+                sql: "DELETE FROM _Metadata WHERE table_name = ?".to_string(),
+                params: vec![serde_json::Value::String(table)],
+            });
+        }
+        statements
+    }
+
+    /// Best-effort removal of the tables a cancelled migration created
+    fn discard_partial_table(
+        table_name: &str,
+        metadata: &SheetMetadata,
+        preexisting: &HashSet<String>,
+        daemon_client: &DaemonClient,
+    ) {
+        let statements = Self::discard_statements(table_name, metadata, preexisting);
+        if statements.is_empty() {
+            return;
+        }
+        if let Err(e) = daemon_client.exec_batch(statements, None) {
+            warn!("Failed to remove partially migrated table '{}': {}", table_name, e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sheets::database::writer::test_helpers::exec_fixture_sql;
+
+    #[test]
+    fn cancelled_run_keeps_tables_that_existed_before_it() {
+        let mut metadata =
+            SheetMetadata::create_generic("Items".to_string(), "Items.json".to_string(), 2, None);
+        metadata.columns[1].header = "Parts".to_string();
+        metadata.columns[1].validator = Some(ColumnValidator::Structure);
+        let conn = Connection::open_in_memory().unwrap();
+        exec_fixture_sql(&conn, "CREATE TABLE \"Items_Parts\" (row_index INTEGER);");
+
+        let preexisting = JsonMigration::existing_sheet_tables(&conn, "Items", &metadata).unwrap();
+        assert_eq!(preexisting, HashSet::from(["Items_Parts".to_string()]));
+
+        let sql: Vec<String> = JsonMigration::discard_statements("Items", &metadata, &preexisting)
+            .into_iter()
+            .map(|s| s.sql)
+            .collect();
+        assert!(sql.contains(&"DROP TABLE IF EXISTS \"Items\"".to_string()));
+        assert!(sql.iter().all(|s| !s.contains("Items_Parts")));
+    }
 }
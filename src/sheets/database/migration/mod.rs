@@ -1,5 +1,6 @@
 // src/sheets/database/migration/mod.rs
 
+pub mod csv_migration;
 pub mod dependency_handler;
 pub mod io_helpers;
 pub mod json_extractor;
@@ -10,13 +11,16 @@ pub mod parent_key_to_row_index;
 pub mod parent_key_helpers;
 pub mod cleanup_temp_new_row_index;
 pub mod hide_temp_new_row_index_in_metadata;
+pub mod project_archive;
 pub mod remove_grand_parent_columns;
 
 // Re-export main types and functions for backward compatibility
+pub use csv_migration::CsvMigration;
 pub use dependency_handler::DependencyHandler;
 pub use io_helpers::{IoHelpers, JsonSheetPair};
 pub use json_migration::{JsonMigration, MigrationReport};
 pub use occasional_fixes::OccasionalFixManager;
+pub use project_archive::{ArchiveManifest, ProjectArchive};
 
 use rusqlite::Connection;
 use std::path::Path;
@@ -35,6 +39,8 @@ impl MigrationTools {
         table_name: &str,
         display_order: Option<i32>,
         on_rows_chunk: Option<&mut dyn FnMut(usize)>,
+        cancel: Option<&std::sync::atomic::AtomicBool>,
+        db_filename: Option<&str>,
         daemon_client: &super::daemon_client::DaemonClient,
     ) -> DbResult<()> {
         JsonMigration::migrate_sheet_from_json(
@@ -44,10 +50,22 @@ impl MigrationTools {
             table_name,
             display_order,
             on_rows_chunk,
+            cancel,
+            db_filename,
             daemon_client,
         )
     }
 
+    /// Import a CSV/TSV file as a new table (delimiter sniffed, column types inferred)
+    pub fn migrate_sheet_from_csv(
+        conn: &mut Connection,
+        csv_path: &Path,
+        table_name: &str,
+        daemon_client: &super::daemon_client::DaemonClient,
+    ) -> DbResult<()> {
+        CsvMigration::migrate_sheet_from_csv(conn, csv_path, table_name, daemon_client)
+    }
+
     /// Scan folder for JSON pairs and their dependencies
     pub fn scan_json_folder(
         folder_path: &Path,
@@ -71,4 +89,34 @@ impl MigrationTools {
     ) -> DbResult<()> {
         IoHelpers::export_sheet_to_json(conn, table_name, output_folder, daemon_client)
     }
+
+    /// Export sheet from database to JSON Lines; returns the written file path
+    pub fn export_sheet_to_jsonl(
+        conn: &Connection,
+        table_name: &str,
+        output_folder: &Path,
+        daemon_client: &super::daemon_client::DaemonClient,
+    ) -> DbResult<std::path::PathBuf> {
+        IoHelpers::export_sheet_to_jsonl(conn, table_name, output_folder, daemon_client)
+    }
+
+    /// Export sheet from database to CSV; returns the written file path
+    pub fn export_sheet_to_csv(
+        conn: &Connection,
+        table_name: &str,
+        output_folder: &Path,
+        daemon_client: &super::daemon_client::DaemonClient,
+    ) -> DbResult<std::path::PathBuf> {
+        IoHelpers::export_sheet_to_csv(conn, table_name, output_folder, daemon_client)
+    }
+
+    /// Export sheet from database to Parquet; returns the written file path
+    pub fn export_sheet_to_parquet(
+        conn: &Connection,
+        table_name: &str,
+        output_folder: &Path,
+        daemon_client: &super::daemon_client::DaemonClient,
+    ) -> DbResult<std::path::PathBuf> {
+        IoHelpers::export_sheet_to_parquet(conn, table_name, output_folder, daemon_client)
+    }
 }
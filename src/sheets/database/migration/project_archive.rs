@@ -0,0 +1,834 @@
+// src/sheets/database/migration/project_archive.rs
+//! Whole-project export and import: every table of every category database, written as
+//! JSON and packed into one `.skdb.zip` archive with a `manifest.json` describing the layout.
+
+use bevy::prelude::*;
+use rusqlite::{Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::File;
+use std::path::{Component, Path, PathBuf};
+
+use super::super::connection::DbConnection;
+use super::super::daemon_client::{DaemonClient, Statement};
+use super::super::error::{DbError, DbResult};
+use super::super::reader::DbReader;
+use super::super::schema;
+use super::dependency_handler::DependencyHandler;
+use super::io_helpers::{IoHelpers, JsonSheetPair};
+use super::json_migration::MigrationReport;
+use super::MigrationTools;
+use crate::sheets::definitions::{ColumnValidator, SheetMetadata};
+use crate::sheets::events::ImportCollisionPolicy;
+
+/// Rows per daemon batch when inserting structure table rows
+const IMPORT_CHUNK_ROWS: usize = 1000;
+
+/// File name suffix of project archives
+pub const PROJECT_ARCHIVE_EXTENSION: &str = "skdb.zip";
+/// Name of the manifest at the archive root
+pub const MANIFEST_FILE_NAME: &str = "manifest.json";
+/// Bumped whenever the archive layout changes incompatibly
+pub const ARCHIVE_FORMAT_VERSION: u32 = 1;
+
+const ARCHIVE_LAYOUT: &str = "Each category database <name>.db is a folder <name>/. Every table \
+is stored as <name>/<table>.json (grid rows as arrays of strings, technical columns included) \
+and <name>/<table>.meta.json (sheet metadata). Structure tables name their parent_table and \
+parent_column; their parent_key column holds the parent row's row_index. Import categories \
+in any order and tables parents-first.";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveManifest {
+    pub format_version: u32,
+    pub created_at: String,
+    /// Human-readable description of the folder/file layout
+    pub layout: String,
+    pub categories: Vec<ArchiveCategory>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveCategory {
+    pub name: String,
+    /// Original database file name (`<name>.db`)
+    pub database_file: String,
+    pub tables: Vec<ArchiveTable>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveTable {
+    pub name: String,
+    /// `main` or `structure`
+    pub table_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_table: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_column: Option<String>,
+    pub row_count: usize,
+    /// Archive-relative paths (forward slashes)
+    pub data_file: String,
+    pub meta_file: String,
+}
+
+pub struct ProjectArchive;
+
+impl ProjectArchive {
+    /// Export every `.db` in `data_dir` into a zip archive at `archive_path`.
+    /// JSON sheets outside category databases are not included.
+    pub fn export_project(
+        data_dir: &Path,
+        archive_path: &Path,
+        daemon_client: &DaemonClient,
+    ) -> DbResult<ArchiveManifest> {
+        let staging_dir = std::env::temp_dir()
+            .join(format!("skylinedb_export_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&staging_dir)?;
+
+        let result = Self::stage_project(data_dir, &staging_dir, daemon_client).and_then(|manifest| {
+            let manifest_json = serde_json::to_string_pretty(&manifest)?;
+            std::fs::write(staging_dir.join(MANIFEST_FILE_NAME), manifest_json)?;
+            Self::zip_directory(&staging_dir, archive_path)?;
+            Ok(manifest)
+        });
+
+        if let Err(e) = std::fs::remove_dir_all(&staging_dir) {
+            warn!("Could not remove export staging dir {:?}: {}", staging_dir, e);
+        }
+        result
+    }
+
+    /// Write each category's tables into `<staging_dir>/<category>/` and describe them
+    fn stage_project(
+        data_dir: &Path,
+        staging_dir: &Path,
+        daemon_client: &DaemonClient,
+    ) -> DbResult<ArchiveManifest> {
+        let mut db_files: Vec<PathBuf> = std::fs::read_dir(data_dir)?
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|p| {
+                p.is_file()
+                    && p.extension()
+                        .map_or(false, |ext| ext.eq_ignore_ascii_case("db"))
+            })
+            .collect();
+        db_files.sort();
+
+        let mut categories = Vec::new();
+        for db_path in db_files {
+            let Some(category) = db_path.file_stem().and_then(|s| s.to_str()).map(str::to_string)
+            else {
+                continue;
+            };
+            let conn = Connection::open(&db_path)?;
+            let category_dir = staging_dir.join(&category);
+            std::fs::create_dir_all(&category_dir)?;
+
+            let mut tables = Vec::new();
+            for table_name in DbReader::list_sheets(&conn)? {
+                IoHelpers::export_sheet_to_json(&conn, &table_name, &category_dir, daemon_client)?;
+                let (table_type, parent_table, parent_column) = Self::table_lineage(&conn, &table_name)?;
+                let row_count: i64 = conn.query_row(
+                    &format!("SELECT COUNT(*) FROM \"{}\"", table_name),
+                    [],
+                    |row| row.get(0),
+                )?;
+                tables.push(ArchiveTable {
+                    data_file: format!("{}/{}.json", category, table_name),
+                    meta_file: format!("{}/{}.meta.json", category, table_name),
+                    name: table_name,
+                    table_type,
+                    parent_table,
+                    parent_column,
+                    row_count: row_count.max(0) as usize,
+                });
+            }
+            info!("Staged {} table(s) of category '{}' for project export", tables.len(), category);
+            categories.push(ArchiveCategory {
+                database_file: format!("{}.db", category),
+                name: category,
+                tables,
+            });
+        }
+
+        Ok(ArchiveManifest {
+            format_version: ARCHIVE_FORMAT_VERSION,
+            created_at: chrono::Local::now().to_rfc3339(),
+            layout: ARCHIVE_LAYOUT.to_string(),
+            categories,
+        })
+    }
+
+    /// Table type and structure parent of a table as registered in `_Metadata`
+    fn table_lineage(
+        conn: &Connection,
+        table_name: &str,
+    ) -> DbResult<(String, Option<String>, Option<String>)> {
+        let row = conn
+            .query_row(
+                "SELECT table_type, parent_table, parent_column FROM _Metadata WHERE table_name = ?",
+                [table_name],
+                |row| {
+                    Ok((
+                        row.get::<_, Option<String>>(0)?,
+                        row.get::<_, Option<String>>(1)?,
+                        row.get::<_, Option<String>>(2)?,
+                    ))
+                },
+            )
+            .optional()?;
+        let (table_type, parent_table, parent_column) = row.unwrap_or((None, None, None));
+        Ok((table_type.unwrap_or_else(|| "main".to_string()), parent_table, parent_column))
+    }
+
+    /// Deflate every file below `source_dir` into `archive_path`, paths relative to the root
+    pub fn zip_directory(source_dir: &Path, archive_path: &Path) -> DbResult<()> {
+        let mut zip = zip::ZipWriter::new(File::create(archive_path)?);
+        let options = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Deflated);
+
+        for entry in walkdir::WalkDir::new(source_dir).sort_by_file_name() {
+            let entry = entry.map_err(|e| DbError::Other(format!("Failed to walk {:?}: {}", source_dir, e)))?;
+            let relative = entry
+                .path()
+                .strip_prefix(source_dir)
+                .map_err(|e| DbError::Other(e.to_string()))?;
+            if relative.as_os_str().is_empty() {
+                continue;
+            }
+            let name = relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            if entry.file_type().is_dir() {
+                zip.add_directory(name, options)?;
+            } else {
+                zip.start_file(name, options)?;
+                std::io::copy(&mut File::open(entry.path())?, &mut zip)?;
+            }
+        }
+        zip.finish()?;
+        Ok(())
+    }
+}
+
+/// An exported table ready for import: technical columns split off, rows in original
+/// row_index order and structure cells cleared (child rows come from their own files)
+struct PreparedTable {
+    metadata: SheetMetadata,
+    grid: Vec<Vec<String>>,
+    old_row_indices: Vec<Option<String>>,
+    parent_keys: Vec<Option<String>>,
+}
+
+impl ProjectArchive {
+    /// Read `manifest.json` straight from an archive without extracting it
+    pub fn read_manifest(archive_path: &Path) -> DbResult<ArchiveManifest> {
+        let mut archive = zip::ZipArchive::new(File::open(archive_path)?)?;
+        let manifest: ArchiveManifest = serde_json::from_reader(archive.by_name(MANIFEST_FILE_NAME)?)?;
+        if manifest.format_version > ARCHIVE_FORMAT_VERSION {
+            return Err(DbError::Other(format!(
+                "Archive format {} is newer than this version supports ({})",
+                manifest.format_version, ARCHIVE_FORMAT_VERSION
+            )));
+        }
+        validate_manifest_paths(&manifest)?;
+        Ok(manifest)
+    }
+
+    /// Top-level tables of the archive that already exist under `data_dir`, as "category/table"
+    pub fn find_collisions(manifest: &ArchiveManifest, data_dir: &Path) -> Vec<String> {
+        let mut collisions = Vec::new();
+        for category in &manifest.categories {
+            let db_path = data_dir.join(category_db_file(category));
+            if !db_path.is_file() {
+                continue;
+            }
+            let Ok(conn) = Connection::open(&db_path) else {
+                continue;
+            };
+            for table in category.tables.iter().filter(|t| t.parent_table.is_none()) {
+                if schema::queries::table_exists(&conn, &table.name).unwrap_or(false) {
+                    collisions.push(format!("{}/{}", category.name, table.name));
+                }
+            }
+        }
+        collisions
+    }
+
+    /// Import an archive written by `export_project` into the category databases under
+    /// `data_dir` (created when missing). Tables are migrated parents-first; structure rows
+    /// get fresh row_index values and their parent_key is remapped to the imported parent.
+    pub fn import_project(
+        archive_path: &Path,
+        data_dir: &Path,
+        policy: ImportCollisionPolicy,
+        daemon_client: &DaemonClient,
+    ) -> DbResult<MigrationReport> {
+        let manifest = Self::read_manifest(archive_path)?;
+        let staging_dir = std::env::temp_dir()
+            .join(format!("skylinedb_import_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&staging_dir)?;
+
+        let result = (|| -> DbResult<MigrationReport> {
+            zip::ZipArchive::new(File::open(archive_path)?)?.extract(&staging_dir)?;
+            let mut report = MigrationReport::default();
+            for category in &manifest.categories {
+                Self::import_category(&staging_dir, data_dir, category, policy, daemon_client, &mut report)?;
+            }
+            Ok(report)
+        })();
+
+        if let Err(e) = std::fs::remove_dir_all(&staging_dir) {
+            warn!("Could not remove import staging dir {:?}: {}", staging_dir, e);
+        }
+        result
+    }
+
+    fn import_category(
+        staging_dir: &Path,
+        data_dir: &Path,
+        category: &ArchiveCategory,
+        policy: ImportCollisionPolicy,
+        daemon_client: &DaemonClient,
+        report: &mut MigrationReport,
+    ) -> DbResult<()> {
+        let category_dir = staging_dir.join(&category.name);
+        let db_file = category_db_file(category);
+        let db_path = data_dir.join(&db_file);
+        let db_filename = db_file.as_str();
+        let mut conn = if db_path.exists() {
+            Connection::open(&db_path)?
+        } else {
+            DbConnection::create_new(&db_path, daemon_client)?
+        };
+
+        // Linked-sheet dependencies come from the metadata files; a structure table also
+        // depends on its parent so parents are always migrated first
+        let scanned = IoHelpers::scan_json_folder(&category_dir).unwrap_or_default();
+        let mut pairs: HashMap<String, JsonSheetPair> = HashMap::new();
+        for table in &category.tables {
+            let data_path = category_dir.join(format!("{}.json", table.name));
+            let meta_path = category_dir.join(format!("{}.meta.json", table.name));
+            if !data_path.is_file() || !meta_path.is_file() {
+                report.skipped_files.push(table.data_file.clone());
+                continue;
+            }
+            let mut dependencies = scanned
+                .get(&table.name)
+                .map(|p| p.dependencies.clone())
+                .unwrap_or_default();
+            dependencies.extend(table.parent_table.clone());
+            pairs.insert(table.name.clone(), JsonSheetPair { data_path, meta_path, dependencies });
+        }
+        Self::collect_unlisted_files(&category_dir, category, report);
+
+        let mut target_names: HashMap<String, String> = HashMap::new();
+        let mut row_maps: HashMap<String, HashMap<String, usize>> = HashMap::new();
+        let ordered = DependencyHandler::order_sheets_by_dependency(&pairs);
+        for (display_order, source_name) in ordered.iter().enumerate() {
+            let (Some(pair), Some(table)) = (
+                pairs.get(source_name),
+                category.tables.iter().find(|t| &t.name == source_name),
+            ) else {
+                continue;
+            };
+            let outcome = match &table.parent_table {
+                None => Self::resolve_root_name(&conn, source_name, policy, db_filename, daemon_client)
+                    .and_then(|target| {
+                        Self::import_root_table(
+                            &mut conn,
+                            pair,
+                            &target,
+                            &category.name,
+                            display_order,
+                            staging_dir,
+                            db_filename,
+                            daemon_client,
+                        )
+                        .map(|(rows, row_map)| (target, rows, row_map))
+                    }),
+                Some(parent) => match (target_names.get(parent), row_maps.get(parent)) {
+                    (Some(target_parent), Some(parent_rows)) => {
+                        let target = format!(
+                            "{}_{}",
+                            target_parent,
+                            table.parent_column.as_deref().unwrap_or_default()
+                        );
+                        Self::import_structure_table(
+                            &conn,
+                            pair,
+                            &target,
+                            &category.name,
+                            parent_rows,
+                            db_filename,
+                            daemon_client,
+                        )
+                        .map(|(rows, row_map)| (target, rows, row_map))
+                    }
+                    _ => Err(DbError::Other(format!("parent table '{}' was not imported", parent))),
+                },
+            };
+            match outcome {
+                Ok((target, rows, row_map)) => {
+                    info!("Imported '{}/{}' as '{}' ({} rows)", category.name, source_name, target, rows);
+                    report.sheets_migrated += 1;
+                    report.rows_migrated += rows;
+                    target_names.insert(source_name.clone(), target);
+                    row_maps.insert(source_name.clone(), row_map);
+                }
+                Err(e) => {
+                    warn!("Failed to import '{}/{}': {}", category.name, source_name, e);
+                    report.sheets_failed += 1;
+                    report
+                        .failed_sheets
+                        .push((format!("{}/{}", category.name, source_name), e.to_string()));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Files in a category folder that the manifest does not list are reported, not imported
+    fn collect_unlisted_files(category_dir: &Path, category: &ArchiveCategory, report: &mut MigrationReport) {
+        let Ok(entries) = std::fs::read_dir(category_dir) else {
+            return;
+        };
+        let mut unlisted: Vec<String> = entries
+            .filter_map(Result::ok)
+            .filter_map(|entry| entry.file_name().to_str().map(|n| format!("{}/{}", category.name, n)))
+            .filter(|path| {
+                !category
+                    .tables
+                    .iter()
+                    .any(|t| &t.data_file == path || &t.meta_file == path)
+            })
+            .collect();
+        unlisted.sort();
+        report.skipped_files.extend(unlisted);
+    }
+
+    /// Name for an imported top-level table, applying `policy` when it already exists
+    fn resolve_root_name(
+        conn: &Connection,
+        name: &str,
+        policy: ImportCollisionPolicy,
+        db_filename: &str,
+        daemon_client: &DaemonClient,
+    ) -> DbResult<String> {
+        if !schema::queries::table_exists(conn, name)? {
+            return Ok(name.to_string());
+        }
+        match policy {
+            ImportCollisionPolicy::Overwrite => {
+                Self::drop_table_tree(conn, name, db_filename, daemon_client)?;
+                Ok(name.to_string())
+            }
+            ImportCollisionPolicy::Suffix => Ok(suffixed_table_name(name, |candidate| {
+                schema::queries::table_exists(conn, candidate).unwrap_or(true)
+            })),
+        }
+    }
+
+    /// Drop a table, its structure descendants and their metadata/AI group tables
+    fn drop_table_tree(
+        conn: &Connection,
+        root: &str,
+        db_filename: &str,
+        daemon_client: &DaemonClient,
+    ) -> DbResult<()> {
+        let mut tables = vec![root.to_string()];
+        let mut next = 0;
+        while next < tables.len() {
+            let mut stmt = conn.prepare("SELECT table_name FROM _Metadata WHERE parent_table = ?")?;
+            let children = stmt
+                .query_map([&tables[next]], |row| row.get::<_, String>(0))?
+                .collect::<Result<Vec<_>, _>>()?;
+            tables.extend(children);
+            next += 1;
+        }
+
+        let mut statements = Vec::new();
+        for table in tables.iter().rev() {
+            for name in [
+                table.clone(),
+                format!("{}_Metadata", table),
+                format!("{}_AIGroups", table),
+            ] {
+                statements.push(Statement {
+                    sql: format!("DROP TABLE IF EXISTS \"{}\"", name),
+                    params: vec![],
+                });
+            }
+            statements.push(Statement {
+                sql: "DELETE FROM _Metadata WHERE table_name = ?".to_string(),
+                params: vec![serde_json::Value::String(table.clone())],
+            });
+        }
+        daemon_client
+            .exec_batch(statements, Some(db_filename))
+            .map_err(|e| DbError::Other(format!("Failed to drop '{}' before import: {}", root, e)))?;
+        info!("Dropped {} existing table(s) under '{}' for import", tables.len(), root);
+        Ok(())
+    }
+
+    fn load_for_import(pair: &JsonSheetPair, target: &str, category_name: &str) -> DbResult<PreparedTable> {
+        let mut metadata = IoHelpers::load_metadata(&pair.meta_path)?;
+        let mut grid = IoHelpers::load_grid_data(&pair.data_path)?;
+        let (old_row_indices, parent_keys) = split_technical_columns(&mut metadata, &mut grid);
+
+        // Exports list rows newest first; import oldest first so row_index order is kept
+        let mut rows: Vec<_> = grid
+            .into_iter()
+            .zip(old_row_indices)
+            .zip(parent_keys)
+            .collect();
+        rows.sort_by_key(|((_, old), _)| {
+            old.as_deref()
+                .and_then(|v| v.trim().parse::<i64>().ok())
+                .unwrap_or(i64::MAX)
+        });
+        let mut prepared = PreparedTable {
+            metadata,
+            grid: Vec::with_capacity(rows.len()),
+            old_row_indices: Vec::with_capacity(rows.len()),
+            parent_keys: Vec::with_capacity(rows.len()),
+        };
+        for ((row, old), key) in rows {
+            prepared.grid.push(row);
+            prepared.old_row_indices.push(old);
+            prepared.parent_keys.push(key);
+        }
+
+        for (idx, col) in prepared.metadata.columns.iter().enumerate() {
+            if matches!(col.validator, Some(ColumnValidator::Structure)) {
+                for row in prepared.grid.iter_mut() {
+                    if let Some(cell) = row.get_mut(idx) {
+                        cell.clear();
+                    }
+                }
+            }
+        }
+        prepared.metadata.sheet_name = target.to_string();
+        prepared.metadata.data_filename = format!("{}.json", target);
+        prepared.metadata.category = Some(category_name.to_string());
+        Ok(prepared)
+    }
+
+    /// Top-level tables go through the regular JSON migration into the category's
+    /// database, which also creates the (empty) tables of their Structure columns
+    fn import_root_table(
+        conn: &mut Connection,
+        pair: &JsonSheetPair,
+        target: &str,
+        category_name: &str,
+        display_order: usize,
+        staging_dir: &Path,
+        db_filename: &str,
+        daemon_client: &DaemonClient,
+    ) -> DbResult<(usize, HashMap<String, usize>)> {
+        let prepared = Self::load_for_import(pair, target, category_name)?;
+        let prepared_dir = staging_dir.join("_prepared");
+        std::fs::create_dir_all(&prepared_dir)?;
+        let data_path = prepared_dir.join(format!("{}.json", target));
+        let meta_path = prepared_dir.join(format!("{}.meta.json", target));
+        std::fs::write(&data_path, serde_json::to_string(&prepared.grid)?)?;
+        std::fs::write(&meta_path, serde_json::to_string(&prepared.metadata)?)?;
+
+        MigrationTools::migrate_sheet_from_json(
+            conn,
+            &data_path,
+            &meta_path,
+            target,
+            Some(display_order as i32),
+            None,
+            None,
+            Some(db_filename),
+            daemon_client,
+        )?;
+        // The migration assigns row_index by position
+        Ok((prepared.grid.len(), row_index_map(&prepared.old_row_indices)))
+    }
+
+    /// Structure tables already exist (created with their parent); write their column
+    /// metadata and rows, pointing parent_key at the parent's new row_index
+    fn import_structure_table(
+        conn: &Connection,
+        pair: &JsonSheetPair,
+        target: &str,
+        category_name: &str,
+        parent_rows: &HashMap<String, usize>,
+        db_filename: &str,
+        daemon_client: &DaemonClient,
+    ) -> DbResult<(usize, HashMap<String, usize>)> {
+        let prepared = Self::load_for_import(pair, target, category_name)?;
+        if !schema::queries::table_exists(conn, target)? {
+            return Err(DbError::TableNotFound(target.to_string()));
+        }
+        schema::create_metadata_table(target, &prepared.metadata, daemon_client, Some(db_filename))?;
+
+        let data_columns: Vec<(usize, &str)> = prepared
+            .metadata
+            .columns
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| !matches!(c.validator, Some(ColumnValidator::Structure)))
+            .map(|(idx, c)| (idx, c.header.as_str()))
+            .collect();
+        let insert_sql = format!(
+            "INSERT INTO \"{}\" (row_index, parent_key{}) VALUES (?, ?{})",
+            target,
+            data_columns
+                .iter()
+                .map(|(_, header)| format!(", \"{}\"", header))
+                .collect::<String>(),
+            ", ?".repeat(data_columns.len())
+        );
+
+        let mut row_map = HashMap::new();
+        let mut batch = Vec::new();
+        let mut imported = 0usize;
+        let mut orphans = 0usize;
+        for ((row, old_index), parent_key) in prepared
+            .grid
+            .iter()
+            .zip(&prepared.old_row_indices)
+            .zip(&prepared.parent_keys)
+        {
+            let Some(new_parent) = parent_key.as_deref().and_then(|k| parent_rows.get(k.trim())) else {
+                orphans += 1;
+                continue;
+            };
+            if let Some(old) = old_index {
+                row_map.insert(old.trim().to_string(), imported);
+            }
+            let mut params = vec![
+                serde_json::Value::Number(imported.into()),
+                serde_json::Value::String(new_parent.to_string()),
+            ];
+            params.extend(
+                data_columns
+                    .iter()
+                    .map(|(idx, _)| serde_json::Value::String(row.get(*idx).cloned().unwrap_or_default())),
+            );
+            batch.push(Statement { sql: insert_sql.clone(), params });
+            imported += 1;
+            if batch.len() >= IMPORT_CHUNK_ROWS {
+                daemon_client
+                    .exec_batch(std::mem::take(&mut batch), Some(db_filename))
+                    .map_err(DbError::Other)?;
+            }
+        }
+        if !batch.is_empty() {
+            daemon_client.exec_batch(batch, Some(db_filename)).map_err(DbError::Other)?;
+        }
+        if orphans > 0 {
+            warn!("Skipped {} row(s) of '{}' whose parent row was not imported", orphans, target);
+        }
+
+        // Deeper levels: create this table's own structure tables before they are imported
+        for col in &prepared.metadata.columns {
+            if matches!(col.validator, Some(ColumnValidator::Structure)) && col.structure_schema.is_some() {
+                schema::create_structure_table(conn, target, col, None, daemon_client, Some(db_filename))?;
+            }
+        }
+        Ok((imported, row_map))
+    }
+}
+
+/// Database file of a category, derived from its (validated) name
+fn category_db_file(category: &ArchiveCategory) -> String {
+    format!("{}.db", category.name)
+}
+
+/// True when `name` is a single ordinary path component (no separators, `..`, roots or drives)
+fn is_plain_file_name(name: &str) -> bool {
+    if name.is_empty() || name.contains(['/', '\\']) {
+        return false;
+    }
+    let mut components = Path::new(name).components();
+    matches!(
+        (components.next(), components.next()),
+        (Some(Component::Normal(c)), None) if c == std::ffi::OsStr::new(name)
+    )
+}
+
+/// Manifest names end up in paths below the data folder; refuse archives whose category or
+/// table names could point elsewhere, or whose file names do not follow the export layout
+fn validate_manifest_paths(manifest: &ArchiveManifest) -> DbResult<()> {
+    for category in &manifest.categories {
+        if !is_plain_file_name(&category.name) {
+            return Err(DbError::Other(format!(
+                "Archive category name '{}' is not a plain file name",
+                category.name
+            )));
+        }
+        if category.database_file != category_db_file(category) {
+            return Err(DbError::Other(format!(
+                "Archive category '{}' names database file '{}' instead of '{}'",
+                category.name,
+                category.database_file,
+                category_db_file(category)
+            )));
+        }
+        for table in &category.tables {
+            if !is_plain_file_name(&table.name) {
+                return Err(DbError::Other(format!(
+                    "Archive table name '{}/{}' is not a plain file name",
+                    category.name, table.name
+                )));
+            }
+            let data_file = format!("{}/{}.json", category.name, table.name);
+            let meta_file = format!("{}/{}.meta.json", category.name, table.name);
+            if table.data_file != data_file || table.meta_file != meta_file {
+                return Err(DbError::Other(format!(
+                    "Archive table '{}/{}' lists files '{}' and '{}' instead of '{}' and '{}'",
+                    category.name, table.name, table.data_file, table.meta_file, data_file, meta_file
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Remove the `row_index` / `parent_key` columns of an exported table, returning their
+/// per-row values (None when the table has no such column)
+fn split_technical_columns(
+    metadata: &mut SheetMetadata,
+    grid: &mut [Vec<String>],
+) -> (Vec<Option<String>>, Vec<Option<String>>) {
+    let position = |name: &str| {
+        metadata
+            .columns
+            .iter()
+            .position(|c| c.header.eq_ignore_ascii_case(name))
+    };
+    let row_index_col = position("row_index");
+    let parent_key_col = position("parent_key");
+    let values_of = |col: Option<usize>| -> Vec<Option<String>> {
+        grid.iter()
+            .map(|row| col.and_then(|c| row.get(c).cloned()))
+            .collect()
+    };
+    let old_row_indices = values_of(row_index_col);
+    let parent_keys = values_of(parent_key_col);
+
+    let mut technical: Vec<usize> = row_index_col.into_iter().chain(parent_key_col).collect();
+    technical.sort_unstable_by(|a, b| b.cmp(a));
+    for idx in technical {
+        metadata.columns.remove(idx);
+        for row in grid.iter_mut() {
+            if idx < row.len() {
+                row.remove(idx);
+            }
+        }
+    }
+    (old_row_indices, parent_keys)
+}
+
+/// Old row_index (as text) -> new row_index, where rows are imported by position
+fn row_index_map(old_row_indices: &[Option<String>]) -> HashMap<String, usize> {
+    old_row_indices
+        .iter()
+        .enumerate()
+        .filter_map(|(pos, old)| old.as_ref().map(|o| (o.trim().to_string(), pos)))
+        .collect()
+}
+
+/// "{name}_imported", then "{name}_imported2", ... skipping names for which `is_taken` is true
+fn suffixed_table_name(name: &str, is_taken: impl Fn(&str) -> bool) -> String {
+    let base = format!("{}_imported", name);
+    if !is_taken(&base) {
+        return base;
+    }
+    (2..)
+        .map(|n| format!("{}{}", base, n))
+        .find(|candidate| !is_taken(candidate))
+        .unwrap_or(base)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zip_directory_keeps_category_folders() {
+        let root = std::env::temp_dir().join(format!("skylinedb_zip_test_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(root.join("Game")).unwrap();
+        std::fs::write(root.join(MANIFEST_FILE_NAME), "{}").unwrap();
+        std::fs::write(root.join("Game").join("Items.json"), "[]").unwrap();
+        let archive_path = root.with_extension(PROJECT_ARCHIVE_EXTENSION);
+
+        ProjectArchive::zip_directory(&root, &archive_path).unwrap();
+        let archive = zip::ZipArchive::new(File::open(&archive_path).unwrap()).unwrap();
+        let mut names: Vec<&str> = archive.file_names().collect();
+        names.sort();
+        assert_eq!(names, vec!["Game/", "Game/Items.json", MANIFEST_FILE_NAME]);
+
+        std::fs::remove_dir_all(&root).unwrap();
+        std::fs::remove_file(&archive_path).unwrap();
+    }
+
+    #[test]
+    fn technical_columns_are_split_off_for_import() {
+        let mut metadata = SheetMetadata::create_generic("T".into(), "T.json".into(), 3, None);
+        metadata.columns[0].header = "row_index".into();
+        metadata.columns[1].header = "parent_key".into();
+        metadata.columns[2].header = "Name".into();
+        let mut grid = vec![
+            vec!["7".to_string(), "2".to_string(), "Bolt".to_string()],
+            vec!["3".to_string(), "2".to_string(), "Nut".to_string()],
+        ];
+        let (old, keys) = split_technical_columns(&mut metadata, &mut grid);
+        assert_eq!(metadata.get_headers(), vec!["Name".to_string()]);
+        assert_eq!(grid, vec![vec!["Bolt".to_string()], vec!["Nut".to_string()]]);
+        assert_eq!(old, vec![Some("7".to_string()), Some("3".to_string())]);
+        assert_eq!(keys, vec![Some("2".to_string()), Some("2".to_string())]);
+
+        let map = row_index_map(&old);
+        assert_eq!(map.get("7"), Some(&0));
+        assert_eq!(map.get("3"), Some(&1));
+    }
+
+    #[test]
+    fn manifest_paths_must_stay_inside_the_layout() {
+        let table = |category: &str, name: &str| ArchiveTable {
+            name: name.to_string(),
+            table_type: "main".to_string(),
+            parent_table: None,
+            parent_column: None,
+            row_count: 0,
+            data_file: format!("{}/{}.json", category, name),
+            meta_file: format!("{}/{}.meta.json", category, name),
+        };
+        let manifest = |name: &str, database_file: &str, tables: Vec<ArchiveTable>| ArchiveManifest {
+            format_version: ARCHIVE_FORMAT_VERSION,
+            created_at: String::new(),
+            layout: String::new(),
+            categories: vec![ArchiveCategory {
+                name: name.to_string(),
+                database_file: database_file.to_string(),
+                tables,
+            }],
+        };
+
+        assert!(validate_manifest_paths(&manifest("Game", "Game.db", vec![table("Game", "Items")])).is_ok());
+        for bad_name in ["..", "../Game", "/tmp/Game", "a/b", "a\\b", ""] {
+            assert!(validate_manifest_paths(&manifest(bad_name, &format!("{}.db", bad_name), vec![])).is_err());
+            assert!(validate_manifest_paths(&manifest("Game", "Game.db", vec![table("Game", bad_name)])).is_err());
+        }
+        assert!(validate_manifest_paths(&manifest("Game", "../Game.db", vec![])).is_err());
+        assert!(validate_manifest_paths(&manifest("Game", "Other.db", vec![])).is_err());
+
+        let mut escaping = table("Game", "Items");
+        escaping.data_file = "../Items.json".to_string();
+        assert!(validate_manifest_paths(&manifest("Game", "Game.db", vec![escaping])).is_err());
+    }
+
+    #[test]
+    fn suffixed_name_skips_taken_names() {
+        assert_eq!(suffixed_table_name("Items", |_| false), "Items_imported");
+        let taken = ["Items_imported"];
+        assert_eq!(suffixed_table_name("Items", |n| taken.contains(&n)), "Items_imported2");
+    }
+}
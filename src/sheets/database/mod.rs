@@ -8,16 +8,21 @@ pub mod daemon_manager;
 pub mod daemon_protocol;
 pub mod daemon_resource;
 pub mod error;
+pub mod maintenance;
 pub mod migration;
 pub mod reader;
+pub mod readonly_query;
 pub mod schema;
 pub mod systems;
 pub mod writer;
 pub mod validation;
 pub use migration::MigrationTools;
+pub use readonly_query::query_readonly;
 pub use systems::{
-    handle_export_requests, handle_migration_completion, handle_migration_requests,
-    handle_upload_json_to_current_db, 
+    handle_compact_database_requests, handle_validate_structure_links_requests, handle_export_requests, handle_jsonl_export_requests,
+    handle_csv_export_requests, handle_parquet_export_requests,
+    handle_migration_completion, handle_migration_requests,
+    handle_project_export_requests, handle_project_import_requests, handle_upload_json_to_current_db, 
 };
 use rusqlite::OptionalExtension;
 
@@ -30,11 +35,9 @@ pub struct DbConfig {
 }
 
 impl DbConfig {
+    /// Data folder chosen in Settings, else Documents/SkylineDB
     pub fn default_path() -> PathBuf {
-        let documents = directories_next::UserDirs::new()
-            .and_then(|dirs| dirs.document_dir().map(|p| p.to_path_buf()))
-            .unwrap_or_else(|| PathBuf::from("."));
-        documents.join("SkylineDB")
+        crate::sheets::systems::io::get_default_data_base_path()
     }
 
     pub fn new() -> Self {
@@ -110,6 +113,186 @@ pub fn persist_column_metadata(
     }
 }
 
+/// Persist a column's UI-only display name (the physical column name is left untouched)
+pub fn persist_column_display_name(
+    category: &str,
+    table_name: &str,
+    column_index: usize,
+    display_name: &str,
+    daemon_client: &daemon_client::DaemonClient,
+) -> Result<(), String> {
+    let db_filename = format!("{}.db", category);
+    match open_or_create_db_for_category(category) {
+        Ok(conn) => crate::sheets::database::writer::DbWriter::update_column_display_name(
+            &conn,
+            table_name,
+            column_index,
+            display_name,
+            Some(&db_filename),
+            daemon_client,
+        )
+        .map_err(|e| e.to_string()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Persist the display-only "trim trailing zeros" flag for a numeric column
+pub fn persist_column_display_trim_zeros(
+    category: &str,
+    table_name: &str,
+    column_index: usize,
+    trim_zeros: bool,
+    daemon_client: &daemon_client::DaemonClient,
+) -> Result<(), String> {
+    let db_filename = format!("{}.db", category);
+    match open_or_create_db_for_category(category) {
+        Ok(conn) => crate::sheets::database::writer::DbWriter::update_column_display_trim_zeros(
+            &conn,
+            table_name,
+            column_index,
+            trim_zeros,
+            Some(&db_filename),
+            daemon_client,
+        )
+        .map_err(|e| e.to_string()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Persist the "AI read-only" flag of a column
+pub fn persist_column_ai_read_only(
+    category: &str,
+    table_name: &str,
+    column_index: usize,
+    read_only: bool,
+    daemon_client: &daemon_client::DaemonClient,
+) -> Result<(), String> {
+    let db_filename = format!("{}.db", category);
+    match open_or_create_db_for_category(category) {
+        Ok(conn) => crate::sheets::database::writer::DbWriter::update_column_ai_read_only(
+            &conn,
+            table_name,
+            column_index,
+            read_only,
+            Some(&db_filename),
+            daemon_client,
+        )
+        .map_err(|e| e.to_string()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Persist the "long text" (multi-line) flag of a column
+pub fn persist_column_multiline(
+    category: &str,
+    table_name: &str,
+    column_index: usize,
+    multiline: bool,
+    daemon_client: &daemon_client::DaemonClient,
+) -> Result<(), String> {
+    let db_filename = format!("{}.db", category);
+    match open_or_create_db_for_category(category) {
+        Ok(conn) => crate::sheets::database::writer::DbWriter::update_column_multiline(
+            &conn,
+            table_name,
+            column_index,
+            multiline,
+            Some(&db_filename),
+            daemon_client,
+        )
+        .map_err(|e| e.to_string()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Persist whether an Enum column adds unknown values to its allowed list
+pub fn persist_column_enum_auto_add(
+    category: &str,
+    table_name: &str,
+    column_index: usize,
+    auto_add: bool,
+    daemon_client: &daemon_client::DaemonClient,
+) -> Result<(), String> {
+    let db_filename = format!("{}.db", category);
+    match open_or_create_db_for_category(category) {
+        Ok(conn) => crate::sheets::database::writer::DbWriter::update_column_enum_auto_add(
+            &conn,
+            table_name,
+            column_index,
+            auto_add,
+            Some(&db_filename),
+            daemon_client,
+        )
+        .map_err(|e| e.to_string()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Persist the numeric (min, max) bounds of a column (None clears them)
+pub fn persist_column_numeric_range(
+    category: &str,
+    table_name: &str,
+    column_index: usize,
+    range: Option<(Option<f64>, Option<f64>)>,
+    daemon_client: &daemon_client::DaemonClient,
+) -> Result<(), String> {
+    let db_filename = format!("{}.db", category);
+    match open_or_create_db_for_category(category) {
+        Ok(conn) => crate::sheets::database::writer::DbWriter::update_column_numeric_range(
+            &conn,
+            table_name,
+            column_index,
+            range,
+            Some(&db_filename),
+            daemon_client,
+        )
+        .map_err(|e| e.to_string()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Persist the value pre-filled into new rows for a column (None clears it)
+pub fn persist_column_default_value(
+    category: &str,
+    table_name: &str,
+    column_index: usize,
+    default_value: Option<&str>,
+    daemon_client: &daemon_client::DaemonClient,
+) -> Result<(), String> {
+    let db_filename = format!("{}.db", category);
+    match open_or_create_db_for_category(category) {
+        Ok(conn) => crate::sheets::database::writer::DbWriter::update_column_default_value(
+            &conn,
+            table_name,
+            column_index,
+            default_value,
+            Some(&db_filename),
+            daemon_client,
+        )
+        .map_err(|e| e.to_string()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Persist a column's UI width by column name (None restores the default width)
+pub fn persist_column_width(
+    category: &str,
+    table_name: &str,
+    column_name: &str,
+    width: Option<f32>,
+    daemon_client: &daemon_client::DaemonClient,
+) -> Result<(), String> {
+    let db_filename = format!("{}.db", category);
+    crate::sheets::database::writer::DbWriter::update_column_width(
+        table_name,
+        column_name,
+        width,
+        Some(&db_filename),
+        daemon_client,
+    )
+    .map_err(|e| e.to_string())
+}
+
 /// Persist validator/data_type change by column name (safe when caller index may refer to UI including technical columns)
 pub fn persist_column_validator_by_name(
     category: &str,
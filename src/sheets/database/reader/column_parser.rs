@@ -43,6 +43,28 @@ pub fn parse_metadata_columns(
                 }
             }
             Some("Structure") => Some(ColumnValidator::Structure),
+            Some("Unique") => {
+                // Config is optional; a missing or unreadable one means case-insensitive
+                let case_sensitive = row
+                    .validator_config
+                    .as_deref()
+                    .and_then(|cfg| serde_json::from_str::<serde_json::Value>(cfg).ok())
+                    .and_then(|cfg| cfg["case_sensitive"].as_bool())
+                    .unwrap_or(false);
+                Some(ColumnValidator::Unique {
+                    data_type,
+                    case_sensitive,
+                })
+            }
+            Some("Enum") => {
+                // Allowed values are stored as a JSON list; an unreadable list allows nothing
+                let values = row
+                    .validator_config
+                    .as_deref()
+                    .and_then(|cfg| serde_json::from_str::<Vec<String>>(cfg).ok())
+                    .unwrap_or_default();
+                Some(ColumnValidator::Enum(values))
+            }
             _ => None,
         };
 
@@ -62,13 +84,26 @@ pub fn parse_metadata_columns(
             ai_context: row.ai_context,
             ai_enable_row_generation: row.ai_enable_row_generation.map(|v| v != 0),
             ai_include_in_send: row.ai_include_in_send.map(|v| v != 0),
-            width: None,
+            // Unusable stored widths fall back to the type default
+            width: row
+                .column_width
+                .map(|w| w as f32)
+                .filter(|w| w.is_finite() && *w > 0.0),
             structure_schema: None,
             structure_column_order: None,
             structure_key_parent_column_index: None,
             structure_ancestor_key_parent_column_indices: None,
             deleted: false,
-            hidden: false,
+            hidden: row.hidden.unwrap_or(0) != 0,
+            trim_numeric_zeros: row.display_trim_zeros.unwrap_or(0) != 0,
+            ai_read_only: row.ai_read_only.unwrap_or(0) != 0,
+            multiline: row.multiline.unwrap_or(0) != 0,
+            enum_auto_add: row.enum_auto_add.unwrap_or(0) != 0,
+            numeric_range: row
+                .numeric_range
+                .as_deref()
+                .and_then(|json| serde_json::from_str(json).ok()),
+            default_value: row.default_value,
         });
     }
 
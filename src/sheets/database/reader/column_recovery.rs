@@ -120,6 +120,12 @@ pub fn recover_orphaned_columns(
                     structure_ancestor_key_parent_column_indices: None,
                     deleted: false,
                     hidden: false,
+                    trim_numeric_zeros: false,
+                    ai_read_only: false,
+                    multiline: false,
+                    enum_auto_add: false,
+                    numeric_range: None,
+                    default_value: None,
                 });
             }
             Err(e) => {
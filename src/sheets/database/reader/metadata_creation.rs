@@ -42,6 +42,12 @@ pub fn create_metadata_from_physical_table(
             structure_ancestor_key_parent_column_indices: None,
             deleted: false,
             hidden: false,
+            trim_numeric_zeros: false,
+            ai_read_only: false,
+            multiline: false,
+            enum_auto_add: false,
+            numeric_range: None,
+            default_value: None,
         });
     }
 
@@ -61,6 +67,8 @@ pub fn create_metadata_from_physical_table(
         random_picker: None,
         structure_parent: None,
         hidden: false,
+        ai_key_column: None,
+        color: None,
     };
 
     create_metadata_table(table_name, &sheet_meta, daemon_client, db_name)?;
@@ -12,7 +12,8 @@ use super::schema::{
 use crate::sheets::definitions::{
     ColumnDataType, ColumnDefinition, ColumnValidator, SheetGridData, SheetMetadata,
 };
-use rusqlite::Connection;
+use rusqlite::{Connection, OptionalExtension};
+use std::collections::{HashMap, HashSet};
 
 pub struct DbReader;
 
@@ -47,6 +48,30 @@ impl DbReader {
             if let Err(e) = queries::add_column_if_missing(daemon_client, &meta_table, "display_name", "TEXT", "NULL", db_name) {
                 bevy::log::debug!("Could not add 'display_name' column to '{}': {}. Continuing anyway.", meta_table, e);
             }
+            if let Err(e) = queries::add_column_if_missing(daemon_client, &meta_table, "display_trim_zeros", "INTEGER", "0", db_name) {
+                bevy::log::debug!("Could not add 'display_trim_zeros' column to '{}': {}. Continuing anyway.", meta_table, e);
+            }
+            if let Err(e) = queries::add_column_if_missing(daemon_client, &meta_table, "column_width", "REAL", "NULL", db_name) {
+                bevy::log::debug!("Could not add 'column_width' column to '{}': {}. Continuing anyway.", meta_table, e);
+            }
+            if let Err(e) = queries::add_column_if_missing(daemon_client, &meta_table, "ai_read_only", "INTEGER", "0", db_name) {
+                bevy::log::debug!("Could not add 'ai_read_only' column to '{}': {}. Continuing anyway.", meta_table, e);
+            }
+            if let Err(e) = queries::add_column_if_missing(daemon_client, &meta_table, "numeric_range", "TEXT", "NULL", db_name) {
+                bevy::log::debug!("Could not add 'numeric_range' column to '{}': {}. Continuing anyway.", meta_table, e);
+            }
+            if let Err(e) = queries::add_column_if_missing(daemon_client, &meta_table, "default_value", "TEXT", "NULL", db_name) {
+                bevy::log::debug!("Could not add 'default_value' column to '{}': {}. Continuing anyway.", meta_table, e);
+            }
+            if let Err(e) = queries::add_column_if_missing(daemon_client, &meta_table, "hidden", "INTEGER", "0", db_name) {
+                bevy::log::debug!("Could not add 'hidden' column to '{}': {}. Continuing anyway.", meta_table, e);
+            }
+            if let Err(e) = queries::add_column_if_missing(daemon_client, &meta_table, "multiline", "INTEGER", "0", db_name) {
+                bevy::log::debug!("Could not add 'multiline' column to '{}': {}. Continuing anyway.", meta_table, e);
+            }
+            if let Err(e) = queries::add_column_if_missing(daemon_client, &meta_table, "enum_auto_add", "INTEGER", "0", db_name) {
+                bevy::log::debug!("Could not add 'enum_auto_add' column to '{}': {}. Continuing anyway.", meta_table, e);
+            }
         }
 
         let table_type = super::schema::queries::get_table_type(conn, table_name)?;
@@ -166,6 +191,59 @@ impl DbReader {
         queries::list_all_tables(conn)
     }
 
+    /// Read the database's category color from `_DatabaseSettings` (absent in databases
+    /// that never had one).
+    pub fn read_database_color(conn: &Connection) -> DbResult<Option<[u8; 3]>> {
+        if !super::schema::queries::table_exists(conn, super::schema::DATABASE_SETTINGS_TABLE)? {
+            return Ok(None);
+        }
+        let value: Option<String> = conn
+            .query_row(
+                &format!(
+                    "SELECT value FROM {} WHERE key = 'color'",
+                    super::schema::DATABASE_SETTINGS_TABLE
+                ),
+                [],
+                |r| r.get(0),
+            )
+            .optional()?;
+        Ok(value.as_deref().and_then(super::schema::parse_hex_color))
+    }
+
+    /// Read the cell comments of a table keyed by (row_index, column_name).
+    /// Tables that never had a comment have no `{table}_Comments` table and yield an empty map.
+    pub fn read_cell_comments(
+        conn: &Connection,
+        table_name: &str,
+    ) -> DbResult<HashMap<(i64, String), String>> {
+        let comments_table = format!("{}_Comments", table_name);
+        if !super::schema::queries::table_exists(conn, &comments_table)? {
+            return Ok(HashMap::new());
+        }
+        let mut stmt = conn.prepare(&format!(
+            "SELECT row_index, column_name, comment FROM \"{}\"",
+            comments_table
+        ))?;
+        let comments = stmt
+            .query_map([], |row| Ok(((row.get(0)?, row.get(1)?), row.get(2)?)))?
+            .collect::<Result<HashMap<_, _>, _>>()?;
+        Ok(comments)
+    }
+
+    /// Read the row_index values of the locked rows of a table.
+    /// Tables that never had a locked row have no `{table}_LockedRows` table and yield an empty set.
+    pub fn read_locked_rows(conn: &Connection, table_name: &str) -> DbResult<HashSet<i64>> {
+        let locks_table = format!("{}_LockedRows", table_name);
+        if !super::schema::queries::table_exists(conn, &locks_table)? {
+            return Ok(HashSet::new());
+        }
+        let mut stmt = conn.prepare(&format!("SELECT row_index FROM \"{}\"", locks_table))?;
+        let locked = stmt
+            .query_map([], |row| row.get(0))?
+            .collect::<Result<HashSet<_>, _>>()?;
+        Ok(locked)
+    }
+
     // ========================================================================
     // Private helper methods
     // ========================================================================
@@ -291,6 +369,14 @@ impl DbReader {
         table_meta: queries::TableMetadataRow,
         is_structure: bool,
     ) -> SheetMetadata {
+        // Older databases only stored the key column's position
+        let ai_key_column = table_meta.ai_key_column.clone().or_else(|| {
+            table_meta
+                .ai_key_column_index
+                .and_then(|v| usize::try_from(v).ok())
+                .and_then(|idx| columns.get(idx))
+                .map(|c| c.header.clone())
+        });
         SheetMetadata {
             sheet_name: table_name.to_string(),
             category: table_meta.category,
@@ -300,7 +386,7 @@ impl DbReader {
             ai_model_id: table_meta.ai_model_id.unwrap_or_else(|| {
                 crate::sheets::definitions::default_ai_model_id()
             }),
-            ai_temperature: None,
+            ai_temperature: table_meta.ai_temperature.map(|t| t as f32),
             requested_grounding_with_google_search: Some(
                 table_meta.ai_grounding.unwrap_or(0) != 0,
             ),
@@ -313,6 +399,11 @@ impl DbReader {
                 .hidden
                 .map(|v| v != 0)
                 .unwrap_or(is_structure),
+            ai_key_column,
+            color: table_meta
+                .color
+                .as_deref()
+                .and_then(super::schema::parse_hex_color),
         }
     }
 }
\ No newline at end of file
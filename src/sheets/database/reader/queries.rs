@@ -62,10 +62,29 @@ pub fn read_metadata_columns(
     conn: &Connection,
     meta_table: &str,
 ) -> DbResult<Vec<MetadataColumnRow>> {
+    // Display options were added after the initial schema; select NULL when an older
+    // table has not been migrated yet so reading never fails on a missing column.
+    let physical_columns = get_physical_column_names(conn, meta_table)?;
+    let optional_column = |name: &'static str| -> &'static str {
+        if physical_columns.iter().any(|c| c.eq_ignore_ascii_case(name)) {
+            name
+        } else {
+            "NULL"
+        }
+    };
     let mut stmt = conn.prepare(&format!(
         "SELECT column_index, column_name, display_name, data_type, validator_type, validator_config, 
-                ai_context, filter_expr, ai_enable_row_generation, ai_include_in_send, deleted
+                ai_context, filter_expr, ai_enable_row_generation, ai_include_in_send, deleted,
+                {}, {}, {}, {}, {}, {}, {}, {}
          FROM \"{}\" ORDER BY column_index",
+        optional_column("display_trim_zeros"),
+        optional_column("column_width"),
+        optional_column("ai_read_only"),
+        optional_column("numeric_range"),
+        optional_column("default_value"),
+        optional_column("hidden"),
+        optional_column("multiline"),
+        optional_column("enum_auto_add"),
         meta_table
     ))?;
 
@@ -83,6 +102,14 @@ pub fn read_metadata_columns(
                 ai_enable_row_generation: row.get(8)?,
                 ai_include_in_send: row.get(9)?,
                 deleted: row.get(10)?,
+                display_trim_zeros: row.get(11)?,
+                column_width: row.get(12)?,
+                ai_read_only: row.get(13)?,
+                numeric_range: row.get(14)?,
+                default_value: row.get(15)?,
+                hidden: row.get(16)?,
+                multiline: row.get(17)?,
+                enum_auto_add: row.get(18)?,
             })
         })?
         .collect::<Result<Vec<_>, _>>()?;
@@ -109,12 +136,23 @@ pub fn insert_orphaned_column_metadata(
     Ok(())
 }
 
+/// Read a table's stored tag color (`#rrggbb`); `None` when unset or the column is missing.
+pub fn read_table_color(conn: &Connection, table_name: &str) -> Option<String> {
+    conn.query_row(
+        "SELECT color FROM _Metadata WHERE table_name = ?",
+        [table_name],
+        |r| r.get::<_, Option<String>>(0),
+    )
+    .ok()
+    .flatten()
+}
+
 /// Read table-level metadata (AI settings, category, etc.)
 pub fn read_table_metadata(
     conn: &Connection,
     table_name: &str,
 ) -> DbResult<TableMetadataRow> {
-    let row = conn
+    let mut row = conn
         .query_row(
             "SELECT ai_allow_add_rows, ai_table_context, ai_model_id, ai_active_group, category, hidden, ai_grounding_with_google_search
              FROM _Metadata WHERE table_name = ?",
@@ -128,6 +166,10 @@ pub fn read_table_metadata(
                     category: row.get(4)?,
                     hidden: row.get(5).ok(),
                     ai_grounding: row.get(6).ok(),
+                    ai_key_column: None,
+                    ai_key_column_index: None,
+                    ai_temperature: None,
+                    color: None,
                 })
             },
         )
@@ -139,8 +181,39 @@ pub fn read_table_metadata(
             category: None,
             hidden: None,
             ai_grounding: None,
+            ai_key_column: None,
+            ai_key_column_index: None,
+            ai_temperature: None,
+            color: None,
         });
 
+    // Columns added by later migrations; read separately so older databases still load
+    row.ai_key_column = conn
+        .query_row(
+            "SELECT ai_key_column FROM _Metadata WHERE table_name = ?",
+            [table_name],
+            |r| r.get::<_, Option<String>>(0),
+        )
+        .ok()
+        .flatten();
+    row.ai_key_column_index = conn
+        .query_row(
+            "SELECT ai_key_column_index FROM _Metadata WHERE table_name = ?",
+            [table_name],
+            |r| r.get::<_, Option<i64>>(0),
+        )
+        .ok()
+        .flatten();
+    row.ai_temperature = conn
+        .query_row(
+            "SELECT ai_temperature FROM _Metadata WHERE table_name = ?",
+            [table_name],
+            |r| r.get::<_, Option<f64>>(0),
+        )
+        .ok()
+        .flatten();
+    row.color = read_table_color(conn, table_name);
+
     Ok(row)
 }
 
@@ -346,6 +419,22 @@ pub struct MetadataColumnRow {
     pub ai_enable_row_generation: Option<i32>,
     pub ai_include_in_send: Option<i32>,
     pub deleted: Option<i32>,
+    /// Display-only: trim insignificant trailing zeros from numeric values
+    pub display_trim_zeros: Option<i32>,
+    /// UI width of the column, keyed by column_name so it survives reorders
+    pub column_width: Option<f64>,
+    /// AI sees the column as context but its suggestions are never applied
+    pub ai_read_only: Option<i32>,
+    /// Numeric bounds as a JSON `[min, max]` pair (null = open-ended)
+    pub numeric_range: Option<String>,
+    /// Value pre-filled into new rows (`@today` for Date columns)
+    pub default_value: Option<String>,
+    /// Column hidden from the grid (header eye icon / "Show hidden")
+    pub hidden: Option<i32>,
+    /// Long text: grid shows a preview, edited in a multi-line dialog
+    pub multiline: Option<i32>,
+    /// Enum columns: unknown values are added to the allowed list
+    pub enum_auto_add: Option<i32>,
 }
 
 impl MetadataColumnRow {
@@ -383,6 +472,11 @@ pub struct TableMetadataRow {
     pub category: Option<String>,
     pub hidden: Option<i32>,
     pub ai_grounding: Option<i32>,
+    pub ai_key_column: Option<String>,
+    /// Legacy position-based key column; only used when `ai_key_column` is unset
+    pub ai_key_column_index: Option<i64>,
+    pub ai_temperature: Option<f64>,
+    pub color: Option<String>,
 }
 
 #[derive(Debug)]
@@ -391,3 +485,45 @@ pub struct GridRow {
     pub values: Vec<String>,
     pub structure_counts: Vec<(usize, String)>, // (col_idx, count_label)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sheets::database::daemon_client::Statement;
+    use crate::sheets::database::writer::test_helpers::{exec_fixture_sql, run_statements};
+
+    #[test]
+    fn long_text_flag_is_optional_and_values_keep_line_breaks() {
+        let conn = Connection::open_in_memory().unwrap();
+        // Metadata table from before the display options were added
+        exec_fixture_sql(
+            &conn,
+            "CREATE TABLE \"Notes_Metadata\" (column_index INTEGER, column_name TEXT, display_name TEXT,
+                data_type TEXT, validator_type TEXT, validator_config TEXT, ai_context TEXT, filter_expr TEXT,
+                ai_enable_row_generation INTEGER, ai_include_in_send INTEGER, deleted INTEGER);
+             INSERT INTO \"Notes_Metadata\" (column_index, column_name, data_type) VALUES (0, 'Body', 'String');",
+        );
+        let rows = read_metadata_columns(&conn, "Notes_Metadata").unwrap();
+        assert_eq!(rows[0].multiline, None);
+
+        exec_fixture_sql(
+            &conn,
+            "ALTER TABLE \"Notes_Metadata\" ADD COLUMN multiline INTEGER DEFAULT 0;
+             UPDATE \"Notes_Metadata\" SET multiline = 1 WHERE column_name = 'Body';",
+        );
+        let rows = read_metadata_columns(&conn, "Notes_Metadata").unwrap();
+        assert_eq!(rows[0].multiline, Some(1));
+
+        let text = "First line\r\nSecond line\n\n\"quoted\", end";
+        exec_fixture_sql(&conn, "CREATE TABLE \"Notes\" (id INTEGER PRIMARY KEY, row_index INTEGER, \"Body\" TEXT);");
+        run_statements(
+            &conn,
+            vec![Statement {
+                sql: "INSERT INTO \"Notes\" (row_index, \"Body\") VALUES (0, ?1)".to_string(),
+                params: vec![serde_json::json!(text)],
+            }],
+        );
+        let grid = read_grid_with_structure_counts(&conn, "Notes", &[(0, "Body".to_string())], &[]).unwrap();
+        assert_eq!(grid[0].values, vec![text.to_string()]);
+    }
+}
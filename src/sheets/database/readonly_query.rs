@@ -0,0 +1,128 @@
+// src/sheets/database/readonly_query.rs
+//! Read-only ad-hoc SQL for the SQL console
+//!
+//! Only a single SELECT statement is accepted. The statement runs on a separate connection
+//! opened read-only (never through the daemon), and SQLite must also report it as
+//! read-only, so a query cannot change data even if it slips past the keyword check.
+
+use super::error::{DbError, DbResult};
+use rusqlite::{types::ValueRef, Connection, OpenFlags};
+
+/// Checks that `sql` is exactly one SELECT statement (one trailing semicolon allowed) and
+/// returns it without the semicolon
+pub fn validate_readonly_sql(sql: &str) -> Result<&str, String> {
+    let trimmed = sql.trim();
+    let body = trimmed.strip_suffix(';').unwrap_or(trimmed).trim_end();
+    if body.is_empty() {
+        return Err("Enter a SELECT statement.".to_string());
+    }
+    if body.contains(';') {
+        return Err("Only a single statement is allowed.".to_string());
+    }
+    let keyword: String = body
+        .chars()
+        .take_while(|c| c.is_ascii_alphabetic())
+        .collect();
+    if !keyword.eq_ignore_ascii_case("select") {
+        return Err(format!(
+            "Only SELECT statements are allowed (got '{}').",
+            if keyword.is_empty() { body.chars().take(12).collect() } else { keyword }
+        ));
+    }
+    Ok(body)
+}
+
+fn cell_to_string(value: ValueRef<'_>) -> String {
+    match value {
+        ValueRef::Null => String::new(),
+        ValueRef::Integer(i) => i.to_string(),
+        ValueRef::Real(f) => f.to_string(),
+        ValueRef::Text(t) => String::from_utf8_lossy(t).into_owned(),
+        ValueRef::Blob(b) => format!("<blob {} bytes>", b.len()),
+    }
+}
+
+/// Runs a validated SELECT on `conn`. The first row holds the column names; every value
+/// is stringified (NULL becomes an empty string).
+pub fn run_readonly_query(conn: &Connection, sql: &str) -> DbResult<Vec<Vec<String>>> {
+    let body = validate_readonly_sql(sql).map_err(DbError::Other)?;
+    let mut stmt = conn.prepare(body)?;
+    if !stmt.readonly() {
+        return Err(DbError::Other(
+            "Statement would modify the database; only read-only queries are allowed.".to_string(),
+        ));
+    }
+    let column_count = stmt.column_count();
+    let mut result = vec![stmt
+        .column_names()
+        .into_iter()
+        .map(|name| name.to_string())
+        .collect::<Vec<_>>()];
+    let mut rows = stmt.query([])?;
+    while let Some(row) = rows.next()? {
+        let mut values = Vec::with_capacity(column_count);
+        for i in 0..column_count {
+            values.push(cell_to_string(row.get_ref(i)?));
+        }
+        result.push(values);
+    }
+    Ok(result)
+}
+
+/// Runs a single SELECT against a database in the data directory (e.g. "Game.db") over a
+/// read-only connection. See `run_readonly_query` for the result layout.
+pub fn query_readonly(sql: &str, db_filename: Option<&str>) -> DbResult<Vec<Vec<String>>> {
+    let db_filename =
+        db_filename.ok_or_else(|| DbError::Other("No database selected.".to_string()))?;
+    let db_path = crate::sheets::systems::io::get_default_data_base_path().join(db_filename);
+    if !db_path.exists() {
+        return Err(DbError::Other(format!(
+            "Database '{}' not found.",
+            db_path.display()
+        )));
+    }
+    // Opened read-only: SQLite refuses any write on this connection
+    let conn = Connection::open_with_flags(
+        &db_path,
+        OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+    )?;
+    run_readonly_query(&conn, sql)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sheets::database::writer::test_helpers::exec_fixture_sql;
+
+    #[test]
+    fn only_single_select_statements_pass_validation() {
+        assert_eq!(validate_readonly_sql("  select * from Items; "), Ok("select * from Items"));
+        assert!(validate_readonly_sql("SELECT 1; DELETE FROM Items").is_err());
+        assert!(validate_readonly_sql("SELECT 1;;").is_err());
+        assert!(validate_readonly_sql("UPDATE Items SET a = 1").is_err());
+        assert!(validate_readonly_sql("WITH x AS (SELECT 1) DELETE FROM Items").is_err());
+        assert!(validate_readonly_sql("   ").is_err());
+    }
+
+    #[test]
+    fn query_returns_header_row_and_stringified_values() {
+        let conn = Connection::open_in_memory().unwrap();
+        exec_fixture_sql(
+            &conn,
+            "CREATE TABLE Items (name TEXT, qty INTEGER, weight REAL, note TEXT);
+             INSERT INTO Items VALUES ('Sword', 2, 1.5, NULL);",
+        );
+        let rows = run_readonly_query(&conn, "SELECT name, qty, weight, note FROM Items;").unwrap();
+        assert_eq!(rows[0], vec!["name", "qty", "weight", "note"]);
+        assert_eq!(rows[1], vec!["Sword", "2", "1.5", ""]);
+    }
+
+    #[test]
+    fn writing_statements_are_rejected() {
+        let conn = Connection::open_in_memory().unwrap();
+        exec_fixture_sql(&conn, "CREATE TABLE Items (name TEXT);");
+        assert!(run_readonly_query(&conn, "DELETE FROM Items").is_err());
+        assert!(run_readonly_query(&conn, "SELECT 1; DROP TABLE Items").is_err());
+        assert!(conn.prepare("SELECT name FROM Items").is_ok());
+    }
+}
@@ -86,6 +86,8 @@ pub fn sql_type_for_column(data_type: ColumnDataType) -> &'static str {
         ColumnDataType::I64 => "INTEGER",
         ColumnDataType::F64 => "REAL",
         ColumnDataType::Link => "TEXT",
+        // NUMERIC affinity, but ISO dates are not numbers so they stay TEXT
+        ColumnDataType::Date => "DATE",
     }
 }
 
@@ -95,6 +97,7 @@ pub fn sql_type_to_column_data_type(sql_type: &str) -> ColumnDataType {
     match sql_type.to_uppercase().as_str() {
         "INTEGER" => ColumnDataType::I64,
         "REAL" | "FLOAT" | "DOUBLE" => ColumnDataType::F64,
+        "DATE" => ColumnDataType::Date,
         _ => ColumnDataType::String,
     }
 }
@@ -108,6 +111,7 @@ pub fn metadata_type_to_column_data_type(type_str: &str) -> ColumnDataType {
         "I64" => ColumnDataType::I64,
         "F64" => ColumnDataType::F64,
         "Link" => ColumnDataType::Link,
+        "Date" => ColumnDataType::Date,
         _ => ColumnDataType::String,
     }
 }
@@ -135,3 +139,46 @@ pub fn sanitize_identifier(name: &str) -> String {
         trimmed
     }
 }
+
+/// Key/value table holding database-wide settings such as the category color
+pub const DATABASE_SETTINGS_TABLE: &str = "_DatabaseSettings";
+
+/// Format a tag color for storage as `#rrggbb`.
+pub fn color_to_hex(color: [u8; 3]) -> String {
+    format!("#{:02x}{:02x}{:02x}", color[0], color[1], color[2])
+}
+
+/// Parse a stored `#rrggbb` tag color. Anything else reads as "no color".
+pub fn parse_hex_color(value: &str) -> Option<[u8; 3]> {
+    let hex = value.trim().strip_prefix('#')?;
+    if hex.len() != 6 || !hex.is_ascii() {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    Some([channel(0)?, channel(2)?, channel(4)?])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hex_color_round_trip() {
+        assert_eq!(color_to_hex([255, 128, 0]), "#ff8000");
+        assert_eq!(parse_hex_color("#ff8000"), Some([255, 128, 0]));
+        assert_eq!(parse_hex_color(" #0A0b0C "), Some([10, 11, 12]));
+        assert_eq!(parse_hex_color("ff8000"), None);
+        assert_eq!(parse_hex_color("#ff80"), None);
+        assert_eq!(parse_hex_color("#gg8000"), None);
+    }
+
+    #[test]
+    fn date_type_round_trips_through_sql_and_metadata() {
+        let sql_type = sql_type_for_column(ColumnDataType::Date);
+        assert_eq!(sql_type_to_column_data_type(sql_type), ColumnDataType::Date);
+        let metadata_type = format!("{:?}", ColumnDataType::Date);
+        assert_eq!(metadata_type_to_column_data_type(&metadata_type), ColumnDataType::Date);
+        // Other TEXT-backed types are unaffected
+        assert_eq!(sql_type_to_column_data_type("TEXT"), ColumnDataType::String);
+    }
+}
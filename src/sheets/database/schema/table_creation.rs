@@ -30,6 +30,26 @@ pub fn ensure_global_metadata_table(conn: &Connection, daemon_client: &DaemonCli
         mark_migration_applied(conn, 2, "Added ai_model_id column to _Metadata", daemon_client)?;
     }
 
+    if !is_migration_applied(conn, 3)? {
+        add_ai_key_column_index_migration(conn, daemon_client)?;
+        mark_migration_applied(conn, 3, "Added ai_key_column_index column to _Metadata", daemon_client)?;
+    }
+
+    if !is_migration_applied(conn, 4)? {
+        add_ai_temperature_migration(conn, daemon_client)?;
+        mark_migration_applied(conn, 4, "Added ai_temperature column to _Metadata", daemon_client)?;
+    }
+
+    if !is_migration_applied(conn, 5)? {
+        add_color_migration(conn, daemon_client)?;
+        mark_migration_applied(conn, 5, "Added color column to _Metadata", daemon_client)?;
+    }
+
+    if !is_migration_applied(conn, 6)? {
+        add_ai_key_column_migration(conn, daemon_client)?;
+        mark_migration_applied(conn, 6, "Added ai_key_column column to _Metadata", daemon_client)?;
+    }
+
     Ok(())
 }
 
@@ -72,6 +92,55 @@ fn add_ai_model_id_column_migration(conn: &Connection, daemon_client: &DaemonCli
     Ok(())
 }
 
+/// Migration 3: Add ai_key_column_index column (per-sheet AI display/key column)
+fn add_ai_key_column_index_migration(conn: &Connection, daemon_client: &DaemonClient) -> DbResult<()> {
+    let existing_cols = queries::get_table_columns(conn, "_Metadata")?;
+
+    if !existing_cols.iter().any(|c| c.eq_ignore_ascii_case("ai_key_column_index")) {
+        writer::add_column_if_missing(conn, "_Metadata", "ai_key_column_index", "INTEGER", daemon_client, None)?;
+        info!("Added ai_key_column_index column to _Metadata table");
+    }
+
+    Ok(())
+}
+
+/// Migration 6: Add ai_key_column column (AI key column by header; supersedes
+/// ai_key_column_index, which is only read as a fallback for older databases)
+fn add_ai_key_column_migration(conn: &Connection, daemon_client: &DaemonClient) -> DbResult<()> {
+    let existing_cols = queries::get_table_columns(conn, "_Metadata")?;
+
+    if !existing_cols.iter().any(|c| c.eq_ignore_ascii_case("ai_key_column")) {
+        writer::add_column_if_missing(conn, "_Metadata", "ai_key_column", "TEXT", daemon_client, None)?;
+        info!("Added ai_key_column column to _Metadata table");
+    }
+
+    Ok(())
+}
+
+/// Migration 4: Add ai_temperature column (NULL = model default)
+fn add_ai_temperature_migration(conn: &Connection, daemon_client: &DaemonClient) -> DbResult<()> {
+    let existing_cols = queries::get_table_columns(conn, "_Metadata")?;
+
+    if !existing_cols.iter().any(|c| c.eq_ignore_ascii_case("ai_temperature")) {
+        writer::add_column_if_missing(conn, "_Metadata", "ai_temperature", "REAL", daemon_client, None)?;
+        info!("Added ai_temperature column to _Metadata table");
+    }
+
+    Ok(())
+}
+
+/// Migration 5: Add color column (`#rrggbb` tag shown in the bottom panel, NULL = theme color)
+fn add_color_migration(conn: &Connection, daemon_client: &DaemonClient) -> DbResult<()> {
+    let existing_cols = queries::get_table_columns(conn, "_Metadata")?;
+
+    if !existing_cols.iter().any(|c| c.eq_ignore_ascii_case("color")) {
+        writer::add_column_if_missing(conn, "_Metadata", "color", "TEXT", daemon_client, None)?;
+        info!("Added color column to _Metadata table");
+    }
+
+    Ok(())
+}
+
 /// Create main data table from metadata
 pub fn create_data_table(
     table_name: &str,
@@ -143,6 +212,8 @@ fn build_validator_info(
         Some(ColumnValidator::Basic(_)) => Some("Basic".to_string()),
         Some(ColumnValidator::Linked { .. }) => Some("Linked".to_string()),
         Some(ColumnValidator::Structure) => Some("Structure".to_string()),
+        Some(ColumnValidator::Unique { .. }) => Some("Unique".to_string()),
+        Some(ColumnValidator::Enum(_)) => Some("Enum".to_string()),
         None => None,
     };
 
@@ -166,6 +237,13 @@ fn build_validator_info(
                 .to_string(),
             )
         }
+        Some(ColumnValidator::Unique { case_sensitive, .. }) => Some(
+            serde_json::json!({
+                "case_sensitive": case_sensitive
+            })
+            .to_string(),
+        ),
+        Some(ColumnValidator::Enum(values)) => Some(serde_json::json!(values).to_string()),
         _ => None,
     };
 
@@ -215,6 +293,13 @@ fn add_missing_metadata_columns(conn: &Connection, meta_table: &str, daemon_clie
     )?;
     writer::add_column_if_missing(conn, meta_table, "ai_include_in_send", "INTEGER DEFAULT 1", daemon_client, db_name)?;
     writer::add_column_if_missing(conn, meta_table, "deleted", "INTEGER DEFAULT 0", daemon_client, db_name)?;
+    writer::add_column_if_missing(conn, meta_table, "display_trim_zeros", "INTEGER DEFAULT 0", daemon_client, db_name)?;
+    writer::add_column_if_missing(conn, meta_table, "column_width", "REAL", daemon_client, db_name)?;
+    writer::add_column_if_missing(conn, meta_table, "ai_read_only", "INTEGER DEFAULT 0", daemon_client, db_name)?;
+    writer::add_column_if_missing(conn, meta_table, "numeric_range", "TEXT", daemon_client, db_name)?;
+    writer::add_column_if_missing(conn, meta_table, "default_value", "TEXT", daemon_client, db_name)?;
+    writer::add_column_if_missing(conn, meta_table, "multiline", "INTEGER DEFAULT 0", daemon_client, db_name)?;
+    writer::add_column_if_missing(conn, meta_table, "enum_auto_add", "INTEGER DEFAULT 0", daemon_client, db_name)?;
     Ok(())
 }
 
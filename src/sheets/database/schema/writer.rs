@@ -46,6 +46,10 @@ pub fn create_global_metadata_table(daemon_client: &DaemonClient) -> DbResult<()
             ai_model_id TEXT,
             ai_grounding_with_google_search INTEGER DEFAULT 0,
             ai_active_group TEXT,
+            ai_key_column_index INTEGER,
+            ai_key_column TEXT,
+            ai_temperature REAL,
+            color TEXT,
             display_order INTEGER,
             category TEXT,
             hidden INTEGER DEFAULT 0,
@@ -112,7 +116,15 @@ pub fn create_sheet_metadata_table(
                 filter_expr TEXT,
                 ai_enable_row_generation INTEGER DEFAULT 0,
                 ai_include_in_send INTEGER DEFAULT 1,
-                deleted INTEGER DEFAULT 0
+                deleted INTEGER DEFAULT 0,
+                display_trim_zeros INTEGER DEFAULT 0,
+                column_width REAL,
+                ai_read_only INTEGER DEFAULT 0,
+                numeric_range TEXT,
+                default_value TEXT,
+                hidden INTEGER DEFAULT 0,
+                multiline INTEGER DEFAULT 0,
+                enum_auto_add INTEGER DEFAULT 0
             )",
             meta_table
         ),
@@ -3,9 +3,21 @@
 use crate::sheets::events::MigrationProgress;
 use bevy::prelude::*;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicUsize};
 use std::sync::mpsc::Receiver;
 use std::sync::{Arc, Mutex};
 
+/// Counters shared with the migration thread, updated after every inserted chunk
+#[derive(Default)]
+pub struct MigrationCounters {
+    /// Rows written so far for the table being migrated
+    pub rows_done: AtomicUsize,
+    /// Rows expected for the table being migrated (main rows plus estimated structure rows)
+    pub rows_total: AtomicUsize,
+    /// Set from the UI; the migration thread checks it between chunks
+    pub cancel: AtomicBool,
+}
+
 #[derive(Resource, Default)]
 pub struct MigrationBackgroundState {
     pub progress_rx: Option<Arc<Mutex<Receiver<MigrationProgress>>>>, // progress updates
@@ -13,4 +25,13 @@ pub struct MigrationBackgroundState {
         Option<Arc<Mutex<Receiver<Result<(super::super::migration::MigrationReport, PathBuf), String>>>>>, // final result with db path
     /// Optional target to auto-select after completion: (category/db name, table name)
     pub post_select: Option<(String, String)>,
+    /// Row counters and cancel flag of the running folder migration
+    pub counters: Option<Arc<MigrationCounters>>,
+}
+
+impl MigrationBackgroundState {
+    /// A migration thread is still writing (its completion has not been received yet)
+    pub fn is_running(&self) -> bool {
+        self.completion_rx.is_some()
+    }
 }
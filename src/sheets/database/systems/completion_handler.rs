@@ -10,6 +10,7 @@ pub fn handle_migration_completion(
 ) {
     for _event in events.read() {
         migration_state.migration_in_progress = false;
+        migration_state.cancel_requested = false;
         // Could add more UI feedback here
     }
 }
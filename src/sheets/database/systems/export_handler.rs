@@ -1,7 +1,10 @@
 // src/sheets/database/systems/export_handler.rs
 
-use crate::sheets::events::{RequestExportSheetToJson, SheetOperationFeedback};
-use crate::sheets::database::migration::MigrationTools;
+use crate::sheets::events::{
+    RequestExportProject, RequestExportSheetToCsv, RequestExportSheetToJson,
+    RequestExportSheetToJsonl, RequestExportSheetToParquet, SheetOperationFeedback,
+};
+use crate::sheets::database::migration::{MigrationTools, ProjectArchive};
 use crate::sheets::database::daemon_resource::SharedDaemonClient;
 use bevy::prelude::*;
 
@@ -54,3 +57,169 @@ pub fn handle_export_requests(
         }
     }
 }
+
+/// Handle requests to export a sheet from SQLite database to JSON Lines
+pub fn handle_jsonl_export_requests(
+    mut events: EventReader<RequestExportSheetToJsonl>,
+    mut feedback_writer: EventWriter<SheetOperationFeedback>,
+    daemon_client: Res<SharedDaemonClient>,
+) {
+    for event in events.read() {
+        info!(
+            "Exporting table '{}' from {:?} to JSONL in {:?}",
+            event.table_name, event.db_path, event.output_folder
+        );
+
+        let result = rusqlite::Connection::open(&event.db_path)
+            .map_err(|e| format!("Failed to open database: {}", e))
+            .and_then(|conn| {
+                MigrationTools::export_sheet_to_jsonl(
+                    &conn,
+                    &event.table_name,
+                    &event.output_folder,
+                    daemon_client.client(),
+                )
+                .map_err(|e| format!("Failed to export '{}': {}", event.table_name, e))
+            });
+        match result {
+            Ok(path) => {
+                let msg = format!("Exported '{}' to {}", event.table_name, path.display());
+                info!("{}", msg);
+                feedback_writer.write(SheetOperationFeedback {
+                    message: msg,
+                    is_error: false,
+                });
+            }
+            Err(msg) => {
+                error!("{}", msg);
+                feedback_writer.write(SheetOperationFeedback {
+                    message: msg,
+                    is_error: true,
+                });
+            }
+        }
+    }
+}
+
+/// Handle requests to export a sheet from SQLite database to CSV
+pub fn handle_csv_export_requests(
+    mut events: EventReader<RequestExportSheetToCsv>,
+    mut feedback_writer: EventWriter<SheetOperationFeedback>,
+    daemon_client: Res<SharedDaemonClient>,
+) {
+    for event in events.read() {
+        info!(
+            "Exporting table '{}' from {:?} to CSV in {:?}",
+            event.table_name, event.db_path, event.output_folder
+        );
+
+        let result = rusqlite::Connection::open(&event.db_path)
+            .map_err(|e| format!("Failed to open database: {}", e))
+            .and_then(|conn| {
+                MigrationTools::export_sheet_to_csv(
+                    &conn,
+                    &event.table_name,
+                    &event.output_folder,
+                    daemon_client.client(),
+                )
+                .map_err(|e| format!("Failed to export '{}': {}", event.table_name, e))
+            });
+        match result {
+            Ok(path) => {
+                let msg = format!("Exported '{}' to {}", event.table_name, path.display());
+                info!("{}", msg);
+                feedback_writer.write(SheetOperationFeedback {
+                    message: msg,
+                    is_error: false,
+                });
+            }
+            Err(msg) => {
+                error!("{}", msg);
+                feedback_writer.write(SheetOperationFeedback {
+                    message: msg,
+                    is_error: true,
+                });
+            }
+        }
+    }
+}
+
+/// Handle requests to export a sheet from SQLite database to Parquet
+pub fn handle_parquet_export_requests(
+    mut events: EventReader<RequestExportSheetToParquet>,
+    mut feedback_writer: EventWriter<SheetOperationFeedback>,
+    daemon_client: Res<SharedDaemonClient>,
+) {
+    for event in events.read() {
+        info!(
+            "Exporting table '{}' from {:?} to Parquet in {:?}",
+            event.table_name, event.db_path, event.output_folder
+        );
+
+        let result = rusqlite::Connection::open(&event.db_path)
+            .map_err(|e| format!("Failed to open database: {}", e))
+            .and_then(|conn| {
+                MigrationTools::export_sheet_to_parquet(
+                    &conn,
+                    &event.table_name,
+                    &event.output_folder,
+                    daemon_client.client(),
+                )
+                .map_err(|e| format!("Failed to export '{}': {}", event.table_name, e))
+            });
+        match result {
+            Ok(path) => {
+                let msg = format!("Exported '{}' to {}", event.table_name, path.display());
+                info!("{}", msg);
+                feedback_writer.write(SheetOperationFeedback {
+                    message: msg,
+                    is_error: false,
+                });
+            }
+            Err(msg) => {
+                error!("{}", msg);
+                feedback_writer.write(SheetOperationFeedback {
+                    message: msg,
+                    is_error: true,
+                });
+            }
+        }
+    }
+}
+
+/// Handle requests to export all category databases into a single project archive
+pub fn handle_project_export_requests(
+    mut events: EventReader<RequestExportProject>,
+    mut feedback_writer: EventWriter<SheetOperationFeedback>,
+    daemon_client: Res<SharedDaemonClient>,
+) {
+    for event in events.read() {
+        let data_dir = crate::sheets::systems::io::get_default_data_base_path();
+        info!("Exporting project from {:?} to {:?}", data_dir, event.archive_path);
+
+        match ProjectArchive::export_project(&data_dir, &event.archive_path, daemon_client.client()) {
+            Ok(manifest) => {
+                let table_count: usize = manifest.categories.iter().map(|c| c.tables.len()).sum();
+                let msg = format!(
+                    "Exported {} table(s) from {} categor(ies) to {}",
+                    table_count,
+                    manifest.categories.len(),
+                    event.archive_path.display()
+                );
+                info!("{}", msg);
+                feedback_writer.write(SheetOperationFeedback {
+                    message: msg,
+                    is_error: false,
+                });
+            }
+            Err(e) => {
+                let msg = format!("Failed to export project: {}", e);
+                error!("{}", msg);
+                feedback_writer.write(SheetOperationFeedback {
+                    message: msg,
+                    is_error: true,
+                });
+            }
+        }
+    }
+}
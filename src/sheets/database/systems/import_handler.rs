@@ -0,0 +1,165 @@
+// src/sheets/database/systems/import_handler.rs
+
+use crate::sheets::database::daemon_resource::SharedDaemonClient;
+use crate::sheets::database::migration::{MigrationReport, ProjectArchive};
+use crate::sheets::database::reader::DbReader;
+use crate::sheets::events::{
+    RequestImportProject, RequestSheetRevalidation, SheetDataModifiedInRegistryEvent,
+    SheetOperationFeedback,
+};
+use crate::sheets::resources::SheetRegistry;
+use crate::sheets::systems::io::get_default_data_base_path;
+use bevy::prelude::*;
+use rusqlite::Connection;
+
+/// Handle requests to import a project archive into the data directory
+pub fn handle_project_import_requests(
+    mut events: EventReader<RequestImportProject>,
+    mut registry: ResMut<SheetRegistry>,
+    mut feedback_writer: EventWriter<SheetOperationFeedback>,
+    mut data_modified_writer: EventWriter<SheetDataModifiedInRegistryEvent>,
+    mut revalidate_writer: EventWriter<RequestSheetRevalidation>,
+    daemon_client: Res<SharedDaemonClient>,
+) {
+    for event in events.read() {
+        let data_dir = get_default_data_base_path();
+        info!(
+            "Importing project {:?} into {:?} ({:?} on collision)",
+            event.archive_path, data_dir, event.collision_policy
+        );
+
+        let manifest = match ProjectArchive::read_manifest(&event.archive_path) {
+            Ok(manifest) => manifest,
+            Err(e) => {
+                let msg = format!("Failed to import project: {}", e);
+                error!("{}", msg);
+                feedback_writer.write(SheetOperationFeedback {
+                    message: msg,
+                    is_error: true,
+                });
+                continue;
+            }
+        };
+        if let Err(e) = std::fs::create_dir_all(&data_dir) {
+            let msg = format!("Failed to import project: cannot create {:?}: {}", data_dir, e);
+            error!("{}", msg);
+            feedback_writer.write(SheetOperationFeedback {
+                message: msg,
+                is_error: true,
+            });
+            continue;
+        }
+
+        let report = match ProjectArchive::import_project(
+            &event.archive_path,
+            &data_dir,
+            event.collision_policy,
+            daemon_client.client(),
+        ) {
+            Ok(report) => report,
+            Err(e) => {
+                let msg = format!("Failed to import project: {}", e);
+                error!("{}", msg);
+                feedback_writer.write(SheetOperationFeedback {
+                    message: msg,
+                    is_error: true,
+                });
+                continue;
+            }
+        };
+
+        for category in &manifest.categories {
+            reload_category(
+                &mut registry,
+                &data_dir.join(&category.database_file),
+                &category.name,
+                &daemon_client,
+                &mut data_modified_writer,
+                &mut revalidate_writer,
+            );
+        }
+
+        let msg = import_summary(&report);
+        for (name, err) in &report.failed_sheets {
+            warn!("Failed to import '{}': {}", name, err);
+        }
+        for file in &report.skipped_files {
+            warn!("Skipped archive file '{}'", file);
+        }
+        info!("{}", msg);
+        feedback_writer.write(SheetOperationFeedback {
+            message: msg,
+            is_error: report.sheets_failed > 0,
+        });
+    }
+}
+
+fn import_summary(report: &MigrationReport) -> String {
+    let mut msg = format!(
+        "Imported {} table(s) with {} row(s)",
+        report.sheets_migrated, report.rows_migrated
+    );
+    if report.sheets_failed > 0 {
+        msg.push_str(&format!(", {} failed", report.sheets_failed));
+    }
+    if !report.skipped_files.is_empty() {
+        msg.push_str(&format!(", {} file(s) skipped", report.skipped_files.len()));
+    }
+    msg
+}
+
+/// Replace the registry's view of a category with the tables now in its database
+fn reload_category(
+    registry: &mut SheetRegistry,
+    db_path: &std::path::Path,
+    db_name: &str,
+    daemon_client: &SharedDaemonClient,
+    data_modified_writer: &mut EventWriter<SheetDataModifiedInRegistryEvent>,
+    revalidate_writer: &mut EventWriter<RequestSheetRevalidation>,
+) {
+    let conn = match Connection::open(db_path) {
+        Ok(conn) => conn,
+        Err(e) => {
+            error!("Post-import: Failed to open '{}': {}", db_path.display(), e);
+            return;
+        }
+    };
+    // Make the daemon's writes visible before reading the tables back
+    let _ = conn.query_row("PRAGMA wal_checkpoint(PASSIVE)", [], |_| Ok(()));
+    let table_names = match DbReader::list_sheets(&conn) {
+        Ok(names) => names,
+        Err(e) => {
+            error!("Post-import: Failed to list tables of '{}': {}", db_name, e);
+            return;
+        }
+    };
+
+    let category = Some(db_name.to_string());
+    // Overwritten tables may have lost structure children that the archive did not contain
+    for stale in registry.get_sheet_names_in_category(&category) {
+        if !table_names.contains(&stale) {
+            let _ = registry.delete_sheet(&category, &stale);
+        }
+    }
+    let _ = registry.create_category(db_name.to_string());
+
+    for table_name in table_names {
+        match DbReader::read_sheet(&conn, &table_name, daemon_client.client(), Some(db_name)) {
+            Ok(mut sheet_data) => {
+                if let Some(meta) = sheet_data.metadata.as_mut() {
+                    meta.category = category.clone();
+                }
+                registry.add_or_replace_sheet(category.clone(), table_name.clone(), sheet_data);
+                data_modified_writer.write(SheetDataModifiedInRegistryEvent {
+                    category: category.clone(),
+                    sheet_name: table_name.clone(),
+                });
+                revalidate_writer.write(RequestSheetRevalidation {
+                    category: category.clone(),
+                    sheet_name: table_name,
+                });
+            }
+            Err(e) => error!("Post-import: Failed to read '{}/{}': {}", db_name, table_name, e),
+        }
+    }
+}
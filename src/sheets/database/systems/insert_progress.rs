@@ -0,0 +1,64 @@
+// src/sheets/database/systems/insert_progress.rs
+
+/// Progress line for the per-chunk insert callback of a JSON import.
+///
+/// `rows_done` counts main rows first and continues counting structure rows after
+/// `grid_rows` (see `MigrationTools::migrate_sheet_from_json`).
+pub(super) fn row_progress_message(
+    table_name: &str,
+    rows_done: usize,
+    grid_rows: usize,
+    struct_estimate: usize,
+) -> String {
+    if rows_done <= grid_rows {
+        return format!(
+            "{}: Inserted {} of {} rows",
+            table_name, rows_done, grid_rows
+        );
+    }
+    let structures_done = rows_done - grid_rows;
+    let structures = if struct_estimate == 0 {
+        format!("{} structure rows", structures_done)
+    } else if structures_done <= struct_estimate {
+        format!("{} of ~{} structure rows", structures_done, struct_estimate)
+    } else {
+        format!("{} structure rows (est {})", structures_done, struct_estimate)
+    };
+    format!(
+        "{}: Inserted {} of {} rows, {}",
+        table_name, grid_rows, grid_rows, structures
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::row_progress_message;
+
+    #[test]
+    fn main_rows_report_inserted_of_total() {
+        assert_eq!(
+            row_progress_message("Aircraft", 1000, 4200, 0),
+            "Aircraft: Inserted 1000 of 4200 rows"
+        );
+        assert_eq!(
+            row_progress_message("Aircraft", 4200, 4200, 10),
+            "Aircraft: Inserted 4200 of 4200 rows"
+        );
+    }
+
+    #[test]
+    fn structure_rows_follow_main_rows() {
+        assert_eq!(
+            row_progress_message("Aircraft", 4300, 4200, 500),
+            "Aircraft: Inserted 4200 of 4200 rows, 100 of ~500 structure rows"
+        );
+        assert_eq!(
+            row_progress_message("Aircraft", 4800, 4200, 500),
+            "Aircraft: Inserted 4200 of 4200 rows, 600 structure rows (est 500)"
+        );
+        assert_eq!(
+            row_progress_message("Aircraft", 4300, 4200, 0),
+            "Aircraft: Inserted 4200 of 4200 rows, 100 structure rows"
+        );
+    }
+}
@@ -0,0 +1,111 @@
+// src/sheets/database/systems/maintenance_handler.rs
+
+use super::MigrationBackgroundState;
+use crate::sheets::database::connection::DbConnection;
+use crate::sheets::database::daemon_resource::SharedDaemonClient;
+use crate::sheets::database::maintenance::compact_database;
+use crate::sheets::database::validation::{
+    daemon_fix_applier, log_structure_link_report, validate_structure_links, OrphanFix,
+};
+use crate::sheets::events::{
+    RequestCompactDatabase, RequestValidateStructureLinks, SheetOperationFeedback,
+};
+use bevy::prelude::*;
+
+/// Handle Settings requests to compact a category database through the daemon
+pub fn handle_compact_database_requests(
+    mut events: EventReader<RequestCompactDatabase>,
+    mut feedback_writer: EventWriter<SheetOperationFeedback>,
+    bg_state: Res<MigrationBackgroundState>,
+    daemon_client: Res<SharedDaemonClient>,
+) {
+    for event in events.read() {
+        // VACUUM rewrites the whole file; it must not interleave with a migration's inserts
+        if bg_state.is_running() {
+            let msg = format!(
+                "Cannot compact {} while a migration is in progress. Try again once it finishes.",
+                event.db_path.display()
+            );
+            warn!("{}", msg);
+            feedback_writer.write(SheetOperationFeedback {
+                message: msg,
+                is_error: true,
+            });
+            continue;
+        }
+
+        info!("Compacting database {:?}", event.db_path);
+        match compact_database(&event.db_path, daemon_client.client()) {
+            Ok(report) => {
+                let msg = report.summary(&event.db_path);
+                info!("{}", msg);
+                feedback_writer.write(SheetOperationFeedback {
+                    message: msg,
+                    is_error: false,
+                });
+            }
+            Err(e) => {
+                let msg = format!("Failed to compact {}: {}", event.db_path.display(), e);
+                error!("{}", msg);
+                feedback_writer.write(SheetOperationFeedback {
+                    message: msg,
+                    is_error: true,
+                });
+            }
+        }
+    }
+}
+
+/// Handle Settings requests to check the structure links of a category database.
+/// Only reports; orphans are listed in the log and repaired from the CLI (with a backup).
+pub fn handle_validate_structure_links_requests(
+    mut events: EventReader<RequestValidateStructureLinks>,
+    mut feedback_writer: EventWriter<SheetOperationFeedback>,
+    daemon_client: Res<SharedDaemonClient>,
+) {
+    for event in events.read() {
+        let db_name = event
+            .db_path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| event.db_path.display().to_string());
+        let result = DbConnection::open_existing(&event.db_path)
+            .map_err(|e| e.to_string())
+            .and_then(|conn| {
+                validate_structure_links(
+                    &conn,
+                    OrphanFix::default(),
+                    daemon_fix_applier(daemon_client.client(), Some(&db_name)),
+                )
+                .map_err(|e| e.to_string())
+            });
+        let (message, is_error) = match result {
+            Ok(reports) => {
+                log_structure_link_report(&reports);
+                let orphans: usize = reports.iter().map(|r| r.orphans.len()).sum();
+                let tables = reports.iter().filter(|r| !r.orphans.is_empty()).count();
+                if orphans == 0 {
+                    (
+                        format!("Structure links in {}: all {} child table(s) OK.", db_name, reports.len()),
+                        false,
+                    )
+                } else {
+                    (
+                        format!(
+                            "{} orphaned structure row(s) in {} table(s) of {} (details in the log). Repair with `validate-structure-links --relink` or `--delete-orphans`.",
+                            orphans, tables, db_name
+                        ),
+                        true,
+                    )
+                }
+            }
+            Err(e) => (format!("Failed to check structure links of {}: {}", db_name, e), true),
+        };
+        if is_error {
+            warn!("{}", message);
+        } else {
+            info!("{}", message);
+        }
+        feedback_writer.write(SheetOperationFeedback { message, is_error });
+    }
+}
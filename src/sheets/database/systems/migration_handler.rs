@@ -1,10 +1,13 @@
 // src/sheets/database/systems/migration_handler.rs
 
+use super::super::error::DbError;
 use super::super::migration::MigrationTools;
-use super::MigrationBackgroundState;
+use super::insert_progress::row_progress_message;
+use super::{MigrationBackgroundState, MigrationCounters};
 use crate::sheets::events::{MigrationProgress, RequestMigrateJsonToDb, SheetOperationFeedback};
 use bevy::prelude::*;
 use std::path::PathBuf;
+use std::sync::atomic::Ordering;
 use std::sync::mpsc::channel;
 use std::sync::{Arc, Mutex};
 use std::thread;
@@ -28,6 +31,8 @@ pub fn handle_migration_requests(
         let json_folder = event.json_folder_path.clone();
         let db_path = event.target_db_path.clone();
         let create_new = event.create_new_db;
+        let counters = Arc::new(MigrationCounters::default());
+        let thread_counters = counters.clone();
 
         // Send initial info message
         feedback_writer.write(SheetOperationFeedback {
@@ -67,6 +72,10 @@ pub fn handle_migration_requests(
 
                 let mut report = super::super::migration::MigrationReport::default();
                 for (idx, sheet_name) in ordered.iter().enumerate() {
+                    if thread_counters.cancel.load(Ordering::Relaxed) {
+                        report.cancelled = true;
+                        break;
+                    }
                     let _ = tx_prog.send(MigrationProgress {
                         total: total_sheets,
                         completed: idx,
@@ -118,44 +127,21 @@ pub fn handle_migration_requests(
                             }
                         }
                         let struct_tables_count = structure_col_indices.len();
+                        thread_counters.rows_done.store(0, Ordering::Relaxed);
+                        thread_counters
+                            .rows_total
+                            .store(grid_rows + struct_estimate, Ordering::Relaxed);
 
                         let sheet_name_for_cb = sheet_name.clone();
                         let tx_prog_cb = tx_prog.clone();
                         let total_sheets_cb = total_sheets;
+                        let counters_cb = thread_counters.clone();
                         let mut row_notifier = move |rows_done: usize| {
-                            let phase = if rows_done <= grid_rows {
-                                "main"
-                            } else {
-                                "structures"
-                            };
-                            let structures_done = rows_done.saturating_sub(grid_rows);
-                            let suffix = if phase == "structures" && struct_estimate > 0 {
-                                if structures_done <= struct_estimate {
-                                    format!(
-                                        " ({} / {} structure rows)",
-                                        structures_done, struct_estimate
-                                    )
-                                } else {
-                                    format!(
-                                        " ({} structure rows so far; est {})",
-                                        structures_done, struct_estimate
-                                    )
-                                }
-                            } else {
-                                String::new()
-                            };
-                            let count_display = if phase == "main" {
-                                grid_rows.min(rows_done)
-                            } else {
-                                structures_done
-                            };
+                            counters_cb.rows_done.store(rows_done, Ordering::Relaxed);
                             let _ = tx_prog_cb.send(MigrationProgress {
                                 total: total_sheets_cb,
                                 completed: idx,
-                                message: format!(
-                                    "{} ({}): {} rows...{}",
-                                    sheet_name_for_cb, phase, count_display, suffix
-                                ),
+                                message: row_progress_message(&sheet_name_for_cb, rows_done, grid_rows, struct_estimate),
                             });
                         };
                         match MigrationTools::migrate_sheet_from_json(
@@ -165,6 +151,8 @@ pub fn handle_migration_requests(
                             sheet_name,
                             Some(idx as i32),
                             Some(&mut row_notifier),
+                            Some(&thread_counters.cancel),
+                            None,
                             &daemon_client,
                         ) {
                             Ok(_) => {
@@ -207,6 +195,15 @@ pub fn handle_migration_requests(
                                     message: completion_msg,
                                 });
                             }
+                            Err(DbError::Cancelled) => {
+                                report.cancelled = true;
+                                let _ = tx_prog.send(MigrationProgress {
+                                    total: total_sheets,
+                                    completed: idx,
+                                    message: format!("Cancelled while migrating '{}'", sheet_name),
+                                });
+                                break;
+                            }
                             Err(e) => {
                                 report.sheets_failed += 1;
                                 report
@@ -280,5 +277,6 @@ pub fn handle_migration_requests(
 
         bg_state.progress_rx = Some(Arc::new(Mutex::new(rx_prog)));
         bg_state.completion_rx = Some(Arc::new(Mutex::new(rx_done)));
+        bg_state.counters = Some(counters);
     }
 }
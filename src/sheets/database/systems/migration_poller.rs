@@ -4,6 +4,7 @@ use super::MigrationBackgroundState;
 use crate::sheets::events::{MigrationCompleted, MigrationProgress, SheetOperationFeedback};
 use crate::sheets::database::daemon_resource::SharedDaemonClient;
 use bevy::prelude::*;
+use std::sync::atomic::Ordering;
 
 /// Poll the background migration thread for progress updates and completion
 pub fn poll_migration_background(
@@ -15,22 +16,37 @@ pub fn poll_migration_background(
     mut data_modified_writer: EventWriter<crate::sheets::events::SheetDataModifiedInRegistryEvent>,
     mut revalidate_writer: EventWriter<crate::sheets::events::RequestSheetRevalidation>,
     mut editor_state: Option<ResMut<crate::ui::elements::editor::state::EditorWindowState>>,
+    mut migration_popup: ResMut<crate::ui::elements::popups::MigrationPopupState>,
     daemon_client: Res<SharedDaemonClient>,
 ) {
+    // Mirror the thread's row counters into the popup and forward a cancel request
+    if let Some(counters) = &bg_state.counters {
+        if migration_popup.cancel_requested {
+            counters.cancel.store(true, Ordering::Relaxed);
+        }
+        migration_popup.table_rows_done = counters.rows_done.load(Ordering::Relaxed);
+        migration_popup.table_rows_total = counters.rows_total.load(Ordering::Relaxed);
+    }
+
     // Drain any progress updates
     if let Some(rx) = &bg_state.progress_rx {
         if let Ok(rx) = rx.lock() {
+            let mut latest_message: Option<String> = None;
             for msg in rx.try_iter() {
-                // For single-file imports, also mirror progress to feedback log instead of popup
                 if bg_state.post_select.is_some() {
-                    feedback_writer.write(SheetOperationFeedback {
-                        message: msg.message.clone(),
-                        is_error: false,
-                    });
                     info!("{}", msg.message);
                 }
+                latest_message = Some(msg.message.clone());
                 progress_writer.write(msg);
             }
+            // For single-file imports, mirror progress to the feedback line instead of the popup.
+            // Only the newest message per frame, so the line shows the current "Inserted X of N rows".
+            if let (Some(message), true) = (latest_message, bg_state.post_select.is_some()) {
+                feedback_writer.write(SheetOperationFeedback {
+                    message,
+                    is_error: false,
+                });
+            }
         }
     }
 
@@ -40,10 +56,17 @@ pub fn poll_migration_background(
         if let Some(res) = result {
             match res {
                 Ok((report, db_path)) => {
-                    let success_msg = format!(
-                        "Migration completed! {} sheets migrated, {} failed",
-                        report.sheets_migrated, report.sheets_failed
-                    );
+                    let success_msg = if report.cancelled {
+                        format!(
+                            "Migration cancelled: {} sheets migrated before cancelling, {} failed",
+                            report.sheets_migrated, report.sheets_failed
+                        )
+                    } else {
+                        format!(
+                            "Migration completed! {} sheets migrated, {} failed",
+                            report.sheets_migrated, report.sheets_failed
+                        )
+                    };
                     info!("{}", success_msg);
                     if !report.failed_sheets.is_empty() {
                         for (name, err) in report.failed_sheets {
@@ -118,6 +141,7 @@ pub fn poll_migration_background(
                     completed_writer.write(MigrationCompleted {});
                     bg_state.progress_rx = None;
                     bg_state.completion_rx = None;
+                    bg_state.counters = None;
                 }
                 Err(err) => {
                     let error_msg = format!("Migration failed: {}", err);
@@ -129,6 +153,7 @@ pub fn poll_migration_background(
                     completed_writer.write(MigrationCompleted {});
                     bg_state.progress_rx = None;
                     bg_state.completion_rx = None;
+                    bg_state.counters = None;
                 }
             }
         }
@@ -3,13 +3,23 @@
 mod background_state;
 mod completion_handler;
 mod export_handler;
+mod import_handler;
+mod insert_progress;
+mod maintenance_handler;
 mod migration_handler;
 mod migration_poller;
 mod upload_handler;
 
-pub use background_state::MigrationBackgroundState;
+pub use background_state::{MigrationBackgroundState, MigrationCounters};
 pub use completion_handler::handle_migration_completion;
-pub use export_handler::handle_export_requests;
+pub use export_handler::{
+    handle_csv_export_requests, handle_export_requests, handle_jsonl_export_requests,
+    handle_parquet_export_requests, handle_project_export_requests,
+};
+pub use import_handler::handle_project_import_requests;
+pub use maintenance_handler::{
+    handle_compact_database_requests, handle_validate_structure_links_requests,
+};
 pub use migration_handler::handle_migration_requests;
 pub use migration_poller::poll_migration_background;
 pub use upload_handler::handle_upload_json_to_current_db;
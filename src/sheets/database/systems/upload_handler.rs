@@ -1,16 +1,17 @@
 // src/sheets/database/systems/upload_handler.rs
 
 use super::super::migration::MigrationTools;
+use super::insert_progress::row_progress_message;
 use super::MigrationBackgroundState;
 use crate::sheets::events::{MigrationProgress, RequestUploadJsonToCurrentDb, SheetOperationFeedback};
 use crate::sheets::systems::io::get_default_data_base_path;
 use bevy::prelude::*;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::mpsc::channel;
 use std::sync::{Arc, Mutex};
 use std::thread;
 
-/// Handle uploading a single JSON file and migrating it into the current database
+/// Handle uploading a single JSON (or CSV/TSV) file and migrating it into the current database
 pub fn handle_upload_json_to_current_db(
     mut events: EventReader<RequestUploadJsonToCurrentDb>,
     mut feedback_writer: EventWriter<SheetOperationFeedback>,
@@ -21,10 +22,12 @@ pub fn handle_upload_json_to_current_db(
 
         info!("Initiating JSON file upload for database '{}'", db_name);
 
-        // Open file dialog for JSON file
+        // Open file dialog for JSON or CSV file
         let picked_file: Option<PathBuf> = rfd::FileDialog::new()
+            .add_filter("Sheet files", &["json", "csv", "tsv"])
             .add_filter("JSON files", &["json"])
-            .set_title("Select JSON sheet file to import")
+            .add_filter("CSV files", &["csv", "tsv"])
+            .set_title("Select JSON or CSV sheet file to import")
             .pick_file();
 
         let json_path = match picked_file {
@@ -40,6 +43,10 @@ pub fn handle_upload_json_to_current_db(
                     });
                     continue;
                 }
+                if is_csv_file(&path) {
+                    start_csv_upload(&path, db_name, &mut feedback_writer, &mut bg_state);
+                    continue;
+                }
                 path
             }
             None => {
@@ -181,39 +188,10 @@ pub fn handle_upload_json_to_current_db(
                 let table_name_cb = table_name_clone.clone();
                 let tx_prog_cb = tx_prog.clone();
                 let mut row_notifier = move |rows_done: usize| {
-                    let phase = if rows_done <= grid_rows {
-                        "main"
-                    } else {
-                        "structures"
-                    };
-                    let structures_done = rows_done.saturating_sub(grid_rows);
-                    let suffix = if phase == "structures" && struct_estimate > 0 {
-                        if structures_done <= struct_estimate {
-                            format!(
-                                " ({} / {} structure rows)",
-                                structures_done, struct_estimate
-                            )
-                        } else {
-                            format!(
-                                " ({} structure rows so far; est {})",
-                                structures_done, struct_estimate
-                            )
-                        }
-                    } else {
-                        String::new()
-                    };
-                    let count_display = if phase == "main" {
-                        grid_rows.min(rows_done)
-                    } else {
-                        structures_done
-                    };
                     let _ = tx_prog_cb.send(MigrationProgress {
                         total: 1,
                         completed: 0,
-                        message: format!(
-                            "{} ({}): {} rows...{}",
-                            table_name_cb, phase, count_display, suffix
-                        ),
+                        message: row_progress_message(&table_name_cb, rows_done, grid_rows, struct_estimate),
                     });
                 };
 
@@ -224,6 +202,8 @@ pub fn handle_upload_json_to_current_db(
                     &table_name_clone,
                     None,
                     Some(&mut row_notifier),
+                    None,
+                    None,
                     &daemon_client,
                 ) {
                     Ok(_) => {
@@ -287,3 +267,91 @@ pub fn handle_upload_json_to_current_db(
         bg_state.post_select = Some((db_name.clone(), table_name.clone()));
     }
 }
+
+fn is_csv_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .map_or(false, |e| e.eq_ignore_ascii_case("csv") || e.eq_ignore_ascii_case("tsv"))
+}
+
+/// Import a CSV/TSV file as a new table in the background, reporting through the same
+/// progress/completion channels as JSON uploads
+fn start_csv_upload(
+    csv_path: &Path,
+    db_name: &str,
+    feedback_writer: &mut EventWriter<SheetOperationFeedback>,
+    bg_state: &mut MigrationBackgroundState,
+) {
+    let table_name = csv_path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("imported_sheet")
+        .to_string();
+    let db_path = get_default_data_base_path().join(format!("{}.db", db_name));
+    if !db_path.exists() {
+        feedback_writer.write(SheetOperationFeedback {
+            message: format!("Database '{}' not found", db_name),
+            is_error: true,
+        });
+        return;
+    }
+    feedback_writer.write(SheetOperationFeedback {
+        message: format!(
+            "CSV import started for '{}' into database '{}'",
+            table_name, db_name
+        ),
+        is_error: false,
+    });
+
+    let (tx_prog, rx_prog) = channel::<MigrationProgress>();
+    let (tx_done, rx_done) =
+        channel::<Result<(super::super::migration::MigrationReport, PathBuf), String>>();
+    let csv_path = csv_path.to_path_buf();
+    let table_name_clone = table_name.clone();
+
+    thread::spawn(move || {
+        let run = || -> Result<(super::super::migration::MigrationReport, PathBuf), String> {
+            let _ = tx_prog.send(MigrationProgress {
+                total: 1,
+                completed: 0,
+                message: format!("Importing '{}'...", table_name_clone),
+            });
+            let mut conn = rusqlite::Connection::open(&db_path).map_err(|e| e.to_string())?;
+            let daemon_path = get_default_data_base_path().join("daemon.sock");
+            let daemon_client = super::super::daemon_client::DaemonClient::new(
+                None,
+                daemon_path.to_string_lossy().to_string(),
+            );
+            let mut report = super::super::migration::MigrationReport::default();
+            let message = match MigrationTools::migrate_sheet_from_csv(
+                &mut conn,
+                &csv_path,
+                &table_name_clone,
+                &daemon_client,
+            ) {
+                Ok(()) => {
+                    report.sheets_migrated += 1;
+                    format!("Completed '{}'", table_name_clone)
+                }
+                Err(e) => {
+                    report.sheets_failed += 1;
+                    report
+                        .failed_sheets
+                        .push((table_name_clone.clone(), e.to_string()));
+                    format!("Failed '{}'", table_name_clone)
+                }
+            };
+            let _ = tx_prog.send(MigrationProgress {
+                total: 1,
+                completed: 1,
+                message,
+            });
+            Ok((report, db_path))
+        }();
+        let _ = tx_done.send(run);
+    });
+
+    bg_state.progress_rx = Some(Arc::new(Mutex::new(rx_prog)));
+    bg_state.completion_rx = Some(Arc::new(Mutex::new(rx_done)));
+    bg_state.post_select = Some((db_name.to_string(), table_name));
+}
@@ -3,8 +3,12 @@
 
 use bevy::prelude::*;
 use rusqlite::Connection;
+use std::collections::HashMap;
 
-use super::error::DbResult;
+use super::daemon_client::{DaemonClient, Statement};
+use super::error::{DbError, DbResult};
+use super::migration::parent_key_helpers::resolve_text_row_index_by_meta;
+use super::schema::queries::table_exists;
 
 #[derive(Debug, Clone)]
 pub struct RowIndexValidationResult {
@@ -247,30 +251,247 @@ pub fn log_validation_report(results: &[RowIndexValidationResult]) {
     }
 }
 
+/// What `validate_structure_links` does with orphaned child rows. Re-linking runs first;
+/// `delete` then removes the orphans that could not be re-linked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct OrphanFix {
+    pub relink: bool,
+    pub delete: bool,
+}
+
+/// A structure child row whose parent_key matches no row_index of its parent table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrphanedChildRow {
+    pub id: i64,
+    pub row_index: i64,
+    pub parent_key: String,
+    /// Parent row the key resolves to when it is a parent's key value rather than a
+    /// row_index (data written before parent keys moved to row_index)
+    pub relink_to: Option<i64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct StructureLinkReport {
+    pub parent_table: String,
+    pub child_table: String,
+    pub child_rows: i64,
+    pub orphans: Vec<OrphanedChildRow>,
+    pub relinked: usize,
+    pub deleted: usize,
+}
+
+impl StructureLinkReport {
+    pub fn summary(&self) -> String {
+        if self.orphans.is_empty() {
+            return format!(
+                "✓ '{}' -> '{}': {} child rows, all linked",
+                self.parent_table, self.child_table, self.child_rows
+            );
+        }
+        let mut text = format!(
+            "⚠ '{}' -> '{}': {} of {} child rows orphaned",
+            self.parent_table,
+            self.child_table,
+            self.orphans.len(),
+            self.child_rows
+        );
+        if self.relinked > 0 {
+            text.push_str(&format!(", {} re-linked", self.relinked));
+        }
+        if self.deleted > 0 {
+            text.push_str(&format!(", {} deleted", self.deleted));
+        }
+        text
+    }
+}
+
+/// Structure (child, parent) pairs, parents before their children so orphans created by
+/// deleting orphaned rows are found further down in the same run
+fn structure_table_pairs(conn: &Connection) -> DbResult<Vec<(String, String)>> {
+    let pairs: Vec<(String, String)> = conn
+        .prepare(
+            "SELECT table_name, parent_table FROM _Metadata
+             WHERE table_type = 'structure' AND parent_table IS NOT NULL",
+        )?
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<Result<Vec<_>, _>>()?;
+    let parent_of: HashMap<&str, &str> = pairs
+        .iter()
+        .map(|(child, parent)| (child.as_str(), parent.as_str()))
+        .collect();
+    let depth = |table: &str| {
+        let mut depth = 0;
+        let mut current = table;
+        while let Some(parent) = parent_of.get(current) {
+            depth += 1;
+            current = parent;
+            if depth > parent_of.len() {
+                break; // cycle in broken metadata
+            }
+        }
+        depth
+    };
+    let mut ordered = pairs.clone();
+    ordered.sort_by_key(|(child, _)| depth(child));
+    Ok(ordered)
+}
+
+fn find_orphans(conn: &Connection, child_table: &str, parent_table: &str) -> DbResult<Vec<OrphanedChildRow>> {
+    let mut orphans: Vec<OrphanedChildRow> = conn
+        .prepare(&format!(
+            "SELECT c.id, c.row_index, COALESCE(c.parent_key, '') FROM \"{}\" c
+             WHERE NOT EXISTS (
+                 SELECT 1 FROM \"{}\" p WHERE CAST(p.row_index AS TEXT) = TRIM(c.parent_key)
+             )
+             ORDER BY c.row_index",
+            child_table, parent_table
+        ))?
+        .query_map([], |row| {
+            Ok(OrphanedChildRow {
+                id: row.get(0)?,
+                row_index: row.get(1)?,
+                parent_key: row.get(2)?,
+                relink_to: None,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    for orphan in &mut orphans {
+        let key = orphan.parent_key.trim();
+        if !key.is_empty() && key.parse::<i64>().is_err() {
+            orphan.relink_to = resolve_text_row_index_by_meta(conn, parent_table, key)?;
+        }
+    }
+    Ok(orphans)
+}
+
+/// Fix applier for `validate_structure_links` that writes through the daemon as one batch
+pub fn daemon_fix_applier<'a>(
+    client: &'a DaemonClient,
+    db_name: Option<&'a str>,
+) -> impl FnMut(Vec<Statement>) -> DbResult<()> + 'a {
+    move |statements| {
+        if statements.is_empty() {
+            return Ok(());
+        }
+        let response = client.exec_batch(statements, db_name).map_err(DbError::Other)?;
+        match response.error {
+            Some(err) => Err(DbError::Other(err)),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Check that every structure child row points at an existing parent row (`parent_key` =
+/// parent `row_index`) and optionally repair the orphans. The repair statements of each
+/// child table are handed to `apply_fixes` (see `daemon_fix_applier`; the CLI runs them
+/// on its own connection).
+pub fn validate_structure_links(
+    conn: &Connection,
+    fix: OrphanFix,
+    mut apply_fixes: impl FnMut(Vec<Statement>) -> DbResult<()>,
+) -> DbResult<Vec<StructureLinkReport>> {
+    let mut reports = Vec::new();
+    for (child_table, parent_table) in structure_table_pairs(conn)? {
+        if !table_exists(conn, &child_table)? || !table_exists(conn, &parent_table)? {
+            warn!(
+                "Skipping structure link check '{}' -> '{}': table missing",
+                parent_table, child_table
+            );
+            continue;
+        }
+        let child_rows: i64 = conn.query_row(
+            &format!("SELECT COUNT(*) FROM \"{}\"", child_table),
+            [],
+            |row| row.get(0),
+        )?;
+        let orphans = find_orphans(conn, &child_table, &parent_table)?;
+
+        let mut statements = Vec::new();
+        let (mut relinked, mut deleted) = (0, 0);
+        for orphan in &orphans {
+            match orphan.relink_to {
+                Some(parent_index) if fix.relink => {
+                    statements.push(Statement {
+                        sql: format!("UPDATE \"{}\" SET parent_key = ? WHERE id = ?", child_table),
+                        params: vec![
+                            serde_json::Value::String(parent_index.to_string()),
+                            serde_json::Value::Number(orphan.id.into()),
+                        ],
+                    });
+                    relinked += 1;
+                }
+                _ if fix.delete => {
+                    statements.push(Statement {
+                        sql: format!("DELETE FROM \"{}\" WHERE id = ?", child_table),
+                        params: vec![serde_json::Value::Number(orphan.id.into())],
+                    });
+                    deleted += 1;
+                }
+                _ => {}
+            }
+        }
+        if !statements.is_empty() {
+            apply_fixes(statements)?;
+        }
+
+        reports.push(StructureLinkReport {
+            parent_table,
+            child_table,
+            child_rows,
+            orphans,
+            relinked,
+            deleted,
+        });
+    }
+    Ok(reports)
+}
+
+/// Log a structure link check, listing the first orphans of each table
+pub fn log_structure_link_report(reports: &[StructureLinkReport]) {
+    for report in reports {
+        if report.orphans.is_empty() {
+            debug!("{}", report.summary());
+            continue;
+        }
+        warn!("{}", report.summary());
+        for orphan in report.orphans.iter().take(5) {
+            warn!(
+                "  Orphan: id={} row_index={} parent_key='{}'{}",
+                orphan.id,
+                orphan.row_index,
+                orphan.parent_key,
+                orphan
+                    .relink_to
+                    .map(|p| format!(" (matches parent row {})", p))
+                    .unwrap_or_default()
+            );
+        }
+        if report.orphans.len() > 5 {
+            warn!("  ... and {} more orphans", report.orphans.len() - 5);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::sheets::database::writer::test_helpers::{exec_fixture_sql, run_statements};
 
     #[test]
     fn test_validation() {
         let conn = Connection::open_in_memory().unwrap();
-        
-        // Create test table
-        conn.execute(
+
+        // Test table with a duplicated and a missing row_index
+        exec_fixture_sql(
+            &conn,
             "CREATE TABLE test_table (
                 id INTEGER PRIMARY KEY,
                 row_index INTEGER,
                 data TEXT
-            )",
-            [],
-        ).unwrap();
-
-        // Insert test data with duplicates
-        conn.execute("INSERT INTO test_table (id, row_index, data) VALUES (1, 0, 'a')", []).unwrap();
-        conn.execute("INSERT INTO test_table (id, row_index, data) VALUES (2, 1, 'b')", []).unwrap();
-        conn.execute("INSERT INTO test_table (id, row_index, data) VALUES (3, 1, 'c')", []).unwrap();
-        conn.execute("INSERT INTO test_table (id, row_index, data) VALUES (4, 2, 'd')", []).unwrap();
-        conn.execute("INSERT INTO test_table (id, row_index, data) VALUES (5, NULL, 'e')", []).unwrap();
+            );
+            INSERT INTO test_table (id, row_index, data) VALUES
+                (1, 0, 'a'), (2, 1, 'b'), (3, 1, 'c'), (4, 2, 'd'), (5, NULL, 'e');",
+        );
 
         let result = validate_table_row_index(&conn, "test_table").unwrap();
         
@@ -283,4 +504,53 @@ mod tests {
         assert_eq!(result.duplicates[0].row_index, 1);
         assert_eq!(result.duplicates[0].count, 2);
     }
+
+    #[test]
+    fn orphaned_structure_rows_are_found_and_repaired() {
+        let conn = Connection::open_in_memory().unwrap();
+        exec_fixture_sql(
+            &conn,
+            "CREATE TABLE _Metadata (table_name TEXT PRIMARY KEY, table_type TEXT, parent_table TEXT);
+             INSERT INTO _Metadata VALUES ('Items', 'main', NULL), ('Items_Parts', 'structure', 'Items');
+             CREATE TABLE Items (id INTEGER PRIMARY KEY, row_index INTEGER, Name TEXT);
+             INSERT INTO Items (row_index, Name) VALUES (1, 'Sword'), (2, 'Axe');
+             CREATE TABLE Items_Parts (id INTEGER PRIMARY KEY, row_index INTEGER, parent_key TEXT, Part TEXT);
+             INSERT INTO Items_Parts (row_index, parent_key, Part) VALUES
+                 (1, '1', 'Blade'), (2, '7', 'Head'), (3, 'Axe', 'Haft');",
+        );
+        let report_only = |_: Vec<Statement>| -> DbResult<()> { panic!("nothing to repair") };
+
+        let reports = validate_structure_links(&conn, OrphanFix::default(), report_only).unwrap();
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].child_rows, 3);
+        // '7' points at a deleted parent; 'Axe' is a key value that resolves to row 2
+        let orphans: Vec<(&str, Option<i64>)> = reports[0]
+            .orphans
+            .iter()
+            .map(|o| (o.parent_key.as_str(), o.relink_to))
+            .collect();
+        assert_eq!(orphans, vec![("7", None), ("Axe", Some(2))]);
+        assert_eq!((reports[0].relinked, reports[0].deleted), (0, 0));
+
+        let fix = OrphanFix { relink: true, delete: true };
+        let reports = validate_structure_links(&conn, fix, |statements| {
+            run_statements(&conn, statements);
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!((reports[0].relinked, reports[0].deleted), (1, 1));
+        let remaining: Vec<(String, String)> = conn
+            .prepare("SELECT parent_key, Part FROM Items_Parts ORDER BY row_index")
+            .unwrap()
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(
+            remaining,
+            vec![("1".to_string(), "Blade".to_string()), ("2".to_string(), "Haft".to_string())]
+        );
+        let reports = validate_structure_links(&conn, OrphanFix::default(), report_only).unwrap();
+        assert!(reports[0].orphans.is_empty());
+    }
 }
@@ -0,0 +1,217 @@
+// src/sheets/database/writer/comments.rs
+// Cell comments - sidecar `{table}_Comments` table keyed by (row_index, column_name)
+
+use super::super::error::DbResult;
+use super::super::schema::queries::table_exists;
+use super::daemon_utils::daemon_error_to_rusqlite;
+use super::helpers::{comments_table_name, quote_identifier};
+use crate::sheets::database::daemon_client::Statement;
+use rusqlite::Connection;
+
+/// Create the comments table of `table_name` if it does not exist yet.
+pub(super) fn create_comments_table_statement(table_name: &str) -> Statement {
+    Statement {
+        sql: format!(
+            "CREATE TABLE IF NOT EXISTS {} (
+                row_index INTEGER NOT NULL,
+                column_name TEXT NOT NULL,
+                comment TEXT NOT NULL,
+                updated_at TEXT DEFAULT CURRENT_TIMESTAMP,
+                PRIMARY KEY (row_index, column_name)
+            )",
+            quote_identifier(&comments_table_name(table_name))
+        ),
+        params: vec![],
+    }
+}
+
+/// Insert or replace the comment of one cell, creating the comments table on first use.
+pub fn upsert_cell_comment(
+    table_name: &str,
+    row_index: i64,
+    column_name: &str,
+    comment: &str,
+    db_filename: Option<&str>,
+    daemon_client: &super::super::daemon_client::DaemonClient,
+) -> DbResult<()> {
+    let statements = vec![
+        create_comments_table_statement(table_name),
+        Statement {
+            sql: format!(
+                "INSERT INTO {} (row_index, column_name, comment) VALUES (?, ?, ?)
+                 ON CONFLICT(row_index, column_name)
+                 DO UPDATE SET comment = excluded.comment, updated_at = CURRENT_TIMESTAMP",
+                quote_identifier(&comments_table_name(table_name))
+            ),
+            params: vec![
+                serde_json::Value::Number(row_index.into()),
+                serde_json::Value::String(column_name.to_string()),
+                serde_json::Value::String(comment.to_string()),
+            ],
+        },
+    ];
+    daemon_client
+        .exec_batch(statements, db_filename)
+        .map_err(daemon_error_to_rusqlite)?;
+    Ok(())
+}
+
+/// Remove the comment of one cell. No-op when the table has no comments table.
+pub fn delete_cell_comment(
+    conn: &Connection,
+    table_name: &str,
+    row_index: i64,
+    column_name: &str,
+    db_filename: Option<&str>,
+    daemon_client: &super::super::daemon_client::DaemonClient,
+) -> DbResult<()> {
+    delete_comments_where(
+        conn,
+        table_name,
+        "row_index = ? AND column_name = ?",
+        vec![
+            serde_json::Value::Number(row_index.into()),
+            serde_json::Value::String(column_name.to_string()),
+        ],
+        db_filename,
+        daemon_client,
+    )
+}
+
+/// Remove every comment attached to the given rows (called when rows are deleted).
+pub fn delete_row_comments(
+    conn: &Connection,
+    table_name: &str,
+    row_indices: &[i64],
+    db_filename: Option<&str>,
+    daemon_client: &super::super::daemon_client::DaemonClient,
+) -> DbResult<()> {
+    let comments_table = comments_table_name(table_name);
+    if row_indices.is_empty() || !table_exists(conn, &comments_table)? {
+        return Ok(());
+    }
+    let statements = row_indices
+        .iter()
+        .map(|row_index| Statement {
+            sql: format!(
+                "DELETE FROM {} WHERE row_index = ?",
+                quote_identifier(&comments_table)
+            ),
+            params: vec![serde_json::Value::Number((*row_index).into())],
+        })
+        .collect();
+    daemon_client
+        .exec_batch(statements, db_filename)
+        .map_err(daemon_error_to_rusqlite)?;
+    Ok(())
+}
+
+/// Remove every comment of a column (called when the column is deleted).
+pub fn delete_column_comments(
+    conn: &Connection,
+    table_name: &str,
+    column_name: &str,
+    db_filename: Option<&str>,
+    daemon_client: &super::super::daemon_client::DaemonClient,
+) -> DbResult<()> {
+    delete_comments_where(
+        conn,
+        table_name,
+        "column_name = ?",
+        vec![serde_json::Value::String(column_name.to_string())],
+        db_filename,
+        daemon_client,
+    )
+}
+
+/// Re-key comments after a column rename so they stay on the same cells.
+pub fn rename_comment_column(
+    conn: &Connection,
+    table_name: &str,
+    old_name: &str,
+    new_name: &str,
+    db_filename: Option<&str>,
+    daemon_client: &super::super::daemon_client::DaemonClient,
+) -> DbResult<()> {
+    let comments_table = comments_table_name(table_name);
+    if old_name == new_name || !table_exists(conn, &comments_table)? {
+        return Ok(());
+    }
+    let stmt = Statement {
+        sql: format!(
+            "UPDATE {} SET column_name = ? WHERE column_name = ?",
+            quote_identifier(&comments_table)
+        ),
+        params: vec![
+            serde_json::Value::String(new_name.to_string()),
+            serde_json::Value::String(old_name.to_string()),
+        ],
+    };
+    daemon_client
+        .exec_batch(vec![stmt], db_filename)
+        .map_err(daemon_error_to_rusqlite)?;
+    Ok(())
+}
+
+/// Statements moving comments from old to new row_index values, in the same two phases
+/// as the data table (negative placeholders first) so the primary key never collides.
+pub fn comment_remap_statements(table_name: &str, changed: &[(i64, i64)]) -> Vec<Statement> {
+    let comments_table = quote_identifier(&comments_table_name(table_name));
+    let mut statements: Vec<Statement> = changed
+        .iter()
+        .map(|(old, new)| Statement {
+            sql: format!("UPDATE {} SET row_index = ? WHERE row_index = ?", comments_table),
+            params: vec![
+                serde_json::Value::Number((-(new + 1)).into()),
+                serde_json::Value::Number((*old).into()),
+            ],
+        })
+        .collect();
+    statements.push(Statement {
+        sql: format!(
+            "UPDATE {} SET row_index = -row_index - 1 WHERE row_index < 0",
+            comments_table
+        ),
+        params: vec![],
+    });
+    statements
+}
+
+/// Statement removing comments whose row is no longer in `table_name`; appended to batches
+/// that replace a table's rows wholesale.
+pub fn orphaned_comments_statement(table_name: &str) -> Statement {
+    Statement {
+        sql: format!(
+            "DELETE FROM {} WHERE row_index NOT IN (SELECT row_index FROM {})",
+            quote_identifier(&comments_table_name(table_name)),
+            quote_identifier(table_name)
+        ),
+        params: vec![],
+    }
+}
+
+fn delete_comments_where(
+    conn: &Connection,
+    table_name: &str,
+    where_clause: &str,
+    params: Vec<serde_json::Value>,
+    db_filename: Option<&str>,
+    daemon_client: &super::super::daemon_client::DaemonClient,
+) -> DbResult<()> {
+    let comments_table = comments_table_name(table_name);
+    if !table_exists(conn, &comments_table)? {
+        return Ok(());
+    }
+    let stmt = Statement {
+        sql: format!(
+            "DELETE FROM {} WHERE {}",
+            quote_identifier(&comments_table),
+            where_clause
+        ),
+        params,
+    };
+    daemon_client
+        .exec_batch(vec![stmt], db_filename)
+        .map_err(daemon_error_to_rusqlite)?;
+    Ok(())
+}
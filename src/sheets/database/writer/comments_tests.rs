@@ -0,0 +1,66 @@
+// src/sheets/database/writer/comments_tests.rs
+// Tests for the cell comments sidecar table
+
+#![cfg(test)]
+
+use super::comments::{
+    comment_remap_statements, create_comments_table_statement, orphaned_comments_statement,
+};
+use super::test_helpers::{exec_fixture_sql, run_statements};
+use rusqlite::Connection;
+
+#[test]
+fn remap_moves_comments_with_their_rows() {
+    let conn = Connection::open_in_memory().unwrap();
+    run_statements(&conn, vec![create_comments_table_statement("Items")]);
+    exec_fixture_sql(
+        &conn,
+        "INSERT INTO Items_Comments (row_index, column_name, comment) VALUES
+            (0, 'Name', 'first'), (1, 'Name', 'second'), (2, 'Name', 'third');",
+    );
+
+    // Reverse the three rows: a naive one-pass update would hit the primary key
+    run_statements(&conn, comment_remap_statements("Items", &[(0, 2), (2, 0)]));
+
+    let mut stmt = conn
+        .prepare("SELECT row_index, comment FROM Items_Comments ORDER BY row_index")
+        .unwrap();
+    let rows: Vec<(i64, String)> = stmt
+        .query_map([], |r| Ok((r.get(0)?, r.get(1)?)))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(
+        rows,
+        vec![
+            (0, "third".to_string()),
+            (1, "second".to_string()),
+            (2, "first".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn orphaned_comments_are_dropped_with_their_rows() {
+    let conn = Connection::open_in_memory().unwrap();
+    run_statements(&conn, vec![create_comments_table_statement("Items")]);
+    exec_fixture_sql(
+        &conn,
+        "CREATE TABLE Items (row_index INTEGER PRIMARY KEY, Name TEXT);
+         INSERT INTO Items (row_index, Name) VALUES (0, 'Bolt'), (2, 'Nut');
+         INSERT INTO Items_Comments (row_index, column_name, comment) VALUES
+            (0, 'Name', 'kept'), (1, 'Name', 'orphaned'), (2, 'Name', 'kept too');",
+    );
+
+    run_statements(&conn, vec![orphaned_comments_statement("Items")]);
+
+    let mut stmt = conn
+        .prepare("SELECT row_index FROM Items_Comments ORDER BY row_index")
+        .unwrap();
+    let rows: Vec<i64> = stmt
+        .query_map([], |r| r.get(0))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(rows, vec![0, 2]);
+}
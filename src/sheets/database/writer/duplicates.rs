@@ -0,0 +1,360 @@
+// src/sheets/database/writer/duplicates.rs
+// Duplication operations - copying a table and its structure descendants under a new name
+
+use super::super::error::{DbError, DbResult};
+use super::super::schema::queries::{get_table_columns, table_exists};
+use super::daemon_utils::daemon_error_to_rusqlite;
+use super::helpers::{build_placeholders, metadata_table_name, quote_column_list, quote_identifier, with_transaction};
+use crate::sheets::database::daemon_client::{DaemonClient, Statement};
+use rusqlite::{types::ValueRef, Connection, OptionalExtension};
+use std::collections::HashMap;
+
+/// Rows sent to the daemon per `exec_batch` call while copying data.
+const COPY_CHUNK_ROWS: usize = 1000;
+
+/// One table of the duplicated tree: source name, copy name and the copy's parent
+/// (`None` for the main table).
+struct TableCopy {
+    source: String,
+    target: String,
+    target_parent: Option<String>,
+    source_parent: Option<String>,
+}
+
+/// Re-target a `CREATE TABLE` statement from sqlite_master at `new_name`, keeping the
+/// column list and constraints.
+pub(super) fn retarget_create_sql(create_sql: &str, new_name: &str) -> Option<String> {
+    let columns_start = create_sql.find('(')?;
+    Some(format!(
+        "CREATE TABLE IF NOT EXISTS {} {}",
+        quote_identifier(new_name),
+        &create_sql[columns_start..]
+    ))
+}
+
+/// Gap-free row_index values for a copied table, keyed by the source row_index.
+/// `source_indices` must be in ascending order so the copy keeps the row order.
+pub(super) fn fresh_row_indices(source_indices: &[i64]) -> HashMap<i64, i64> {
+    source_indices
+        .iter()
+        .enumerate()
+        .map(|(new_idx, &old_idx)| (old_idx, new_idx as i64))
+        .collect()
+}
+
+fn value_to_json(value: ValueRef<'_>) -> serde_json::Value {
+    match value {
+        ValueRef::Null => serde_json::Value::Null,
+        ValueRef::Integer(i) => serde_json::json!(i),
+        ValueRef::Real(f) => serde_json::json!(f),
+        ValueRef::Text(t) => serde_json::Value::String(String::from_utf8_lossy(t).into_owned()),
+        ValueRef::Blob(b) => serde_json::Value::String(String::from_utf8_lossy(b).into_owned()),
+    }
+}
+
+fn create_sql_of(conn: &Connection, table_name: &str) -> DbResult<Option<String>> {
+    Ok(conn
+        .query_row(
+            "SELECT sql FROM sqlite_master WHERE type = 'table' AND name = ?",
+            [table_name],
+            |r| r.get::<_, String>(0),
+        )
+        .optional()?)
+}
+
+/// Collect the source table and every structure descendant (parents before children),
+/// naming each copy `{copied_parent}_{parent_column}`.
+fn collect_table_tree(conn: &Connection, source_table: &str, target_table: &str) -> DbResult<Vec<TableCopy>> {
+    let mut tables = vec![TableCopy {
+        source: source_table.to_string(),
+        target: target_table.to_string(),
+        target_parent: None,
+        source_parent: None,
+    }];
+    let mut stmt = conn.prepare(
+        "SELECT table_name, parent_column FROM _Metadata WHERE table_type = 'structure' AND parent_table = ?",
+    )?;
+    let mut next = 0;
+    while next < tables.len() {
+        let (parent_source, parent_target) = (tables[next].source.clone(), tables[next].target.clone());
+        let children: Vec<(String, Option<String>)> = stmt
+            .query_map([parent_source.as_str()], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<Result<Vec<_>, _>>()?;
+        for (child_source, parent_column) in children {
+            let column = parent_column.unwrap_or_else(|| {
+                child_source
+                    .strip_prefix(&format!("{}_", parent_source))
+                    .unwrap_or(&child_source)
+                    .to_string()
+            });
+            tables.push(TableCopy {
+                target: format!("{}_{}", parent_target, column),
+                source: child_source,
+                target_parent: Some(parent_target.clone()),
+                source_parent: Some(parent_source.clone()),
+            });
+        }
+        next += 1;
+    }
+    Ok(tables)
+}
+
+/// Copy the `_Metadata` row of `copy.source` for `copy.target`, pointing structure tables
+/// at the copied parent and placing a copied main table at `display_order`.
+fn copy_global_metadata_statement(
+    metadata_columns: &[String],
+    copy: &TableCopy,
+    display_order: Option<i32>,
+) -> Statement {
+    let mut select_exprs = Vec::with_capacity(metadata_columns.len());
+    let mut params = Vec::new();
+    for column in metadata_columns {
+        match column.to_ascii_lowercase().as_str() {
+            "table_name" => {
+                select_exprs.push("?".to_string());
+                params.push(serde_json::json!(copy.target));
+            }
+            "parent_table" if copy.target_parent.is_some() => {
+                select_exprs.push("?".to_string());
+                params.push(serde_json::json!(copy.target_parent));
+            }
+            "display_order" if copy.target_parent.is_none() => {
+                select_exprs.push("?".to_string());
+                params.push(serde_json::json!(display_order));
+            }
+            "created_at" | "updated_at" => select_exprs.push("CURRENT_TIMESTAMP".to_string()),
+            _ => select_exprs.push(quote_identifier(column)),
+        }
+    }
+    params.push(serde_json::json!(copy.source));
+    Statement {
+        sql: format!(
+            "INSERT OR REPLACE INTO _Metadata ({}) SELECT {} FROM _Metadata WHERE table_name = ?",
+            quote_column_list(metadata_columns),
+            select_exprs.join(", ")
+        ),
+        params,
+    }
+}
+
+/// Copy all rows of `copy.source` into `copy.target` with fresh row_index values.
+/// Structure rows get their parent_key remapped through `parent_map`; rows whose parent
+/// is missing from the source are orphans and are not copied.
+/// Returns the source→copy row_index map for the copied table's own children.
+fn copy_table_rows(
+    conn: &Connection,
+    copy: &TableCopy,
+    parent_map: Option<&HashMap<i64, i64>>,
+    db_filename: Option<&str>,
+    daemon_client: &DaemonClient,
+) -> DbResult<HashMap<i64, i64>> {
+    let columns: Vec<String> = get_table_columns(conn, &copy.source)?
+        .into_iter()
+        .filter(|c| !c.eq_ignore_ascii_case("id"))
+        .collect();
+    let row_index_pos = columns
+        .iter()
+        .position(|c| c.eq_ignore_ascii_case("row_index"))
+        .ok_or_else(|| DbError::InvalidMetadata(format!("Table '{}' has no row_index column", copy.source)))?;
+    let parent_key_pos = columns.iter().position(|c| c.eq_ignore_ascii_case("parent_key"));
+
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {} FROM {} ORDER BY row_index",
+        quote_column_list(&columns),
+        quote_identifier(&copy.source)
+    ))?;
+    let mut rows: Vec<Vec<serde_json::Value>> = Vec::new();
+    let mut query = stmt.query([])?;
+    while let Some(row) = query.next()? {
+        let mut values = Vec::with_capacity(columns.len());
+        for i in 0..columns.len() {
+            values.push(value_to_json(row.get_ref(i)?));
+        }
+        rows.push(values);
+    }
+
+    let source_indices: Vec<i64> = rows.iter().filter_map(|r| r[row_index_pos].as_i64()).collect();
+    let row_map = fresh_row_indices(&source_indices);
+
+    let insert_sql = format!(
+        "INSERT INTO {} ({}) VALUES ({})",
+        quote_identifier(&copy.target),
+        quote_column_list(&columns),
+        build_placeholders(columns.len())
+    );
+    let mut batch: Vec<Statement> = Vec::with_capacity(rows.len().min(COPY_CHUNK_ROWS));
+    let mut orphans = 0usize;
+    for mut values in rows {
+        let Some(new_index) = values[row_index_pos].as_i64().and_then(|i| row_map.get(&i)) else {
+            continue;
+        };
+        values[row_index_pos] = serde_json::json!(new_index);
+        if let (Some(pos), Some(parent_map)) = (parent_key_pos, parent_map) {
+            let old_parent = match &values[pos] {
+                serde_json::Value::String(s) => s.trim().parse::<i64>().ok(),
+                other => other.as_i64(),
+            };
+            match old_parent.and_then(|p| parent_map.get(&p)) {
+                Some(new_parent) => values[pos] = serde_json::json!(new_parent.to_string()),
+                None => {
+                    orphans += 1;
+                    continue;
+                }
+            }
+        }
+        batch.push(Statement { sql: insert_sql.clone(), params: values });
+        if batch.len() >= COPY_CHUNK_ROWS {
+            daemon_client
+                .exec_batch(std::mem::take(&mut batch), db_filename)
+                .map_err(daemon_error_to_rusqlite)?;
+        }
+    }
+    if !batch.is_empty() {
+        daemon_client.exec_batch(batch, db_filename).map_err(daemon_error_to_rusqlite)?;
+    }
+    if orphans > 0 {
+        bevy::log::warn!(
+            "Duplicate '{}' -> '{}': skipped {} orphan row(s) whose parent row no longer exists",
+            copy.source, copy.target, orphans
+        );
+    }
+    Ok(row_map)
+}
+
+/// Duplicate a main table with its metadata, AI groups and all descendant structure tables.
+/// Copies get fresh row_index values and structure links point at the copied parents.
+/// Fails without writing anything if any copy name is already taken.
+/// Returns the (source, copy) table names, main table first.
+pub fn duplicate_table_and_descendants(
+    conn: &Connection,
+    source_table: &str,
+    target_table: &str,
+    db_filename: Option<&str>,
+    daemon_client: &DaemonClient,
+) -> DbResult<Vec<(String, String)>> {
+    if !table_exists(conn, source_table)? {
+        return Err(DbError::TableNotFound(source_table.to_string()));
+    }
+    let tables = collect_table_tree(conn, source_table, target_table)?;
+    for copy in &tables {
+        if table_exists(conn, &copy.target)? || table_exists(conn, &metadata_table_name(&copy.target))? {
+            return Err(DbError::Other(format!("Table '{}' already exists", copy.target)));
+        }
+    }
+    let metadata_columns = get_table_columns(conn, "_Metadata")?;
+    let next_order: Option<i32> = conn
+        .query_row(
+            "SELECT COALESCE(MAX(display_order), -1) + 1 FROM _Metadata WHERE table_type = 'main'",
+            [],
+            |r| r.get::<_, i32>(0),
+        )
+        .ok();
+
+    with_transaction(conn, daemon_client, |conn| {
+        bevy::log::info!(
+            "DB duplicate: '{}' -> '{}' ({} table(s) including descendants)",
+            source_table, target_table, tables.len()
+        );
+        let mut row_maps: HashMap<String, HashMap<i64, i64>> = HashMap::new();
+        for copy in &tables {
+            // Schema: data, per-table metadata and (optional) AI groups tables
+            let mut statements = Vec::new();
+            for (source, target) in [
+                (copy.source.clone(), copy.target.clone()),
+                (metadata_table_name(&copy.source), metadata_table_name(&copy.target)),
+                (format!("{}_AIGroups", copy.source), format!("{}_AIGroups", copy.target)),
+            ] {
+                let Some(create_sql) = create_sql_of(conn, &source)? else {
+                    continue;
+                };
+                let create_sql = retarget_create_sql(&create_sql, &target)
+                    .ok_or_else(|| DbError::Other(format!("Unrecognized schema for table '{}'", source)))?;
+                statements.push(Statement { sql: create_sql, params: vec![] });
+                if source != copy.source {
+                    statements.push(Statement {
+                        sql: format!("INSERT INTO {} SELECT * FROM {}", quote_identifier(&target), quote_identifier(&source)),
+                        params: vec![],
+                    });
+                }
+            }
+            let index_column = if copy.target_parent.is_some() { "parent_key" } else { "row_index" };
+            statements.push(Statement {
+                sql: format!(
+                    "CREATE INDEX IF NOT EXISTS idx_{}_{} ON {}({})",
+                    crate::sheets::database::schema::sanitize_identifier(&copy.target),
+                    index_column,
+                    quote_identifier(&copy.target),
+                    index_column
+                ),
+                params: vec![],
+            });
+            statements.push(copy_global_metadata_statement(&metadata_columns, copy, next_order));
+            daemon_client.exec_batch(statements, db_filename).map_err(daemon_error_to_rusqlite)?;
+
+            let parent_map = copy.source_parent.as_ref().and_then(|p| row_maps.get(p));
+            let row_map = copy_table_rows(conn, copy, parent_map, db_filename, daemon_client)?;
+            row_maps.insert(copy.source.clone(), row_map);
+        }
+        Ok(())
+    })?;
+
+    Ok(tables.into_iter().map(|t| (t.source, t.target)).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_sql_is_retargeted_with_constraints_intact() {
+        let sql = "CREATE TABLE \"Items_Tags\" (id INTEGER PRIMARY KEY AUTOINCREMENT, row_index INTEGER NOT NULL, parent_key TEXT NOT NULL, UNIQUE(parent_key, row_index))";
+        assert_eq!(
+            retarget_create_sql(sql, "Loot_Tags").unwrap(),
+            "CREATE TABLE IF NOT EXISTS \"Loot_Tags\" (id INTEGER PRIMARY KEY AUTOINCREMENT, row_index INTEGER NOT NULL, parent_key TEXT NOT NULL, UNIQUE(parent_key, row_index))"
+        );
+        assert!(retarget_create_sql("CREATE TABLE broken", "X").is_none());
+    }
+
+    #[test]
+    fn fresh_row_indices_are_gap_free_and_keep_order() {
+        let map = fresh_row_indices(&[3, 7, 40, 41]);
+        assert_eq!(map[&3], 0);
+        assert_eq!(map[&7], 1);
+        assert_eq!(map[&40], 2);
+        assert_eq!(map[&41], 3);
+        assert!(fresh_row_indices(&[]).is_empty());
+    }
+
+    #[test]
+    fn global_metadata_copy_rewires_parent_and_order() {
+        let columns: Vec<String> = ["table_name", "table_type", "parent_table", "display_order", "updated_at"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let child = TableCopy {
+            source: "Items_Tags".into(),
+            target: "Loot_Tags".into(),
+            target_parent: Some("Loot".into()),
+            source_parent: Some("Items".into()),
+        };
+        let stmt = copy_global_metadata_statement(&columns, &child, Some(5));
+        assert!(stmt.sql.contains("SELECT ?, \"table_type\", ?, \"display_order\", CURRENT_TIMESTAMP FROM _Metadata"));
+        assert_eq!(
+            stmt.params,
+            vec![serde_json::json!("Loot_Tags"), serde_json::json!("Loot"), serde_json::json!("Items_Tags")]
+        );
+
+        let main = TableCopy {
+            source: "Items".into(),
+            target: "Loot".into(),
+            target_parent: None,
+            source_parent: None,
+        };
+        let stmt = copy_global_metadata_statement(&columns, &main, Some(5));
+        assert!(stmt.sql.contains("SELECT ?, \"table_type\", \"parent_table\", ?, CURRENT_TIMESTAMP"));
+        assert_eq!(
+            stmt.params,
+            vec![serde_json::json!("Loot"), serde_json::json!(5), serde_json::json!("Items")]
+        );
+    }
+}
@@ -55,6 +55,16 @@ pub fn metadata_table_name(table_name: &str) -> String {
     format!("{}_Metadata", table_name)
 }
 
+/// Get the cell comments table name for a given table.
+pub fn comments_table_name(table_name: &str) -> String {
+    format!("{}_Comments", table_name)
+}
+
+/// Get the locked rows table name for a given table.
+pub fn locked_rows_table_name(table_name: &str) -> String {
+    format!("{}_LockedRows", table_name)
+}
+
 /// Get column index from metadata table by column name.
 pub fn get_column_index_by_name(conn: &Connection, meta_table: &str, column_name: &str) -> DbResult<Option<i32>> {
     // Checkpoint WAL to ensure we see the latest daemon writes
@@ -243,6 +253,14 @@ pub fn rename_table_triplet(conn: &Connection, old_name: &str, new_name: &str, d
     if groups_exists {
         rename_table(conn, &old_groups, &new_groups, db_filename, daemon_client)?;
     }
+    let old_comments = comments_table_name(old_name);
+    if table_exists(conn, &old_comments)? {
+        rename_table(conn, &old_comments, &comments_table_name(new_name), db_filename, daemon_client)?;
+    }
+    let old_locks = locked_rows_table_name(old_name);
+    if table_exists(conn, &old_locks)? {
+        rename_table(conn, &old_locks, &locked_rows_table_name(new_name), db_filename, daemon_client)?;
+    }
     let delete_stmt = Statement {
         sql: "DELETE FROM _Metadata WHERE table_name = ?".to_string(),
         params: vec![serde_json::Value::String(new_name.to_string())],
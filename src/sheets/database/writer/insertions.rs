@@ -6,14 +6,21 @@ use super::helpers::build_insert_sql;
 use crate::sheets::definitions::{ColumnValidator, SheetMetadata};
 use rusqlite::{Connection, Transaction};
 use bevy::prelude::*;
+use std::sync::atomic::{AtomicBool, Ordering};
 
-/// Insert grid data rows and invoke a progress callback after each 1,000 rows.
+/// Rows sent to the daemon per `exec_batch` call by the batch insert paths.
+const INSERT_CHUNK_ROWS: usize = 1000;
+
+/// Insert grid data rows and invoke a progress callback with the number of rows
+/// written so far after each chunk of `INSERT_CHUNK_ROWS` rows (including the last one).
+/// Returns `DbError::Cancelled` before writing the next chunk once `cancel` is set.
 pub fn insert_grid_data_with_progress<F: FnMut(usize)>(
     tx: &Transaction,
     table_name: &str,
     grid: &[Vec<String>],
     metadata: &SheetMetadata,
     mut on_chunk: F,
+    cancel: Option<&AtomicBool>,
     daemon_client: &crate::sheets::database::daemon_client::DaemonClient,
 ) -> DbResult<()> {
     let column_names: Vec<String> = metadata
@@ -63,22 +70,26 @@ pub fn insert_grid_data_with_progress<F: FnMut(usize)>(
         }
         batch.push(Statement { sql: insert_sql.clone(), params: params_json });
 
-        if row_idx > 0 && row_idx % 1000 == 0 {
-            // Execute batch chunk to prevent huge memory consumption
-            let chunk = std::mem::take(&mut batch);
-            if !chunk.is_empty() {
-                // Use connection to verify table still present before write (read-only)
-                // Read-only check skipped in batch mode; schema validated earlier.
-                daemon_client.exec_batch(chunk, None)
-                    .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(std::io::Error::new(std::io::ErrorKind::Other, e))))?;
+        if batch.len() >= INSERT_CHUNK_ROWS {
+            if cancel.is_some_and(|flag| flag.load(Ordering::Relaxed)) {
+                return Err(super::super::error::DbError::Cancelled);
             }
-            on_chunk(row_idx);
+            // Execute batch chunk to prevent huge memory consumption.
+            // Read-only check skipped in batch mode; schema validated earlier.
+            let chunk = std::mem::take(&mut batch);
+            daemon_client.exec_batch(chunk, None)
+                .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(std::io::Error::new(std::io::ErrorKind::Other, e))))?;
+            on_chunk(row_idx + 1);
         }
     }
     // Flush remaining statements
     if !batch.is_empty() {
+        if cancel.is_some_and(|flag| flag.load(Ordering::Relaxed)) {
+            return Err(super::super::error::DbError::Cancelled);
+        }
         daemon_client.exec_batch(batch, None)
             .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(std::io::Error::new(std::io::ErrorKind::Other, e))))?;
+        on_chunk(grid.len());
     }
 
     // Mirror inserts into local transaction in test mode so subsequent reads see them.
@@ -102,31 +113,36 @@ pub fn insert_grid_data_with_progress<F: FnMut(usize)>(
     Ok(())
 }
 
-/// Insert a new row at an explicit row_index value (internal helper).
-/// Note: Caller must ensure row_index uniqueness.
-fn insert_row_with_index(
+/// Build the daemon INSERT statement for one row at an explicit row_index value.
+pub(super) fn row_insert_statement(
     table_name: &str,
     row_index: i32,
     row_data: &[String],
     column_names: &[String],
-    db_filename: Option<&str>, // Database filename for daemon (e.g., "optima.db")
-    daemon_client: &crate::sheets::database::daemon_client::DaemonClient,
-) -> DbResult<i64> {
-    use crate::sheets::database::daemon_client::Statement;
-    
-    let insert_sql = build_insert_sql(table_name, column_names);
-    
+) -> crate::sheets::database::daemon_client::Statement {
     // Build params for daemon: row_index + row_data
     let mut params: Vec<serde_json::Value> = vec![serde_json::Value::Number(row_index.into())];
     for data in row_data {
         params.push(serde_json::Value::String(data.clone()));
     }
-    
-    let stmt = Statement {
-        sql: insert_sql,
+    crate::sheets::database::daemon_client::Statement {
+        sql: build_insert_sql(table_name, column_names),
         params,
-    };
-    
+    }
+}
+
+/// Insert a new row at an explicit row_index value (internal helper).
+/// Note: Caller must ensure row_index uniqueness.
+fn insert_row_with_index(
+    table_name: &str,
+    row_index: i32,
+    row_data: &[String],
+    column_names: &[String],
+    db_filename: Option<&str>, // Database filename for daemon (e.g., "optima.db")
+    daemon_client: &crate::sheets::database::daemon_client::DaemonClient,
+) -> DbResult<i64> {
+    let stmt = row_insert_statement(table_name, row_index, row_data, column_names);
+
     // Execute through daemon with explicit database filename
     let _response = daemon_client.exec_batch(vec![stmt], db_filename)
         .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(std::io::Error::new(
@@ -223,12 +239,15 @@ pub fn prepend_row(
 /// This prevents race conditions when adding multiple rows - all rows get sequential
 /// row_index values starting from max + 1.
 /// With DESC sort order, newest rows appear at the top visually (in reverse order of insertion).
-pub fn prepend_rows_batch(
+/// Rows are sent to the daemon in chunks of `INSERT_CHUNK_ROWS`; `on_chunk` receives
+/// the number of rows inserted so far after each chunk.
+pub fn prepend_rows_batch_with_progress<F: FnMut(usize)>(
     conn: &Connection,
     table_name: &str,
     rows_data: &[Vec<String>],
     column_names: &[String],
     db_filename: Option<&str>, // Database filename for daemon (e.g., "optima.db")
+    mut on_chunk: F,
     daemon_client: &crate::sheets::database::daemon_client::DaemonClient,
 ) -> DbResult<Vec<i64>> {
     if rows_data.is_empty() {
@@ -287,18 +306,29 @@ pub fn prepend_rows_batch(
         next
     };
     
-    // Insert all rows with sequential row_index values
-    let mut inserted_ids = Vec::with_capacity(rows_data.len());
-    let mut row_indices = Vec::with_capacity(rows_data.len());
-    for (i, row_data) in rows_data.iter().enumerate() {
-        let row_index = start_index + i as i32;
-        let id = insert_row_with_index(table_name, row_index, row_data, column_names, db_filename, daemon_client)?;
-        inserted_ids.push(id);
-        row_indices.push(row_index);
+    // Insert all rows with sequential row_index values, one daemon batch per chunk
+    let mut rows_done = 0usize;
+    for chunk in rows_data.chunks(INSERT_CHUNK_ROWS) {
+        let statements: Vec<_> = chunk
+            .iter()
+            .enumerate()
+            .map(|(i, row_data)| {
+                let row_index = start_index + (rows_done + i) as i32;
+                row_insert_statement(table_name, row_index, row_data, column_names)
+            })
+            .collect();
+        daemon_client.exec_batch(statements, db_filename)
+            .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                e
+            ))))?;
+        rows_done += chunk.len();
+        on_chunk(rows_done);
     }
     
     tx.commit()?;
-    info!("prepend_rows_batch: Inserted {} rows into '{}' with row_index values: {:?}", 
-          rows_data.len(), table_name, row_indices);
-    Ok(inserted_ids)
+    info!("prepend_rows_batch: Inserted {} rows into '{}' with row_index values {}..={}", 
+          rows_data.len(), table_name, start_index, start_index + rows_data.len() as i32 - 1);
+    // Placeholder IDs, see insert_row_with_index - callers only check for errors
+    Ok(vec![-1; rows_data.len()])
 }
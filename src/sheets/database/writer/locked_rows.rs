@@ -0,0 +1,112 @@
+// src/sheets/database/writer/locked_rows.rs
+// Row locks - sidecar `{table}_LockedRows` table listing the row_index values of read-only rows
+
+use super::super::error::DbResult;
+use super::super::schema::queries::table_exists;
+use super::daemon_utils::daemon_error_to_rusqlite;
+use super::helpers::{locked_rows_table_name, quote_identifier};
+use crate::sheets::database::daemon_client::Statement;
+use rusqlite::Connection;
+
+/// Create the locked rows table of `table_name` if it does not exist yet.
+pub(super) fn create_locked_rows_table_statement(table_name: &str) -> Statement {
+    Statement {
+        sql: format!(
+            "CREATE TABLE IF NOT EXISTS {} (
+                row_index INTEGER PRIMARY KEY,
+                locked_at TEXT DEFAULT CURRENT_TIMESTAMP
+            )",
+            quote_identifier(&locked_rows_table_name(table_name))
+        ),
+        params: vec![],
+    }
+}
+
+/// Mark one row as locked, creating the locked rows table on first use.
+pub fn lock_row(
+    table_name: &str,
+    row_index: i64,
+    db_filename: Option<&str>,
+    daemon_client: &super::super::daemon_client::DaemonClient,
+) -> DbResult<()> {
+    let statements = vec![
+        create_locked_rows_table_statement(table_name),
+        Statement {
+            sql: format!(
+                "INSERT OR IGNORE INTO {} (row_index) VALUES (?)",
+                quote_identifier(&locked_rows_table_name(table_name))
+            ),
+            params: vec![serde_json::Value::Number(row_index.into())],
+        },
+    ];
+    daemon_client
+        .exec_batch(statements, db_filename)
+        .map_err(daemon_error_to_rusqlite)?;
+    Ok(())
+}
+
+/// Remove the locks of the given rows (unlocking, or the rows were deleted).
+/// No-op when the table never had a locked row.
+pub fn delete_row_locks(
+    conn: &Connection,
+    table_name: &str,
+    row_indices: &[i64],
+    db_filename: Option<&str>,
+    daemon_client: &super::super::daemon_client::DaemonClient,
+) -> DbResult<()> {
+    let locks_table = locked_rows_table_name(table_name);
+    if row_indices.is_empty() || !table_exists(conn, &locks_table)? {
+        return Ok(());
+    }
+    let statements = row_indices
+        .iter()
+        .map(|row_index| Statement {
+            sql: format!(
+                "DELETE FROM {} WHERE row_index = ?",
+                quote_identifier(&locks_table)
+            ),
+            params: vec![serde_json::Value::Number((*row_index).into())],
+        })
+        .collect();
+    daemon_client
+        .exec_batch(statements, db_filename)
+        .map_err(daemon_error_to_rusqlite)?;
+    Ok(())
+}
+
+/// Statements moving locks from old to new row_index values, using the same negative
+/// placeholders as the data table so the primary key never collides mid-update.
+pub fn locked_row_remap_statements(table_name: &str, changed: &[(i64, i64)]) -> Vec<Statement> {
+    let locks_table = quote_identifier(&locked_rows_table_name(table_name));
+    let mut statements: Vec<Statement> = changed
+        .iter()
+        .map(|(old, new)| Statement {
+            sql: format!("UPDATE {} SET row_index = ? WHERE row_index = ?", locks_table),
+            params: vec![
+                serde_json::Value::Number((-(new + 1)).into()),
+                serde_json::Value::Number((*old).into()),
+            ],
+        })
+        .collect();
+    statements.push(Statement {
+        sql: format!(
+            "UPDATE {} SET row_index = -row_index - 1 WHERE row_index < 0",
+            locks_table
+        ),
+        params: vec![],
+    });
+    statements
+}
+
+/// Statement removing locks whose row is no longer in `table_name`; appended to batches
+/// that replace a table's rows wholesale.
+pub fn orphaned_locks_statement(table_name: &str) -> Statement {
+    Statement {
+        sql: format!(
+            "DELETE FROM {} WHERE row_index NOT IN (SELECT row_index FROM {})",
+            quote_identifier(&locked_rows_table_name(table_name)),
+            quote_identifier(table_name)
+        ),
+        params: vec![],
+    }
+}
@@ -0,0 +1,54 @@
+// src/sheets/database/writer/locked_rows_tests.rs
+// Tests for the row locks sidecar table
+
+#![cfg(test)]
+
+use super::locked_rows::{
+    create_locked_rows_table_statement, locked_row_remap_statements, orphaned_locks_statement,
+};
+use super::test_helpers::{exec_fixture_sql, run_statements};
+use rusqlite::Connection;
+
+#[test]
+fn remap_swaps_locks_without_key_collisions() {
+    let conn = Connection::open_in_memory().unwrap();
+    run_statements(&conn, vec![create_locked_rows_table_statement("Items")]);
+    exec_fixture_sql(&conn, "INSERT INTO Items_LockedRows (row_index) VALUES (0), (3);");
+
+    // Row 0 moves onto locked row 3's index while row 3 moves to 1
+    run_statements(&conn, locked_row_remap_statements("Items", &[(0, 3), (3, 1)]));
+
+    let mut stmt = conn
+        .prepare("SELECT row_index FROM Items_LockedRows ORDER BY row_index")
+        .unwrap();
+    let rows: Vec<i64> = stmt
+        .query_map([], |r| r.get(0))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(rows, vec![1, 3]);
+}
+
+#[test]
+fn orphaned_locks_are_dropped_with_their_rows() {
+    let conn = Connection::open_in_memory().unwrap();
+    run_statements(&conn, vec![create_locked_rows_table_statement("Items")]);
+    exec_fixture_sql(
+        &conn,
+        "CREATE TABLE Items (row_index INTEGER PRIMARY KEY, Name TEXT);
+         INSERT INTO Items (row_index, Name) VALUES (1, 'Bolt');
+         INSERT INTO Items_LockedRows (row_index) VALUES (1), (4);",
+    );
+
+    run_statements(&conn, vec![orphaned_locks_statement("Items")]);
+
+    let mut stmt = conn
+        .prepare("SELECT row_index FROM Items_LockedRows ORDER BY row_index")
+        .unwrap();
+    let rows: Vec<i64> = stmt
+        .query_map([], |r| r.get(0))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(rows, vec![1]);
+}
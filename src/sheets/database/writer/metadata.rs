@@ -2,7 +2,9 @@
 // Metadata operations - managing table and column metadata, AI settings
 
 use super::super::error::DbResult;
-use super::super::schema::{sql_type_for_column, runtime_to_persisted_column_index};
+use super::super::schema::{
+    color_to_hex, runtime_to_persisted_column_index, sql_type_for_column, DATABASE_SETTINGS_TABLE,
+};
 use super::helpers::metadata_table_name;
 use crate::sheets::definitions::{ColumnDataType, ColumnValidator};
 use crate::sheets::database::daemon_client::{DaemonClient, Statement};
@@ -82,6 +84,13 @@ fn validator_to_metadata(
             }).to_string();
             (Some("Structure".to_string()), Some(cfg))
         }
+        Some(ColumnValidator::Unique { case_sensitive, .. }) => {
+            let cfg = serde_json::json!({ "case_sensitive": case_sensitive }).to_string();
+            (Some("Unique".to_string()), Some(cfg))
+        }
+        Some(ColumnValidator::Enum(values)) => {
+            (Some("Enum".to_string()), Some(serde_json::json!(values).to_string()))
+        }
         None => (None, None),
     }
 }
@@ -133,7 +142,57 @@ pub fn update_table_hidden(
     exec_daemon_stmt(sql, params, db_filename, daemon_client)
 }
 
-/// Update table-level flags in _Metadata
+/// Update a table's tag color in the global _Metadata table (`None` clears it)
+pub fn update_table_color(
+    _conn: &Connection,
+    table_name: &str,
+    color: Option<[u8; 3]>,
+    db_filename: Option<&str>,
+    daemon_client: &DaemonClient,
+) -> DbResult<()> {
+    let sql = "INSERT INTO _Metadata (table_name, color) VALUES (?, ?) \
+              ON CONFLICT(table_name) DO UPDATE SET color = excluded.color, updated_at = CURRENT_TIMESTAMP".to_string();
+    let params = vec![
+        serde_json::Value::String(table_name.to_string()),
+        opt_string_to_json(color.map(color_to_hex)),
+    ];
+    exec_daemon_stmt(sql, params, db_filename, daemon_client)
+}
+
+/// Store the database's own tag color (its category color in the bottom panel).
+/// `_Metadata` holds one row per table, so database-wide values live in `_DatabaseSettings`.
+pub fn update_database_color(
+    color: Option<[u8; 3]>,
+    db_filename: Option<&str>,
+    daemon_client: &DaemonClient,
+) -> DbResult<()> {
+    let create = Statement {
+        sql: format!(
+            "CREATE TABLE IF NOT EXISTS {} (key TEXT PRIMARY KEY, value TEXT)",
+            DATABASE_SETTINGS_TABLE
+        ),
+        params: vec![],
+    };
+    let write = match color {
+        Some(c) => Statement {
+            sql: format!(
+                "INSERT OR REPLACE INTO {} (key, value) VALUES ('color', ?)",
+                DATABASE_SETTINGS_TABLE
+            ),
+            params: vec![serde_json::Value::String(color_to_hex(c))],
+        },
+        None => Statement {
+            sql: format!("DELETE FROM {} WHERE key = 'color'", DATABASE_SETTINGS_TABLE),
+            params: vec![],
+        },
+    };
+    daemon_client
+        .exec_batch(vec![create, write], db_filename)
+        .map_err(super::daemon_utils::daemon_error_to_rusqlite)?;
+    Ok(())
+}
+
+/// Update table-level flags in _Metadata. `None` leaves a setting untouched.
 pub fn update_table_ai_settings(
     _conn: &Connection,
     table_name: &str,
@@ -142,6 +201,7 @@ pub fn update_table_ai_settings(
     model_id: Option<&str>,
     active_group: Option<&str>,
     grounding_with_google_search: Option<bool>,
+    temperature: Option<Option<f32>>,
     db_filename: Option<&str>,
     daemon_client: &DaemonClient,
 ) -> DbResult<()> {
@@ -168,6 +228,14 @@ pub fn update_table_ai_settings(
         sets.push("ai_grounding_with_google_search = ?");
         params.push(bool_to_json(v));
     }
+    // Some(None) clears the temperature so the model default applies
+    if let Some(v) = temperature {
+        sets.push("ai_temperature = ?");
+        params.push(
+            v.and_then(|t| serde_json::Number::from_f64(t as f64))
+                .map_or(serde_json::Value::Null, serde_json::Value::Number),
+        );
+    }
     
     if sets.is_empty() {
         return Ok(());
@@ -182,6 +250,27 @@ pub fn update_table_ai_settings(
     exec_daemon_stmt(sql, params, db_filename, daemon_client)
 }
 
+/// Update (or clear with `None`) the per-sheet AI key/display column in _Metadata
+pub fn update_table_ai_key_column(
+    _conn: &Connection,
+    table_name: &str,
+    key_column: Option<&str>,
+    db_filename: Option<&str>,
+    daemon_client: &DaemonClient,
+) -> DbResult<()> {
+    // The legacy index is cleared so it can never shadow a later change
+    let sql = "UPDATE _Metadata SET ai_key_column = ?, ai_key_column_index = NULL, updated_at = CURRENT_TIMESTAMP WHERE table_name = ?"
+        .to_string();
+    let params = vec![
+        match key_column {
+            Some(header) => serde_json::Value::String(header.to_string()),
+            None => serde_json::Value::Null,
+        },
+        serde_json::Value::String(table_name.to_string()),
+    ];
+    exec_daemon_stmt(sql, params, db_filename, daemon_client)
+}
+
 /// Update a column's filter, ai_context, include flag, and hidden flag in the table's metadata table
 /// Note: column_index is the RUNTIME index (includes technical columns like row_index)
 pub fn update_column_metadata(
@@ -269,6 +358,186 @@ pub fn update_column_ai_include(
     exec_daemon_stmt(sql, params, db_filename, daemon_client)
 }
 
+/// Set the display-only "trim trailing zeros" flag for a numeric column in the metadata table
+/// Note: column_index is the RUNTIME index (includes technical columns like row_index)
+pub fn update_column_display_trim_zeros(
+    conn: &Connection,
+    table_name: &str,
+    column_index: usize,
+    trim_zeros: bool,
+    db_filename: Option<&str>,
+    daemon_client: &DaemonClient,
+) -> DbResult<()> {
+    let persisted_index = match get_persisted_index_or_skip(conn, table_name, column_index, daemon_client, db_filename)? {
+        Some(idx) => idx,
+        None => return Ok(()),
+    };
+    
+    let meta_table = metadata_table_name(table_name);
+    bevy::log::info!("update_column_display_trim_zeros: runtime={} -> persisted={} trim={}", column_index, persisted_index, trim_zeros);
+    
+    let sql = format!("UPDATE \"{}\" SET display_trim_zeros = ? WHERE column_index = ?", meta_table);
+    let params = vec![bool_to_json(trim_zeros), serde_json::Value::Number(persisted_index.into())];
+    
+    exec_daemon_stmt(sql, params, db_filename, daemon_client)
+}
+
+/// Set the "AI read-only" flag of a column in the metadata table
+/// Note: column_index is the RUNTIME index (includes technical columns like row_index)
+pub fn update_column_ai_read_only(
+    conn: &Connection,
+    table_name: &str,
+    column_index: usize,
+    read_only: bool,
+    db_filename: Option<&str>,
+    daemon_client: &DaemonClient,
+) -> DbResult<()> {
+    let persisted_index = match get_persisted_index_or_skip(conn, table_name, column_index, daemon_client, db_filename)? {
+        Some(idx) => idx,
+        None => return Ok(()),
+    };
+    
+    let meta_table = metadata_table_name(table_name);
+    bevy::log::info!("update_column_ai_read_only: runtime={} -> persisted={} read_only={}", column_index, persisted_index, read_only);
+    
+    let sql = format!("UPDATE \"{}\" SET ai_read_only = ? WHERE column_index = ?", meta_table);
+    let params = vec![bool_to_json(read_only), serde_json::Value::Number(persisted_index.into())];
+    
+    exec_daemon_stmt(sql, params, db_filename, daemon_client)
+}
+
+/// Set the "long text" (multi-line) flag of a column in the metadata table
+/// Note: column_index is the RUNTIME index (includes technical columns like row_index)
+pub fn update_column_multiline(
+    conn: &Connection,
+    table_name: &str,
+    column_index: usize,
+    multiline: bool,
+    db_filename: Option<&str>,
+    daemon_client: &DaemonClient,
+) -> DbResult<()> {
+    let persisted_index = match get_persisted_index_or_skip(conn, table_name, column_index, daemon_client, db_filename)? {
+        Some(idx) => idx,
+        None => return Ok(()),
+    };
+
+    let meta_table = metadata_table_name(table_name);
+    bevy::log::info!("update_column_multiline: runtime={} -> persisted={} multiline={}", column_index, persisted_index, multiline);
+
+    let sql = format!("UPDATE \"{}\" SET multiline = ? WHERE column_index = ?", meta_table);
+    let params = vec![bool_to_json(multiline), serde_json::Value::Number(persisted_index.into())];
+
+    exec_daemon_stmt(sql, params, db_filename, daemon_client)
+}
+
+/// Set the "add unknown values" flag of an Enum column in the metadata table
+/// Note: column_index is the RUNTIME index (includes technical columns like row_index)
+pub fn update_column_enum_auto_add(
+    conn: &Connection,
+    table_name: &str,
+    column_index: usize,
+    auto_add: bool,
+    db_filename: Option<&str>,
+    daemon_client: &DaemonClient,
+) -> DbResult<()> {
+    let persisted_index = match get_persisted_index_or_skip(conn, table_name, column_index, daemon_client, db_filename)? {
+        Some(idx) => idx,
+        None => return Ok(()),
+    };
+
+    let meta_table = metadata_table_name(table_name);
+    bevy::log::info!("update_column_enum_auto_add: runtime={} -> persisted={} auto_add={}", column_index, persisted_index, auto_add);
+
+    let sql = format!("UPDATE \"{}\" SET enum_auto_add = ? WHERE column_index = ?", meta_table);
+    let params = vec![bool_to_json(auto_add), serde_json::Value::Number(persisted_index.into())];
+
+    exec_daemon_stmt(sql, params, db_filename, daemon_client)
+}
+
+/// Set the numeric bounds of a column in the metadata table (NULL clears them)
+/// Note: column_index is the RUNTIME index (includes technical columns like row_index)
+pub fn update_column_numeric_range(
+    conn: &Connection,
+    table_name: &str,
+    column_index: usize,
+    range: Option<(Option<f64>, Option<f64>)>,
+    db_filename: Option<&str>,
+    daemon_client: &DaemonClient,
+) -> DbResult<()> {
+    let persisted_index = match get_persisted_index_or_skip(conn, table_name, column_index, daemon_client, db_filename)? {
+        Some(idx) => idx,
+        None => return Ok(()),
+    };
+
+    let meta_table = metadata_table_name(table_name);
+    bevy::log::info!("update_column_numeric_range: runtime={} -> persisted={} range={:?}", column_index, persisted_index, range);
+
+    let sql = format!("UPDATE \"{}\" SET numeric_range = ? WHERE column_index = ?", meta_table);
+    let range_json = match range {
+        Some(bounds) => serde_json::Value::String(serde_json::to_string(&bounds)?),
+        None => serde_json::Value::Null,
+    };
+    let params = vec![range_json, serde_json::Value::Number(persisted_index.into())];
+
+    exec_daemon_stmt(sql, params, db_filename, daemon_client)
+}
+
+/// Set the new-row default of a column in the metadata table (NULL clears it)
+/// Note: column_index is the RUNTIME index (includes technical columns like row_index)
+pub fn update_column_default_value(
+    conn: &Connection,
+    table_name: &str,
+    column_index: usize,
+    default_value: Option<&str>,
+    db_filename: Option<&str>,
+    daemon_client: &DaemonClient,
+) -> DbResult<()> {
+    let persisted_index = match get_persisted_index_or_skip(conn, table_name, column_index, daemon_client, db_filename)? {
+        Some(idx) => idx,
+        None => return Ok(()),
+    };
+
+    let meta_table = metadata_table_name(table_name);
+    bevy::log::info!("update_column_default_value: runtime={} -> persisted={} default={:?}", column_index, persisted_index, default_value);
+
+    let sql = format!("UPDATE \"{}\" SET default_value = ? WHERE column_index = ?", meta_table);
+    let default_json = match default_value {
+        Some(value) => serde_json::Value::String(value.to_string()),
+        None => serde_json::Value::Null,
+    };
+    let params = vec![default_json, serde_json::Value::Number(persisted_index.into())];
+
+    exec_daemon_stmt(sql, params, db_filename, daemon_client)
+}
+
+/// Build the statement that stores a column's UI width (NULL resets to the default width).
+/// Keyed by column_name rather than column_index so the width follows the column through reorders.
+pub(super) fn column_width_statement(meta_table: &str, column_name: &str, width: Option<f32>) -> Statement {
+    let width_value = width
+        .and_then(|w| serde_json::Number::from_f64(w as f64))
+        .map(serde_json::Value::Number)
+        .unwrap_or(serde_json::Value::Null);
+    Statement {
+        sql: format!("UPDATE \"{}\" SET column_width = ? WHERE column_name = ?", meta_table),
+        params: vec![width_value, serde_json::Value::String(column_name.to_string())],
+    }
+}
+
+/// Set the UI width of a column in the metadata table, identified by its physical name
+pub fn update_column_width(
+    table_name: &str,
+    column_name: &str,
+    width: Option<f32>,
+    db_filename: Option<&str>,
+    daemon_client: &DaemonClient,
+) -> DbResult<()> {
+    let meta_table = metadata_table_name(table_name);
+    bevy::log::debug!("update_column_width: '{}'.'{}' -> {:?}", table_name, column_name, width);
+
+    let stmt = column_width_statement(&meta_table, column_name, width);
+    exec_daemon_stmt(stmt.sql, stmt.params, db_filename, daemon_client)
+}
+
 /// Update a column's validator (data_type, validator_type, validator_config) and optional AI flags in metadata
 /// Note: column_index is the RUNTIME index (includes technical columns like row_index)
 pub fn update_column_validator(
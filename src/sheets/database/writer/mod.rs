@@ -4,15 +4,22 @@
 mod insertions;
 mod updates;
 mod renames;
+mod duplicates;
 mod metadata;
 mod cascades;
+mod comments;
+mod locked_rows;
 mod helpers;
 mod daemon_utils;
 
 #[cfg(test)]
-mod test_helpers;
+pub(crate) mod test_helpers;
 #[cfg(test)]
 mod helpers_tests;
+#[cfg(test)]
+mod comments_tests;
+#[cfg(test)]
+mod locked_rows_tests;
 
 use super::error::DbResult;
 use crate::sheets::definitions::{ColumnDataType, ColumnValidator, SheetMetadata};
@@ -24,7 +31,10 @@ use rusqlite::{Connection, Transaction};
 /// - `insertions`: Row and grid data insertion
 /// - `updates`: Cell and metadata updates
 /// - `renames`: Column and table renaming
+/// - `duplicates`: Copying a table tree under a new name
 /// - `metadata`: AI settings and column metadata management
+/// - `comments`: Cell comments kept in the `{table}_Comments` sidecar
+/// - `locked_rows`: Read-only rows kept in the `{table}_LockedRows` sidecar
 pub struct DbWriter;
 
 impl DbWriter {
@@ -32,16 +42,17 @@ impl DbWriter {
     // INSERTIONS - See insertions.rs
     // ============================================================================
     
-    /// Insert grid data rows with progress callback
+    /// Insert grid data rows with progress callback; stops before the next chunk once `cancel` is set
     pub fn insert_grid_data_with_progress<F: FnMut(usize)>(
         tx: &Transaction,
         table_name: &str,
         grid: &[Vec<String>],
         metadata: &SheetMetadata,
         on_chunk: F,
+        cancel: Option<&std::sync::atomic::AtomicBool>,
         daemon_client: &crate::sheets::database::daemon_client::DaemonClient,
     ) -> DbResult<()> {
-        insertions::insert_grid_data_with_progress(tx, table_name, grid, metadata, on_chunk, daemon_client)
+        insertions::insert_grid_data_with_progress(tx, table_name, grid, metadata, on_chunk, cancel, daemon_client)
     }
 
     /// Prepend a row (row_index = 0) by shifting existing rows down
@@ -56,17 +67,28 @@ impl DbWriter {
         insertions::prepend_row(conn, table_name, row_data, column_names, db_filename, daemon_client)
     }
 
+    /// INSERT statement for one row at an explicit row_index (caller keeps row_index unique)
+    pub fn row_insert_statement(
+        table_name: &str,
+        row_index: i32,
+        row_data: &[String],
+        column_names: &[String],
+    ) -> super::daemon_client::Statement {
+        insertions::row_insert_statement(table_name, row_index, row_data, column_names)
+    }
+
     /// Batch prepend multiple rows with single row_index calculation
-    /// Prevents race conditions when adding multiple rows at once
-    pub fn prepend_rows_batch(
+    /// Prevents race conditions when adding multiple rows at once; reports rows inserted after each chunk
+    pub fn prepend_rows_batch_with_progress<F: FnMut(usize)>(
         conn: &Connection,
         table_name: &str,
         rows_data: &[Vec<String>],
         column_names: &[String],
         db_filename: Option<&str>, // Database filename for daemon (e.g., "optima.db")
+        on_chunk: F,
         daemon_client: &crate::sheets::database::daemon_client::DaemonClient,
     ) -> DbResult<Vec<i64>> {
-        insertions::prepend_rows_batch(conn, table_name, rows_data, column_names, db_filename, daemon_client)
+        insertions::prepend_rows_batch_with_progress(conn, table_name, rows_data, column_names, db_filename, on_chunk, daemon_client)
     }
 
     // ============================================================================
@@ -86,6 +108,17 @@ impl DbWriter {
         updates::update_structure_cell_by_id(conn, table_name, row_id, column_name, value, db_filename, daemon_client)
     }
 
+    /// Update many cells by row id in one transaction: (row_id, column_name, value)
+    pub fn update_cells_by_id(
+        conn: &Connection,
+        table_name: &str,
+        updates: &[(i64, String, String)],
+        db_filename: Option<&str>,
+        daemon_client: &crate::sheets::database::daemon_client::DaemonClient,
+    ) -> DbResult<()> {
+        updates::update_cells_by_id(conn, table_name, updates, db_filename, daemon_client)
+    }
+
     /// Update column ordering in metadata
     pub fn update_column_indices(
         conn: &Connection,
@@ -97,6 +130,26 @@ impl DbWriter {
         updates::update_column_indices(conn, table_name, ordered_pairs, db_filename, daemon_client)
     }
 
+    /// Reassign row_index values (old, new) and remap child parent_keys and cell comments accordingly
+    pub fn remap_row_indices(
+        conn: &Connection,
+        table_name: &str,
+        mapping: &[(i64, i64)],
+        db_filename: Option<&str>,
+        daemon_client: &super::daemon_client::DaemonClient,
+    ) -> DbResult<()> {
+        updates::remap_row_indices(conn, table_name, mapping, db_filename, daemon_client)
+    }
+
+    /// Statements of `remap_row_indices` without sending them, to combine with other writes
+    pub fn remap_row_indices_statements(
+        conn: &Connection,
+        table_name: &str,
+        mapping: &[(i64, i64)],
+    ) -> DbResult<Vec<super::daemon_client::Statement>> {
+        updates::remap_row_indices_statements(conn, table_name, mapping)
+    }
+
     // ============================================================================
     // RENAMES - See renames.rs
     // ============================================================================
@@ -110,7 +163,8 @@ impl DbWriter {
         db_filename: Option<&str>,
         daemon_client: &crate::sheets::database::daemon_client::DaemonClient,
     ) -> DbResult<()> {
-        renames::rename_data_column(conn, table_name, old_name, new_name, db_filename, daemon_client)
+        renames::rename_data_column(conn, table_name, old_name, new_name, db_filename, daemon_client)?;
+        comments::rename_comment_column(conn, table_name, old_name, new_name, db_filename, daemon_client)
     }
 
     /// Atomically rename a structure table and update the parent table's metadata column name.
@@ -179,6 +233,103 @@ impl DbWriter {
         }
     }
 
+    // ============================================================================
+    // CELL COMMENTS - See comments.rs
+    // ============================================================================
+
+    /// Insert or replace the comment on one cell, identified by row_index and column name
+    pub fn upsert_cell_comment(
+        table_name: &str,
+        row_index: i64,
+        column_name: &str,
+        comment: &str,
+        db_filename: Option<&str>,
+        daemon_client: &super::daemon_client::DaemonClient,
+    ) -> DbResult<()> {
+        comments::upsert_cell_comment(table_name, row_index, column_name, comment, db_filename, daemon_client)
+    }
+
+    /// Remove the comment on one cell
+    pub fn delete_cell_comment(
+        conn: &Connection,
+        table_name: &str,
+        row_index: i64,
+        column_name: &str,
+        db_filename: Option<&str>,
+        daemon_client: &super::daemon_client::DaemonClient,
+    ) -> DbResult<()> {
+        comments::delete_cell_comment(conn, table_name, row_index, column_name, db_filename, daemon_client)
+    }
+
+    /// Remove the comments of deleted rows
+    pub fn delete_row_comments(
+        conn: &Connection,
+        table_name: &str,
+        row_indices: &[i64],
+        db_filename: Option<&str>,
+        daemon_client: &super::daemon_client::DaemonClient,
+    ) -> DbResult<()> {
+        comments::delete_row_comments(conn, table_name, row_indices, db_filename, daemon_client)
+    }
+
+    /// Remove the comments of a deleted column
+    pub fn delete_column_comments(
+        conn: &Connection,
+        table_name: &str,
+        column_name: &str,
+        db_filename: Option<&str>,
+        daemon_client: &super::daemon_client::DaemonClient,
+    ) -> DbResult<()> {
+        comments::delete_column_comments(conn, table_name, column_name, db_filename, daemon_client)
+    }
+
+    // ============================================================================
+    // LOCKED ROWS - See locked_rows.rs
+    // ============================================================================
+
+    /// Lock or unlock one row, identified by row_index
+    pub fn set_row_locked(
+        conn: &Connection,
+        table_name: &str,
+        row_index: i64,
+        locked: bool,
+        db_filename: Option<&str>,
+        daemon_client: &super::daemon_client::DaemonClient,
+    ) -> DbResult<()> {
+        if locked {
+            locked_rows::lock_row(table_name, row_index, db_filename, daemon_client)
+        } else {
+            locked_rows::delete_row_locks(conn, table_name, &[row_index], db_filename, daemon_client)
+        }
+    }
+
+    /// Remove the locks of deleted rows
+    pub fn delete_row_locks(
+        conn: &Connection,
+        table_name: &str,
+        row_indices: &[i64],
+        db_filename: Option<&str>,
+        daemon_client: &super::daemon_client::DaemonClient,
+    ) -> DbResult<()> {
+        locked_rows::delete_row_locks(conn, table_name, row_indices, db_filename, daemon_client)
+    }
+
+    /// Statements dropping the comments and locks of rows missing from `table_name`, for
+    /// whichever sidecars exist. Append them to a batch that replaces the table's rows.
+    pub fn orphaned_row_sidecar_statements(
+        conn: &Connection,
+        table_name: &str,
+    ) -> DbResult<Vec<super::daemon_client::Statement>> {
+        let mut statements = Vec::new();
+        if super::schema::queries::table_exists(conn, &helpers::comments_table_name(table_name))? {
+            statements.push(comments::orphaned_comments_statement(table_name));
+        }
+        if super::schema::queries::table_exists(conn, &helpers::locked_rows_table_name(table_name))? {
+            statements.push(locked_rows::orphaned_locks_statement(table_name));
+        }
+        Ok(statements)
+    }
+
     /// Rename a main table and all descendant structure tables to preserve links after a sheet rename.
     pub fn rename_table_and_descendants(
         conn: &Connection,
@@ -190,6 +341,18 @@ impl DbWriter {
         renames::rename_table_and_descendants(conn, old_table, new_table, db_filename, daemon_client)
     }
 
+    /// Duplicate a main table and all descendant structure tables under a new name.
+    /// Returns the (source, copy) table names, main table first.
+    pub fn duplicate_table_and_descendants(
+        conn: &Connection,
+        source_table: &str,
+        target_table: &str,
+        db_filename: Option<&str>,
+        daemon_client: &crate::sheets::database::daemon_client::DaemonClient,
+    ) -> DbResult<Vec<(String, String)>> {
+        duplicates::duplicate_table_and_descendants(conn, source_table, target_table, db_filename, daemon_client)
+    }
+
     /// Best-effort: drop a physical column from a table if it exists (SQLite 3.35+).
     pub fn drop_physical_column_if_exists(
         conn: &Connection,
@@ -241,6 +404,26 @@ impl DbWriter {
         metadata::update_table_hidden(conn, table_name, hidden, db_filename, daemon_client)
     }
 
+    /// Set or clear a table's tag color
+    pub fn update_table_color(
+        conn: &Connection,
+        table_name: &str,
+        color: Option<[u8; 3]>,
+        db_filename: Option<&str>,
+        daemon_client: &crate::sheets::database::daemon_client::DaemonClient,
+    ) -> DbResult<()> {
+        metadata::update_table_color(conn, table_name, color, db_filename, daemon_client)
+    }
+
+    /// Set or clear the database's category color
+    pub fn update_database_color(
+        color: Option<[u8; 3]>,
+        db_filename: Option<&str>,
+        daemon_client: &crate::sheets::database::daemon_client::DaemonClient,
+    ) -> DbResult<()> {
+        metadata::update_database_color(color, db_filename, daemon_client)
+    }
+
     /// Update table-level AI settings in _Metadata
     pub fn update_table_ai_settings(
         conn: &Connection,
@@ -250,6 +433,7 @@ impl DbWriter {
         model_id: Option<&str>,
         active_group: Option<&str>,
         grounding_with_google_search: Option<bool>,
+        temperature: Option<Option<f32>>,
         db_filename: Option<&str>,
         daemon_client: &crate::sheets::database::daemon_client::DaemonClient,
     ) -> DbResult<()> {
@@ -261,11 +445,23 @@ impl DbWriter {
             model_id,
             active_group,
             grounding_with_google_search,
+            temperature,
             db_filename,
             daemon_client,
         )
     }
 
+    /// Update the per-sheet AI key/display column in _Metadata (`None` clears it)
+    pub fn update_table_ai_key_column(
+        conn: &Connection,
+        table_name: &str,
+        key_column: Option<&str>,
+        db_filename: Option<&str>,
+        daemon_client: &crate::sheets::database::daemon_client::DaemonClient,
+    ) -> DbResult<()> {
+        metadata::update_table_ai_key_column(conn, table_name, key_column, db_filename, daemon_client)
+    }
+
     /// Update a column's metadata (filter, ai_context, ai_include, hidden)
     pub fn update_column_metadata(
         conn: &Connection,
@@ -315,6 +511,89 @@ impl DbWriter {
         metadata::update_column_ai_include(conn, table_name, column_index, include, db_filename, daemon_client)
     }
 
+    /// Update the display-only "trim trailing zeros" flag for a numeric column
+    pub fn update_column_display_trim_zeros(
+        conn: &Connection,
+        table_name: &str,
+        column_index: usize,
+        trim_zeros: bool,
+        db_filename: Option<&str>,
+        daemon_client: &super::daemon_client::DaemonClient,
+    ) -> DbResult<()> {
+        metadata::update_column_display_trim_zeros(conn, table_name, column_index, trim_zeros, db_filename, daemon_client)
+    }
+
+    /// Update the "AI read-only" flag of a column (sent as context, never changed by AI)
+    pub fn update_column_ai_read_only(
+        conn: &Connection,
+        table_name: &str,
+        column_index: usize,
+        read_only: bool,
+        db_filename: Option<&str>,
+        daemon_client: &super::daemon_client::DaemonClient,
+    ) -> DbResult<()> {
+        metadata::update_column_ai_read_only(conn, table_name, column_index, read_only, db_filename, daemon_client)
+    }
+
+    /// Update the "long text" flag of a column (grid preview + multi-line editor)
+    pub fn update_column_multiline(
+        conn: &Connection,
+        table_name: &str,
+        column_index: usize,
+        multiline: bool,
+        db_filename: Option<&str>,
+        daemon_client: &super::daemon_client::DaemonClient,
+    ) -> DbResult<()> {
+        metadata::update_column_multiline(conn, table_name, column_index, multiline, db_filename, daemon_client)
+    }
+
+    /// Update whether an Enum column adds unknown values to its allowed list
+    pub fn update_column_enum_auto_add(
+        conn: &Connection,
+        table_name: &str,
+        column_index: usize,
+        auto_add: bool,
+        db_filename: Option<&str>,
+        daemon_client: &super::daemon_client::DaemonClient,
+    ) -> DbResult<()> {
+        metadata::update_column_enum_auto_add(conn, table_name, column_index, auto_add, db_filename, daemon_client)
+    }
+
+    /// Update the numeric (min, max) bounds of a column; None clears them
+    pub fn update_column_numeric_range(
+        conn: &Connection,
+        table_name: &str,
+        column_index: usize,
+        range: Option<(Option<f64>, Option<f64>)>,
+        db_filename: Option<&str>,
+        daemon_client: &super::daemon_client::DaemonClient,
+    ) -> DbResult<()> {
+        metadata::update_column_numeric_range(conn, table_name, column_index, range, db_filename, daemon_client)
+    }
+
+    /// Update the value pre-filled into new rows for a column; None clears it
+    pub fn update_column_default_value(
+        conn: &Connection,
+        table_name: &str,
+        column_index: usize,
+        default_value: Option<&str>,
+        db_filename: Option<&str>,
+        daemon_client: &super::daemon_client::DaemonClient,
+    ) -> DbResult<()> {
+        metadata::update_column_default_value(conn, table_name, column_index, default_value, db_filename, daemon_client)
+    }
+
+    /// Update a column's UI width (keyed by column name, so it survives reorders)
+    pub fn update_column_width(
+        table_name: &str,
+        column_name: &str,
+        width: Option<f32>,
+        db_filename: Option<&str>,
+        daemon_client: &super::daemon_client::DaemonClient,
+    ) -> DbResult<()> {
+        metadata::update_column_width(table_name, column_name, width, db_filename, daemon_client)
+    }
+
     /// Update a column's validator and optional AI flags
     pub fn update_column_validator(
         conn: &Connection,
@@ -461,4 +740,83 @@ mod tests {
         assert_eq!(rows[1], (1, "A1".to_string()));
         assert_eq!(rows[2], (0, "A0".to_string()));
     }
+
+    #[test]
+    fn test_column_widths_follow_columns_through_reorder() {
+        use super::test_helpers::create_mock_daemon_client;
+        use crate::sheets::systems::logic::reorder_column::{move_column, persisted_column_order};
+
+        let conn = Connection::open_in_memory().unwrap();
+        let table = "Main";
+        setup_metadata_table(&conn, table, &["A", "B", "C"]);
+        let meta = format!("{}_Metadata", table);
+        conn.execute_batch(&format!(
+            "ALTER TABLE \"{0}\" ADD COLUMN display_name TEXT;
+             ALTER TABLE \"{0}\" ADD COLUMN column_width REAL;
+             UPDATE \"{0}\" SET data_type = 'String';",
+            meta
+        ))
+        .unwrap();
+
+        for (name, width) in [("A", 80.0f32), ("B", 120.0), ("C", 200.0)] {
+            let stmt = metadata::column_width_statement(&meta, name, Some(width));
+            conn.execute(&stmt.sql, rusqlite::params_from_iter(stmt.params.iter()))
+                .unwrap();
+        }
+
+        // Drag C in front of A, then persist the order the way the reorder handler does
+        let mut sheet = SheetMetadata::create_generic(table.into(), format!("{}.json", table), 4, None);
+        for (col, header) in sheet.columns.iter_mut().zip(["row_index", "A", "B", "C"]) {
+            col.header = header.to_string();
+        }
+        let mut grid = vec![vec!["0".to_string(), "a".into(), "b".into(), "c".into()]];
+        move_column(&mut sheet, &mut grid, 3, 1).unwrap();
+        let pairs = persisted_column_order(&sheet, 1);
+        DbWriter::update_column_indices(&conn, table, &pairs, None, &create_mock_daemon_client())
+            .unwrap();
+
+        let rows = crate::sheets::database::reader::queries::read_metadata_columns(&conn, &meta).unwrap();
+        let widths: Vec<(String, Option<f64>)> = rows
+            .into_iter()
+            .map(|r| (r.column_name, r.column_width))
+            .collect();
+        assert_eq!(
+            widths,
+            vec![
+                ("C".to_string(), Some(200.0)),
+                ("A".to_string(), Some(80.0)),
+                ("B".to_string(), Some(120.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_insert_grid_stops_when_cancelled() {
+        use super::test_helpers::create_mock_daemon_client;
+        use std::sync::atomic::AtomicBool;
+
+        let mut conn = Connection::open_in_memory().unwrap();
+        let table = "Main";
+        setup_simple_table(&conn, table);
+        let mut metadata =
+            SheetMetadata::create_generic(table.to_string(), String::new(), 1, None);
+        metadata.columns[0].header = "Name".to_string();
+        let grid = vec![vec!["A0".to_string()], vec!["A1".to_string()]];
+
+        let cancel = AtomicBool::new(true);
+        let mut reported = Vec::new();
+        let tx = conn.transaction().unwrap();
+        let result = DbWriter::insert_grid_data_with_progress(
+            &tx,
+            table,
+            &grid,
+            &metadata,
+            |rows| reported.push(rows),
+            Some(&cancel),
+            &create_mock_daemon_client(),
+        );
+
+        assert!(matches!(result, Err(super::super::error::DbError::Cancelled)));
+        assert!(reported.is_empty());
+    }
 }
@@ -4,7 +4,7 @@
 #![cfg(test)]
 
 use rusqlite::{params, Connection};
-use crate::sheets::database::daemon_client::DaemonClient;
+use crate::sheets::database::daemon_client::{DaemonClient, Statement};
 
 /// Create a mock daemon client for testing
 /// 
@@ -22,6 +22,29 @@ pub fn create_mock_daemon_client() -> DaemonClient {
     DaemonClient::new_mock()
 }
 
+/// Run fixture SQL (schema and rows, `;`-separated) directly on a test connection.
+///
+/// Test modules outside this whitelisted file build their fixtures through here, so
+/// runtime files never call rusqlite's write methods themselves.
+///
+/// # Example
+/// ```
+/// let conn = Connection::open_in_memory().unwrap();
+/// exec_fixture_sql(&conn, "CREATE TABLE Items (name TEXT); INSERT INTO Items VALUES ('Sword');");
+/// ```
+pub fn exec_fixture_sql(conn: &Connection, sql: &str) {
+    conn.execute_batch(sql).unwrap();
+}
+
+/// Run daemon statements directly on a test connection, in order, the way the daemon
+/// would apply a batch.
+pub fn run_statements(conn: &Connection, statements: Vec<Statement>) {
+    for stmt in statements {
+        conn.execute(&stmt.sql, rusqlite::params_from_iter(stmt.params.iter()))
+            .unwrap();
+    }
+}
+
 /// Set up a simple test table with standard columns for testing.
 /// 
 /// Creates a table with:
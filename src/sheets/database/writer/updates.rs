@@ -2,7 +2,8 @@
 // Update operations - modifying cell values and structure data
 
 use super::super::error::DbResult;
-use super::helpers::{build_update_sql, metadata_table_name};
+use super::super::schema::queries::table_exists;
+use super::helpers::{build_update_sql, comments_table_name, locked_rows_table_name, metadata_table_name};
 use rusqlite::Connection;
 
 /// Update a structure sheet's cell value by row id.
@@ -37,6 +38,47 @@ pub fn update_structure_cell_by_id(
     Ok(())
 }
 
+/// Update many cells by row id in a single daemon batch (one transaction).
+/// Each update is (row_id, column_name, value). Either all updates apply or none do.
+pub fn update_cells_by_id(
+    _conn: &Connection,
+    table_name: &str,
+    updates: &[(i64, String, String)],
+    db_filename: Option<&str>,
+    daemon_client: &crate::sheets::database::daemon_client::DaemonClient,
+) -> DbResult<()> {
+    use crate::sheets::database::daemon_client::Statement;
+
+    if updates.is_empty() {
+        return Ok(());
+    }
+
+    let statements: Vec<Statement> = updates
+        .iter()
+        .map(|(row_id, column_name, value)| Statement {
+            sql: build_update_sql(table_name, column_name, "id = ?"),
+            params: vec![
+                serde_json::Value::String(value.clone()),
+                serde_json::Value::Number((*row_id).into()),
+            ],
+        })
+        .collect();
+
+    bevy::log::info!(
+        "update_cells_by_id: Writing {} cell(s) to '{}' in one batch",
+        statements.len(),
+        table_name
+    );
+
+    daemon_client.exec_batch(statements, db_filename)
+        .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            e
+        ))))?;
+
+    Ok(())
+}
+
 /// Update the order (column_index) for columns in the table's metadata table.
 /// Pairs are (column_name, new_index). This updates metadata only; no physical reorder of table columns.
 pub fn update_column_indices(
@@ -130,3 +172,106 @@ pub fn update_column_indices(
     bevy::log::info!("update_column_indices: Successfully updated column order for '{}'", table_name);
     Ok(())
 }
+
+/// Reassign `row_index` values of a table's rows, e.g. after sorting. Pairs are
+/// (old row_index, new row_index) and must form a permutation of the affected values.
+/// Direct child structure tables reference the parent's row_index through `parent_key`,
+/// so their keys are remapped in the same batch (one transaction).
+pub fn remap_row_indices(
+    conn: &Connection,
+    table_name: &str,
+    mapping: &[(i64, i64)],
+    db_filename: Option<&str>,
+    daemon_client: &crate::sheets::database::daemon_client::DaemonClient,
+) -> DbResult<()> {
+    let statements = remap_row_indices_statements(conn, table_name, mapping)?;
+    if statements.is_empty() {
+        return Ok(());
+    }
+
+    daemon_client.exec_batch(statements, db_filename)
+        .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            e
+        ))))?;
+
+    Ok(())
+}
+
+/// Statements behind `remap_row_indices`, for callers that send them in a larger batch
+pub fn remap_row_indices_statements(
+    conn: &Connection,
+    table_name: &str,
+    mapping: &[(i64, i64)],
+) -> DbResult<Vec<crate::sheets::database::daemon_client::Statement>> {
+    use crate::sheets::database::daemon_client::Statement;
+
+    let changed: Vec<(i64, i64)> = mapping.iter().copied().filter(|(o, n)| o != n).collect();
+    if changed.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let child_tables: Vec<String> = conn
+        .prepare("SELECT table_name FROM _Metadata WHERE table_type = 'structure' AND parent_table = ?")?
+        .query_map([table_name], |row| row.get(0))?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Phase 1 moves every affected value to a negative placeholder (-(new + 1)) so the
+    // UNIQUE row_index constraint never sees two rows with the same value mid-update;
+    // phase 2 flips the placeholders back. row_index values are never negative.
+    let mut statements = Vec::new();
+    for (old, new) in &changed {
+        statements.push(Statement {
+            sql: format!("UPDATE \"{}\" SET row_index = ? WHERE row_index = ?", table_name),
+            params: vec![
+                serde_json::Value::Number((-(new + 1)).into()),
+                serde_json::Value::Number((*old).into()),
+            ],
+        });
+    }
+    statements.push(Statement {
+        sql: format!(
+            "UPDATE \"{}\" SET row_index = -row_index - 1 WHERE row_index < 0",
+            table_name
+        ),
+        params: vec![],
+    });
+
+    // Cell comments are keyed by row_index too and must follow their rows
+    if table_exists(conn, &comments_table_name(table_name))? {
+        statements.extend(super::comments::comment_remap_statements(table_name, &changed));
+    }
+    // Locks are per row and move the same way
+    if table_exists(conn, &locked_rows_table_name(table_name))? {
+        statements.extend(super::locked_rows::locked_row_remap_statements(table_name, &changed));
+    }
+
+    // parent_key is TEXT: same two phases with a "-" prefixed placeholder
+    for child_table in &child_tables {
+        for (old, new) in &changed {
+            statements.push(Statement {
+                sql: format!("UPDATE \"{}\" SET parent_key = ? WHERE parent_key = ?", child_table),
+                params: vec![
+                    serde_json::Value::String(format!("-{}", new + 1)),
+                    serde_json::Value::String(old.to_string()),
+                ],
+            });
+        }
+        statements.push(Statement {
+            sql: format!(
+                "UPDATE \"{}\" SET parent_key = CAST(CAST(substr(parent_key, 2) AS INTEGER) - 1 AS TEXT) WHERE parent_key LIKE '-%'",
+                child_table
+            ),
+            params: vec![],
+        });
+    }
+
+    bevy::log::info!(
+        "remap_row_indices: Moving {} row(s) in '{}' ({} child table(s))",
+        changed.len(),
+        table_name,
+        child_tables.len()
+    );
+
+    Ok(statements)
+}
@@ -1,6 +1,6 @@
 // src/sheets/events.rs
 use bevy::prelude::Event;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 
 use super::definitions::ColumnValidator;
@@ -19,6 +19,34 @@ pub struct AddSheetRowRequest {
     // Optional: initial cell values to set on the newly inserted row (at insert time)
     // Vector of (col_index, value)
     pub initial_values: Option<Vec<(usize, String)>>,
+    /// Add the row below the sheet's current last row instead of at the top
+    pub at_end: bool,
+}
+
+/// Clone a row next to itself. `row_index` is the grid row; the copy gets a fresh DB
+/// row_index and copies of the row's structure child rows.
+#[derive(Event, Debug, Clone)]
+pub struct DuplicateRowEvent {
+    pub category: Option<String>,
+    pub sheet_name: String,
+    pub row_index: usize,
+}
+
+/// Merge rows into the topmost of them. `row_indices` are grid rows; `column_choices`
+/// holds the value picked for a conflicting column (others take the first non-empty value).
+#[derive(Event, Debug, Clone)]
+pub struct MergeRowsEvent {
+    pub category: Option<String>,
+    pub sheet_name: String,
+    pub row_indices: Vec<usize>,
+    pub column_choices: HashMap<usize, String>,
+}
+
+/// Replace a DB sheet's rows with those of its last JSON export (`get_full_sheet_path`)
+#[derive(Event, Debug, Clone)]
+pub struct RevertSheetFromJsonEvent {
+    pub category: Option<String>,
+    pub sheet_name: String,
 }
 
 /// Batch add multiple rows at once with single row_index calculation
@@ -30,6 +58,8 @@ pub struct AddSheetRowsBatchRequest {
     // Each row can have its own initial values
     // Vector of rows, where each row is Vector of (col_index, value)
     pub rows_initial_values: Vec<Vec<(usize, String)>>,
+    /// Add the rows below the sheet's current last row, in order, instead of at the top
+    pub at_end: bool,
 }
 
 // ... (rest of the existing events remain the same) ...
@@ -39,12 +69,42 @@ pub struct RequestAddColumn {
     pub sheet_name: String,
 }
 
+/// Move a column to any position (e.g. a header drag-and-drop). `to_index` is the column's
+/// index after the move. Technical columns (row_index/parent_key) cannot be moved or passed.
 #[derive(Event, Debug, Clone)]
-pub struct RequestReorderColumn {
+pub struct MoveColumnEvent {
     pub category: Option<String>,
     pub sheet_name: String,
-    pub old_index: usize,
-    pub new_index: usize,
+    pub from_index: usize,
+    pub to_index: usize,
+}
+
+/// Sort a sheet's rows by one column's values. The new order is persisted, so it
+/// survives reloads (DB sheets reassign `row_index`, JSON sheets are re-saved).
+#[derive(Event, Debug, Clone)]
+pub struct SortSheetByColumnEvent {
+    pub category: Option<String>,
+    pub sheet_name: String,
+    pub col_index: usize,
+    pub ascending: bool,
+}
+
+/// Column width changed by resizing its header. Identified by physical column name
+/// (`header`) so the width stays with the column when columns are reordered.
+#[derive(Event, Debug, Clone)]
+pub struct RequestUpdateColumnWidth {
+    pub category: Option<String>,
+    pub sheet_name: String,
+    pub column_header: String,
+    pub new_width: f32,
+}
+
+/// Flip a column's `hidden` flag. Technical columns are left alone.
+#[derive(Event, Debug, Clone)]
+pub struct ToggleColumnHiddenEvent {
+    pub category: Option<String>,
+    pub sheet_name: String,
+    pub col_index: usize,
 }
 
 #[derive(Event, Debug, Clone)]
@@ -62,6 +122,15 @@ pub struct RequestRenameSheet {
     pub new_name: String,
 }
 
+/// Copy a sheet (grid, column metadata and Structure child tables) under a new name
+/// in the same category. Rejected if `new_sheet_name` is already taken.
+#[derive(Event, Debug, Clone)]
+pub struct DuplicateSheetEvent {
+    pub category: Option<String>,
+    pub source_sheet: String,
+    pub new_sheet_name: String,
+}
+
 /// Internal event to request a cache rename without borrowing conflicts.
 /// Forwarded into RequestRenameSheet by a dedicated system in UpdateCaches set.
 #[derive(Event, Debug, Clone)]
@@ -127,6 +196,69 @@ pub struct UpdateCellEvent {
     pub new_value: String,
 }
 
+/// A single cell change inside an [`UpdateCellsBatchEvent`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CellEdit {
+    pub row_index: usize,
+    pub col_index: usize,
+    pub new_value: String,
+}
+
+/// Bulk cell update for one sheet (paste, find & replace, AI apply).
+/// All DB writes go through a single daemon transaction, followed by one save
+/// and one revalidation instead of one per cell.
+#[derive(Event, Debug, Clone)]
+pub struct UpdateCellsBatchEvent {
+    pub category: Option<String>,
+    pub sheet_name: String,
+    pub edits: Vec<CellEdit>,
+    /// Report how many cells changed once the batch is applied (rejections are always reported)
+    pub report_result: bool,
+}
+
+/// Replace `find` with `replace` in every cell of one column. With `whole_cell` only
+/// cells whose entire value matches are replaced. Structure columns are rejected.
+#[derive(Event, Debug, Clone)]
+pub struct FindReplaceInColumnEvent {
+    pub category: Option<String>,
+    pub sheet_name: String,
+    pub col_index: usize,
+    pub find: String,
+    pub replace: String,
+    pub case_sensitive: bool,
+    pub whole_cell: bool,
+}
+
+/// Attach or replace the comment on a cell of a DB-backed sheet. `row_index` is the grid
+/// row; comments are stored against the row's DB row_index and the column header.
+#[derive(Event, Debug, Clone)]
+pub struct UpsertCellCommentEvent {
+    pub category: Option<String>,
+    pub sheet_name: String,
+    pub row_index: usize,
+    pub col_index: usize,
+    pub comment: String,
+}
+
+/// Lock or unlock a row of a DB-backed sheet against edits. `row_index` is the grid row;
+/// locks are stored against the row's DB row_index.
+#[derive(Event, Debug, Clone)]
+pub struct SetRowLockEvent {
+    pub category: Option<String>,
+    pub sheet_name: String,
+    pub row_index: usize,
+    pub locked: bool,
+}
+
+/// Remove the comment on a cell (see [`UpsertCellCommentEvent`]).
+#[derive(Event, Debug, Clone)]
+pub struct DeleteCellCommentEvent {
+    pub category: Option<String>,
+    pub sheet_name: String,
+    pub row_index: usize,
+    pub col_index: usize,
+}
+
 #[derive(Event, Debug, Clone)]
 pub struct RequestUpdateColumnValidator {
     pub category: Option<String>,
@@ -139,6 +271,8 @@ pub struct RequestUpdateColumnValidator {
     pub key_parent_column_index: Option<usize>,
     // NEW: Original validator of the target column BEFORE switching to Structure (for self-inclusion case)
     pub original_self_validator: Option<ColumnValidator>,
+    /// Converting away from Structure: serialize child rows into the cell before the child table is dropped
+    pub flatten_structure_children: bool,
 }
 
 #[derive(Event, Debug, Clone)]
@@ -176,6 +310,19 @@ pub struct AiBatchTaskResult {
     pub kind: AiBatchResultKind,
 }
 
+/// Request to replace the stored API key; the new key is validated before anything is overwritten
+#[derive(Event, Debug, Clone)]
+pub struct RequestRotateApiKey {
+    pub new_key: String,
+}
+
+/// Outcome of validating a key from `RequestRotateApiKey`
+#[derive(Event, Debug, Clone)]
+pub struct ApiKeyValidationResult {
+    pub new_key: String,
+    pub result: Result<(), String>,
+}
+
 #[derive(Debug, Clone)]
 pub enum AiBatchResultKind {
     Root {
@@ -265,6 +412,15 @@ pub struct RequestRenameAiSchemaGroup {
     pub new_name: String,
 }
 
+/// Moves the schema group at `from_index` to `to_index` (indices into `ai_schema_groups`)
+#[derive(Event, Debug, Clone)]
+pub struct ReorderAiSchemaGroupEvent {
+    pub category: Option<String>,
+    pub sheet_name: String,
+    pub from_index: usize,
+    pub to_index: usize,
+}
+
 #[derive(Event, Debug, Clone)]
 pub struct RequestDeleteAiSchemaGroup {
     pub category: Option<String>,
@@ -279,6 +435,25 @@ pub struct RequestSelectAiSchemaGroup {
     pub group_name: String,
 }
 
+/// Set whether AI may append rows while the named schema group is active
+#[derive(Event, Debug, Clone)]
+pub struct RequestSetAiSchemaGroupAllowRows {
+    pub category: Option<String>,
+    pub sheet_name: String,
+    pub group_name: String,
+    pub allow: bool,
+}
+
+/// Skip (or stop skipping) a structure table of the active schema group during AI processing
+#[derive(Event, Debug, Clone)]
+pub struct RequestSetAiStructureSkip {
+    pub category: Option<String>,
+    pub sheet_name: String,
+    /// Structure path starting at the root sheet column index
+    pub structure_path: Vec<usize>,
+    pub skip: bool,
+}
+
 #[derive(Event, Debug, Clone)]
 pub struct RequestSheetRevalidation {
     pub category: Option<String>,
@@ -303,6 +478,13 @@ pub struct RequestRenameCategory {
     pub new_name: String,
 }
 
+/// Set (or clear with `None`) the tag color of a database category
+#[derive(Event, Debug, Clone)]
+pub struct RequestSetCategoryColor {
+    pub name: String,
+    pub color: Option<[u8; 3]>,
+}
+
 // --- Clipboard events ---
 #[derive(Event, Debug, Clone)]
 pub struct RequestCopyCell {
@@ -320,6 +502,28 @@ pub struct RequestPasteCell {
     pub col_index: usize,
 }
 
+/// Paste a block of cells (parsed from tab-separated clipboard text) starting at the active
+/// cell. Pasted row `r` goes to `target_rows[r]` and pasted column `c` to `target_cols[c]`
+/// (the visible rows/columns from the active cell on); rows beyond `target_rows` are added
+/// as new rows below the sheet's last row and columns beyond `target_cols` are ignored.
+#[derive(Event, Debug, Clone)]
+pub struct RequestPasteBlock {
+    pub category: Option<String>,
+    pub sheet_name: String,
+    pub target_rows: Vec<usize>,
+    pub target_cols: Vec<usize>,
+    pub values: Vec<Vec<String>>,
+}
+
+/// Search a sheet and its structure child tables for `query` (see `StructureSearch`)
+#[derive(Event, Debug, Clone)]
+pub struct RequestStructureSearch {
+    pub category: Option<String>,
+    pub sheet_name: String,
+    pub query: String,
+    pub case_sensitive: bool,
+}
+
 // --- Database Migration events ---
 #[derive(Event, Debug, Clone)]
 pub struct RequestMigrateJsonToDb {
@@ -351,6 +555,65 @@ pub struct RequestExportSheetToJson {
     pub output_folder: PathBuf,
 }
 
+/// Export a database table as JSON Lines (`{table}.jsonl`, one object per row)
+#[derive(Event, Debug, Clone)]
+pub struct RequestExportSheetToJsonl {
+    pub db_path: PathBuf,
+    pub table_name: String,
+    pub output_folder: PathBuf,
+}
+
+/// Export a database table as CSV (`{table}.csv`, header row, RFC 4180 quoting)
+#[derive(Event, Debug, Clone)]
+pub struct RequestExportSheetToCsv {
+    pub db_path: PathBuf,
+    pub table_name: String,
+    pub output_folder: PathBuf,
+}
+
+/// Export a database table as Apache Parquet (`{table}.parquet`, typed numeric columns)
+#[derive(Event, Debug, Clone)]
+pub struct RequestExportSheetToParquet {
+    pub db_path: PathBuf,
+    pub table_name: String,
+    pub output_folder: PathBuf,
+}
+
+/// Compact a category database (VACUUM + REINDEX); refused while a migration runs
+#[derive(Event, Debug, Clone)]
+pub struct RequestCompactDatabase {
+    pub db_path: PathBuf,
+}
+
+/// Check that every structure child row of a category database still has its parent
+/// (report only; repairs are left to the `validate-structure-links` CLI command)
+#[derive(Event, Debug, Clone)]
+pub struct RequestValidateStructureLinks {
+    pub db_path: PathBuf,
+}
+
+/// Export every category database into one `.skdb.zip` archive (JSON tables + manifest)
+#[derive(Event, Debug, Clone)]
+pub struct RequestExportProject {
+    pub archive_path: PathBuf,
+}
+
+/// What to do when an imported table name already exists in the target database
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportCollisionPolicy {
+    /// Drop the existing table (and its structure tables) and import in its place
+    Overwrite,
+    /// Keep the existing table and import under "{name}_imported"
+    Suffix,
+}
+
+/// Import a `.skdb.zip` project archive written by project export
+#[derive(Event, Debug, Clone)]
+pub struct RequestImportProject {
+    pub archive_path: PathBuf,
+    pub collision_policy: ImportCollisionPolicy,
+}
+
 /// Structure table recreation strategy when table already exists
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum StructureRecreationStrategy {
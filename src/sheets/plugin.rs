@@ -4,14 +4,25 @@ use bevy::prelude::*;
 use super::events::{
     AddSheetRowRequest,
     AddSheetRowsBatchRequest,
+    DuplicateRowEvent,
+    MergeRowsEvent,
+    RevertSheetFromJsonEvent,
     AiBatchTaskResult,
     AiTaskResult,
+    ApiKeyValidationResult,
+    DeleteCellCommentEvent,
+    DuplicateSheetEvent,
+    FindReplaceInColumnEvent,
     JsonSheetUploaded,
     MigrationCompleted,
+    MoveColumnEvent,
+    ReorderAiSchemaGroupEvent,
     RequestAddColumn,
     RequestBatchUpdateColumnAiInclude,
     RequestCopyCell,
     RequestCreateAiSchemaGroup,
+    RequestCompactDatabase,
+    RequestValidateStructureLinks,
     // Category events
     RequestCreateCategory,
     // NEW: Import RequestCreateNewSheet
@@ -22,11 +33,17 @@ use super::events::{
     RequestDeleteRows,
     RequestDeleteSheet,
     RequestDeleteSheetFile,
+    RequestExportProject,
+    RequestExportSheetToCsv,
     RequestExportSheetToJson,
+    RequestExportSheetToJsonl,
+    RequestExportSheetToParquet,
+    RequestImportProject,
     RequestInitiateFileUpload,
     // Database migration events
     RequestMigrateJsonToDb,
     RequestMoveSheetToCategory,
+    RequestPasteBlock,
     RequestPasteCell,
     RequestProcessUpload,
     RequestRenameAiSchemaGroup,
@@ -34,21 +51,31 @@ use super::events::{
     RequestRenameCacheEntry,
     RequestRenameSheet,
     RequestRenameSheetFile,
-    RequestReorderColumn,
+    RequestRotateApiKey,
     RequestSelectAiSchemaGroup,
+    RequestSetAiSchemaGroupAllowRows,
+    RequestSetAiStructureSkip,
+    RequestSetCategoryColor,
     RequestSheetRevalidation,
+    RequestStructureSearch,
     RequestToggleAiRowGeneration,
     RequestUpdateAiSendSchema,
     RequestUpdateAiStructureSend,
     RequestUpdateColumnAiInclude,
     RequestUpdateColumnName,
     RequestUpdateColumnValidator,
+    RequestUpdateColumnWidth,
     RequestUploadJsonToCurrentDb,
+    SetRowLockEvent,
     SheetDataModifiedInRegistryEvent,
     SheetOperationFeedback,
+    SortSheetByColumnEvent,
+    ToggleColumnHiddenEvent,
     UpdateCellEvent,
+    UpdateCellsBatchEvent,
+    UpsertCellCommentEvent,
 };
-use super::resources::{ClipboardBuffer, SheetRegistry, SheetRenderCache};
+use super::resources::{ClipboardBuffer, EditHistory, LoadedSheetLru, SheetRegistry, SheetRenderCache};
 use super::systems;
 use super::systems::logic::handle_sheet_render_cache_update;
 use super::systems::logic::sync_structure::{
@@ -89,18 +116,29 @@ impl Plugin for SheetsPlugin {
         app.init_resource::<SheetRenderCache>();
         app.init_resource::<PendingStructureCascade>();
         app.init_resource::<ClipboardBuffer>();
+        app.init_resource::<LoadedSheetLru>();
+        app.init_resource::<EditHistory>();
         app.init_resource::<super::database::systems::MigrationBackgroundState>();
+        app.init_resource::<systems::logic::structure_search::StructureSearch>();
+        app.init_resource::<systems::logic::update_column_width::ColumnWidthSaveQueue>();
         app.init_resource::<super::database::checkpoint::CheckpointTimer>();
         app.init_resource::<super::database::daemon_resource::SharedDaemonClient>();
 
         app.add_event::<AddSheetRowRequest>()
             .add_event::<AddSheetRowsBatchRequest>()
+            .add_event::<DuplicateRowEvent>()
+            .add_event::<MergeRowsEvent>()
+            .add_event::<RevertSheetFromJsonEvent>()
             .add_event::<RequestAddColumn>()
-            .add_event::<RequestReorderColumn>()
+            .add_event::<MoveColumnEvent>()
+            .add_event::<RequestUpdateColumnWidth>()
+            .add_event::<ToggleColumnHiddenEvent>()
+            .add_event::<SortSheetByColumnEvent>()
             // NEW: Register RequestCreateNewSheet event
             .add_event::<RequestCreateNewSheet>()
             .add_event::<JsonSheetUploaded>()
             .add_event::<RequestRenameSheet>()
+            .add_event::<DuplicateSheetEvent>()
             .add_event::<RequestRenameCacheEntry>()
             .add_event::<RequestDeleteSheet>()
             .add_event::<RequestDeleteSheetFile>()
@@ -111,10 +149,17 @@ impl Plugin for SheetsPlugin {
             .add_event::<RequestUpdateColumnName>()
             .add_event::<RequestUpdateColumnValidator>()
             .add_event::<UpdateCellEvent>()
+            .add_event::<UpdateCellsBatchEvent>()
+            .add_event::<FindReplaceInColumnEvent>()
+            .add_event::<UpsertCellCommentEvent>()
+            .add_event::<DeleteCellCommentEvent>()
+            .add_event::<SetRowLockEvent>()
             .add_event::<RequestDeleteRows>()
             .add_event::<RequestDeleteColumns>()
             .add_event::<AiTaskResult>()
             .add_event::<AiBatchTaskResult>()
+            .add_event::<RequestRotateApiKey>()
+            .add_event::<ApiKeyValidationResult>()
             .add_event::<SheetDataModifiedInRegistryEvent>()
             .add_event::<RequestSheetRevalidation>()
             .add_event::<RequestToggleAiRowGeneration>()
@@ -125,17 +170,22 @@ impl Plugin for SheetsPlugin {
             .add_event::<RequestMoveSheetToCategory>()
             .add_event::<RequestCreateAiSchemaGroup>()
             .add_event::<RequestRenameAiSchemaGroup>()
+            .add_event::<ReorderAiSchemaGroupEvent>()
             .add_event::<RequestDeleteAiSchemaGroup>()
             .add_event::<RequestSelectAiSchemaGroup>()
+            .add_event::<RequestSetAiSchemaGroupAllowRows>()
+            .add_event::<RequestSetAiStructureSkip>()
             // Daemon management event
             .add_event::<super::database::daemon_resource::RequestDaemonShutdown>();
         // Category management events
         app.add_event::<RequestCreateCategory>()
             .add_event::<RequestDeleteCategory>()
-            .add_event::<RequestRenameCategory>();
+            .add_event::<RequestRenameCategory>()
+            .add_event::<RequestSetCategoryColor>();
         // Clipboard events
         app.add_event::<RequestCopyCell>()
-            .add_event::<RequestPasteCell>();
+            .add_event::<RequestPasteCell>()
+            .add_event::<RequestPasteBlock>();
 
         // Database migration events
         app.add_event::<RequestMigrateJsonToDb>()
@@ -143,6 +193,14 @@ impl Plugin for SheetsPlugin {
             .add_event::<MigrationCompleted>()
             .add_event::<crate::sheets::events::MigrationProgress>()
             .add_event::<RequestExportSheetToJson>()
+            .add_event::<RequestExportSheetToJsonl>()
+            .add_event::<RequestExportSheetToCsv>()
+            .add_event::<RequestExportSheetToParquet>()
+            .add_event::<RequestExportProject>()
+            .add_event::<RequestCompactDatabase>()
+            .add_event::<RequestValidateStructureLinks>()
+            .add_event::<RequestStructureSearch>()
+            .add_event::<RequestImportProject>()
             // Structure table recreation event
             .add_event::<crate::sheets::events::RequestStructureTableRecreation>();
 
@@ -181,27 +239,39 @@ impl Plugin for SheetsPlugin {
             systems::logic::handle_delete_request,
             systems::logic::handle_add_row_request,
             systems::logic::handle_add_rows_batch_request,
+            systems::logic::handle_duplicate_row_request,
             systems::logic::handle_toggle_ai_row_generation,
             systems::logic::handle_update_column_ai_include,
             systems::logic::handle_update_ai_send_schema,
             systems::logic::handle_update_ai_structure_send,
             systems::logic::handle_create_ai_schema_group,
             systems::logic::handle_rename_ai_schema_group,
+            systems::logic::handle_reorder_ai_schema_group,
             systems::logic::handle_delete_ai_schema_group,
             systems::logic::handle_select_ai_schema_group,
+            systems::logic::handle_set_ai_schema_group_allow_rows,
+            systems::logic::handle_set_ai_structure_skip,
         )
             .chain();
 
         let apply_changes_stage_two = (
             systems::logic::handle_add_column_request,
             systems::logic::handle_reorder_column_request,
+            systems::logic::handle_update_column_width,
+            systems::logic::flush_column_width_saves,
+            systems::logic::handle_toggle_column_hidden,
+            systems::logic::handle_sort_sheet_by_column,
             // NEW: Add system for creating sheets
             systems::logic::handle_create_new_sheet_request,
+            systems::logic::handle_duplicate_sheet_request,
             // Category create/delete
             systems::logic::handle_create_category_request,
             systems::logic::handle_delete_category_request,
             systems::logic::handle_rename_category_request,
+            systems::logic::handle_set_category_color_request,
             systems::logic::handle_delete_rows_request,
+            systems::logic::handle_merge_rows_request,
+            systems::logic::handle_revert_sheet_from_json,
         )
             .chain();
 
@@ -212,7 +282,14 @@ impl Plugin for SheetsPlugin {
             systems::logic::handle_update_column_validator,
             systems::logic::handle_structure_table_recreation,
             systems::logic::handle_update_column_name,
+            // Block paste and find & replace feed the batch update below; new paste rows
+            // are added next frame
+            systems::logic::handle_paste_block,
+            systems::logic::handle_find_replace_in_column,
             systems::logic::handle_cell_update,
+            systems::logic::handle_cell_batch_update,
+            systems::logic::handle_cell_comment_requests,
+            systems::logic::handle_row_lock_requests,
             // Clipboard operations
             systems::logic::handle_copy_cell,
             systems::logic::handle_paste_cell,
@@ -253,12 +330,23 @@ impl Plugin for SheetsPlugin {
                 .in_set(SheetSystemSet::ProcessAsyncResults),
         );
 
+        app.add_systems(
+            Update,
+            (
+                systems::ai::api_key_rotation::handle_api_key_rotation_request,
+                systems::ai::api_key_rotation::apply_api_key_validation_result,
+            )
+                .in_set(SheetSystemSet::ProcessAsyncResults),
+        );
+
         app.add_systems(
             Update,
             (
                 handle_sheet_render_cache_update,
                 systems::logic::handle_sync_virtual_structure_sheet,
                 handle_emit_structure_cascade_events,
+                systems::logic::refresh_cell_comments_cache,
+                systems::logic::refresh_locked_rows,
                 // Run inline structure migration once after sheets are loaded and caches are building
                 systems::logic::run_inline_structure_migration_once,
                 // UI progress updater for migration
@@ -272,11 +360,22 @@ impl Plugin for SheetsPlugin {
             (
                 systems::io::handle_delete_sheet_file_request,
                 systems::io::handle_rename_sheet_file_request,
+                // Drop grids of least-recently-viewed DB sheets beyond the configured limit
+                systems::io::sheet_eviction::evict_least_recently_used_sheets,
                 // Database migration systems
                 super::database::handle_migration_requests,
                 poll_migration_background,
                 super::database::handle_upload_json_to_current_db,
                 super::database::handle_export_requests,
+                super::database::handle_jsonl_export_requests,
+                super::database::handle_csv_export_requests,
+                super::database::handle_parquet_export_requests,
+                super::database::handle_project_export_requests,
+                super::database::handle_project_import_requests,
+                super::database::handle_compact_database_requests,
+                super::database::handle_validate_structure_links_requests,
+                systems::logic::structure_search::handle_structure_search_requests,
+                systems::logic::structure_search::poll_structure_search,
                 super::database::handle_migration_completion,
                 // Periodic WAL checkpoint to prevent data loss
                 super::database::checkpoint::periodic_checkpoint,
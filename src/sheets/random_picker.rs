@@ -41,6 +41,24 @@ pub struct RandomPickerSettings {
     /// New: support multiple summarizer columns
     #[serde(default)]
     pub summarizer_columns: Vec<usize>,
+    /// How many distinct values one Refresh draws (without replacement). 0 and 1 both mean one.
+    #[serde(default = "default_draw_count", skip_serializing_if = "is_single_draw")]
+    pub draw_count: usize,
+    /// Rows already drawn, by row_index (grid position for JSON sheets); Refresh skips them
+    /// until picks are reset
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub excluded_row_indices: Vec<i64>,
+    /// Fixed RNG seed for reproducible draws; None draws from the thread RNG
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+}
+
+fn default_draw_count() -> usize {
+    1
+}
+
+fn is_single_draw(v: &usize) -> bool {
+    *v <= 1
 }
 
 // Helper functions for skip_serializing_if
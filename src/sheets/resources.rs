@@ -1,6 +1,6 @@
 // src/sheets/resources.rs
 use bevy::prelude::*;
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use crate::ui::validation::ValidationState;
 use super::definitions::{ColumnValidator, SheetGridData, SheetMetadata};
 #[derive(Clone, Debug, Default)]
@@ -116,6 +116,11 @@ impl SheetRenderCache {
 pub struct SheetRegistry {
     categorized_sheets: BTreeMap<Option<String>, HashMap<String, SheetGridData>>,
     explicit_categories: BTreeMap<String, ()>,
+    /// Tag colors of database categories, read from each database at startup
+    category_colors: HashMap<String, [u8; 3]>,
+    /// Locked DB row_index values per sheet, loaded from `{table}_LockedRows` when the
+    /// sheet is opened. Sheets without an entry have not been loaded yet.
+    locked_rows: HashMap<(Option<String>, String), HashSet<i64>>,
 }
 impl SheetRegistry {
     pub fn register(&mut self, mut metadata: SheetMetadata) -> bool {
@@ -361,6 +366,10 @@ impl SheetRegistry {
             // 4. Insert back into the *same category's* HashMap with the new name
             category_map.insert(new_name.clone(), data); // data now has updated metadata
 
+            if let Some(locked) = self.locked_rows.remove(&(category.clone(), old_name.to_string())) {
+                self.locked_rows.insert((category.clone(), new_name.clone()), locked);
+            }
+
             Ok(updated_data_for_return)
         } else {
             Err(format!(
@@ -385,6 +394,7 @@ impl SheetRegistry {
                 if category_map.is_empty() {
                     self.categorized_sheets.remove(category);
                 }
+                self.locked_rows.remove(&(category.clone(), sheet_name.to_string()));
                 Ok(data) // Return the removed data
             } else {
                 Err(format!(
@@ -415,6 +425,47 @@ impl SheetRegistry {
         self.explicit_categories.insert(trimmed.to_string(), ());
         Ok(())
     }
+    pub fn category_color(&self, name: &str) -> Option<[u8; 3]> {
+        self.category_colors.get(name).copied()
+    }
+    pub fn set_category_color(&mut self, name: &str, color: Option<[u8; 3]>) {
+        match color {
+            Some(c) => {
+                self.category_colors.insert(name.to_string(), c);
+            }
+            None => {
+                self.category_colors.remove(name);
+            }
+        }
+    }
+    pub fn locked_rows(&self, category: &Option<String>, sheet_name: &str) -> Option<&HashSet<i64>> {
+        self.locked_rows.get(&(category.clone(), sheet_name.to_string()))
+    }
+    /// Whether the row at `grid_row` of the sheet is locked against edits. Database sheets
+    /// whose locks were not loaded yet read as unlocked; writers call
+    /// `row_locks::ensure_locked_rows_loaded` first.
+    pub fn is_grid_row_locked(&self, category: &Option<String>, sheet_name: &str, grid_row: usize) -> bool {
+        let Some(locked) = self.locked_rows(category, sheet_name) else {
+            return false;
+        };
+        self.get_sheet(category, sheet_name)
+            .and_then(|sheet| sheet.row_indices.get(grid_row))
+            .is_some_and(|row_index| locked.contains(row_index))
+    }
+    pub fn set_locked_rows(&mut self, category: &Option<String>, sheet_name: &str, rows: HashSet<i64>) {
+        self.locked_rows.insert((category.clone(), sheet_name.to_string()), rows);
+    }
+    pub fn set_row_locked(&mut self, category: &Option<String>, sheet_name: &str, row_index: i64, locked: bool) {
+        let rows = self
+            .locked_rows
+            .entry((category.clone(), sheet_name.to_string()))
+            .or_default();
+        if locked {
+            rows.insert(row_index);
+        } else {
+            rows.remove(&row_index);
+        }
+    }
     pub fn delete_category(&mut self, name: &str) -> Result<Vec<String>, String> {
         let key = Some(name.to_string());
         let mut deleted: Vec<String> = Vec::new();
@@ -423,6 +474,8 @@ impl SheetRegistry {
         }
         // Remove explicit flag too
         self.explicit_categories.remove(name);
+        self.category_colors.remove(name);
+        self.locked_rows.retain(|(cat, _), _| cat != &key);
         if deleted.is_empty() {
             // If nothing was deleted and explicit flag removed, category might not have existed
             if !self.explicit_categories.contains_key(name) {
@@ -448,6 +501,20 @@ impl SheetRegistry {
         }
         // Move explicit flag if present
         let had_explicit = self.explicit_categories.remove(old_name).is_some();
+        if let Some(color) = self.category_colors.remove(old_name) {
+            self.category_colors.insert(new_name.to_string(), color);
+        }
+        let moved_locks: Vec<_> = self
+            .locked_rows
+            .keys()
+            .filter(|(cat, _)| cat == &old_key)
+            .cloned()
+            .collect();
+        for old in moved_locks {
+            if let Some(locked) = self.locked_rows.remove(&old) {
+                self.locked_rows.insert((new_key.clone(), old.1), locked);
+            }
+        }
         if let Some(mut map) = self.categorized_sheets.remove(&old_key) {
             // Update metadata category for all sheets
             for (_name, data) in map.iter_mut() {
@@ -475,3 +542,369 @@ pub struct ClipboardBuffer {
     pub source_validator: Option<ColumnValidator>,
     pub structure_data: Option<Vec<Vec<String>>>,
 }
+
+// --- Loaded Sheet LRU Resource ---
+/// Most-recently-viewed order of sheets (front = most recent).
+/// Used to decide which DB-backed sheets keep their grid data in memory.
+#[derive(Resource, Default, Debug)]
+pub struct LoadedSheetLru {
+    order: VecDeque<(Option<String>, String)>,
+}
+
+impl LoadedSheetLru {
+    /// Marks a sheet as most recently used. Returns true if it was not already at the front.
+    pub fn touch(&mut self, category: &Option<String>, sheet_name: &str) -> bool {
+        if self
+            .order
+            .front()
+            .map_or(false, |(c, s)| c == category && s == sheet_name)
+        {
+            return false;
+        }
+        self.order
+            .retain(|(c, s)| !(c == category && s == sheet_name));
+        self.order.push_front((category.clone(), sheet_name.to_string()));
+        true
+    }
+
+    /// Recency rank of a sheet (0 = most recent). Sheets never viewed rank last.
+    pub fn rank(&self, category: &Option<String>, sheet_name: &str) -> usize {
+        self.order
+            .iter()
+            .position(|(c, s)| c == category && s == sheet_name)
+            .unwrap_or(usize::MAX)
+    }
+
+    /// Drops a sheet from the order (e.g. after it was deleted or renamed).
+    pub fn forget(&mut self, category: &Option<String>, sheet_name: &str) {
+        self.order
+            .retain(|(c, s)| !(c == category && s == sheet_name));
+    }
+
+    /// Iterates tracked sheets from most to least recently used.
+    pub fn iter(&self) -> impl Iterator<Item = &(Option<String>, String)> {
+        self.order.iter()
+    }
+}
+
+// --- Edit History Resource ---
+/// Maximum number of cell edits kept for undo
+pub const EDIT_HISTORY_LIMIT: usize = 200;
+
+/// One accepted cell change. `row` is the grid index at edit time; `row_index` is the
+/// DB row_index (when known), used to find the row again after rows were added or moved.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CellEditRecord {
+    pub category: Option<String>,
+    pub sheet_name: String,
+    pub row: usize,
+    pub row_index: Option<i64>,
+    pub col: usize,
+    pub old_value: String,
+    pub new_value: String,
+}
+
+impl CellEditRecord {
+    /// The change that reverts this one
+    pub fn inverted(&self) -> Self {
+        Self {
+            old_value: self.new_value.clone(),
+            new_value: self.old_value.clone(),
+            ..self.clone()
+        }
+    }
+
+    fn same_cell(&self, other: &Self) -> bool {
+        let same_row = match (self.row_index, other.row_index) {
+            (Some(a), Some(b)) => a == b,
+            _ => self.row == other.row,
+        };
+        same_row
+            && self.col == other.col
+            && self.category == other.category
+            && self.sheet_name == other.sheet_name
+    }
+}
+
+/// Bounded undo/redo stacks of cell edits. Undo and redo hand back the change to apply
+/// (as an `UpdateCellEvent`); the edit it produces is recognised as a replay and does
+/// not touch the stacks again.
+#[derive(Resource, Default, Debug)]
+pub struct EditHistory {
+    undo: VecDeque<CellEditRecord>,
+    redo: Vec<CellEditRecord>,
+    /// Changes issued by undo/redo that have not been applied yet
+    replaying: Vec<CellEditRecord>,
+}
+
+impl EditHistory {
+    /// Records an accepted edit. A fresh edit clears the redo branch.
+    pub fn record(&mut self, record: CellEditRecord) {
+        if let Some(pos) = self.replaying.iter().position(|r| r.same_cell(&record)) {
+            self.replaying.remove(pos);
+            return;
+        }
+        self.redo.clear();
+        self.push_undo(record);
+    }
+
+    /// Pops the latest edit and returns the change that reverts it
+    pub fn undo(&mut self) -> Option<CellEditRecord> {
+        let record = self.undo.pop_back()?;
+        let inverse = record.inverted();
+        self.redo.push(record);
+        self.replaying.push(inverse.clone());
+        Some(inverse)
+    }
+
+    /// Re-applies the most recently undone edit
+    pub fn redo(&mut self) -> Option<CellEditRecord> {
+        let record = self.redo.pop()?;
+        self.push_undo(record.clone());
+        self.replaying.push(record.clone());
+        Some(record)
+    }
+
+    /// Forgets replays that were rejected or left the cell unchanged, so they cannot
+    /// swallow a later edit of the same cell
+    pub fn finish_replays(&mut self) {
+        self.replaying.clear();
+    }
+
+    /// Points a sheet's records at their rows' new DB row_index values after rows were
+    /// renumbered with (old, new) pairs
+    pub fn remap_row_indices(
+        &mut self,
+        category: &Option<String>,
+        sheet_name: &str,
+        mapping: &[(i64, i64)],
+    ) {
+        let moved: HashMap<i64, i64> = mapping.iter().copied().collect();
+        for record in self.sheet_records_mut(category, sheet_name) {
+            if let Some(new) = record.row_index.and_then(|ri| moved.get(&ri)) {
+                record.row_index = Some(*new);
+            }
+        }
+    }
+
+    /// Shifts a sheet's records below `grid_row` down by one after a row was inserted there
+    pub fn shift_rows_for_insert(
+        &mut self,
+        category: &Option<String>,
+        sheet_name: &str,
+        grid_row: usize,
+    ) {
+        for record in self.sheet_records_mut(category, sheet_name) {
+            if record.row >= grid_row {
+                record.row += 1;
+            }
+        }
+    }
+
+    /// Forgets every record of a sheet (its rows were replaced wholesale)
+    pub fn clear_sheet(&mut self, category: &Option<String>, sheet_name: &str) {
+        let other_sheet = |r: &CellEditRecord| !(&r.category == category && r.sheet_name == sheet_name);
+        self.undo.retain(other_sheet);
+        self.redo.retain(other_sheet);
+        self.replaying.retain(other_sheet);
+    }
+
+    /// Forgets a sheet's records of rows that no longer exist, by DB row_index
+    pub fn drop_row_indices(
+        &mut self,
+        category: &Option<String>,
+        sheet_name: &str,
+        row_indices: &HashSet<i64>,
+    ) {
+        let kept = |r: &CellEditRecord| {
+            !(&r.category == category
+                && r.sheet_name == sheet_name
+                && r.row_index.is_some_and(|ri| row_indices.contains(&ri)))
+        };
+        self.undo.retain(kept);
+        self.redo.retain(kept);
+        self.replaying.retain(kept);
+    }
+
+    fn sheet_records_mut<'a>(
+        &'a mut self,
+        category: &'a Option<String>,
+        sheet_name: &'a str,
+    ) -> impl Iterator<Item = &'a mut CellEditRecord> + 'a {
+        self.undo
+            .iter_mut()
+            .chain(self.redo.iter_mut())
+            .chain(self.replaying.iter_mut())
+            .filter(move |r| &r.category == category && r.sheet_name == sheet_name)
+    }
+
+    pub fn undo_len(&self) -> usize {
+        self.undo.len()
+    }
+
+    pub fn redo_len(&self) -> usize {
+        self.redo.len()
+    }
+
+    fn push_undo(&mut self, record: CellEditRecord) {
+        self.undo.push_back(record);
+        while self.undo.len() > EDIT_HISTORY_LIMIT {
+            self.undo.pop_front();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edit(row: usize, old: &str, new: &str) -> CellEditRecord {
+        CellEditRecord {
+            category: Some("Game".to_string()),
+            sheet_name: "Items".to_string(),
+            row,
+            row_index: Some(row as i64 + 100),
+            col: 2,
+            old_value: old.to_string(),
+            new_value: new.to_string(),
+        }
+    }
+
+    #[test]
+    fn undo_then_redo_round_trips_without_re_recording() {
+        let mut history = EditHistory::default();
+        history.record(edit(0, "a", "b"));
+
+        let inverse = history.undo().unwrap();
+        assert_eq!((inverse.old_value.as_str(), inverse.new_value.as_str()), ("b", "a"));
+        // The cell handler reports the applied inverse; it must not become a new edit
+        history.record(inverse);
+        assert_eq!((history.undo_len(), history.redo_len()), (0, 1));
+
+        let again = history.redo().unwrap();
+        history.record(again);
+        assert_eq!((history.undo_len(), history.redo_len()), (1, 0));
+    }
+
+    #[test]
+    fn fresh_edit_clears_redo_branch() {
+        let mut history = EditHistory::default();
+        history.record(edit(0, "a", "b"));
+        history.record(edit(1, "c", "d"));
+        history.undo();
+        history.finish_replays();
+        history.record(edit(3, "x", "y"));
+        assert_eq!(history.redo_len(), 0);
+        assert!(history.redo().is_none());
+    }
+
+    #[test]
+    fn stack_is_capped() {
+        let mut history = EditHistory::default();
+        for i in 0..EDIT_HISTORY_LIMIT + 25 {
+            history.record(edit(i, "old", "new"));
+        }
+        assert_eq!(history.undo_len(), EDIT_HISTORY_LIMIT);
+        // Oldest entries were dropped
+        let mut last = None;
+        while let Some(r) = history.undo() {
+            last = Some(r);
+        }
+        assert_eq!(last.unwrap().row, 25);
+    }
+
+    #[test]
+    fn remap_follows_moved_rows_of_the_same_sheet_only() {
+        let mut history = EditHistory::default();
+        history.record(edit(0, "a", "b")); // row_index 100
+        history.record(edit(1, "c", "d")); // row_index 101
+        let mut other = edit(0, "e", "f");
+        other.sheet_name = "Weapons".to_string();
+        history.record(other);
+
+        // Swap 100 and 101 in Items: both pairs apply at once, not one after the other
+        history.remap_row_indices(&Some("Game".to_string()), "Items", &[(100, 101), (101, 100)]);
+        let weapons = history.undo().unwrap();
+        assert_eq!(weapons.row_index, Some(100));
+        assert_eq!(history.undo().unwrap().row_index, Some(100));
+        assert_eq!(history.undo().unwrap().row_index, Some(101));
+    }
+
+    #[test]
+    fn clearing_a_sheet_keeps_other_sheets_records() {
+        let mut history = EditHistory::default();
+        history.record(edit(0, "a", "b"));
+        let mut other = edit(0, "e", "f");
+        other.sheet_name = "Weapons".to_string();
+        history.record(other);
+        history.record(edit(1, "c", "d"));
+        history.undo();
+
+        history.clear_sheet(&Some("Game".to_string()), "Items");
+        assert_eq!((history.undo_len(), history.redo_len()), (1, 0));
+        assert_eq!(history.undo().unwrap().sheet_name, "Weapons");
+    }
+
+    #[test]
+    fn dropping_row_indices_forgets_only_those_rows() {
+        let mut history = EditHistory::default();
+        history.record(edit(0, "a", "b")); // row_index 100
+        history.record(edit(1, "c", "d")); // row_index 101
+        history.undo();
+
+        history.drop_row_indices(&Some("Game".to_string()), "Items", &HashSet::from([101]));
+        assert_eq!((history.undo_len(), history.redo_len()), (1, 0));
+        assert_eq!(history.undo().unwrap().row_index, Some(100));
+    }
+
+    #[test]
+    fn insert_shifts_rows_at_and_below_the_insert_point() {
+        let mut history = EditHistory::default();
+        history.record(edit(0, "a", "b"));
+        history.record(edit(3, "c", "d"));
+        history.shift_rows_for_insert(&Some("Game".to_string()), "Items", 2);
+        assert_eq!(history.undo().unwrap().row, 4);
+        assert_eq!(history.undo().unwrap().row, 0);
+    }
+
+    #[test]
+    fn category_color_follows_rename_and_delete() {
+        let mut registry = SheetRegistry::default();
+        registry.create_category("Game".to_string()).unwrap();
+        registry.set_category_color("Game", Some([200, 40, 40]));
+
+        registry.rename_category("Game", "World").unwrap();
+        assert_eq!(registry.category_color("Game"), None);
+        assert_eq!(registry.category_color("World"), Some([200, 40, 40]));
+
+        registry.delete_category("World").unwrap();
+        assert_eq!(registry.category_color("World"), None);
+    }
+
+    #[test]
+    fn row_locks_follow_row_index_and_sheet_rename() {
+        let cat = Some("Game".to_string());
+        let mut registry = SheetRegistry::default();
+        let mut sheet = SheetGridData::default();
+        sheet.metadata = Some(SheetMetadata::create_generic(
+            "Items".to_string(),
+            "Items.json".to_string(),
+            1,
+            cat.clone(),
+        ));
+        sheet.grid = vec![vec!["a".into()], vec!["b".into()]];
+        sheet.row_indices = vec![5, 2];
+        registry.add_or_replace_sheet(cat.clone(), "Items".to_string(), sheet);
+
+        registry.set_row_locked(&cat, "Items", 2, true);
+        assert!(registry.is_grid_row_locked(&cat, "Items", 1));
+        assert!(!registry.is_grid_row_locked(&cat, "Items", 0));
+
+        registry.rename_sheet(&cat, "Items", "Gear".to_string()).unwrap();
+        assert!(registry.locked_rows(&cat, "Items").is_none());
+        assert!(registry.is_grid_row_locked(&cat, "Gear", 1));
+
+        registry.set_row_locked(&cat, "Gear", 2, false);
+        assert!(!registry.is_grid_row_locked(&cat, "Gear", 1));
+    }
+}
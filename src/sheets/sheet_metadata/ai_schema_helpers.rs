@@ -67,6 +67,18 @@ pub fn ensure_ai_schema_groups_initialized(meta: &mut SheetMetadata) {
     }
 }
 
+/// Moves a schema group to a new position. Returns false (and changes nothing) when the
+/// indices are equal or out of bounds. The active group is tracked by name, so it follows.
+pub fn reorder_ai_schema_group(meta: &mut SheetMetadata, from_index: usize, to_index: usize) -> bool {
+    let len = meta.ai_schema_groups.len();
+    if from_index == to_index || from_index >= len || to_index >= len {
+        return false;
+    }
+    let group = meta.ai_schema_groups.remove(from_index);
+    meta.ai_schema_groups.insert(to_index, group);
+    true
+}
+
 pub fn set_active_ai_schema_group_included_columns(
     meta: &mut SheetMetadata,
     included: &[usize],
@@ -142,10 +154,71 @@ pub fn set_active_ai_schema_group_allow_rows(meta: &mut SheetMetadata, allow: bo
     false
 }
 
+/// Set a group's "allow add rows" flag. The group's flag overrides the table-level
+/// `ai_enable_row_generation`, so for the active group that flag is updated too.
+/// Returns whether anything changed.
+pub fn set_ai_schema_group_allow_rows(
+    meta: &mut SheetMetadata,
+    group_name: &str,
+    allow: bool,
+) -> Result<bool, String> {
+    let group = meta
+        .ai_schema_groups
+        .iter_mut()
+        .find(|g| g.name == group_name)
+        .ok_or_else(|| format!("AI schema group '{}' not found", group_name))?;
+    let mut changed = group.allow_add_rows != allow;
+    group.allow_add_rows = allow;
+    if meta.ai_active_schema_group.as_deref() == Some(group_name)
+        && meta.ai_enable_row_generation != allow
+    {
+        meta.ai_enable_row_generation = allow;
+        changed = true;
+    }
+    Ok(changed)
+}
+
+/// Whether AI may append rows: the active group's flag, or the table-level flag when no
+/// group is active
+pub fn effective_ai_allow_add_rows(meta: &SheetMetadata) -> bool {
+    meta.ai_active_schema_group
+        .as_deref()
+        .and_then(|name| meta.ai_schema_groups.iter().find(|g| g.name == name))
+        .map_or(meta.ai_enable_row_generation, |group| group.allow_add_rows)
+}
+
 pub fn set_active_ai_schema_group_structure_override(
     meta: &mut SheetMetadata,
     path: &[usize],
     override_value: Option<bool>,
+) -> bool {
+    update_active_group_structure_entry(meta, path, |entry| {
+        let changed = entry.allow_add_rows != override_value;
+        entry.allow_add_rows = override_value;
+        changed
+    })
+}
+
+/// Marks a structure path of the active group as skipped (or not) by AI processing.
+/// Returns whether anything changed.
+pub fn set_active_ai_schema_group_structure_skip(
+    meta: &mut SheetMetadata,
+    path: &[usize],
+    skip: bool,
+) -> bool {
+    update_active_group_structure_entry(meta, path, |entry| {
+        let changed = entry.skip != skip;
+        entry.skip = skip;
+        changed
+    })
+}
+
+/// Applies `update` to the active group's entry for `path`, creating it if needed and
+/// dropping it again once it neither overrides row generation nor skips the path.
+fn update_active_group_structure_entry(
+    meta: &mut SheetMetadata,
+    path: &[usize],
+    update: impl FnOnce(&mut AiSchemaGroupStructureOverride) -> bool,
 ) -> bool {
     if path.is_empty() || !meta.structure_path_exists(path) {
         return false;
@@ -163,36 +236,24 @@ pub fn set_active_ai_schema_group_structure_override(
         return false;
     };
 
-    if let Some(value) = override_value {
-        if let Some(entry) = group
-            .structure_row_generation_overrides
-            .iter_mut()
-            .find(|entry| entry.path == path)
-        {
-            if entry.allow_add_rows != value {
-                entry.allow_add_rows = value;
-                return true;
-            }
-            return false;
-        }
-
-        group
-            .structure_row_generation_overrides
-            .push(AiSchemaGroupStructureOverride {
+    let overrides = &mut group.structure_row_generation_overrides;
+    let position = match overrides.iter().position(|entry| entry.path == path) {
+        Some(position) => position,
+        None => {
+            overrides.push(AiSchemaGroupStructureOverride {
                 path: path.to_vec(),
-                allow_add_rows: value,
+                allow_add_rows: None,
+                skip: false,
             });
-        group
-            .structure_row_generation_overrides
-            .sort_by(|a, b| a.path.cmp(&b.path));
-        true
-    } else {
-        let original_len = group.structure_row_generation_overrides.len();
-        group
-            .structure_row_generation_overrides
-            .retain(|entry| entry.path != path);
-        original_len != group.structure_row_generation_overrides.len()
+            overrides.len() - 1
+        }
+    };
+    let changed = update(&mut overrides[position]);
+    if overrides[position].allow_add_rows.is_none() && !overrides[position].skip {
+        overrides.remove(position);
     }
+    overrides.sort_by(|a, b| a.path.cmp(&b.path));
+    changed
 }
 
 pub fn apply_ai_schema_group(meta: &mut SheetMetadata, group_name: &str) -> Result<bool, String> {
@@ -266,3 +327,81 @@ pub fn ensure_unique_schema_group_name(meta: &SheetMetadata, desired: &str) -> S
         counter += 1;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn group(name: &str, allow_add_rows: bool) -> AiSchemaGroup {
+        AiSchemaGroup {
+            name: name.to_string(),
+            included_columns: Vec::new(),
+            allow_add_rows,
+            structure_row_generation_overrides: Vec::new(),
+            included_structures: Vec::new(),
+        }
+    }
+
+    fn meta_with_groups() -> SheetMetadata {
+        let mut meta = SheetMetadata::create_generic("Items".to_string(), "Items.json".to_string(), 1, None);
+        meta.ai_enable_row_generation = true;
+        meta.ai_schema_groups = vec![group("Draft", true), group("Final", false)];
+        meta.ai_active_schema_group = Some("Draft".to_string());
+        meta
+    }
+
+    #[test]
+    fn active_group_overrides_table_flag() {
+        let mut meta = meta_with_groups();
+        meta.ai_enable_row_generation = false;
+        assert!(effective_ai_allow_add_rows(&meta));
+
+        meta.ai_active_schema_group = None;
+        assert!(!effective_ai_allow_add_rows(&meta));
+    }
+
+    #[test]
+    fn switching_groups_switches_the_effective_flag() {
+        let mut meta = meta_with_groups();
+        assert!(apply_ai_schema_group(&mut meta, "Final").unwrap());
+        assert!(!meta.ai_enable_row_generation);
+        assert!(!effective_ai_allow_add_rows(&meta));
+    }
+
+    #[test]
+    fn structure_skip_is_kept_apart_from_row_generation() {
+        let mut meta = meta_with_groups();
+        meta.columns[0].validator = Some(ColumnValidator::Structure);
+        meta.columns[0].structure_schema = Some(Vec::new());
+        let entries = |meta: &SheetMetadata| meta.ai_schema_groups[0].structure_row_generation_overrides.clone();
+
+        assert!(set_active_ai_schema_group_structure_skip(&mut meta, &[0], true));
+        assert_eq!(entries(&meta)[0].allow_add_rows, None);
+        assert!(entries(&meta)[0].skip);
+
+        assert!(set_active_ai_schema_group_structure_override(&mut meta, &[0], Some(false)));
+        assert!(set_active_ai_schema_group_structure_skip(&mut meta, &[0], false));
+        assert_eq!(entries(&meta)[0].allow_add_rows, Some(false));
+        assert!(!entries(&meta)[0].skip);
+
+        // An entry that neither overrides nor skips is dropped
+        assert!(set_active_ai_schema_group_structure_override(&mut meta, &[0], None));
+        assert!(entries(&meta).is_empty());
+        assert!(!set_active_ai_schema_group_structure_skip(&mut meta, &[0], false));
+    }
+
+    #[test]
+    fn group_flag_updates_table_flag_only_for_the_active_group() {
+        let mut meta = meta_with_groups();
+        assert!(set_ai_schema_group_allow_rows(&mut meta, "Final", true).unwrap());
+        assert!(meta.ai_enable_row_generation);
+        assert!(meta.ai_schema_groups[1].allow_add_rows);
+
+        assert!(set_ai_schema_group_allow_rows(&mut meta, "Draft", false).unwrap());
+        assert!(!meta.ai_enable_row_generation);
+        assert!(!effective_ai_allow_add_rows(&meta));
+
+        assert!(!set_ai_schema_group_allow_rows(&mut meta, "Draft", false).unwrap());
+        assert!(set_ai_schema_group_allow_rows(&mut meta, "Missing", true).is_err());
+    }
+}
@@ -65,6 +65,13 @@ impl<'de> Deserialize<'de> for SheetMetadata {
             #[serde(default)]
             hidden: bool,
             #[serde(default)]
+            ai_key_column: Option<String>,
+            /// Position-based key column written by earlier versions
+            #[serde(default)]
+            ai_key_column_index: Option<usize>,
+            #[serde(default)]
+            color: Option<[u8; 3]>,
+            #[serde(default)]
             structures_meta: HashMap<String, LegacyStructureColumnMeta>,
         }
 
@@ -87,6 +94,8 @@ impl<'de> Deserialize<'de> for SheetMetadata {
                 random_picker: cur.random_picker,
                 structure_parent: cur.structure_parent,
                 hidden: cur.hidden,
+                ai_key_column: cur.ai_key_column,
+                color: cur.color,
             };
 
             // Auto-migrate deprecated AI sampling params if they equal legacy defaults
@@ -116,7 +125,12 @@ impl<'de> Deserialize<'de> for SheetMetadata {
                             Some(entry.ancestor_key_parent_column_indices.clone());
                     }
                 }
-                col.width = None; // discard legacy width
+            }
+
+            if meta.ai_key_column.is_none() {
+                if let Some(idx) = cur.ai_key_column_index {
+                    meta.set_ai_key_column(Some(idx));
+                }
             }
 
             meta.ensure_column_consistency();
@@ -69,6 +69,12 @@ pub(super) fn deserialize_legacy_format<E: de::Error>(value: Value) -> Result<Sh
             ai_include_in_send: None,
             deleted: false,
             hidden: false,
+            trim_numeric_zeros: false,
+            ai_read_only: false,
+            multiline: false,
+            enum_auto_add: false,
+            numeric_range: None,
+            default_value: None,
             width: None,
             structure_schema: None,
             structure_column_order: None,
@@ -96,6 +102,8 @@ pub(super) fn deserialize_legacy_format<E: de::Error>(value: Value) -> Result<Sh
         random_picker: legacy.random_picker,
         structure_parent: None,
         hidden: false,
+        ai_key_column: None,
+        color: None,
     };
 
     // Auto-migrate deprecated AI sampling params if they equal legacy defaults
@@ -58,6 +58,14 @@ pub struct SheetMetadata {
     pub structure_parent: Option<StructureParentLink>,
     #[serde(default)]
     pub hidden: bool,
+    /// Header of the grid column used as the row's display value/key in AI payloads.
+    /// `None` uses the first data column. Kept by header so moving or deleting other
+    /// columns cannot point it at a different one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ai_key_column: Option<String>,
+    /// RGB tag color tinting the sheet's tab in the bottom panel. `None` uses the theme color.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<[u8; 3]>,
 }
 
 impl SheetMetadata {
@@ -88,6 +96,8 @@ impl SheetMetadata {
             random_picker: None,
             structure_parent: None,
             hidden: false,
+            ai_key_column: None,
+            color: None,
         };
         meta.ensure_ai_schema_groups_initialized();
         meta
@@ -119,7 +129,7 @@ impl SheetMetadata {
         self
             .columns
             .iter()
-            .map(|c| c.display_header.as_ref().cloned().unwrap_or_else(|| c.header.clone()))
+            .map(|c| c.display_name().to_string())
             .collect()
     }
 
@@ -148,10 +158,25 @@ impl SheetMetadata {
         structure_helpers::collect_included_structure_paths(&self.columns)
     }
 
+    /// Every path that leads to a Structure column or nested Structure field, sorted.
+    pub fn structure_table_paths(&self) -> Vec<Vec<usize>> {
+        let mut paths: Vec<Vec<usize>> =
+            structure_helpers::collect_all_structure_paths(&self.columns)
+                .into_iter()
+                .filter(|path| self.structure_fields_for_path(path).is_some())
+                .collect();
+        paths.sort();
+        paths
+    }
+
     pub fn ensure_ai_schema_groups_initialized(&mut self) {
         ai_schema_helpers::ensure_ai_schema_groups_initialized(self);
     }
 
+    pub fn reorder_ai_schema_group(&mut self, from_index: usize, to_index: usize) -> bool {
+        ai_schema_helpers::reorder_ai_schema_group(self, from_index, to_index)
+    }
+
     pub fn set_active_ai_schema_group_included_columns(&mut self, included: &[usize]) -> bool {
         ai_schema_helpers::set_active_ai_schema_group_included_columns(self, included)
     }
@@ -167,6 +192,20 @@ impl SheetMetadata {
         ai_schema_helpers::set_active_ai_schema_group_allow_rows(self, allow)
     }
 
+    pub fn set_ai_schema_group_allow_rows(
+        &mut self,
+        group_name: &str,
+        allow: bool,
+    ) -> Result<bool, String> {
+        ai_schema_helpers::set_ai_schema_group_allow_rows(self, group_name, allow)
+    }
+
+    /// Whether AI may append rows to this table (the active schema group overrides the
+    /// table-level flag)
+    pub fn effective_ai_allow_add_rows(&self) -> bool {
+        ai_schema_helpers::effective_ai_allow_add_rows(self)
+    }
+
     pub fn apply_structure_send_inclusion(&mut self, included_paths: &[Vec<usize>]) -> bool {
         structure_helpers::apply_structure_send_inclusion(&mut self.columns, included_paths)
     }
@@ -183,6 +222,10 @@ impl SheetMetadata {
         )
     }
 
+    pub fn set_active_ai_schema_group_structure_skip(&mut self, path: &[usize], skip: bool) -> bool {
+        ai_schema_helpers::set_active_ai_schema_group_structure_skip(self, path, skip)
+    }
+
     pub fn describe_structure_path(&self, path: &[usize]) -> Option<String> {
         structure_helpers::describe_structure_path(&self.columns, path)
     }
@@ -295,4 +338,46 @@ impl SheetMetadata {
             .cloned()
             .unwrap_or_default()
     }
+
+    /// The configured AI key/display column, if it still points at a usable data column
+    ///
+    /// Deleted, technical, metadata and Structure columns are rejected so a stale
+    /// setting silently falls back to the default key column.
+    pub fn ai_key_column_override(&self) -> Option<usize> {
+        let header = self.ai_key_column.as_deref()?;
+        let (idx, col) = self
+            .columns
+            .iter()
+            .enumerate()
+            .find(|(_, c)| !c.deleted && c.header == header)?;
+        if Self::is_technical_column_header(&col.header)
+            || Self::is_metadata_column_header(&col.header)
+            || matches!(col.validator, Some(ColumnValidator::Structure))
+        {
+            return None;
+        }
+        Some(idx)
+    }
+
+    /// Set the AI key/display column from a grid column index (`None` = default)
+    pub fn set_ai_key_column(&mut self, col_index: Option<usize>) {
+        self.ai_key_column = col_index
+            .and_then(|idx| self.columns.get(idx))
+            .map(|c| c.header.clone());
+    }
+
+    /// Column whose value identifies a row to the AI: the configured key column,
+    /// otherwise the first data column
+    pub fn ai_display_column_index(&self) -> Option<usize> {
+        self.ai_key_column_override()
+            .or_else(|| self.find_first_data_column_index())
+    }
+
+    /// Display value identifying `row` to the AI (see `ai_display_column_index`)
+    pub fn get_ai_display_value(&self, row: &[String]) -> String {
+        match self.ai_key_column_override() {
+            Some(idx) => row.get(idx).cloned().unwrap_or_default(),
+            None => self.get_first_data_column_value(row),
+        }
+    }
 }
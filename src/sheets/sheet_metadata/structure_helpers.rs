@@ -214,7 +214,8 @@ fn collect_structure_row_overrides_from_column(
     if let Some(value) = column.ai_enable_row_generation {
         output.push(AiSchemaGroupStructureOverride {
             path: vec![column_index],
-            allow_add_rows: value,
+            allow_add_rows: Some(value),
+            skip: false,
         });
     }
 
@@ -234,7 +235,8 @@ fn collect_structure_row_overrides_from_field(
     if let Some(value) = field.ai_enable_row_generation {
         output.push(AiSchemaGroupStructureOverride {
             path: path.clone(),
-            allow_add_rows: value,
+            allow_add_rows: Some(value),
+            skip: false,
         });
     }
 
@@ -254,8 +256,9 @@ pub fn apply_structure_row_generation_overrides(
     let mut desired: HashMap<Vec<usize>, bool> = overrides
         .iter()
         .filter_map(|entry| {
+            let value = entry.allow_add_rows?;
             if structure_path_exists(columns, &entry.path) {
-                Some((entry.path.clone(), entry.allow_add_rows))
+                Some((entry.path.clone(), value))
             } else {
                 warn!(
                     "Skipping AI schema group structure override with invalid path: {:?}",
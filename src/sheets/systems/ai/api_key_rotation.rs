@@ -0,0 +1,99 @@
+// src/sheets/systems/ai/api_key_rotation.rs
+//! API key rotation: a new key is validated against the provider before it replaces
+//! the keyring entry and `SessionApiKey`. A rejected key leaves the old one in place.
+
+use bevy::prelude::*;
+use bevy_tokio_tasks::TokioTasksRuntime;
+
+use crate::sheets::events::{ApiKeyValidationResult, RequestRotateApiKey, SheetOperationFeedback};
+use crate::sheets::systems::ai::structure_processor::python_executor;
+use crate::ui::elements::editor::state::EditorWindowState;
+use crate::{ApiKeyDisplayStatus, SessionApiKey};
+
+/// Keyring service shared with Settings, startup loading and the Python processor
+const KEYRING_SERVICE: &str = "GoogleGeminiAPI";
+
+/// Spawn a background validation for each rotation request
+pub fn handle_api_key_rotation_request(
+    mut events: EventReader<RequestRotateApiKey>,
+    runtime: Res<TokioTasksRuntime>,
+    mut api_key_status: ResMut<ApiKeyDisplayStatus>,
+) {
+    for event in events.read() {
+        let new_key = event.new_key.trim().to_string();
+        if new_key.is_empty() {
+            continue;
+        }
+        api_key_status.status = "Validating new key...".to_string();
+        info!("Validating new API key before saving it.");
+
+        runtime.spawn_background_task(move |mut ctx| async move {
+            let result = python_executor::validate_api_key(new_key.clone()).await;
+            ctx.run_on_main_thread(move |ctx| {
+                ctx.world
+                    .send_event(ApiKeyValidationResult { new_key, result });
+            })
+            .await;
+        });
+    }
+}
+
+/// Store a validated key in the keyring and session; keep the previous key otherwise
+pub fn apply_api_key_validation_result(
+    mut events: EventReader<ApiKeyValidationResult>,
+    mut session_api_key: ResMut<SessionApiKey>,
+    mut api_key_status: ResMut<ApiKeyDisplayStatus>,
+    mut editor_state: Option<ResMut<EditorWindowState>>,
+    mut feedback_writer: EventWriter<SheetOperationFeedback>,
+) {
+    for event in events.read() {
+        if let Some(state) = editor_state.as_deref_mut() {
+            state.settings_api_key_validating = false;
+        }
+        let had_key = session_api_key.0.is_some();
+
+        if let Err(e) = &event.result {
+            warn!("New API key rejected: {}", e);
+            api_key_status.status = if had_key {
+                "New key rejected - previous key kept".to_string()
+            } else {
+                "New key rejected".to_string()
+            };
+            feedback_writer.write(SheetOperationFeedback {
+                message: format!("API key validation failed: {}", e),
+                is_error: true,
+            });
+            continue;
+        }
+
+        let username = whoami::username();
+        match keyring::Entry::new(KEYRING_SERVICE, username.as_str())
+            .and_then(|entry| entry.set_password(&event.new_key))
+        {
+            Ok(_) => {
+                info!("Validated API key saved to the credential store.");
+                session_api_key.0 = Some(event.new_key.clone());
+                api_key_status.status = "Key Set (validated)".to_string();
+                if let Some(state) = editor_state.as_deref_mut() {
+                    state.settings_new_api_key_input.clear();
+                }
+                feedback_writer.write(SheetOperationFeedback {
+                    message: "API key validated and saved.".to_string(),
+                    is_error: false,
+                });
+            }
+            Err(e) => {
+                warn!("Failed to save validated API key to the credential store: {}", e);
+                api_key_status.status = if had_key {
+                    "Key valid but not saved - previous key kept".to_string()
+                } else {
+                    "Key valid but not saved".to_string()
+                };
+                feedback_writer.write(SheetOperationFeedback {
+                    message: format!("API key is valid but could not be saved: {}", e),
+                    is_error: true,
+                });
+            }
+        }
+    }
+}
@@ -3,27 +3,17 @@
 // from multiple UI layouts.
 use bevy::prelude::*;
 use bevy_tokio_tasks::TokioTasksRuntime;
-use pyo3::{exceptions::PyValueError, prelude::*, types::PyString};
-use std::ffi::CString;
+use pyo3::{exceptions::PyValueError, prelude::*};
 
 use crate::{
     sheets::{
         events::{AiBatchResultKind, AiBatchTaskResult},
+        systems::ai::{python_runtime::require_python_runtime, structure_processor::python_executor},
     },
     ui::systems::SendEvent,
     SessionApiKey,
 };
 
-/// Rewrite the Python processor file to ensure it's up to date before execution
-fn rewrite_python_processor() {
-    const AI_PROCESSOR_PY: &str = include_str!("../../../../script/ai_processor.py");
-    let script_path = std::path::Path::new("script/ai_processor.py");
-    if let Some(parent) = script_path.parent() {
-        let _ = std::fs::create_dir_all(parent);
-    }
-    let _ = std::fs::write(script_path, AI_PROCESSOR_PY);
-}
-
 /// Parent key information for structured data (e.g., structure columns)
 #[derive(Clone, serde::Serialize, Debug)]
 pub struct ParentKeyInfo {
@@ -50,6 +40,9 @@ pub struct BatchPayload {
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub rows_data: Vec<Vec<String>>,
     pub requested_grounding_with_google_search: bool,
+    /// Sampling temperature; omitted when unset so the model default applies
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ai_temperature: Option<f32>,
     pub allow_row_additions: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub key_prefix_count: Option<usize>,
@@ -131,7 +124,7 @@ pub fn spawn_batch_task(
     key_prefix_count: usize,
 ) {
     // Rewrite Python processor file to ensure it's up to date
-    rewrite_python_processor();
+    python_executor::rewrite_python_processor();
 
     let api_key_for_task = api_key.0.clone();
     let included_cols_clone = included_cols.clone();
@@ -139,10 +132,13 @@ pub fn spawn_batch_task(
     let key_prefix_count_clone = key_prefix_count;
     let commands_entity = commands.spawn_empty().id();
     runtime.spawn_background_task(move |mut ctx| async move {
-        let api_key_value = match api_key_for_task {
-            Some(k) if !k.is_empty() => k,
-            _ => {
-                let err_msg = "API Key not set".to_string();
+        let api_key_value = match api_key_for_task
+            .filter(|k| !k.is_empty())
+            .ok_or_else(|| "API Key not set".to_string())
+            .and_then(|k| require_python_runtime().map(|_| k))
+        {
+            Ok(k) => k,
+            Err(err_msg) => {
                 ctx.run_on_main_thread(move |world_ctx| {
                     world_ctx
                         .world
@@ -166,57 +162,49 @@ pub fn spawn_batch_task(
             }
         };
         let (result, raw_response) = tokio::task::spawn_blocking(move || {
-            Python::with_gil(
-                |py| -> PyResult<(Result<Vec<Vec<String>>, String>, Option<String>)> {
-                    let python_file_path = "script/ai_processor.py";
-                    let processor_code_string = std::fs::read_to_string(python_file_path)?;
-                    let code_c_str = CString::new(processor_code_string)
-                        .map_err(|e| PyValueError::new_err(format!("CString error: {}", e)))?;
-                    let file_name_c_str = CString::new(python_file_path).map_err(|e| {
-                        PyValueError::new_err(format!("File name CString error: {}", e))
-                    })?;
-                    let module_name_c_str = CString::new("ai_processor").map_err(|e| {
-                        PyValueError::new_err(format!("Module name CString error: {}", e))
-                    })?;
-                    let module = PyModule::from_code(
-                        py,
-                        code_c_str.as_c_str(),
-                        file_name_c_str.as_c_str(),
-                        module_name_c_str.as_c_str(),
-                    )?;
-                    let binding =
-                        module.call_method1("execute_ai_query", (api_key_value, payload_json))?;
-                    let result_json_str: &str = binding.downcast::<PyString>()?.to_str()?;
-                    #[derive(serde::Deserialize)]
-                    struct PyResp {
-                        success: bool,
-                        data: Option<serde_json::Value>,
-                        error: Option<String>,
-                        raw_response: Option<String>,
-                    }
-                    let resp: PyResp = serde_json::from_str(result_json_str)
-                        .map_err(|e| PyValueError::new_err(format!("Parse JSON error: {}", e)))?;
-                    if resp.success {
-                        if let Some(data) = resp.data {
-                            let parsed_result = parse_python_response_data(&data);
-                            Ok((parsed_result, resp.raw_response))
+            Python::with_gil(|py| {
+                let (call_result, captured) =
+                    python_executor::call_ai_processor(py, api_key_value, payload_json);
+                let parsed = call_result.and_then(
+                    |result_json_str| -> PyResult<(Result<Vec<Vec<String>>, String>, Option<String>)> {
+                        #[derive(serde::Deserialize)]
+                        struct PyResp {
+                            success: bool,
+                            data: Option<serde_json::Value>,
+                            error: Option<String>,
+                            raw_response: Option<String>,
+                        }
+                        let resp: PyResp = serde_json::from_str(&result_json_str)
+                            .map_err(|e| PyValueError::new_err(format!("Parse JSON error: {}", e)))?;
+                        if resp.success {
+                            if let Some(data) = resp.data {
+                                let parsed_result = parse_python_response_data(&data);
+                                Ok((parsed_result, resp.raw_response))
+                            } else {
+                                Ok((Err("No data returned".to_string()), resp.raw_response))
+                            }
                         } else {
-                            Ok((Err("No data returned".to_string()), resp.raw_response))
+                            Ok((
+                                Err(resp
+                                    .error
+                                    .unwrap_or_else(|| "Unknown batch error".to_string())),
+                                resp.raw_response,
+                            ))
                         }
-                    } else {
-                        Ok((
-                            Err(resp
-                                .error
-                                .unwrap_or_else(|| "Unknown batch error".to_string())),
-                            resp.raw_response,
-                        ))
-                    }
-                },
-            )
+                    },
+                );
+                match parsed {
+                    Ok((Err(e), raw)) => (Err(captured.append_stderr(e)), raw),
+                    Ok(ok) => ok,
+                    Err(e) => (
+                        Err(captured.append_stderr(format!("PyO3 error: {}", e))),
+                        Some(e.to_string()),
+                    ),
+                }
+            })
         })
         .await
-        .unwrap_or_else(|e| Ok((Err(format!("Tokio panic: {}", e)), None)))
-        .unwrap_or_else(|e| (Err(format!("PyO3 error: {}", e)), Some(e.to_string())));
+        .unwrap_or_else(|e| (Err(format!("Tokio panic: {}", e)), None));
         ctx.run_on_main_thread(move |world_ctx| {
             world_ctx
                 .world
@@ -11,6 +11,101 @@ use super::parent_chain_helpers::{
 };
 use crate::sheets::systems::ai::column_helpers::extract_linked_column_info;
 
+/// How AI-suggested rows are matched against existing rows when looking for duplicates
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DuplicateMatchOptions {
+    /// Also catch near-duplicates (punctuation/spacing differences, small typos)
+    pub fuzzy: bool,
+    /// Minimum similarity (0.0..=1.0) a fuzzy candidate needs to count as a duplicate
+    pub similarity_threshold: f32,
+}
+
+impl DuplicateMatchOptions {
+    pub fn from_state(state: &EditorWindowState) -> Self {
+        Self {
+            fuzzy: state.ai_duplicate_fuzzy,
+            similarity_threshold: state.ai_duplicate_similarity.clamp(0.0, 1.0),
+        }
+    }
+}
+
+/// Comparable form for fuzzy matching: normalized and stripped of punctuation and whitespace,
+/// so "F-16C" and "f16 c" agree. Composite keys keep their column boundaries.
+pub fn fuzzy_key(value: &str) -> String {
+    value
+        .split("||")
+        .map(|part| {
+            normalize_cell_value(part)
+                .chars()
+                .filter(|c| c.is_alphanumeric())
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("|")
+}
+
+/// Character-level Levenshtein distance
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b_chars.len()).collect();
+    let mut curr = vec![0; b_chars.len() + 1];
+    for (i, ca) in a.chars().enumerate() {
+        curr[0] = i + 1;
+        for (j, cb) in b_chars.iter().enumerate() {
+            let cost = if ca == *cb { 0 } else { 1 };
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b_chars.len()]
+}
+
+/// Similarity in 0.0..=1.0 (1.0 = identical) based on edit distance relative to the longer string
+pub fn similarity(a: &str, b: &str) -> f32 {
+    let max_len = a.chars().count().max(b.chars().count());
+    if max_len == 0 {
+        return 1.0;
+    }
+    1.0 - levenshtein(a, b) as f32 / max_len as f32
+}
+
+/// Look up an already-normalized value (or composite key) in a duplicate map.
+/// Exact hits always win; with fuzzy matching enabled the most similar key at or above
+/// the threshold is used (ties go to the earliest row).
+pub fn find_duplicate_in_map(
+    candidate: &str,
+    map: &HashMap<String, usize>,
+    options: DuplicateMatchOptions,
+) -> Option<usize> {
+    if let Some(&row) = map.get(candidate) {
+        return Some(row);
+    }
+    if !options.fuzzy {
+        return None;
+    }
+    let candidate_key = fuzzy_key(candidate);
+    if candidate_key.chars().all(|c| c == '|') {
+        return None;
+    }
+    let mut best: Option<(f32, usize)> = None;
+    for (existing, &row) in map {
+        let score = similarity(&candidate_key, &fuzzy_key(existing));
+        if score < options.similarity_threshold {
+            continue;
+        }
+        let better = match best {
+            None => true,
+            Some((best_score, best_row)) => {
+                score > best_score || (score == best_score && row < best_row)
+            }
+        };
+        if better {
+            best = Some((score, row));
+        }
+    }
+    best.map(|(_, row)| row)
+}
+
 /// Resolve a linked cell's stored value (usually a row_index) to its display text in the target sheet,
 /// and return the normalized comparable string.
 pub fn resolve_linked_display_value(
@@ -237,3 +332,39 @@ pub fn build_composite_duplicate_map_for_parents(
 
     map
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fuzzy(threshold: f32) -> DuplicateMatchOptions {
+        DuplicateMatchOptions { fuzzy: true, similarity_threshold: threshold }
+    }
+
+    #[test]
+    fn exact_mode_ignores_near_duplicates() {
+        let map = HashMap::from([("f-16c".to_string(), 3)]);
+        let exact = DuplicateMatchOptions { fuzzy: false, similarity_threshold: 0.85 };
+        assert_eq!(find_duplicate_in_map("f-16c", &map, exact), Some(3));
+        assert_eq!(find_duplicate_in_map("f16c", &map, exact), None);
+    }
+
+    #[test]
+    fn fuzzy_mode_matches_punctuation_and_typos() {
+        let map = HashMap::from([("f-16c".to_string(), 3), ("mig-29".to_string(), 5)]);
+        assert_eq!(find_duplicate_in_map("f16c", &map, fuzzy(1.0)), Some(3));
+        assert_eq!(find_duplicate_in_map("f 16 c", &map, fuzzy(1.0)), Some(3));
+        // One substitution in five comparable characters (similarity 0.8)
+        assert_eq!(find_duplicate_in_map("mig-2g", &map, fuzzy(0.75)), Some(5));
+        assert_eq!(find_duplicate_in_map("mig-2g", &map, fuzzy(0.9)), None);
+        assert_eq!(find_duplicate_in_map("", &map, fuzzy(0.0)), None);
+    }
+
+    #[test]
+    fn fuzzy_composite_keys_keep_column_boundaries() {
+        let map = HashMap::from([("ab||c".to_string(), 1)]);
+        assert_eq!(fuzzy_key("A-b||c"), "ab|c");
+        assert_eq!(find_duplicate_in_map("a||bc", &map, fuzzy(1.0)), None);
+        assert_eq!(find_duplicate_in_map("a b||c", &map, fuzzy(1.0)), Some(1));
+    }
+}
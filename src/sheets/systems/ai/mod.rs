@@ -20,8 +20,13 @@ pub mod structure_jobs; // Structure job enqueueing logic
 pub mod structure_results; // Structure result processing helpers
 
 // Other systems
+pub mod api_key_rotation; // Validate-then-save API key rotation
+pub mod models; // Curated Gemini model IDs with context window / grounding metadata
+pub mod python_runtime; // Session-cached check for the Python interpreter and AI packages
+pub mod script_file; // Where the bundled ai_processor.py is written (configurable)
 pub mod structure_processor;
 pub mod throttled;
+pub mod usage_ledger; // Per-sheet AI request/row counts persisted across sessions
 pub mod utils; // shared helpers (parser)
 pub mod cache {
     pub mod linked_column_cache;
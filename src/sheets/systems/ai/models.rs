@@ -0,0 +1,111 @@
+// src/sheets/systems/ai/models.rs
+//! Curated list of Gemini model IDs offered in the AI Context popup. Any other ID can still
+//! be typed in as a custom model; these entries only drive the dropdown and its hints.
+
+/// Known Gemini model and what the UI needs to know about it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GeminiModelInfo {
+    pub id: &'static str,
+    pub label: &'static str,
+    /// Input context window in tokens
+    pub context_window: u32,
+    /// Whether the model accepts Google Search grounding
+    pub supports_grounding: bool,
+}
+
+pub const KNOWN_GEMINI_MODELS: &[GeminiModelInfo] = &[
+    GeminiModelInfo {
+        id: "gemini-flash-latest",
+        label: "Gemini Flash (latest)",
+        context_window: 1_048_576,
+        supports_grounding: true,
+    },
+    GeminiModelInfo {
+        id: "gemini-flash-lite-latest",
+        label: "Gemini Flash-Lite (latest)",
+        context_window: 1_048_576,
+        supports_grounding: true,
+    },
+    GeminiModelInfo {
+        id: "gemini-2.5-pro",
+        label: "Gemini 2.5 Pro",
+        context_window: 1_048_576,
+        supports_grounding: true,
+    },
+    GeminiModelInfo {
+        id: "gemini-2.5-flash",
+        label: "Gemini 2.5 Flash",
+        context_window: 1_048_576,
+        supports_grounding: true,
+    },
+    GeminiModelInfo {
+        id: "gemini-2.5-flash-lite",
+        label: "Gemini 2.5 Flash-Lite",
+        context_window: 1_048_576,
+        supports_grounding: true,
+    },
+    GeminiModelInfo {
+        id: "gemini-2.0-flash",
+        label: "Gemini 2.0 Flash",
+        context_window: 1_048_576,
+        supports_grounding: true,
+    },
+    GeminiModelInfo {
+        id: "gemini-2.0-flash-lite",
+        label: "Gemini 2.0 Flash-Lite",
+        context_window: 1_048_576,
+        supports_grounding: false,
+    },
+    GeminiModelInfo {
+        id: "gemini-1.5-pro",
+        label: "Gemini 1.5 Pro",
+        context_window: 2_097_152,
+        supports_grounding: true,
+    },
+    GeminiModelInfo {
+        id: "gemini-1.5-flash",
+        label: "Gemini 1.5 Flash",
+        context_window: 1_048_576,
+        supports_grounding: true,
+    },
+    GeminiModelInfo {
+        id: "gemini-1.5-flash-8b",
+        label: "Gemini 1.5 Flash-8B",
+        context_window: 1_048_576,
+        supports_grounding: false,
+    },
+];
+
+/// Curated entry for a model ID (surrounding whitespace ignored), None for custom IDs
+pub fn find_known_model(model_id: &str) -> Option<&'static GeminiModelInfo> {
+    let model_id = model_id.trim();
+    KNOWN_GEMINI_MODELS.iter().find(|m| m.id == model_id)
+}
+
+/// Grounding is assumed available for custom IDs; only curated entries can rule it out
+pub fn model_supports_grounding(model_id: &str) -> bool {
+    find_known_model(model_id).is_none_or(|m| m.supports_grounding)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sheets::definitions::default_ai_model_id;
+
+    #[test]
+    fn default_model_is_listed_and_ids_are_unique() {
+        assert!(find_known_model(&default_ai_model_id()).is_some());
+        let mut ids: Vec<&str> = KNOWN_GEMINI_MODELS.iter().map(|m| m.id).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), KNOWN_GEMINI_MODELS.len());
+    }
+
+    #[test]
+    fn grounding_support_falls_back_to_true_for_custom_ids() {
+        assert!(model_supports_grounding(" gemini-2.5-flash "));
+        assert!(!model_supports_grounding("gemini-2.0-flash-lite"));
+        assert!(model_supports_grounding("my-tuned-model-001"));
+        assert!(find_known_model("my-tuned-model-001").is_none());
+    }
+}
@@ -10,7 +10,9 @@ use super::row_helpers::{
     extract_ai_snapshot_from_new_row, normalize_cell_value, skip_key_prefix,
 };
 use super::column_helpers::calculate_dynamic_prefix;
-use super::duplicate_map_helpers::build_composite_duplicate_map_for_parents;
+use super::duplicate_map_helpers::{
+    build_composite_duplicate_map_for_parents, find_duplicate_in_map, DuplicateMatchOptions,
+};
 
 /// Detect which new rows are duplicates of existing rows (by first column)
 pub fn detect_duplicate_indices(
@@ -22,6 +24,7 @@ pub fn detect_duplicate_indices(
 ) -> Vec<usize> {
     let mut duplicate_indices = Vec::new();
     let (cat_ctx, sheet_ctx) = state.current_sheet_context();
+    let match_options = DuplicateMatchOptions::from_state(state);
 
     info!(
         "detect_duplicate_indices: extra_slice.len()={}, included={:?}, sheet_ctx={:?}",
@@ -82,17 +85,18 @@ pub fn detect_duplicate_indices(
         }
 
         let ai_composite = ai_composite_parts.join("||");
+        let matched_row = find_duplicate_in_map(&ai_composite, &composite_map, match_options);
 
         info!(
-            "Row {}: ai_provided_parents={:?}, ai_snapshot={:?}, ai_composite='{}', in_map={}",
+            "Row {}: ai_provided_parents={:?}, ai_snapshot={:?}, ai_composite='{}', matched_row={:?}",
             new_idx,
             ai_provided_parent_names,
             ai_snapshot,
             ai_composite,
-            composite_map.contains_key(&ai_composite)
+            matched_row
         );
 
-        if composite_map.contains_key(&ai_composite) {
+        if matched_row.is_some() {
             duplicate_indices.push(new_idx);
         }
     }
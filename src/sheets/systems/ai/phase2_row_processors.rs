@@ -11,10 +11,10 @@ use crate::ui::elements::editor::state::{EditorWindowState, NewRowReview, RowRev
 
 use super::row_helpers::{
     create_row_snapshots, extract_ai_snapshot_from_new_row, generate_review_choices,
-    skip_key_prefix,
+    keep_original_for_read_only_columns, skip_key_prefix, snap_enum_suggestions,
 };
 use super::column_helpers::calculate_dynamic_prefix;
-use super::duplicate_map_helpers::build_duplicate_map_for_parents;
+use super::duplicate_map_helpers::{build_duplicate_map_for_parents, DuplicateMatchOptions};
 use super::original_cache::cache_original_row_for_review;
 
 /// Process original rows from batch results
@@ -48,11 +48,13 @@ pub fn process_original_rows(
             continue;
         }
 
-        let (original_snapshot, ai_snapshot) = create_row_snapshots(
+        let (original_snapshot, mut ai_snapshot) = create_row_snapshots(
             registry, cat_ctx, sheet_ctx, row_index, suggestion, included,
         );
+        snap_enum_suggestions(&mut ai_snapshot, included, registry, cat_ctx, sheet_ctx);
 
-        let choices = generate_review_choices(&original_snapshot, &ai_snapshot);
+        let mut choices = generate_review_choices(&original_snapshot, &ai_snapshot);
+        keep_original_for_read_only_columns(&mut choices, included, registry, cat_ctx, sheet_ctx);
 
         state.ai_row_reviews.push(RowReview {
             row_index,
@@ -128,7 +130,8 @@ pub fn process_duplicate_rows(
             continue;
         }
 
-        let ai_snapshot = extract_ai_snapshot_from_new_row(suggestion, included);
+        let mut ai_snapshot = extract_ai_snapshot_from_new_row(suggestion, included);
+        snap_enum_suggestions(&mut ai_snapshot, included, registry, cat_ctx, sheet_ctx);
 
         // Find the matched existing row (reuse key_actual_col_opt calculated earlier)
         let (duplicate_match_row, choices, original_for_merge, merge_selected) =
@@ -140,6 +143,7 @@ pub fn process_duplicate_rows(
                 cat_ctx,
                 &sheet_ctx_opt,
                 registry,
+                DuplicateMatchOptions::from_state(state),
             );
 
         // For duplicates, projected_row_index is the matched original's row_index
@@ -204,7 +208,8 @@ pub fn process_new_rows(
             continue;
         }
 
-        let ai_snapshot = extract_ai_snapshot_from_new_row(suggestion, included);
+        let mut ai_snapshot = extract_ai_snapshot_from_new_row(suggestion, included);
+        snap_enum_suggestions(&mut ai_snapshot, included, registry, cat_ctx, sheet_ctx);
 
         // Calculate projected row index for this new row
         let projected_row_index = max_row_index + 1 + new_start_offset + pos;
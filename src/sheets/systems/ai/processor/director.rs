@@ -31,6 +31,7 @@ use super::navigator::IndexMapper;
 use super::parser::{ParseResult, ParsedRow, ResponseParser};
 use super::pre_processor::{PreProcessConfig, PreProcessor, PreparedBatch};
 use super::storager::{ColumnResult, ResultStorage, StoredRowResult};
+use crate::sheets::ai_schema::AiSchemaGroupStructureOverride;
 use crate::sheets::resources::SheetRegistry;
 
 /// Status of the processing
@@ -142,6 +143,62 @@ impl PendingJob {
     pub fn is_first_step(&self) -> bool {
         self.step_path.is_empty()
     }
+
+    /// Split this job so each request stays within `max_rows` rows and `char_budget`
+    /// characters of included cells. Root jobs split by row; child jobs split by parent,
+    /// keeping each parent's rows together.
+    pub fn split_by_budget(
+        self,
+        grid: &[Vec<String>],
+        included_indices: &[usize],
+        max_rows: usize,
+        char_budget: usize,
+    ) -> Vec<PendingJob> {
+        let row_chars = |row_idx: &usize| {
+            grid.get(*row_idx)
+                .map(|row| PreProcessor::estimate_row_chars(row, included_indices))
+                .unwrap_or(0)
+        };
+        if self.parents.is_empty() {
+            let sizes: Vec<(usize, usize)> =
+                self.root_target_rows.iter().map(|r| (1, row_chars(r))).collect();
+            let chunks = PreProcessor::chunk_by_budget(&sizes, max_rows, char_budget);
+            if chunks.len() <= 1 {
+                return vec![self];
+            }
+            chunks
+                .into_iter()
+                .map(|range| PendingJob {
+                    root_target_rows: self.root_target_rows[range].to_vec(),
+                    ..self.clone()
+                })
+                .collect()
+        } else {
+            // AI-added parents have no rows yet but still cost one entry in the request
+            let sizes: Vec<(usize, usize)> = self
+                .parents
+                .iter()
+                .map(|p| {
+                    (
+                        p.target_rows.len().max(1),
+                        p.target_rows.iter().map(row_chars).sum::<usize>()
+                            + p.parent_display_value.chars().count(),
+                    )
+                })
+                .collect();
+            let chunks = PreProcessor::chunk_by_budget(&sizes, max_rows, char_budget);
+            if chunks.len() <= 1 {
+                return vec![self];
+            }
+            chunks
+                .into_iter()
+                .map(|range| PendingJob {
+                    parents: self.parents[range].to_vec(),
+                    ..self.clone()
+                })
+                .collect()
+        }
+    }
 }
 
 /// Current processing state
@@ -164,6 +221,8 @@ pub struct ProcessingState {
     /// Orphaned rows from multi-parent parsing (unmatched parent prefixes)
     /// These rows need to be displayed in AI Review for re-parenting
     pub orphaned_rows: Vec<ParsedRow>,
+    /// Rows the AI returned with the wrong shape, described for the review's "needs attention" list
+    pub malformed_rows: Vec<String>,
 }
 
 impl Default for ProcessingState {
@@ -177,6 +236,7 @@ impl Default for ProcessingState {
             error_message: None,
             generation_id: 0,
             orphaned_rows: Vec::new(),
+            malformed_rows: Vec::new(),
         }
     }
 }
@@ -483,12 +543,51 @@ impl ChildJobBuilder {
         jobs
     }
 
+    /// Exclude structure columns the session must not descend into.
+    ///
+    /// A child table at `child_depth` (1 = direct child of the root sheet) is skipped when it
+    /// is deeper than `depth_limit`, or when its structure path from the root sheet, or any
+    /// ancestor of that path, is marked as skipped. Skipping a column also stops its
+    /// descendants, since they are only discovered after the child step runs.
+    /// `root_path_of` maps a metadata column index to that root-relative path; columns whose
+    /// path cannot be resolved are only subject to the depth limit.
+    pub fn apply_structure_limits(
+        &mut self,
+        child_depth: usize,
+        depth_limit: usize,
+        overrides: &[AiSchemaGroupStructureOverride],
+        root_path_of: impl Fn(usize) -> Option<Vec<usize>>,
+    ) {
+        for col_info in self.structure_columns.iter_mut().filter(|c| c.ai_include) {
+            let skipped_by_group = root_path_of(col_info.metadata_column_index)
+                .is_some_and(|path| structure_path_skipped(&path, overrides));
+            if child_depth > depth_limit || skipped_by_group {
+                bevy::log::info!(
+                    "Skipping structure '{}_{}' (depth {} of limit {}, skipped by group: {})",
+                    self.parent_sheet_name,
+                    col_info.column_header,
+                    child_depth,
+                    depth_limit,
+                    skipped_by_group
+                );
+                col_info.ai_include = false;
+            }
+        }
+    }
+
     /// Get columns that are included for AI processing
     pub fn included_columns(&self) -> Vec<&StructureColumnInfo> {
         self.structure_columns.iter().filter(|c| c.ai_include).collect()
     }
 }
 
+/// True when `path` or one of its ancestor paths is marked as skipped
+pub fn structure_path_skipped(path: &[usize], overrides: &[AiSchemaGroupStructureOverride]) -> bool {
+    overrides
+        .iter()
+        .any(|o| o.skip && !o.path.is_empty() && path.starts_with(&o.path))
+}
+
 /// Director - orchestrates the AI processing flow
 #[derive(Debug, Default)]
 pub struct Director {
@@ -583,6 +682,14 @@ impl Director {
         self.job_queue.pop_front()
     }
 
+    /// Put jobs back at the front of the queue, in order (remaining parts of a split job)
+    pub fn requeue_front(&mut self, jobs: Vec<PendingJob>) {
+        self.state.total_steps += jobs.len();
+        for job in jobs.into_iter().rev() {
+            self.job_queue.push_front(job);
+        }
+    }
+
     /// Queue additional jobs (for child tables)
     pub fn queue_jobs(&mut self, jobs: Vec<PendingJob>) {
         let new_count = jobs.len();
@@ -593,6 +700,24 @@ impl Director {
         self.state.total_steps += new_count;
     }
 
+    /// Record a row that was returned with the wrong shape and therefore not applied
+    fn note_malformed_row(&mut self, table_name: &str, display_value: &str, reason: &str) {
+        let label = if display_value.trim().is_empty() {
+            "(unknown row)"
+        } else {
+            display_value
+        };
+        bevy::log::warn!(
+            "Malformed AI row in '{}': '{}' - {}",
+            table_name,
+            label,
+            reason
+        );
+        self.state
+            .malformed_rows
+            .push(format!("{}: '{}' - {}", table_name, label, reason));
+    }
+
     /// Build StoredRowResult objects from parse results
     ///
     /// For AI-added rows, validates the ancestry chain to ensure the row
@@ -615,6 +740,11 @@ impl Director {
         for (idx, parsed_row) in parse_result.original_rows.iter().enumerate() {
             // Get the original prepared row by position
             if let Some(prep) = batch.rows.get(idx) {
+                if let Some(reason) = &parsed_row.malformed {
+                    // Leave the original row untouched rather than applying shifted values
+                    self.note_malformed_row(&job.table_name, &prep.stable_id.display_value, reason);
+                    continue;
+                }
                 let columns: Vec<ColumnResult> = column_names
                     .iter()
                     .enumerate()
@@ -637,6 +767,10 @@ impl Director {
 
         // AI-added rows - register with navigator and validate ancestry
         for parsed_row in &parse_result.ai_added_rows {
+            if let Some(reason) = &parsed_row.malformed {
+                self.note_malformed_row(&job.table_name, &parsed_row.display_value, reason);
+                continue;
+            }
             // Register this AI-added row and get a stable ID
             let display_value = parsed_row.display_value.clone();
             
@@ -776,6 +910,10 @@ impl Director {
         let orphaned_rows = std::mem::take(&mut self.state.orphaned_rows);
 
         for parsed_row in orphaned_rows {
+            if let Some(reason) = &parsed_row.malformed {
+                self.note_malformed_row(&job.table_name, &parsed_row.display_value, reason);
+                continue;
+            }
             // Register this orphaned row as AI-added (we don't know its true parent)
             let display_value = parsed_row.display_value.clone();
             let claimed_ancestry = parsed_row.prefix_columns.clone();
@@ -857,7 +995,10 @@ impl Director {
 
         // Step 1: Build PreProcessConfig using included_indices from RequestConfig
         // Use is_child_table from config to correctly detect table type (not step position)
-        let key_column_index = PreProcessor::get_key_column_index(request_config.is_child_table, None);
+        let key_column_index = PreProcessor::get_key_column_index(
+            request_config.is_child_table,
+            request_config.key_column_index,
+        );
         
         // Build column names from indices (for PreProcessConfig compatibility)
         // These are just placeholders - the actual data extraction uses included_indices
@@ -1079,7 +1220,10 @@ impl Director {
         // The key column index for parsing should be the position within included columns,
         // not the raw grid index. For child tables, grid key is column 2 (after row_index, parent_key),
         // but if included_indices is [2, 3], the key is at position 0 within expected_columns.
-        let grid_key_col = PreProcessor::get_key_column_index(prepared.request_config.is_child_table, None);
+        let grid_key_col = PreProcessor::get_key_column_index(
+            prepared.request_config.is_child_table,
+            prepared.request_config.key_column_index,
+        );
         let parser_key_col = prepared.request_config.included_indices
             .iter()
             .position(|&idx| idx == grid_key_col)
@@ -1222,8 +1366,10 @@ impl Director {
             
             all_stored_results.extend(stored_results);
             
-            total_processed += parse_result.original_rows.len() + parse_result.ai_added_rows.len();
-            total_added += parse_result.ai_added_rows.len();
+            let malformed_added = parse_result.ai_added_rows.iter().filter(|r| r.is_malformed()).count();
+            total_processed += parse_result.original_rows.len() + parse_result.ai_added_rows.len()
+                - parse_result.malformed_count();
+            total_added += parse_result.ai_added_rows.len() - malformed_added;
             total_lost += parse_result.lost_display_values.len();
         }
 
@@ -1350,6 +1496,26 @@ mod tests {
         assert_eq!(child_job.parents[0].parent_stable_index, 5);
     }
 
+    #[test]
+    fn test_split_job_by_budget() {
+        let grid: Vec<Vec<String>> = ["short", "a much longer description", "tiny"]
+            .iter()
+            .map(|text| vec!["0".to_string(), text.to_string()])
+            .collect();
+        let root_job = PendingJob::root("Aircraft".to_string(), None, vec![0, 1, 2]);
+
+        let unsplit = root_job.clone().split_by_budget(&grid, &[1], 32, 0);
+        assert_eq!(unsplit.len(), 1);
+
+        let parts = root_job.clone().split_by_budget(&grid, &[1], 32, 20);
+        let rows: Vec<Vec<usize>> = parts.iter().map(|j| j.root_target_rows.clone()).collect();
+        assert_eq!(rows, vec![vec![0], vec![1], vec![2]]);
+
+        let parts = root_job.split_by_budget(&grid, &[1], 2, 0);
+        assert_eq!(parts.len(), 2);
+        assert!(parts.iter().all(|j| j.is_first_step()));
+    }
+
     #[test]
     fn test_director_session() {
         let mut director = Director::new();
@@ -1497,6 +1663,30 @@ mod tests {
         assert_eq!(jobs.len(), 1); // 1 job for Aircraft_Engines with 2 parents
     }
 
+    #[test]
+    fn test_child_job_builder_structure_limits() {
+        let overrides = vec![
+            AiSchemaGroupStructureOverride { path: vec![2], allow_add_rows: None, skip: true },
+            AiSchemaGroupStructureOverride { path: vec![3], allow_add_rows: Some(false), skip: false },
+        ];
+        // A path below a skipped ancestor is skipped too; disabling row generation is not skipping
+        assert!(structure_path_skipped(&[2, 0], &overrides));
+        assert!(!structure_path_skipped(&[3, 1], &overrides));
+
+        let mut builder = ChildJobBuilder::new("Aircraft".to_string(), None);
+        builder.add_structure_column(2, "Engines".to_string(), true);
+        builder.add_structure_column(3, "Weapons".to_string(), true);
+        builder.apply_structure_limits(1, 2, &overrides, |idx| Some(vec![idx]));
+        let included: Vec<&str> = builder.included_columns().iter().map(|c| c.column_header.as_str()).collect();
+        assert_eq!(included, vec!["Weapons"]);
+
+        // Beyond the depth limit nothing is queued, overrides or not
+        let mut deep = ChildJobBuilder::new("Aircraft_Weapons".to_string(), None);
+        deep.add_structure_column(4, "Ammo".to_string(), true);
+        deep.apply_structure_limits(3, 2, &[], |_| None);
+        assert!(deep.included_columns().is_empty());
+    }
+
     #[test]
     fn test_processing_status_cancelled() {
         let mut director = Director::new();
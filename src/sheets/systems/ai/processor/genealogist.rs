@@ -529,7 +529,7 @@ impl Genealogist {
             };
 
             // Get display value
-            let display_value = parent_meta.get_first_data_column_value(row);
+            let display_value = parent_meta.get_ai_display_value(row);
 
             levels.push(AncestryLevel {
                 table_name: current_table.clone(),
@@ -677,7 +677,7 @@ impl Genealogist {
             };
 
             // Try to get AI-modified display value first, fall back to original
-            let first_data_col_idx = parent_meta.find_first_data_column_index().unwrap_or(0);
+            let first_data_col_idx = parent_meta.ai_display_column_index().unwrap_or(0);
             let display_value = storage
                 .get_ai_display_value(
                     &current_table,
@@ -687,7 +687,7 @@ impl Genealogist {
                 )
                 .unwrap_or_else(|| {
                     // No AI modification - use original DB value
-                    parent_meta.get_first_data_column_value(row)
+                    parent_meta.get_ai_display_value(row)
                 });
 
             debug!(
@@ -776,7 +776,7 @@ impl Genealogist {
         let first_data_col_idx = registry
             .get_sheet(&stable_id.category, &stable_id.table_name)
             .and_then(|s| s.metadata.as_ref())
-            .and_then(|m| m.find_first_data_column_index())
+            .and_then(|m| m.ai_display_column_index())
             .unwrap_or(0);
 
         // Try to get AI-modified display value for the starting row
@@ -823,7 +823,7 @@ impl Genealogist {
                 let parent_first_data_col = registry
                     .get_sheet(&parent_id.category, &parent_id.table_name)
                     .and_then(|s| s.metadata.as_ref())
-                    .and_then(|m| m.find_first_data_column_index())
+                    .and_then(|m| m.ai_display_column_index())
                     .unwrap_or(0);
 
                 // Try to get AI-modified display value
@@ -996,7 +996,7 @@ impl Genealogist {
             let ai_context = registry
                 .get_sheet(category, ancestor_table)
                 .and_then(|s| s.metadata.as_ref())
-                .and_then(|m| m.find_first_data_column_index().and_then(|idx| m.columns.get(idx)))
+                .and_then(|m| m.ai_display_column_index().and_then(|idx| m.columns.get(idx)))
                 .and_then(|col| col.ai_context.clone());
 
             // Use ai_context if available, otherwise generate fallback with table name
@@ -22,15 +22,18 @@ use bevy::prelude::*;
 use bevy_tokio_tasks::TokioTasksRuntime;
 
 use crate::sheets::resources::SheetRegistry;
+use crate::sheets::systems::ai::usage_ledger::save_usage_ledger;
 use crate::ui::elements::ai_review::ai_context_utils::build_lineage_prefixes;
 use crate::ui::elements::editor::state::{
-    AiModeState, EditorWindowState, RowReview, NewRowReview,
+    AiModeState, AiPayloadPreview, AiTokenEstimate, EditorWindowState, RowReview, NewRowReview,
     ReviewChoice as StateReviewChoice,
 };
 use crate::SessionApiKey;
 
 use super::director::{ChildJobBuilder, Director, PendingJob, PreparedStep, ProcessedParentInfo};
-use super::messenger::{Messenger, MessengerResult, RequestConfig};
+use super::genealogist::Genealogist;
+use super::messenger::{Messenger, MessengerResult, RequestConfig, RetryPolicy};
+use super::pre_processor::PreProcessor;
 use crate::sheets::column_validator::ColumnValidator;
 
 /// Resource to track the Director session across frames.
@@ -165,6 +168,7 @@ pub fn start_director_session_v2(
     state.ai_mode = AiModeState::Submitting;
     state.ai_row_reviews.clear();
     state.ai_new_row_reviews.clear();
+    state.ai_malformed_row_notes.clear();
     state.ai_last_send_root_rows = selection;
     state.ai_last_send_root_category = category.clone();
     state.ai_last_send_root_sheet = Some(sheet_name);
@@ -194,6 +198,134 @@ pub fn start_director_session_v2(
     );
 }
 
+/// Root requests a send of the current selection would make, before any is prepared
+struct RootRequestPlan<'a> {
+    sheet_name: String,
+    row_indices: &'a [i64],
+    grid: Vec<Vec<String>>,
+    config: RequestConfig,
+    /// The root job split by the width limit and character budget
+    parts: Vec<PendingJob>,
+}
+
+/// Mirrors `start_director_session_v2` + `dispatch_next_step` for the root job: same
+/// column inclusion, lineage prefixes and width/budget split.
+fn plan_root_requests<'a>(
+    state: &EditorWindowState,
+    registry: &'a SheetRegistry,
+) -> Result<RootRequestPlan<'a>, String> {
+    let selection: Vec<usize> = state.ai_selected_rows.iter().copied().collect();
+    if selection.is_empty() {
+        return Err("No rows selected".to_string());
+    }
+    let category = state.selected_category.clone();
+    let sheet_name = state
+        .selected_sheet_name
+        .clone()
+        .ok_or_else(|| "No sheet selected".to_string())?;
+    let sheet = registry
+        .get_sheet(&category, &sheet_name)
+        .ok_or_else(|| format!("Sheet '{}' not found", sheet_name))?;
+    let meta = sheet
+        .metadata
+        .as_ref()
+        .ok_or_else(|| format!("No metadata for sheet '{}'", sheet_name))?;
+
+    let mut config = build_request_config(meta, None)?;
+    let lineage_prefixes = build_lineage_prefixes(state, registry, &selection);
+    if !lineage_prefixes.prefix_values.is_empty() {
+        config.lineage_prefix_values = lineage_prefixes.prefix_values;
+        config.lineage_prefix_contexts = lineage_prefixes.prefix_contexts;
+    }
+    if let Some(nav_ctx) = state.structure_navigation_stack.last() {
+        config.root_parent_table_name = Some(nav_ctx.parent_sheet_name.clone());
+        config.root_parent_stable_index = nav_ctx.parent_row_key.parse::<usize>().ok();
+    }
+
+    let grid: Vec<Vec<String>> = sheet.grid.iter().map(|row| row.to_vec()).collect();
+    let root_job = PendingJob::root(sheet_name.clone(), category, selection);
+    let parts = root_job.split_by_budget(
+        &grid,
+        &config.included_indices,
+        state.ai_width_limit,
+        state.ai_char_budget,
+    );
+    Ok(RootRequestPlan {
+        sheet_name,
+        row_indices: &sheet.row_indices,
+        grid,
+        config,
+        parts,
+    })
+}
+
+/// Build the payload of the first request a send would make, without calling the model.
+///
+/// Uses a throwaway Director so the running session (if any) is untouched. Child steps
+/// are not previewed; their payloads depend on the first response.
+pub fn preview_director_payload(
+    state: &EditorWindowState,
+    registry: &SheetRegistry,
+) -> Result<AiPayloadPreview, String> {
+    let RootRequestPlan {
+        sheet_name,
+        row_indices,
+        grid,
+        config,
+        mut parts,
+    } = plan_root_requests(state, registry)?;
+    let request_parts = parts.len();
+    let job = parts.remove(0);
+
+    let prepared = Director::new().prepare_step(&job, &grid, row_indices, registry, config)?;
+    let payload_json = serde_json::from_str::<serde_json::Value>(&prepared.payload_json)
+        .and_then(|v| serde_json::to_string_pretty(&v))
+        .unwrap_or(prepared.payload_json);
+    let child_structures = child_job_builder(registry, &job, state.ai_depth_limit)
+        .map(|builder| {
+            builder
+                .included_columns()
+                .iter()
+                .map(|col| col.column_header.clone())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(AiPayloadPreview {
+        table_name: sheet_name,
+        payload_json,
+        request_parts,
+        child_structures,
+    })
+}
+
+/// Estimate the tokens a send of the current selection would use, by preparing every
+/// root request's payload (as the preview does for the first) and counting ~4 chars per token.
+pub fn estimate_director_tokens(
+    state: &EditorWindowState,
+    registry: &SheetRegistry,
+) -> Result<AiTokenEstimate, String> {
+    let plan = plan_root_requests(state, registry)?;
+    let mut estimate = AiTokenEstimate {
+        total_tokens: 0,
+        largest_request_tokens: 0,
+        request_parts: plan.parts.len(),
+    };
+    for job in &plan.parts {
+        let prepared = Director::new().prepare_step(
+            job,
+            &plan.grid,
+            plan.row_indices,
+            registry,
+            plan.config.clone(),
+        )?;
+        let tokens = PreProcessor::estimate_tokens(prepared.payload_json.chars().count());
+        estimate.total_tokens += tokens;
+        estimate.largest_request_tokens = estimate.largest_request_tokens.max(tokens);
+    }
+    Ok(estimate)
+}
+
 /// Dispatch the next processing step (internal).
 /// 
 /// This is a THIN wrapper that:
@@ -275,6 +407,23 @@ fn dispatch_next_step(
     let grid: Vec<Vec<String>> = sheet.grid.iter().map(|row| row.to_vec()).collect();
     let row_indices: Vec<i64> = sheet.row_indices.clone();
 
+    // Keep each request within the width limit and character budget; the rest waits in the queue
+    let mut parts = job.split_by_budget(
+        &grid,
+        &config.included_indices,
+        state.ai_width_limit,
+        state.ai_char_budget,
+    );
+    let job = parts.remove(0);
+    if !parts.is_empty() {
+        info!(
+            "Split AI request for '{}' into {} parts to stay within batch limits",
+            job.table_name,
+            parts.len() + 1
+        );
+        director.requeue_front(parts);
+    }
+
     // Director prepares the step (builds payload, registers with Navigator)
     let prepared = match director.prepare_step(&job, &grid, &row_indices, registry, config) {
         Ok(p) => p,
@@ -312,15 +461,20 @@ fn dispatch_next_step(
 
     let api_key_owned = api_key.to_string();
     let payload_json = prepared.payload_json;
+    let retry_policy = RetryPolicy {
+        max_retries: state.ai_max_retries,
+        initial_backoff: std::time::Duration::from_millis(state.ai_retry_backoff_ms),
+        min_request_interval: std::time::Duration::from_millis(state.ai_min_request_interval_ms),
+    };
 
     // Use Messenger.execute() for the async call
     runtime.spawn_background_task(move |mut ctx| async move {
         // Ensure Python script exists
         Messenger::ensure_python_script();
         
-        // Create messenger and execute
-        let messenger = Messenger::new();
-        let result = messenger.execute(api_key_owned, payload_json).await;
+        // Create messenger and execute (transient failures are retried with backoff)
+        let messenger = Messenger::with_retry_policy(retry_policy);
+        let result = messenger.execute_with_retry(api_key_owned, payload_json).await;
         
         ctx.run_on_main_thread(move |world_ctx| {
             world_ctx
@@ -367,12 +521,14 @@ fn build_request_config(
         column_contexts: inclusion.column_contexts,
         ai_context: meta.ai_general_rule.clone(),
         model_id,
-        allow_row_generation: meta.ai_enable_row_generation,
+        allow_row_generation: meta.effective_ai_allow_add_rows(),
         grounding_with_google_search: meta.requested_grounding_with_google_search.unwrap_or(false),
+        ai_temperature: meta.ai_temperature,
         lineage_prefix_values: Vec::new(),
         lineage_prefix_contexts: Vec::new(),
         prefix_column_names: Vec::new(), // Will be populated in Director.prepare_step from ancestry
         is_child_table,
+        key_column_index: meta.ai_key_column_override(),
         root_parent_table_name: None,
         root_parent_stable_index: None,
     })
@@ -436,7 +592,7 @@ pub fn poll_director_results(
         process_step_with_director(&mut session, &mut state, &registry, &job, &prepared, &callback.messenger_result);
         
         // After processing, detect Structure columns and queue child table jobs
-        detect_and_queue_child_jobs(&mut session, &registry, &job);
+        detect_and_queue_child_jobs(&mut session, &registry, &job, state.ai_depth_limit);
     }
 
     // Clean up callback entity
@@ -496,6 +652,15 @@ fn process_step_with_director(
         state.ai_raw_output_display = raw.clone();
     }
 
+    // Approximate usage accounting: every completed step was one request
+    state.ai_usage.record(
+        &job.category,
+        &job.table_name,
+        step_result.rows_processed,
+        step_result.ai_added_count,
+    );
+    save_usage_ledger(&state.ai_usage);
+
     if step_result.success {
         // Log with progress info
         let proc_state = director.state();
@@ -554,6 +719,51 @@ fn process_step_with_director(
     }
 }
 
+/// Structure columns of `job`'s table whose child tables would be queued after it,
+/// with the depth limit and schema group overrides applied. None when there are none.
+fn child_job_builder(
+    registry: &SheetRegistry,
+    job: &PendingJob,
+    depth_limit: usize,
+) -> Option<ChildJobBuilder> {
+    let parent_meta = registry
+        .get_sheet(&job.category, &job.table_name)?
+        .metadata
+        .as_ref()?;
+
+    // Build ChildJobBuilder with Structure columns
+    let mut builder = ChildJobBuilder::new(job.table_name.clone(), job.category.clone());
+
+    for (idx, col) in parent_meta.columns.iter().enumerate() {
+        if matches!(col.validator, Some(ColumnValidator::Structure)) {
+            let ai_include = col.ai_include_in_send.unwrap_or(false);
+            builder.add_structure_column(idx, col.header.clone(), ai_include);
+        }
+    }
+
+    // Combine the depth limit with per-path overrides of the root sheet's active group
+    let child_depth = Genealogist::get_table_depth(registry, &job.category, &job.table_name) + 1;
+    let (root_meta, headers) = root_structure_context(registry, &job.category, &job.table_name);
+    let overrides = root_meta
+        .map(|root| {
+            root.ai_active_schema_group
+                .as_ref()
+                .and_then(|active| root.ai_schema_groups.iter().find(|g| &g.name == active))
+                .map(|group| group.structure_row_generation_overrides.clone())
+                .unwrap_or_default()
+        })
+        .unwrap_or_default();
+    builder.apply_structure_limits(child_depth, depth_limit, &overrides, |idx| {
+        let header = parent_meta.columns.get(idx)?.header.as_str();
+        root_structure_path(root_meta?, &headers, header, idx)
+    });
+
+    if builder.included_columns().is_empty() {
+        return None;
+    }
+    Some(builder)
+}
+
 /// Detect Structure columns in a processed table and queue child jobs.
 /// 
 /// This enables multi-step processing: after processing a parent table,
@@ -561,42 +771,26 @@ fn process_step_with_director(
 /// 
 /// Child table naming convention: `{ParentSheet}_{ColumnHeader}`
 /// Child table column 1 is always `parent_key` pointing to parent's row_index.
+///
+/// Children deeper than `depth_limit`, or whose structure path is skipped in the root
+/// sheet's active schema group, are not queued (see `ChildJobBuilder::apply_structure_limits`).
 fn detect_and_queue_child_jobs(
     session: &mut DirectorSession,
     registry: &SheetRegistry,
     job: &PendingJob,
+    depth_limit: usize,
 ) {
     let director = match session.director.as_mut() {
         Some(d) => d,
         None => return,
     };
 
-    // Get parent sheet metadata
-    let parent_sheet = match registry.get_sheet(&job.category, &job.table_name) {
-        Some(s) => s,
+    // No structure columns within the limits? Nothing to do
+    let builder = match child_job_builder(registry, job, depth_limit) {
+        Some(b) => b,
         None => return,
     };
 
-    let parent_meta = match &parent_sheet.metadata {
-        Some(m) => m,
-        None => return,
-    };
-
-    // Build ChildJobBuilder with Structure columns
-    let mut builder = ChildJobBuilder::new(job.table_name.clone(), job.category.clone());
-
-    for (idx, col) in parent_meta.columns.iter().enumerate() {
-        if matches!(col.validator, Some(ColumnValidator::Structure)) {
-            let ai_include = col.ai_include_in_send.unwrap_or(false);
-            builder.add_structure_column(idx, col.header.clone(), ai_include);
-        }
-    }
-
-    // No structure columns? Nothing to do
-    if builder.included_columns().is_empty() {
-        return;
-    }
-
     // Get ALL processed rows (original + AI added) from Director storage
     // This ensures we generate child jobs for AI-added rows too.
     // Also extract AI column values for propagating content to child steps.
@@ -690,6 +884,64 @@ fn detect_and_queue_child_jobs(
     }
 }
 
+/// Root sheet metadata of `table_name` and the structure column headers leading from the
+/// root down to it (empty when `table_name` is itself the root).
+fn root_structure_context<'a>(
+    registry: &'a SheetRegistry,
+    category: &Option<String>,
+    table_name: &str,
+) -> (Option<&'a crate::sheets::sheet_metadata::SheetMetadata>, Vec<String>) {
+    let mut headers = Vec::new();
+    let mut current = table_name.to_string();
+    loop {
+        let Some(meta) = registry
+            .get_sheet(category, &current)
+            .and_then(|s| s.metadata.as_ref())
+        else {
+            return (None, headers);
+        };
+        if !meta.columns.iter().any(|c| c.header.eq_ignore_ascii_case("parent_key")) {
+            headers.reverse();
+            return (Some(meta), headers);
+        }
+        let parent = match &meta.structure_parent {
+            Some(link) => link.parent_sheet.clone(),
+            None => match current.rsplit_once('_') {
+                Some((parent, _)) => parent.to_string(),
+                None => return (None, headers),
+            },
+        };
+        let Some(header) = current.strip_prefix(&format!("{}_", parent)) else {
+            return (None, headers);
+        };
+        headers.push(header.to_string());
+        current = parent;
+    }
+}
+
+/// Structure path from the root sheet to the column `column_header` (metadata index
+/// `column_index`) of the table reached through `headers`.
+fn root_structure_path(
+    root_meta: &crate::sheets::sheet_metadata::SheetMetadata,
+    headers: &[String],
+    column_header: &str,
+    column_index: usize,
+) -> Option<Vec<usize>> {
+    let Some((first, rest)) = headers.split_first() else {
+        return Some(vec![column_index]);
+    };
+    let root_idx = root_meta.columns.iter().position(|c| &c.header == first)?;
+    let mut path = vec![root_idx];
+    let mut fields = root_meta.columns[root_idx].structure_schema.as_ref()?;
+    for header in rest {
+        let idx = fields.iter().position(|f| &f.header == header)?;
+        path.push(idx);
+        fields = fields[idx].structure_schema.as_ref()?;
+    }
+    path.push(fields.iter().position(|f| f.header == column_header)?);
+    Some(path)
+}
+
 /// Complete the session and transfer results from Director to EditorWindowState.
 fn complete_session(
     session: &mut DirectorSession,
@@ -703,6 +955,14 @@ fn complete_session(
     if let Some(director) = session.director.as_ref() {
         let storage = director.storage();
         let navigator = director.navigator();
+        state.ai_malformed_row_notes = director.state().malformed_rows.clone();
+        if !state.ai_malformed_row_notes.is_empty() {
+            // Keep the notes visible in the output panel even if the review closes itself
+            state.ai_raw_output_display.push_str(&format!(
+                "\n\nRows needing attention (not applied):\n{}",
+                state.ai_malformed_row_notes.join("\n")
+            ));
+        }
         
         // Debug: log all table keys in storage
         info!("complete_session: Storager contents:");
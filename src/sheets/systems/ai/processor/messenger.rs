@@ -7,18 +7,33 @@
 //!
 //! - Build AI request payload from prepared data
 //! - Execute Python AI query (via pyo3)
-//! - Handle timeouts and errors
+//! - Handle timeouts and errors, retrying transient ones with exponential backoff
+//! - Keep a minimum delay between requests (rate limits)
 //! - Return raw response for parsing
 //!
+//! ## Retries and pacing
+//!
+//! Rate limits (429), server errors (5xx) and timeouts are retried up to
+//! `RetryPolicy::max_retries` times, waiting `initial_backoff`, then twice as long, and so
+//! on. Bad requests (400) and authentication failures are returned at once. The minimum
+//! request interval is measured from the start of the previous request, so a backoff wait
+//! that already covered it adds no further delay. The `throttled` module only paces how
+//! results are applied to the grid and does not delay requests.
+//!
 //! ## Payload Format
 //!
 //! The `AiPayload` structure matches what the Python `ai_processor.py` script expects.
 //! Field names must match exactly for compatibility.
 
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use bevy::prelude::{info, warn};
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use pyo3::types::PyString;
-use std::ffi::CString;
+
+use crate::sheets::systems::ai::python_runtime::require_python_runtime;
+use crate::sheets::systems::ai::structure_processor::python_executor;
 
 use super::genealogist::Ancestry;
 use super::genealogist::Lineage;
@@ -36,6 +51,7 @@ use super::pre_processor::PreparedBatch;
 /// - `column_contexts` - Per-column AI context (decorated with type info)
 /// - `rows_data` - Array of row arrays (flat format)
 /// - `requested_grounding_with_google_search` - Enable Google Search grounding
+/// - `ai_temperature` - Sampling temperature; omitted when unset so the model default applies
 /// - `allow_row_additions` - Whether AI can add new rows
 /// - `user_prompt` - Optional user prompt
 #[derive(Clone, serde::Serialize, Debug)]
@@ -47,6 +63,8 @@ pub struct AiPayload {
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub rows_data: Vec<Vec<String>>,
     pub requested_grounding_with_google_search: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ai_temperature: Option<f32>,
     pub allow_row_additions: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub key_prefix_count: Option<usize>,
@@ -103,6 +121,8 @@ pub struct RequestConfig {
     pub allow_row_generation: bool,
     /// Grounding with Google Search
     pub grounding_with_google_search: bool,
+    /// Sampling temperature (None = model default)
+    pub ai_temperature: Option<f32>,
     /// Lineage prefix values (ancestor display values) - prepended to each row
     pub lineage_prefix_values: Vec<String>,
     /// Lineage prefix contexts (AI context per ancestor) - prepended to column_contexts
@@ -112,6 +132,8 @@ pub struct RequestConfig {
     /// Whether this is a child table (has parent_key column)
     /// Used to determine the key column index for matching rows
     pub is_child_table: bool,
+    /// Per-sheet key/display column override (grid index); None uses the default for the table type
+    pub key_column_index: Option<usize>,
     /// Root parent table name - when first step is a child table (from navigation)
     pub root_parent_table_name: Option<String>,
     /// Root parent stable index - when first step is a child table (from navigation)
@@ -128,34 +150,142 @@ impl Default for RequestConfig {
             model_id: "gemini-2.5-flash-preview-05-20".to_string(),
             allow_row_generation: false,
             grounding_with_google_search: false,
+            ai_temperature: None,
             lineage_prefix_values: Vec::new(),
             lineage_prefix_contexts: Vec::new(),
             prefix_column_names: Vec::new(),
             is_child_table: false,
+            key_column_index: None,
             root_parent_table_name: None,
             root_parent_stable_index: None,
         }
     }
 }
 
+/// Longest single backoff wait, however many attempts failed before
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Start of the most recent AI request, shared by every messenger
+static LAST_REQUEST_START: Mutex<Option<Instant>> = Mutex::new(None);
+
+/// Retry and pacing settings for AI requests (from `AppSettings`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Extra attempts after the first one fails with a retryable error
+    pub max_retries: u32,
+    /// Wait before the first retry; doubled for every further retry
+    pub initial_backoff: Duration,
+    /// Minimum time between the starts of two requests
+    pub min_request_interval: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_secs(2),
+            min_request_interval: Duration::from_secs(1),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Wait before retry number `retry` (0 = first retry)
+    pub fn backoff_delay(&self, retry: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2u32.saturating_pow(retry))
+            .min(MAX_BACKOFF)
+    }
+}
+
+/// True when `message` contains `code` as a standalone number (not part of a longer one)
+fn mentions_status_code(message: &str, code: &str) -> bool {
+    message.match_indices(code).any(|(pos, _)| {
+        let before = message[..pos].chars().next_back();
+        let after = message[pos + code.len()..].chars().next();
+        !before.is_some_and(|c| c.is_ascii_digit()) && !after.is_some_and(|c| c.is_ascii_digit())
+    })
+}
+
+/// Whether a failed request is worth sending again: rate limits, server errors and
+/// timeouts are; bad payloads, authentication and local errors are not.
+pub fn is_retryable_error(message: &str) -> bool {
+    let lower = message.to_lowercase();
+    const PERMANENT: [&str; 6] = [
+        "invalid_argument",
+        "permission_denied",
+        "unauthenticated",
+        "api key",
+        "api_key",
+        "failed_precondition",
+    ];
+    if ["400", "401", "403", "404"]
+        .iter()
+        .any(|code| mentions_status_code(&lower, code))
+        || PERMANENT.iter().any(|m| lower.contains(m))
+    {
+        return false;
+    }
+    const TRANSIENT: [&str; 9] = [
+        "resource_exhausted",
+        "rate limit",
+        "unavailable",
+        "overloaded",
+        "deadline_exceeded",
+        "deadline exceeded",
+        "timed out",
+        "timeout",
+        "connection reset",
+    ];
+    ["429", "500", "502", "503", "504"]
+        .iter()
+        .any(|code| mentions_status_code(&lower, code))
+        || TRANSIENT.iter().any(|m| lower.contains(m))
+}
+
+/// Sleep until `min_interval` has passed since the previous request started, then mark
+/// this request as started
+async fn wait_for_request_slot(min_interval: Duration) {
+    loop {
+        let wait = {
+            let mut last = LAST_REQUEST_START.lock().unwrap_or_else(|e| e.into_inner());
+            let now = Instant::now();
+            match *last {
+                Some(prev) if now.duration_since(prev) < min_interval => {
+                    min_interval - now.duration_since(prev)
+                }
+                _ => {
+                    *last = Some(now);
+                    return;
+                }
+            }
+        };
+        tokio::time::sleep(wait).await;
+    }
+}
+
 /// AI Messenger - handles communication with the AI
 #[derive(Debug, Default)]
-pub struct Messenger;
+pub struct Messenger {
+    retry_policy: RetryPolicy,
+}
 
 impl Messenger {
     /// Create a new messenger
     pub fn new() -> Self {
-        Self
+        Self::default()
     }
 
-    /// Ensure the Python processor script exists
-    pub fn ensure_python_script() {
-        const AI_PROCESSOR_PY: &str = include_str!("../../../../../script/ai_processor.py");
-        let script_path = std::path::Path::new("script/ai_processor.py");
-        if let Some(parent) = script_path.parent() {
-            let _ = std::fs::create_dir_all(parent);
+    /// Messenger retrying and pacing requests according to `policy`
+    pub fn with_retry_policy(policy: RetryPolicy) -> Self {
+        Self {
+            retry_policy: policy,
         }
-        let _ = std::fs::write(script_path, AI_PROCESSOR_PY);
+    }
+
+    /// Ensure the Python processor script exists and matches this build
+    pub fn ensure_python_script() {
+        python_executor::rewrite_python_processor();
     }
 
     /// Build request payload JSON using BatchPayload format
@@ -278,6 +408,7 @@ impl Messenger {
             column_contexts,
             rows_data,
             requested_grounding_with_google_search: config.grounding_with_google_search,
+            ai_temperature: config.ai_temperature,
             allow_row_additions: config.allow_row_generation,
             key_prefix_count: None,  // Never sent to AI - used internally for response parsing
             key_prefix_headers: None, // Never sent to AI - used internally for response parsing
@@ -395,6 +526,7 @@ impl Messenger {
             column_contexts,
             rows_data,
             requested_grounding_with_google_search: config.grounding_with_google_search,
+            ai_temperature: config.ai_temperature,
             allow_row_additions: config.allow_row_generation,
             key_prefix_count: None,  // Not sent to AI
             key_prefix_headers: None, // Not sent to AI
@@ -405,6 +537,46 @@ impl Messenger {
             .map_err(|e| format!("Failed to serialize payload: {}", e))
     }
 
+    /// Execute AI request, retrying transient failures per the messenger's `RetryPolicy`
+    /// and keeping the minimum interval between requests.
+    /// A failure after the last retry is returned with the attempt count appended.
+    pub async fn execute_with_retry(
+        &self,
+        api_key: String,
+        payload_json: String,
+    ) -> MessengerResult {
+        let policy = self.retry_policy;
+        let mut retry = 0;
+        loop {
+            wait_for_request_slot(policy.min_request_interval).await;
+            let mut result = self.execute(api_key.clone(), payload_json.clone()).await;
+            let error = match (&result.success, &result.error) {
+                (false, Some(error)) => error.clone(),
+                _ => return result,
+            };
+            if !is_retryable_error(&error) {
+                return result;
+            }
+            if retry >= policy.max_retries {
+                if retry > 0 {
+                    result.error = Some(format!("{} (gave up after {} attempts)", error, retry + 1));
+                }
+                return result;
+            }
+            let delay = policy.backoff_delay(retry);
+            warn!(
+                "AI request failed with a retryable error, retry {}/{} in {:.1}s: {}",
+                retry + 1,
+                policy.max_retries,
+                delay.as_secs_f32(),
+                error
+            );
+            tokio::time::sleep(delay).await;
+            retry += 1;
+            info!("Retrying AI request (attempt {})", retry + 1);
+        }
+    }
+
     /// Execute AI request
     ///
     /// # Arguments
@@ -418,35 +590,33 @@ impl Messenger {
         api_key: String,
         payload_json: String,
     ) -> MessengerResult {
+        if let Err(e) = require_python_runtime() {
+            return MessengerResult::error(e, None);
+        }
         let result = tokio::task::spawn_blocking(move || {
-            Python::with_gil(|py| -> PyResult<MessengerResult> {
-                let python_file_path = "script/ai_processor.py";
-                let processor_code_string = std::fs::read_to_string(python_file_path)?;
-                let code_c_str = CString::new(processor_code_string)
-                    .map_err(|e| PyValueError::new_err(format!("CString error: {}", e)))?;
-                let file_name_c_str = CString::new(python_file_path)
-                    .map_err(|e| PyValueError::new_err(format!("File name CString error: {}", e)))?;
-                let module_name_c_str = CString::new("ai_processor")
-                    .map_err(|e| PyValueError::new_err(format!("Module name CString error: {}", e)))?;
-
-                let module = PyModule::from_code(
-                    py,
-                    code_c_str.as_c_str(),
-                    file_name_c_str.as_c_str(),
-                    module_name_c_str.as_c_str(),
-                )?;
-
-                let binding = module.call_method1("execute_ai_query", (api_key, payload_json))?;
-                let result_str: &str = binding.downcast::<PyString>()?.to_str()?;
-
-                Self::parse_python_response(result_str)
+            Python::with_gil(|py| {
+                let (call_result, captured) =
+                    python_executor::call_ai_processor(py, api_key, payload_json);
+                let parsed = call_result.and_then(|result_str| Self::parse_python_response(&result_str));
+                (parsed, captured)
             })
         })
         .await;
 
         match result {
-            Ok(Ok(messenger_result)) => messenger_result,
-            Ok(Err(e)) => MessengerResult::error(format!("PyO3 error: {}", e), None),
+            Ok((Ok(mut messenger_result), captured)) => {
+                if !messenger_result.success {
+                    messenger_result.error = messenger_result
+                        .error
+                        .take()
+                        .map(|e| captured.append_stderr(e));
+                }
+                messenger_result
+            }
+            Ok((Err(e), captured)) => MessengerResult::error(
+                captured.append_stderr(format!("PyO3 error: {}", e)),
+                None,
+            ),
             Err(e) => MessengerResult::error(format!("Tokio panic: {}", e), None),
         }
     }
@@ -500,10 +670,12 @@ mod tests {
             model_id: "gemini-2.5-flash-preview-05-20".to_string(),
             allow_row_generation: true,
             grounding_with_google_search: false,
+            ai_temperature: None,
             lineage_prefix_values: Vec::new(),
             lineage_prefix_contexts: Vec::new(),
             prefix_column_names: Vec::new(),
             is_child_table: false,
+            key_column_index: None,
             root_parent_table_name: None,
             root_parent_stable_index: None,
         };
@@ -539,6 +711,37 @@ mod tests {
         assert!(json["rows_data"].as_array().unwrap().len() == 1);
         assert_eq!(json["rows_data"][0][0], "MiG-25PD");
         assert_eq!(json["rows_data"][0][1], "3000");
+        // Unset temperature is left out so the model default applies
+        assert!(json.get("ai_temperature").is_none());
+
+        let warm = RequestConfig {
+            ai_temperature: Some(0.5),
+            ..config
+        };
+        let payload = messenger.build_payload(&warm, &batch, None, None).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&payload).unwrap();
+        assert_eq!(json["ai_temperature"], 0.5);
+    }
+
+    #[test]
+    fn transient_errors_are_retried_with_growing_backoff() {
+        assert!(is_retryable_error("429 RESOURCE_EXHAUSTED. Quota exceeded"));
+        assert!(is_retryable_error("503 UNAVAILABLE: The model is overloaded"));
+        assert!(is_retryable_error("Request timed out"));
+        assert!(!is_retryable_error("400 INVALID_ARGUMENT: bad schema"));
+        assert!(!is_retryable_error("API key not valid. Please pass a valid API key."));
+        assert!(!is_retryable_error("JSON decode error: expected value"));
+        // Numbers that only contain a status code are not one
+        assert!(!is_retryable_error("Row 15003 could not be parsed"));
+
+        let policy = RetryPolicy {
+            max_retries: 5,
+            initial_backoff: Duration::from_secs(2),
+            min_request_interval: Duration::ZERO,
+        };
+        assert_eq!(policy.backoff_delay(0), Duration::from_secs(2));
+        assert_eq!(policy.backoff_delay(2), Duration::from_secs(8));
+        assert_eq!(policy.backoff_delay(10), MAX_BACKOFF);
     }
 
     #[test]
@@ -37,5 +37,5 @@ pub mod integration;
 pub use integration::{
     DirectorSession,
     start_director_session_v2, poll_director_results,
-    cancel_director_session,
+    cancel_director_session, preview_director_payload, estimate_director_tokens,
 };
@@ -16,6 +16,10 @@
 //! - First `sent_count` rows → Original  
 //! - Remaining rows → AI-added
 //! - If fewer than `sent_count` rows returned → some are "lost"
+//!
+//! Array rows whose width doesn't match `prefix_count + expected_columns` are kept in
+//! their position slot but flagged as malformed, so later rows are never shifted onto
+//! the wrong original row and no misaligned values get mapped.
 
 use std::collections::HashMap;
 
@@ -56,6 +60,15 @@ impl ParseResult {
     pub fn is_success(&self) -> bool {
         self.error.is_none()
     }
+
+    /// Number of returned rows flagged as malformed
+    pub fn malformed_count(&self) -> usize {
+        self.original_rows
+            .iter()
+            .chain(&self.ai_added_rows)
+            .filter(|r| r.is_malformed())
+            .count()
+    }
 }
 
 /// A single parsed row from the AI response
@@ -67,9 +80,27 @@ pub struct ParsedRow {
     pub prefix_columns: Vec<String>,
     /// Column values: (column_name, value) - excludes prefix columns
     pub columns: Vec<(String, String)>,
+    /// Why this row could not be mapped (column count mismatch, wrong shape).
+    /// Malformed rows carry no column values and must not be applied.
+    pub malformed: Option<String>,
 }
 
 impl ParsedRow {
+    /// Placeholder for a row that could not be mapped onto the expected columns
+    pub fn malformed(display_value: String, prefix_columns: Vec<String>, reason: String) -> Self {
+        Self {
+            display_value,
+            prefix_columns,
+            columns: Vec::new(),
+            malformed: Some(reason),
+        }
+    }
+
+    /// Whether this row was flagged as malformed
+    pub fn is_malformed(&self) -> bool {
+        self.malformed.is_some()
+    }
+
     /// Get value for a column by index (excludes prefix columns)
     pub fn get_column_by_index(&self, index: usize) -> Option<&str> {
         self.columns.get(index).map(|(_, v)| v.as_str())
@@ -163,21 +194,23 @@ impl ResponseParser {
         let mut rows_without_prefix: Vec<ParsedRow> = Vec::new();
         
         for row_value in rows_array {
-            if let Ok(parsed_row) = self.parse_row(row_value) {
-                // Join ALL prefix columns to create unique ancestry key
-                // This matches how parent_map keys are built in director.rs
-                if !parsed_row.prefix_columns.is_empty() {
-                    let prefix_key = parsed_row.prefix_columns.join("|");
-                    if !prefix_key.is_empty() {
-                        parent_rows.entry(prefix_key).or_default().push(parsed_row);
-                    } else {
-                        // Empty prefix - treat as orphaned
-                        rows_without_prefix.push(parsed_row);
-                    }
+            // Unparseable rows have no usable prefix and end up orphaned (flagged malformed)
+            let parsed_row = self
+                .parse_row(row_value)
+                .unwrap_or_else(|e| ParsedRow::malformed(String::new(), Vec::new(), e));
+            // Join ALL prefix columns to create unique ancestry key
+            // This matches how parent_map keys are built in director.rs
+            if !parsed_row.prefix_columns.is_empty() {
+                let prefix_key = parsed_row.prefix_columns.join("|");
+                if !prefix_key.is_empty() {
+                    parent_rows.entry(prefix_key).or_default().push(parsed_row);
                 } else {
-                    // No prefix columns at all - treat as orphaned
+                    // Empty prefix - treat as orphaned
                     rows_without_prefix.push(parsed_row);
                 }
+            } else {
+                // No prefix columns at all - treat as orphaned
+                rows_without_prefix.push(parsed_row);
             }
         }
         
@@ -329,19 +362,15 @@ impl ResponseParser {
         let mut ai_added_rows = Vec::new();
 
         for (idx, row_value) in rows.iter().enumerate() {
-            match self.parse_row(row_value) {
-                Ok(parsed_row) => {
-                    // First sent_count rows are Original, rest are AI-added
-                    if idx < sent_count {
-                        original_rows.push(parsed_row);
-                    } else {
-                        ai_added_rows.push(parsed_row);
-                    }
-                }
-                Err(_e) => {
-                    // Row-level parse errors are ignored; such rows are skipped.
-                    // This could result in fewer original rows than expected.
-                }
+            // Unparseable rows still occupy their slot so later rows stay aligned
+            let parsed_row = self
+                .parse_row(row_value)
+                .unwrap_or_else(|e| ParsedRow::malformed(String::new(), Vec::new(), e));
+            // First sent_count rows are Original, rest are AI-added
+            if idx < sent_count {
+                original_rows.push(parsed_row);
+            } else {
+                ai_added_rows.push(parsed_row);
             }
         }
 
@@ -398,7 +427,32 @@ impl ResponseParser {
             .take(self.prefix_count)
             .map(value_to_string)
             .collect();
-        
+
+        // Values are mapped by position, so any width mismatch would shift them
+        let expected_width = self.prefix_count + self.expected_columns.len();
+        if !self.expected_columns.is_empty() && arr.len() != expected_width {
+            let display_value = arr
+                .get(self.prefix_count + self.key_column_index)
+                .map(value_to_string)
+                .unwrap_or_default();
+            let reason = format!(
+                "Row has {} values, expected {} ({} {})",
+                arr.len(),
+                expected_width,
+                if arr.len() > expected_width { "too wide for" } else { "too narrow for" },
+                if self.prefix_count > 0 {
+                    format!(
+                        "{} prefix + {} columns",
+                        self.prefix_count,
+                        self.expected_columns.len()
+                    )
+                } else {
+                    format!("{} columns", self.expected_columns.len())
+                }
+            );
+            return Ok(ParsedRow::malformed(display_value, prefix_columns, reason));
+        }
+
         let mut columns = Vec::new();
         for (idx, value) in arr.iter().skip(self.prefix_count).enumerate() {
             let column_name = self
@@ -421,6 +475,7 @@ impl ResponseParser {
             display_value,
             prefix_columns,
             columns,
+            malformed: None,
         })
     }
 
@@ -498,6 +553,7 @@ impl ResponseParser {
             display_value,
             prefix_columns,
             columns,
+            malformed: None,
         })
     }
 }
@@ -667,6 +723,65 @@ mod tests {
         assert_eq!(result.lost_display_values.len(), 2); // 3 sent - 1 returned = 2 lost
     }
 
+    #[test]
+    fn test_over_wide_row_is_flagged_not_shifted() {
+        let parser = ResponseParser::new(vec!["Name".to_string(), "Speed".to_string()], 0, 0, Vec::new());
+
+        let json = r#"[
+            ["MiG-25PD", "extra", "3000"],
+            ["Su-27", "2500"]
+        ]"#;
+
+        let result = parser.parse(json, 2);
+
+        assert!(result.is_success());
+        assert_eq!(result.original_rows.len(), 2);
+        assert_eq!(result.malformed_count(), 1);
+        let bad = &result.original_rows[0];
+        assert!(bad.columns.is_empty());
+        assert!(bad.malformed.as_deref().unwrap().contains("3 values, expected 2"));
+        // The following row keeps its own slot and values
+        assert!(!result.original_rows[1].is_malformed());
+        assert_eq!(result.original_rows[1].get_column_by_index(1), Some("2500"));
+    }
+
+    #[test]
+    fn test_under_wide_row_is_flagged_not_shifted() {
+        let parser = ResponseParser::new(
+            vec!["Weapon".to_string(), "Damage".to_string()],
+            0,
+            1,
+            vec!["Pylon".to_string()],
+        );
+
+        let json = r#"[
+            ["Pylon 1", "Cannon"],
+            ["Pylon 1", "MG", "50"]
+        ]"#;
+
+        let result = parser.parse(json, 1);
+
+        assert!(result.is_success());
+        assert_eq!(result.original_rows.len(), 1);
+        assert_eq!(result.ai_added_rows.len(), 1);
+        assert_eq!(result.lost_display_values.len(), 0);
+        let bad = &result.original_rows[0];
+        assert!(bad.malformed.as_deref().unwrap().contains("too narrow"));
+        assert_eq!(bad.display_value, "Cannon");
+        assert_eq!(result.ai_added_rows[0].get_column_by_index(1), Some("50"));
+    }
+
+    #[test]
+    fn test_unparseable_row_keeps_its_slot() {
+        let parser = ResponseParser::new(vec!["Name".to_string()], 0, 0, Vec::new());
+
+        let result = parser.parse(r#"[42, ["Second"]]"#, 2);
+
+        assert_eq!(result.original_rows.len(), 2);
+        assert!(result.original_rows[0].is_malformed());
+        assert_eq!(result.original_rows[1].display_value, "Second");
+    }
+
     #[test]
     fn test_empty_response() {
         let parser = ResponseParser::new(vec!["Name".to_string()], 0, 0, Vec::new());
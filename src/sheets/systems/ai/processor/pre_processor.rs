@@ -10,6 +10,7 @@
 //! - Register indexes with Navigator
 //! - Build row data for AI request
 //! - Handle structure vs root table differences
+//! - Size batches: at most `ai_width_limit` rows and `ai_char_budget` characters each
 //!
 //! ## Display Value Resolution
 //!
@@ -17,10 +18,12 @@
 //! For AI-added rows: get from Navigator (stored from previous step)
 //!
 //! Key column index:
+//! - Sheets with a configured AI key column (`SheetMetadata::ai_key_column`): that column
 //! - Structure tables: column 2 (after row_index, parent_key)
 //! - Root tables: column 1 (after row_index or id)
 
 use std::collections::HashSet;
+use std::ops::Range;
 
 use super::navigator::{IndexMapper, StableRowId};
 
@@ -221,6 +224,51 @@ impl PreProcessor {
         }
     }
 
+    /// Estimated payload size of a row: total length of the cells sent to the AI
+    pub fn estimate_row_chars(row: &[String], included_column_indices: &[usize]) -> usize {
+        included_column_indices
+            .iter()
+            .filter_map(|&col_idx| row.get(col_idx))
+            .map(|cell| cell.chars().count())
+            .sum()
+    }
+
+    /// Rough token count of a payload (about 4 characters per token for typical text).
+    /// Used for the estimate shown before sending, never to cut requests.
+    pub fn estimate_tokens(chars: usize) -> usize {
+        chars.div_ceil(4)
+    }
+
+    /// Split consecutive items into chunks of at most `max_rows` rows and `char_budget`
+    /// characters (0 = no character limit). `item_sizes` holds (rows, chars) per item.
+    /// An item that alone exceeds either limit becomes its own chunk instead of being dropped.
+    pub fn chunk_by_budget(
+        item_sizes: &[(usize, usize)],
+        max_rows: usize,
+        char_budget: usize,
+    ) -> Vec<Range<usize>> {
+        let max_rows = max_rows.max(1);
+        let mut chunks = Vec::new();
+        let mut start = 0;
+        let (mut rows, mut chars) = (0usize, 0usize);
+        for (idx, &(item_rows, item_chars)) in item_sizes.iter().enumerate() {
+            let over_rows = rows + item_rows > max_rows;
+            let over_chars = char_budget > 0 && chars + item_chars > char_budget;
+            if idx > start && (over_rows || over_chars) {
+                chunks.push(start..idx);
+                start = idx;
+                rows = 0;
+                chars = 0;
+            }
+            rows += item_rows;
+            chars += item_chars;
+        }
+        if start < item_sizes.len() {
+            chunks.push(start..item_sizes.len());
+        }
+        chunks
+    }
+
     /// Get the appropriate key column index for a table
     ///
     /// - Child tables: column 2 (after row_index, parent_key)
@@ -278,6 +326,30 @@ mod tests {
         assert!(navigator.get("Aircraft", None, 1).is_none()); // Not selected
     }
 
+    #[test]
+    fn test_prepare_batch_with_configured_key_column() {
+        let processor = PreProcessor::new();
+        let mut navigator = IndexMapper::new();
+
+        // Sheet configured to identify rows by "Speed" (index 2) instead of "Name"
+        let config = PreProcessConfig::for_root_table(
+            "Aircraft".to_string(),
+            None,
+            PreProcessor::get_key_column_index(false, Some(2)),
+            vec![1, 2],
+            vec!["Name".to_string(), "Speed".to_string()],
+        );
+
+        let grid = make_grid();
+        let row_indices: Vec<i64> = vec![0, 1, 2];
+        let batch = processor.prepare_batch(config, &grid, &row_indices, &[1], &mut navigator);
+
+        assert_eq!(batch.rows.len(), 1);
+        assert_eq!(batch.rows[0].display_value(), "500");
+        assert!(batch.sent_display_values.contains("500"));
+        assert!(!batch.sent_display_values.contains("LaGG-3"));
+    }
+
     #[test]
     fn test_key_column_index() {
         // Parent/root table (is_child_table = false)
@@ -288,4 +360,30 @@ mod tests {
         assert_eq!(PreProcessor::get_key_column_index(true, None), 2);
         assert_eq!(PreProcessor::get_key_column_index(true, Some(5)), 5);
     }
+
+    #[test]
+    fn test_chunk_by_budget() {
+        let grid = make_grid();
+        let sizes: Vec<(usize, usize)> = grid
+            .iter()
+            .map(|row| (1, PreProcessor::estimate_row_chars(row, &[1, 2])))
+            .collect();
+        assert_eq!(sizes, vec![(1, 12), (1, 9), (1, 9)]);
+
+        // Row limit only
+        assert_eq!(PreProcessor::chunk_by_budget(&sizes, 2, 0), vec![0..2, 2..3]);
+        // Character budget splits before the row limit is reached
+        assert_eq!(PreProcessor::chunk_by_budget(&sizes, 32, 20), vec![0..1, 1..3]);
+        // A row larger than the budget is still sent, alone
+        assert_eq!(PreProcessor::chunk_by_budget(&sizes, 32, 5), vec![0..1, 1..2, 2..3]);
+        assert!(PreProcessor::chunk_by_budget(&[], 32, 5).is_empty());
+    }
+
+    #[test]
+    fn test_estimate_tokens_rounds_up() {
+        assert_eq!(PreProcessor::estimate_tokens(0), 0);
+        assert_eq!(PreProcessor::estimate_tokens(1), 1);
+        assert_eq!(PreProcessor::estimate_tokens(8), 2);
+        assert_eq!(PreProcessor::estimate_tokens(9), 3);
+    }
 }
@@ -0,0 +1,79 @@
+// src/sheets/systems/ai/python_runtime.rs
+//! Capability check for the embedded Python runtime used by the AI script.
+//!
+//! The interpreter is initialized once and the packages imported by
+//! `ai_processor.py` are probed. The outcome is cached for the session so
+//! AI entry points can refuse early with an actionable message instead of failing
+//! inside the first Python call.
+
+use pyo3::prelude::*;
+use std::sync::OnceLock;
+
+/// Python modules the AI script imports, with the pip package that provides each
+const REQUIRED_MODULES: &[(&str, &str)] = &[("google.genai", "google-genai")];
+
+static PYTHON_RUNTIME: OnceLock<Result<String, String>> = OnceLock::new();
+
+/// Interpreter version (`Ok`) or an explanation of what to install (`Err`).
+/// The first call initializes Python; later calls return the cached result.
+pub fn python_runtime_status() -> &'static Result<String, String> {
+    PYTHON_RUNTIME.get_or_init(check_python_runtime)
+}
+
+/// Guard for AI entry points: `Err` carries the install guidance to surface to the user
+pub fn require_python_runtime() -> Result<(), String> {
+    python_runtime_status()
+        .as_ref()
+        .map(|_| ())
+        .map_err(|reason| format!("AI unavailable: {}", reason))
+}
+
+fn check_python_runtime() -> Result<String, String> {
+    // pyo3 panics when no usable interpreter can be initialized
+    let outcome = std::panic::catch_unwind(|| {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let info = py.version_info();
+            let version = format!("{}.{}.{}", info.major, info.minor, info.patch);
+            let missing: Vec<&str> = REQUIRED_MODULES
+                .iter()
+                .filter(|(module, _)| py.import(*module).is_err())
+                .map(|(_, package)| *package)
+                .collect();
+            (version, missing)
+        })
+    });
+    match outcome {
+        Ok((version, missing)) if missing.is_empty() => {
+            bevy::log::info!("Python {} runtime ready for AI features.", version);
+            Ok(version)
+        }
+        Ok((version, missing)) => Err(missing_packages_message(&version, &missing)),
+        Err(_) => Err(
+            "No usable Python interpreter was found. Install Python 3 (64-bit, added to PATH), \
+             then run: pip install google-genai"
+                .to_string(),
+        ),
+    }
+}
+
+fn missing_packages_message(version: &str, missing: &[&str]) -> String {
+    format!(
+        "Python {} is missing required package(s): {}. Install them with: pip install {}",
+        version,
+        missing.join(", "),
+        missing.join(" ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_packages_message_names_install_command() {
+        let msg = missing_packages_message("3.12.1", &["google-genai", "requests"]);
+        assert!(msg.contains("Python 3.12.1"));
+        assert!(msg.ends_with("pip install google-genai requests"));
+    }
+}
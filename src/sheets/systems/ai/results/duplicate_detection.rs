@@ -6,8 +6,10 @@ use std::collections::HashMap;
 use crate::sheets::resources::SheetRegistry;
 use crate::ui::elements::editor::state::ReviewChoice;
 
+use crate::sheets::systems::ai::duplicate_map_helpers::{find_duplicate_in_map, DuplicateMatchOptions};
 use crate::sheets::systems::ai::row_helpers::{
-    extract_original_snapshot_for_merge, generate_review_choices, normalize_cell_value,
+    extract_original_snapshot_for_merge, generate_review_choices,
+    keep_original_for_read_only_columns, normalize_cell_value,
 };
 
 /// Check if a new row is a duplicate of an existing row
@@ -20,6 +22,7 @@ pub fn check_for_duplicate(
     cat_ctx: &Option<String>,
     sheet_ctx: &Option<String>,
     registry: &SheetRegistry,
+    match_options: DuplicateMatchOptions,
 ) -> (
     Option<usize>,
     Option<Vec<ReviewChoice>>,
@@ -39,7 +42,9 @@ pub fn check_for_duplicate(
     };
 
     let normalized_first = normalize_cell_value(first_val);
-    let Some(&matched_row_index) = first_col_value_to_row.get(&normalized_first) else {
+    let Some(matched_row_index) =
+        find_duplicate_in_map(&normalized_first, first_col_value_to_row, match_options)
+    else {
         return (None, None, None, false);
     };
 
@@ -57,7 +62,8 @@ pub fn check_for_duplicate(
 
     let orig_vec = extract_original_snapshot_for_merge(existing_row, included);
     // When generating choices, align the ai_snapshot with included[]; ai_snapshot is already in included order
-    let choices = generate_review_choices(&orig_vec, ai_snapshot);
+    let mut choices = generate_review_choices(&orig_vec, ai_snapshot);
+    keep_original_for_read_only_columns(&mut choices, included, registry, cat_ctx, sheet_name);
 
     (Some(matched_row_index), Some(choices), Some(orig_vec), true)
 }
@@ -11,10 +11,12 @@ use crate::ui::elements::editor::state::{
 
 use crate::sheets::systems::ai::row_helpers::{
     create_row_snapshots, extract_ai_snapshot_from_new_row, generate_review_choices,
-    skip_key_prefix,
+    keep_original_for_read_only_columns, skip_key_prefix, snap_enum_suggestions,
 };
 use crate::sheets::systems::ai::column_helpers::calculate_dynamic_prefix;
-use crate::sheets::systems::ai::duplicate_map_helpers::build_duplicate_map_for_parents;
+use crate::sheets::systems::ai::duplicate_map_helpers::{
+    build_duplicate_map_for_parents, DuplicateMatchOptions,
+};
 use crate::sheets::systems::ai::original_cache::cache_original_row_for_review;
 use crate::sheets::systems::ai::phase2_helpers::detect_duplicate_indices;
 use crate::sheets::systems::ai::structure_jobs::enqueue_structure_jobs_for_batch;
@@ -102,10 +104,13 @@ pub fn handle_batch_result(
             state.ai_row_reviews.clear();
             state.ai_new_row_reviews.clear();
             state.ai_structure_reviews.clear();
+            state.ai_review_tally = Default::default();
 
             // Calculate max existing row_index for projected index assignment
             let max_existing_row_index = ev.original_row_indices.iter().copied().max().unwrap_or(0);
             let mut next_projected_index = max_existing_row_index + 1;
+            // Suggestions for Enum columns that match no allowed value
+            let mut invalid_enum_values = 0usize;
 
             // Process original rows → RowReview
             for (i, suggestion_full) in orig_slice.iter().enumerate() {
@@ -123,11 +128,25 @@ pub fn handle_batch_result(
                     continue;
                 }
 
-                let (original_snapshot, ai_snapshot) = create_row_snapshots(
+                let (original_snapshot, mut ai_snapshot) = create_row_snapshots(
                     registry, &cat_ctx, &sheet_name, row_index, suggestion, included,
                 );
+                invalid_enum_values += snap_enum_suggestions(
+                    &mut ai_snapshot,
+                    included,
+                    registry,
+                    &cat_ctx,
+                    &sheet_name,
+                );
 
-                let choices = generate_review_choices(&original_snapshot, &ai_snapshot);
+                let mut choices = generate_review_choices(&original_snapshot, &ai_snapshot);
+                keep_original_for_read_only_columns(
+                    &mut choices,
+                    included,
+                    registry,
+                    &cat_ctx,
+                    &sheet_name,
+                );
 
                 state.ai_row_reviews.push(RowReview {
                     row_index,
@@ -173,7 +192,14 @@ pub fn handle_batch_result(
                     continue;
                 }
 
-                let ai_snapshot = extract_ai_snapshot_from_new_row(suggestion, included);
+                let mut ai_snapshot = extract_ai_snapshot_from_new_row(suggestion, included);
+                invalid_enum_values += snap_enum_suggestions(
+                    &mut ai_snapshot,
+                    included,
+                    registry,
+                    &cat_ctx,
+                    &sheet_name,
+                );
 
                 let is_duplicate = duplicate_indices.contains(&new_idx);
 
@@ -198,6 +224,7 @@ pub fn handle_batch_result(
                             &cat_ctx,
                             &sheet_ctx_opt,
                             registry,
+                            DuplicateMatchOptions::from_state(state),
                         );
 
                     // For duplicates, projected_row_index = matched original's row_index
@@ -259,6 +286,16 @@ pub fn handle_batch_result(
                 }
             }
 
+            if invalid_enum_values > 0 {
+                feedback_writer.write(SheetOperationFeedback {
+                    message: format!(
+                        "AI suggested {} value(s) outside the allowed values of enum columns; they are highlighted in review.",
+                        invalid_enum_values
+                    ),
+                    is_error: false,
+                });
+            }
+
             // Store batch context for structure job enqueueing
             let batch_context = BatchProcessingContext {
                 duplicate_indices: duplicate_indices.clone(),
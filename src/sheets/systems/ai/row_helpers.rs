@@ -2,6 +2,7 @@
 // Helper functions for processing AI result rows, handling key prefixes, snapshots, and review choices
 
 use crate::sheets::resources::SheetRegistry;
+use crate::sheets::systems::logic::enum_validator::{enum_values, nearest_enum_value};
 use crate::ui::elements::editor::state::ReviewChoice;
 
 /// Skip key prefix from a full row, returning the data portion
@@ -62,6 +63,71 @@ pub fn generate_review_choices(
         .collect()
 }
 
+/// Whether a column is marked AI read-only (sent as context, suggestions never applied)
+pub fn is_ai_read_only_column(
+    registry: &SheetRegistry,
+    category: &Option<String>,
+    sheet_name: &str,
+    column_index: usize,
+) -> bool {
+    registry
+        .get_sheet(category, sheet_name)
+        .and_then(|s| s.metadata.as_ref())
+        .and_then(|m| m.columns.get(column_index))
+        .map_or(false, |c| c.ai_read_only)
+}
+
+/// Force the Original choice for AI read-only columns so their suggestions are never applied
+pub fn keep_original_for_read_only_columns(
+    choices: &mut [ReviewChoice],
+    included_cols: &[usize],
+    registry: &SheetRegistry,
+    category: &Option<String>,
+    sheet_name: &str,
+) {
+    for (choice, &col) in choices.iter_mut().zip(included_cols) {
+        if is_ai_read_only_column(registry, category, sheet_name, col) {
+            *choice = ReviewChoice::Original;
+        }
+    }
+}
+
+/// Snap suggestions for Enum columns to their nearest allowed value. Suggestions with no
+/// close allowed value are kept as-is: review flags them and applying one is rejected unless
+/// the column adds unknown values. Returns how many were left outside the allowed values.
+pub fn snap_enum_suggestions(
+    ai_snapshot: &mut [String],
+    included_cols: &[usize],
+    registry: &SheetRegistry,
+    category: &Option<String>,
+    sheet_name: &str,
+) -> usize {
+    let Some(metadata) = registry
+        .get_sheet(category, sheet_name)
+        .and_then(|s| s.metadata.as_ref())
+    else {
+        return 0;
+    };
+    let mut invalid = 0;
+    for (value, &col) in ai_snapshot.iter_mut().zip(included_cols) {
+        let Some(allowed) = metadata.columns.get(col).and_then(|c| enum_values(&c.validator)) else {
+            continue;
+        };
+        if value.trim().is_empty() {
+            continue;
+        }
+        match nearest_enum_value(value, allowed) {
+            Some(snapped) => {
+                if snapped != value.as_str() {
+                    *value = snapped.to_string();
+                }
+            }
+            None => invalid += 1,
+        }
+    }
+    invalid
+}
+
 /// Normalize a cell value for duplicate detection (remove whitespace, lowercase)
 pub fn normalize_cell_value(value: &str) -> String {
     value.replace(['\r', '\n'], "").trim().to_lowercase()
@@ -0,0 +1,135 @@
+// src/sheets/systems/ai/script_file.rs
+//! Location of the bundled `ai_processor.py` on disk.
+//!
+//! The script is embedded in the binary and written out before Python loads it. It goes to
+//! the path configured in Settings, or else next to the executable (`script/ai_processor.py`),
+//! falling back to the user data directory when that folder is read-only. The file is only
+//! rewritten when its content differs from the embedded copy.
+
+use bevy::log::{debug, info, warn};
+use directories_next::ProjectDirs;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+/// The script shipped with this build
+pub const AI_PROCESSOR_PY: &str = include_str!("../../../../script/ai_processor.py");
+
+const SCRIPT_RELATIVE_PATH: &str = "script/ai_processor.py";
+
+/// Path chosen in Settings; `None` = automatic
+static CONFIGURED_PATH: RwLock<Option<PathBuf>> = RwLock::new(None);
+/// Where the script was last written (or found up to date)
+static RESOLVED_PATH: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// Use `path` for the script from now on (`None` = automatic); the next
+/// `ensure_ai_script` resolves the location again.
+pub fn set_configured_script_path(path: Option<PathBuf>) {
+    *CONFIGURED_PATH.write().unwrap_or_else(|e| e.into_inner()) = path;
+    *RESOLVED_PATH.write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// Locations tried in order: only the configured one when set, otherwise the
+/// executable's directory and then the user data directory.
+fn candidate_paths(configured: Option<&Path>) -> Vec<PathBuf> {
+    if let Some(path) = configured {
+        return vec![path.to_path_buf()];
+    }
+    let mut candidates = Vec::new();
+    if let Some(exe_dir) = std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(Path::to_path_buf))
+    {
+        candidates.push(exe_dir.join(SCRIPT_RELATIVE_PATH));
+    }
+    if let Some(dirs) = ProjectDirs::from("com", "SkylineApps", "SkylineDB") {
+        candidates.push(dirs.data_dir().join(SCRIPT_RELATIVE_PATH));
+    }
+    if candidates.is_empty() {
+        candidates.push(PathBuf::from(SCRIPT_RELATIVE_PATH));
+    }
+    candidates
+}
+
+/// Write `contents` to `path` unless the file already holds exactly that.
+/// Returns whether the file was written.
+fn write_if_changed(path: &Path, contents: &[u8]) -> io::Result<bool> {
+    if fs::read(path).is_ok_and(|existing| existing == contents) {
+        return Ok(false);
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)?;
+    Ok(true)
+}
+
+/// Make sure the script on disk matches this build and return its path.
+/// Cheap when nothing changed (one read and compare), so it runs before every AI call.
+pub fn ensure_ai_script() -> Result<PathBuf, String> {
+    let configured = CONFIGURED_PATH
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone();
+    let previous = RESOLVED_PATH.read().unwrap_or_else(|e| e.into_inner()).clone();
+
+    let mut failures = Vec::new();
+    for path in candidate_paths(configured.as_deref()) {
+        match write_if_changed(&path, AI_PROCESSOR_PY.as_bytes()) {
+            Ok(written) => {
+                if written {
+                    info!("ai_processor.py written to {}", path.display());
+                } else if previous.as_ref() != Some(&path) {
+                    info!("Using ai_processor.py at {} (up to date)", path.display());
+                } else {
+                    debug!("ai_processor.py at {} is up to date", path.display());
+                }
+                *RESOLVED_PATH.write().unwrap_or_else(|e| e.into_inner()) = Some(path.clone());
+                return Ok(path);
+            }
+            Err(e) => {
+                warn!("Cannot write ai_processor.py to {}: {}", path.display(), e);
+                failures.push(format!("{}: {}", path.display(), e));
+            }
+        }
+    }
+    Err(format!("Could not write ai_processor.py ({})", failures.join("; ")))
+}
+
+/// Path Python should load the script from: where it was last written, else the
+/// first candidate location.
+pub fn ai_script_path() -> PathBuf {
+    if let Some(path) = RESOLVED_PATH.read().unwrap_or_else(|e| e.into_inner()).clone() {
+        return path;
+    }
+    let configured = CONFIGURED_PATH
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone();
+    candidate_paths(configured.as_deref()).remove(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn script_is_rewritten_only_when_content_differs() {
+        let dir = std::env::temp_dir().join(format!("skylinedb_script_test_{}", uuid::Uuid::new_v4()));
+        let path = dir.join(SCRIPT_RELATIVE_PATH);
+
+        assert!(write_if_changed(&path, b"v1").unwrap());
+        assert!(!write_if_changed(&path, b"v1").unwrap());
+        assert!(write_if_changed(&path, b"v2").unwrap());
+        assert_eq!(fs::read(&path).unwrap(), b"v2");
+
+        // A configured path is the only candidate; automatic ones never use the CWD first
+        assert_eq!(candidate_paths(Some(&path)), vec![path.clone()]);
+        let automatic = candidate_paths(None);
+        assert!(automatic.iter().all(|p| p.ends_with(SCRIPT_RELATIVE_PATH)));
+        assert!(automatic[0].is_absolute());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
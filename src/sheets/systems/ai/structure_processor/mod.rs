@@ -3,7 +3,7 @@
 
 mod existing_row_extractor;
 mod new_row_extractor;
-mod python_executor;
+pub mod python_executor;
 mod task_executor;
 mod data_preparation;
 
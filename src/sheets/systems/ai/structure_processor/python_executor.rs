@@ -2,19 +2,181 @@
 //! Python AI query execution and response parsing
 
 use bevy::prelude::*;
-use pyo3::exceptions::PyValueError;
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::PyString;
-use std::ffi::CString;
+use pyo3::sync::OnceLockExt;
+use pyo3::types::{PyString, PyTuple};
+use std::ffi::{CStr, CString};
+use std::sync::OnceLock;
 
-/// Rewrite the Python processor file to ensure it's up to date before execution
+use crate::sheets::systems::ai::python_runtime::require_python_runtime;
+use crate::sheets::systems::ai::script_file::{ai_script_path, ensure_ai_script};
+
+/// Rewrite the Python processor file if it differs from this build before execution
 pub fn rewrite_python_processor() {
-    const AI_PROCESSOR_PY: &str = include_str!("../../../../../script/ai_processor.py");
-    let script_path = std::path::Path::new("script/ai_processor.py");
-    if let Some(parent) = script_path.parent() {
-        let _ = std::fs::create_dir_all(parent);
+    if let Err(e) = ensure_ai_script() {
+        error!("{}", e);
+    }
+}
+
+/// Output written by the Python processor to `sys.stdout` / `sys.stderr` during a call
+#[derive(Debug, Default, Clone)]
+pub struct CapturedPythonOutput {
+    pub stdout: String,
+    pub stderr: String,
+}
+
+impl CapturedPythonOutput {
+    /// Append captured stderr (if any) to an error message so Python-side failures
+    /// (missing packages, API errors, tracebacks) are visible in the AI log.
+    pub fn append_stderr(&self, message: String) -> String {
+        let stderr = self.stderr.trim_end();
+        if stderr.is_empty() {
+            message
+        } else {
+            format!("{}\n--- Python stderr ---\n{}", message, stderr)
+        }
+    }
+}
+
+/// Replacements for `sys.stdout` / `sys.stderr`: each thread's writes go to the buffer it
+/// registered with `begin()`, everything else to the original stream. AI calls run on
+/// several blocking threads at once, so swapping the streams per call would mix their output.
+const THREAD_CAPTURE_CODE: &CStr = c"
+import sys
+import threading
+
+
+class ThreadCapture:
+    def __init__(self, fallback):
+        self.fallback = fallback
+        self.buffers = {}
+
+    def begin(self, buffer):
+        self.buffers[threading.get_ident()] = buffer
+
+    def end(self):
+        self.buffers.pop(threading.get_ident(), None)
+
+    def target(self):
+        return self.buffers.get(threading.get_ident(), self.fallback)
+
+    def write(self, text):
+        target = self.target()
+        return target.write(text) if target is not None else len(text)
+
+    def flush(self):
+        target = self.target()
+        if target is not None:
+            target.flush()
+
+    def __getattr__(self, name):
+        return getattr(self.fallback, name)
+
+
+def install():
+    sys.stdout = ThreadCapture(sys.stdout)
+    sys.stderr = ThreadCapture(sys.stderr)
+    return sys.stdout, sys.stderr
+";
+
+/// The installed (stdout, stderr) capture streams; installed once per process
+static THREAD_CAPTURE: OnceLock<Result<(Py<PyAny>, Py<PyAny>), String>> = OnceLock::new();
+
+fn thread_capture(py: Python<'_>) -> PyResult<(Bound<'_, PyAny>, Bound<'_, PyAny>)> {
+    let installed = THREAD_CAPTURE.get_or_init_py_attached(py, || {
+        (|| -> PyResult<(Py<PyAny>, Py<PyAny>)> {
+            let module = PyModule::from_code(
+                py,
+                THREAD_CAPTURE_CODE,
+                c"skylinedb_thread_capture.py",
+                c"skylinedb_thread_capture",
+            )?;
+            module.call_method0("install")?.extract()
+        })()
+        .map_err(|e| e.to_string())
+    });
+    match installed {
+        Ok((out, err)) => Ok((out.bind(py).clone(), err.bind(py).clone())),
+        Err(e) => Err(PyRuntimeError::new_err(e.clone())),
     }
-    let _ = std::fs::write(script_path, AI_PROCESSOR_PY);
+}
+
+/// Load `ai_processor.py` (see `script_file`) and call `execute_ai_query`, capturing what
+/// the calling thread writes to stdout/stderr for the duration of the call.
+///
+/// Any Python exception is printed (with traceback) into the captured stderr
+/// before being returned, so callers can surface it alongside the error.
+pub fn call_ai_processor(
+    py: Python<'_>,
+    api_key: String,
+    payload_json: String,
+) -> (PyResult<String>, CapturedPythonOutput) {
+    call_processor_function(py, "execute_ai_query", vec![api_key, payload_json])
+}
+
+/// Call a string-returning function of `ai_processor.py` with string arguments,
+/// capturing stdout/stderr as described on `call_ai_processor`.
+fn call_processor_function(
+    py: Python<'_>,
+    function_name: &str,
+    args: Vec<String>,
+) -> (PyResult<String>, CapturedPythonOutput) {
+    let redirect = (|| -> PyResult<_> {
+        let (out, err) = thread_capture(py)?;
+        let io = py.import("io")?;
+        let out_buf = io.call_method0("StringIO")?;
+        let err_buf = io.call_method0("StringIO")?;
+        out.call_method1("begin", (&out_buf,))?;
+        err.call_method1("begin", (&err_buf,))?;
+        Ok((out, err, out_buf, err_buf))
+    })();
+
+    let result = (|| -> PyResult<String> {
+        let python_file_path = ai_script_path();
+        let processor_code_string = std::fs::read_to_string(&python_file_path)?;
+        let code_c_str = CString::new(processor_code_string)
+            .map_err(|e| PyValueError::new_err(format!("CString error: {}", e)))?;
+        let file_name_c_str = CString::new(python_file_path.to_string_lossy().into_owned())
+            .map_err(|e| PyValueError::new_err(format!("File name CString error: {}", e)))?;
+        let module_name_c_str = CString::new("ai_processor")
+            .map_err(|e| PyValueError::new_err(format!("Module name CString error: {}", e)))?;
+
+        let module = PyModule::from_code(py, code_c_str.as_c_str(), file_name_c_str.as_c_str(), module_name_c_str.as_c_str())?;
+        let binding = module.call_method1(function_name, PyTuple::new(py, args)?)?;
+        let result_str = binding.downcast::<PyString>()?.to_str()?.to_string();
+        Ok(result_str)
+    })();
+
+    let mut captured = CapturedPythonOutput::default();
+    match redirect {
+        Ok((out, err, out_buf, err_buf)) => {
+            if let Err(e) = &result {
+                // Writes the traceback into this thread's captured sys.stderr
+                e.display(py);
+            }
+            let _ = out.call_method0("end");
+            let _ = err.call_method0("end");
+            captured.stdout = out_buf
+                .call_method0("getvalue")
+                .and_then(|v| v.extract::<String>())
+                .unwrap_or_default();
+            captured.stderr = err_buf
+                .call_method0("getvalue")
+                .and_then(|v| v.extract::<String>())
+                .unwrap_or_default();
+        }
+        Err(e) => warn!("Failed to capture Python stdout/stderr: {}", e),
+    }
+
+    if !captured.stdout.trim().is_empty() {
+        info!("ai_processor.py stdout:\n{}", captured.stdout.trim_end());
+    }
+    if !captured.stderr.trim().is_empty() {
+        warn!("ai_processor.py stderr:\n{}", captured.stderr.trim_end());
+    }
+
+    (result, captured)
 }
 
 /// Execute Python AI query and parse response
@@ -24,27 +186,60 @@ pub async fn execute_python_ai_query(
     api_key: String,
     payload_json: String,
 ) -> (Result<Vec<Vec<String>>, String>, Option<String>, Option<Vec<usize>>) {
+    if let Err(e) = require_python_runtime() {
+        return (Err(e), None, None);
+    }
     tokio::task::spawn_blocking(move || {
-        Python::with_gil(|py| -> PyResult<(Result<Vec<Vec<String>>, String>, Option<String>, Option<Vec<usize>>)> {
-            let python_file_path = "script/ai_processor.py";
-            let processor_code_string = std::fs::read_to_string(python_file_path)?;
-            let code_c_str = CString::new(processor_code_string)
-                .map_err(|e| PyValueError::new_err(format!("CString error: {}", e)))?;
-            let file_name_c_str = CString::new(python_file_path)
-                .map_err(|e| PyValueError::new_err(format!("File name CString error: {}", e)))?;
-            let module_name_c_str = CString::new("ai_processor")
-                .map_err(|e| PyValueError::new_err(format!("Module name CString error: {}", e)))?;
-
-            let module = PyModule::from_code(py, code_c_str.as_c_str(), file_name_c_str.as_c_str(), module_name_c_str.as_c_str())?;
-            let binding = module.call_method1("execute_ai_query", (api_key, payload_json))?;
-            let result_str: &str = binding.downcast::<PyString>()?.to_str()?;
-
-            parse_ai_response(result_str)
+        Python::with_gil(|py| {
+            let (call_result, captured) = call_ai_processor(py, api_key, payload_json);
+            match call_result.and_then(|result_str| parse_ai_response(&result_str)) {
+                Ok((Err(e), raw, partitions)) => (Err(captured.append_stderr(e)), raw, partitions),
+                Ok(ok) => ok,
+                Err(e) => (
+                    Err(captured.append_stderr(format!("PyO3 error: {}", e))),
+                    Some(e.to_string()),
+                    None,
+                ),
+            }
         })
     })
     .await
-    .unwrap_or_else(|e| Ok((Err(format!("Tokio panic: {}", e)), None, None)))
-    .unwrap_or_else(|e| (Err(format!("PyO3 error: {}", e)), Some(e.to_string()), None))
+    .unwrap_or_else(|e| (Err(format!("Tokio panic: {}", e)), None, None))
+}
+
+/// Check an API key with the processor's `validate_api_key` (lists one model, no generation).
+///
+/// Returns `Err` with the provider's message when the key is rejected or Python fails.
+pub async fn validate_api_key(api_key: String) -> Result<(), String> {
+    require_python_runtime()?;
+    rewrite_python_processor();
+    tokio::task::spawn_blocking(move || {
+        Python::with_gil(|py| {
+            let (call_result, captured) =
+                call_processor_function(py, "validate_api_key", vec![api_key]);
+            match call_result {
+                Ok(result_str) => parse_key_validation_response(&result_str),
+                Err(e) => Err(captured.append_stderr(format!("PyO3 error: {}", e))),
+            }
+        })
+    })
+    .await
+    .unwrap_or_else(|e| Err(format!("Tokio panic: {}", e)))
+}
+
+/// Parse the `{ success, error? }` JSON returned by `validate_api_key`
+fn parse_key_validation_response(response_text: &str) -> Result<(), String> {
+    let parsed: serde_json::Value = serde_json::from_str(response_text)
+        .map_err(|e| format!("JSON parse error: {}", e))?;
+    if parsed.get("success").and_then(|v| v.as_bool()).unwrap_or(false) {
+        Ok(())
+    } else {
+        Err(parsed
+            .get("error")
+            .and_then(|v| v.as_str())
+            .unwrap_or("Unknown error")
+            .to_string())
+    }
 }
 
 /// Parse AI response JSON
@@ -127,3 +322,37 @@ fn parse_flat_response(
 
     Ok((Ok(out), parsed.get("raw_response").and_then(|v| v.as_str()).map(|s| s.to_string()), None))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_append_stderr() {
+        let empty = CapturedPythonOutput::default();
+        assert_eq!(empty.append_stderr("AI failed".to_string()), "AI failed");
+
+        let captured = CapturedPythonOutput {
+            stdout: String::new(),
+            stderr: "ModuleNotFoundError: No module named 'google'\n".to_string(),
+        };
+        assert_eq!(
+            captured.append_stderr("PyO3 error".to_string()),
+            "PyO3 error\n--- Python stderr ---\nModuleNotFoundError: No module named 'google'"
+        );
+    }
+
+    #[test]
+    fn test_parse_key_validation_response() {
+        assert_eq!(parse_key_validation_response(r#"{"success": true}"#), Ok(()));
+        assert_eq!(
+            parse_key_validation_response(r#"{"success": false, "error": "API key not valid"}"#),
+            Err("API key not valid".to_string())
+        );
+        assert_eq!(
+            parse_key_validation_response(r#"{"success": false}"#),
+            Err("Unknown error".to_string())
+        );
+        assert!(parse_key_validation_response("not json").is_err());
+    }
+}
@@ -238,6 +238,7 @@ fn build_payload(
         requested_grounding_with_google_search: root_meta
             .requested_grounding_with_google_search
             .unwrap_or(false),
+        ai_temperature: root_meta.ai_temperature,
         allow_row_additions,
         key_prefix_count: None,
         key_prefix_headers: None,
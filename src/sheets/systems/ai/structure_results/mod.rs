@@ -4,6 +4,7 @@
 
 mod builder;
 mod cache;
+mod nested;
 mod processor;
 
 use bevy::prelude::*;
@@ -20,6 +21,7 @@ use super::utils::parse_structure_rows_from_cell;
 // Re-export submodule functions
 use builder::build_parent_row;
 use cache::populate_parent_row_cache;
+use nested::{gather_descendant_rows, structure_table_chain};
 use processor::process_structure_suggestion_row;
 
 /// Process a single parent row's structure partition results
@@ -85,60 +87,33 @@ pub fn process_structure_partition(
     };
 
     // --- Build original rows ---
-    // For child structure tables (real tables with parent_key), load actual child rows
-    let mut original_rows = if structure_path.len() == 1 {
-        // Compute child table name
-        let column_header = sheet.metadata.as_ref()
-            .and_then(|m| m.columns.get(column_index))
-            .map(|c| c.header.as_str())
-            .unwrap_or("");
-        let child_table_name = format!("{}_{}", root_sheet, column_header);
-        
-        // Try to get child table from registry
-        if let Some(child_sheet) = registry.get_sheet(root_category, &child_table_name) {
-            // This is a real child table - load actual rows filtered by parent_key
-            info!(
-                "Loading child table rows from {} for actual_parent_db_row_index={} (review_index={}), child_sheet.grid.len()={}, schema_len={}",
-                child_table_name, actual_parent_db_row_index, parent_row_index, child_sheet.grid.len(), schema_len
-            );
-            
-            let mut rows: Vec<Vec<String>> = Vec::new();
-            
-            // Filter child rows by parent_key (column index 1) matching actual database row_index
-            // Skip first 2 technical columns (row_index=0, parent_key=1)
-            // and take only the data columns that match the schema
-            for (_idx, child_row) in child_sheet.grid.iter().enumerate() {
-                if let Some(parent_key_str) = child_row.get(1) {
-                    if let Ok(parent_key_val) = parent_key_str.parse::<usize>() {
-                        if parent_key_val == actual_parent_db_row_index {
-                            // Extract only data columns (skip row_index and parent_key)
-                            let data_columns: Vec<String> = child_row
-                                .iter()
-                                .skip(2)  // Skip row_index and parent_key
-                                .take(schema_len)  // Take only schema-defined columns
-                                .cloned()
-                                .collect();
-                            rows.push(data_columns);
-                        }
+    // Structure data lives in real child tables linked by parent_key; nested paths walk the
+    // chain of child tables down from this parent row
+    let mut original_rows = match structure_table_chain(registry, root_category, root_sheet, structure_path) {
+        Ok(chain) => {
+            let child_table_name = chain.last().cloned().unwrap_or_default();
+            match gather_descendant_rows(registry, root_category, &chain, actual_parent_db_row_index, schema_len) {
+                Some(mut rows) => {
+                    info!(
+                        "Loaded {} rows from {} (depth={}) for actual_parent_db_row_index={} (review_index={}), schema_len={}",
+                        rows.len(), child_table_name, chain.len(), actual_parent_db_row_index, parent_row_index, schema_len
+                    );
+                    // Ensure we have at least one row for the structure
+                    if rows.is_empty() {
+                        rows.push(vec![String::new(); schema_len]);
                     }
+                    rows
+                }
+                None => {
+                    warn!("Child table chain {:?} not fully loaded in registry (this shouldn't happen for structure columns)", chain);
+                    vec![vec![String::new(); schema_len]]
                 }
             }
-            
-            info!("  Found {} child rows matching parent_key={}", rows.len(), actual_parent_db_row_index);
-            
-            // Ensure we have at least one row for the structure
-            if rows.is_empty() {
-                rows.push(vec![String::new(); schema_len]);
-            }
-            rows
-        } else {
-            warn!("Child table {} not found in registry (this shouldn't happen for structure columns)", child_table_name);
+        }
+        Err(e) => {
+            warn!("Cannot resolve child table for structure path {:?} of {}: {}", structure_path, root_sheet, e);
             vec![vec![String::new(); schema_len]]
         }
-    } else {
-        // Nested structures not fully supported yet - use empty row
-        warn!("Nested structure path detected (depth={}), not yet fully supported", structure_path.len());
-        vec![vec![String::new(); schema_len]]
     };
 
     // Normalize row lengths
@@ -0,0 +1,145 @@
+// src/sheets/systems/ai/structure_results/nested.rs
+// Loading of original structure rows from child tables at any depth of a structure path
+
+use std::collections::HashSet;
+
+use crate::sheets::definitions::SheetMetadata;
+use crate::sheets::resources::SheetRegistry;
+
+/// Child table names along a structure path, outermost first: `{root}_{column}` for the
+/// first level, then `{child}_{field}` for each nested field. Nested indices count the
+/// child table's data columns (technical row_index/parent_key columns excluded), matching
+/// the structure schema the path was built from.
+pub(super) fn structure_table_chain(
+    registry: &SheetRegistry,
+    category: &Option<String>,
+    root_sheet: &str,
+    structure_path: &[usize],
+) -> Result<Vec<String>, String> {
+    let mut chain: Vec<String> = Vec::with_capacity(structure_path.len());
+    let mut table = root_sheet.to_string();
+    for (depth, &column_index) in structure_path.iter().enumerate() {
+        let meta = registry
+            .get_sheet(category, &table)
+            .and_then(|s| s.metadata.as_ref())
+            .ok_or_else(|| format!("table '{}' is not loaded", table))?;
+        let column = if depth == 0 {
+            meta.columns.get(column_index)
+        } else {
+            meta.columns
+                .iter()
+                .filter(|c| !SheetMetadata::is_technical_column_header(&c.header))
+                .nth(column_index)
+        }
+        .ok_or_else(|| format!("column {} not found in '{}'", column_index, table))?;
+        table = format!("{}_{}", table, column.header);
+        chain.push(table.clone());
+    }
+    Ok(chain)
+}
+
+/// Data columns of every row in the last table of `chain` that descends from the root row
+/// `root_row_index`: each level keeps the rows whose parent_key is one of the row_index
+/// values kept one level up. Returns None when a table of the chain is not loaded.
+pub(super) fn gather_descendant_rows(
+    registry: &SheetRegistry,
+    category: &Option<String>,
+    chain: &[String],
+    root_row_index: usize,
+    schema_len: usize,
+) -> Option<Vec<Vec<String>>> {
+    let mut parent_keys: HashSet<usize> = HashSet::from([root_row_index]);
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    for (depth, table) in chain.iter().enumerate() {
+        let grid = &registry.get_sheet(category, table)?.grid;
+        let children = grid.iter().filter(|row| {
+            row.get(1)
+                .and_then(|key| key.trim().parse::<usize>().ok())
+                .is_some_and(|key| parent_keys.contains(&key))
+        });
+        if depth + 1 < chain.len() {
+            parent_keys = children
+                .filter_map(|row| row.first().and_then(|idx| idx.trim().parse::<usize>().ok()))
+                .collect();
+        } else {
+            // Skip row_index and parent_key, keep only schema-defined columns
+            rows = children
+                .map(|row| row.iter().skip(2).take(schema_len).cloned().collect())
+                .collect();
+        }
+    }
+    Some(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sheets::definitions::{ColumnValidator, SheetGridData};
+
+    fn table(name: &str, headers: &[&str], grid: Vec<Vec<&str>>) -> SheetGridData {
+        let mut metadata = SheetMetadata::create_generic(
+            name.to_string(),
+            format!("{}.json", name),
+            headers.len(),
+            Some("Game".to_string()),
+        );
+        for (column, header) in metadata.columns.iter_mut().zip(headers) {
+            column.header = header.to_string();
+        }
+        SheetGridData {
+            metadata: Some(metadata),
+            row_indices: (0..grid.len() as i64).collect(),
+            grid: grid
+                .into_iter()
+                .map(|row| row.into_iter().map(str::to_string).collect())
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn grandchild_rows_follow_the_parent_key_chain() {
+        let cat = Some("Game".to_string());
+        let mut registry = SheetRegistry::default();
+        let mut items = table("Items", &["row_index", "Name", "Parts"], vec![
+            vec!["1", "Sword", ""],
+            vec!["2", "Shield", ""],
+        ]);
+        items.metadata.as_mut().unwrap().columns[2].validator = Some(ColumnValidator::Structure);
+        registry.add_or_replace_sheet(cat.clone(), "Items".to_string(), items);
+        // Parts of Sword: 10, 11; part of Shield: 12
+        registry.add_or_replace_sheet(
+            cat.clone(),
+            "Items_Parts".to_string(),
+            table("Items_Parts", &["row_index", "parent_key", "Part", "Materials"], vec![
+                vec!["10", "1", "Blade", ""],
+                vec!["11", "1", "Hilt", ""],
+                vec!["12", "2", "Boss", ""],
+            ]),
+        );
+        registry.add_or_replace_sheet(
+            cat.clone(),
+            "Items_Parts_Materials".to_string(),
+            table("Items_Parts_Materials", &["row_index", "parent_key", "Material", "Qty"], vec![
+                vec!["100", "10", "Steel", "3"],
+                vec!["101", "12", "Oak", "1"],
+                vec!["102", "11", "Leather", "2"],
+                vec!["103", "1", "Wrong level", "9"],
+            ]),
+        );
+
+        // Path: Items.Parts (column 2) -> Materials (second data field of Items_Parts)
+        let chain = structure_table_chain(&registry, &cat, "Items", &[2, 1]).unwrap();
+        assert_eq!(chain, vec!["Items_Parts", "Items_Parts_Materials"]);
+
+        let rows = gather_descendant_rows(&registry, &cat, &chain, 1, 2).unwrap();
+        assert_eq!(rows, vec![vec!["Steel", "3"], vec!["Leather", "2"]]);
+        let rows = gather_descendant_rows(&registry, &cat, &chain, 2, 2).unwrap();
+        assert_eq!(rows, vec![vec!["Oak", "1"]]);
+        // First level behaves like the single-level lookup
+        let rows = gather_descendant_rows(&registry, &cat, &chain[..1], 1, 1).unwrap();
+        assert_eq!(rows, vec![vec!["Blade"], vec!["Hilt"]]);
+
+        assert!(structure_table_chain(&registry, &cat, "Items", &[2, 5]).is_err());
+        assert!(gather_descendant_rows(&registry, &cat, &["Missing".to_string()], 1, 1).is_none());
+    }
+}
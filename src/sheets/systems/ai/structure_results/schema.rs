@@ -176,6 +176,12 @@ mod tests {
             ai_include_in_send: None,
             deleted: false,
             hidden: false, // Test column, not hidden
+            trim_numeric_zeros: false,
+            ai_read_only: false,
+            multiline: false,
+            enum_auto_add: false,
+            numeric_range: None,
+            default_value: None,
             width: None,
             structure_schema: Some(vec![name_field.clone(), nested_field.clone()]),
             structure_column_order: None,
@@ -198,6 +204,8 @@ mod tests {
             random_picker: None,
             structure_parent: None,
             hidden: false,
+            ai_key_column: None,
+            color: None,
         };
         let sheet = crate::sheets::definitions::SheetGridData {
             grid: vec![vec![json.to_string()]],
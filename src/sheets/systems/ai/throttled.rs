@@ -4,7 +4,7 @@ use bevy::prelude::*;
 
 pub fn apply_throttled_ai_changes(
     mut state: ResMut<EditorWindowState>,
-    mut cell_update_writer: EventWriter<crate::sheets::events::UpdateCellEvent>,
+    mut cell_batch_writer: EventWriter<crate::sheets::events::UpdateCellsBatchEvent>,
     mut add_row_writer: EventWriter<crate::sheets::events::AddSheetRowRequest>,
     mut add_rows_batch_writer: EventWriter<crate::sheets::events::AddSheetRowsBatchRequest>,
 ) {
@@ -15,6 +15,7 @@ pub fn apply_throttled_ai_changes(
             category,
             sheet_name,
             rows_initial_values,
+            at_end: false,
         });
         return; // Process only one batch per frame
     }
@@ -26,6 +27,39 @@ pub fn apply_throttled_ai_changes(
     let queue_size_before = state.ai_throttled_apply_queue.len();
     let mut operations_processed = 0;
     
+    // Consecutive cell updates are cheap to apply together: send them as one
+    // batch (single DB transaction) instead of counting each against the frame limit
+    let (cat, sheet_opt) = state.current_sheet_context();
+    let mut cell_edits: Vec<crate::sheets::events::CellEdit> = Vec::new();
+    while matches!(
+        state.ai_throttled_apply_queue.front(),
+        Some(ThrottledAiAction::UpdateCell { .. })
+    ) {
+        if let Some(ThrottledAiAction::UpdateCell {
+            row_index,
+            col_index,
+            value,
+        }) = state.ai_throttled_apply_queue.pop_front()
+        {
+            cell_edits.push(crate::sheets::events::CellEdit {
+                row_index,
+                col_index,
+                new_value: value,
+            });
+        }
+    }
+    if !cell_edits.is_empty() {
+        if let Some(sheet) = sheet_opt.clone() {
+            cell_batch_writer.write(crate::sheets::events::UpdateCellsBatchEvent {
+                category: cat.clone(),
+                sheet_name: sheet,
+                edits: cell_edits,
+                report_result: false,
+            });
+        }
+        operations_processed += 1;
+    }
+    
     while let Some(action) = state.ai_throttled_apply_queue.pop_front() {
         let (cat, sheet_opt) = state.current_sheet_context();
         if let Some(sheet) = sheet_opt.clone() {
@@ -35,13 +69,13 @@ pub fn apply_throttled_ai_changes(
                     col_index,
                     value,
                 } => {
-                    cell_update_writer.write(crate::sheets::events::UpdateCellEvent {
-                        category: cat.clone(),
-                        sheet_name: sheet,
+                    // Updates queued behind an AddRow wait for the next frame's batch
+                    state.ai_throttled_apply_queue.push_front(ThrottledAiAction::UpdateCell {
                         row_index,
                         col_index,
-                        new_value: value,
+                        value,
                     });
+                    break;
                 }
                 ThrottledAiAction::AddRow { initial_values } => {
                     add_row_writer.write(crate::sheets::events::AddSheetRowRequest {
@@ -52,6 +86,7 @@ pub fn apply_throttled_ai_changes(
                         } else {
                             Some(initial_values)
                         },
+                        at_end: false,
                     });
                 }
             }
@@ -0,0 +1,129 @@
+// src/sheets/systems/ai/usage_ledger.rs
+//! Approximate per-sheet AI usage (requests sent, rows processed, rows added),
+//! kept in a JSON sidecar next to the UI prefs so it survives across sessions.
+
+use bevy::log::{error, warn};
+use directories_next::ProjectDirs;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fs, io, path::PathBuf};
+
+const QUALIFIER: &str = "com";
+const ORGANIZATION: &str = "BevyAppOrg";
+const APPLICATION: &str = "BevySpreadsheetEditor";
+const LEDGER_FILE: &str = "ai_usage.json";
+
+/// Running AI usage totals for one sheet
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct AiSheetUsage {
+    #[serde(default)]
+    pub requests: u64,
+    #[serde(default)]
+    pub rows_processed: u64,
+    #[serde(default)]
+    pub rows_added: u64,
+}
+
+/// Usage totals keyed by "category/sheet" (root sheets use an empty category)
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct AiUsageLedger {
+    #[serde(default)]
+    pub sheets: BTreeMap<String, AiSheetUsage>,
+}
+
+fn ledger_key(category: &Option<String>, sheet_name: &str) -> String {
+    format!("{}/{}", category.as_deref().unwrap_or(""), sheet_name)
+}
+
+impl AiUsageLedger {
+    /// Totals for a sheet (zero when it never used AI)
+    pub fn usage(&self, category: &Option<String>, sheet_name: &str) -> AiSheetUsage {
+        self.sheets
+            .get(&ledger_key(category, sheet_name))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Count one AI request and the rows it produced
+    pub fn record(
+        &mut self,
+        category: &Option<String>,
+        sheet_name: &str,
+        rows_processed: usize,
+        rows_added: usize,
+    ) {
+        let entry = self
+            .sheets
+            .entry(ledger_key(category, sheet_name))
+            .or_default();
+        entry.requests += 1;
+        entry.rows_processed += rows_processed as u64;
+        entry.rows_added += rows_added as u64;
+    }
+
+    /// Forget the totals for a sheet
+    pub fn reset(&mut self, category: &Option<String>, sheet_name: &str) {
+        self.sheets.remove(&ledger_key(category, sheet_name));
+    }
+}
+
+fn get_ledger_path() -> io::Result<PathBuf> {
+    if let Some(proj) = ProjectDirs::from(QUALIFIER, ORGANIZATION, APPLICATION) {
+        let cfg_dir = proj.config_dir();
+        fs::create_dir_all(cfg_dir)?;
+        Ok(cfg_dir.join(LEDGER_FILE))
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "No project dirs for AI usage ledger",
+        ))
+    }
+}
+
+/// Load the ledger; a missing or unreadable file starts from zero
+pub fn load_usage_ledger() -> AiUsageLedger {
+    match get_ledger_path().and_then(fs::File::open) {
+        Ok(file) => serde_json::from_reader(file).unwrap_or_else(|e| {
+            error!("Failed to parse AI usage ledger: {}. Starting from zero.", e);
+            AiUsageLedger::default()
+        }),
+        Err(e) => {
+            warn!("AI usage ledger not found or unreadable ({}). Starting from zero.", e);
+            AiUsageLedger::default()
+        }
+    }
+}
+
+pub fn save_usage_ledger(ledger: &AiUsageLedger) {
+    match get_ledger_path().and_then(fs::File::create) {
+        Ok(file) => {
+            if let Err(e) = serde_json::to_writer_pretty(file, ledger) {
+                error!("Failed to save AI usage ledger: {}", e);
+            }
+        }
+        Err(e) => error!("Failed to open AI usage ledger for writing: {}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_accumulates_per_sheet_and_reset_clears() {
+        let mut ledger = AiUsageLedger::default();
+        let cat = Some("Game".to_string());
+        ledger.record(&cat, "Items", 10, 2);
+        ledger.record(&cat, "Items", 5, 0);
+        ledger.record(&None, "Items", 1, 1);
+
+        assert_eq!(
+            ledger.usage(&cat, "Items"),
+            AiSheetUsage { requests: 2, rows_processed: 15, rows_added: 2 }
+        );
+        assert_eq!(ledger.usage(&None, "Items").requests, 1);
+
+        ledger.reset(&cat, "Items");
+        assert_eq!(ledger.usage(&cat, "Items"), AiSheetUsage::default());
+        assert_eq!(ledger.usage(&None, "Items").requests, 1);
+    }
+}
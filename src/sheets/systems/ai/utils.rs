@@ -232,8 +232,8 @@ pub fn extract_structure_settings(
     registry: Option<&crate::sheets::resources::SheetRegistry>,
     category: &Option<String>,
 ) -> (Option<usize>, bool) {
-    // Default fallback: use parent sheet's setting
-    let mut sheet_allow_add_rows = root_meta.ai_enable_row_generation;
+    // Default fallback: use parent sheet's setting (its active schema group overrides it)
+    let mut sheet_allow_add_rows = root_meta.effective_ai_allow_add_rows();
 
     if let Some(&first_path_idx) = structure_path.first() {
         if let Some(first_col) = root_meta.columns.get(first_path_idx) {
@@ -245,7 +245,7 @@ pub fn extract_structure_settings(
                 
                 if let Some(child_sheet) = reg.get_sheet(category, &child_table_name) {
                     if let Some(child_meta) = &child_sheet.metadata {
-                        sheet_allow_add_rows = child_meta.ai_enable_row_generation;
+                        sheet_allow_add_rows = child_meta.effective_ai_allow_add_rows();
                         bevy::log::info!(
                             "Using child table '{}' sheet-level ai_enable_row_generation={} for structure calls",
                             child_table_name, sheet_allow_add_rows
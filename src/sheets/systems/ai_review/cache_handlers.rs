@@ -15,6 +15,8 @@ pub fn cancel_batch(state: &mut EditorWindowState, session: Option<&mut Director
     state.ai_mode = AiModeState::Idle;
     state.ai_row_reviews.clear();
     state.ai_new_row_reviews.clear();
+    state.ai_malformed_row_notes.clear();
+    state.ai_review_tally = Default::default();
     state.ai_selected_rows.clear();
     state.ai_structure_detail_context = None;
     // Clear batch processing context
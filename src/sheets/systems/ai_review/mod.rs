@@ -5,6 +5,7 @@ pub mod cache_handlers;
 pub mod child_table_loader;
 pub mod display_context;
 pub mod review_logic;
+pub mod review_summary;
 pub mod structure_persistence;
 
 pub use row_plans_impl::*;
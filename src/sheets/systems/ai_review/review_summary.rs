@@ -0,0 +1,166 @@
+// src/sheets/systems/ai_review/review_summary.rs
+//! Live tallies for the AI batch review summary bar. Decided rows leave the review lists,
+//! so accepted/rejected row counts come from `ReviewDecisionTally`, which the accept and
+//! decline handlers bump; everything else is counted from what is still on screen.
+
+use crate::ui::elements::editor::state::{
+    EditorWindowState, NewRowReview, ReviewDecisionTally, RowReview, StructureReviewEntry,
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReviewSummary {
+    /// Existing rows still under review where the AI suggested a different value
+    pub changed: usize,
+    /// Existing rows still under review that the AI left as they were
+    pub unchanged: usize,
+    pub accepted: usize,
+    pub rejected: usize,
+    /// Existing and new rows still waiting for accept/decline
+    pub undecided: usize,
+    /// New rows not matching an existing row, waiting to be added or dropped
+    pub new_rows_pending: usize,
+    /// New rows matching an existing row whose merge/keep-separate choice is still open
+    pub merges_pending: usize,
+    pub structures_accepted: usize,
+    pub structures_rejected: usize,
+    pub structures_undecided: usize,
+}
+
+/// True when the AI changed any of the row's cells or one of its structures
+pub fn row_review_has_changes(review: &RowReview, structures: &[StructureReviewEntry]) -> bool {
+    let cells_changed = review
+        .ai
+        .iter()
+        .enumerate()
+        .any(|(pos, ai)| review.original.get(pos).map(String::as_str).unwrap_or("") != ai);
+    cells_changed
+        || structures.iter().any(|entry| {
+            entry.parent_new_row_index.is_none()
+                && entry.parent_row_index == review.row_index
+                && entry.has_changes
+        })
+}
+
+pub fn summarize_review(
+    row_reviews: &[RowReview],
+    new_row_reviews: &[NewRowReview],
+    structure_reviews: &[StructureReviewEntry],
+    tally: ReviewDecisionTally,
+) -> ReviewSummary {
+    let changed = row_reviews
+        .iter()
+        .filter(|rr| row_review_has_changes(rr, structure_reviews))
+        .count();
+    let merges_pending = new_row_reviews
+        .iter()
+        .filter(|nr| nr.duplicate_match_row.is_some() && !nr.merge_decided)
+        .count();
+    ReviewSummary {
+        changed,
+        unchanged: row_reviews.len() - changed,
+        accepted: tally.accepted,
+        rejected: tally.rejected,
+        undecided: row_reviews.len() + new_row_reviews.len(),
+        new_rows_pending: new_row_reviews
+            .iter()
+            .filter(|nr| nr.duplicate_match_row.is_none())
+            .count(),
+        merges_pending,
+        structures_accepted: structure_reviews.iter().filter(|e| e.decided && e.accepted).count(),
+        structures_rejected: structure_reviews.iter().filter(|e| e.decided && e.rejected).count(),
+        structures_undecided: structure_reviews.iter().filter(|e| e.is_undecided()).count(),
+    }
+}
+
+/// Summary of the review currently shown in the editor
+pub fn summarize_state(state: &EditorWindowState) -> ReviewSummary {
+    summarize_review(
+        &state.ai_row_reviews,
+        &state.ai_new_row_reviews,
+        &state.ai_structure_reviews,
+        state.ai_review_tally,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn row(row_index: usize, original: &[&str], ai: &[&str]) -> RowReview {
+        RowReview {
+            row_index,
+            original: original.iter().map(|s| s.to_string()).collect(),
+            ai: ai.iter().map(|s| s.to_string()).collect(),
+            choices: Vec::new(),
+            non_structure_columns: (0..ai.len()).collect(),
+            key_overrides: HashMap::new(),
+            ancestor_key_values: Vec::new(),
+            ancestor_dropdown_cache: HashMap::new(),
+            is_orphan: false,
+        }
+    }
+
+    fn new_row(duplicate_match_row: Option<usize>, merge_decided: bool) -> NewRowReview {
+        NewRowReview {
+            ai: vec!["x".to_string()],
+            non_structure_columns: vec![0],
+            duplicate_match_row,
+            choices: None,
+            merge_selected: false,
+            merge_decided,
+            original_for_merge: None,
+            key_overrides: HashMap::new(),
+            ancestor_key_values: Vec::new(),
+            ancestor_dropdown_cache: HashMap::new(),
+            projected_row_index: 0,
+            is_orphan: false,
+        }
+    }
+
+    fn structure(parent_row_index: usize, has_changes: bool, decided: bool) -> StructureReviewEntry {
+        StructureReviewEntry {
+            root_category: None,
+            root_sheet: "Items".to_string(),
+            parent_row_index,
+            parent_new_row_index: None,
+            structure_path: vec![2],
+            has_changes,
+            accepted: decided,
+            rejected: false,
+            decided,
+            original_rows: Vec::new(),
+            ai_rows: Vec::new(),
+            merged_rows: Vec::new(),
+            differences: Vec::new(),
+            schema_headers: Vec::new(),
+            original_rows_count: 0,
+            orphaned_ai_rows: Vec::new(),
+            orphaned_claimed_ancestries: Vec::new(),
+            orphaned_decided: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn summary_counts_changes_decisions_and_pending_merges() {
+        let rows = vec![
+            row(1, &["a", "b"], &["a", "c"]),
+            row(2, &["a", "b"], &["a", "b"]),
+            row(3, &["a"], &["a"]),
+        ];
+        let new_rows = vec![new_row(None, false), new_row(Some(1), false), new_row(Some(2), true)];
+        // Row 3 only differs inside a structure; the other structure is already accepted
+        let structures = vec![structure(3, true, false), structure(9, true, true)];
+        let tally = ReviewDecisionTally { accepted: 4, rejected: 1 };
+
+        let summary = summarize_review(&rows, &new_rows, &structures, tally);
+        assert_eq!(summary.changed, 2);
+        assert_eq!(summary.unchanged, 1);
+        assert_eq!((summary.accepted, summary.rejected, summary.undecided), (4, 1, 6));
+        assert_eq!(summary.new_rows_pending, 1);
+        assert_eq!(summary.merges_pending, 1);
+        assert_eq!(summary.structures_accepted, 1);
+        assert_eq!(summary.structures_undecided, 1);
+        assert!(!row_review_has_changes(&rows[1], &structures));
+    }
+}
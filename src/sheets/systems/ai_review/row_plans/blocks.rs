@@ -1,5 +1,6 @@
 use std::collections::HashSet;
 
+use crate::sheets::systems::ai_review::review_summary::row_review_has_changes;
 use crate::ui::elements::editor::state::EditorWindowState;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -33,6 +34,11 @@ pub fn build_blocks(state: &EditorWindowState) -> (Vec<RowBlock>, HashSet<usize>
     };
 
     for i in 0..state.ai_row_reviews.len() {
+        if state.ai_review_only_changed
+            && !row_review_has_changes(&state.ai_row_reviews[i], &state.ai_structure_reviews)
+        {
+            continue;
+        }
         push_group(vec![
             RowBlock::OriginalPreview(i, RowKind::Existing),
             RowBlock::AiSuggested(i, RowKind::Existing),
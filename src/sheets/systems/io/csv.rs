@@ -0,0 +1,152 @@
+// src/sheets/systems/io/csv.rs
+//! RFC 4180 CSV encoding of sheet grids (CRLF line endings, fields quoted only when needed),
+//! and a tolerant reader for importing CSV/TSV exports.
+
+/// Quote a field when it contains a comma, quote or line break; inner quotes are doubled
+pub fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Append one record (terminated by CRLF) to `out`
+pub fn push_csv_record<S: AsRef<str>>(out: &mut String, fields: &[S]) {
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        out.push_str(&escape_csv_field(field.as_ref()));
+    }
+    out.push_str("\r\n");
+}
+
+/// Guess the delimiter from the first line: the most frequent of comma, tab and semicolon
+/// outside quotes (comma when none occurs)
+pub fn sniff_csv_delimiter(text: &str) -> char {
+    let mut counts = [(',', 0usize), ('\t', 0), (';', 0)];
+    let mut in_quotes = false;
+    for ch in text.chars() {
+        match ch {
+            '"' => in_quotes = !in_quotes,
+            '\n' | '\r' if !in_quotes => break,
+            _ if !in_quotes => {
+                if let Some(entry) = counts.iter_mut().find(|(d, _)| *d == ch) {
+                    entry.1 += 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    // Ties go to the earlier entry (comma first)
+    counts
+        .iter()
+        .rev()
+        .filter(|(_, n)| *n > 0)
+        .max_by_key(|(_, n)| *n)
+        .map_or(',', |(d, _)| *d)
+}
+
+/// Parse CSV text into records. Quoted fields may contain the delimiter, doubled quotes and
+/// line breaks; CRLF and LF line endings are accepted and blank lines are skipped.
+pub fn parse_csv(text: &str, delimiter: char) -> Vec<Vec<String>> {
+    let text = text.trim_start_matches('\u{FEFF}');
+    let mut records = Vec::new();
+    let mut record: Vec<String> = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+
+    let mut end_record = |record: &mut Vec<String>, field: &mut String| {
+        record.push(std::mem::take(field));
+        let blank = record.len() == 1 && record[0].is_empty();
+        let done = std::mem::take(record);
+        if !blank {
+            records.push(done);
+        }
+    };
+
+    while let Some(ch) = chars.next() {
+        if in_quotes {
+            if ch == '"' {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    field.push('"');
+                } else {
+                    in_quotes = false;
+                }
+            } else {
+                field.push(ch);
+            }
+        } else if ch == '"' && field.is_empty() {
+            in_quotes = true;
+        } else if ch == delimiter {
+            record.push(std::mem::take(&mut field));
+        } else if ch == '\r' {
+            if chars.peek() == Some(&'\n') {
+                chars.next();
+            }
+            end_record(&mut record, &mut field);
+        } else if ch == '\n' {
+            end_record(&mut record, &mut field);
+        } else {
+            field.push(ch);
+        }
+    }
+    if !field.is_empty() || !record.is_empty() {
+        end_record(&mut record, &mut field);
+    }
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sniffs_delimiter_from_first_line() {
+        assert_eq!(sniff_csv_delimiter("a;b;c\n1,5;2;3"), ';');
+        assert_eq!(sniff_csv_delimiter("a\tb\n"), '\t');
+        assert_eq!(sniff_csv_delimiter("\"x;y\",b\n"), ',');
+        assert_eq!(sniff_csv_delimiter("single"), ',');
+    }
+
+    #[test]
+    fn parses_quoted_fields_and_line_endings() {
+        let text = "\u{FEFF}Name,Note\r\nSword,\"sharp, \"\"heavy\"\"\"\r\n\nBow,\"two\nlines\"\nShield,";
+        let records = parse_csv(text, ',');
+        assert_eq!(
+            records,
+            vec![
+                vec!["Name".to_string(), "Note".to_string()],
+                vec!["Sword".to_string(), "sharp, \"heavy\"".to_string()],
+                vec!["Bow".to_string(), "two\nlines".to_string()],
+                vec!["Shield".to_string(), String::new()],
+            ]
+        );
+    }
+
+    #[test]
+    fn encoded_records_parse_back() {
+        let mut out = String::new();
+        push_csv_record(&mut out, &["a,b", "c\"d", "e\nf"]);
+        assert_eq!(parse_csv(&out, ','), vec![vec!["a,b", "c\"d", "e\nf"]]);
+    }
+
+    #[test]
+    fn fields_are_quoted_only_when_needed() {
+        assert_eq!(escape_csv_field("plain"), "plain");
+        assert_eq!(escape_csv_field("a,b"), "\"a,b\"");
+        assert_eq!(escape_csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(escape_csv_field("two\nlines"), "\"two\nlines\"");
+    }
+
+    #[test]
+    fn records_end_with_crlf() {
+        let mut out = String::new();
+        push_csv_record(&mut out, &["Name", "Note"]);
+        push_csv_record(&mut out, &["Sword".to_string(), "sharp, heavy".to_string()]);
+        assert_eq!(out, "Name,Note\r\nSword,\"sharp, heavy\"\r\n");
+    }
+}
@@ -0,0 +1,299 @@
+// src/sheets/systems/io/data_dir.rs
+//! Location of the data folder (category databases, JSON sheets, the daemon).
+//!
+//! Defaults to `Documents/SkylineDB`; Settings can point it elsewhere, e.g. when Documents
+//! is redirected to slow network storage. The daemon is started with the folder, so a new
+//! location is only used from the next start. Moving the old contents also happens then,
+//! before the daemon runs and while no database file is open.
+
+use bevy::log::{error, info, warn};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+use super::DEFAULT_DATA_DIR;
+
+/// Folder chosen in Settings; `None` = `Documents/SkylineDB`
+static CONFIGURED_DATA_DIR: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// File written and removed again to check that a folder accepts new files
+const WRITE_PROBE_FILE: &str = ".skylinedb_write_test";
+
+/// Use `path` as the data folder (`None` = default). Call once at startup, before the daemon
+/// or any database is touched.
+pub fn set_configured_data_dir(path: Option<PathBuf>) {
+    *CONFIGURED_DATA_DIR.write().unwrap_or_else(|e| e.into_inner()) = path;
+}
+
+pub fn configured_data_dir() -> Option<PathBuf> {
+    CONFIGURED_DATA_DIR
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+}
+
+/// `Documents/SkylineDB`, or `./SkylineDB` when there is no Documents folder
+pub fn default_data_dir() -> PathBuf {
+    directories_next::UserDirs::new()
+        .and_then(|dirs| dirs.document_dir().map(|p| p.to_path_buf()))
+        .unwrap_or_else(|| {
+            error!(
+                "Failed to get Documents directory, using current working directory '.' instead."
+            );
+            PathBuf::from(".")
+        })
+        .join(DEFAULT_DATA_DIR)
+}
+
+/// Check that `path` can serve as the data folder: absolute, creatable and writable
+pub fn validate_data_dir(path: &Path) -> Result<(), String> {
+    if !path.is_absolute() {
+        return Err(format!("'{}' is not an absolute path.", path.display()));
+    }
+    if path.is_file() {
+        return Err(format!("'{}' is a file, not a folder.", path.display()));
+    }
+    fs::create_dir_all(path).map_err(|e| format!("Cannot create '{}': {}", path.display(), e))?;
+    let probe = path.join(WRITE_PROBE_FILE);
+    fs::write(&probe, b"ok").map_err(|e| format!("'{}' is not writable: {}", path.display(), e))?;
+    let _ = fs::remove_file(&probe);
+    Ok(())
+}
+
+/// Move everything in `from` into `to` and return how many entries were moved.
+/// Nothing is moved when an entry of the same name already exists in `to`.
+pub fn move_data_dir_contents(from: &Path, to: &Path) -> Result<usize, String> {
+    if !from.is_dir() {
+        return Ok(0);
+    }
+    if same_dir(from, to) {
+        return Ok(0);
+    }
+    if to.starts_with(from) {
+        return Err(format!(
+            "'{}' is inside '{}'; choose a folder outside the current one.",
+            to.display(),
+            from.display()
+        ));
+    }
+    fs::create_dir_all(to).map_err(|e| format!("Cannot create '{}': {}", to.display(), e))?;
+
+    let entries: Vec<PathBuf> = fs::read_dir(from)
+        .map_err(|e| format!("Cannot read '{}': {}", from.display(), e))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .collect();
+    let conflicts: Vec<String> = entries
+        .iter()
+        .filter_map(|src| src.file_name())
+        .filter(|name| to.join(name).exists())
+        .map(|name| name.to_string_lossy().into_owned())
+        .collect();
+    if !conflicts.is_empty() {
+        return Err(format!(
+            "'{}' already contains: {}",
+            to.display(),
+            conflicts.join(", ")
+        ));
+    }
+
+    // Every entry lands in `to` before any source is deleted, so a failure part way leaves
+    // the old folder complete and the app can keep using it.
+    let mut placed: Vec<PlacedEntry> = Vec::with_capacity(entries.len());
+    for src in &entries {
+        let Some(name) = src.file_name() else {
+            continue;
+        };
+        let dst = to.join(name);
+        match place_entry(src, &dst) {
+            Ok(renamed) => placed.push(PlacedEntry {
+                src: src.clone(),
+                dst,
+                renamed,
+            }),
+            Err(e) => {
+                let _ = remove_entry(&dst);
+                undo_placed(&placed);
+                return Err(format!(
+                    "Failed to move '{}' to '{}': {}. Nothing was moved.",
+                    src.display(),
+                    dst.display(),
+                    e
+                ));
+            }
+        }
+    }
+    for entry in placed.iter().filter(|p| !p.renamed) {
+        if let Err(e) = remove_entry(&entry.src) {
+            warn!(
+                "Copied '{}' to '{}' but could not remove the original: {}",
+                entry.src.display(),
+                entry.dst.display(),
+                e
+            );
+        }
+    }
+    info!(
+        "Moved {} entries from '{}' to '{}'",
+        placed.len(),
+        from.display(),
+        to.display()
+    );
+    Ok(placed.len())
+}
+
+/// An entry already present in the new folder; `renamed` = the source is gone,
+/// otherwise it was copied and the source is still in place
+struct PlacedEntry {
+    src: PathBuf,
+    dst: PathBuf,
+    renamed: bool,
+}
+
+/// Rename, or copy when the target is on another drive. Returns whether it was renamed.
+fn place_entry(src: &Path, dst: &Path) -> io::Result<bool> {
+    if fs::rename(src, dst).is_ok() {
+        return Ok(true);
+    }
+    copy_recursive(src, dst)?;
+    Ok(false)
+}
+
+/// Put renamed entries back and drop the copies, restoring the old folder
+fn undo_placed(placed: &[PlacedEntry]) {
+    for entry in placed.iter().rev() {
+        let result = if entry.renamed {
+            fs::rename(&entry.dst, &entry.src)
+                .or_else(|_| copy_recursive(&entry.dst, &entry.src))
+                .and_then(|_| remove_entry(&entry.dst))
+        } else {
+            remove_entry(&entry.dst)
+        };
+        if let Err(e) = result {
+            error!(
+                "Could not restore '{}' from '{}': {}",
+                entry.src.display(),
+                entry.dst.display(),
+                e
+            );
+        }
+    }
+}
+
+fn same_dir(a: &Path, b: &Path) -> bool {
+    match (fs::canonicalize(a), fs::canonicalize(b)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+fn remove_entry(path: &Path) -> io::Result<()> {
+    match fs::symlink_metadata(path) {
+        Ok(meta) if meta.is_dir() => fs::remove_dir_all(path),
+        Ok(_) => fs::remove_file(path),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+fn copy_recursive(src: &Path, dst: &Path) -> io::Result<()> {
+    if src.is_dir() {
+        fs::create_dir_all(dst)?;
+        for entry in fs::read_dir(src)? {
+            let entry = entry?;
+            copy_recursive(&entry.path(), &dst.join(entry.file_name()))?;
+        }
+        Ok(())
+    } else {
+        fs::copy(src, dst).map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(label: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("skylinedb_data_dir_{}_{}", label, uuid::Uuid::new_v4()))
+    }
+
+    #[test]
+    fn validation_requires_a_writable_absolute_folder() {
+        let dir = temp_dir("validate");
+        assert!(validate_data_dir(&dir).is_ok());
+        assert!(dir.is_dir());
+        assert!(!dir.join(WRITE_PROBE_FILE).exists());
+
+        assert!(validate_data_dir(Path::new("relative/SkylineDB")).is_err());
+        let file = dir.join("Game.db");
+        fs::write(&file, b"").unwrap();
+        assert!(validate_data_dir(&file).is_err());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn contents_move_unless_names_collide() {
+        let from = temp_dir("from");
+        let to = temp_dir("to");
+        fs::create_dir_all(from.join("Game")).unwrap();
+        fs::write(from.join("Game.db"), b"db").unwrap();
+        fs::write(from.join("Game").join("Items.json"), b"[]").unwrap();
+
+        assert_eq!(move_data_dir_contents(&from, &to).unwrap(), 2);
+        assert_eq!(fs::read(to.join("Game.db")).unwrap(), b"db");
+        assert!(to.join("Game").join("Items.json").exists());
+        assert_eq!(fs::read_dir(&from).unwrap().count(), 0);
+
+        // A second source with a clashing name leaves both folders untouched
+        fs::write(from.join("Game.db"), b"other").unwrap();
+        fs::write(from.join("Extra.db"), b"x").unwrap();
+        assert!(move_data_dir_contents(&from, &to).is_err());
+        assert!(from.join("Extra.db").exists());
+        assert!(!to.join("Extra.db").exists());
+
+        // Moving into a subfolder of the source is refused
+        assert!(move_data_dir_contents(&from, &from.join("nested")).is_err());
+
+        let _ = fs::remove_dir_all(&from);
+        let _ = fs::remove_dir_all(&to);
+    }
+
+    #[test]
+    fn undo_restores_renamed_entries_and_drops_copies() {
+        let from = temp_dir("undo_from");
+        let to = temp_dir("undo_to");
+        fs::create_dir_all(&from).unwrap();
+        fs::create_dir_all(to.join("Game")).unwrap();
+        fs::write(to.join("Game.db"), b"db").unwrap();
+        fs::write(to.join("Game").join("Items.json"), b"[]").unwrap();
+        fs::write(from.join("Copied.db"), b"c").unwrap();
+        fs::write(to.join("Copied.db"), b"c").unwrap();
+
+        undo_placed(&[
+            PlacedEntry {
+                src: from.join("Game.db"),
+                dst: to.join("Game.db"),
+                renamed: true,
+            },
+            PlacedEntry {
+                src: from.join("Game"),
+                dst: to.join("Game"),
+                renamed: true,
+            },
+            PlacedEntry {
+                src: from.join("Copied.db"),
+                dst: to.join("Copied.db"),
+                renamed: false,
+            },
+        ]);
+
+        assert_eq!(fs::read(from.join("Game.db")).unwrap(), b"db");
+        assert!(from.join("Game").join("Items.json").exists());
+        assert_eq!(fs::read(from.join("Copied.db")).unwrap(), b"c");
+        assert_eq!(fs::read_dir(&to).unwrap().count(), 0);
+
+        let _ = fs::remove_dir_all(&from);
+        let _ = fs::remove_dir_all(&to);
+    }
+}
@@ -41,6 +41,18 @@ pub fn save_sheet_metadata(
                             &metadata.sheet_name, e
                         );
                     }
+                    if let Err(e) = crate::sheets::database::writer::DbWriter::update_table_color(
+                        &conn,
+                        &metadata.sheet_name,
+                        metadata.color,
+                        db_path.file_name().and_then(|n| n.to_str()),
+                        daemon_client,
+                    ) {
+                        error!(
+                            "Failed to update color in DB for '{}': {}",
+                            &metadata.sheet_name, e
+                        );
+                    }
                 }
                 Err(e) => error!("Failed to open database '{}': {}", db_path.display(), e),
             }
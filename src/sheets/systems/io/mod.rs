@@ -1,35 +1,30 @@
 // src/sheets/systems/io/mod.rs
 
 use crate::sheets::definitions::SheetMetadata;
-use bevy::prelude::{error, trace};
+use bevy::prelude::trace;
 use std::path::{Path, PathBuf};
 
 // --- Submodule Declarations ---
+pub mod csv; // CSV encoding for exports
+pub mod data_dir; // Data folder location (default or chosen in Settings)
 pub mod load; // Runtime uploads
 pub mod lazy_load; // Lazy loading of database tables
 pub mod metadata_persistence;
 pub mod parsers;
 pub mod save;
+pub mod sheet_eviction; // LRU eviction of loaded sheet grids
 pub mod startup;
 pub mod validator; // <-- ADDED new startup submodule
 
 // --- Shared Constants ---
-// Legacy constant kept for reference, but default path now uses Documents/SkylineDB
+// Name of the default data folder under Documents (see `data_dir` for the Settings override)
 pub const DEFAULT_DATA_DIR: &str = "SkylineDB";
 
 // --- Shared Helper Functions ---
-/// Get the default data base path for JSON sheets.
-/// Now uses Documents/SkylineDB to match the database location.
+/// Get the data base path for JSON sheets and databases: the folder chosen in Settings,
+/// else Documents/SkylineDB.
 pub fn get_default_data_base_path() -> PathBuf {
-    let data_path = directories_next::UserDirs::new()
-        .and_then(|dirs| dirs.document_dir().map(|p| p.to_path_buf()))
-        .unwrap_or_else(|| {
-            error!(
-                "Failed to get Documents directory, using current working directory '.' instead."
-            );
-            PathBuf::from(".")
-        })
-        .join(DEFAULT_DATA_DIR);
+    let data_path = data_dir::configured_data_dir().unwrap_or_else(data_dir::default_data_dir);
 
     trace!("Data base path determined as: {:?}", data_path);
     data_path
@@ -0,0 +1,139 @@
+// src/sheets/systems/io/sheet_eviction.rs
+//! LRU eviction of in-memory grid data for DB-backed sheets
+//!
+//! Only the `max_loaded_sheets` most-recently-viewed sheets keep their grid in the
+//! registry. Evicted sheets keep their metadata and are reloaded from the database
+//! by `reload_sheet_cache_from_db` / `load_linked_target_sheets` when needed again.
+//! Cell edits are written to the database as they are applied, so an evicted grid
+//! never holds unsaved data. JSON-backed sheets have no reload source and are never evicted.
+
+use bevy::prelude::*;
+use std::collections::HashSet;
+use crate::sheets::{
+    definitions::ColumnValidator,
+    resources::{LoadedSheetLru, SheetRegistry, SheetRenderCache},
+};
+use crate::ui::elements::editor::state::{AiModeState, EditorWindowState};
+
+type SheetKey = (Option<String>, String);
+
+/// Sheets that must stay loaded: the selected sheet, its linked/structure targets,
+/// sheets on the structure navigation path and the sheet under AI review.
+fn collect_protected_sheets(state: &EditorWindowState, registry: &SheetRegistry) -> HashSet<SheetKey> {
+    let mut protected: HashSet<SheetKey> = HashSet::new();
+    let category = &state.selected_category;
+    if let Some(sheet_name) = &state.selected_sheet_name {
+        protected.insert((category.clone(), sheet_name.clone()));
+        if let Some(metadata) = registry
+            .get_sheet(category, sheet_name)
+            .and_then(|s| s.metadata.as_ref())
+        {
+            for col in &metadata.columns {
+                match &col.validator {
+                    Some(ColumnValidator::Linked { target_sheet_name, .. }) => {
+                        protected.insert((category.clone(), target_sheet_name.clone()));
+                    }
+                    Some(ColumnValidator::Structure) => {
+                        protected.insert((category.clone(), format!("{}_{}", sheet_name, col.header)));
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    for nav in &state.structure_navigation_stack {
+        protected.insert((nav.parent_category.clone(), nav.parent_sheet_name.clone()));
+        protected.insert((nav.parent_category.clone(), nav.structure_sheet_name.clone()));
+    }
+    if !state.ai_current_sheet.is_empty() {
+        protected.insert((state.ai_current_category.clone(), state.ai_current_sheet.clone()));
+    }
+    protected
+}
+
+/// Drops grid data of the least-recently-viewed DB-backed sheets beyond the configured limit.
+/// Runs when the selected sheet changes (after it finished loading).
+pub fn evict_least_recently_used_sheets(
+    mut state: ResMut<EditorWindowState>,
+    mut registry: ResMut<SheetRegistry>,
+    mut lru: ResMut<LoadedSheetLru>,
+    mut render_cache: ResMut<SheetRenderCache>,
+) {
+    if state.sheet_is_loading {
+        return;
+    }
+    let Some(sheet_name) = state.selected_sheet_name.clone() else {
+        return;
+    };
+    if !lru.touch(&state.selected_category, &sheet_name) {
+        return;
+    }
+
+    // Forget sheets that were deleted or renamed since they were viewed
+    let stale: Vec<SheetKey> = lru
+        .iter()
+        .filter(|(c, s)| registry.get_sheet(c, s).is_none())
+        .cloned()
+        .collect();
+    for (c, s) in &stale {
+        lru.forget(c, s);
+    }
+
+    let limit = state.max_loaded_sheets;
+    if limit == 0 {
+        return;
+    }
+    // AI processing and review read other sheets' grids (structure children, parents)
+    if state.ai_mode != AiModeState::Idle
+        || state.ai_batch_review_active
+        || !state.ai_throttled_apply_queue.is_empty()
+        || !state.ai_throttled_batch_add_queue.is_empty()
+    {
+        return;
+    }
+
+    let protected = collect_protected_sheets(&state, &registry);
+    let mut protected_loaded = 0usize;
+    let mut candidates: Vec<(usize, SheetKey)> = Vec::new();
+    for (category, name, sheet) in registry.iter_sheets() {
+        let is_db_backed = sheet
+            .metadata
+            .as_ref()
+            .map_or(false, |m| m.category.is_some());
+        if !is_db_backed || sheet.grid.is_empty() {
+            continue;
+        }
+        let key = (category.clone(), name.clone());
+        if protected.contains(&key) {
+            protected_loaded += 1;
+        } else {
+            candidates.push((lru.rank(category, name), key));
+        }
+    }
+
+    let keep = limit.saturating_sub(protected_loaded);
+    if candidates.len() <= keep {
+        return;
+    }
+    candidates.sort_by_key(|(rank, _)| *rank);
+
+    let mut evicted = 0usize;
+    for (_, (category, name)) in candidates.into_iter().skip(keep) {
+        if let Some(sheet) = registry.get_sheet_mut(&category, &name) {
+            sheet.grid = Vec::new();
+            sheet.row_indices = Vec::new();
+            render_cache.clear_sheet_render_data(&category, &name);
+            state
+                .filtered_row_indices_cache
+                .remove(&(category.clone(), name.clone()));
+            debug!("Evicted grid data for sheet '{:?}/{}'.", category, name);
+            evicted += 1;
+        }
+    }
+    if evicted > 0 {
+        info!(
+            "Evicted grid data for {} least-recently-used sheet(s) (limit: {} loaded).",
+            evicted, limit
+        );
+    }
+}
@@ -39,6 +39,7 @@ pub fn scan_and_load_database_files(
         if path
             .extension()
             .map_or(false, |ext| ext.eq_ignore_ascii_case("db"))
+            && !crate::cli::is_backup_file(path)
         {
             db_files.push(path.to_path_buf());
         }
@@ -122,6 +123,10 @@ fn register_database_as_category(
 
             // Just create the category, don't load tables yet
             let _ = registry.create_category(db_name.clone());
+            match crate::sheets::database::reader::DbReader::read_database_color(&conn) {
+                Ok(color) => registry.set_category_color(&db_name, color),
+                Err(e) => warn!("Startup DB Scan: Failed to read color of '{}': {}", db_name, e),
+            }
             info!(
                 "Startup DB Scan: Database '{}' registered as empty category (tables will load on demand)",
                 db_name
@@ -232,6 +237,7 @@ fn get_table_names(
              WHERE type='table' 
              AND name NOT LIKE 'sqlite_%'
              AND name NOT LIKE '%_Metadata'
+             AND name != '_DatabaseSettings'
              ORDER BY name",
         ) {
             Ok(mut stmt) => match stmt.query_map([], |row| row.get(0)) {
@@ -276,6 +282,9 @@ fn register_table_stub(
     
     // If hidden is explicitly set in DB, use that; otherwise default based on is_structure
     let hidden = hidden_in_db.map(|v| v != 0).unwrap_or(is_structure);
+    let color = crate::sheets::database::reader::queries::read_table_color(conn, table_name)
+        .as_deref()
+        .and_then(crate::sheets::database::schema::parse_hex_color);
     
     // Create a minimal SheetGridData with just metadata, no actual data
     let metadata = crate::sheets::definitions::SheetMetadata {
@@ -293,6 +302,8 @@ fn register_table_stub(
         ai_active_schema_group: None,
         random_picker: None,
         structure_parent: None,
+        ai_key_column: None,
+        color,
     };
 
     let sheet_data = crate::sheets::definitions::SheetGridData {
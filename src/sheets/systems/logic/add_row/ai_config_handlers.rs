@@ -1,17 +1,20 @@
 // src/sheets/systems/logic/add_row_handlers/ai_config_handlers.rs
-// AI Schema Group configuration handlers (create, rename, delete, select)
+// AI Schema Group configuration handlers (create, rename, reorder, delete, select, allow rows,
+// structure skips)
 
 use crate::sheets::{
+    database::daemon_resource::SharedDaemonClient,
     definitions::AiSchemaGroup,
     events::{
-        RequestCreateAiSchemaGroup, RequestDeleteAiSchemaGroup, RequestRenameAiSchemaGroup,
-        RequestSelectAiSchemaGroup, SheetDataModifiedInRegistryEvent, SheetOperationFeedback,
+        ReorderAiSchemaGroupEvent, RequestCreateAiSchemaGroup, RequestDeleteAiSchemaGroup,
+        RequestRenameAiSchemaGroup, RequestSelectAiSchemaGroup, RequestSetAiSchemaGroupAllowRows,
+        RequestSetAiStructureSkip, SheetDataModifiedInRegistryEvent, SheetOperationFeedback,
     },
     resources::SheetRegistry,
 };
 use bevy::prelude::*;
 
-use super::json_persistence::save_to_json;
+use super::{db_persistence::update_table_ai_settings_db, json_persistence::save_to_json};
 
 /// Handles AI schema group creation requests
 pub fn handle_create_ai_schema_group(
@@ -187,6 +190,45 @@ pub fn handle_rename_ai_schema_group(
     }
 }
 
+/// Handles AI schema group reorder requests (drag handles in the group panel)
+pub fn handle_reorder_ai_schema_group(
+    mut ev: EventReader<ReorderAiSchemaGroupEvent>,
+    mut registry: ResMut<SheetRegistry>,
+    mut feedback: EventWriter<SheetOperationFeedback>,
+    mut data_modified_writer: EventWriter<SheetDataModifiedInRegistryEvent>,
+) {
+    for e in ev.read() {
+        let Some(meta) = registry
+            .get_sheet_mut(&e.category, &e.sheet_name)
+            .and_then(|sheet| sheet.metadata.as_mut())
+        else {
+            feedback.write(SheetOperationFeedback {
+                message: format!(
+                    "Sheet {:?}/{} not found when reordering AI schema groups",
+                    e.category, e.sheet_name
+                ),
+                is_error: true,
+            });
+            continue;
+        };
+
+        meta.ensure_ai_schema_groups_initialized();
+
+        // Equal or out-of-range indices are a silent no-op (e.g. a drop onto itself)
+        if !meta.reorder_ai_schema_group(e.from_index, e.to_index) {
+            continue;
+        }
+
+        let meta_clone = meta.clone();
+        save_to_json(registry.as_ref(), &meta_clone);
+
+        data_modified_writer.write(SheetDataModifiedInRegistryEvent {
+            category: e.category.clone(),
+            sheet_name: e.sheet_name.clone(),
+        });
+    }
+}
+
 /// Handles AI schema group deletion requests
 pub fn handle_delete_ai_schema_group(
     mut ev: EventReader<RequestDeleteAiSchemaGroup>,
@@ -288,6 +330,7 @@ pub fn handle_select_ai_schema_group(
     mut registry: ResMut<SheetRegistry>,
     mut feedback: EventWriter<SheetOperationFeedback>,
     mut data_modified_writer: EventWriter<SheetDataModifiedInRegistryEvent>,
+    daemon_client: Res<SharedDaemonClient>,
 ) {
     for e in ev.read() {
         let Some(sheet) = registry.get_sheet_mut(&e.category, &e.sheet_name) else {
@@ -314,11 +357,26 @@ pub fn handle_select_ai_schema_group(
 
         meta.ensure_ai_schema_groups_initialized();
 
+        let allow_rows_before = meta.ai_enable_row_generation;
         match meta.apply_ai_schema_group(&e.group_name) {
             Ok(changed) => {
                 let meta_clone = meta.clone();
                 save_to_json(registry.as_ref(), &meta_clone);
-                
+                // The group's "allow add rows" replaces the table-level flag
+                if meta_clone.ai_enable_row_generation != allow_rows_before {
+                    if let Err(err) = update_table_ai_settings_db(
+                        &e.category,
+                        &e.sheet_name,
+                        Some(meta_clone.ai_enable_row_generation),
+                        daemon_client.client(),
+                    ) {
+                        warn!(
+                            "Failed to persist AI row generation of {:?}/{}: {}",
+                            e.category, e.sheet_name, err
+                        );
+                    }
+                }
+
                 if changed {
                     feedback.write(SheetOperationFeedback {
                         message: format!(
@@ -353,3 +411,125 @@ pub fn handle_select_ai_schema_group(
         }
     }
 }
+
+/// Handles per-group "allow add rows" changes. The active group's flag overrides the
+/// table-level one, so changing it also updates (and persists) the table flag.
+pub fn handle_set_ai_schema_group_allow_rows(
+    mut ev: EventReader<RequestSetAiSchemaGroupAllowRows>,
+    mut registry: ResMut<SheetRegistry>,
+    mut feedback: EventWriter<SheetOperationFeedback>,
+    mut data_modified_writer: EventWriter<SheetDataModifiedInRegistryEvent>,
+    daemon_client: Res<SharedDaemonClient>,
+) {
+    for e in ev.read() {
+        let Some(meta) = registry
+            .get_sheet_mut(&e.category, &e.sheet_name)
+            .and_then(|sheet| sheet.metadata.as_mut())
+        else {
+            feedback.write(SheetOperationFeedback {
+                message: format!(
+                    "Sheet {:?}/{} not found when updating AI schema group '{}'",
+                    e.category, e.sheet_name, e.group_name
+                ),
+                is_error: true,
+            });
+            continue;
+        };
+
+        meta.ensure_ai_schema_groups_initialized();
+
+        match meta.set_ai_schema_group_allow_rows(&e.group_name, e.allow) {
+            Ok(changed) => {
+                let is_active = meta.ai_active_schema_group.as_deref() == Some(e.group_name.as_str());
+                if changed {
+                    let meta_clone = meta.clone();
+                    save_to_json(registry.as_ref(), &meta_clone);
+                    if is_active {
+                        if let Err(err) = update_table_ai_settings_db(
+                            &e.category,
+                            &e.sheet_name,
+                            Some(e.allow),
+                            daemon_client.client(),
+                        ) {
+                            warn!(
+                                "Failed to persist AI row generation of {:?}/{}: {}",
+                                e.category, e.sheet_name, err
+                            );
+                        }
+                    }
+                    data_modified_writer.write(SheetDataModifiedInRegistryEvent {
+                        category: e.category.clone(),
+                        sheet_name: e.sheet_name.clone(),
+                    });
+                }
+                feedback.write(SheetOperationFeedback {
+                    message: format!(
+                        "AI row generation {} for schema group '{}' of {:?}/{}",
+                        if e.allow { "ENABLED" } else { "DISABLED" },
+                        e.group_name,
+                        e.category,
+                        e.sheet_name
+                    ),
+                    is_error: false,
+                });
+            }
+            Err(err) => {
+                feedback.write(SheetOperationFeedback {
+                    message: format!(
+                        "Failed to update AI schema group '{}' for {:?}/{}: {}",
+                        e.group_name, e.category, e.sheet_name, err
+                    ),
+                    is_error: true,
+                });
+            }
+        }
+    }
+}
+
+/// Handles skipping a structure table of the active schema group. A skipped table (and
+/// everything below it) is not queued when the Director processes child tables.
+pub fn handle_set_ai_structure_skip(
+    mut ev: EventReader<RequestSetAiStructureSkip>,
+    mut registry: ResMut<SheetRegistry>,
+    mut feedback: EventWriter<SheetOperationFeedback>,
+    mut data_modified_writer: EventWriter<SheetDataModifiedInRegistryEvent>,
+) {
+    for e in ev.read() {
+        let Some(meta) = registry
+            .get_sheet_mut(&e.category, &e.sheet_name)
+            .and_then(|sheet| sheet.metadata.as_mut())
+        else {
+            feedback.write(SheetOperationFeedback {
+                message: format!(
+                    "Sheet {:?}/{} not found when updating AI structure skip",
+                    e.category, e.sheet_name
+                ),
+                is_error: true,
+            });
+            continue;
+        };
+
+        meta.ensure_ai_schema_groups_initialized();
+        let label = meta
+            .describe_structure_path(&e.structure_path)
+            .unwrap_or_else(|| format!("path {:?}", e.structure_path));
+        if !meta.set_active_ai_schema_group_structure_skip(&e.structure_path, e.skip) {
+            continue;
+        }
+
+        let meta_clone = meta.clone();
+        save_to_json(registry.as_ref(), &meta_clone);
+        data_modified_writer.write(SheetDataModifiedInRegistryEvent {
+            category: e.category.clone(),
+            sheet_name: e.sheet_name.clone(),
+        });
+        feedback.write(SheetOperationFeedback {
+            message: format!(
+                "AI processing {} structure '{}'",
+                if e.skip { "SKIPS" } else { "INCLUDES" },
+                label
+            ),
+            is_error: false,
+        });
+    }
+}
@@ -3,7 +3,9 @@
 
 use crate::sheets::{
     events::{AddSheetRowsBatchRequest, SheetDataModifiedInRegistryEvent, SheetOperationFeedback},
-    resources::SheetRegistry,
+    resources::{EditHistory, SheetRegistry},
+    systems::logic::column_defaults::apply_column_defaults,
+    systems::ui_handlers::sheet_handlers::reload_sheet_tree_from_db,
 };
 use crate::ui::elements::editor::state::EditorWindowState;
 use bevy::prelude::*;
@@ -21,6 +23,7 @@ pub fn handle_add_rows_batch_request(
     mut feedback_writer: EventWriter<SheetOperationFeedback>,
     mut data_modified_writer: EventWriter<SheetDataModifiedInRegistryEvent>,
     mut editor_state: Option<ResMut<EditorWindowState>>,
+    mut edit_history: ResMut<EditHistory>,
     daemon_client: Res<crate::sheets::database::daemon_resource::SharedDaemonClient>,
 ) {
     for event in events.read() {
@@ -39,6 +42,8 @@ pub fn handle_add_rows_batch_request(
         let structure_context = get_structure_context(&editor_state, &sheet_name, &category, &registry);
 
         let num_rows = event.rows_initial_values.len();
+        // DB rows added at the end shift other rows' row_index; re-read the sheet afterwards
+        let mut reload_after_append = false;
 
         if let Some(sheet_data) = registry.get_sheet_mut(&category, &sheet_name) {
             if let Some(metadata) = &sheet_data.metadata {
@@ -57,9 +62,13 @@ pub fn handle_add_rows_batch_request(
                         .iter()
                         .any(|c| c.header.eq_ignore_ascii_case("parent_key"));
 
-                // Insert all rows at top (index 0, 1, 2, etc.)
-                for (row_idx, initial_values) in event.rows_initial_values.iter().enumerate() {
-                    sheet_data.grid.insert(row_idx, vec![String::new(); num_cols]);
+                // Insert all rows at top (index 0, 1, 2, etc.) or after the last row, in order
+                let first_row = if event.at_end { sheet_data.grid.len() } else { 0 };
+                for (offset, initial_values) in event.rows_initial_values.iter().enumerate() {
+                    let row_idx = first_row + offset;
+                    let mut new_row = vec![String::new(); num_cols];
+                    apply_column_defaults(&metadata.columns, &mut new_row);
+                    sheet_data.grid.insert(row_idx, new_row);
 
                     // Auto-fill structure sheet columns
                     if is_structure_sheet {
@@ -98,15 +107,19 @@ pub fn handle_add_rows_batch_request(
                     }
                 }
 
-                let msg = format!(
-                    "Added {} new row(s) at the top of sheet '{:?}/{}'.",
-                    num_rows, category, sheet_name
-                );
-                info!("{}", msg);
-                feedback_writer.write(SheetOperationFeedback {
-                    message: msg,
-                    is_error: false,
-                });
+                let position = if event.at_end { "bottom" } else { "top" };
+                // DB-backed sheets report how many rows the database took instead
+                if metadata.category.is_none() {
+                    let msg = format!(
+                        "Added {} new row(s) at the {} of sheet '{:?}/{}'.",
+                        num_rows, position, category, sheet_name
+                    );
+                    info!("{}", msg);
+                    feedback_writer.write(SheetOperationFeedback {
+                        message: msg,
+                        is_error: false,
+                    });
+                }
 
                 // Invalidate cache
                 invalidate_sheet_cache(&mut editor_state, &category, &sheet_name);
@@ -116,16 +129,35 @@ pub fn handle_add_rows_batch_request(
                     if meta.category.is_some() {
                         // DB-backed: batch insert all rows
                         let persist_start = std::time::Instant::now();
-                        match persist_rows_batch_to_db(meta, &sheet_name, &category, &sheet_data.grid, num_rows, daemon_client.client()) {
-                            Ok(_) => {
+                        let mut rows_inserted = 0usize;
+                        let mut on_chunk = |rows_done: usize| {
+                            rows_inserted = rows_done;
+                            info!("Inserted {} of {} rows into '{:?}/{}'", rows_done, num_rows, category, sheet_name);
+                        };
+                        let persist_result = persist_rows_batch_to_db(meta, &sheet_name, &category, &sheet_data.grid[first_row..], num_rows, event.at_end, &mut on_chunk, daemon_client.client());
+                        match persist_result {
+                            Ok(moves) => {
                                 let duration = persist_start.elapsed();
                                 info!("Batch of {} rows persisted to DB in {:?}", num_rows, duration);
+                                feedback_writer.write(SheetOperationFeedback {
+                                    message: format!(
+                                        "Inserted {} of {} row(s) at the {} of '{}'.",
+                                        rows_inserted, num_rows, position, sheet_name
+                                    ),
+                                    is_error: false,
+                                });
+
+                                if event.at_end {
+                                    // Recorded edits follow the rows that moved up
+                                    edit_history.remap_row_indices(&category, &sheet_name, &moves);
+                                    reload_after_append = true;
+                                }
                                 
                                 // For structure sheets, reload row_index values from DB
                                 let is_structure_sheet = meta.columns.len() >= 2
                                     && meta.columns.get(0).map(|c| c.header.eq_ignore_ascii_case("row_index")).unwrap_or(false);
                                 
-                                if is_structure_sheet {
+                                if is_structure_sheet && !event.at_end {
                                     // Query the top N rows from DB to get actual row_index values
                                     if let Some(cat) = &meta.category {
                                         let base_path = crate::sheets::systems::io::get_default_data_base_path();
@@ -159,7 +191,15 @@ pub fn handle_add_rows_batch_request(
                                 }
                             }
                             Err(e) => {
-                                error!("Failed to persist batch rows to DB: {}", e);
+                                let msg = format!(
+                                    "Inserted {} of {} row(s) into '{}'; the rest failed: {}",
+                                    rows_inserted, num_rows, sheet_name, e
+                                );
+                                error!("{}", msg);
+                                feedback_writer.write(SheetOperationFeedback {
+                                    message: msg,
+                                    is_error: true,
+                                });
                             }
                         }
                     } else {
@@ -194,5 +234,19 @@ pub fn handle_add_rows_batch_request(
                 is_error: true,
             });
         }
+
+        if reload_after_append {
+            match reload_sheet_tree_from_db(&mut registry, daemon_client.client(), &category, &sheet_name) {
+                Ok(tables) => {
+                    for table in tables.into_iter().filter(|t| t != &sheet_name) {
+                        data_modified_writer.write(SheetDataModifiedInRegistryEvent {
+                            category: category.clone(),
+                            sheet_name: table,
+                        });
+                    }
+                }
+                Err(e) => error!("Reload after adding rows to '{}' failed: {}", sheet_name, e),
+            }
+        }
     }
 }
@@ -12,8 +12,9 @@ pub(super) fn invalidate_sheet_cache(
     if let Some(state_mut) = editor_state.as_mut() {
         state_mut.force_filter_recalculation = true;
         
-        // Ensure we scroll to the newly added row at the top
+        // Ensure we scroll to the newly added row (at the top unless the caller says otherwise)
         state_mut.request_scroll_to_new_row = true;
+        state_mut.new_row_grid_index = 0;
         
         // Remove cached entries for this specific sheet
         let keys_to_remove: Vec<_> = state_mut
@@ -1,21 +1,81 @@
 // src/sheets/systems/logic/add_row_handlers/db_persistence.rs
 // Database-specific persistence operations for add_row functionality
 
+use crate::sheets::database::{daemon_client::DaemonClient, writer::DbWriter};
 use crate::sheets::definitions::{ColumnValidator, SheetMetadata};
-use rusqlite::OptionalExtension;
+use rusqlite::{Connection, OptionalExtension};
 use bevy::prelude::*;
 
-///  Prepends a row to the database table
+/// (old, new) row_index pairs that move `count` rows inserted from `first_new` (one past the
+/// table's max) down to `lowest_index`, shifting the rows from there up by `count`. The
+/// first inserted row ends up highest, so the rows keep their order when shown.
+fn bottom_row_index_remapping(lowest_index: i64, first_new: i64, count: i64) -> Vec<(i64, i64)> {
+    let mut mapping: Vec<(i64, i64)> = (lowest_index..first_new).map(|i| (i, i + count)).collect();
+    mapping.extend((0..count).map(|k| (first_new + k, lowest_index + count - 1 - k)));
+    mapping.retain(|(old, new)| old != new);
+    mapping
+}
+
+/// Inserts rows below the lowest row_index (sheets are shown by descending row_index):
+/// they take max + 1 onwards like a prepend and are then moved down with the row_index
+/// remapping the sort uses, in one daemon batch. Returns the row_index moves applied.
+fn append_rows(
+    conn: &Connection,
+    table_name: &str,
+    rows_data: &[Vec<String>],
+    column_names: &[String],
+    db_filename: Option<&str>,
+    daemon_client: &DaemonClient,
+) -> Result<Vec<(i64, i64)>, String> {
+    if rows_data.is_empty() {
+        return Ok(Vec::new());
+    }
+    // See prepend_row: make the daemon's latest writes visible before reading the bounds
+    let _ = conn.query_row("PRAGMA wal_checkpoint(PASSIVE)", [], |_| Ok(()));
+    let (lowest, highest): (Option<i64>, Option<i64>) = conn
+        .query_row(
+            &format!("SELECT MIN(row_index), MAX(row_index) FROM \"{}\"", table_name),
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .map_err(|e| e.to_string())?;
+    let first_new = highest.map_or(0, |max| max + 1);
+    let count = rows_data.len() as i64;
+    if i32::try_from(first_new + count).is_err() {
+        return Err(format!("row_index {} is out of range for '{}'", first_new + count, table_name));
+    }
+
+    let mut statements: Vec<_> = rows_data
+        .iter()
+        .enumerate()
+        .map(|(k, row_data)| {
+            DbWriter::row_insert_statement(table_name, (first_new + k as i64) as i32, row_data, column_names)
+        })
+        .collect();
+    let mapping = bottom_row_index_remapping(lowest.unwrap_or(first_new), first_new, count);
+    statements.extend(
+        DbWriter::remap_row_indices_statements(conn, table_name, &mapping).map_err(|e| e.to_string())?,
+    );
+    let response = daemon_client.exec_batch(statements, db_filename)?;
+    if let Some(err) = response.error {
+        return Err(err);
+    }
+    Ok(mapping)
+}
+
+/// Adds a row to the database table: on top, or below the last row when `at_end` is set.
+/// Returns the row_index moves applied to existing rows (only when added at the end).
 pub(super) fn persist_row_to_db(
     metadata: &SheetMetadata,
     sheet_name: &str,
     category: &Option<String>,
-    grid_data: &[Vec<String>],
-    daemon_client: &crate::sheets::database::daemon_client::DaemonClient,
-) -> Result<(), String> {
+    row0: &[String],
+    at_end: bool,
+    daemon_client: &DaemonClient,
+) -> Result<Vec<(i64, i64)>, String> {
     // Only proceed if this is a DB-backed sheet
     let Some(cat) = category.as_ref() else {
-        return Ok(()); // Not a DB sheet, skip
+        return Ok(Vec::new()); // Not a DB sheet, skip
     };
 
     let base_path = crate::sheets::systems::io::get_default_data_base_path();
@@ -27,9 +87,7 @@ pub(super) fn persist_row_to_db(
 
     let conn = crate::sheets::database::connection::DbConnection::open_existing(&db_path)
         .map_err(|e| format!("Failed to open database: {}", e))?;
-
-    // Get the first row from grid_data
-    let row0 = grid_data.get(0).ok_or("No data to insert")?;
+    let mut moves = Vec::new();
 
     // Detect if this is a structure sheet (has row_index and parent_key columns at indices 0 and 1)
     let is_structure_sheet = metadata.columns.len() >= 2
@@ -110,15 +168,14 @@ pub(super) fn persist_row_to_db(
             }
         }
 
-        crate::sheets::database::writer::DbWriter::prepend_row(
-            &conn,
-            physical_table_name,
-            &row_data,
-            &column_names,
-            db_path.file_name().and_then(|n| n.to_str()),
-            daemon_client,
-        )
-        .map_err(|e| format!("Failed to prepend row to structure table: {:?}", e))?;
+        let db_filename = db_path.file_name().and_then(|n| n.to_str());
+        if at_end {
+            moves = append_rows(&conn, physical_table_name, &[row_data], &column_names, db_filename, daemon_client)
+                .map_err(|e| format!("Failed to append row to structure table: {}", e))?;
+        } else {
+            DbWriter::prepend_row(&conn, physical_table_name, &row_data, &column_names, db_filename, daemon_client)
+                .map_err(|e| format!("Failed to prepend row to structure table: {:?}", e))?;
+        }
 
     } else {
         // Regular table: build column names and row_data for DB insert
@@ -140,35 +197,39 @@ pub(super) fn persist_row_to_db(
             row_data.push(row0.get(i).cloned().unwrap_or_default());
         }
 
-        crate::sheets::database::writer::DbWriter::prepend_row(
-            &conn,
-            physical_table_name,
-            &row_data,
-            &column_names,
-            db_path.file_name().and_then(|n| n.to_str()),
-            daemon_client,
-        )
-        .map_err(|e| format!("Failed to prepend row to database: {:?}", e))?;
+        let db_filename = db_path.file_name().and_then(|n| n.to_str());
+        if at_end {
+            moves = append_rows(&conn, physical_table_name, &[row_data], &column_names, db_filename, daemon_client)
+                .map_err(|e| format!("Failed to append row to database: {}", e))?;
+        } else {
+            DbWriter::prepend_row(&conn, physical_table_name, &row_data, &column_names, db_filename, daemon_client)
+                .map_err(|e| format!("Failed to prepend row to database: {:?}", e))?;
+        }
     }
 
     // Checkpoint to ensure data is flushed to disk
     let _ = crate::sheets::database::checkpoint::checkpoint_database(&conn);
 
-    Ok(())
+    Ok(moves)
 }
 
-/// Batch prepends multiple rows to the database table with single row_index calculation
+/// Batch adds multiple rows to the database table with single row_index calculation: on
+/// top, or below the last row in one daemon batch when `at_end` is set. Returns the
+/// row_index moves applied to existing rows (only when added at the end).
+#[allow(clippy::too_many_arguments)]
 pub(super) fn persist_rows_batch_to_db(
     metadata: &SheetMetadata,
     sheet_name: &str,
     category: &Option<String>,
     grid_data: &[Vec<String>],
     num_rows: usize,
+    at_end: bool,
+    on_chunk: &mut dyn FnMut(usize),
     daemon_client: &crate::sheets::database::daemon_client::DaemonClient,
-) -> Result<(), String> {
+) -> Result<Vec<(i64, i64)>, String> {
     // Only proceed if this is a DB-backed sheet
     let Some(cat) = category.as_ref() else {
-        return Ok(()); // Not a DB sheet, skip
+        return Ok(Vec::new()); // Not a DB sheet, skip
     };
 
     let base_path = crate::sheets::systems::io::get_default_data_base_path();
@@ -181,6 +242,8 @@ pub(super) fn persist_rows_batch_to_db(
     let conn = crate::sheets::database::connection::DbConnection::open_existing(&db_path)
         .map_err(|e| format!("Failed to open database: {}", e))?;
 
+    let moves;
+
     // Detect if this is a structure sheet
     let is_structure_sheet = metadata.columns.len() >= 2
         && metadata.columns.get(0).map(|c| c.header.eq_ignore_ascii_case("row_index")).unwrap_or(false)
@@ -267,15 +330,17 @@ pub(super) fn persist_rows_batch_to_db(
             }
         }
 
-        crate::sheets::database::writer::DbWriter::prepend_rows_batch(
+        moves = insert_batch_rows(
             &conn,
             physical_table_name,  // Use physical table name for database operations
             &batch_rows,
             &column_names,
             db_path.file_name().and_then(|n| n.to_str()), // Pass database filename to daemon
+            at_end,
+            on_chunk,
             daemon_client,
         )
-        .map_err(|e| format!("Failed to batch prepend rows to structure table: {:?}", e))?;
+        .map_err(|e| format!("Failed to batch add rows to structure table: {}", e))?;
 
     } else {
         // Regular table: batch insert
@@ -305,21 +370,53 @@ pub(super) fn persist_rows_batch_to_db(
             }
         }
 
-        crate::sheets::database::writer::DbWriter::prepend_rows_batch(
+        moves = insert_batch_rows(
             &conn,
             physical_table_name,  // Use physical table name for database operations
             &batch_rows,
             &column_names,
             db_path.file_name().and_then(|n| n.to_str()), // Pass database filename to daemon
+            at_end,
+            on_chunk,
             daemon_client,
         )
-        .map_err(|e| format!("Failed to batch prepend rows to database: {:?}", e))?;
+        .map_err(|e| format!("Failed to batch add rows to database: {}", e))?;
     }
 
     // Checkpoint to ensure data is flushed to disk
     let _ = crate::sheets::database::checkpoint::checkpoint_database(&conn);
 
-    Ok(())
+    Ok(moves)
+}
+
+/// Prepends `rows_data` in chunks, or appends them below the last row in one batch
+#[allow(clippy::too_many_arguments)]
+fn insert_batch_rows(
+    conn: &Connection,
+    table_name: &str,
+    rows_data: &[Vec<String>],
+    column_names: &[String],
+    db_filename: Option<&str>,
+    at_end: bool,
+    on_chunk: &mut dyn FnMut(usize),
+    daemon_client: &DaemonClient,
+) -> Result<Vec<(i64, i64)>, String> {
+    if at_end {
+        let moves = append_rows(conn, table_name, rows_data, column_names, db_filename, daemon_client)?;
+        on_chunk(rows_data.len());
+        return Ok(moves);
+    }
+    DbWriter::prepend_rows_batch_with_progress(
+        conn,
+        table_name,
+        rows_data,
+        column_names,
+        db_filename,
+        |rows_done| on_chunk(rows_done),
+        daemon_client,
+    )
+    .map_err(|e| format!("{:?}", e))?;
+    Ok(Vec::new())
 }
 
 /// Updates AI settings in the database for a table
@@ -353,6 +450,7 @@ pub(super) fn update_table_ai_settings_db(
         None,
         None,
         None,
+        None,
         db_path.file_name().and_then(|n| n.to_str()),
         daemon_client,
     )
@@ -438,3 +536,29 @@ pub(super) fn update_column_metadata_db(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn appended_row_moves_below_the_lowest_row() {
+        // Rows 2..=4 exist; the new row is inserted at 5 and takes 2, the rest shift up
+        assert_eq!(
+            bottom_row_index_remapping(2, 5, 1),
+            vec![(2, 3), (3, 4), (4, 5), (5, 2)]
+        );
+        // Empty table: the new row already is the only one
+        assert!(bottom_row_index_remapping(0, 0, 1).is_empty());
+    }
+
+    #[test]
+    fn appended_rows_keep_their_order_below_the_lowest_row() {
+        // Two rows inserted at 5 and 6: the first is shown above the second
+        assert_eq!(
+            bottom_row_index_remapping(2, 5, 2),
+            vec![(2, 4), (3, 5), (4, 6), (5, 3), (6, 2)]
+        );
+        assert_eq!(bottom_row_index_remapping(0, 0, 2), vec![(0, 1), (1, 0)]);
+    }
+}
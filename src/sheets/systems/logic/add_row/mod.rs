@@ -9,11 +9,13 @@ mod common;
 mod db_persistence;
 mod json_persistence;
 mod row_addition;
+mod row_duplication;
 
 // Re-export public handlers
 pub use ai_config_handlers::{
     handle_create_ai_schema_group, handle_delete_ai_schema_group, handle_rename_ai_schema_group,
-    handle_select_ai_schema_group,
+    handle_reorder_ai_schema_group, handle_select_ai_schema_group,
+    handle_set_ai_schema_group_allow_rows, handle_set_ai_structure_skip,
 };
 pub use ai_schema_handlers::{
     handle_toggle_ai_row_generation, handle_update_ai_send_schema,
@@ -21,3 +23,4 @@ pub use ai_schema_handlers::{
 };
 pub use batch_row_addition::handle_add_rows_batch_request;
 pub use row_addition::handle_add_row_request;
+pub use row_duplication::handle_duplicate_row_request;
@@ -3,7 +3,9 @@
 
 use crate::sheets::{
     events::{AddSheetRowRequest, SheetDataModifiedInRegistryEvent, SheetOperationFeedback},
-    resources::SheetRegistry,
+    resources::{EditHistory, SheetRegistry},
+    systems::logic::column_defaults::apply_column_defaults,
+    systems::ui_handlers::sheet_handlers::reload_sheet_tree_from_db,
 };
 use crate::ui::elements::editor::state::EditorWindowState;
 use bevy::prelude::*;
@@ -21,6 +23,7 @@ pub fn handle_add_row_request(
     mut feedback_writer: EventWriter<SheetOperationFeedback>,
     mut data_modified_writer: EventWriter<SheetDataModifiedInRegistryEvent>,
     mut editor_state: Option<ResMut<EditorWindowState>>,
+    mut edit_history: ResMut<EditHistory>,
     daemon_client: Res<crate::sheets::database::daemon_resource::SharedDaemonClient>,
 ) {
     for event in events.read() {
@@ -36,13 +39,18 @@ pub fn handle_add_row_request(
 
         let mut metadata_cache: Option<crate::sheets::definitions::SheetMetadata> = None;
         let mut pending_json_save: Option<crate::sheets::definitions::SheetMetadata> = None;
+        // DB rows added at the end shift other rows' row_index; re-read the sheet afterwards
+        let mut reload_after_append = false;
 
         if let Some(sheet_data) = registry.get_sheet_mut(&category, &sheet_name) {
             if let Some(metadata) = &sheet_data.metadata {
                 let num_cols = metadata.columns.len();
                 
-                // Unified behavior: always insert at top for consistency
-                sheet_data.grid.insert(0, vec![String::new(); num_cols]);
+                // Insert at top by default; quick add puts the row below the last one
+                let insert_at = if event.at_end { sheet_data.grid.len() } else { 0 };
+                let mut new_row = vec![String::new(); num_cols];
+                apply_column_defaults(&metadata.columns, &mut new_row);
+                sheet_data.grid.insert(insert_at, new_row);
 
                 // Detect if this is a structure sheet by checking if it has 'row_index' (at index 0) 
                 // and 'parent_key' columns (anywhere in the columns)
@@ -59,7 +67,7 @@ pub fn handle_add_row_request(
 
                 // Auto-fill structure sheet columns if in structure navigation context OR if this is a structure sheet
                 if is_structure_sheet {
-                    if let Some(row0) = sheet_data.grid.get_mut(0) {
+                    if let Some(row0) = sheet_data.grid.get_mut(insert_at) {
                         // Auto-fill row_index column (index 0) with placeholder - will be updated after DB insert
                         if row0.len() > 0 && row0[0].is_empty() {
                             row0[0] = "PENDING".to_string();  // Placeholder until DB assigns actual row_index
@@ -91,7 +99,7 @@ pub fn handle_add_row_request(
 
                 // If initial values provided, set them now to avoid race with subsequent events
                 if let Some(init) = &event.initial_values {
-                    if let Some(row0) = sheet_data.grid.get_mut(0) {
+                    if let Some(row0) = sheet_data.grid.get_mut(insert_at) {
                         for (col, val) in init {
                             if *col < row0.len() {
                                 row0[*col] = val.clone();
@@ -101,7 +109,8 @@ pub fn handle_add_row_request(
                 }
 
                 let msg = format!(
-                    "Added new row at the top of sheet '{:?}/{}'.",
+                    "Added new row at the {} of sheet '{:?}/{}'.",
+                    if event.at_end { "bottom" } else { "top" },
                     category, sheet_name
                 );
                 info!("{}", msg);
@@ -113,15 +122,32 @@ pub fn handle_add_row_request(
                 // Invalidate any cached filtered indices for this sheet to force UI refresh
                 invalidate_sheet_cache(&mut editor_state, &category, &sheet_name);
 
+                // Focus the first visible plain-text data cell of the new row for immediate entry
+                if let Some(state_mut) = editor_state.as_mut() {
+                    let first_text_col = state_mut
+                        .get_visible_column_indices(&category, &sheet_name, metadata)
+                        .into_iter()
+                        .find(|&idx| {
+                            metadata.columns.get(idx).map_or(false, |c| {
+                                !crate::sheets::definitions::SheetMetadata::is_technical_column_header(&c.header)
+                                    && matches!(c.data_type, crate::sheets::definitions::ColumnDataType::String)
+                                    && matches!(c.validator, None | Some(crate::sheets::definitions::ColumnValidator::Basic(_)))
+                            })
+                        });
+                    state_mut.new_row_grid_index = insert_at;
+                    state_mut.pending_new_row_focus =
+                        first_text_col.map(|col| (category.clone(), sheet_name.clone(), insert_at, col));
+                }
+
                 metadata_cache = Some(metadata.clone());
 
                 // Persist to DB if DB-backed, otherwise save JSON
                 if let Some(meta) = &sheet_data.metadata {
                     if meta.category.is_some() {
-                        // DB-backed: prepend row in database too
+                        // DB-backed: add the row in database too
                         let persist_start = std::time::Instant::now();
-                        match persist_row_to_db(meta, &sheet_name, &category, &sheet_data.grid, daemon_client.client()) {
-                            Ok(_) => {
+                        match persist_row_to_db(meta, &sheet_name, &category, &sheet_data.grid[insert_at], event.at_end, daemon_client.client()) {
+                            Ok(moves) => {
                                 let duration = persist_start.elapsed();
                                 if duration.as_millis() > 100 {
                                     info!("Row persisted to DB in {:?} (slow operation)", duration);
@@ -129,11 +155,17 @@ pub fn handle_add_row_request(
                                     trace!("Row persisted to DB in {:?}", duration);
                                 }
                                 
+                                if event.at_end {
+                                    // Recorded edits follow the rows that moved up
+                                    edit_history.remap_row_indices(&category, &sheet_name, &moves);
+                                    reload_after_append = true;
+                                }
+
                                 // For structure sheets, reload row_index value from DB
                                 let is_structure_sheet = meta.columns.len() >= 2
                                     && meta.columns.get(0).map(|c| c.header.eq_ignore_ascii_case("row_index")).unwrap_or(false);
                                 
-                                if is_structure_sheet {
+                                if is_structure_sheet && !event.at_end {
                                     // Query the top row from DB to get actual row_index value
                                     if let Some(cat) = &meta.category {
                                         let base_path = crate::sheets::systems::io::get_default_data_base_path();
@@ -209,5 +241,19 @@ pub fn handle_add_row_request(
         if let Some(meta) = pending_json_save.take() {
             persist_row_addition_json(registry.as_ref(), &meta);
         }
+
+        if reload_after_append {
+            match reload_sheet_tree_from_db(&mut registry, daemon_client.client(), &category, &sheet_name) {
+                Ok(tables) => {
+                    for table in tables.into_iter().filter(|t| t != &sheet_name) {
+                        data_modified_writer.write(SheetDataModifiedInRegistryEvent {
+                            category: category.clone(),
+                            sheet_name: table,
+                        });
+                    }
+                }
+                Err(e) => error!("Reload after adding a row to '{}' failed: {}", sheet_name, e),
+            }
+        }
     }
 }
@@ -0,0 +1,399 @@
+// src/sheets/systems/logic/add_row/row_duplication.rs
+// "Clone row": copies a row next to itself together with its structure child rows.
+//
+// DB sheets: the copy takes a fresh row_index (max + 1), child rows are copied level by
+// level under the new keys, and the copy is then moved directly above its source (sheets
+// are shown by descending row_index) with the same row_index remapping the sort uses,
+// which keeps parent_keys, comments and locks in step. All of it goes to the daemon as
+// one batch, so a failure leaves the tables untouched. The sheet tree is re-read
+// afterwards so the registry matches the database.
+//
+// Recorded cell edits of the moved rows are pointed at their new positions.
+
+use crate::sheets::{
+    database::{
+        daemon_client::{DaemonClient, Statement},
+        reader::DbReader,
+        writer::DbWriter,
+    },
+    definitions::{ColumnDefinition, ColumnValidator, SheetMetadata},
+    events::{DuplicateRowEvent, SheetDataModifiedInRegistryEvent, SheetOperationFeedback},
+    resources::{EditHistory, SheetRegistry},
+    systems::logic::update_column_validator::structure_naming::structure_child_table_name,
+    systems::ui_handlers::sheet_handlers::reload_sheet_tree_from_db,
+};
+use crate::ui::elements::editor::state::EditorWindowState;
+use bevy::prelude::*;
+use rusqlite::Connection;
+use std::collections::HashMap;
+
+use super::{
+    cache_handlers::{invalidate_sheet_cache, resolve_virtual_context},
+    json_persistence::persist_row_addition_json,
+};
+
+/// Copy of a row for insertion: DB-assigned technical values (row_index, id) are cleared,
+/// parent_key is kept so the copy stays under the same parent
+fn clone_row_values(columns: &[ColumnDefinition], row: &[String]) -> Vec<String> {
+    row.iter()
+        .enumerate()
+        .map(|(idx, value)| {
+            let assigned_by_db = columns.get(idx).is_some_and(|c| {
+                SheetMetadata::is_technical_column_header(&c.header)
+                    && !c.header.eq_ignore_ascii_case("parent_key")
+            });
+            if assigned_by_db {
+                String::new()
+            } else {
+                value.clone()
+            }
+        })
+        .collect()
+}
+
+/// (old, new) row_index pairs that move a copy given `copy_index` (one past the table's
+/// previous max) to `source_index + 1`, shifting the rows in between up by one
+fn adjacent_row_index_remapping(source_index: i64, copy_index: i64) -> Vec<(i64, i64)> {
+    if copy_index <= source_index + 1 {
+        return Vec::new();
+    }
+    let mut mapping: Vec<(i64, i64)> = ((source_index + 1)..copy_index).map(|i| (i, i + 1)).collect();
+    mapping.push((copy_index, source_index + 1));
+    mapping
+}
+
+/// Hands out row_index values past each table's current max, continuing from the last
+/// value given out so several rows of one batch never collide
+fn next_row_index(
+    conn: &Connection,
+    table_name: &str,
+    allocated: &mut HashMap<String, i64>,
+) -> Result<i64, String> {
+    let next = match allocated.get(table_name) {
+        Some(next) => *next,
+        None => conn
+            .query_row(
+                &format!("SELECT MAX(row_index) FROM \"{}\"", table_name),
+                [],
+                |row| row.get::<_, Option<i64>>(0),
+            )
+            .map_err(|e| e.to_string())?
+            .map_or(0, |max| max + 1),
+    };
+    allocated.insert(table_name.to_string(), next + 1);
+    Ok(next)
+}
+
+/// INSERT of a copied row at `row_index`: stored data columns plus parent_key; other
+/// technical, structure and deleted columns are left out
+fn copy_insert_statement(
+    metadata: &SheetMetadata,
+    table_name: &str,
+    row_index: i64,
+    row: &[String],
+) -> Result<Statement, String> {
+    let (column_names, values): (Vec<String>, Vec<String>) = metadata
+        .columns
+        .iter()
+        .enumerate()
+        .filter(|(_, c)| {
+            !c.deleted
+                && !matches!(c.validator, Some(ColumnValidator::Structure))
+                && (!SheetMetadata::is_technical_column_header(&c.header)
+                    || c.header.eq_ignore_ascii_case("parent_key"))
+        })
+        .map(|(idx, c)| (c.header.clone(), row.get(idx).cloned().unwrap_or_default()))
+        .unzip();
+    let row_index = i32::try_from(row_index)
+        .map_err(|_| format!("row_index {} is out of range for '{}'", row_index, table_name))?;
+    Ok(DbWriter::row_insert_statement(table_name, row_index, &values, &column_names))
+}
+
+/// Add INSERTs copying the child rows of every (old parent row_index, new parent
+/// row_index) pair into each structure table of `parent_table`, recursing into deeper
+/// levels. Returns how many child rows were copied.
+#[allow(clippy::too_many_arguments)]
+fn duplicate_child_rows(
+    conn: &Connection,
+    category: &Option<String>,
+    parent_table: &str,
+    parent_meta: &SheetMetadata,
+    key_map: &[(i64, i64)],
+    allocated: &mut HashMap<String, i64>,
+    statements: &mut Vec<Statement>,
+    daemon_client: &DaemonClient,
+) -> Result<usize, String> {
+    let mut copied = 0;
+    for column in parent_meta
+        .columns
+        .iter()
+        .filter(|c| !c.deleted && matches!(c.validator, Some(ColumnValidator::Structure)))
+    {
+        let child_table = structure_child_table_name(parent_table, &column.header);
+        let child = match DbReader::read_sheet(conn, &child_table, daemon_client, category.as_deref()) {
+            Ok(data) => data,
+            Err(e) => {
+                warn!("Skipping structure table '{}' while cloning a row: {}", child_table, e);
+                continue;
+            }
+        };
+        let Some(child_meta) = child.metadata.as_ref() else {
+            continue;
+        };
+
+        let mut child_map: Vec<(i64, i64)> = Vec::new();
+        for (old_parent, new_parent) in key_map {
+            let old_key = old_parent.to_string();
+            for (row, old_index) in child
+                .grid
+                .iter()
+                .zip(child.row_indices.iter())
+                .filter(|(row, _)| row.get(1).is_some_and(|key| key.trim() == old_key))
+            {
+                let mut copy = clone_row_values(&child_meta.columns, row);
+                if let Some(key) = copy.get_mut(1) {
+                    *key = new_parent.to_string();
+                }
+                let new_index = next_row_index(conn, &child_table, allocated)?;
+                statements.push(copy_insert_statement(child_meta, &child_table, new_index, &copy)?);
+                child_map.push((*old_index, new_index));
+            }
+        }
+        copied += child_map.len();
+        if !child_map.is_empty() {
+            copied += duplicate_child_rows(
+                conn,
+                category,
+                &child_table,
+                child_meta,
+                &child_map,
+                allocated,
+                statements,
+                daemon_client,
+            )?;
+        }
+    }
+    Ok(copied)
+}
+
+/// Insert the copy and its child rows and move it above the source, in one daemon batch.
+/// Returns the number of copied child rows and the row_index moves applied to the sheet.
+fn duplicate_row_in_db(
+    metadata: &SheetMetadata,
+    sheet_name: &str,
+    category: &Option<String>,
+    copy: Vec<String>,
+    source_index: i64,
+    daemon_client: &DaemonClient,
+) -> Result<(usize, Vec<(i64, i64)>), String> {
+    let Some(cat) = category.as_ref() else {
+        return Err("not a database sheet".to_string());
+    };
+    let db_path = crate::sheets::systems::io::get_default_data_base_path().join(format!("{}.db", cat));
+    let conn = crate::sheets::database::connection::DbConnection::open_existing(&db_path)
+        .map_err(|e| e.to_string())?;
+
+    let mut allocated = HashMap::new();
+    let mut statements = Vec::new();
+    let copy_index = next_row_index(&conn, sheet_name, &mut allocated)?;
+    statements.push(copy_insert_statement(metadata, sheet_name, copy_index, &copy)?);
+    let children = duplicate_child_rows(
+        &conn,
+        category,
+        sheet_name,
+        metadata,
+        &[(source_index, copy_index)],
+        &mut allocated,
+        &mut statements,
+        daemon_client,
+    )?;
+
+    let mapping = adjacent_row_index_remapping(source_index, copy_index);
+    statements.extend(
+        DbWriter::remap_row_indices_statements(&conn, sheet_name, &mapping).map_err(|e| e.to_string())?,
+    );
+
+    let response = daemon_client.exec_batch(statements, db_path.file_name().and_then(|n| n.to_str()))?;
+    if let Some(err) = response.error {
+        return Err(err);
+    }
+    Ok((children, mapping))
+}
+
+pub fn handle_duplicate_row_request(
+    mut events: EventReader<DuplicateRowEvent>,
+    mut registry: ResMut<SheetRegistry>,
+    mut feedback_writer: EventWriter<SheetOperationFeedback>,
+    mut data_modified_writer: EventWriter<SheetDataModifiedInRegistryEvent>,
+    mut editor_state: Option<ResMut<EditorWindowState>>,
+    mut edit_history: ResMut<EditHistory>,
+    daemon_client: Res<crate::sheets::database::daemon_resource::SharedDaemonClient>,
+) {
+    for event in events.read() {
+        let (category, sheet_name) = resolve_virtual_context(
+            &editor_state,
+            event.category.clone(),
+            event.sheet_name.clone(),
+        );
+        let mut fail = |msg: String| {
+            warn!("{}", msg);
+            feedback_writer.write(SheetOperationFeedback {
+                message: msg,
+                is_error: true,
+            });
+        };
+
+        let Some(sheet) = registry.get_sheet(&category, &sheet_name) else {
+            fail(format!("Cannot clone row: sheet '{:?}/{}' not found.", category, sheet_name));
+            continue;
+        };
+        let Some(metadata) = sheet.metadata.clone() else {
+            fail(format!("Cannot clone row in '{:?}/{}': metadata missing.", category, sheet_name));
+            continue;
+        };
+        let Some(source_row) = sheet.grid.get(event.row_index) else {
+            fail(format!(
+                "Cannot clone row {} of '{}': row not found.",
+                event.row_index + 1,
+                sheet_name
+            ));
+            continue;
+        };
+        let copy = clone_row_values(&metadata.columns, source_row);
+
+        let mut cloned = true;
+        if metadata.category.is_none() {
+            // JSON sheet: no structure tables or row_index values to look after
+            if let Some(sheet) = registry.get_sheet_mut(&category, &sheet_name) {
+                sheet.grid.insert(event.row_index, copy);
+            }
+            edit_history.shift_rows_for_insert(&category, &sheet_name, event.row_index);
+            persist_row_addition_json(registry.as_ref(), &metadata);
+        } else {
+            if sheet.row_indices.len() != sheet.grid.len() {
+                fail(format!(
+                    "Cannot clone row in '{}': row indices are out of sync with the grid; reload the sheet and retry.",
+                    sheet_name
+                ));
+                continue;
+            }
+            let source_index = sheet.row_indices[event.row_index];
+            match duplicate_row_in_db(
+                &metadata,
+                &sheet_name,
+                &category,
+                copy,
+                source_index,
+                daemon_client.client(),
+            ) {
+                Ok((children, mapping)) => {
+                    edit_history.remap_row_indices(&category, &sheet_name, &mapping);
+                    if children > 0 {
+                        info!(
+                            "Cloned row {} of '{:?}/{}' with {} structure row(s).",
+                            source_index, category, sheet_name, children
+                        );
+                    }
+                }
+                Err(e) => {
+                    cloned = false;
+                    fail(format!("Failed to clone row in '{}': {}", sheet_name, e));
+                }
+            }
+            if cloned {
+                match reload_sheet_tree_from_db(&mut registry, daemon_client.client(), &category, &sheet_name) {
+                    Ok(tables) => {
+                        for table in tables.into_iter().filter(|t| t != &sheet_name) {
+                            data_modified_writer.write(SheetDataModifiedInRegistryEvent {
+                                category: category.clone(),
+                                sheet_name: table,
+                            });
+                        }
+                    }
+                    Err(e) => error!("Reload after cloning a row in '{}' failed: {}", sheet_name, e),
+                }
+            }
+        }
+
+        if let Some(state) = editor_state.as_mut() {
+            state.parent_lineage_cache.clear();
+        }
+        invalidate_sheet_cache(&mut editor_state, &category, &sheet_name);
+        if let Some(state) = editor_state.as_mut() {
+            // The copy sits where the source was drawn; stay there instead of jumping to the top
+            state.request_scroll_to_new_row = false;
+        }
+        data_modified_writer.write(SheetDataModifiedInRegistryEvent {
+            category: category.clone(),
+            sheet_name: sheet_name.clone(),
+        });
+        if cloned {
+            feedback_writer.write(SheetOperationFeedback {
+                message: format!("Cloned row {} of '{}'.", event.row_index + 1, sheet_name),
+                is_error: false,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sheets::database::writer::test_helpers::exec_fixture_sql;
+    use crate::sheets::definitions::ColumnDataType;
+
+    #[test]
+    fn clone_clears_db_assigned_columns_but_keeps_parent_key() {
+        let columns: Vec<ColumnDefinition> = ["row_index", "parent_key", "Name", "Parts"]
+            .iter()
+            .map(|h| ColumnDefinition::new_basic(h.to_string(), ColumnDataType::String))
+            .collect();
+        let row: Vec<String> = ["12", "3", "Blade", "2"].iter().map(|s| s.to_string()).collect();
+        assert_eq!(clone_row_values(&columns, &row), vec!["", "3", "Blade", "2"]);
+    }
+
+    #[test]
+    fn copy_insert_writes_data_columns_and_parent_key_only() {
+        let mut columns: Vec<ColumnDefinition> = ["row_index", "parent_key", "Name", "Parts", "Old"]
+            .iter()
+            .map(|h| ColumnDefinition::new_basic(h.to_string(), ColumnDataType::String))
+            .collect();
+        columns[3].validator = Some(ColumnValidator::Structure);
+        columns[4].deleted = true;
+        let mut metadata =
+            SheetMetadata::create_generic("Items_Parts".into(), "Items_Parts.json".into(), 5, None);
+        metadata.columns = columns;
+        let row: Vec<String> = ["", "7", "Blade", "", "x"].iter().map(|s| s.to_string()).collect();
+
+        let stmt = copy_insert_statement(&metadata, "Items_Parts", 12, &row).unwrap();
+        assert_eq!(
+            stmt.sql,
+            "INSERT INTO \"Items_Parts\" (row_index, \"parent_key\", \"Name\") VALUES (?, ?, ?)"
+        );
+        assert_eq!(stmt.params, vec![serde_json::json!(12), serde_json::json!("7"), serde_json::json!("Blade")]);
+    }
+
+    #[test]
+    fn row_indices_continue_past_the_max_within_one_batch() {
+        let conn = Connection::open_in_memory().unwrap();
+        exec_fixture_sql(
+            &conn,
+            "CREATE TABLE \"Items\" (row_index INTEGER); INSERT INTO \"Items\" VALUES (4), (9); CREATE TABLE \"Empty\" (row_index INTEGER);",
+        );
+        let mut allocated = HashMap::new();
+        assert_eq!(next_row_index(&conn, "Items", &mut allocated).unwrap(), 10);
+        assert_eq!(next_row_index(&conn, "Items", &mut allocated).unwrap(), 11);
+        assert_eq!(next_row_index(&conn, "Empty", &mut allocated).unwrap(), 0);
+    }
+
+    #[test]
+    fn copy_moves_directly_above_its_source() {
+        // Copy inserted at 9 while the source is 5: 6..=8 shift up, the copy takes 6
+        assert_eq!(
+            adjacent_row_index_remapping(5, 9),
+            vec![(6, 7), (7, 8), (8, 9), (9, 6)]
+        );
+        // Source already on top: the copy lands next to it
+        assert!(adjacent_row_index_remapping(8, 9).is_empty());
+    }
+}
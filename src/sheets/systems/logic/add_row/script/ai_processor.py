@@ -12,6 +12,8 @@ from __future__ import annotations
 import json
 import os
 import re
+import sys
+import traceback
 
 """AI Processor
 
@@ -470,6 +472,8 @@ def execute_ai_query(api_key: str, payload_json: str) -> str:  # noqa: D401
         
         return json.dumps(payload_out, ensure_ascii=False)
     except Exception as e:  # pragma: no cover
+        # Traceback goes to stderr, which the Rust side captures and appends to the error
+        traceback.print_exc(file=sys.stderr)
         return make_err(f"Unhandled exception: {e}")
 
 def extract_first_json(text: str) -> str:
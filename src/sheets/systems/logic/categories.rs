@@ -4,7 +4,7 @@ use crate::sheets::{
     database::daemon_resource::SharedDaemonClient,
     events::{
         RequestCreateCategory, RequestDeleteCategory,
-        RequestRenameCategory, SheetOperationFeedback,
+        RequestRenameCategory, RequestSetCategoryColor, SheetOperationFeedback,
     },
     resources::SheetRegistry,
     systems::io::get_default_data_base_path,
@@ -256,3 +256,36 @@ pub fn handle_rename_category_request(
         }
     }
 }
+
+/// Handles category color changes: stored in the category's database, mirrored in the registry
+pub fn handle_set_category_color_request(
+    mut events: EventReader<RequestSetCategoryColor>,
+    mut registry: ResMut<SheetRegistry>,
+    mut feedback: EventWriter<SheetOperationFeedback>,
+    daemon_client: Res<SharedDaemonClient>,
+) {
+    for ev in events.read() {
+        let db_filename = format!("{}.db", ev.name);
+        if !get_default_data_base_path().join(&db_filename).exists() {
+            feedback.write(SheetOperationFeedback {
+                message: format!("Database '{}' not found; color not saved", ev.name),
+                is_error: true,
+            });
+            continue;
+        }
+        match crate::sheets::database::writer::DbWriter::update_database_color(
+            ev.color,
+            Some(&db_filename),
+            daemon_client.client(),
+        ) {
+            Ok(()) => registry.set_category_color(&ev.name, ev.color),
+            Err(e) => {
+                error!("Failed to save color of database '{}': {}", ev.name, e);
+                feedback.write(SheetOperationFeedback {
+                    message: format!("Failed to save color of '{}': {}", ev.name, e),
+                    is_error: true,
+                });
+            }
+        }
+    }
+}
@@ -12,6 +12,8 @@ use crate::ui::validation::ValidationState;
 /// # Arguments
 /// * `is_column_selected_for_deletion` - Whether the column is selected for deletion
 /// * `is_row_selected` - Whether the row is selected
+/// * `is_row_locked` - Whether the row is locked against edits
+/// * `is_find_match` - Whether the cell contains the in-sheet find query
 /// * `current_interaction_mode` - Current interaction mode (Delete, AI, etc.)
 /// * `is_structure_column` - Whether this is a structure column
 /// * `is_structure_ai_included` - Whether structure column is included in AI
@@ -22,6 +24,8 @@ use crate::ui::validation::ValidationState;
 pub fn determine_cell_background_color(
     is_column_selected_for_deletion: bool,
     is_row_selected: bool,
+    is_row_locked: bool,
+    is_find_match: bool,
     current_interaction_mode: SheetInteractionState,
     is_structure_column: bool,
     is_structure_ai_included: bool,
@@ -46,6 +50,11 @@ pub fn determine_cell_background_color(
         } else {
             Color32::from_rgba_unmultiplied(20, 60, 120, 200)
         }
+    } else if is_find_match {
+        Color32::from_rgba_unmultiplied(150, 120, 20, 200)
+    } else if is_row_locked && effective_validation_state != ValidationState::Invalid {
+        // Subtle slate tint so locked rows read as read-only without hiding invalid cells
+        Color32::from_rgb(38, 40, 52)
     } else {
         get_validation_based_background(effective_validation_state, is_linked_column, basic_type)
     }
@@ -65,13 +74,16 @@ fn get_validation_based_background(
         ValidationState::Empty => {
             match basic_type {
                 ColumnDataType::String | ColumnDataType::Link => text_cell_fill,
-                ColumnDataType::I64 | ColumnDataType::F64 => dark_cell_fill,
+                ColumnDataType::I64 | ColumnDataType::F64 | ColumnDataType::Date => dark_cell_fill,
                 _ => Color32::TRANSPARENT,
             }
         }
         ValidationState::Valid => {
             if is_linked_column
-                || matches!(basic_type, ColumnDataType::Bool | ColumnDataType::I64 | ColumnDataType::F64)
+                || matches!(
+                    basic_type,
+                    ColumnDataType::Bool | ColumnDataType::I64 | ColumnDataType::F64 | ColumnDataType::Date
+                )
             {
                 // numeric and linked columns use standard dark fill
                 dark_cell_fill
@@ -0,0 +1,196 @@
+// src/sheets/systems/logic/cell_comments.rs
+use crate::{
+    sheets::{
+        database::{daemon_resource::SharedDaemonClient, reader::DbReader, writer::DbWriter},
+        definitions::SheetGridData,
+        events::{
+            DeleteCellCommentEvent, SheetDataModifiedInRegistryEvent, SheetOperationFeedback,
+            UpsertCellCommentEvent,
+        },
+        resources::SheetRegistry,
+        systems::io::get_default_data_base_path,
+    },
+    ui::elements::editor::state::EditorWindowState,
+};
+use bevy::prelude::*;
+use rusqlite::Connection;
+
+/// Storage key of a grid cell's comment: the row's DB row_index and the column header.
+/// Headers survive column reordering, and row_index follows the row through sorts.
+pub fn cell_comment_key(
+    sheet: &SheetGridData,
+    grid_row: usize,
+    col_index: usize,
+) -> Option<(i64, String)> {
+    let row_index = *sheet.row_indices.get(grid_row)?;
+    let column = sheet.metadata.as_ref()?.columns.get(col_index)?;
+    Some((row_index, column.header.clone()))
+}
+
+/// Writes comment upserts and deletions through the daemon and mirrors them in the
+/// editor's comment cache so the indicator updates without a reload.
+pub fn handle_cell_comment_requests(
+    mut upsert_events: EventReader<UpsertCellCommentEvent>,
+    mut delete_events: EventReader<DeleteCellCommentEvent>,
+    registry: Res<SheetRegistry>,
+    mut feedback_writer: EventWriter<SheetOperationFeedback>,
+    mut editor_state_opt: Option<ResMut<EditorWindowState>>,
+    daemon_client: Res<SharedDaemonClient>,
+) {
+    let requests = upsert_events
+        .read()
+        .map(|e| {
+            (
+                e.category.clone(),
+                e.sheet_name.clone(),
+                e.row_index,
+                e.col_index,
+                Some(e.comment.trim().to_string()).filter(|c| !c.is_empty()),
+            )
+        })
+        .chain(delete_events.read().map(|e| {
+            (e.category.clone(), e.sheet_name.clone(), e.row_index, e.col_index, None)
+        }))
+        .collect::<Vec<_>>();
+
+    for (category, sheet_name, grid_row, col_index, comment) in requests {
+        let mut fail = |msg: String| {
+            error!("{}", msg);
+            feedback_writer.write(SheetOperationFeedback {
+                message: msg,
+                is_error: true,
+            });
+        };
+
+        let Some(db_name) = category.as_deref() else {
+            fail(format!(
+                "Cell comments are only available for database sheets ('{}').",
+                sheet_name
+            ));
+            continue;
+        };
+        let Some((row_index, column_name)) = registry
+            .get_sheet(&category, &sheet_name)
+            .and_then(|sheet| cell_comment_key(sheet, grid_row, col_index))
+        else {
+            fail(format!(
+                "Failed to update comment: cell ({}, {}) not found in '{:?}/{}'.",
+                grid_row, col_index, category, sheet_name
+            ));
+            continue;
+        };
+
+        let db_path = get_default_data_base_path().join(format!("{}.db", db_name));
+        let db_filename = db_path.file_name().and_then(|n| n.to_str());
+        let result = match &comment {
+            Some(text) => DbWriter::upsert_cell_comment(
+                &sheet_name,
+                row_index,
+                &column_name,
+                text,
+                db_filename,
+                daemon_client.client(),
+            ),
+            None => Connection::open(&db_path)
+                .map_err(Into::into)
+                .and_then(|conn| {
+                    DbWriter::delete_cell_comment(
+                        &conn,
+                        &sheet_name,
+                        row_index,
+                        &column_name,
+                        db_filename,
+                        daemon_client.client(),
+                    )
+                }),
+        };
+        if let Err(e) = result {
+            fail(format!(
+                "Failed to update comment on '{:?}/{}' ({}, '{}'): {}",
+                category, sheet_name, row_index, column_name, e
+            ));
+            continue;
+        }
+
+        if let Some(state) = editor_state_opt.as_mut() {
+            if state.cell_comments_sheet.as_ref() == Some(&(category.clone(), sheet_name.clone())) {
+                let key = (row_index, column_name);
+                match comment {
+                    Some(text) => {
+                        state.cell_comments.insert(key, text);
+                    }
+                    None => {
+                        state.cell_comments.remove(&key);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Loads the comments of the selected sheet when the selection changes or the sheet's
+/// data was modified (sorts and deletions move or remove comments in the DB).
+pub fn refresh_cell_comments_cache(
+    mut modified_events: EventReader<SheetDataModifiedInRegistryEvent>,
+    editor_state_opt: Option<ResMut<EditorWindowState>>,
+) {
+    let Some(mut state) = editor_state_opt else {
+        modified_events.clear();
+        return;
+    };
+    let selected = state
+        .selected_sheet_name
+        .clone()
+        .map(|name| (state.selected_category.clone(), name));
+    let selected_modified = modified_events
+        .read()
+        .any(|e| selected.as_ref() == Some(&(e.category.clone(), e.sheet_name.clone())));
+    if state.cell_comments_sheet == selected && !selected_modified {
+        return;
+    }
+
+    state.cell_comments.clear();
+    state.cell_comments_sheet = selected.clone();
+    let Some((Some(db_name), sheet_name)) = selected else {
+        return;
+    };
+    let db_path = get_default_data_base_path().join(format!("{}.db", db_name));
+    if !db_path.exists() {
+        return;
+    }
+    let loaded = Connection::open(&db_path)
+        .map_err(Into::into)
+        .and_then(|conn| {
+            // Make the daemon's writes visible before reading
+            let _ = conn.query_row("PRAGMA wal_checkpoint(PASSIVE)", [], |_| Ok(()));
+            DbReader::read_cell_comments(&conn, &sheet_name)
+        });
+    match loaded {
+        Ok(comments) => state.cell_comments = comments,
+        Err(e) => warn!("Failed to load cell comments of '{}/{}': {}", db_name, sheet_name, e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sheets::definitions::SheetMetadata;
+
+    #[test]
+    fn comment_key_uses_db_row_index_and_header() {
+        let metadata = SheetMetadata::create_generic(
+            "Items".to_string(),
+            "Items.json".to_string(),
+            2,
+            Some("Game".to_string()),
+        );
+        let sheet = SheetGridData {
+            metadata: Some(metadata),
+            grid: vec![vec!["a".into(), "b".into()], vec!["c".into(), "d".into()]],
+            row_indices: vec![7, 3],
+        };
+        assert_eq!(cell_comment_key(&sheet, 1, 1), Some((3, "Column 2".to_string())));
+        assert_eq!(cell_comment_key(&sheet, 2, 0), None);
+        assert_eq!(cell_comment_key(&sheet, 0, 5), None);
+    }
+}
@@ -1,12 +1,21 @@
 // src/sheets/systems/logic/clipboard.rs
 use crate::sheets::{
-    definitions::ColumnValidator,
-    events::{RequestCopyCell, RequestPasteCell, SheetOperationFeedback, UpdateCellEvent},
+    definitions::{ColumnValidator, SheetMetadata},
+    events::{
+        AddSheetRowsBatchRequest, CellEdit, RequestCopyCell, RequestPasteBlock, RequestPasteCell,
+        SheetOperationFeedback, UpdateCellEvent, UpdateCellsBatchEvent,
+    },
     resources::{ClipboardBuffer, SheetRegistry},
-    systems::ai::utils::parse_structure_rows_from_cell,
+    systems::{
+        ai::utils::parse_structure_rows_from_cell,
+        io::csv::parse_csv,
+        logic::unique_validator::{normalize_unique_key, unique_settings},
+    },
 };
+use super::update_cell::validate_new_row_value;
 use crate::ui::elements::ai_review::serialization_helpers::serialize_structure_rows_to_json;
 use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
 
 /// Handle copy cell events - copies cell value and structure data if applicable
 pub fn handle_copy_cell(
@@ -188,3 +197,349 @@ pub fn handle_paste_cell(
         });
     }
 }
+
+/// Cells of tab-separated clipboard text (as copied from Excel or another sheet), or `None`
+/// when the text is a single value that a plain cell paste handles.
+pub fn parse_tsv_block(text: &str) -> Option<Vec<Vec<String>>> {
+    let rows = parse_csv(text, '\t');
+    let is_block = rows.len() > 1 || rows.first().is_some_and(|row| row.len() > 1);
+    is_block.then_some(rows)
+}
+
+/// Where the cells of a pasted block go
+#[derive(Debug, Default, PartialEq)]
+pub struct BlockPastePlan {
+    /// Writes into existing rows
+    pub edits: Vec<CellEdit>,
+    /// Initial (col_index, value) pairs of rows to add past the end of the sheet, in paste order
+    pub new_rows: Vec<Vec<(usize, String)>>,
+    /// Values dropped because they land on a structure or technical column
+    pub skipped: usize,
+    /// Values dropped because they fall beyond the last column
+    pub clipped: usize,
+}
+
+/// Lay out pasted `values` onto `target_rows` x `target_cols` (see [`RequestPasteBlock`]).
+/// Structure and technical columns inside the block keep their value.
+pub fn plan_block_paste(
+    metadata: &SheetMetadata,
+    target_rows: &[usize],
+    target_cols: &[usize],
+    values: &[Vec<String>],
+) -> BlockPastePlan {
+    let mut plan = BlockPastePlan::default();
+    for (r, row_values) in values.iter().enumerate() {
+        let grid_row = target_rows.get(r).copied();
+        let mut new_row = Vec::new();
+        for (c, value) in row_values.iter().enumerate() {
+            let Some((col_index, column)) = target_cols
+                .get(c)
+                .and_then(|&col| metadata.columns.get(col).map(|column| (col, column)))
+            else {
+                plan.clipped += 1;
+                continue;
+            };
+            if matches!(column.validator, Some(ColumnValidator::Structure))
+                || SheetMetadata::is_technical_column_header(&column.header)
+            {
+                plan.skipped += 1;
+                continue;
+            }
+            match grid_row {
+                Some(row_index) => plan.edits.push(CellEdit {
+                    row_index,
+                    col_index,
+                    new_value: value.clone(),
+                }),
+                None => new_row.push((col_index, value.clone())),
+            }
+        }
+        if grid_row.is_none() {
+            plan.new_rows.push(new_row);
+        }
+    }
+    plan
+}
+
+/// Drops values of new rows that repeat an earlier new row's value in a `Unique` column
+/// (comparison as in [`normalize_unique_key`]) and returns why each one was dropped
+pub fn drop_repeated_unique_values(
+    metadata: &SheetMetadata,
+    new_rows: &mut [Vec<(usize, String)>],
+) -> Vec<String> {
+    let mut seen: HashMap<usize, HashSet<String>> = HashMap::new();
+    let mut dropped = Vec::new();
+    for row in new_rows.iter_mut() {
+        row.retain(|(col_index, value)| {
+            let Some(column) = metadata.columns.get(*col_index) else {
+                return true;
+            };
+            let Some(key) = unique_settings(&column.validator).and_then(|(data_type, case_sensitive)| {
+                normalize_unique_key(value, data_type, case_sensitive)
+            }) else {
+                return true;
+            };
+            if seen.entry(*col_index).or_default().insert(key) {
+                return true;
+            }
+            dropped.push(format!(
+                "'{}' must be unique; '{}' is pasted into more than one new row.",
+                column.display_name(),
+                value.trim()
+            ));
+            false
+        });
+    }
+    dropped
+}
+
+/// Handle block paste events - existing cells go through one batch update (which validates
+/// each cell and reports the outcome); values for new rows are validated here before the
+/// rows are requested below the sheet's last row.
+pub fn handle_paste_block(
+    mut events: EventReader<RequestPasteBlock>,
+    registry: Res<SheetRegistry>,
+    mut batch_writer: EventWriter<UpdateCellsBatchEvent>,
+    mut add_rows_writer: EventWriter<AddSheetRowsBatchRequest>,
+    mut feedback_writer: EventWriter<SheetOperationFeedback>,
+) {
+    for event in events.read() {
+        let Some(metadata) = registry
+            .get_sheet(&event.category, &event.sheet_name)
+            .and_then(|sheet| sheet.metadata.as_ref())
+        else {
+            feedback_writer.write(SheetOperationFeedback {
+                message: format!(
+                    "Sheet '{}/{}' not found",
+                    event.category.as_deref().unwrap_or("root"),
+                    event.sheet_name
+                ),
+                is_error: true,
+            });
+            continue;
+        };
+
+        let plan = plan_block_paste(metadata, &event.target_rows, &event.target_cols, &event.values);
+
+        let mut failed = 0usize;
+        let mut new_rows: Vec<Vec<(usize, String)>> = plan
+            .new_rows
+            .iter()
+            .map(|values| {
+                values
+                    .iter()
+                    .filter_map(|(col, value)| {
+                        match validate_new_row_value(
+                            &registry,
+                            &event.category,
+                            &event.sheet_name,
+                            *col,
+                            value,
+                        ) {
+                            Ok(value) => Some((*col, value)),
+                            Err(e) => {
+                                warn!("Block paste into '{}': {}", event.sheet_name, e);
+                                failed += 1;
+                                None
+                            }
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        for reason in drop_repeated_unique_values(metadata, &mut new_rows) {
+            warn!("Block paste into '{}': {}", event.sheet_name, reason);
+            failed += 1;
+        }
+
+        // The batch update and the add-rows handler report how many cells and rows landed
+        if !plan.edits.is_empty() {
+            batch_writer.write(UpdateCellsBatchEvent {
+                category: event.category.clone(),
+                sheet_name: event.sheet_name.clone(),
+                edits: plan.edits,
+                report_result: true,
+            });
+        }
+        // Rows past the end go below the sheet's last row, in paste order
+        let added_rows = new_rows.len();
+        if added_rows > 0 {
+            add_rows_writer.write(AddSheetRowsBatchRequest {
+                category: event.category.clone(),
+                sheet_name: event.sheet_name.clone(),
+                rows_initial_values: new_rows,
+                at_end: true,
+            });
+        }
+
+        let mut notes: Vec<String> = Vec::new();
+        if plan.skipped > 0 {
+            notes.push(format!("skipped {} structure/technical cell(s)", plan.skipped));
+        }
+        if plan.clipped > 0 {
+            notes.push(format!("ignored {} cell(s) past the last column", plan.clipped));
+        }
+        if failed > 0 {
+            notes.push(format!("{} cell(s) of new rows failed validation (see log)", failed));
+        }
+        if notes.is_empty() {
+            continue;
+        }
+        let message = format!("Block paste into '{}': {}.", event.sheet_name, notes.join(", "));
+        info!("{}", message);
+        feedback_writer.write(SheetOperationFeedback {
+            message,
+            is_error: failed > 0,
+        });
+    }
+}
+
+/// Spreadsheet column letters for a zero-based position (0 -> "A", 25 -> "Z", 26 -> "AA")
+pub fn column_letters(position: usize) -> String {
+    let mut n = position + 1;
+    let mut letters = Vec::new();
+    while n > 0 {
+        let rem = (n - 1) % 26;
+        letters.push((b'A' + rem as u8) as char);
+        n = (n - 1) / 26;
+    }
+    letters.iter().rev().collect()
+}
+
+/// A1-style reference for a block of visible cells, given zero-based visible column positions
+/// and zero-based visible row positions. Row 1 is the header row of an exported sheet, so the
+/// first data row is row 2. A single cell yields e.g. "B2", a block "B2:D10".
+pub fn a1_range_reference(
+    first_col: usize,
+    first_row: usize,
+    last_col: usize,
+    last_row: usize,
+) -> String {
+    let (c0, c1) = (first_col.min(last_col), first_col.max(last_col));
+    let (r0, r1) = (first_row.min(last_row), first_row.max(last_row));
+    let start = format!("{}{}", column_letters(c0), r0 + 2);
+    if c0 == c1 && r0 == r1 {
+        return start;
+    }
+    format!("{}:{}{}", start, column_letters(c1), r1 + 2)
+}
+
+/// Join rows of cell values as tab-separated text. Values containing tabs, line breaks or
+/// quotes are quoted the way spreadsheet applications expect when pasting.
+pub fn rows_to_tsv(rows: &[Vec<String>]) -> String {
+    rows.iter()
+        .map(|row| {
+            row.iter()
+                .map(|value| {
+                    if value.contains(['\t', '\n', '\r', '"']) {
+                        format!("\"{}\"", value.replace('"', "\"\""))
+                    } else {
+                        value.clone()
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("\t")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn column_letters_roll_over_like_spreadsheets() {
+        assert_eq!(column_letters(0), "A");
+        assert_eq!(column_letters(25), "Z");
+        assert_eq!(column_letters(26), "AA");
+        assert_eq!(column_letters(51), "AZ");
+        assert_eq!(column_letters(702), "AAA");
+    }
+
+    #[test]
+    fn a1_reference_skips_header_row_and_normalizes_corners() {
+        assert_eq!(a1_range_reference(1, 0, 3, 8), "B2:D10");
+        assert_eq!(a1_range_reference(3, 8, 1, 0), "B2:D10");
+        assert_eq!(a1_range_reference(0, 4, 0, 4), "A6");
+    }
+
+    #[test]
+    fn tsv_block_needs_more_than_one_cell() {
+        assert_eq!(parse_tsv_block("single\r\n"), None);
+        assert_eq!(
+            parse_tsv_block("a\tb\r\nc\t\"d\te\"\r\n"),
+            Some(vec![
+                vec!["a".to_string(), "b".to_string()],
+                vec!["c".to_string(), "d\te".to_string()],
+            ])
+        );
+    }
+
+    #[test]
+    fn block_paste_skips_structures_clips_width_and_grows_rows() {
+        let mut metadata = SheetMetadata::create_generic(
+            "Items".to_string(),
+            "Items.json".to_string(),
+            3,
+            Some("Game".to_string()),
+        );
+        metadata.columns[1].validator = Some(ColumnValidator::Structure);
+        let rows = vec![
+            vec!["a".to_string(), "b".to_string(), "c".to_string(), "d".to_string()],
+            vec!["e".to_string(), "f".to_string(), "g".to_string()],
+        ];
+
+        // Only grid row 4 is left below the active cell; all three columns are visible
+        let plan = plan_block_paste(&metadata, &[4], &[0, 1, 2], &rows);
+        assert_eq!(
+            plan.edits,
+            vec![
+                CellEdit { row_index: 4, col_index: 0, new_value: "a".to_string() },
+                CellEdit { row_index: 4, col_index: 2, new_value: "c".to_string() },
+            ]
+        );
+        assert_eq!(plan.new_rows, vec![vec![(0, "e".to_string()), (2, "g".to_string())]]);
+        assert_eq!(plan.skipped, 2);
+        assert_eq!(plan.clipped, 1);
+    }
+
+    #[test]
+    fn new_rows_cannot_repeat_each_others_unique_values() {
+        let mut metadata = SheetMetadata::create_generic(
+            "Items".to_string(),
+            "Items.json".to_string(),
+            2,
+            Some("Game".to_string()),
+        );
+        metadata.columns[0].validator = Some(ColumnValidator::Unique {
+            data_type: metadata.columns[0].data_type,
+            case_sensitive: false,
+        });
+        let mut new_rows = vec![
+            vec![(0, "Sword".to_string()), (1, "a".to_string())],
+            vec![(0, " sword ".to_string()), (1, "a".to_string())],
+            vec![(0, String::new()), (1, "b".to_string())],
+            vec![(0, String::new())],
+        ];
+
+        let dropped = drop_repeated_unique_values(&metadata, &mut new_rows);
+        assert_eq!(dropped.len(), 1);
+        // The repeat is dropped, the non-unique column and empty cells are kept
+        assert_eq!(new_rows[1], vec![(1, "a".to_string())]);
+        assert_eq!(new_rows[0].len(), 2);
+        assert_eq!(new_rows[3], vec![(0, String::new())]);
+    }
+
+    #[test]
+    fn tsv_quotes_values_that_would_break_cells() {
+        let rows = vec![
+            vec!["F-16".to_string(), "multi\nline".to_string()],
+            vec!["say \"hi\"".to_string(), String::new()],
+        ];
+        assert_eq!(
+            rows_to_tsv(&rows),
+            "F-16\t\"multi\nline\"\n\"say \"\"hi\"\"\"\t"
+        );
+    }
+}
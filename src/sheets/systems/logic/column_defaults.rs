@@ -0,0 +1,129 @@
+// src/sheets/systems/logic/column_defaults.rs
+// Per-column default values pre-filled into newly added rows. Defaults are checked against
+// the column type when set in the column options popup; Date columns also accept `@today`,
+// which is stored as-is and expanded at insertion time.
+
+use chrono::NaiveDate;
+
+use crate::sheets::definitions::{ColumnDataType, ColumnDefinition, SheetMetadata};
+use crate::sheets::systems::logic::date_values::{normalize_date, ISO_DATE_FORMAT};
+
+/// Date default expanded to the current date when a row is added
+pub const TODAY_TOKEN: &str = "@today";
+
+/// Check a default typed in the column options popup. Blank clears the default; other
+/// values come back in the form they are stored in (ISO dates, `true`/`false` flags).
+pub fn parse_default_value(data_type: ColumnDataType, input: &str) -> Result<Option<String>, String> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Ok(None);
+    }
+    let stored = match data_type {
+        ColumnDataType::String | ColumnDataType::Link => input.to_string(),
+        ColumnDataType::Bool => match trimmed.to_lowercase().as_str() {
+            "true" | "1" => "true".to_string(),
+            "false" | "0" => "false".to_string(),
+            _ => return Err(format!("'{}' is not a boolean (true/false)", trimmed)),
+        },
+        ColumnDataType::I64 => trimmed
+            .parse::<i64>()
+            .map(|v| v.to_string())
+            .map_err(|_| format!("'{}' is not a whole number", trimmed))?,
+        ColumnDataType::F64 => {
+            trimmed
+                .parse::<f64>()
+                .map_err(|_| format!("'{}' is not a number", trimmed))?;
+            trimmed.to_string()
+        }
+        ColumnDataType::Date if trimmed.eq_ignore_ascii_case(TODAY_TOKEN) => TODAY_TOKEN.to_string(),
+        ColumnDataType::Date => normalize_date(trimmed)
+            .ok_or_else(|| format!("'{}' is not a date (or {})", trimmed, TODAY_TOKEN))?,
+    };
+    Ok(Some(stored))
+}
+
+/// Value a new row gets for this column, with `@today` expanded for Date columns
+pub fn resolve_default_value(column: &ColumnDefinition, today: NaiveDate) -> Option<String> {
+    let value = column.default_value.as_deref()?;
+    if matches!(column.data_type, ColumnDataType::Date) && value.eq_ignore_ascii_case(TODAY_TOKEN) {
+        Some(today.format(ISO_DATE_FORMAT).to_string())
+    } else {
+        Some(value.to_string())
+    }
+}
+
+/// Fill the empty cells of a freshly inserted row with their columns' defaults.
+/// Technical columns (row_index, parent_key, ...) are never touched.
+pub fn apply_column_defaults(columns: &[ColumnDefinition], row: &mut [String]) {
+    apply_column_defaults_on(columns, row, chrono::Local::now().date_naive());
+}
+
+fn apply_column_defaults_on(columns: &[ColumnDefinition], row: &mut [String], today: NaiveDate) {
+    for (cell, column) in row.iter_mut().zip(columns) {
+        if !cell.is_empty()
+            || column.deleted
+            || SheetMetadata::is_technical_column_header(&column.header)
+            || SheetMetadata::is_metadata_column_header(&column.header)
+        {
+            continue;
+        }
+        if let Some(value) = resolve_default_value(column, today) {
+            *cell = value;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_are_checked_against_the_column_type() {
+        assert_eq!(parse_default_value(ColumnDataType::String, "  "), Ok(None));
+        assert_eq!(
+            parse_default_value(ColumnDataType::String, "n/a").as_deref(),
+            Ok(Some("n/a"))
+        );
+        assert_eq!(parse_default_value(ColumnDataType::I64, " 42 ").as_deref(), Ok(Some("42")));
+        assert!(parse_default_value(ColumnDataType::I64, "4.2").is_err());
+        assert_eq!(parse_default_value(ColumnDataType::F64, "4.20").as_deref(), Ok(Some("4.20")));
+        assert!(parse_default_value(ColumnDataType::F64, "abc").is_err());
+        assert_eq!(parse_default_value(ColumnDataType::Bool, "1").as_deref(), Ok(Some("true")));
+        assert!(parse_default_value(ColumnDataType::Bool, "yes").is_err());
+        assert_eq!(
+            parse_default_value(ColumnDataType::Date, "07.03.2024").as_deref(),
+            Ok(Some("2024-03-07"))
+        );
+        assert_eq!(
+            parse_default_value(ColumnDataType::Date, "@Today").as_deref(),
+            Ok(Some(TODAY_TOKEN))
+        );
+        assert!(parse_default_value(ColumnDataType::Date, "tomorrow").is_err());
+        assert!(parse_default_value(ColumnDataType::String, TODAY_TOKEN).is_ok());
+    }
+
+    #[test]
+    fn new_rows_get_defaults_only_in_empty_data_cells() {
+        let today = NaiveDate::from_ymd_opt(2024, 3, 7).unwrap();
+        let mut row_index = ColumnDefinition::new_basic("row_index".to_string(), ColumnDataType::I64);
+        row_index.default_value = Some("9".to_string());
+        let mut created = ColumnDefinition::new_basic("Created".to_string(), ColumnDataType::Date);
+        created.default_value = Some(TODAY_TOKEN.to_string());
+        let mut status = ColumnDefinition::new_basic("Status".to_string(), ColumnDataType::String);
+        status.default_value = Some("open".to_string());
+        let mut text = ColumnDefinition::new_basic("Text".to_string(), ColumnDataType::String);
+        text.default_value = Some(TODAY_TOKEN.to_string());
+        let name = ColumnDefinition::new_basic("Name".to_string(), ColumnDataType::String);
+        let columns = vec![row_index, created, status, text, name];
+
+        let mut row = vec![
+            String::new(),
+            String::new(),
+            "closed".to_string(),
+            String::new(),
+            String::new(),
+        ];
+        apply_column_defaults_on(&columns, &mut row, today);
+        assert_eq!(row, vec!["", "2024-03-07", "closed", TODAY_TOKEN, ""]);
+    }
+}
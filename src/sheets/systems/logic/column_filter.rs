@@ -0,0 +1,83 @@
+// src/sheets/systems/logic/column_filter.rs
+// Regex column filters.
+//
+// A column filter starting with `re:` is one regular expression matched against the cell
+// text, instead of '|' separated substring terms (so `|` keeps its regex meaning). Patterns
+// are matched as written; prepend `(?i)` for case-insensitive matching.
+
+use regex::Regex;
+use std::collections::HashMap;
+
+pub const REGEX_FILTER_PREFIX: &str = "re:";
+
+/// Pattern of a regex filter with the `re:` prefix stripped, or `None` for a plain filter
+pub fn regex_filter_pattern(filter_text: &str) -> Option<&str> {
+    filter_text.trim_start().strip_prefix(REGEX_FILTER_PREFIX)
+}
+
+/// Compile a regex filter pattern; the error text is meant to be shown to the user
+pub fn compile_filter_regex(pattern: &str) -> Result<Regex, String> {
+    if pattern.trim().is_empty() {
+        return Err("empty pattern after 're:'".to_string());
+    }
+    Regex::new(pattern).map_err(|e| e.to_string())
+}
+
+/// Compiled regex filters per (category, sheet, column index). A column's entry is only
+/// recompiled when its pattern changes, so callers can look patterns up every frame.
+#[derive(Default)]
+pub struct FilterRegexCache {
+    entries: HashMap<(Option<String>, String, usize), (String, Result<Regex, String>)>,
+}
+
+impl FilterRegexCache {
+    pub fn get_or_compile(
+        &mut self,
+        category: &Option<String>,
+        sheet_name: &str,
+        col_index: usize,
+        pattern: &str,
+    ) -> Result<&Regex, &str> {
+        let entry = self
+            .entries
+            .entry((category.clone(), sheet_name.to_string(), col_index))
+            .or_insert_with(|| (pattern.to_string(), compile_filter_regex(pattern)));
+        if entry.0 != pattern {
+            *entry = (pattern.to_string(), compile_filter_regex(pattern));
+        }
+        entry.1.as_ref().map_err(|e| e.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prefix_is_stripped_before_compiling() {
+        assert_eq!(regex_filter_pattern("re:^a|b$"), Some("^a|b$"));
+        assert_eq!(regex_filter_pattern("  re:x"), Some("x"));
+        assert_eq!(regex_filter_pattern("red|blue"), None);
+        let re = compile_filter_regex(regex_filter_pattern("re:^sword_\\d+$").unwrap()).unwrap();
+        assert!(re.is_match("sword_12"));
+        assert!(!re.is_match("great sword_12"));
+    }
+
+    #[test]
+    fn invalid_and_empty_patterns_report_errors() {
+        assert!(compile_filter_regex("(unclosed").is_err());
+        assert!(compile_filter_regex("  ").is_err());
+    }
+
+    #[test]
+    fn cache_recompiles_only_when_the_pattern_changes() {
+        let mut cache = FilterRegexCache::default();
+        let cat = Some("Game".to_string());
+        assert!(cache.get_or_compile(&cat, "Items", 2, "^a").unwrap().is_match("abc"));
+        assert!(cache.get_or_compile(&cat, "Items", 2, "(").is_err());
+        assert!(cache.get_or_compile(&cat, "Items", 2, "c$").unwrap().is_match("abc"));
+        // Other columns are cached independently
+        assert!(cache.get_or_compile(&cat, "Items", 3, "(").is_err());
+        assert!(cache.get_or_compile(&cat, "Items", 2, "c$").is_ok());
+    }
+}
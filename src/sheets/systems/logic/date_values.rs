@@ -0,0 +1,73 @@
+// src/sheets/systems/logic/date_values.rs
+// Parsing for `Date` columns. Values are stored as ISO-8601 text (YYYY-MM-DD); edits may
+// use a few common spellings, which are normalized before they reach the grid or the DB.
+
+use chrono::NaiveDate;
+
+/// Storage format of `Date` cells
+pub const ISO_DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// Accepted input spellings, tried in order. Day-first wins over month-first for the
+/// ambiguous dotted/slashed forms, matching how most of our sheets are written.
+const INPUT_FORMATS: &[&str] = &[
+    ISO_DATE_FORMAT,
+    "%Y/%m/%d",
+    "%Y.%m.%d",
+    "%d.%m.%Y",
+    "%d/%m/%Y",
+    "%m/%d/%Y",
+    "%d-%m-%Y",
+    "%Y%m%d",
+    "%d %b %Y",
+    "%b %d, %Y",
+    "%d %B %Y",
+    "%B %d, %Y",
+];
+
+/// Parse a date typed in any of the accepted formats
+pub fn parse_date(input: &str) -> Option<NaiveDate> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return None;
+    }
+    // Full timestamps ("2024-05-01T10:00:00", "2024-05-01 10:00") keep their date part
+    let date_part = match trimmed.find(['T', ' ']) {
+        Some(pos) if trimmed.as_bytes().get(4) == Some(&b'-') => &trimmed[..pos],
+        _ => trimmed,
+    };
+    INPUT_FORMATS
+        .iter()
+        .find_map(|fmt| NaiveDate::parse_from_str(date_part, fmt).ok())
+}
+
+/// ISO-8601 form of a date input, or `None` when it is not a date
+pub fn normalize_date(input: &str) -> Option<String> {
+    parse_date(input).map(|date| date.format(ISO_DATE_FORMAT).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn common_formats_normalize_to_iso() {
+        assert_eq!(normalize_date("2024-03-07").as_deref(), Some("2024-03-07"));
+        assert_eq!(normalize_date(" 2024/3/7 ").as_deref(), Some("2024-03-07"));
+        assert_eq!(normalize_date("07.03.2024").as_deref(), Some("2024-03-07"));
+        assert_eq!(normalize_date("20240307").as_deref(), Some("2024-03-07"));
+        assert_eq!(normalize_date("7 Mar 2024").as_deref(), Some("2024-03-07"));
+        assert_eq!(normalize_date("March 7, 2024").as_deref(), Some("2024-03-07"));
+        assert_eq!(normalize_date("2024-03-07T12:30:00").as_deref(), Some("2024-03-07"));
+        // Month-first only when day-first is impossible
+        assert_eq!(normalize_date("03/25/2024").as_deref(), Some("2024-03-25"));
+    }
+
+    #[test]
+    fn malformed_dates_are_rejected() {
+        assert_eq!(normalize_date(""), None);
+        assert_eq!(normalize_date("2024-02-30"), None);
+        assert_eq!(normalize_date("2024-13-01"), None);
+        assert_eq!(normalize_date("next tuesday"), None);
+        assert_eq!(normalize_date("12345"), None);
+    }
+}
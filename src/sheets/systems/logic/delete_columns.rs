@@ -1,5 +1,6 @@
 // src/sheets/systems/logic/delete_columns.rs
 use crate::sheets::{
+    database::daemon_client::{DaemonClient, Statement, TransactionMode},
     definitions::{ColumnValidator, SheetMetadata},
     events::{
         RequestDeleteColumns, RequestDeleteSheetFile, SheetDataModifiedInRegistryEvent,
@@ -10,29 +11,142 @@ use crate::sheets::{
 };
 use bevy::prelude::*;
 use crate::sheets::database::open_or_create_db_for_category;
-use std::collections::HashMap;
+use rusqlite::Connection;
+use std::collections::HashSet;
 use std::path::PathBuf;
 
+/// Structure columns that use one of `indices` as their parent key column while staying
+/// themselves: (key column, structure column). Such a key can only go together with the
+/// structure column (and so its child table).
+pub fn blocked_key_columns(metadata: &SheetMetadata, indices: &HashSet<usize>) -> Vec<(usize, usize)> {
+    metadata
+        .columns
+        .iter()
+        .enumerate()
+        .filter(|(idx, c)| {
+            !c.deleted
+                && !indices.contains(idx)
+                && matches!(c.validator, Some(ColumnValidator::Structure))
+        })
+        .filter_map(|(idx, c)| {
+            c.structure_key_parent_column_index
+                .filter(|key| indices.contains(key))
+                .map(|key| (key, idx))
+        })
+        .collect()
+}
+
+fn physical_column_exists(conn: &Connection, table_name: &str, column_name: &str) -> bool {
+    let Ok(mut stmt) = conn.prepare(&format!("PRAGMA table_info(\"{}\")", table_name)) else {
+        return false;
+    };
+    let Ok(names) = stmt.query_map([], |row| row.get::<_, String>(1)) else {
+        return false;
+    };
+    names.flatten().any(|name| name == column_name)
+}
+
+/// Remove columns of a DB-backed sheet, and the child tables of its structure columns,
+/// in one transaction: either every column goes or none does.
+fn delete_db_columns(
+    category: &str,
+    table_name: &str,
+    columns: &[String],
+    structure_tables: &[String],
+    daemon_client: &DaemonClient,
+) -> Result<(), String> {
+    let conn = open_or_create_db_for_category(category).map_err(|e| e.to_string())?;
+    let db_filename = format!("{}.db", category);
+    let meta_table = format!("{}_Metadata", table_name);
+
+    let mut statements = Vec::new();
+    let mut dropped_any = false;
+    for column_name in columns {
+        // Mark deleted flag and disable AI include in metadata
+        statements.push(Statement {
+            sql: format!(
+                "UPDATE \"{}\" SET deleted = 1, ai_include_in_send = 0 WHERE column_name = ?",
+                meta_table
+            ),
+            params: vec![serde_json::json!(column_name)],
+        });
+        if physical_column_exists(&conn, table_name, column_name) {
+            // Wipe data first, then drop the column (SQLite 3.35.0+)
+            statements.push(Statement {
+                sql: format!("UPDATE \"{}\" SET \"{}\" = NULL", table_name, column_name),
+                params: vec![],
+            });
+            statements.push(Statement {
+                sql: format!("ALTER TABLE \"{}\" DROP COLUMN \"{}\"", table_name, column_name),
+                params: vec![],
+            });
+            dropped_any = true;
+        }
+    }
+    for structure_table in structure_tables {
+        statements.push(Statement {
+            sql: format!("DROP TABLE IF EXISTS \"{}\"", structure_table),
+            params: vec![],
+        });
+        statements.push(Statement {
+            sql: "DELETE FROM _Metadata WHERE table_name = ?".to_string(),
+            params: vec![serde_json::json!(structure_table)],
+        });
+        statements.push(Statement {
+            sql: format!("DROP TABLE IF EXISTS \"{}_Metadata\"", structure_table),
+            params: vec![],
+        });
+    }
+
+    let response = daemon_client.exec_batch(statements, Some(&db_filename))?;
+    if let Some(err) = response.error {
+        return Err(err);
+    }
+
+    // Best effort from here on: the columns are gone either way
+    if dropped_any || !structure_tables.is_empty() {
+        // VACUUM cannot run inside a transaction
+        match daemon_client.exec_batch_with_mode(
+            vec![Statement { sql: "VACUUM".to_string(), params: vec![] }],
+            Some(&db_filename),
+            TransactionMode::NoTransaction,
+        ) {
+            Ok(response) if response.error.is_some() => warn!(
+                "Failed to vacuum '{}' after column deletion: {:?}",
+                db_filename, response.error
+            ),
+            Err(e) => warn!("Failed to vacuum '{}' after column deletion: {}", db_filename, e),
+            Ok(_) => {}
+        }
+    }
+    for column_name in columns {
+        if let Err(e) = crate::sheets::database::writer::DbWriter::delete_column_comments(
+            &conn,
+            table_name,
+            column_name,
+            Some(&db_filename),
+            daemon_client,
+        ) {
+            warn!("Failed to delete cell comments of column '{}.{}': {}", table_name, column_name, e);
+        }
+    }
+    Ok(())
+}
+
+/// Deletes the selected columns of a sheet as one operation. The request is refused as a
+/// whole when an index is out of range or a column is the parent key of a structure column
+/// that stays. Structure columns take their child tables with them.
 pub fn handle_delete_columns_request(
     mut events: EventReader<RequestDeleteColumns>,
     mut registry: ResMut<SheetRegistry>,
     mut feedback_writer: EventWriter<SheetOperationFeedback>,
     mut data_modified_writer: EventWriter<SheetDataModifiedInRegistryEvent>,
     mut file_delete_writer: EventWriter<RequestDeleteSheetFile>,
-    // Virtual structure system removed - editor_state no longer needed
     daemon_client: Res<crate::sheets::database::daemon_resource::SharedDaemonClient>,
 ) {
-    let mut sheets_to_save: HashMap<(Option<String>, String), SheetMetadata> = HashMap::new();
-    let mut structure_sheets_to_delete: Vec<(Option<String>, String)> = Vec::new();
-    // Collect DB-backed column deletion requests: (category, table_name, column_name)
-    let mut db_column_deletions: Vec<(String, String, String)> = Vec::new();
-
     for event in events.read() {
-        // Process delete event; scheduled DB column deletions will be recorded in outer vector
         let (category, sheet_name) = (&event.category, &event.sheet_name);
-        let indices_to_delete = &event.column_indices;
-
-        if indices_to_delete.is_empty() {
+        if event.column_indices.is_empty() {
             trace!(
                 "Skipping delete columns request for '{:?}/{}': No indices provided.",
                 category,
@@ -41,315 +155,157 @@ pub fn handle_delete_columns_request(
             continue;
         }
 
-        let mut operation_successful = false;
-        let mut deleted_count = 0;
-        let mut error_message: Option<String> = None;
-        let mut metadata_cache: Option<SheetMetadata> = None;
-
-        if let Some(sheet_data) = registry.get_sheet_mut(category, sheet_name) {
-            if let Some(metadata) = &mut sheet_data.metadata {
-                // Sort indices descending to avoid shifting issues during removal
-                let mut sorted_indices: Vec<usize> = indices_to_delete.iter().cloned().collect();
-                sorted_indices.sort_unstable_by(|a, b| b.cmp(a)); // Sort descending
-
-                let initial_col_count = metadata.columns.len();
+        let fail = |feedback_writer: &mut EventWriter<SheetOperationFeedback>, err: String| {
+            error!("Failed to delete columns from '{:?}/{}': {}", category, sheet_name, err);
+            feedback_writer.write(SheetOperationFeedback {
+                message: format!("Column delete failed for '{:?}/{}': {}", category, sheet_name, err),
+                is_error: true,
+            });
+        };
 
-                for &col_idx_to_remove in &sorted_indices {
-                    if col_idx_to_remove < metadata.columns.len() {
-                        // Mark column as deleted for reuse and disable AI inclusion
-                        if let Some(col_def) = metadata.columns.get_mut(col_idx_to_remove) {
-                            col_def.deleted = true;
-                            col_def.ai_include_in_send = Some(false);
-                            // Schedule metadata update for DB-backed
-                            if let Some(cat_str) = category.clone() {
-                                db_column_deletions.push((cat_str, sheet_name.clone(), col_def.header.clone()));
-                            }
-                            // Check for structure validator cascade
-                            if matches!(col_def.validator, Some(ColumnValidator::Structure)) {
-                                let structure_sheet_name = format!("{}_{}", sheet_name, col_def.header);
-                                structure_sheets_to_delete.push((category.clone(), structure_sheet_name));
-                            }
-                        }
-                        deleted_count += 1;
-                    } else {
-                        let err_msg = format!(
-                            "Column index {} out of bounds ({} columns). Deletion partially failed.",
-                            col_idx_to_remove, initial_col_count
-                        );
-                        error_message = Some(err_msg.clone());
-                        warn!(
-                            "Skipping delete for column index {} in '{:?}/{}': Out of bounds. {}",
-                            col_idx_to_remove, category, sheet_name, err_msg
-                        );
-                    }
-                }
+        let Some(metadata) = registry
+            .get_sheet(category, sheet_name)
+            .and_then(|s| s.metadata.as_ref())
+        else {
+            fail(&mut feedback_writer, "sheet or its metadata not found.".to_string());
+            continue;
+        };
 
-                if deleted_count > 0 {
-                    metadata.ensure_column_consistency(); // Recalculate consistency if needed
-                    operation_successful = true;
-                    metadata_cache = Some(metadata.clone());
-                    data_modified_writer.write(SheetDataModifiedInRegistryEvent {
-                        category: category.clone(),
-                        sheet_name: sheet_name.clone(),
-                    });
-                }
-            } else {
-                error_message = Some(format!(
-                    "Metadata missing for sheet '{:?}/{}'. Cannot delete columns.",
-                    category, sheet_name
-                ));
-            }
-        } else {
-            error_message = Some(format!(
-                "Sheet '{:?}/{}' not found. Cannot delete columns.",
-                category, sheet_name
-            ));
+        if let Some(&bad) = event
+            .column_indices
+            .iter()
+            .find(|&&idx| metadata.columns.get(idx).map_or(true, |c| c.deleted))
+        {
+            fail(
+                &mut feedback_writer,
+                format!("column index {} does not exist ({} columns). Nothing was deleted.", bad, metadata.columns.len()),
+            );
+            continue;
         }
 
-        if operation_successful {
-            let base_msg = format!(
-                "Deleted {} column(s) from sheet '{:?}/{}'.",
-                deleted_count, category, sheet_name
+        let blocked = blocked_key_columns(metadata, &event.column_indices);
+        if !blocked.is_empty() {
+            let names: Vec<String> = blocked
+                .iter()
+                .map(|&(key, structure)| {
+                    format!(
+                        "'{}' (key of structure '{}')",
+                        metadata.columns[key].display_name(),
+                        metadata.columns[structure].display_name()
+                    )
+                })
+                .collect();
+            fail(
+                &mut feedback_writer,
+                format!(
+                    "{} cannot be deleted unless the structure column is deleted too. Nothing was deleted.",
+                    names.join(", ")
+                ),
             );
-            let final_msg = if let Some(ref err) = error_message {
-                format!("{} {}", base_msg, err)
-            } else {
-                base_msg
-            };
-            info!("{}", final_msg);
-            feedback_writer.write(SheetOperationFeedback {
-                message: final_msg,
-                is_error: error_message.is_some(),
-            });
+            continue;
+        }
 
-            if let Some(meta) = metadata_cache {
-                sheets_to_save.insert((category.clone(), sheet_name.clone()), meta);
-            } else if deleted_count > 0 {
-                warn!(
-                    "Columns deleted from '{:?}/{}' but cannot save: Metadata missing or no columns actually deleted from metadata.",
-                    category, sheet_name
-                );
+        let mut indices: Vec<usize> = event.column_indices.iter().copied().collect();
+        indices.sort_unstable();
+        let headers: Vec<String> = indices.iter().map(|&i| metadata.columns[i].header.clone()).collect();
+        // (registry sheet name, physical table name) of each structure child
+        let structure_sheets: Vec<(String, Option<String>)> = indices
+            .iter()
+            .filter(|&&i| matches!(metadata.columns[i].validator, Some(ColumnValidator::Structure)))
+            .map(|&i| {
+                let child = format!("{}_{}", sheet_name, metadata.columns[i].header);
+                let table = registry
+                    .get_sheet(category, &child)
+                    .and_then(|s| s.metadata.as_ref())
+                    .map(|m| m.data_filename.clone());
+                (child, table)
+            })
+            .collect();
+
+        // DB first so a failed transaction leaves the registry untouched as well
+        if let Some(cat) = category {
+            let structure_tables: Vec<String> =
+                structure_sheets.iter().filter_map(|(_, t)| t.clone()).collect();
+            if let Err(e) = delete_db_columns(cat, sheet_name, &headers, &structure_tables, daemon_client.client()) {
+                fail(&mut feedback_writer, format!("{}. Nothing was deleted.", e));
+                continue;
             }
-        } else if let Some(err) = error_message {
-            error!(
-                "Failed to delete columns from '{:?}/{}': {}",
-                category, sheet_name, err
-            );
-            feedback_writer.write(SheetOperationFeedback {
-                message: format!(
-                    "Column delete failed for '{:?}/{}': {}",
-                    category, sheet_name, err
-                ),
-                is_error: true,
-            });
         }
-    }
 
-    if !sheets_to_save.is_empty() {
-        let registry_immut = registry.as_ref();
-        for ((cat, name), metadata) in sheets_to_save {
-            info!("Columns deleted in '{:?}/{}', triggering save.", cat, name);
-            if metadata.category.is_none() {
-                save_single_sheet(registry_immut, &metadata);
-            }
+        let Some(metadata) = registry
+            .get_sheet_mut(category, sheet_name)
+            .and_then(|s| s.metadata.as_mut())
+        else {
+            continue;
+        };
+        for &idx in &indices {
+            let col_def = &mut metadata.columns[idx];
+            // Mark column as deleted for reuse and disable AI inclusion
+            col_def.deleted = true;
+            col_def.ai_include_in_send = Some(false);
+        }
+        metadata.ensure_column_consistency();
+        let metadata_snapshot = metadata.clone();
+        data_modified_writer.write(SheetDataModifiedInRegistryEvent {
+            category: category.clone(),
+            sheet_name: sheet_name.clone(),
+        });
+        if metadata_snapshot.category.is_none() {
+            save_single_sheet(registry.as_ref(), &metadata_snapshot);
         }
-    }
 
-    // Cascade delete structure sheets
-    if !structure_sheets_to_delete.is_empty() {
-        for (struct_category, struct_sheet_name) in structure_sheets_to_delete {
-            // Capture physical table name BEFORE removing from registry
-            let physical_table_name = if let Some(sheet) = registry.get_sheet(&struct_category, &struct_sheet_name) {
-                sheet.metadata.as_ref().map(|m| m.data_filename.clone())
-            } else {
-                None
-            };
-            
-            // Remove from registry
-            if let Ok(_removed) = registry.delete_sheet(&struct_category, &struct_sheet_name) {
-                info!(
-                    "Removed structure sheet '{:?}/{}' from registry due to cascade delete.",
-                    struct_category, struct_sheet_name
+        // Cascade: structure sheets of deleted structure columns
+        for (structure_sheet, _) in &structure_sheets {
+            if registry.delete_sheet(category, structure_sheet).is_err() {
+                warn!(
+                    "Attempted to cascade delete structure sheet '{:?}/{}' but it was not found in registry.",
+                    category, structure_sheet
                 );
-
-                // Delete physical files (.json and .meta.json)
-                let category_path = if let Some(ref cat) = struct_category {
-                    format!("{}/", cat)
-                } else {
-                    String::new()
-                };
-
-                let json_path =
-                    PathBuf::from(format!("{}{}.json", category_path, struct_sheet_name));
-                let meta_path =
-                    PathBuf::from(format!("{}{}.meta.json", category_path, struct_sheet_name));
-
+                continue;
+            }
+            info!(
+                "Removed structure sheet '{:?}/{}' from registry due to cascade delete.",
+                category, structure_sheet
+            );
+            if category.is_none() {
+                // JSON sheets: delete the .json and .meta.json files
                 file_delete_writer.write(RequestDeleteSheetFile {
-                    relative_path: json_path,
+                    relative_path: PathBuf::from(format!("{}.json", structure_sheet)),
                 });
                 file_delete_writer.write(RequestDeleteSheetFile {
-                    relative_path: meta_path,
+                    relative_path: PathBuf::from(format!("{}.meta.json", structure_sheet)),
                 });
-
-                // If DB-backed, also remove the structure table and its metadata rows
-                if let Some(cat) = &struct_category {
-                    if let Some(phys_name) = physical_table_name {
-                        let base = crate::sheets::systems::io::get_default_data_base_path();
-                        let db_path = base.join(format!("{}.db", cat));
-                        if db_path.exists() {
-                            if let Ok(_conn) = crate::sheets::database::connection::DbConnection::open_existing(&db_path) {
-                                // Use daemon for all write operations
-                                let meta_table = format!("{}_Metadata", phys_name);
-                                
-                                let statements = vec![
-                                    // Drop the structure data table if exists
-                                    crate::sheets::database::daemon_client::Statement {
-                                        sql: format!("DROP TABLE IF EXISTS \"{}\"", phys_name),
-                                        params: vec![],
-                                    },
-                                    // Remove metadata entry for the structure table from _Metadata
-                                    crate::sheets::database::daemon_client::Statement {
-                                        sql: "DELETE FROM _Metadata WHERE table_name = ?".to_string(),
-                                        params: vec![serde_json::json!(phys_name)],
-                                    },
-                                    // Drop per-table metadata table if exists
-                                    crate::sheets::database::daemon_client::Statement {
-                                        sql: format!("DROP TABLE IF EXISTS \"{}\"", meta_table),
-                                        params: vec![],
-                                    },
-                                ];
-                                
-                                match daemon_client.client().exec_batch(statements, db_path.file_name().and_then(|n| n.to_str())) {
-                                    Ok(response) => {
-                                        if response.error.is_some() {
-                                            warn!("Daemon error dropping structure table '{}' in DB '{}': {:?}", 
-                                                  phys_name, db_path.display(), response.error);
-                                        } else {
-                                            info!("Dropped structure table '{}', metadata entry, and metadata table from DB '{}'.", 
-                                                  phys_name, db_path.display());
-                                        }
-                                    }
-                                    Err(e) => {
-                                        warn!("Failed to execute DROP/DELETE batch via daemon for '{}': {:?}", phys_name, e);
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-
-                feedback_writer.write(SheetOperationFeedback {
-                    message: format!(
-                        "Cascade deleted structure sheet '{:?}/{}'.",
-                        struct_category, struct_sheet_name
-                    ),
-                    is_error: false,
-                });
-            } else {
-                warn!(
-                    "Attempted to cascade delete structure sheet '{:?}/{}' but it was not found in registry.",
-                    struct_category, struct_sheet_name
-                );
             }
         }
-    }
 
-    // Cascade drop columns for DB-backed sheets
-    // Process DB-backed column deletions: remove metadata entries only
-    if !db_column_deletions.is_empty() {
-        info!("Marking {} DB-backed column(s) deleted: {:?}", db_column_deletions.len(), db_column_deletions);
-        for (cat, table_name, column_name) in db_column_deletions {
-            let base = crate::sheets::systems::io::get_default_data_base_path();
-            let db_path = base.join(format!("{}.db", cat));
-            
-            match open_or_create_db_for_category(&cat) {
-                Ok(conn) => {
-                    let meta_table = format!("{}_Metadata", table_name);
-                    
-                    // Check if column exists first (for reads we still use direct connection)
-                    let mut col_exists = false;
-                    if let Ok(mut stmt) = conn.prepare(&format!("PRAGMA table_info(\"{}\")", table_name)) {
-                        if let Ok(mut rows) = stmt.query([]) {
-                            while let Ok(Some(row)) = rows.next() {
-                                let name: String = row.get(1).unwrap_or_default();
-                                if name == column_name {
-                                    col_exists = true;
-                                    break;
-                                }
-                            }
-                        }
-                    }
-                    
-                    // Build statements for daemon execution (transactional part)
-                    let mut statements = vec![
-                        // Mark deleted flag and disable AI include in metadata
-                        crate::sheets::database::daemon_client::Statement {
-                            sql: format!("UPDATE \"{}\" SET deleted = 1, ai_include_in_send = 0 WHERE column_name = ?", meta_table),
-                            params: vec![serde_json::json!(column_name)],
-                        },
-                    ];
-                    
-                    if col_exists {
-                        // Wipe data first
-                        statements.push(crate::sheets::database::daemon_client::Statement {
-                            sql: format!("UPDATE \"{}\" SET \"{}\" = NULL", table_name, column_name),
-                            params: vec![],
-                        });
-                        
-                        // Try to drop the column (SQLite 3.35.0+)
-                        statements.push(crate::sheets::database::daemon_client::Statement {
-                            sql: format!("ALTER TABLE \"{}\" DROP COLUMN \"{}\"", table_name, column_name),
-                            params: vec![],
-                        });
-                    }
-                    
-                    // Execute transactional statements first
-                    match daemon_client.client().exec_batch(statements, db_path.file_name().and_then(|n| n.to_str())) {
-                        Ok(response) => {
-                            if response.error.is_some() {
-                                warn!("Daemon error during column deletion for '{}.{}': {:?}", table_name, column_name, response.error);
-                            } else {
-                                if col_exists {
-                                    info!("Successfully marked deleted, wiped, and dropped column '{}' from table '{}'", column_name, table_name);
-                                    
-                                    // Run VACUUM separately (cannot run inside transaction)
-                                    let vacuum_statements = vec![
-                                        crate::sheets::database::daemon_client::Statement {
-                                            sql: "VACUUM".to_string(),
-                                            params: vec![],
-                                        },
-                                    ];
-                                    
-                                    match daemon_client.client().exec_batch_with_mode(
-                                        vacuum_statements, 
-                                        db_path.file_name().and_then(|n| n.to_str()),
-                                        crate::sheets::database::daemon_client::TransactionMode::NoTransaction
-                                    ) {
-                                        Ok(vacuum_response) => {
-                                            if vacuum_response.error.is_some() {
-                                                warn!("Failed to vacuum after column deletion for '{}.{}': {:?}", 
-                                                      table_name, column_name, vacuum_response.error);
-                                            } else {
-                                                info!("Successfully vacuumed database after deleting column '{}'", column_name);
-                                            }
-                                        }
-                                        Err(e) => {
-                                            warn!("Failed to vacuum database after column deletion for '{}.{}': {:?}", 
-                                                  table_name, column_name, e);
-                                        }
-                                    }
-                                } else {
-                                    info!("Marked column '{}' deleted in metadata (column didn't exist in table '{}')", column_name, table_name);
-                                }
-                            }
-                        }
-                        Err(e) => {
-                            warn!("Failed to execute column deletion batch via daemon for '{}.{}': {:?}", table_name, column_name, e);
-                        }
-                    }
-                }
-                Err(e) => warn!("Could not open DB for category '{}' when marking column '{}' deleted: {}", cat, column_name, e),
-            }
+        let mut message = format!(
+            "Deleted {} column(s) from sheet '{:?}/{}'.",
+            indices.len(),
+            category,
+            sheet_name
+        );
+        if !structure_sheets.is_empty() {
+            message.push_str(&format!(" {} structure table(s) removed with them.", structure_sheets.len()));
         }
+        info!("{}", message);
+        feedback_writer.write(SheetOperationFeedback { message, is_error: false });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn structure_key_columns_only_go_with_their_structure() {
+        // Column 0 is the parent key of structure column 2
+        let mut metadata = SheetMetadata::create_generic("Items".to_string(), "Items.json".to_string(), 3, None);
+        let structure = &mut metadata.columns[2];
+        structure.validator = Some(ColumnValidator::Structure);
+        structure.structure_key_parent_column_index = Some(0);
+
+        assert_eq!(blocked_key_columns(&metadata, &HashSet::from([0, 1])), vec![(0, 2)]);
+        assert!(blocked_key_columns(&metadata, &HashSet::from([0, 2])).is_empty());
+        assert!(blocked_key_columns(&metadata, &HashSet::from([1])).is_empty());
     }
 }
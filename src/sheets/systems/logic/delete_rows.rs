@@ -276,7 +276,7 @@ pub fn handle_delete_rows_request(
                 let db_path = base.join(format!("{}.db", db_name));
                 if db_path.exists() {
                     match crate::sheets::database::connection::DbConnection::open_existing(&db_path) {
-                        Ok(_conn) => {
+                        Ok(conn) => {
                             // Use daemon for DELETE operations
                             let table_type = if is_structure { "structure" } else { "regular" };
                             info!("Deleting {} {} table rows from '{}' (physical: '{}') with row_index values: {:?}", 
@@ -306,6 +306,26 @@ pub fn handle_delete_rows_request(
                                     error!("Failed to execute DELETE batch via daemon for '{}': {:?}", physical_table_name, e);
                                 }
                             }
+
+                            // Comments of deleted rows would otherwise resurface on rows that reuse the index
+                            if let Err(e) = crate::sheets::database::writer::DbWriter::delete_row_comments(
+                                &conn,
+                                &physical_table_name,
+                                &row_index_values,
+                                db_path.file_name().and_then(|n| n.to_str()),
+                                daemon_client.client(),
+                            ) {
+                                warn!("Failed to delete cell comments of deleted rows in '{}': {}", physical_table_name, e);
+                            }
+                            if let Err(e) = crate::sheets::database::writer::DbWriter::delete_row_locks(
+                                &conn,
+                                &physical_table_name,
+                                &row_index_values,
+                                db_path.file_name().and_then(|n| n.to_str()),
+                                daemon_client.client(),
+                            ) {
+                                warn!("Failed to delete locks of deleted rows in '{}': {}", physical_table_name, e);
+                            }
                         }
                         Err(e) => {
                             error!(
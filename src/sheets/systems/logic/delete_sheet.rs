@@ -152,6 +152,7 @@ pub fn handle_delete_request(
                                     Ok(_conn) => {
                                         let meta_table = format!("{}_Metadata", child_name);
                                         let ai_groups_table = format!("{}_AIGroups", child_name);
+                                        let comments_table = format!("{}_Comments", child_name);
                                         
                                         let statements = vec![
                                             crate::sheets::database::daemon_client::Statement {
@@ -166,6 +167,10 @@ pub fn handle_delete_request(
                                                 sql: format!("DROP TABLE IF EXISTS \"{}\"", ai_groups_table),
                                                 params: vec![],
                                             },
+                                            crate::sheets::database::daemon_client::Statement {
+                                                sql: format!("DROP TABLE IF EXISTS \"{}\"", comments_table),
+                                                params: vec![],
+                                            },
                                             crate::sheets::database::daemon_client::Statement {
                                                 sql: "DELETE FROM _Metadata WHERE table_name = ?".to_string(),
                                                 params: vec![serde_json::json!(child_name)],
@@ -293,6 +298,7 @@ pub fn handle_delete_request(
                             Ok(_conn) => {
                                 let meta_table = format!("{}_Metadata", sheet_name);
                                 let ai_groups_table = format!("{}_AIGroups", sheet_name);
+                                let comments_table = format!("{}_Comments", sheet_name);
                                 
                                 let statements = vec![
                                     crate::sheets::database::daemon_client::Statement {
@@ -307,6 +313,10 @@ pub fn handle_delete_request(
                                         sql: format!("DROP TABLE IF EXISTS \"{}\"", ai_groups_table),
                                         params: vec![],
                                     },
+                                    crate::sheets::database::daemon_client::Statement {
+                                        sql: format!("DROP TABLE IF EXISTS \"{}\"", comments_table),
+                                        params: vec![],
+                                    },
                                     crate::sheets::database::daemon_client::Statement {
                                         sql: "DELETE FROM _Metadata WHERE table_name = ?".to_string(),
                                         params: vec![serde_json::json!(sheet_name)],
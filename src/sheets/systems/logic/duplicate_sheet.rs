@@ -0,0 +1,193 @@
+// src/sheets/systems/logic/duplicate_sheet.rs
+use crate::{
+    sheets::{
+        database::{daemon_resource::SharedDaemonClient, reader::DbReader, writer::DbWriter},
+        events::{DuplicateSheetEvent, SheetDataModifiedInRegistryEvent, SheetOperationFeedback},
+        resources::SheetRegistry,
+        systems::io::{save::save_single_sheet, validator},
+    },
+    ui::elements::editor::state::EditorWindowState,
+};
+use bevy::prelude::*;
+use rusqlite::Connection;
+
+/// Default name offered for a copy of `source`: "{source}_copy", then "{source}_copy2", ...
+/// skipping names for which `is_taken` returns true.
+pub fn suggest_duplicate_name(source: &str, is_taken: impl Fn(&str) -> bool) -> String {
+    let base = format!("{}_copy", source);
+    if !is_taken(&base) {
+        return base;
+    }
+    (2..)
+        .map(|n| format!("{}{}", base, n))
+        .find(|candidate| !is_taken(candidate))
+        .unwrap_or(base)
+}
+
+/// Copies a sheet under a new name in the same category. DB-backed sheets are copied
+/// table by table (grid, column metadata, AI groups and every Structure child table) with
+/// fresh row_index values; JSON sheets are copied in memory and saved.
+pub fn handle_duplicate_sheet_request(
+    mut events: EventReader<DuplicateSheetEvent>,
+    mut registry: ResMut<SheetRegistry>,
+    mut feedback_writer: EventWriter<SheetOperationFeedback>,
+    mut data_modified_writer: EventWriter<SheetDataModifiedInRegistryEvent>,
+    mut editor_state_opt: Option<ResMut<EditorWindowState>>,
+    daemon_client: Res<SharedDaemonClient>,
+) {
+    for event in events.read() {
+        let category = &event.category;
+        let source_name = event.source_sheet.as_str();
+        let new_name = event.new_sheet_name.trim();
+
+        let mut fail = |msg: String| {
+            error!("{}", msg);
+            feedback_writer.write(SheetOperationFeedback {
+                message: msg,
+                is_error: true,
+            });
+        };
+
+        if let Err(e) = validator::validate_derived_sheet_name(new_name) {
+            fail(format!("Failed to duplicate sheet: Invalid name '{}'. {}", new_name, e));
+            continue;
+        }
+        if registry.get_sheet(category, new_name).is_some() {
+            fail(format!(
+                "Failed to duplicate sheet: Name '{}' already exists in category '{:?}'.",
+                new_name, category
+            ));
+            continue;
+        }
+        let Some(source_data) = registry.get_sheet(category, source_name).cloned() else {
+            fail(format!(
+                "Failed to duplicate sheet: '{:?}/{}' not found.",
+                category, source_name
+            ));
+            continue;
+        };
+
+        let mut copied: Vec<String> = Vec::new();
+        match category {
+            None => {
+                // JSON sheets keep structure data inline, so the grid copy is complete
+                let mut copy = source_data;
+                if let Some(meta) = &mut copy.metadata {
+                    meta.sheet_name = new_name.to_string();
+                    meta.data_filename = format!("{}.json", new_name);
+                }
+                registry.add_or_replace_sheet(None, new_name.to_string(), copy);
+                if let Some(meta) = registry
+                    .get_sheet(category, new_name)
+                    .and_then(|d| d.metadata.clone())
+                {
+                    save_single_sheet(registry.as_ref(), &meta);
+                }
+                copied.push(new_name.to_string());
+            }
+            Some(db_name) => {
+                let db_path = crate::sheets::systems::io::get_default_data_base_path()
+                    .join(format!("{}.db", db_name));
+                let conn = match Connection::open(&db_path) {
+                    Ok(conn) => conn,
+                    Err(e) => {
+                        fail(format!(
+                            "Failed to open DB '{}' to duplicate '{}': {}",
+                            db_path.display(),
+                            source_name,
+                            e
+                        ));
+                        continue;
+                    }
+                };
+                let db_filename = db_path.file_name().and_then(|n| n.to_str());
+                let pairs = match DbWriter::duplicate_table_and_descendants(
+                    &conn,
+                    source_name,
+                    new_name,
+                    db_filename,
+                    daemon_client.client(),
+                ) {
+                    Ok(pairs) => pairs,
+                    Err(e) => {
+                        fail(format!(
+                            "Failed to duplicate '{:?}/{}' as '{}': {}",
+                            category, source_name, new_name, e
+                        ));
+                        continue;
+                    }
+                };
+
+                // Make the daemon's writes visible before reading the copies back
+                let _ = conn.query_row("PRAGMA wal_checkpoint(PASSIVE)", [], |_| Ok(()));
+                for (source_table, copy_table) in &pairs {
+                    let mut loaded = match DbReader::read_sheet(
+                        &conn,
+                        copy_table,
+                        daemon_client.client(),
+                        db_filename,
+                    ) {
+                        Ok(loaded) => loaded,
+                        Err(e) => {
+                            warn!("Failed to load duplicated table '{}': {}", copy_table, e);
+                            continue;
+                        }
+                    };
+                    // Point structure children at the copied parent, not the source
+                    let source_link = registry
+                        .get_sheet(category, source_table)
+                        .and_then(|d| d.metadata.as_ref())
+                        .and_then(|m| m.structure_parent.clone());
+                    if let (Some(meta), Some(mut link)) = (&mut loaded.metadata, source_link) {
+                        if let Some((_, copy_parent)) =
+                            pairs.iter().find(|(src, _)| *src == link.parent_sheet)
+                        {
+                            link.parent_sheet = copy_parent.clone();
+                        }
+                        meta.structure_parent = Some(link);
+                    }
+                    registry.add_or_replace_sheet(category.clone(), copy_table.clone(), loaded);
+                    copied.push(copy_table.clone());
+                }
+            }
+        }
+
+        let msg = format!(
+            "Duplicated sheet '{:?}/{}' as '{}' ({} table(s)).",
+            category,
+            source_name,
+            new_name,
+            copied.len()
+        );
+        info!("{}", msg);
+        feedback_writer.write(SheetOperationFeedback {
+            message: msg,
+            is_error: false,
+        });
+        for sheet_name in copied {
+            data_modified_writer.write(SheetDataModifiedInRegistryEvent {
+                category: category.clone(),
+                sheet_name,
+            });
+        }
+
+        if let Some(editor_state) = editor_state_opt.as_mut() {
+            editor_state.selected_category = category.clone();
+            editor_state.selected_sheet_name = Some(new_name.to_string());
+            editor_state.reset_interaction_modes_and_selections();
+            editor_state.force_filter_recalculation = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn suggested_name_skips_taken_copies() {
+        assert_eq!(suggest_duplicate_name("Items", |_| false), "Items_copy");
+        let taken = ["Items_copy", "Items_copy2"];
+        assert_eq!(suggest_duplicate_name("Items", |n| taken.contains(&n)), "Items_copy3");
+    }
+}
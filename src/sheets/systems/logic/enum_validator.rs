@@ -0,0 +1,117 @@
+// src/sheets/systems/logic/enum_validator.rs
+// Allowed-value checks for columns with an `Enum` validator.
+//
+// Values match their allowed spelling after trimming and ignoring case, and are stored with
+// that spelling. Empty cells are always allowed. AI suggestions that match nothing exactly are
+// snapped to the closest allowed value when the difference is small (a typo or a plural).
+
+use crate::sheets::definitions::ColumnValidator;
+
+/// Allowed values of an `Enum` validator, `None` for any other validator
+pub fn enum_values(validator: &Option<ColumnValidator>) -> Option<&[String]> {
+    match validator {
+        Some(ColumnValidator::Enum(values)) => Some(values),
+        _ => None,
+    }
+}
+
+/// The allowed spelling of `value` (trimmed, case-insensitive), or `None` when it is not allowed
+pub fn canonical_enum_value<'a>(value: &str, allowed: &'a [String]) -> Option<&'a str> {
+    let trimmed = value.trim();
+    allowed
+        .iter()
+        .find(|v| v.as_str() == trimmed)
+        .or_else(|| allowed.iter().find(|v| v.trim().to_lowercase() == trimmed.to_lowercase()))
+        .map(String::as_str)
+}
+
+/// Levenshtein distance over chars
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b_chars.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b_chars.len() + 1];
+        for (j, cb) in b_chars.iter().enumerate() {
+            let substitution = prev[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(prev[j + 1] + 1).min(current[j] + 1);
+        }
+        prev = current;
+    }
+    prev[b_chars.len()]
+}
+
+/// Closest allowed value for `value`: its allowed spelling when it matches, otherwise the
+/// allowed value within a third of its length in edits (at least one). Ties go to the value
+/// listed first. `None` when nothing is that close.
+pub fn nearest_enum_value<'a>(value: &str, allowed: &'a [String]) -> Option<&'a str> {
+    if let Some(exact) = canonical_enum_value(value, allowed) {
+        return Some(exact);
+    }
+    let needle = value.trim().to_lowercase();
+    if needle.is_empty() {
+        return None;
+    }
+    allowed
+        .iter()
+        .map(|v| {
+            let candidate = v.trim().to_lowercase();
+            let limit = (candidate.chars().count().max(needle.chars().count()) / 3).max(1);
+            (v, edit_distance(&needle, &candidate), limit)
+        })
+        .filter(|(_, distance, limit)| distance <= limit)
+        .min_by_key(|(_, distance, _)| *distance)
+        .map(|(v, _, _)| v.as_str())
+}
+
+/// Append `value` (trimmed) to an `Enum` validator's allowed values unless it is already
+/// allowed or empty. Returns whether the list changed.
+pub fn add_enum_value(validator: &mut Option<ColumnValidator>, value: &str) -> bool {
+    let Some(ColumnValidator::Enum(values)) = validator else {
+        return false;
+    };
+    let trimmed = value.trim();
+    if trimmed.is_empty() || canonical_enum_value(trimmed, values).is_some() {
+        return false;
+    }
+    values.push(trimmed.to_string());
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn allowed() -> Vec<String> {
+        vec!["Common".to_string(), "Rare".to_string(), "Legendary".to_string()]
+    }
+
+    #[test]
+    fn values_match_their_allowed_spelling() {
+        let allowed = allowed();
+        assert_eq!(canonical_enum_value(" rare ", &allowed), Some("Rare"));
+        assert_eq!(canonical_enum_value("LEGENDARY", &allowed), Some("Legendary"));
+        assert_eq!(canonical_enum_value("Epic", &allowed), None);
+    }
+
+    #[test]
+    fn suggestions_snap_only_when_close() {
+        let allowed = allowed();
+        assert_eq!(nearest_enum_value("Legendry", &allowed), Some("Legendary"));
+        assert_eq!(nearest_enum_value("rares", &allowed), Some("Rare"));
+        assert_eq!(nearest_enum_value("commons", &allowed), Some("Common"));
+        assert_eq!(nearest_enum_value("Epic", &allowed), None);
+        assert_eq!(nearest_enum_value("", &allowed), None);
+    }
+
+    #[test]
+    fn auto_add_appends_new_values_once() {
+        let mut validator = Some(ColumnValidator::Enum(allowed()));
+        assert!(add_enum_value(&mut validator, " Epic "));
+        assert!(!add_enum_value(&mut validator, "epic"));
+        assert!(!add_enum_value(&mut validator, "  "));
+        assert_eq!(enum_values(&validator).unwrap().last().map(String::as_str), Some("Epic"));
+
+        let mut basic = Some(ColumnValidator::Basic(crate::sheets::definitions::ColumnDataType::String));
+        assert!(!add_enum_value(&mut basic, "Epic"));
+    }
+}
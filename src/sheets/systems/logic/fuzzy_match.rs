@@ -0,0 +1,96 @@
+// src/sheets/systems/logic/fuzzy_match.rs
+// Fuzzy name matching for the quick-open overlay.
+//
+// A query matches when its characters appear in order in the candidate (case-insensitive).
+// Consecutive matched characters and matches at word starts ("_", "-", space, camelCase)
+// score higher, so "itm" ranks "Items" above "InventoryTemplate".
+
+const MATCH_SCORE: i32 = 1;
+const CONTIGUOUS_BONUS: i32 = 5;
+const WORD_START_BONUS: i32 = 3;
+
+fn is_word_start(prev: Option<char>, current: char) -> bool {
+    match prev {
+        None => true,
+        Some(p) => {
+            matches!(p, '_' | '-' | ' ' | '.' | '/') || (p.is_lowercase() && current.is_uppercase())
+        }
+    }
+}
+
+/// Score of `query` as a subsequence of `candidate`, or `None` when it does not match.
+/// An empty query matches everything with score 0.
+pub fn fuzzy_match_score(query: &str, candidate: &str) -> Option<i32> {
+    let mut query_chars = query.chars().filter(|c| !c.is_whitespace()).peekable();
+    let mut score = 0;
+    let mut prev: Option<char> = None;
+    let mut prev_matched = false;
+    for c in candidate.chars() {
+        let Some(&q) = query_chars.peek() else {
+            break;
+        };
+        if c.to_lowercase().eq(q.to_lowercase()) {
+            score += MATCH_SCORE;
+            if prev_matched {
+                score += CONTIGUOUS_BONUS;
+            }
+            if is_word_start(prev, c) {
+                score += WORD_START_BONUS;
+            }
+            query_chars.next();
+            prev_matched = true;
+        } else {
+            prev_matched = false;
+        }
+        prev = Some(c);
+    }
+    query_chars.peek().is_none().then_some(score)
+}
+
+/// Indices of the matching `names`, best first. Ties go to the shorter name, then
+/// alphabetical order.
+pub fn rank_fuzzy_matches(query: &str, names: &[&str]) -> Vec<usize> {
+    let mut scored: Vec<(usize, i32)> = names
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, name)| fuzzy_match_score(query, name).map(|score| (idx, score)))
+        .collect();
+    scored.sort_by(|(a_idx, a_score), (b_idx, b_score)| {
+        b_score
+            .cmp(a_score)
+            .then_with(|| names[*a_idx].len().cmp(&names[*b_idx].len()))
+            .then_with(|| names[*a_idx].to_lowercase().cmp(&names[*b_idx].to_lowercase()))
+    });
+    scored.into_iter().map(|(idx, _)| idx).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subsequence_is_required() {
+        assert!(fuzzy_match_score("itm", "Items").is_some());
+        assert!(fuzzy_match_score("ITM", "items").is_some());
+        assert!(fuzzy_match_score("mti", "Items").is_none());
+        assert_eq!(fuzzy_match_score("", "Items"), Some(0));
+    }
+
+    #[test]
+    fn contiguous_and_word_start_matches_rank_first() {
+        let names = ["InventoryTemplate", "Items", "ShipItems", "Weapons"];
+        assert_eq!(rank_fuzzy_matches("item", &names), vec![1, 2, 0]);
+        // A contiguous "It" beats scattered letters
+        assert_eq!(rank_fuzzy_matches("it", &names)[0], 1);
+        assert!(
+            fuzzy_match_score("ship", "ShipItems").unwrap()
+                > fuzzy_match_score("ship", "SmallHullPiping").unwrap()
+        );
+    }
+
+    #[test]
+    fn ties_prefer_shorter_then_alphabetical_names() {
+        let names = ["Bravo", "Alpha", "Al"];
+        assert_eq!(rank_fuzzy_matches("", &names), vec![2, 1, 0]);
+    }
+}
@@ -0,0 +1,66 @@
+// src/sheets/systems/logic/grid_navigation.rs
+//! Spreadsheet-style movement of the active cell across the displayed grid
+
+/// One keyboard step of the active cell
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridMove {
+    Up,
+    Down,
+    Left,
+    Right,
+    /// Tab: right, wrapping to the first column of the next row
+    Next,
+    /// Shift+Tab: left, wrapping to the last column of the previous row
+    Previous,
+}
+
+/// Cell reached from `current` (grid row, column index) by `step`. `rows` and `columns` are
+/// the displayed rows and the navigable columns, both in display order. Arrow moves stop at
+/// the edges; `None` when there is nowhere to go or `current` is not displayed.
+pub fn next_grid_cell(
+    rows: &[usize],
+    columns: &[usize],
+    current: (usize, usize),
+    step: GridMove,
+) -> Option<(usize, usize)> {
+    let row_pos = rows.iter().position(|&r| r == current.0)?;
+    let col_pos = columns.iter().position(|&c| c == current.1)?;
+    let (row_pos, col_pos) = match step {
+        GridMove::Up => (row_pos.checked_sub(1)?, col_pos),
+        GridMove::Down => (row_pos + 1, col_pos),
+        GridMove::Left => (row_pos, col_pos.checked_sub(1)?),
+        GridMove::Right => (row_pos, col_pos + 1),
+        GridMove::Next if col_pos + 1 < columns.len() => (row_pos, col_pos + 1),
+        GridMove::Next => (row_pos + 1, 0),
+        GridMove::Previous if col_pos > 0 => (row_pos, col_pos - 1),
+        GridMove::Previous => (row_pos.checked_sub(1)?, columns.len() - 1),
+    };
+    Some((*rows.get(row_pos)?, *columns.get(col_pos)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Filtered rows shown in display order, with technical column 0 left out
+    const ROWS: [usize; 3] = [4, 1, 7];
+    const COLUMNS: [usize; 3] = [1, 2, 5];
+
+    #[test]
+    fn arrows_follow_display_order_and_stop_at_edges() {
+        assert_eq!(next_grid_cell(&ROWS, &COLUMNS, (1, 2), GridMove::Down), Some((7, 2)));
+        assert_eq!(next_grid_cell(&ROWS, &COLUMNS, (1, 2), GridMove::Up), Some((4, 2)));
+        assert_eq!(next_grid_cell(&ROWS, &COLUMNS, (1, 2), GridMove::Right), Some((1, 5)));
+        assert_eq!(next_grid_cell(&ROWS, &COLUMNS, (7, 2), GridMove::Down), None);
+        assert_eq!(next_grid_cell(&ROWS, &COLUMNS, (4, 1), GridMove::Left), None);
+        assert_eq!(next_grid_cell(&ROWS, &COLUMNS, (4, 0), GridMove::Right), None);
+    }
+
+    #[test]
+    fn tab_wraps_between_rows() {
+        assert_eq!(next_grid_cell(&ROWS, &COLUMNS, (4, 5), GridMove::Next), Some((1, 1)));
+        assert_eq!(next_grid_cell(&ROWS, &COLUMNS, (1, 1), GridMove::Previous), Some((4, 5)));
+        assert_eq!(next_grid_cell(&ROWS, &COLUMNS, (7, 5), GridMove::Next), None);
+        assert_eq!(next_grid_cell(&ROWS, &COLUMNS, (4, 1), GridMove::Previous), None);
+    }
+}
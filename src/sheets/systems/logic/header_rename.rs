@@ -0,0 +1,168 @@
+// src/sheets/systems/logic/header_rename.rs
+//! Batch transformation of column display names (find/replace plus prefix/suffix).
+//!
+//! Only the UI-only display name changes; physical column names are untouched.
+//! Technical, metadata and deleted columns are never renamed.
+
+use crate::sheets::definitions::SheetMetadata;
+use std::collections::HashSet;
+
+/// Transformation applied to every eligible header
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HeaderTransform {
+    /// Text to find (case-sensitive); empty disables find/replace
+    pub find: String,
+    pub replace: String,
+    pub prefix: String,
+    pub suffix: String,
+}
+
+impl HeaderTransform {
+    pub fn apply(&self, name: &str) -> String {
+        let replaced = if self.find.is_empty() {
+            name.to_string()
+        } else {
+            name.replace(&self.find, &self.replace)
+        };
+        format!("{}{}{}", self.prefix, replaced, self.suffix)
+    }
+}
+
+/// One row of the rename preview
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeaderRenamePreview {
+    pub col_index: usize,
+    pub old_name: String,
+    pub new_name: String,
+    /// The new name is empty or collides (case-insensitive) with another column's final name
+    pub conflict: bool,
+}
+
+impl HeaderRenamePreview {
+    pub fn is_change(&self) -> bool {
+        self.old_name != self.new_name
+    }
+}
+
+/// Columns whose display name may be batch-renamed
+pub fn is_header_renamable(metadata: &SheetMetadata, col_index: usize) -> bool {
+    metadata.columns.get(col_index).map_or(false, |c| {
+        !c.deleted
+            && !SheetMetadata::is_technical_column_header(&c.header)
+            && !SheetMetadata::is_metadata_column_header(&c.header)
+    })
+}
+
+/// Preview the transformed names of all renamable columns not in `excluded`.
+/// Conflicts are checked against the final names of every live column.
+pub fn preview_header_renames(
+    metadata: &SheetMetadata,
+    transform: &HeaderTransform,
+    excluded: &HashSet<usize>,
+) -> Vec<HeaderRenamePreview> {
+    let final_names: Vec<Option<String>> = metadata
+        .columns
+        .iter()
+        .enumerate()
+        .map(|(idx, col)| {
+            if col.deleted {
+                None
+            } else if is_header_renamable(metadata, idx) && !excluded.contains(&idx) {
+                Some(transform.apply(col.display_name()))
+            } else {
+                Some(col.display_name().to_string())
+            }
+        })
+        .collect();
+
+    metadata
+        .columns
+        .iter()
+        .enumerate()
+        .filter(|(idx, _)| is_header_renamable(metadata, *idx))
+        .map(|(idx, col)| {
+            let new_name = final_names[idx].clone().unwrap_or_default();
+            let trimmed = new_name.trim();
+            let duplicate = final_names.iter().enumerate().any(|(other, name)| {
+                other != idx
+                    && name
+                        .as_deref()
+                        .map_or(false, |n| n.trim().eq_ignore_ascii_case(trimmed))
+            });
+            HeaderRenamePreview {
+                col_index: idx,
+                old_name: col.display_name().to_string(),
+                new_name,
+                conflict: trimmed.is_empty() || duplicate,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sheets::definitions::{ColumnDataType, ColumnDefinition};
+
+    fn metadata_with(headers: &[&str]) -> SheetMetadata {
+        let mut meta = SheetMetadata::create_generic(
+            "Items".to_string(),
+            "Items.json".to_string(),
+            headers.len(),
+            None,
+        );
+        for (col, header) in meta.columns.iter_mut().zip(headers) {
+            *col = ColumnDefinition::new_basic(header.to_string(), ColumnDataType::String);
+        }
+        meta
+    }
+
+    #[test]
+    fn transform_replaces_then_adds_prefix_and_suffix() {
+        let t = HeaderTransform {
+            find: "_".to_string(),
+            replace: " ".to_string(),
+            prefix: "[".to_string(),
+            suffix: "]".to_string(),
+        };
+        assert_eq!(t.apply("item_name"), "[item name]");
+    }
+
+    #[test]
+    fn preview_skips_technical_columns_and_excluded() {
+        let meta = metadata_with(&["row_index", "name", "cost"]);
+        let t = HeaderTransform {
+            prefix: "x_".to_string(),
+            ..Default::default()
+        };
+        let excluded: HashSet<usize> = [2].into_iter().collect();
+        let preview = preview_header_renames(&meta, &t, &excluded);
+        let renamed: Vec<(usize, &str)> = preview
+            .iter()
+            .filter(|p| p.is_change())
+            .map(|p| (p.col_index, p.new_name.as_str()))
+            .collect();
+        assert_eq!(renamed, vec![(1, "x_name")]);
+        assert!(preview.iter().all(|p| p.col_index != 0));
+    }
+
+    #[test]
+    fn preview_flags_collisions_and_empty_names() {
+        let meta = metadata_with(&["name_a", "name_b", "a"]);
+        let t = HeaderTransform {
+            find: "name_".to_string(),
+            ..Default::default()
+        };
+        let preview = preview_header_renames(&meta, &t, &HashSet::new());
+        // "name_a" -> "a" collides with the existing "a"
+        assert!(preview.iter().find(|p| p.col_index == 0).unwrap().conflict);
+        assert!(!preview.iter().find(|p| p.col_index == 1).unwrap().conflict);
+
+        let erase = HeaderTransform {
+            find: "a".to_string(),
+            ..Default::default()
+        };
+        let preview = preview_header_renames(&meta, &erase, &HashSet::new());
+        assert!(preview.iter().find(|p| p.col_index == 2).unwrap().conflict);
+    }
+}
@@ -0,0 +1,397 @@
+// src/sheets/systems/logic/merge_rows.rs
+// "Merge rows": N selected rows become one. The topmost row survives; every mergeable
+// column takes the first non-empty value among the rows unless the user picked one of
+// the conflicting values, then the other rows are deleted.
+//
+// DB sheets: the survivor's cells are updated, the structure child rows of the merged
+// rows are re-parented under the survivor (level 1 only; deeper levels hang off those
+// child rows and follow automatically) and the merged rows are deleted, all in one
+// daemon batch. The survivor keeps its row_index, so its own child rows and comments
+// stay attached; the comments and locks of the deleted rows go with them. JSON sheets
+// have no structure tables to look after.
+//
+// Locked rows are never merged. The merge itself is not undoable, and recorded cell
+// edits of the deleted rows are dropped so Undo cannot try to write into them.
+
+use crate::sheets::{
+    database::daemon_client::{DaemonClient, Statement},
+    database::writer::DbWriter,
+    definitions::{ColumnValidator, SheetMetadata},
+    events::{MergeRowsEvent, SheetDataModifiedInRegistryEvent, SheetOperationFeedback},
+    resources::{EditHistory, SheetRegistry},
+    systems::io::save::save_single_sheet,
+    systems::logic::update_column_validator::structure_naming::structure_child_table_name,
+    systems::ui_handlers::sheet_handlers::reload_sheet_tree_from_db,
+};
+use crate::ui::elements::editor::state::EditorWindowState;
+use crate::sheets::systems::logic::row_locks::ensure_locked_rows_loaded;
+use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+/// A column whose merged rows hold different non-empty values
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeConflict {
+    pub col_index: usize,
+    /// Distinct non-empty values in row order
+    pub values: Vec<String>,
+}
+
+/// Columns whose values are merged: technical columns keep the survivor's values and
+/// structure columns are merged through their child rows instead
+pub fn mergeable_columns(metadata: &SheetMetadata) -> Vec<usize> {
+    metadata
+        .columns
+        .iter()
+        .enumerate()
+        .filter(|(_, c)| {
+            !c.deleted
+                && !SheetMetadata::is_technical_column_header(&c.header)
+                && !matches!(c.validator, Some(ColumnValidator::Structure))
+        })
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+/// Distinct non-empty values of a column in row order (surrounding whitespace ignored)
+fn distinct_values(rows: &[Vec<String>], col_index: usize) -> Vec<String> {
+    let mut values: Vec<String> = Vec::new();
+    for value in rows.iter().filter_map(|row| row.get(col_index)) {
+        let trimmed = value.trim();
+        if !trimmed.is_empty() && !values.iter().any(|v| v.trim() == trimmed) {
+            values.push(value.clone());
+        }
+    }
+    values
+}
+
+/// Columns of `columns` the user has to decide on
+pub fn merge_conflicts(rows: &[Vec<String>], columns: &[usize]) -> Vec<MergeConflict> {
+    columns
+        .iter()
+        .filter_map(|&col_index| {
+            let values = distinct_values(rows, col_index);
+            (values.len() > 1).then_some(MergeConflict { col_index, values })
+        })
+        .collect()
+}
+
+/// The merged row: the first row with each of `columns` replaced by the chosen value
+/// (`choices`, keyed by column) or else the first non-empty value among the rows.
+/// Columns outside `columns` keep the first row's values.
+pub fn merge_row_values(
+    rows: &[Vec<String>],
+    columns: &[usize],
+    choices: &HashMap<usize, String>,
+) -> Vec<String> {
+    let Some(first) = rows.first() else {
+        return Vec::new();
+    };
+    let mut merged = first.clone();
+    for &col_index in columns {
+        let value = choices
+            .get(&col_index)
+            .cloned()
+            .or_else(|| distinct_values(rows, col_index).into_iter().next());
+        if let (Some(value), Some(cell)) = (value, merged.get_mut(col_index)) {
+            *cell = value;
+        }
+    }
+    merged
+}
+
+/// Write the merge to the database: survivor cells, child re-parenting and deletes in one
+/// batch. Returns the number of child rows moved under the survivor.
+fn merge_rows_in_db(
+    metadata: &SheetMetadata,
+    sheet_name: &str,
+    survivor: (i64, &[String]),
+    merged: &[String],
+    removed: &[i64],
+    daemon_client: &DaemonClient,
+) -> Result<usize, String> {
+    let (survivor_index, survivor_row) = survivor;
+    let Some(cat) = metadata.category.as_ref() else {
+        return Err("not a database sheet".to_string());
+    };
+    let db_path = crate::sheets::systems::io::get_default_data_base_path().join(format!("{}.db", cat));
+    let conn = crate::sheets::database::connection::DbConnection::open_existing(&db_path)
+        .map_err(|e| e.to_string())?;
+    let db_filename = db_path.file_name().and_then(|n| n.to_str());
+
+    let mut statements: Vec<Statement> = Vec::new();
+    for (col_index, column) in metadata.columns.iter().enumerate() {
+        let (Some(before), Some(after)) = (survivor_row.get(col_index), merged.get(col_index)) else {
+            continue;
+        };
+        if before != after {
+            statements.push(Statement {
+                sql: format!(
+                    "UPDATE \"{}\" SET \"{}\" = ? WHERE row_index = ?",
+                    sheet_name, column.header
+                ),
+                params: vec![
+                    serde_json::Value::String(after.clone()),
+                    serde_json::Value::Number(survivor_index.into()),
+                ],
+            });
+        }
+    }
+
+    let mut moved_children = 0;
+    for column in metadata
+        .columns
+        .iter()
+        .filter(|c| !c.deleted && matches!(c.validator, Some(ColumnValidator::Structure)))
+    {
+        let child_table = structure_child_table_name(sheet_name, &column.header);
+        for old_parent in removed {
+            let count: i64 = conn
+                .query_row(
+                    &format!("SELECT COUNT(*) FROM \"{}\" WHERE parent_key = ?", child_table),
+                    [old_parent.to_string()],
+                    |row| row.get(0),
+                )
+                .unwrap_or(0);
+            if count == 0 {
+                continue;
+            }
+            moved_children += count as usize;
+            // parent_key is TEXT
+            statements.push(Statement {
+                sql: format!("UPDATE \"{}\" SET parent_key = ? WHERE parent_key = ?", child_table),
+                params: vec![
+                    serde_json::Value::String(survivor_index.to_string()),
+                    serde_json::Value::String(old_parent.to_string()),
+                ],
+            });
+        }
+    }
+
+    for row_index in removed {
+        statements.push(Statement {
+            sql: format!("DELETE FROM \"{}\" WHERE row_index = ?", sheet_name),
+            params: vec![serde_json::json!(row_index)],
+        });
+    }
+
+    let response = daemon_client.exec_batch(statements, db_filename)?;
+    if let Some(e) = response.error {
+        return Err(format!("{:?}", e));
+    }
+
+    // Comments and locks of the merged rows would otherwise resurface on rows that reuse the index
+    if let Err(e) = DbWriter::delete_row_comments(&conn, sheet_name, removed, db_filename, daemon_client) {
+        warn!("Failed to delete cell comments of merged rows in '{}': {}", sheet_name, e);
+    }
+    if let Err(e) = DbWriter::delete_row_locks(&conn, sheet_name, removed, db_filename, daemon_client) {
+        warn!("Failed to delete locks of merged rows in '{}': {}", sheet_name, e);
+    }
+    Ok(moved_children)
+}
+
+pub fn handle_merge_rows_request(
+    mut events: EventReader<MergeRowsEvent>,
+    mut registry: ResMut<SheetRegistry>,
+    mut feedback_writer: EventWriter<SheetOperationFeedback>,
+    mut data_modified_writer: EventWriter<SheetDataModifiedInRegistryEvent>,
+    mut editor_state: Option<ResMut<EditorWindowState>>,
+    mut edit_history: ResMut<EditHistory>,
+    daemon_client: Res<crate::sheets::database::daemon_resource::SharedDaemonClient>,
+) {
+    for event in events.read() {
+        let category = &event.category;
+        let sheet_name = &event.sheet_name;
+        let mut fail = |msg: String| {
+            warn!("{}", msg);
+            feedback_writer.write(SheetOperationFeedback {
+                message: msg,
+                is_error: true,
+            });
+        };
+
+        let mut row_positions = event.row_indices.clone();
+        row_positions.sort_unstable();
+        row_positions.dedup();
+        if row_positions.len() < 2 {
+            fail(format!("Cannot merge rows of '{}': select at least two rows.", sheet_name));
+            continue;
+        }
+        ensure_locked_rows_loaded(&mut registry, category, sheet_name);
+        let Some(sheet) = registry.get_sheet(category, sheet_name) else {
+            fail(format!("Cannot merge rows: sheet '{:?}/{}' not found.", category, sheet_name));
+            continue;
+        };
+        let Some(metadata) = sheet.metadata.clone() else {
+            fail(format!("Cannot merge rows in '{:?}/{}': metadata missing.", category, sheet_name));
+            continue;
+        };
+        // Merging overwrites the survivor and deletes the others, so no locked row may take part
+        let locked: Vec<String> = row_positions
+            .iter()
+            .filter(|&&pos| registry.is_grid_row_locked(category, sheet_name, pos))
+            .map(|pos| (pos + 1).to_string())
+            .collect();
+        if !locked.is_empty() {
+            fail(format!(
+                "Cannot merge rows of '{}': row(s) {} are locked; unlock them first.",
+                sheet_name,
+                locked.join(", ")
+            ));
+            continue;
+        }
+        let Some(rows) = row_positions
+            .iter()
+            .map(|&idx| sheet.grid.get(idx).cloned())
+            .collect::<Option<Vec<Vec<String>>>>()
+        else {
+            fail(format!(
+                "Cannot merge rows of '{}': the selection no longer matches the sheet.",
+                sheet_name
+            ));
+            continue;
+        };
+
+        let columns = mergeable_columns(&metadata);
+        let conflicts = merge_conflicts(&rows, &columns).len();
+        let merged = merge_row_values(&rows, &columns, &event.column_choices);
+        let survivor_pos = row_positions[0];
+
+        let mut moved_children = 0;
+        if metadata.category.is_none() {
+            if let Some(sheet) = registry.get_sheet_mut(category, sheet_name) {
+                sheet.grid[survivor_pos] = merged;
+                for &pos in row_positions[1..].iter().rev() {
+                    sheet.grid.remove(pos);
+                    if pos < sheet.row_indices.len() {
+                        sheet.row_indices.remove(pos);
+                    }
+                }
+            }
+            save_single_sheet(registry.as_ref(), &metadata);
+            // JSON records are addressed by grid position, which the deletions shifted
+            edit_history.clear_sheet(category, sheet_name);
+        } else {
+            if sheet.row_indices.len() != sheet.grid.len() {
+                fail(format!(
+                    "Cannot merge rows in '{}': row indices are out of sync with the grid; reload the sheet and retry.",
+                    sheet_name
+                ));
+                continue;
+            }
+            let survivor_index = sheet.row_indices[survivor_pos];
+            let removed: Vec<i64> = row_positions[1..]
+                .iter()
+                .map(|&pos| sheet.row_indices[pos])
+                .collect();
+            let result = merge_rows_in_db(
+                &metadata,
+                &metadata.sheet_name,
+                (survivor_index, &rows[0]),
+                &merged,
+                &removed,
+                daemon_client.client(),
+            );
+            match reload_sheet_tree_from_db(&mut registry, daemon_client.client(), category, sheet_name) {
+                Ok(tables) => {
+                    for table in tables.into_iter().filter(|t| t != sheet_name) {
+                        data_modified_writer.write(SheetDataModifiedInRegistryEvent {
+                            category: category.clone(),
+                            sheet_name: table,
+                        });
+                    }
+                }
+                Err(e) => error!("Reload after merging rows in '{}' failed: {}", sheet_name, e),
+            }
+            match result {
+                Ok(children) => {
+                    moved_children = children;
+                    let removed: HashSet<i64> = removed.into_iter().collect();
+                    edit_history.drop_row_indices(category, sheet_name, &removed);
+                }
+                Err(e) => {
+                    fail(format!("Failed to merge rows in '{}': {}", sheet_name, e));
+                    data_modified_writer.write(SheetDataModifiedInRegistryEvent {
+                        category: category.clone(),
+                        sheet_name: sheet_name.clone(),
+                    });
+                    continue;
+                }
+            }
+        }
+
+        if let Some(state) = editor_state.as_mut() {
+            state.parent_lineage_cache.clear();
+            state.ai_selected_rows.clear();
+            state.force_filter_recalculation = true;
+        }
+        data_modified_writer.write(SheetDataModifiedInRegistryEvent {
+            category: category.clone(),
+            sheet_name: sheet_name.clone(),
+        });
+
+        let mut message = format!(
+            "Merged {} rows of '{}' into row {}",
+            row_positions.len(),
+            sheet_name,
+            survivor_pos + 1
+        );
+        if conflicts > 0 {
+            message.push_str(&format!(", {} conflicting column(s) resolved", conflicts));
+        }
+        if moved_children > 0 {
+            message.push_str(&format!(", {} structure row(s) moved", moved_children));
+        }
+        message.push('.');
+        info!("{}", message);
+        feedback_writer.write(SheetOperationFeedback {
+            message,
+            is_error: false,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(values: &[&str]) -> Vec<String> {
+        values.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn first_non_empty_value_wins_and_conflicts_are_reported() {
+        // row_index, Name, Damage, Notes
+        let rows = vec![
+            row(&["7", "Sword", "", "sharp"]),
+            row(&["3", "Sword ", "12", ""]),
+            row(&["2", "", "14", "old"]),
+        ];
+        let columns = [1, 2, 3];
+
+        // "Sword" and "Sword " are the same value
+        let conflicts = merge_conflicts(&rows, &columns);
+        assert_eq!(
+            conflicts,
+            vec![
+                MergeConflict { col_index: 2, values: row(&["12", "14"]) },
+                MergeConflict { col_index: 3, values: row(&["sharp", "old"]) },
+            ]
+        );
+
+        let merged = merge_row_values(&rows, &columns, &HashMap::new());
+        assert_eq!(merged, row(&["7", "Sword", "12", "sharp"]));
+
+        let choices = HashMap::from([(2, "14".to_string())]);
+        assert_eq!(
+            merge_row_values(&rows, &columns, &choices),
+            row(&["7", "Sword", "14", "sharp"])
+        );
+    }
+
+    #[test]
+    fn columns_outside_the_merge_keep_the_first_row() {
+        let rows = vec![row(&["7", ""]), row(&["3", "x"])];
+        assert_eq!(merge_row_values(&rows, &[], &HashMap::new()), row(&["7", ""]));
+        assert!(merge_row_values(&[], &[0], &HashMap::new()).is_empty());
+    }
+}
@@ -354,6 +354,12 @@ fn create_structure_sheet_metadata(
             ai_include_in_send: None,
             deleted: false,
             hidden: false, // Legacy, will be filtered by reader/writer
+            trim_numeric_zeros: false,
+            ai_read_only: false,
+            multiline: false,
+            enum_auto_add: false,
+            numeric_range: None,
+            default_value: None,
             width: None,
             structure_schema: None,
             structure_column_order: None,
@@ -371,6 +377,12 @@ fn create_structure_sheet_metadata(
             ai_include_in_send: None,
             deleted: false,
             hidden: false, // Legacy, will be filtered by reader/writer
+            trim_numeric_zeros: false,
+            ai_read_only: false,
+            multiline: false,
+            enum_auto_add: false,
+            numeric_range: None,
+            default_value: None,
             width: None,
             structure_schema: None,
             structure_column_order: None,
@@ -393,6 +405,12 @@ fn create_structure_sheet_metadata(
                 ai_include_in_send: None,
                 deleted: false,
                 hidden: false, // User-defined schema field
+                trim_numeric_zeros: false,
+                ai_read_only: false,
+                multiline: false,
+                enum_auto_add: false,
+                numeric_range: None,
+                default_value: None,
                 width: None,
                 structure_schema: None,
                 structure_column_order: None,
@@ -3,56 +3,92 @@
 pub mod add_column;
 pub mod add_row;
 pub mod reorder_column;
+pub mod row_locks;
+pub mod row_selection;
+pub mod sort_rows;
 pub mod categories;
 pub mod cell_background_logic;
+pub mod cell_comments;
 pub mod cell_validator_logic;
 pub mod clipboard;
+pub mod column_defaults;
+pub mod column_filter;
 pub mod create_sheet;
+pub mod date_values;
 pub mod delete_columns;
 pub mod delete_rows;
 pub mod delete_sheet;
+pub mod duplicate_sheet;
+pub mod enum_validator;
+pub mod fuzzy_match;
+pub mod grid_navigation;
+pub mod header_rename;
 pub mod lineage_helpers;
+pub mod merge_rows;
 pub mod migrate_inline_structures;
 pub mod move_sheet;
+pub mod random_picker;
 pub mod rename_sheet;
+pub mod revert_sheet;
+pub mod sheet_diff;
+pub mod sheet_find;
 pub mod structure_preview_logic;
+pub mod structure_search;
+pub mod structure_tree;
+pub mod summarizer;
 pub mod sync_structure;
+pub mod toggle_column_hidden;
+pub mod unique_validator;
 pub mod update_cell;
 pub mod update_column_name;
 pub mod update_column_validator;
+pub mod update_column_width;
 pub mod update_render_cache;
 
 pub use add_column::handle_add_column_request;
 pub use add_row::handle_add_row_request;
 pub use add_row::handle_add_rows_batch_request;
+pub use add_row::handle_duplicate_row_request;
 pub use add_row::handle_create_ai_schema_group;
 pub use add_row::handle_delete_ai_schema_group;
 pub use add_row::handle_rename_ai_schema_group;
+pub use add_row::handle_reorder_ai_schema_group;
 pub use add_row::handle_select_ai_schema_group;
+pub use add_row::handle_set_ai_schema_group_allow_rows;
+pub use add_row::handle_set_ai_structure_skip;
 pub use add_row::handle_toggle_ai_row_generation;
 pub use add_row::handle_update_ai_send_schema;
 pub use add_row::handle_update_ai_structure_send;
 pub use add_row::handle_update_column_ai_include;
 pub use reorder_column::handle_reorder_column_request;
+pub use row_locks::{handle_row_lock_requests, refresh_locked_rows};
+pub use sort_rows::handle_sort_sheet_by_column;
 pub use categories::{
     handle_create_category_request, handle_delete_category_request, handle_rename_category_request,
+    handle_set_category_color_request,
 };
 pub use cell_background_logic::determine_cell_background_color;
+pub use cell_comments::{handle_cell_comment_requests, refresh_cell_comments_cache};
 pub use cell_validator_logic::{
     determine_effective_validation_state, is_column_ai_included,
     is_structure_column_ai_included, prefetch_linked_column_values,
 };
-pub use clipboard::{handle_copy_cell, handle_paste_cell};
+pub use clipboard::{handle_copy_cell, handle_paste_block, handle_paste_cell};
 pub use create_sheet::handle_create_new_sheet_request;
 pub use delete_columns::handle_delete_columns_request;
 pub use delete_rows::handle_delete_rows_request;
 pub use delete_sheet::handle_delete_request;
+pub use duplicate_sheet::handle_duplicate_sheet_request;
+pub use merge_rows::handle_merge_rows_request;
 pub use migrate_inline_structures::run_inline_structure_migration_once;
 pub use move_sheet::handle_move_sheet_to_category_request;
 pub use rename_sheet::handle_rename_request;
+pub use revert_sheet::handle_revert_sheet_from_json;
 pub use structure_preview_logic::{generate_structure_preview, generate_structure_preview_from_rows_with_headers};
 pub use sync_structure::handle_sync_virtual_structure_sheet;
-pub use update_cell::handle_cell_update;
+pub use toggle_column_hidden::handle_toggle_column_hidden;
+pub use update_cell::{handle_cell_batch_update, handle_cell_update, handle_find_replace_in_column};
 pub use update_column_name::handle_update_column_name;
 pub use update_column_validator::{handle_structure_table_recreation, handle_update_column_validator};
+pub use update_column_width::{flush_column_width_saves, handle_update_column_width};
 pub use update_render_cache::handle_sheet_render_cache_update;
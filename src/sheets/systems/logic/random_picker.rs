@@ -0,0 +1,224 @@
+// src/sheets/systems/logic/random_picker.rs
+//! Row weighting for the Toybox random picker.
+//!
+//! Each configured weight column contributes `(|value| * multiplier) ^ power`, where a
+//! negative stored exponent `e` means a root (`-2` -> power `0.5`). Rows whose weight cells
+//! are all missing or zero fall back to weight 1, as does every row when no weight column
+//! is configured.
+//!
+//! Draws take the RNG as a parameter so a seeded `StdRng` replays the same sequence.
+
+use rand::Rng;
+
+/// Weight of one row for the configured weight columns (exponents/multipliers default to 1)
+pub fn row_weight(
+    row: &[String],
+    weight_cols: &[usize],
+    exponents: &[f64],
+    multipliers: &[f64],
+) -> f64 {
+    let mut wsum = 0.0f64;
+    for (wi, &wc) in weight_cols.iter().enumerate() {
+        let Some(n) = row.get(wc).and_then(|cell| cell.trim().parse::<f64>().ok()) else {
+            continue;
+        };
+        let exp = exponents.get(wi).copied().unwrap_or(1.0);
+        let mult = multipliers.get(wi).copied().unwrap_or(1.0);
+        let applied_power = if exp < 0.0 { 1.0 / (-exp) } else { exp };
+        wsum += (n.abs() * mult).powf(applied_power);
+    }
+    if weight_cols.is_empty() || wsum == 0.0 {
+        1.0
+    } else {
+        wsum
+    }
+}
+
+/// Candidate rows (non-empty result cell) with their weights, in grid order
+pub fn weighted_candidates(
+    grid: &[Vec<String>],
+    result_col: usize,
+    weight_cols: &[usize],
+    exponents: &[f64],
+    multipliers: &[f64],
+) -> Vec<(usize, f64)> {
+    grid.iter()
+        .enumerate()
+        .filter(|(_, row)| row.get(result_col).map_or(false, |v| !v.trim().is_empty()))
+        .map(|(r_idx, row)| (r_idx, row_weight(row, weight_cols, exponents, multipliers)))
+        .collect()
+}
+
+/// Key under which a drawn grid row is remembered: its database row_index, which survives
+/// sorting, inserts and deletes, or the grid position for sheets without row indices
+pub fn row_pick_key(row_indices: &[i64], grid_row: usize) -> i64 {
+    row_indices.get(grid_row).copied().unwrap_or(grid_row as i64)
+}
+
+/// Draw `n` values by weight from `(row, weight, value)` candidates.
+/// Without replacement each candidate is drawn at most once, so asking for more than there
+/// are returns every candidate exactly once (in drawn order).
+pub fn draw_weighted<R: Rng + ?Sized>(
+    candidates: &[(usize, f64, String)],
+    n: usize,
+    with_replacement: bool,
+    rng: &mut R,
+) -> Vec<String> {
+    draw_weighted_positions(candidates, n, with_replacement, rng)
+        .into_iter()
+        .map(|pos| candidates[pos].2.clone())
+        .collect()
+}
+
+/// Like `draw_weighted`, but returns positions into `candidates` so callers can tell
+/// which rows were drawn
+pub fn draw_weighted_positions<R: Rng + ?Sized>(
+    candidates: &[(usize, f64, String)],
+    n: usize,
+    with_replacement: bool,
+    rng: &mut R,
+) -> Vec<usize> {
+    let mut pool: Vec<(usize, &(usize, f64, String))> = candidates.iter().enumerate().collect();
+    let mut drawn = Vec::with_capacity(n.min(candidates.len()));
+    while drawn.len() < n && !pool.is_empty() {
+        let weights: Vec<&(usize, f64, String)> = pool.iter().map(|(_, c)| *c).collect();
+        let idx = pick_weighted_index(&weights, rng.random::<f64>());
+        if with_replacement {
+            drawn.push(pool[idx].0);
+        } else {
+            drawn.push(pool.swap_remove(idx).0);
+        }
+    }
+    drawn
+}
+
+/// Index selected by `unit` (in 0..1) walking the cumulative weights; uniform when weights sum to 0
+fn pick_weighted_index(pool: &[&(usize, f64, String)], unit: f64) -> usize {
+    let total: f64 = pool.iter().map(|c| c.1.max(0.0)).sum();
+    if total <= 0.0 {
+        return ((unit * pool.len() as f64) as usize).min(pool.len() - 1);
+    }
+    let mut target = unit * total;
+    for (idx, candidate) in pool.iter().enumerate() {
+        let w = candidate.1.max(0.0);
+        if target <= w {
+            return idx;
+        }
+        target -= w;
+    }
+    pool.len() - 1
+}
+
+/// The `n` heaviest candidates with their normalized selection probability (0..=1),
+/// heaviest first; ties keep grid order
+pub fn top_pick_probabilities(candidates: &[(usize, f64)], n: usize) -> Vec<(usize, f64)> {
+    let total: f64 = candidates.iter().map(|c| c.1).sum();
+    if total <= 0.0 {
+        return Vec::new();
+    }
+    let mut sorted: Vec<(usize, f64)> = candidates.to_vec();
+    sorted.sort_by(|a, b| b.1.total_cmp(&a.1));
+    sorted.truncate(n);
+    sorted.into_iter().map(|(row, w)| (row, w / total)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(cells: &[&str]) -> Vec<String> {
+        cells.iter().map(|c| c.to_string()).collect()
+    }
+
+    #[test]
+    fn row_weight_applies_multiplier_then_power() {
+        let r = row(&["a", "4", "9"]);
+        assert_eq!(row_weight(&r, &[1], &[2.0], &[0.5]), 4.0);
+        // Negative exponent is a root: 9 ^ (1/2)
+        assert_eq!(row_weight(&r, &[2], &[-2.0], &[1.0]), 3.0);
+        assert_eq!(row_weight(&r, &[1, 2], &[], &[]), 13.0);
+    }
+
+    #[test]
+    fn unweighted_or_zero_rows_fall_back_to_one() {
+        let r = row(&["a", "0", "x"]);
+        assert_eq!(row_weight(&r, &[], &[], &[]), 1.0);
+        assert_eq!(row_weight(&r, &[1, 2], &[], &[]), 1.0);
+    }
+
+    fn candidates(weights: &[f64]) -> Vec<(usize, f64, String)> {
+        weights
+            .iter()
+            .enumerate()
+            .map(|(i, w)| (i, *w, format!("v{}", i)))
+            .collect()
+    }
+
+    #[test]
+    fn draw_without_replacement_returns_each_candidate_once() {
+        let pool = candidates(&[1.0, 5.0, 0.5]);
+        let mut drawn = draw_weighted(&pool, 10, false, &mut rand::rng());
+        drawn.sort();
+        assert_eq!(drawn, vec!["v0", "v1", "v2"]);
+        assert_eq!(draw_weighted(&pool, 2, false, &mut rand::rng()).len(), 2);
+        assert!(draw_weighted(&[], 3, false, &mut rand::rng()).is_empty());
+    }
+
+    #[test]
+    fn draw_with_replacement_returns_n_values() {
+        let drawn = draw_weighted(&candidates(&[1.0]), 4, true, &mut rand::rng());
+        assert_eq!(drawn, vec!["v0"; 4]);
+    }
+
+    #[test]
+    fn drawn_positions_are_distinct_without_replacement() {
+        let mut positions =
+            draw_weighted_positions(&candidates(&[2.0, 0.0, 1.0, 7.0]), 3, false, &mut rand::rng());
+        positions.sort();
+        positions.dedup();
+        assert_eq!(positions.len(), 3);
+        assert!(positions.iter().all(|&p| p < 4));
+    }
+
+    #[test]
+    fn same_seed_replays_the_same_draw_sequence() {
+        use rand::{rngs::StdRng, SeedableRng};
+        let pool = candidates(&[1.0, 2.0, 3.0, 4.0, 5.0]);
+        let sequence = |seed: u64| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            // Three consecutive Refreshes advance the same RNG
+            (0..3)
+                .map(|_| draw_weighted(&pool, 2, true, &mut rng))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(sequence(42), sequence(42));
+        let draws = sequence(42);
+        assert!(draws.iter().all(|d| d.len() == 2));
+    }
+
+    #[test]
+    fn weighted_index_follows_cumulative_weights() {
+        let pool = candidates(&[1.0, 3.0]);
+        let refs: Vec<&(usize, f64, String)> = pool.iter().collect();
+        assert_eq!(pick_weighted_index(&refs, 0.2), 0);
+        assert_eq!(pick_weighted_index(&refs, 0.3), 1);
+        let zero = candidates(&[0.0, 0.0]);
+        let refs: Vec<&(usize, f64, String)> = zero.iter().collect();
+        assert_eq!(pick_weighted_index(&refs, 0.9), 1);
+    }
+
+    #[test]
+    fn picks_are_keyed_by_row_index_when_the_sheet_has_them() {
+        assert_eq!(row_pick_key(&[7, 3, 12], 1), 3);
+        assert_eq!(row_pick_key(&[], 1), 1);
+    }
+
+    #[test]
+    fn top_probabilities_are_normalized_over_all_candidates() {
+        let grid = vec![row(&["a", "1"]), row(&["", "50"]), row(&["b", "3"]), row(&["c", "4"])];
+        let candidates = weighted_candidates(&grid, 0, &[1], &[], &[]);
+        assert_eq!(candidates, vec![(0, 1.0), (2, 3.0), (3, 4.0)]);
+        let top = top_pick_probabilities(&candidates, 2);
+        assert_eq!(top, vec![(3, 0.5), (2, 0.375)]);
+    }
+}
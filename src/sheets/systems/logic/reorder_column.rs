@@ -1,15 +1,104 @@
 // src/sheets/systems/logic/reorder_column.rs
 use crate::sheets::{
     definitions::SheetMetadata,
-    events::{RequestReorderColumn, SheetDataModifiedInRegistryEvent, SheetOperationFeedback},
+    events::{MoveColumnEvent, SheetDataModifiedInRegistryEvent, SheetOperationFeedback},
     resources::SheetRegistry,
     systems::io::save::save_single_sheet,
 };
 use bevy::prelude::*;
 use std::collections::HashMap;
 
+/// Where a column at `index` ends up after the column at `from` is moved to `to`
+/// (remove + insert semantics).
+pub fn moved_column_index(index: usize, from: usize, to: usize) -> usize {
+    if index == from {
+        to
+    } else if from < index && index <= to {
+        index - 1
+    } else if to <= index && index < from {
+        index + 1
+    } else {
+        index
+    }
+}
+
+/// Moves column `from` to position `to` in both the metadata and every grid row, keeping
+/// cells aligned with their column definitions. Technical columns (row_index/parent_key)
+/// can neither be moved nor have columns moved in front of them.
+pub fn move_column(
+    metadata: &mut SheetMetadata,
+    grid: &mut [Vec<String>],
+    from: usize,
+    to: usize,
+) -> Result<(), String> {
+    let num_cols = metadata.columns.len();
+    if from >= num_cols || to >= num_cols {
+        return Err(format!(
+            "Invalid indices for move. From: {}, To: {}. Total columns: {}.",
+            from, to, num_cols
+        ));
+    }
+    if SheetMetadata::is_technical_column_header(&metadata.columns[from].header) {
+        return Err(format!(
+            "'{}' is a technical column and cannot be moved.",
+            metadata.columns[from].header
+        ));
+    }
+    let technical_prefix = metadata
+        .columns
+        .iter()
+        .take_while(|c| SheetMetadata::is_technical_column_header(&c.header))
+        .count();
+    if to < technical_prefix {
+        return Err("Columns cannot be moved in front of technical columns.".to_string());
+    }
+    if from == to {
+        return Ok(());
+    }
+
+    let col_def = metadata.columns.remove(from);
+    metadata.columns.insert(to, col_def);
+    // Inline structure metadata travels with ColumnDefinition automatically; index references do not
+    for col in metadata.columns.iter_mut() {
+        if let Some(key_idx) = col.structure_key_parent_column_index.as_mut() {
+            *key_idx = moved_column_index(*key_idx, from, to);
+        }
+    }
+
+    for row in grid.iter_mut() {
+        if from < row.len() {
+            let cell = row.remove(from);
+            let insert_at = to.min(row.len());
+            row.insert(insert_at, cell);
+        } else {
+            warn!(
+                "Row has fewer cells than expected during column move. Row len: {}, from: {}. Skipping this row.",
+                row.len(),
+                from
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Ordered (column_name, persisted_index) pairs for the metadata table, skipping the
+/// `technical_columns` leading columns (row_index, plus parent_key for structures) that
+/// are not stored there
+pub fn persisted_column_order(
+    metadata: &SheetMetadata,
+    technical_columns: usize,
+) -> Vec<(String, i32)> {
+    metadata
+        .columns
+        .iter()
+        .skip(technical_columns)
+        .enumerate()
+        .map(|(persisted_idx, c)| (c.header.clone(), persisted_idx as i32))
+        .collect()
+}
+
 pub fn handle_reorder_column_request(
-    mut events: EventReader<RequestReorderColumn>,
+    mut events: EventReader<MoveColumnEvent>,
     mut registry: ResMut<SheetRegistry>,
     mut feedback_writer: EventWriter<SheetOperationFeedback>,
     mut data_modified_writer: EventWriter<SheetDataModifiedInRegistryEvent>,
@@ -21,8 +110,8 @@ pub fn handle_reorder_column_request(
 
     for event in events.read() {
         let (category, sheet_name) = (&event.category, &event.sheet_name);
-        let old_index = event.old_index;
-        let new_index = event.new_index;
+        let old_index = event.from_index;
+        let new_index = event.to_index;
 
         if old_index == new_index {
             trace!(
@@ -40,26 +129,11 @@ pub fn handle_reorder_column_request(
 
         if let Some(sheet_data) = registry.get_sheet_mut(category, sheet_name) {
             if let Some(metadata) = &mut sheet_data.metadata {
-                let num_cols = metadata.columns.len();
-                if old_index < num_cols && new_index < num_cols {
-                    // Reorder ColumnDefinition in metadata
-                    let col_def_to_move = metadata.columns.remove(old_index);
-                    metadata.columns.insert(new_index, col_def_to_move);
-                    // Inline structure metadata travels with ColumnDefinition automatically.
-
-                    // Reorder cells in each row of the grid
-                    for row in sheet_data.grid.iter_mut() {
-                        if old_index < row.len() {
-                            // Should always be true if data is consistent
-                            let cell_to_move = row.remove(old_index);
-                            // new_index might need adjustment if row.len() was less than metadata.columns.len()
-                            // However, assuming consistent data, direct insertion is fine.
-                            row.insert(new_index, cell_to_move);
-                        } else {
-                            warn!("Row in sheet '{:?}/{}' has fewer cells than expected during column reorder. Row len: {}, old_index: {}. Skipping reorder for this row.", category, sheet_name, row.len(), old_index);
-                        }
-                    }
-
+                let move_result =
+                    move_column(metadata, &mut sheet_data.grid, old_index, new_index);
+                if let Err(e) = move_result {
+                    error_message = Some(e);
+                } else {
                     metadata.ensure_column_consistency(); // Just in case
                     operation_successful = true;
                     metadata_cache = Some(metadata.clone());
@@ -93,18 +167,7 @@ pub fn handle_reorder_column_request(
                                     // Calculate how many technical columns to skip
                                     let tech_cols_count = if is_structure { 2 } else { 1 };
 
-                                    // Build ordered pairs (column_name, persisted_index)
-                                    // Skip technical columns (row_index for regular, row_index+parent_key for structure)
-                                    let mut pairs: Vec<(String, i32)> = Vec::new();
-                                    for (runtime_idx, c) in metadata.columns.iter().enumerate() {
-                                        // Skip technical columns
-                                        if runtime_idx < tech_cols_count {
-                                            continue;
-                                        }
-                                        // Convert runtime index to persisted index
-                                        let persisted_idx = runtime_idx - tech_cols_count;
-                                        pairs.push((c.header.clone(), persisted_idx as i32));
-                                    }
+                                    let pairs = persisted_column_order(metadata, tech_cols_count);
 
                                     if !pairs.is_empty() {
                                         info!(
@@ -154,11 +217,6 @@ pub fn handle_reorder_column_request(
                             );
                         }
                     }
-                } else {
-                    error_message = Some(format!(
-                        "Invalid indices for reorder. Old: {}, New: {}. Total columns: {}.",
-                        old_index, new_index, num_cols
-                    ));
                 }
             } else {
                 error_message = Some(format!(
@@ -174,6 +232,43 @@ pub fn handle_reorder_column_request(
         }
 
         if operation_successful {
+            // Child structure sheets point back at their parent column by index
+            let child_keys: Vec<(Option<String>, String)> = registry
+                .iter_sheets()
+                .filter(|(_, _, data)| {
+                    data.metadata
+                        .as_ref()
+                        .and_then(|m| m.structure_parent.as_ref())
+                        .is_some_and(|p| {
+                            &p.parent_category == category && &p.parent_sheet == sheet_name
+                        })
+                })
+                .map(|(cat, name, _)| (cat.clone(), name.clone()))
+                .collect();
+            for (child_cat, child_name) in child_keys {
+                let mut remapped_child = false;
+                if let Some(link) = registry
+                    .get_sheet_mut(&child_cat, &child_name)
+                    .and_then(|s| s.metadata.as_mut())
+                    .and_then(|m| m.structure_parent.as_mut())
+                {
+                    let remapped =
+                        moved_column_index(link.parent_column_index, old_index, new_index);
+                    remapped_child = remapped != link.parent_column_index;
+                    link.parent_column_index = remapped;
+                }
+                // DB children link to the parent column by name; JSON children keep this
+                // index in their own metadata file
+                if remapped_child {
+                    if let Some(child_meta) = registry
+                        .get_sheet(&child_cat, &child_name)
+                        .and_then(|s| s.metadata.clone())
+                    {
+                        sheets_to_save.insert((child_cat, child_name), child_meta);
+                    }
+                }
+            }
+
             let msg = format!(
                 "Reordered column from index {} to {} in sheet '{:?}/{}'.",
                 old_index, new_index, category, sheet_name
@@ -203,7 +298,7 @@ pub fn handle_reorder_column_request(
 
     // Note: For DB-backed sheets, column order is persisted directly to the database
     // during the reorder operation above (via update_column_indices).
-    // For JSON-backed sheets, we save the metadata file here.
+    // For JSON-backed sheets (and their remapped child sheets), we save the metadata file here.
     if !sheets_to_save.is_empty() {
         let registry_immut = registry.as_ref();
         for ((cat, name), metadata) in sheets_to_save {
@@ -214,3 +309,67 @@ pub fn handle_reorder_column_request(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sheet(headers: &[&str]) -> (SheetMetadata, Vec<Vec<String>>) {
+        let mut metadata =
+            SheetMetadata::create_generic("Items".into(), "Items.json".into(), headers.len(), None);
+        for (col, header) in metadata.columns.iter_mut().zip(headers) {
+            col.header = header.to_string();
+        }
+        let grid = (0..2)
+            .map(|r| headers.iter().map(|h| format!("{}{}", h, r)).collect())
+            .collect();
+        (metadata, grid)
+    }
+
+    #[test]
+    fn moved_index_follows_remove_then_insert() {
+        // Moving 1 -> 3 shifts 2 and 3 left
+        let moved: Vec<usize> = (0..5).map(|i| moved_column_index(i, 1, 3)).collect();
+        assert_eq!(moved, vec![0, 3, 1, 2, 4]);
+        // Moving 3 -> 1 shifts 1 and 2 right
+        let moved: Vec<usize> = (0..5).map(|i| moved_column_index(i, 3, 1)).collect();
+        assert_eq!(moved, vec![0, 2, 3, 1, 4]);
+    }
+
+    #[test]
+    fn move_keeps_cells_aligned_and_remaps_key_parent() {
+        let (mut metadata, mut grid) = sheet(&["row_index", "Name", "Tier", "Parts"]);
+        metadata.columns[3].structure_key_parent_column_index = Some(1);
+        move_column(&mut metadata, &mut grid, 1, 3).unwrap();
+
+        let headers: Vec<&str> = metadata.columns.iter().map(|c| c.header.as_str()).collect();
+        assert_eq!(headers, vec!["row_index", "Tier", "Parts", "Name"]);
+        assert_eq!(grid[1], vec!["row_index1", "Tier1", "Parts1", "Name1"]);
+        assert_eq!(metadata.columns[2].structure_key_parent_column_index, Some(3));
+    }
+
+    #[test]
+    fn ai_key_column_follows_its_header_across_moves_and_deletes() {
+        let (mut metadata, mut grid) = sheet(&["row_index", "Name", "Tier", "Parts"]);
+        metadata.set_ai_key_column(Some(2));
+        move_column(&mut metadata, &mut grid, 2, 1).unwrap();
+        assert_eq!(metadata.ai_key_column_override(), Some(1));
+
+        // A reload drops deleted columns; the header still resolves to the same column
+        metadata.columns.remove(2);
+        assert_eq!(metadata.ai_key_column_override(), Some(1));
+        metadata.columns[1].deleted = true;
+        assert_eq!(metadata.ai_key_column_override(), None);
+    }
+
+    #[test]
+    fn technical_columns_cannot_move_or_be_passed() {
+        let (mut metadata, mut grid) = sheet(&["row_index", "parent_key", "Name", "Tier"]);
+        assert!(move_column(&mut metadata, &mut grid, 0, 2).is_err());
+        assert!(move_column(&mut metadata, &mut grid, 3, 1).is_err());
+        assert!(move_column(&mut metadata, &mut grid, 3, 9).is_err());
+        assert_eq!(grid[0], vec!["row_index0", "parent_key0", "Name0", "Tier0"]);
+        assert!(move_column(&mut metadata, &mut grid, 3, 2).is_ok());
+        assert_eq!(grid[0], vec!["row_index0", "parent_key0", "Tier0", "Name0"]);
+    }
+}
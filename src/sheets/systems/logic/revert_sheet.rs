@@ -0,0 +1,363 @@
+// src/sheets/systems/logic/revert_sheet.rs
+// "Revert to Saved JSON": discard a DB sheet's rows and load the ones in its last JSON
+// export (`{category}/{table}.json` under the data folder, see `get_full_sheet_path`).
+//
+// The table is emptied and refilled in one daemon batch, so a failure leaves it untouched.
+// Values are matched to the current columns by header using the export's `.meta.json`
+// (by position when it is missing); columns the export lacks stay empty. Exported rows keep
+// their row_index so structure child rows (linked through parent_key) stay attached; the
+// structure tables themselves are not reverted. Locked rows are read-only, so they keep
+// their current values and the export's rows with the same row_index are skipped. Cell
+// comments of rows the export lacks are removed in the same batch. The sheet tree is
+// re-read afterwards and the sheet's undo history is dropped.
+
+use crate::sheets::{
+    database::connection::DbConnection,
+    database::reader::DbReader,
+    database::daemon_client::{DaemonClient, Statement},
+    database::migration::IoHelpers,
+    database::writer::DbWriter,
+    definitions::{ColumnValidator, SheetMetadata},
+    events::{
+        RequestSheetRevalidation, RevertSheetFromJsonEvent, SheetDataModifiedInRegistryEvent,
+        SheetOperationFeedback,
+    },
+    resources::{EditHistory, SheetRegistry},
+    systems::io::{get_default_data_base_path, get_full_metadata_path, get_full_sheet_path},
+    systems::ui_handlers::sheet_handlers::reload_sheet_tree_from_db,
+};
+use crate::ui::elements::editor::state::EditorWindowState;
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+/// Rows of a saved grid rearranged for the current table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RevertRows {
+    /// Data columns written back (structure, technical and deleted columns excluded)
+    pub headers: Vec<String>,
+    /// (row_index, values in `headers` order)
+    pub rows: Vec<(i64, Vec<String>)>,
+    /// Locked rows that keep their current values
+    pub kept_locked: Vec<i64>,
+}
+
+/// Arrange `grid` (saved with `saved_headers`, or with the current column layout when
+/// `None`) for the current columns. Saved row_index values are kept when every row has a
+/// distinct one; otherwise rows are renumbered so the first row is shown first again.
+/// Rows in `locked` stay as they are: saved rows with their row_index are skipped and
+/// renumbering steps over them.
+pub fn revert_rows(
+    current: &SheetMetadata,
+    saved_headers: Option<&[String]>,
+    grid: &[Vec<String>],
+    locked: &HashSet<i64>,
+) -> RevertRows {
+    let source_index = |header: &str, current_idx: usize| -> Option<usize> {
+        match saved_headers {
+            Some(saved) => saved
+                .iter()
+                .position(|h| h == header)
+                .or_else(|| saved.iter().position(|h| h.eq_ignore_ascii_case(header))),
+            None => Some(current_idx),
+        }
+    };
+
+    let mut headers = Vec::new();
+    let mut sources = Vec::new();
+    for (idx, col) in current.columns.iter().enumerate() {
+        if col.deleted
+            || SheetMetadata::is_technical_column_header(&col.header)
+            || matches!(col.validator, Some(ColumnValidator::Structure))
+        {
+            continue;
+        }
+        headers.push(col.header.clone());
+        sources.push(source_index(&col.header, idx));
+    }
+
+    let row_index_source = current
+        .columns
+        .iter()
+        .position(|c| c.header.eq_ignore_ascii_case("row_index"))
+        .and_then(|idx| source_index("row_index", idx));
+    let saved_indices: Option<Vec<i64>> = row_index_source.and_then(|src| {
+        grid.iter()
+            .map(|row| row.get(src).and_then(|v| v.trim().parse::<i64>().ok()))
+            .collect()
+    });
+    let indices = match saved_indices {
+        Some(indices) if indices.iter().collect::<HashSet<_>>().len() == indices.len() => indices,
+        // The grid lists rows by descending row_index
+        _ => {
+            let mut free: Vec<i64> = (0..)
+                .filter(|i| !locked.contains(i))
+                .take(grid.len())
+                .collect();
+            free.reverse();
+            free
+        }
+    };
+
+    let rows = grid
+        .iter()
+        .zip(indices)
+        .filter(|(_, row_index)| !locked.contains(row_index))
+        .map(|(row, row_index)| {
+            let values = sources
+                .iter()
+                .map(|src| src.and_then(|s| row.get(s).cloned()).unwrap_or_default())
+                .collect();
+            (row_index, values)
+        })
+        .collect();
+    let mut kept_locked: Vec<i64> = locked.iter().copied().collect();
+    kept_locked.sort_unstable();
+    RevertRows {
+        headers,
+        rows,
+        kept_locked,
+    }
+}
+
+/// Empty `table_name` except its locked rows and insert `revert` into it (run as one
+/// atomic batch)
+pub fn revert_statements(table_name: &str, revert: &RevertRows) -> Vec<Statement> {
+    let mut statements = vec![if revert.kept_locked.is_empty() {
+        Statement {
+            sql: format!("DELETE FROM \"{}\"", table_name),
+            params: vec![],
+        }
+    } else {
+        let placeholders = vec!["?"; revert.kept_locked.len()].join(", ");
+        Statement {
+            sql: format!(
+                "DELETE FROM \"{}\" WHERE row_index NOT IN ({})",
+                table_name, placeholders
+            ),
+            params: revert.kept_locked.iter().map(|i| serde_json::json!(i)).collect(),
+        }
+    }];
+    let columns: String = revert
+        .headers
+        .iter()
+        .map(|h| format!(", \"{}\"", h))
+        .collect();
+    let placeholders: String = revert.headers.iter().map(|_| ", ?").collect();
+    let insert_sql = format!(
+        "INSERT INTO \"{}\" (row_index{}) VALUES (?{})",
+        table_name, columns, placeholders
+    );
+    for (row_index, values) in &revert.rows {
+        let mut params = Vec::with_capacity(values.len() + 1);
+        params.push(serde_json::json!(row_index));
+        params.extend(values.iter().cloned().map(serde_json::Value::String));
+        statements.push(Statement {
+            sql: insert_sql.clone(),
+            params,
+        });
+    }
+    statements
+}
+
+fn revert_table_from_json(
+    metadata: &SheetMetadata,
+    category: &str,
+    daemon_client: &DaemonClient,
+) -> Result<RevertRows, String> {
+    let base_path = get_default_data_base_path();
+    let json_path = get_full_sheet_path(&base_path, metadata);
+    if !json_path.exists() {
+        return Err(format!(
+            "No saved JSON for '{}' at {}; export the sheet to JSON first.",
+            metadata.sheet_name,
+            json_path.display()
+        ));
+    }
+    let grid = IoHelpers::load_grid_data(&json_path)
+        .map_err(|e| format!("Failed to read {}: {}", json_path.display(), e))?;
+    let meta_path = get_full_metadata_path(&base_path, metadata);
+    let saved_headers: Option<Vec<String>> = if meta_path.exists() {
+        match IoHelpers::load_metadata(&meta_path) {
+            Ok(saved) => Some(saved.columns.into_iter().map(|c| c.header).collect()),
+            Err(e) => {
+                warn!("Ignoring unreadable {}: {}; matching columns by position", meta_path.display(), e);
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    let db_filename = format!("{}.db", category);
+    let conn = DbConnection::open_existing(&base_path.join(&db_filename)).map_err(|e| e.to_string())?;
+    let locked = DbReader::read_locked_rows(&conn, &metadata.sheet_name).map_err(|e| e.to_string())?;
+    let revert = revert_rows(metadata, saved_headers.as_deref(), &grid, &locked);
+    let mut statements = revert_statements(&metadata.sheet_name, &revert);
+    // Comments of rows the export does not have would otherwise cling to nothing (or to a
+    // later row that reuses the index)
+    statements.extend(
+        DbWriter::orphaned_row_sidecar_statements(&conn, &metadata.sheet_name).map_err(|e| e.to_string())?,
+    );
+    let response = daemon_client.exec_batch(statements, Some(&db_filename))?;
+    if let Some(e) = response.error {
+        return Err(format!("{:?}", e));
+    }
+    Ok(revert)
+}
+
+pub fn handle_revert_sheet_from_json(
+    mut events: EventReader<RevertSheetFromJsonEvent>,
+    mut registry: ResMut<SheetRegistry>,
+    mut feedback_writer: EventWriter<SheetOperationFeedback>,
+    mut data_modified_writer: EventWriter<SheetDataModifiedInRegistryEvent>,
+    mut revalidate_writer: EventWriter<RequestSheetRevalidation>,
+    mut editor_state: Option<ResMut<EditorWindowState>>,
+    mut edit_history: ResMut<EditHistory>,
+    daemon_client: Res<crate::sheets::database::daemon_resource::SharedDaemonClient>,
+) {
+    for event in events.read() {
+        let category = &event.category;
+        let sheet_name = &event.sheet_name;
+        let mut fail = |msg: String| {
+            warn!("{}", msg);
+            feedback_writer.write(SheetOperationFeedback {
+                message: msg,
+                is_error: true,
+            });
+        };
+
+        let Some(cat_str) = category.as_deref() else {
+            fail(format!(
+                "Cannot revert '{}': only database-backed sheets have a JSON export to revert to.",
+                sheet_name
+            ));
+            continue;
+        };
+        let Some(metadata) = registry
+            .get_sheet(category, sheet_name)
+            .and_then(|sheet| sheet.metadata.clone())
+        else {
+            fail(format!("Cannot revert: sheet '{:?}/{}' not found.", category, sheet_name));
+            continue;
+        };
+
+        let reverted = match revert_table_from_json(&metadata, cat_str, daemon_client.client()) {
+            Ok(revert) => revert,
+            Err(e) => {
+                fail(format!("Revert of '{}' failed: {}", sheet_name, e));
+                continue;
+            }
+        };
+
+        match reload_sheet_tree_from_db(&mut registry, daemon_client.client(), category, sheet_name) {
+            Ok(tables) => {
+                for table in tables {
+                    data_modified_writer.write(SheetDataModifiedInRegistryEvent {
+                        category: category.clone(),
+                        sheet_name: table,
+                    });
+                }
+            }
+            Err(e) => error!("Reload after reverting '{}' failed: {}", sheet_name, e),
+        }
+        // Recorded edits describe rows that were just replaced
+        edit_history.clear_sheet(category, sheet_name);
+        revalidate_writer.write(RequestSheetRevalidation {
+            category: category.clone(),
+            sheet_name: sheet_name.clone(),
+        });
+
+        if let Some(state) = editor_state.as_mut() {
+            state.parent_lineage_cache.clear();
+            state.ai_selected_rows.clear();
+            state.force_filter_recalculation = true;
+        }
+
+        let message = if reverted.kept_locked.is_empty() {
+            format!("Reverted '{}' to its saved JSON ({} rows).", sheet_name, reverted.rows.len())
+        } else {
+            format!(
+                "Reverted '{}' to its saved JSON ({} rows); {} locked row(s) kept their current values.",
+                sheet_name,
+                reverted.rows.len(),
+                reverted.kept_locked.len()
+            )
+        };
+        info!("{}", message);
+        feedback_writer.write(SheetOperationFeedback {
+            message,
+            is_error: false,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metadata(headers: &[&str]) -> SheetMetadata {
+        let mut meta = SheetMetadata::create_generic("Items".into(), "Items.json".into(), headers.len(), Some("Game".into()));
+        for (col, header) in meta.columns.iter_mut().zip(headers) {
+            col.header = header.to_string();
+        }
+        meta
+    }
+
+    fn grid(rows: &[&[&str]]) -> Vec<Vec<String>> {
+        rows.iter()
+            .map(|row| row.iter().map(|s| s.to_string()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn saved_values_follow_headers() {
+        let current = metadata(&["row_index", "Name", "Price", "Weight"]);
+        let saved: Vec<String> = ["row_index", "Price", "name"].iter().map(|s| s.to_string()).collect();
+        let revert = revert_rows(&current, Some(&saved), &grid(&[&["7", "10", "Sword"], &["3", "4", "Shield"]]), &HashSet::new());
+        assert_eq!(revert.headers, vec!["Name", "Price", "Weight"]);
+        assert_eq!(
+            revert.rows,
+            vec![
+                (7, vec!["Sword".to_string(), "10".to_string(), String::new()]),
+                (3, vec!["Shield".to_string(), "4".to_string(), String::new()]),
+            ]
+        );
+
+        // Without saved headers the current layout is assumed
+        let revert = revert_rows(&current, None, &grid(&[&["2", "Axe", "8", "5"]]), &HashSet::new());
+        assert_eq!(revert.rows, vec![(2, vec!["Axe".to_string(), "8".to_string(), "5".to_string()])]);
+    }
+
+    #[test]
+    fn unusable_row_indices_are_renumbered() {
+        let current = metadata(&["row_index", "Name"]);
+        let duplicated = revert_rows(&current, None, &grid(&[&["1", "A"], &["1", "B"], &["", "C"]]), &HashSet::new());
+        let indices: Vec<i64> = duplicated.rows.iter().map(|(idx, _)| *idx).collect();
+        assert_eq!(indices, vec![2, 1, 0]);
+
+        let statements = revert_statements("Items", &duplicated);
+        assert_eq!(statements.len(), 4);
+        assert_eq!(statements[0].sql, "DELETE FROM \"Items\"");
+        assert_eq!(statements[1].sql, "INSERT INTO \"Items\" (row_index, \"Name\") VALUES (?, ?)");
+        assert_eq!(statements[1].params, vec![serde_json::json!(2), serde_json::json!("A")]);
+    }
+
+    #[test]
+    fn locked_rows_keep_their_current_values() {
+        let current = metadata(&["row_index", "Name"]);
+        let locked = HashSet::from([3]);
+        let revert = revert_rows(&current, None, &grid(&[&["7", "Sword"], &["3", "Shield"]]), &locked);
+        assert_eq!(revert.rows, vec![(7, vec!["Sword".to_string()])]);
+        assert_eq!(revert.kept_locked, vec![3]);
+
+        let statements = revert_statements("Items", &revert);
+        assert_eq!(statements[0].sql, "DELETE FROM \"Items\" WHERE row_index NOT IN (?)");
+        assert_eq!(statements[0].params, vec![serde_json::json!(3)]);
+
+        // Renumbered rows step over the locked row_index
+        let locked = HashSet::from([1]);
+        let renumbered = revert_rows(&current, None, &grid(&[&["", "A"], &["", "B"]]), &locked);
+        let indices: Vec<i64> = renumbered.rows.iter().map(|(idx, _)| *idx).collect();
+        assert_eq!(indices, vec![2, 0]);
+    }
+}
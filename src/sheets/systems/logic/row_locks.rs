@@ -0,0 +1,162 @@
+// src/sheets/systems/logic/row_locks.rs
+use crate::{
+    sheets::{
+        database::{daemon_resource::SharedDaemonClient, reader::DbReader, writer::DbWriter},
+        events::{SetRowLockEvent, SheetDataModifiedInRegistryEvent, SheetOperationFeedback},
+        resources::SheetRegistry,
+        systems::io::get_default_data_base_path,
+    },
+    ui::elements::editor::state::EditorWindowState,
+};
+use bevy::prelude::*;
+use rusqlite::Connection;
+use std::collections::HashSet;
+
+/// Writes lock/unlock requests through the daemon and mirrors them in the registry,
+/// which is what the cell update handlers check.
+pub fn handle_row_lock_requests(
+    mut events: EventReader<SetRowLockEvent>,
+    mut registry: ResMut<SheetRegistry>,
+    mut feedback_writer: EventWriter<SheetOperationFeedback>,
+    daemon_client: Res<SharedDaemonClient>,
+) {
+    for event in events.read() {
+        let mut fail = |msg: String| {
+            error!("{}", msg);
+            feedback_writer.write(SheetOperationFeedback {
+                message: msg,
+                is_error: true,
+            });
+        };
+
+        let Some(db_name) = event.category.as_deref() else {
+            fail(format!(
+                "Row locks are only available for database sheets ('{}').",
+                event.sheet_name
+            ));
+            continue;
+        };
+        let Some(row_index) = registry
+            .get_sheet(&event.category, &event.sheet_name)
+            .and_then(|sheet| sheet.row_indices.get(event.row_index).copied())
+        else {
+            fail(format!(
+                "Failed to change row lock: row {} not found in '{:?}/{}'.",
+                event.row_index, event.category, event.sheet_name
+            ));
+            continue;
+        };
+
+        let db_path = get_default_data_base_path().join(format!("{}.db", db_name));
+        let result = Connection::open(&db_path)
+            .map_err(Into::into)
+            .and_then(|conn| {
+                DbWriter::set_row_locked(
+                    &conn,
+                    &event.sheet_name,
+                    row_index,
+                    event.locked,
+                    db_path.file_name().and_then(|n| n.to_str()),
+                    daemon_client.client(),
+                )
+            });
+        if let Err(e) = result {
+            fail(format!(
+                "Failed to {} row {} of '{:?}/{}': {}",
+                if event.locked { "lock" } else { "unlock" },
+                event.row_index,
+                event.category,
+                event.sheet_name,
+                e
+            ));
+            continue;
+        }
+
+        registry.set_row_locked(&event.category, &event.sheet_name, row_index, event.locked);
+        feedback_writer.write(SheetOperationFeedback {
+            message: format!(
+                "Row {} of '{}' {}.",
+                event.row_index + 1,
+                event.sheet_name,
+                if event.locked { "locked" } else { "unlocked" }
+            ),
+            is_error: false,
+        });
+    }
+}
+
+/// Loads the locked rows of every database sheet once it is in the registry, and the
+/// selected sheet's again after its data was modified (sorts and deletions move or remove
+/// locks in the DB).
+pub fn refresh_locked_rows(
+    mut modified_events: EventReader<SheetDataModifiedInRegistryEvent>,
+    editor_state_opt: Option<Res<EditorWindowState>>,
+    mut registry: ResMut<SheetRegistry>,
+) {
+    let selected = editor_state_opt.and_then(|state| {
+        state
+            .selected_sheet_name
+            .clone()
+            .map(|name| (state.selected_category.clone(), name))
+    });
+    let selected_modified = modified_events.read().any(|e| {
+        selected
+            .as_ref()
+            .is_some_and(|(category, name)| &e.category == category && &e.sheet_name == name)
+    });
+    if selected_modified {
+        if let Some((category, sheet_name)) = &selected {
+            if let Some(db_name) = category.as_deref() {
+                let locked = load_locked_rows(db_name, sheet_name);
+                registry.set_locked_rows(category, sheet_name, locked);
+            }
+        }
+    }
+
+    let unloaded: Vec<(Option<String>, String)> = registry
+        .iter_sheets()
+        .filter(|(category, name, _)| {
+            category.is_some() && registry.locked_rows(category, name).is_none()
+        })
+        .map(|(category, name, _)| (category.clone(), name.clone()))
+        .collect();
+    for (category, sheet_name) in unloaded {
+        ensure_locked_rows_loaded(&mut registry, &category, &sheet_name);
+    }
+}
+
+/// Reads the locked rows of a database sheet into the registry unless they are already
+/// there, so a lock check never mistakes "not loaded yet" for "unlocked"
+pub fn ensure_locked_rows_loaded(
+    registry: &mut SheetRegistry,
+    category: &Option<String>,
+    sheet_name: &str,
+) {
+    if registry.locked_rows(category, sheet_name).is_some() {
+        return;
+    }
+    let Some(db_name) = category.as_deref() else {
+        return;
+    };
+    let locked = load_locked_rows(db_name, sheet_name);
+    registry.set_locked_rows(category, sheet_name, locked);
+}
+
+/// A failed read counts as "no locks" instead of being retried every frame
+fn load_locked_rows(db_name: &str, sheet_name: &str) -> HashSet<i64> {
+    let db_path = get_default_data_base_path().join(format!("{}.db", db_name));
+    if !db_path.exists() {
+        return HashSet::new();
+    }
+    let loaded = Connection::open(&db_path)
+        .map_err(Into::into)
+        .and_then(|conn| {
+            // Make the daemon's writes visible before reading
+            let _ = conn.query_row("PRAGMA wal_checkpoint(PASSIVE)", [], |_| Ok(()));
+            DbReader::read_locked_rows(&conn, sheet_name)
+        });
+    loaded.unwrap_or_else(|e| {
+        warn!("Failed to load locked rows of '{}/{}': {}", db_name, sheet_name, e);
+        HashSet::new()
+    })
+}
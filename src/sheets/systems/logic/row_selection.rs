@@ -0,0 +1,86 @@
+// src/sheets/systems/logic/row_selection.rs
+//! Row selection shared by AI mode and Delete mode: click, Ctrl+Click and Shift+Click on
+//! the row checkboxes, over the rows shown under the current filters
+
+use std::collections::HashSet;
+
+/// Apply a click on the checkbox of `clicked` to `selected` and return the new range anchor.
+///
+/// * plain click or Ctrl+Click (`toggle`): flip the row
+/// * Shift+Click: select the rows between the anchor and `clicked` in display order,
+///   replacing the selection (Ctrl+Shift+Click adds the range instead)
+///
+/// `visible_rows` are the filtered rows in display order. Without an anchor among them a
+/// Shift+Click selects only `clicked`. The anchor stays put for Shift+Click so the range
+/// can be adjusted; any other click moves it to `clicked`.
+pub fn apply_row_click(
+    selected: &mut HashSet<usize>,
+    anchor: Option<usize>,
+    clicked: usize,
+    visible_rows: &[usize],
+    shift: bool,
+    toggle: bool,
+) -> Option<usize> {
+    if !shift {
+        if !selected.remove(&clicked) {
+            selected.insert(clicked);
+        }
+        return Some(clicked);
+    }
+
+    let clicked_pos = visible_rows.iter().position(|&r| r == clicked);
+    let anchor_pos = anchor.and_then(|a| visible_rows.iter().position(|&r| r == a));
+    if !toggle {
+        selected.clear();
+    }
+    match (anchor_pos, clicked_pos) {
+        (Some(a), Some(c)) => {
+            let (start, end) = if a <= c { (a, c) } else { (c, a) };
+            selected.extend(visible_rows[start..=end].iter().copied());
+            anchor
+        }
+        _ => {
+            selected.insert(clicked);
+            Some(clicked)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Display order differs from grid order, as with a sorted or filtered sheet
+    const VISIBLE: [usize; 5] = [4, 2, 7, 0, 5];
+
+    #[test]
+    fn plain_and_ctrl_clicks_toggle_one_row() {
+        let mut selected = HashSet::from([4]);
+        assert_eq!(apply_row_click(&mut selected, Some(4), 7, &VISIBLE, false, false), Some(7));
+        assert_eq!(selected, HashSet::from([4, 7]));
+        assert_eq!(apply_row_click(&mut selected, Some(7), 4, &VISIBLE, false, true), Some(4));
+        assert_eq!(selected, HashSet::from([7]));
+    }
+
+    #[test]
+    fn shift_click_selects_the_displayed_range() {
+        let mut selected = HashSet::from([5]);
+        // Backwards from the anchor, replacing the selection; the anchor is kept
+        assert_eq!(apply_row_click(&mut selected, Some(0), 2, &VISIBLE, true, false), Some(0));
+        assert_eq!(selected, HashSet::from([2, 7, 0]));
+        // Ctrl+Shift adds the range to the selection
+        let mut selected = HashSet::from([4]);
+        apply_row_click(&mut selected, Some(0), 5, &VISIBLE, true, true);
+        assert_eq!(selected, HashSet::from([4, 0, 5]));
+    }
+
+    #[test]
+    fn shift_click_without_visible_anchor_selects_one_row() {
+        let mut selected = HashSet::from([4, 2]);
+        // Row 9 is hidden by the filter
+        assert_eq!(apply_row_click(&mut selected, Some(9), 7, &VISIBLE, true, false), Some(7));
+        assert_eq!(selected, HashSet::from([7]));
+        assert_eq!(apply_row_click(&mut selected, None, 0, &VISIBLE, true, true), Some(0));
+        assert_eq!(selected, HashSet::from([7, 0]));
+    }
+}
@@ -0,0 +1,282 @@
+// src/sheets/systems/logic/sheet_diff.rs
+// "Compare sheets": rows of two sheets are matched by a shared key column and compared
+// cell by cell. Columns are matched by header (case-insensitive); a column present in only
+// one sheet shows up as removed (left only) or added (right only) in every matched row.
+// Rows sharing a key are paired in order of appearance, so the n-th "Sword" on the left
+// is compared with the n-th "Sword" on the right.
+
+use std::collections::HashMap;
+
+use crate::sheets::definitions::{SheetGridData, SheetMetadata};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnPresence {
+    Both,
+    /// Only in the left (old) sheet
+    Removed,
+    /// Only in the right (new) sheet
+    Added,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffColumn {
+    pub header: String,
+    pub presence: ColumnPresence,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CellDiff {
+    Same(String),
+    Changed { before: String, after: String },
+    Removed(String),
+    Added(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RowDiffKind {
+    Unchanged,
+    Changed,
+    Removed,
+    Added,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RowDiff {
+    pub key: String,
+    pub kind: RowDiffKind,
+    /// One entry per `SheetDiff::columns`
+    pub cells: Vec<CellDiff>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SheetDiff {
+    /// Left sheet's columns in order, then the columns only the right sheet has
+    pub columns: Vec<DiffColumn>,
+    /// Left rows in order (removed ones included), then rows only the right sheet has
+    pub rows: Vec<RowDiff>,
+}
+
+impl SheetDiff {
+    pub fn count(&self, kind: RowDiffKind) -> usize {
+        self.rows.iter().filter(|r| r.kind == kind).count()
+    }
+}
+
+/// Headers and rows of a sheet as compared: deleted and technical columns are left out
+pub fn diff_input(sheet: &SheetGridData) -> (Vec<String>, Vec<Vec<String>>) {
+    let Some(metadata) = sheet.metadata.as_ref() else {
+        return (Vec::new(), Vec::new());
+    };
+    let kept: Vec<usize> = metadata
+        .columns
+        .iter()
+        .enumerate()
+        .filter(|(_, c)| !c.deleted && !SheetMetadata::is_technical_column_header(&c.header))
+        .map(|(idx, _)| idx)
+        .collect();
+    let headers = kept
+        .iter()
+        .map(|&idx| metadata.columns[idx].header.clone())
+        .collect();
+    let rows = sheet
+        .grid
+        .iter()
+        .map(|row| {
+            kept.iter()
+                .map(|&idx| row.get(idx).cloned().unwrap_or_default())
+                .collect()
+        })
+        .collect();
+    (headers, rows)
+}
+
+fn find_column(headers: &[String], header: &str) -> Option<usize> {
+    headers.iter().position(|h| h.eq_ignore_ascii_case(header))
+}
+
+/// Compare `right` (new) against `left` (old), matching rows by `key_header`.
+/// Fails when either sheet lacks the key column.
+pub fn diff_sheets(
+    left_headers: &[String],
+    left_rows: &[Vec<String>],
+    right_headers: &[String],
+    right_rows: &[Vec<String>],
+    key_header: &str,
+) -> Result<SheetDiff, String> {
+    let left_key = find_column(left_headers, key_header)
+        .ok_or_else(|| format!("left sheet has no column '{}'", key_header))?;
+    let right_key = find_column(right_headers, key_header)
+        .ok_or_else(|| format!("right sheet has no column '{}'", key_header))?;
+
+    // (left position, right position) per diff column
+    let mut sources: Vec<(Option<usize>, Option<usize>)> = Vec::new();
+    let mut columns: Vec<DiffColumn> = Vec::new();
+    for (left_idx, header) in left_headers.iter().enumerate() {
+        let right_idx = find_column(right_headers, header);
+        sources.push((Some(left_idx), right_idx));
+        columns.push(DiffColumn {
+            header: header.clone(),
+            presence: if right_idx.is_some() {
+                ColumnPresence::Both
+            } else {
+                ColumnPresence::Removed
+            },
+        });
+    }
+    for (right_idx, header) in right_headers.iter().enumerate() {
+        if find_column(left_headers, header).is_none() {
+            sources.push((None, Some(right_idx)));
+            columns.push(DiffColumn {
+                header: header.clone(),
+                presence: ColumnPresence::Added,
+            });
+        }
+    }
+
+    let cell = |row: &[String], idx: Option<usize>| -> String {
+        idx.and_then(|i| row.get(i)).cloned().unwrap_or_default()
+    };
+    let key_of = |row: &[String], idx: usize| row.get(idx).map(|k| k.trim().to_string()).unwrap_or_default();
+
+    // Right rows by key, in order of appearance; matching consumes them front to back
+    let mut right_by_key: HashMap<String, Vec<usize>> = HashMap::new();
+    for (pos, row) in right_rows.iter().enumerate() {
+        right_by_key.entry(key_of(row, right_key)).or_default().push(pos);
+    }
+    let mut matched_right = vec![false; right_rows.len()];
+
+    let mut rows: Vec<RowDiff> = Vec::with_capacity(left_rows.len());
+    for left_row in left_rows {
+        let key = key_of(left_row, left_key);
+        let right_pos = right_by_key.get_mut(&key).and_then(|positions| {
+            (!positions.is_empty()).then(|| positions.remove(0))
+        });
+        let Some(right_pos) = right_pos else {
+            rows.push(RowDiff {
+                key,
+                kind: RowDiffKind::Removed,
+                cells: sources
+                    .iter()
+                    .map(|(l, _)| CellDiff::Removed(cell(left_row, *l)))
+                    .collect(),
+            });
+            continue;
+        };
+        matched_right[right_pos] = true;
+        let right_row = &right_rows[right_pos];
+        let cells: Vec<CellDiff> = sources
+            .iter()
+            .map(|(l, r)| match (l, r) {
+                (Some(_), Some(_)) => {
+                    let (before, after) = (cell(left_row, *l), cell(right_row, *r));
+                    if before == after {
+                        CellDiff::Same(before)
+                    } else {
+                        CellDiff::Changed { before, after }
+                    }
+                }
+                (Some(_), None) => CellDiff::Removed(cell(left_row, *l)),
+                _ => CellDiff::Added(cell(right_row, *r)),
+            })
+            .collect();
+        // One-sided columns differ in every row; only shared columns decide the row kind
+        let kind = if cells.iter().any(|c| matches!(c, CellDiff::Changed { .. })) {
+            RowDiffKind::Changed
+        } else {
+            RowDiffKind::Unchanged
+        };
+        rows.push(RowDiff { key, kind, cells });
+    }
+
+    for (pos, right_row) in right_rows.iter().enumerate() {
+        if matched_right[pos] {
+            continue;
+        }
+        rows.push(RowDiff {
+            key: key_of(right_row, right_key),
+            kind: RowDiffKind::Added,
+            cells: sources
+                .iter()
+                .map(|(_, r)| CellDiff::Added(cell(right_row, *r)))
+                .collect(),
+        });
+    }
+
+    Ok(SheetDiff { columns, rows })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn rows_are_matched_by_key_and_compared_per_cell() {
+        let left_headers = strings(&["Name", "Damage", "Weight"]);
+        let left_rows = vec![
+            strings(&["Sword", "10", "3"]),
+            strings(&["Axe", "12", "5"]),
+            strings(&["Bow", "8", "2"]),
+        ];
+        // Weight dropped, Price added, Axe removed, Spear added, Sword changed
+        let right_headers = strings(&["name", "Price", "Damage"]);
+        let right_rows = vec![
+            strings(&["Spear", "40", "9"]),
+            strings(&["Bow", "25", "8"]),
+            strings(&["Sword", "30", "11"]),
+        ];
+
+        let diff = diff_sheets(&left_headers, &left_rows, &right_headers, &right_rows, "Name").unwrap();
+        let presence: Vec<ColumnPresence> = diff.columns.iter().map(|c| c.presence).collect();
+        assert_eq!(
+            presence,
+            vec![
+                ColumnPresence::Both,
+                ColumnPresence::Both,
+                ColumnPresence::Removed,
+                ColumnPresence::Added
+            ]
+        );
+
+        let kinds: Vec<(&str, RowDiffKind)> =
+            diff.rows.iter().map(|r| (r.key.as_str(), r.kind)).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("Sword", RowDiffKind::Changed),
+                ("Axe", RowDiffKind::Removed),
+                ("Bow", RowDiffKind::Unchanged),
+                ("Spear", RowDiffKind::Added),
+            ]
+        );
+        assert_eq!(
+            diff.rows[0].cells,
+            vec![
+                CellDiff::Same("Sword".to_string()),
+                CellDiff::Changed { before: "10".to_string(), after: "11".to_string() },
+                CellDiff::Removed("3".to_string()),
+                CellDiff::Added("30".to_string()),
+            ]
+        );
+        assert_eq!(diff.rows[3].cells[2], CellDiff::Added(String::new()));
+        assert_eq!(diff.count(RowDiffKind::Changed), 1);
+    }
+
+    #[test]
+    fn duplicate_keys_pair_in_order_and_missing_key_column_fails() {
+        let headers = strings(&["Name", "Qty"]);
+        let left = vec![strings(&["Ore", "1"]), strings(&["Ore", "2"])];
+        let right = vec![strings(&["Ore", "1"]), strings(&["Ore", "3"]), strings(&["Ore", "4"])];
+        let diff = diff_sheets(&headers, &left, &headers, &right, "Name").unwrap();
+        let kinds: Vec<RowDiffKind> = diff.rows.iter().map(|r| r.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![RowDiffKind::Unchanged, RowDiffKind::Changed, RowDiffKind::Added]
+        );
+
+        assert!(diff_sheets(&headers, &left, &strings(&["Qty"]), &right, "Name").is_err());
+    }
+}
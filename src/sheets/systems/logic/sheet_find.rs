@@ -0,0 +1,107 @@
+// src/sheets/systems/logic/sheet_find.rs
+//! In-sheet find (Ctrl+F): substring matching over the displayed cells
+
+/// Typing pause before the find query is applied to the grid
+pub const FIND_DEBOUNCE_SECS: f64 = 0.25;
+
+/// Whether `text` contains `query`. An empty query matches nothing.
+pub fn text_matches_find(text: &str, query: &str, case_sensitive: bool) -> bool {
+    if query.is_empty() {
+        return false;
+    }
+    if case_sensitive {
+        text.contains(query)
+    } else {
+        text.to_lowercase().contains(&query.to_lowercase())
+    }
+}
+
+/// Cells containing `query`, row by row in display order. `rows` and `columns` are the
+/// displayed rows and columns; `cell_text` gives the text shown in a (row, column) cell.
+pub fn find_matches<'a>(
+    rows: &[usize],
+    columns: &[usize],
+    query: &str,
+    case_sensitive: bool,
+    cell_text: impl Fn(usize, usize) -> &'a str,
+) -> Vec<(usize, usize)> {
+    if query.is_empty() {
+        return Vec::new();
+    }
+    let needle = if case_sensitive {
+        query.to_string()
+    } else {
+        query.to_lowercase()
+    };
+    let mut matches = Vec::new();
+    for &row in rows {
+        for &col in columns {
+            let text = cell_text(row, col);
+            let hit = if case_sensitive {
+                text.contains(needle.as_str())
+            } else {
+                text.to_lowercase().contains(needle.as_str())
+            };
+            if hit {
+                matches.push((row, col));
+            }
+        }
+    }
+    matches
+}
+
+/// Position of the match after (`forward`) or before `current`, wrapping around.
+/// Without a current match, forward starts at the first match and backward at the last.
+pub fn step_find_match(current: Option<usize>, match_count: usize, forward: bool) -> Option<usize> {
+    if match_count == 0 {
+        return None;
+    }
+    Some(match (current, forward) {
+        (None, true) => 0,
+        (None, false) => match_count - 1,
+        (Some(pos), true) => (pos + 1) % match_count,
+        (Some(pos), false) => (pos + match_count - 1) % match_count,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Vec<Vec<String>> {
+        [["Iron Sword", "10"], ["Wooden shield", "4"], ["Sword of Dawn", "40"]]
+            .iter()
+            .map(|row| row.iter().map(|s| s.to_string()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn matching_ignores_case_unless_asked() {
+        assert!(text_matches_find("Iron Sword", "sword", false));
+        assert!(!text_matches_find("Iron Sword", "sword", true));
+        assert!(!text_matches_find("Iron Sword", "", false));
+    }
+
+    #[test]
+    fn matches_follow_display_order() {
+        let grid = grid();
+        let text = |r: usize, c: usize| grid[r][c].as_str();
+        assert_eq!(
+            find_matches(&[2, 0, 1], &[0, 1], "sword", false, text),
+            vec![(2, 0), (0, 0)]
+        );
+        assert_eq!(find_matches(&[0, 1, 2], &[1], "0", false, text), vec![(0, 1), (2, 1)]);
+        // Rows hidden by filters are not searched
+        assert_eq!(find_matches(&[1], &[0, 1], "sword", false, text), Vec::new());
+        assert!(find_matches(&[0, 1, 2], &[0, 1], "", false, text).is_empty());
+    }
+
+    #[test]
+    fn navigation_wraps_around() {
+        assert_eq!(step_find_match(None, 3, true), Some(0));
+        assert_eq!(step_find_match(None, 3, false), Some(2));
+        assert_eq!(step_find_match(Some(2), 3, true), Some(0));
+        assert_eq!(step_find_match(Some(0), 3, false), Some(2));
+        assert_eq!(step_find_match(Some(0), 0, true), None);
+    }
+}
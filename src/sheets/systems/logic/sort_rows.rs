@@ -0,0 +1,286 @@
+// src/sheets/systems/logic/sort_rows.rs
+// Sorting a sheet's rows by one column and persisting the resulting order.
+//
+// DB sheets are read ordered by `row_index` (descending), so the sorted order is stored by
+// handing the existing row_index values out again in the new row order. Child structure
+// rows point at their parent through `parent_key` (the parent's row_index) and are
+// remapped together with the parent rows.
+
+use crate::sheets::{
+    definitions::{ColumnDataType, ColumnValidator},
+    events::{SheetDataModifiedInRegistryEvent, SheetOperationFeedback, SortSheetByColumnEvent},
+    resources::{SheetRegistry, SheetRenderCache},
+    systems::io::save::save_single_sheet,
+    systems::logic::update_column_validator::structure_naming::structure_child_table_name,
+};
+use bevy::prelude::*;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+/// Type-aware comparison of two non-empty cells: numeric for I64/F64 (unparsable values
+/// after numbers), lexicographic otherwise
+fn compare_cells(a: &str, b: &str, data_type: ColumnDataType) -> Ordering {
+    if matches!(data_type, ColumnDataType::I64 | ColumnDataType::F64) {
+        match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+            (Ok(x), Ok(y)) => return x.total_cmp(&y),
+            (Ok(_), Err(_)) => return Ordering::Less,
+            (Err(_), Ok(_)) => return Ordering::Greater,
+            (Err(_), Err(_)) => {}
+        }
+    }
+    a.cmp(b)
+}
+
+/// Grid positions in sorted order. The sort is stable, so rows with equal keys keep their
+/// relative order; empty cells go last in both directions.
+pub fn sorted_row_order(
+    grid: &[Vec<String>],
+    col_index: usize,
+    data_type: ColumnDataType,
+    ascending: bool,
+) -> Vec<usize> {
+    let cell = |row: usize| grid[row].get(col_index).map(|s| s.as_str()).unwrap_or("");
+    let mut order: Vec<usize> = (0..grid.len()).collect();
+    order.sort_by(|&x, &y| {
+        let (a, b) = (cell(x), cell(y));
+        match (a.trim().is_empty(), b.trim().is_empty()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => {
+                let ord = compare_cells(a, b, data_type);
+                if ascending {
+                    ord
+                } else {
+                    ord.reverse()
+                }
+            }
+        }
+    });
+    order
+}
+
+/// (old row_index, new row_index) pairs that store `order` given the grid's current
+/// `row_indices`: the existing values are handed out again, highest first, because
+/// sheets are displayed by descending row_index.
+pub fn row_index_remapping(row_indices: &[i64], order: &[usize]) -> Vec<(i64, i64)> {
+    let mut slots = row_indices.to_vec();
+    slots.sort_unstable_by(|a, b| b.cmp(a));
+    order
+        .iter()
+        .zip(slots)
+        .map(|(&pos, new_index)| (row_indices[pos], new_index))
+        .collect()
+}
+
+/// Everything needed to apply one sort, computed from the registry before any write
+struct SortPlan {
+    /// Old grid positions in their new order
+    order: Vec<usize>,
+    /// (old, new) row_index pairs; empty for JSON sheets
+    mapping: Vec<(i64, i64)>,
+    child_tables: Vec<String>,
+    column_label: String,
+}
+
+fn plan_sort(registry: &SheetRegistry, event: &SortSheetByColumnEvent) -> Result<SortPlan, String> {
+    let sheet = registry
+        .get_sheet(&event.category, &event.sheet_name)
+        .ok_or_else(|| "sheet not found".to_string())?;
+    let metadata = sheet
+        .metadata
+        .as_ref()
+        .ok_or_else(|| "metadata missing".to_string())?;
+    let col_def = metadata
+        .columns
+        .get(event.col_index)
+        .ok_or_else(|| format!("column index {} out of range", event.col_index))?;
+    let order = sorted_row_order(&sheet.grid, event.col_index, col_def.data_type, event.ascending);
+    let mapping = if metadata.category.is_some() {
+        if sheet.row_indices.len() != sheet.grid.len() {
+            return Err(
+                "row indices are out of sync with the grid; reload the sheet and retry".to_string(),
+            );
+        }
+        row_index_remapping(&sheet.row_indices, &order)
+    } else {
+        Vec::new()
+    };
+    let child_tables = metadata
+        .columns
+        .iter()
+        .filter(|c| !c.deleted && matches!(c.validator, Some(ColumnValidator::Structure)))
+        .map(|c| structure_child_table_name(&event.sheet_name, &c.header))
+        .collect();
+    Ok(SortPlan {
+        order,
+        mapping,
+        child_tables,
+        column_label: col_def.display_name().to_string(),
+    })
+}
+
+pub fn handle_sort_sheet_by_column(
+    mut events: EventReader<SortSheetByColumnEvent>,
+    mut registry: ResMut<SheetRegistry>,
+    mut feedback_writer: EventWriter<SheetOperationFeedback>,
+    mut data_modified_writer: EventWriter<SheetDataModifiedInRegistryEvent>,
+    mut render_cache: ResMut<SheetRenderCache>,
+    daemon_client: Res<crate::sheets::database::daemon_resource::SharedDaemonClient>,
+) {
+    for event in events.read() {
+        let (category, sheet_name) = (&event.category, &event.sheet_name);
+        let direction = if event.ascending { "ascending" } else { "descending" };
+
+        // Work out the new order without touching the registry, so a failed DB write
+        // leaves memory and database in agreement
+        let SortPlan {
+            order,
+            mapping,
+            child_tables,
+            column_label,
+        } = match plan_sort(&registry, event) {
+            Ok(plan) => plan,
+            Err(e) => {
+                feedback_writer.write(SheetOperationFeedback {
+                    message: format!("Sort failed for '{:?}/{}': {}", category, sheet_name, e),
+                    is_error: true,
+                });
+                continue;
+            }
+        };
+
+        if let Some(cat) = category {
+            let db_path = crate::sheets::systems::io::get_default_data_base_path()
+                .join(format!("{}.db", cat));
+            let persisted = crate::sheets::database::connection::DbConnection::open_existing(&db_path)
+                .map_err(|e| e.to_string())
+                .and_then(|conn| {
+                    crate::sheets::database::writer::DbWriter::remap_row_indices(
+                        &conn,
+                        sheet_name,
+                        &mapping,
+                        db_path.file_name().and_then(|n| n.to_str()),
+                        daemon_client.client(),
+                    )
+                    .map_err(|e| e.to_string())
+                });
+            if let Err(e) = persisted {
+                error!("Failed to persist sort of '{:?}/{}': {}", category, sheet_name, e);
+                feedback_writer.write(SheetOperationFeedback {
+                    message: format!("Sort failed for '{:?}/{}': {}", category, sheet_name, e),
+                    is_error: true,
+                });
+                continue;
+            }
+        }
+
+        let mut json_meta_to_save = None;
+        if let Some(sheet) = registry.get_sheet_mut(category, sheet_name) {
+            let mut old_rows: Vec<Option<Vec<String>>> =
+                std::mem::take(&mut sheet.grid).into_iter().map(Some).collect();
+            sheet.grid = order.iter().filter_map(|&pos| old_rows[pos].take()).collect();
+            if !mapping.is_empty() {
+                sheet.row_indices = mapping.iter().map(|(_, new)| *new).collect();
+                let row_index_col = sheet.metadata.as_ref().and_then(|m| {
+                    m.columns.iter().position(|c| c.header.eq_ignore_ascii_case("row_index"))
+                });
+                if let Some(ri_col) = row_index_col {
+                    for (row, new_index) in sheet.grid.iter_mut().zip(sheet.row_indices.iter()) {
+                        if let Some(cell) = row.get_mut(ri_col) {
+                            *cell = new_index.to_string();
+                        }
+                    }
+                }
+            }
+            if category.is_none() {
+                json_meta_to_save = sheet.metadata.clone();
+            }
+        }
+        render_cache.clear_sheet_render_data(category, sheet_name);
+        data_modified_writer.write(SheetDataModifiedInRegistryEvent {
+            category: category.clone(),
+            sheet_name: sheet_name.clone(),
+        });
+
+        // Loaded child tables follow the new parent row_index values
+        let key_map: HashMap<String, String> = mapping
+            .iter()
+            .filter(|(old, new)| old != new)
+            .map(|(old, new)| (old.to_string(), new.to_string()))
+            .collect();
+        if !key_map.is_empty() {
+            for child_name in &child_tables {
+                let Some(child) = registry.get_sheet_mut(category, child_name) else {
+                    continue;
+                };
+                let Some(pk_col) = child.metadata.as_ref().and_then(|m| {
+                    m.columns.iter().position(|c| c.header.eq_ignore_ascii_case("parent_key"))
+                }) else {
+                    continue;
+                };
+                for row in child.grid.iter_mut() {
+                    if let Some(cell) = row.get_mut(pk_col) {
+                        if let Some(new_key) = key_map.get(cell.as_str()) {
+                            *cell = new_key.clone();
+                        }
+                    }
+                }
+                render_cache.clear_sheet_render_data(category, child_name);
+                data_modified_writer.write(SheetDataModifiedInRegistryEvent {
+                    category: category.clone(),
+                    sheet_name: child_name.clone(),
+                });
+            }
+        }
+
+        if let Some(meta) = json_meta_to_save {
+            save_single_sheet(&*registry, &meta);
+        }
+
+        let msg = format!(
+            "Sorted '{}' by '{}' ({}).",
+            sheet_name, column_label, direction
+        );
+        info!("{} [{:?}]", msg, category);
+        feedback_writer.write(SheetOperationFeedback {
+            message: msg,
+            is_error: false,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(values: &[&str]) -> Vec<Vec<String>> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, v)| vec![i.to_string(), v.to_string()])
+            .collect()
+    }
+
+    #[test]
+    fn numeric_columns_sort_by_value() {
+        let g = grid(&["10", "9", "-1.5", "100"]);
+        assert_eq!(sorted_row_order(&g, 1, ColumnDataType::F64, true), vec![2, 1, 0, 3]);
+        // Lexicographic for strings
+        assert_eq!(sorted_row_order(&g, 1, ColumnDataType::String, true), vec![2, 0, 3, 1]);
+    }
+
+    #[test]
+    fn sort_is_stable_and_keeps_empty_cells_last() {
+        let g = grid(&["b", "", "a", "b", "a"]);
+        assert_eq!(sorted_row_order(&g, 1, ColumnDataType::String, true), vec![2, 4, 0, 3, 1]);
+        assert_eq!(sorted_row_order(&g, 1, ColumnDataType::String, false), vec![0, 3, 2, 4, 1]);
+    }
+
+    #[test]
+    fn remapping_hands_out_existing_indices_highest_first() {
+        // Grid shown as row_index 7, 5, 2; sorted order puts the last row first
+        let mapping = row_index_remapping(&[7, 5, 2], &[2, 0, 1]);
+        assert_eq!(mapping, vec![(2, 7), (7, 5), (5, 2)]);
+    }
+}
@@ -0,0 +1,404 @@
+// src/sheets/systems/logic/structure_search.rs
+// Search a sheet and, recursively, its `{sheet}_{column}` structure child tables.
+//
+// The scan runs on a background thread over snapshots of the loaded tables; child tables
+// that are still unloaded stubs are read from the category database by the thread. The
+// UI polls the result and can cancel a long scan between rows.
+
+use crate::sheets::database::{
+    daemon_client::DaemonClient, daemon_manager::get_daemon_path, reader::DbReader,
+};
+use crate::sheets::definitions::{ColumnValidator, SheetGridData, SheetMetadata};
+use crate::sheets::events::RequestStructureSearch;
+use crate::sheets::resources::SheetRegistry;
+use bevy::prelude::*;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::sync::{Arc, Mutex};
+
+/// Stop collecting after this many hits; the outcome reports the truncation
+pub const MAX_SEARCH_HITS: usize = 1000;
+/// Characters of context kept on each side of a match in a hit's snippet
+const SNIPPET_CONTEXT: usize = 20;
+
+/// A parent row on the way from the searched sheet down to a hit's table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchAncestor {
+    pub table_name: String,
+    /// The parent row's `row_index` value (the child rows' `parent_key`)
+    pub row_key: String,
+    /// First content column of the parent row, for breadcrumbs
+    pub display: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchHit {
+    pub table_name: String,
+    /// Grid row of the hit in its table
+    pub row_index: usize,
+    pub col_index: usize,
+    pub snippet: String,
+    /// Parent rows from the searched sheet down to the hit's table (empty for the sheet itself)
+    pub path: Vec<SearchAncestor>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SearchOutcome {
+    pub hits: Vec<SearchHit>,
+    pub tables_scanned: usize,
+    pub truncated: bool,
+    pub cancelled: bool,
+}
+
+struct SearchJob {
+    cancel: Arc<AtomicBool>,
+    result_rx: Mutex<Receiver<SearchOutcome>>,
+}
+
+/// Query, progress and results of the structure-aware search panel
+#[derive(Resource, Default)]
+pub struct StructureSearch {
+    /// (category, sheet) the results belong to
+    pub searched_sheet: Option<(Option<String>, String)>,
+    pub query: String,
+    pub case_sensitive: bool,
+    pub outcome: Option<SearchOutcome>,
+    job: Option<SearchJob>,
+}
+
+impl StructureSearch {
+    pub fn is_running(&self) -> bool {
+        self.job.is_some()
+    }
+
+    /// Ask the running scan to stop; the hits found so far are still reported
+    pub fn cancel(&self) {
+        if let Some(job) = &self.job {
+            job.cancel.store(true, Ordering::Relaxed);
+        }
+    }
+}
+
+fn fold(c: char, case_sensitive: bool) -> char {
+    if case_sensitive {
+        c
+    } else {
+        c.to_lowercase().next().unwrap_or(c)
+    }
+}
+
+/// Snippet around the first occurrence of `query` in `cell`, or None when it does not occur.
+/// `query` must be non-empty.
+pub fn match_snippet(cell: &str, query: &str, case_sensitive: bool) -> Option<String> {
+    let chars: Vec<char> = cell.chars().collect();
+    let folded: Vec<char> = chars.iter().map(|&c| fold(c, case_sensitive)).collect();
+    let needle: Vec<char> = query.chars().map(|c| fold(c, case_sensitive)).collect();
+    if needle.is_empty() || needle.len() > folded.len() {
+        return None;
+    }
+    let start = folded.windows(needle.len()).position(|w| w == needle.as_slice())?;
+    let end = start + needle.len();
+    let from = start.saturating_sub(SNIPPET_CONTEXT);
+    let to = (end + SNIPPET_CONTEXT).min(chars.len());
+    let mut snippet: String = chars[from..to].iter().collect();
+    if from > 0 {
+        snippet.insert(0, '…');
+    }
+    if to < chars.len() {
+        snippet.push('…');
+    }
+    Some(snippet)
+}
+
+fn column_position(metadata: &SheetMetadata, header: &str) -> Option<usize> {
+    metadata
+        .columns
+        .iter()
+        .position(|c| c.header.eq_ignore_ascii_case(header))
+}
+
+/// Breadth-first scan of `root` and its structure child tables. `load` returns a table's
+/// data (None when it does not exist); technical columns are not searched.
+pub fn scan_structure_tables(
+    root: &str,
+    mut load: impl FnMut(&str) -> Option<SheetGridData>,
+    query: &str,
+    case_sensitive: bool,
+    cancel: &AtomicBool,
+) -> SearchOutcome {
+    let mut outcome = SearchOutcome::default();
+    let query = query.trim();
+    if query.is_empty() {
+        return outcome;
+    }
+    // Loaded tables by name, with their parent table, for lineage lookups
+    let mut tables: HashMap<String, (SheetGridData, Option<String>)> = HashMap::new();
+    let mut visited = HashSet::from([root.to_string()]);
+    let mut queue = VecDeque::from([(root.to_string(), None::<String>)]);
+
+    while let Some((table_name, parent)) = queue.pop_front() {
+        let Some(data) = load(&table_name) else {
+            continue;
+        };
+        outcome.tables_scanned += 1;
+        let Some(metadata) = data.metadata.as_ref() else {
+            continue;
+        };
+        for col in &metadata.columns {
+            if matches!(col.validator, Some(ColumnValidator::Structure)) {
+                let child = format!("{}_{}", table_name, col.header);
+                if visited.insert(child.clone()) {
+                    queue.push_back((child, Some(table_name.clone())));
+                }
+            }
+        }
+
+        let searchable: Vec<usize> = (0..metadata.columns.len())
+            .filter(|&i| {
+                let header = &metadata.columns[i].header;
+                !header.eq_ignore_ascii_case("row_index")
+                    && !header.eq_ignore_ascii_case("parent_key")
+                    && !matches!(metadata.columns[i].validator, Some(ColumnValidator::Structure))
+            })
+            .collect();
+        let parent_key_col = column_position(metadata, "parent_key");
+        for (row_index, row) in data.grid.iter().enumerate() {
+            if cancel.load(Ordering::Relaxed) {
+                outcome.cancelled = true;
+                return outcome;
+            }
+            for &col_index in &searchable {
+                let Some(cell) = row.get(col_index) else {
+                    continue;
+                };
+                let Some(snippet) = match_snippet(cell, query, case_sensitive) else {
+                    continue;
+                };
+                if outcome.hits.len() >= MAX_SEARCH_HITS {
+                    outcome.truncated = true;
+                    return outcome;
+                }
+                let parent_key = parent_key_col.and_then(|c| row.get(c)).cloned();
+                outcome.hits.push(SearchHit {
+                    table_name: table_name.clone(),
+                    row_index,
+                    col_index,
+                    snippet,
+                    path: lineage(&tables, parent.as_deref(), parent_key),
+                });
+            }
+        }
+        tables.insert(table_name, (data, parent));
+    }
+    outcome
+}
+
+/// Walk `parent_key` links up to the searched sheet, returning ancestors root-first
+fn lineage<'a>(
+    tables: &'a HashMap<String, (SheetGridData, Option<String>)>,
+    mut parent: Option<&'a str>,
+    mut parent_key: Option<String>,
+) -> Vec<SearchAncestor> {
+    let mut path = Vec::new();
+    while let (Some(table_name), Some(key)) = (parent, parent_key.take()) {
+        let Some((data, grandparent)) = tables.get(table_name) else {
+            break;
+        };
+        let Some(metadata) = data.metadata.as_ref() else {
+            break;
+        };
+        let key = key.trim().to_string();
+        let Some(row) = data
+            .grid
+            .iter()
+            .find(|row| row.first().is_some_and(|v| v.trim() == key))
+        else {
+            break;
+        };
+        path.push(SearchAncestor {
+            table_name: table_name.to_string(),
+            row_key: key,
+            display: metadata.get_first_data_column_value(row),
+        });
+        parent_key = column_position(metadata, "parent_key").and_then(|c| row.get(c)).cloned();
+        parent = grandparent.as_deref();
+    }
+    path.reverse();
+    path
+}
+
+/// Start a scan for each request, cancelling the one in progress
+pub fn handle_structure_search_requests(
+    mut events: EventReader<RequestStructureSearch>,
+    registry: Res<SheetRegistry>,
+    mut search: ResMut<StructureSearch>,
+) {
+    let Some(event) = events.read().last() else {
+        return;
+    };
+    search.cancel();
+    search.job = None;
+    search.searched_sheet = Some((event.category.clone(), event.sheet_name.clone()));
+    search.query = event.query.clone();
+    search.case_sensitive = event.case_sensitive;
+    search.outcome = None;
+
+    // Snapshot the root and every loaded table named like one of its descendants
+    let prefix = format!("{}_", event.sheet_name);
+    let snapshots: HashMap<String, SheetGridData> = registry
+        .iter_sheets()
+        .filter(|(cat, name, data)| {
+            *cat == &event.category
+                && (name.as_str() == event.sheet_name || name.starts_with(&prefix))
+                && data.metadata.as_ref().is_some_and(|m| !m.columns.is_empty())
+        })
+        .map(|(_, name, data)| (name.clone(), data.clone()))
+        .collect();
+    let db_path = event.category.as_ref().map(|cat| {
+        crate::sheets::systems::io::get_default_data_base_path().join(format!("{}.db", cat))
+    });
+
+    let cancel = Arc::new(AtomicBool::new(false));
+    let (tx, rx) = mpsc::channel();
+    let root = event.sheet_name.clone();
+    let query = event.query.clone();
+    let case_sensitive = event.case_sensitive;
+    let thread_cancel = cancel.clone();
+    std::thread::spawn(move || {
+        let mut snapshots = snapshots;
+        let conn = db_path
+            .as_ref()
+            .filter(|p| p.exists())
+            .and_then(|p| rusqlite::Connection::open(p).ok());
+        let daemon_client = DaemonClient::new(None, get_daemon_path().to_string_lossy().to_string());
+        let db_name = db_path
+            .as_ref()
+            .and_then(|p| p.file_name())
+            .and_then(|n| n.to_str())
+            .map(str::to_string);
+        let load = |table: &str| -> Option<SheetGridData> {
+            if let Some(data) = snapshots.remove(table) {
+                return Some(data);
+            }
+            // Unloaded stub: read it straight from the category database
+            let conn = conn.as_ref()?;
+            if !crate::sheets::database::schema::queries::table_exists(conn, table).ok()? {
+                return None;
+            }
+            DbReader::read_sheet(conn, table, &daemon_client, db_name.as_deref()).ok()
+        };
+        let outcome = scan_structure_tables(&root, load, &query, case_sensitive, &thread_cancel);
+        let _ = tx.send(outcome);
+    });
+    search.job = Some(SearchJob {
+        cancel,
+        result_rx: Mutex::new(rx),
+    });
+}
+
+/// Pick up the result of a finished scan
+pub fn poll_structure_search(mut search: ResMut<StructureSearch>) {
+    let Some(job) = &search.job else {
+        return;
+    };
+    let received = match job.result_rx.lock() {
+        Ok(rx) => rx.try_recv(),
+        Err(_) => Err(TryRecvError::Disconnected),
+    };
+    match received {
+        Ok(outcome) => {
+            info!(
+                "Structure search for '{}': {} hit(s) in {} table(s){}",
+                search.query,
+                outcome.hits.len(),
+                outcome.tables_scanned,
+                if outcome.cancelled { " (cancelled)" } else { "" }
+            );
+            search.outcome = Some(outcome);
+            search.job = None;
+        }
+        Err(TryRecvError::Empty) => {}
+        Err(TryRecvError::Disconnected) => {
+            warn!("Structure search thread ended without a result");
+            search.job = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sheets::definitions::SheetMetadata;
+
+    fn table(name: &str, headers: &[&str], rows: &[&[&str]]) -> SheetGridData {
+        let mut metadata =
+            SheetMetadata::create_generic(name.into(), format!("{}.json", name), headers.len(), None);
+        for (col, header) in metadata.columns.iter_mut().zip(headers) {
+            col.header = header.to_string();
+            if *header == "Parts" {
+                col.validator = Some(ColumnValidator::Structure);
+            }
+        }
+        SheetGridData {
+            metadata: Some(metadata),
+            grid: rows
+                .iter()
+                .map(|r| r.iter().map(|v| v.to_string()).collect())
+                .collect(),
+            row_indices: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn snippet_is_case_insensitive_by_default_and_trimmed_around_the_match() {
+        assert_eq!(match_snippet("Iron Sword", "sword", false), Some("Iron Sword".into()));
+        assert_eq!(match_snippet("Iron Sword", "sword", true), None);
+        let long = format!("{}needle{}", "a".repeat(30), "b".repeat(30));
+        let snippet = match_snippet(&long, "NEEDLE", false).unwrap();
+        assert_eq!(snippet, format!("…{}needle{}…", "a".repeat(20), "b".repeat(20)));
+    }
+
+    #[test]
+    fn scan_descends_into_child_tables_with_lineage() {
+        let mut db: HashMap<String, SheetGridData> = HashMap::new();
+        db.insert(
+            "Items".into(),
+            table("Items", &["row_index", "Name", "Parts"], &[&["1", "Sword", ""], &["2", "Iron Axe", ""]]),
+        );
+        db.insert(
+            "Items_Parts".into(),
+            table(
+                "Items_Parts",
+                &["row_index", "parent_key", "Part"],
+                &[&["10", "1", "Blade"], &["11", "2", "Iron head"]],
+            ),
+        );
+        let cancel = AtomicBool::new(false);
+        let outcome =
+            scan_structure_tables("Items", |t| db.get(t).cloned(), "iron", false, &cancel);
+
+        assert_eq!(outcome.tables_scanned, 2);
+        let found: Vec<(&str, usize, usize)> = outcome
+            .hits
+            .iter()
+            .map(|h| (h.table_name.as_str(), h.row_index, h.col_index))
+            .collect();
+        assert_eq!(found, vec![("Items", 1, 1), ("Items_Parts", 1, 2)]);
+        assert!(outcome.hits[0].path.is_empty());
+        assert_eq!(
+            outcome.hits[1].path,
+            vec![SearchAncestor {
+                table_name: "Items".into(),
+                row_key: "2".into(),
+                display: "Iron Axe".into(),
+            }]
+        );
+
+        cancel.store(true, Ordering::Relaxed);
+        let outcome =
+            scan_structure_tables("Items", |t| db.get(t).cloned(), "iron", false, &cancel);
+        assert!(outcome.cancelled);
+        assert!(outcome.hits.is_empty());
+    }
+}
@@ -0,0 +1,176 @@
+// src/sheets/systems/logic/structure_tree.rs
+// Structure hierarchy of a sheet for the editor's tree panel.
+//
+// A table's children are the `{sheet}_{column}` tables of its Structure columns plus any
+// table whose `structure_parent` link points back at it. Links can be stale or circular
+// (e.g. after renames), so every walk keeps a visited set and stops at the first repeat.
+
+use crate::sheets::definitions::ColumnValidator;
+use crate::sheets::resources::SheetRegistry;
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StructureTreeNode {
+    pub category: Option<String>,
+    pub sheet_name: String,
+    /// Rows in the registry; None while the table is still an unloaded stub
+    pub row_count: Option<usize>,
+    pub children: Vec<StructureTreeNode>,
+}
+
+/// Top-most ancestor of `sheet_name`, following `structure_parent` links.
+pub fn structure_tree_root(
+    registry: &SheetRegistry,
+    category: &Option<String>,
+    sheet_name: &str,
+) -> (Option<String>, String) {
+    let mut current = (category.clone(), sheet_name.to_string());
+    let mut visited = HashSet::from([current.clone()]);
+    while let Some(link) = registry
+        .get_sheet(&current.0, &current.1)
+        .and_then(|sheet| sheet.metadata.as_ref())
+        .and_then(|meta| meta.structure_parent.as_ref())
+    {
+        let parent = (link.parent_category.clone(), link.parent_sheet.clone());
+        if registry.get_sheet(&parent.0, &parent.1).is_none() || !visited.insert(parent.clone()) {
+            break;
+        }
+        current = parent;
+    }
+    current
+}
+
+/// Structure hierarchy below `sheet_name`. None when the sheet is not in the registry.
+pub fn build_structure_tree(
+    registry: &SheetRegistry,
+    category: &Option<String>,
+    sheet_name: &str,
+) -> Option<StructureTreeNode> {
+    let mut visited = HashSet::new();
+    build_node(registry, category, sheet_name, &mut visited)
+}
+
+fn build_node(
+    registry: &SheetRegistry,
+    category: &Option<String>,
+    sheet_name: &str,
+    visited: &mut HashSet<(Option<String>, String)>,
+) -> Option<StructureTreeNode> {
+    if !visited.insert((category.clone(), sheet_name.to_string())) {
+        return None;
+    }
+    let sheet = registry.get_sheet(category, sheet_name)?;
+    let meta = sheet.metadata.as_ref();
+    // Lazily registered DB tables have no columns until opened
+    let loaded = meta.is_some_and(|m| !m.columns.is_empty());
+
+    let mut child_keys: Vec<(Option<String>, String)> = meta
+        .map(|m| {
+            m.columns
+                .iter()
+                .filter(|col| matches!(col.validator, Some(ColumnValidator::Structure)))
+                .map(|col| (category.clone(), format!("{}_{}", sheet_name, col.header)))
+                .filter(|(cat, name)| registry.get_sheet(cat, name).is_some())
+                .collect()
+        })
+        .unwrap_or_default();
+    let mut linked: Vec<(Option<String>, String)> = registry
+        .iter_sheets()
+        .filter(|(_, name, _)| !name.starts_with("__virtual__"))
+        .filter(|(_, _, data)| {
+            data.metadata
+                .as_ref()
+                .and_then(|m| m.structure_parent.as_ref())
+                .is_some_and(|link| {
+                    &link.parent_category == category && link.parent_sheet == sheet_name
+                })
+        })
+        .map(|(cat, name, _)| (cat.clone(), name.clone()))
+        .collect();
+    linked.sort();
+    for key in linked {
+        if !child_keys.contains(&key) {
+            child_keys.push(key);
+        }
+    }
+
+    let children = child_keys
+        .iter()
+        .filter_map(|(cat, name)| build_node(registry, cat, name, visited))
+        .collect();
+    Some(StructureTreeNode {
+        category: category.clone(),
+        sheet_name: sheet_name.to_string(),
+        row_count: loaded.then_some(sheet.grid.len()),
+        children,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sheets::definitions::{SheetGridData, SheetMetadata, StructureParentLink};
+
+    fn sheet(name: &str, rows: usize, parent: Option<&str>) -> SheetGridData {
+        let category = Some("Game".to_string());
+        let mut metadata = SheetMetadata::create_generic(
+            name.to_string(),
+            format!("{}.json", name),
+            2,
+            category.clone(),
+        );
+        metadata.structure_parent = parent.map(|p| StructureParentLink {
+            parent_category: category,
+            parent_sheet: p.to_string(),
+            parent_column_index: 1,
+        });
+        SheetGridData {
+            metadata: Some(metadata),
+            grid: vec![vec![String::new(); 2]; rows],
+            row_indices: (0..rows as i64).collect(),
+        }
+    }
+
+    #[test]
+    fn builds_hierarchy_from_validators_and_links_despite_cycles() {
+        let cat = Some("Game".to_string());
+        let mut registry = SheetRegistry::default();
+
+        // Items has a Structure column "Tags"; its child is found by name
+        let mut items = sheet("Items", 3, Some("Items_Tags_Notes"));
+        let tags_col = &mut items.metadata.as_mut().unwrap().columns[1];
+        tags_col.header = "Tags".to_string();
+        tags_col.validator = Some(ColumnValidator::Structure);
+        registry.add_or_replace_sheet(cat.clone(), "Items".to_string(), items);
+        registry.add_or_replace_sheet(
+            cat.clone(),
+            "Items_Tags".to_string(),
+            sheet("Items_Tags", 5, Some("Items")),
+        );
+        // Grandchild only reachable through its structure_parent link; Items links back to it
+        registry.add_or_replace_sheet(
+            cat.clone(),
+            "Items_Tags_Notes".to_string(),
+            sheet("Items_Tags_Notes", 1, Some("Items_Tags")),
+        );
+
+        assert_eq!(
+            structure_tree_root(&registry, &cat, "Items_Tags_Notes"),
+            (cat.clone(), "Items".to_string())
+        );
+
+        let tree = build_structure_tree(&registry, &cat, "Items").unwrap();
+        assert_eq!(tree.row_count, Some(3));
+        assert_eq!(tree.children.len(), 1);
+        let tags = &tree.children[0];
+        assert_eq!(
+            (tags.sheet_name.as_str(), tags.row_count),
+            ("Items_Tags", Some(5))
+        );
+        assert_eq!(tags.children.len(), 1);
+        let notes = &tags.children[0];
+        assert_eq!(notes.sheet_name, "Items_Tags_Notes");
+        // The cyclic link back to Items does not recurse
+        assert!(notes.children.is_empty());
+    }
+}
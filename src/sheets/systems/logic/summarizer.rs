@@ -0,0 +1,130 @@
+// src/sheets/systems/logic/summarizer.rs
+// Statistics computed by the toybox Summarizer over the numeric cells of the selected columns
+// (or over the dates, when every selected column is a Date column)
+
+use crate::sheets::systems::logic::date_values::ISO_DATE_FORMAT;
+use crate::ui::elements::editor::state::SummarizerStat;
+use chrono::NaiveDate;
+
+/// Apply `stat` to `values`. Sum and Count of no values are 0; the other stats are NaN
+/// because they are undefined for an empty set.
+pub fn compute_stat(values: &[f64], stat: SummarizerStat) -> f64 {
+    match stat {
+        SummarizerStat::Sum => values.iter().sum(),
+        SummarizerStat::Count => values.len() as f64,
+        _ if values.is_empty() => f64::NAN,
+        SummarizerStat::Mean => values.iter().sum::<f64>() / values.len() as f64,
+        SummarizerStat::Min => values.iter().cloned().fold(f64::INFINITY, f64::min),
+        SummarizerStat::Max => values.iter().cloned().fold(f64::NEG_INFINITY, f64::max),
+        SummarizerStat::Median => {
+            let mut sorted = values.to_vec();
+            sorted.sort_by(|a, b| a.total_cmp(b));
+            let mid = sorted.len() / 2;
+            if sorted.len() % 2 == 0 {
+                (sorted[mid - 1] + sorted[mid]) / 2.0
+            } else {
+                sorted[mid]
+            }
+        }
+    }
+}
+
+/// Result line shown in the top panel, e.g. "Mean: 12.3400 (values: 5, invalid: 1)"
+pub fn format_stat_result(stat: SummarizerStat, result: f64, count: usize, invalid: usize) -> String {
+    let value = match stat {
+        SummarizerStat::Count => format!("{}", count),
+        _ if result.is_nan() => "n/a".to_string(),
+        _ => format!("{:.4}", result),
+    };
+    format!(
+        "{}: {} (values: {}, invalid: {})",
+        stat.label(),
+        value,
+        count,
+        invalid
+    )
+}
+
+/// Result line for Date columns: Min/Max are the earliest/latest date and Count counts
+/// dates; the arithmetic stats are not defined for dates.
+pub fn format_date_stat_result(stat: SummarizerStat, dates: &[NaiveDate], invalid: usize) -> String {
+    let value = match stat {
+        SummarizerStat::Count => dates.len().to_string(),
+        SummarizerStat::Min => dates
+            .iter()
+            .min()
+            .map_or("n/a".to_string(), |d| d.format(ISO_DATE_FORMAT).to_string()),
+        SummarizerStat::Max => dates
+            .iter()
+            .max()
+            .map_or("n/a".to_string(), |d| d.format(ISO_DATE_FORMAT).to_string()),
+        _ => "n/a for dates".to_string(),
+    };
+    format!(
+        "{}: {} (values: {}, invalid: {})",
+        stat.label(),
+        value,
+        dates.len(),
+        invalid
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn median_averages_middle_pair_for_even_length() {
+        assert_eq!(compute_stat(&[4.0, 1.0, 3.0, 2.0], SummarizerStat::Median), 2.5);
+        assert_eq!(compute_stat(&[5.0, 1.0, 3.0], SummarizerStat::Median), 3.0);
+    }
+
+    #[test]
+    fn basic_stats() {
+        let v = [2.0, -1.0, 5.0, 2.0];
+        assert_eq!(compute_stat(&v, SummarizerStat::Sum), 8.0);
+        assert_eq!(compute_stat(&v, SummarizerStat::Mean), 2.0);
+        assert_eq!(compute_stat(&v, SummarizerStat::Min), -1.0);
+        assert_eq!(compute_stat(&v, SummarizerStat::Max), 5.0);
+        assert_eq!(compute_stat(&v, SummarizerStat::Count), 4.0);
+    }
+
+    #[test]
+    fn empty_input_is_undefined_except_sum_and_count() {
+        assert_eq!(compute_stat(&[], SummarizerStat::Sum), 0.0);
+        assert_eq!(compute_stat(&[], SummarizerStat::Count), 0.0);
+        assert!(compute_stat(&[], SummarizerStat::Median).is_nan());
+        assert_eq!(
+            format_stat_result(SummarizerStat::Mean, f64::NAN, 0, 2),
+            "Mean: n/a (values: 0, invalid: 2)"
+        );
+        assert_eq!(
+            format_stat_result(SummarizerStat::Mean, 12.34, 3, 0),
+            "Mean: 12.3400 (values: 3, invalid: 0)"
+        );
+    }
+
+    #[test]
+    fn date_stats_report_earliest_and_latest() {
+        let dates: Vec<NaiveDate> = ["2024-05-01", "2023-12-31", "2024-01-15"]
+            .iter()
+            .map(|s| NaiveDate::parse_from_str(s, ISO_DATE_FORMAT).unwrap())
+            .collect();
+        assert_eq!(
+            format_date_stat_result(SummarizerStat::Min, &dates, 1),
+            "Min: 2023-12-31 (values: 3, invalid: 1)"
+        );
+        assert_eq!(
+            format_date_stat_result(SummarizerStat::Max, &dates, 0),
+            "Max: 2024-05-01 (values: 3, invalid: 0)"
+        );
+        assert_eq!(
+            format_date_stat_result(SummarizerStat::Mean, &dates, 0),
+            "Mean: n/a for dates (values: 3, invalid: 0)"
+        );
+        assert_eq!(
+            format_date_stat_result(SummarizerStat::Max, &[], 0),
+            "Max: n/a (values: 0, invalid: 0)"
+        );
+    }
+}
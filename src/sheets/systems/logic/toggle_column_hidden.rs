@@ -0,0 +1,93 @@
+// src/sheets/systems/logic/toggle_column_hidden.rs
+use crate::sheets::{
+    definitions::SheetMetadata,
+    events::{SheetOperationFeedback, ToggleColumnHiddenEvent},
+    resources::SheetRegistry,
+    systems::io::save::save_single_sheet,
+};
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// Flips the `hidden` flag of a column (header eye icon, Ctrl+Shift+H, "Show hidden columns").
+///
+/// Technical columns (row_index, parent_key, ...) are never toggled; they stay hidden
+/// unless shown through the developer setting. The grid picks the change up on the next
+/// frame because visible columns are recomputed from metadata while drawing.
+pub fn handle_toggle_column_hidden(
+    mut events: EventReader<ToggleColumnHiddenEvent>,
+    mut registry: ResMut<SheetRegistry>,
+    mut feedback_writer: EventWriter<SheetOperationFeedback>,
+    daemon_client: Res<crate::sheets::database::daemon_resource::SharedDaemonClient>,
+) {
+    let mut changed_json_sheets: HashMap<(Option<String>, String), SheetMetadata> =
+        HashMap::new();
+
+    for event in events.read() {
+        let category = &event.category;
+        let sheet_name = &event.sheet_name;
+
+        let Some(metadata) = registry
+            .get_sheet_mut(category, sheet_name)
+            .and_then(|s| s.metadata.as_mut())
+        else {
+            warn!(
+                "Column visibility toggle skipped: sheet '{:?}/{}' not found.",
+                category, sheet_name
+            );
+            continue;
+        };
+        let Some(column_def) = metadata.columns.get_mut(event.col_index) else {
+            warn!(
+                "Column visibility toggle skipped: column {} out of range in '{:?}/{}'.",
+                event.col_index, category, sheet_name
+            );
+            continue;
+        };
+        if column_def.deleted || SheetMetadata::is_technical_column_header(&column_def.header) {
+            continue;
+        }
+
+        column_def.hidden = !column_def.hidden;
+        let hidden = column_def.hidden;
+        let label = column_def.display_name().to_string();
+        info!(
+            "Column '{}' of '{:?}/{}' is now {}.",
+            label,
+            category,
+            sheet_name,
+            if hidden { "hidden" } else { "shown" }
+        );
+
+        if let Some(db_category) = metadata.category.clone() {
+            if let Err(e) = crate::sheets::database::persist_column_metadata(
+                &db_category,
+                &metadata.sheet_name,
+                event.col_index,
+                None,
+                None,
+                None,
+                Some(hidden),
+                daemon_client.client(),
+            ) {
+                error!(
+                    "Failed to persist hidden flag of column '{}' in '{:?}/{}': {}",
+                    label, category, sheet_name, e
+                );
+                feedback_writer.write(SheetOperationFeedback {
+                    message: format!("Failed to save visibility of column '{}': {}", label, e),
+                    is_error: true,
+                });
+            }
+        } else {
+            changed_json_sheets.insert((category.clone(), sheet_name.clone()), metadata.clone());
+        }
+    }
+
+    if !changed_json_sheets.is_empty() {
+        let registry_immut = registry.as_ref();
+        for ((cat, name), metadata) in changed_json_sheets {
+            debug!("Column visibility changed for '{:?}/{}', saving sheet.", cat, name);
+            save_single_sheet(registry_immut, &metadata);
+        }
+    }
+}
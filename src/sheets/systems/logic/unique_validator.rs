@@ -0,0 +1,133 @@
+// src/sheets/systems/logic/unique_validator.rs
+// Duplicate detection for columns with a `Unique` validator.
+//
+// Cells are compared by a normalized key: trimmed, numeric columns by parsed value (so "1"
+// and "1.0" collide in an F64 column), dates by their ISO form, bools case-insensitively, and
+// text case-insensitively
+// unless the validator is case sensitive. Empty cells never conflict.
+
+use crate::sheets::definitions::{ColumnDataType, ColumnValidator};
+use crate::sheets::systems::logic::date_values::normalize_date;
+use std::collections::HashMap;
+
+/// Comparison key of a cell, or `None` for an empty cell
+pub fn normalize_unique_key(
+    value: &str,
+    data_type: ColumnDataType,
+    case_sensitive: bool,
+) -> Option<String> {
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        return None;
+    }
+    let key = match data_type {
+        ColumnDataType::I64 | ColumnDataType::F64 => match trimmed.parse::<f64>() {
+            Ok(n) => (n + 0.0).to_string(),
+            Err(_) => trimmed.to_string(),
+        },
+        ColumnDataType::Bool => trimmed.to_lowercase(),
+        ColumnDataType::Date => normalize_date(trimmed).unwrap_or_else(|| trimmed.to_string()),
+        _ if case_sensitive => trimmed.to_string(),
+        _ => trimmed.to_lowercase(),
+    };
+    Some(key)
+}
+
+/// (data type, case sensitive) of a `Unique` validator, `None` for any other validator
+pub fn unique_settings(validator: &Option<ColumnValidator>) -> Option<(ColumnDataType, bool)> {
+    match validator {
+        Some(ColumnValidator::Unique {
+            data_type,
+            case_sensitive,
+        }) => Some((*data_type, *case_sensitive)),
+        _ => None,
+    }
+}
+
+/// First grid row (other than `exclude_row`) whose cell in `col_index` collides with `value`
+pub fn find_duplicate_row(
+    grid: &[Vec<String>],
+    col_index: usize,
+    value: &str,
+    exclude_row: Option<usize>,
+    data_type: ColumnDataType,
+    case_sensitive: bool,
+) -> Option<usize> {
+    let key = normalize_unique_key(value, data_type, case_sensitive)?;
+    grid.iter().enumerate().position(|(row_idx, row)| {
+        Some(row_idx) != exclude_row
+            && row
+                .get(col_index)
+                .and_then(|cell| normalize_unique_key(cell, data_type, case_sensitive))
+                .is_some_and(|k| k == key)
+    })
+}
+
+/// Every value that appears more than once in `col_index`, with the grid rows holding it.
+/// Groups are ordered by their first row; the value shown is the first row's cell text.
+pub fn duplicate_groups(
+    grid: &[Vec<String>],
+    col_index: usize,
+    data_type: ColumnDataType,
+    case_sensitive: bool,
+) -> Vec<(String, Vec<usize>)> {
+    let mut group_of_key: HashMap<String, usize> = HashMap::new();
+    let mut groups: Vec<(String, Vec<usize>)> = Vec::new();
+    for (row_idx, row) in grid.iter().enumerate() {
+        let Some(cell) = row.get(col_index) else {
+            continue;
+        };
+        let Some(key) = normalize_unique_key(cell, data_type, case_sensitive) else {
+            continue;
+        };
+        match group_of_key.get(&key) {
+            Some(&group_idx) => groups[group_idx].1.push(row_idx),
+            None => {
+                group_of_key.insert(key, groups.len());
+                groups.push((cell.trim().to_string(), vec![row_idx]));
+            }
+        }
+    }
+    groups.retain(|(_, rows)| rows.len() > 1);
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(values: &[&str]) -> Vec<Vec<String>> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, v)| vec![i.to_string(), v.to_string()])
+            .collect()
+    }
+
+    #[test]
+    fn text_comparison_honours_case_flag() {
+        let g = grid(&["Sword", "shield", "bow"]);
+        assert_eq!(find_duplicate_row(&g, 1, " SWORD ", None, ColumnDataType::String, false), Some(0));
+        assert_eq!(find_duplicate_row(&g, 1, "SWORD", None, ColumnDataType::String, true), None);
+        // The edited row itself is not a conflict
+        assert_eq!(find_duplicate_row(&g, 1, "sword", Some(0), ColumnDataType::String, false), None);
+    }
+
+    #[test]
+    fn numbers_compare_by_value_and_empty_cells_never_conflict() {
+        let g = grid(&["1.0", "", "-0"]);
+        assert_eq!(find_duplicate_row(&g, 1, "1", None, ColumnDataType::F64, true), Some(0));
+        assert_eq!(find_duplicate_row(&g, 1, "0", None, ColumnDataType::I64, true), Some(2));
+        assert_eq!(find_duplicate_row(&g, 1, "  ", None, ColumnDataType::String, true), None);
+    }
+
+    #[test]
+    fn duplicate_groups_list_rows_in_order() {
+        let g = grid(&["a", "B", "", "A", "b", "", "c"]);
+        assert_eq!(
+            duplicate_groups(&g, 1, ColumnDataType::String, false),
+            vec![("a".to_string(), vec![0, 3]), ("B".to_string(), vec![1, 4])]
+        );
+        assert!(duplicate_groups(&g, 1, ColumnDataType::String, true).is_empty());
+    }
+}
@@ -0,0 +1,423 @@
+// src/sheets/systems/logic/update_cell/batch_update.rs
+//! Batched cell updates - many edits, one DB transaction, one save, one revalidation
+
+use super::{
+    cascade, cell_update, db_persistence, extend_enum_values, record_edit, resolve_grid_row_index,
+    validation,
+};
+use crate::sheets::{
+    events::{
+        RequestSheetRevalidation, SheetDataModifiedInRegistryEvent, SheetOperationFeedback,
+        UpdateCellsBatchEvent,
+    },
+    resources::{EditHistory, SheetRegistry},
+    systems::{io::save::save_single_sheet, logic::row_locks::ensure_locked_rows_loaded},
+};
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+/// Key-column change that may need to cascade to child tables once the batch is written
+struct PendingCascade {
+    col_idx: usize,
+    header: String,
+    old_value: String,
+    new_value: String,
+}
+
+/// Cell of a DB-backed sheet changed in the grid, waiting for the batch write
+struct StagedEdit {
+    row_idx: usize,
+    col_idx: usize,
+    result: cell_update::CellUpdateResult,
+}
+
+/// System handler for `UpdateCellsBatchEvent`
+///
+/// Plain cells of DB-backed sheets are collected and written with a single
+/// `DbWriter::update_cells_by_id` call (one daemon transaction). They enter the edit
+/// history only once it commits; when it fails they get their old values back. Structure
+/// JSON cells of DB-backed sheets rebuild child rows outside that transaction, so they are
+/// rejected here and must be edited one at a time.
+pub fn handle_cell_batch_update(
+    mut events: EventReader<UpdateCellsBatchEvent>,
+    mut registry: ResMut<SheetRegistry>,
+    mut feedback_writer: EventWriter<SheetOperationFeedback>,
+    mut data_modified_writer: EventWriter<SheetDataModifiedInRegistryEvent>,
+    mut revalidate_writer: EventWriter<RequestSheetRevalidation>,
+    mut edit_history: ResMut<EditHistory>,
+    daemon_client: Res<crate::sheets::database::daemon_resource::SharedDaemonClient>,
+) {
+    for event in events.read() {
+        let category = &event.category;
+        let sheet_name = &event.sheet_name;
+        if event.edits.is_empty() {
+            continue;
+        }
+
+        let is_db_backed = registry
+            .get_sheet(category, sheet_name)
+            .and_then(|s| s.metadata.as_ref())
+            .map_or(false, |m| m.category.is_some());
+        let db = if is_db_backed {
+            match registry
+                .get_sheet(category, sheet_name)
+                .and_then(|s| s.metadata.as_ref())
+                .map(db_persistence::open_sheet_database)
+            {
+                Some(Ok(db)) => db,
+                Some(Err(e)) => {
+                    error!(
+                        "Batch update for '{:?}/{}' could not open database: {}",
+                        category, sheet_name, e
+                    );
+                    feedback_writer.write(SheetOperationFeedback {
+                        message: format!("Batch update failed for '{}': {}", sheet_name, e),
+                        is_error: true,
+                    });
+                    continue;
+                }
+                None => None,
+            }
+        } else {
+            None
+        };
+
+        ensure_locked_rows_loaded(&mut registry, category, sheet_name);
+        // Resolve every edit up-front so later edits see the same row mapping
+        let resolved: Vec<(usize, usize, &str)> = event
+            .edits
+            .iter()
+            .map(|edit| {
+                (
+                    resolve_grid_row_index(registry.as_ref(), category, sheet_name, edit.row_index),
+                    edit.col_index,
+                    edit.new_value.as_str(),
+                )
+            })
+            .collect();
+
+        let mut id_updates: Vec<(i64, String, String)> = Vec::new();
+        let mut cascades: Vec<PendingCascade> = Vec::new();
+        let mut verified_columns: HashSet<String> = HashSet::new();
+        let mut changed_count = 0usize;
+        let mut staged: Vec<StagedEdit> = Vec::new();
+        let mut rejected: Vec<String> = Vec::new();
+        // Values for auto-adding Enum columns; added once the cells are actually stored
+        let mut enum_additions: Vec<(usize, String)> = Vec::new();
+
+        for (row_idx, col_idx, raw_value) in resolved {
+            let validated = validation::validate_cell_location(
+                registry.as_ref(),
+                category,
+                sheet_name,
+                row_idx,
+                col_idx,
+            )
+            .and_then(|()| {
+                validation::validate_row_unlocked(registry.as_ref(), category, sheet_name, row_idx)
+            })
+            .and_then(|()| {
+                validation::normalize_date_value(
+                    registry.as_ref(),
+                    category,
+                    sheet_name,
+                    col_idx,
+                    raw_value,
+                )
+            })
+            .and_then(|new_value| {
+                validation::normalize_enum_value(
+                    registry.as_ref(),
+                    category,
+                    sheet_name,
+                    col_idx,
+                    &new_value,
+                )
+            })
+            .and_then(|new_value| {
+                validation::validate_numeric_range(
+                    registry.as_ref(),
+                    category,
+                    sheet_name,
+                    col_idx,
+                    &new_value,
+                )?;
+                validation::validate_unique_value(
+                    registry.as_ref(),
+                    category,
+                    sheet_name,
+                    row_idx,
+                    col_idx,
+                    &new_value,
+                )?;
+                Ok(new_value)
+            });
+            let new_value = match validated {
+                Ok(value) => value,
+                Err(err_msg) => {
+                    rejected.push(format!("cell[{},{}]: {}", row_idx, col_idx, err_msg));
+                    continue;
+                }
+            };
+            let new_value = new_value.as_str();
+
+            let Some(sheet_data) = registry.get_sheet(category, sheet_name) else {
+                continue;
+            };
+            let col_meta = cell_update::extract_column_metadata(&sheet_data.metadata, col_idx);
+            if db.is_some() {
+                if col_meta.is_structure_col && !col_meta.looks_like_real_structure {
+                    // Structure JSON rebuilds child rows, which cannot join the batch transaction
+                    rejected.push(format!(
+                        "cell[{},{}]: structure cells are edited one at a time, not in a batch",
+                        row_idx, col_idx
+                    ));
+                    continue;
+                }
+                if col_meta.looks_like_real_structure && col_idx < 2 {
+                    rejected.push(format!(
+                        "cell[{},{}]: id and parent_key are not editable",
+                        row_idx, col_idx
+                    ));
+                    continue;
+                }
+            }
+
+            let Some(sheet_data) = registry.get_sheet_mut(category, sheet_name) else {
+                continue;
+            };
+            let Some(row) = sheet_data.grid.get_mut(row_idx) else {
+                continue;
+            };
+            let Some(cell) = row.get_mut(col_idx) else {
+                continue;
+            };
+            // Later edits of the batch validate against the updated grid (e.g. unique
+            // checks); DB-backed cells are put back if the batch write fails
+            let update_result = cell_update::update_cell_value(
+                cell,
+                new_value,
+                &sheet_data.metadata,
+                col_idx,
+                row_idx,
+                category,
+                sheet_name,
+            );
+            if !update_result.changed {
+                continue;
+            }
+
+            let (Some(metadata), Some((conn, _))) = (&sheet_data.metadata, &db) else {
+                changed_count += 1;
+                enum_additions.push((col_idx, new_value.to_string()));
+                record_edit(
+                    &mut edit_history,
+                    category,
+                    sheet_name,
+                    &sheet_data.row_indices,
+                    row_idx,
+                    col_idx,
+                    &update_result,
+                );
+                continue;
+            };
+            let final_val = update_result.final_value.clone().unwrap_or_default();
+            let table_name = &metadata.sheet_name;
+
+            let column_ready = if verified_columns.contains(&col_meta.header) {
+                Ok(())
+            } else {
+                let ensured =
+                    db_persistence::ensure_column_exists(conn, table_name, &col_meta.header);
+                if ensured.is_ok() {
+                    verified_columns.insert(col_meta.header.clone());
+                }
+                ensured
+            };
+            let row_id = column_ready.and_then(|()| {
+                if col_meta.looks_like_real_structure {
+                    db_persistence::structure_row_id(row)
+                } else {
+                    db_persistence::regular_row_id(conn, metadata, row_idx, category)
+                }
+            });
+            match row_id {
+                Ok(row_id) => {
+                    if !col_meta.looks_like_real_structure
+                        && cascade::is_key_column(metadata, col_idx)
+                    {
+                        if let Some(old_value) = update_result.old_value.clone() {
+                            cascades.push(PendingCascade {
+                                col_idx,
+                                header: col_meta.header.clone(),
+                                old_value,
+                                new_value: final_val.clone(),
+                            });
+                        }
+                    }
+                    id_updates.push((row_id, col_meta.header, final_val));
+                    enum_additions.push((col_idx, new_value.to_string()));
+                    staged.push(StagedEdit {
+                        row_idx,
+                        col_idx,
+                        result: update_result,
+                    });
+                }
+                Err(e) => {
+                    row[col_idx] = update_result.old_value.unwrap_or_default();
+                    rejected.push(format!("cell[{},{}]: {}", row_idx, col_idx, e));
+                }
+            }
+        }
+
+        // Single transaction for all plain cell writes. The staged cells only count as
+        // edited (history, revalidation, Enum additions) once it commits; otherwise they
+        // are put back.
+        let mut batch_rolled_back = false;
+        if let (Some((conn, db_path)), Some(metadata)) = (
+            &db,
+            registry
+                .get_sheet(category, sheet_name)
+                .and_then(|s| s.metadata.as_ref()),
+        ) {
+            if !id_updates.is_empty() {
+                match crate::sheets::database::writer::DbWriter::update_cells_by_id(
+                    conn,
+                    &metadata.sheet_name,
+                    &id_updates,
+                    db_path.file_name().and_then(|n| n.to_str()),
+                    daemon_client.client(),
+                ) {
+                    Ok(()) => {
+                        for c in &cascades {
+                            cascade::cascade_key_change_if_needed(
+                                conn,
+                                metadata,
+                                c.col_idx,
+                                &c.header,
+                                &c.old_value,
+                                &c.new_value,
+                                daemon_client.client(),
+                            );
+                        }
+                        if let Some(sheet_data) = registry.get_sheet(category, sheet_name) {
+                            for edit in &staged {
+                                record_edit(
+                                    &mut edit_history,
+                                    category,
+                                    sheet_name,
+                                    &sheet_data.row_indices,
+                                    edit.row_idx,
+                                    edit.col_idx,
+                                    &edit.result,
+                                );
+                            }
+                        }
+                        changed_count += staged.len();
+                    }
+                    Err(e) => {
+                        let msg = format!(
+                            "Batch update of {} cell(s) in '{:?}/{}' was rolled back; the cells keep their previous values: {}",
+                            id_updates.len(),
+                            category,
+                            sheet_name,
+                            e
+                        );
+                        error!("{}", msg);
+                        feedback_writer.write(SheetOperationFeedback {
+                            message: msg,
+                            is_error: true,
+                        });
+                        batch_rolled_back = true;
+                        if let Some(sheet_data) = registry.get_sheet_mut(category, sheet_name) {
+                            // Newest first, so a cell edited twice ends on its pre-batch value
+                            for edit in staged.drain(..).rev() {
+                                if let Some(cell) = sheet_data
+                                    .grid
+                                    .get_mut(edit.row_idx)
+                                    .and_then(|row| row.get_mut(edit.col_idx))
+                                {
+                                    *cell = edit.result.old_value.unwrap_or_default();
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        if !batch_rolled_back {
+            for (col_idx, value) in &enum_additions {
+                extend_enum_values(
+                    &mut registry,
+                    category,
+                    sheet_name,
+                    *col_idx,
+                    value,
+                    daemon_client.client(),
+                );
+            }
+        }
+
+        if !rejected.is_empty() {
+            for r in &rejected {
+                warn!("Batch cell update rejected for '{:?}/{}' {}", category, sheet_name, r);
+            }
+            feedback_writer.write(SheetOperationFeedback {
+                message: format!(
+                    "{} of {} cell update(s) in '{}' were rejected (see log).",
+                    rejected.len(),
+                    event.edits.len(),
+                    sheet_name
+                ),
+                is_error: true,
+            });
+        }
+
+        if event.report_result && rejected.is_empty() && !batch_rolled_back {
+            feedback_writer.write(SheetOperationFeedback {
+                message: format!(
+                    "Updated {} of {} cell(s) in '{}'{}.",
+                    changed_count,
+                    event.edits.len(),
+                    sheet_name,
+                    if changed_count < event.edits.len() {
+                        "; the others already held those values"
+                    } else {
+                        ""
+                    }
+                ),
+                is_error: false,
+            });
+        }
+
+        if changed_count == 0 {
+            continue;
+        }
+        info!(
+            "Batch updated {} cell(s) in '{:?}/{}'.",
+            changed_count, category, sheet_name
+        );
+
+        data_modified_writer.write(SheetDataModifiedInRegistryEvent {
+            category: category.clone(),
+            sheet_name: sheet_name.clone(),
+        });
+
+        let registry_immut = registry.as_ref();
+        if let Some(metadata) = registry_immut
+            .get_sheet(category, sheet_name)
+            .and_then(|s| s.metadata.as_ref())
+        {
+            if metadata.category.is_none() {
+                save_single_sheet(registry_immut, metadata);
+            }
+        }
+
+        revalidate_writer.write(RequestSheetRevalidation {
+            category: category.clone(),
+            sheet_name: sheet_name.clone(),
+        });
+    }
+}
+
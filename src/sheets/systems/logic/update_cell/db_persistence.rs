@@ -5,6 +5,63 @@ use crate::sheets::definitions::{ColumnValidator, SheetMetadata, StructureFieldD
 use bevy::prelude::*;
 use rusqlite::Connection;
 
+/// Verifies that a column still exists in the physical table before writing to it
+pub fn ensure_column_exists(conn: &Connection, table_name: &str, col_header: &str) -> Result<(), String> {
+    use crate::sheets::database::schema::queries::column_exists;
+    if !column_exists(conn, table_name, col_header)
+        .map_err(|e| format!("Failed to verify column existence: {}", e))? {
+        return Err(format!(
+            "Structure changed: Column '{}' does not exist in table '{}'",
+            col_header, table_name
+        ));
+    }
+    Ok(())
+}
+
+/// Resolves the DB row id of a structure table row (first grid column holds the id)
+pub fn structure_row_id(row: &[String]) -> Result<i64, String> {
+    let id_str = row.get(0).ok_or("Missing id column")?;
+    id_str.parse::<i64>()
+        .map_err(|e| format!("Invalid id: {}", e))
+}
+
+/// Resolves the DB row id of a regular table row from its visual (grid) index
+pub fn regular_row_id(
+    conn: &Connection,
+    metadata: &SheetMetadata,
+    row_idx: usize,
+    category: &Option<String>,
+) -> Result<i64, String> {
+    // Query: SELECT id FROM table ORDER BY row_index DESC LIMIT 1 OFFSET visual_idx
+    // NOTE: Reads stay direct for performance
+    conn.query_row(
+        &format!(
+            "SELECT id FROM \"{}\" ORDER BY row_index DESC LIMIT 1 OFFSET {}",
+            metadata.sheet_name, row_idx
+        ),
+        [],
+        |row| row.get(0),
+    ).map_err(|e| format!("Could not find row ID for visual index {} in '{:?}/{}': {}", 
+                         row_idx, category, metadata.sheet_name, e))
+}
+
+/// Opens the category database backing a DB sheet. Returns None when the DB file does not exist.
+pub fn open_sheet_database(
+    metadata: &SheetMetadata,
+) -> Result<Option<(Connection, std::path::PathBuf)>, String> {
+    let cat = metadata.category.as_ref().ok_or("No category")?;
+    let base = crate::sheets::systems::io::get_default_data_base_path();
+    let db_path = base.join(format!("{}.db", cat));
+    
+    if !db_path.exists() {
+        return Ok(None); // No database, skip persistence
+    }
+    
+    let conn = crate::sheets::database::connection::DbConnection::open_existing(&db_path)
+        .map_err(|e| format!("Failed to open database: {}", e))?;
+    Ok(Some((conn, db_path)))
+}
+
 /// Persists a structure table cell update to the database
 pub fn persist_structure_cell_update(
     conn: &Connection,
@@ -24,18 +81,9 @@ pub fn persist_structure_cell_update(
     let table_name = &metadata.sheet_name;
     
     // Validate that the column exists before attempting to update
-    use crate::sheets::database::schema::queries::column_exists;
-    if !column_exists(conn, table_name, col_header)
-        .map_err(|e| format!("Failed to verify column existence: {}", e))? {
-        return Err(format!(
-            "Structure changed: Column '{}' does not exist in table '{}'",
-            col_header, table_name
-        ));
-    }
+    ensure_column_exists(conn, table_name, col_header)?;
     
-    let id_str = row.get(0).ok_or("Missing id column")?;
-    let row_id = id_str.parse::<i64>()
-        .map_err(|e| format!("Invalid id: {}", e))?;
+    let row_id = structure_row_id(row)?;
     
     crate::sheets::database::writer::DbWriter::update_structure_cell_by_id(
         conn,
@@ -67,26 +115,9 @@ pub fn persist_regular_cell_update(
     let table_name = &metadata.sheet_name;
     
     // Validate that the column exists before attempting to update
-    use crate::sheets::database::schema::queries::column_exists;
-    if !column_exists(conn, table_name, col_header)
-        .map_err(|e| format!("Failed to verify column existence: {}", e))? {
-        return Err(format!(
-            "Structure changed: Column '{}' does not exist in table '{}'",
-            col_header, table_name
-        ));
-    }
+    ensure_column_exists(conn, table_name, col_header)?;
     
-    // Query: SELECT id FROM table ORDER BY row_index DESC LIMIT 1 OFFSET visual_idx
-    // NOTE: Reads stay direct for performance
-    let row_id: i64 = conn.query_row(
-        &format!(
-            "SELECT id FROM \"{}\" ORDER BY row_index DESC LIMIT 1 OFFSET {}",
-            table_name, row_idx
-        ),
-        [],
-        |row| row.get(0),
-    ).map_err(|e| format!("Could not find row ID for visual index {} in '{:?}/{}': {}", 
-                         row_idx, category, metadata.sheet_name, e))?;
+    let row_id = regular_row_id(conn, metadata, row_idx, category)?;
     
     // Update by ID instead of row_index - WRITE goes through daemon
     use crate::sheets::database::daemon_client::Statement;
@@ -383,16 +414,9 @@ pub fn persist_cell_to_database(
     looks_like_real_structure: bool,
     daemon_client: &crate::sheets::database::daemon_client::DaemonClient,
 ) -> Result<(), String> {
-    let cat = metadata.category.as_ref().ok_or("No category")?;
-    let base = crate::sheets::systems::io::get_default_data_base_path();
-    let db_path = base.join(format!("{}.db", cat));
-    
-    if !db_path.exists() {
+    let Some((conn, db_path)) = open_sheet_database(metadata)? else {
         return Ok(()); // No database, skip persistence
-    }
-    
-    let conn = crate::sheets::database::connection::DbConnection::open_existing(&db_path)
-        .map_err(|e| format!("Failed to open database: {}", e))?;
+    };
     
     if looks_like_real_structure {
         persist_structure_cell_update(&conn, metadata, row, col_idx, col_header, updated_value, &db_path, daemon_client)?;
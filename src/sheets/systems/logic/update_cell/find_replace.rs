@@ -0,0 +1,198 @@
+// src/sheets/systems/logic/update_cell/find_replace.rs
+//! Find & replace across every cell of one column
+
+use crate::sheets::{
+    definitions::ColumnValidator,
+    events::{CellEdit, FindReplaceInColumnEvent, SheetOperationFeedback, UpdateCellsBatchEvent},
+    resources::SheetRegistry,
+};
+use bevy::prelude::*;
+
+/// Byte length of `needle` at the start of `haystack` when they match ignoring case
+fn case_insensitive_prefix_len(haystack: &str, needle: &str) -> Option<usize> {
+    let mut hay_chars = haystack.char_indices();
+    for n in needle.chars() {
+        let (_, h) = hay_chars.next()?;
+        if !h.to_lowercase().eq(n.to_lowercase()) {
+            return None;
+        }
+    }
+    Some(hay_chars.next().map_or(haystack.len(), |(idx, _)| idx))
+}
+
+/// New cell text after replacing `find` with `replacement`, or `None` when nothing matched.
+/// With `whole_cell` the entire (untrimmed) cell must equal `find`.
+pub fn replace_in_cell(
+    cell: &str,
+    find: &str,
+    replacement: &str,
+    case_sensitive: bool,
+    whole_cell: bool,
+) -> Option<String> {
+    if find.is_empty() {
+        return None;
+    }
+    if whole_cell {
+        let matched = if case_sensitive {
+            cell == find
+        } else {
+            case_insensitive_prefix_len(cell, find) == Some(cell.len())
+        };
+        return (matched && cell != replacement).then(|| replacement.to_string());
+    }
+    let replaced = if case_sensitive {
+        cell.replace(find, replacement)
+    } else {
+        let mut out = String::with_capacity(cell.len());
+        let mut rest = cell;
+        while !rest.is_empty() {
+            if let Some(len) = case_insensitive_prefix_len(rest, find) {
+                out.push_str(replacement);
+                rest = &rest[len..];
+            } else {
+                let ch = rest.chars().next().unwrap_or_default();
+                out.push(ch);
+                rest = &rest[ch.len_utf8()..];
+            }
+        }
+        out
+    };
+    (replaced != cell).then_some(replaced)
+}
+
+/// Edits for every cell of column `col_idx` in `grid` that `replace_in_cell` changes
+pub fn plan_find_replace(
+    grid: &[Vec<String>],
+    col_idx: usize,
+    find: &str,
+    replacement: &str,
+    case_sensitive: bool,
+    whole_cell: bool,
+) -> Vec<CellEdit> {
+    grid.iter()
+        .enumerate()
+        .filter_map(|(row_index, row)| {
+            let cell = row.get(col_idx)?;
+            replace_in_cell(cell, find, replacement, case_sensitive, whole_cell).map(|new_value| {
+                CellEdit {
+                    row_index,
+                    col_index: col_idx,
+                    new_value,
+                }
+            })
+        })
+        .collect()
+}
+
+/// System handler for `FindReplaceInColumnEvent`
+///
+/// Matching cells are sent as one `UpdateCellsBatchEvent`, so each replacement goes through
+/// the same validation as a manual edit (locked rows, dates, enums, ranges, unique values)
+/// and all DB writes share one transaction. The batch handler reports rejected cells.
+pub fn handle_find_replace_in_column(
+    mut events: EventReader<FindReplaceInColumnEvent>,
+    registry: Res<SheetRegistry>,
+    mut batch_writer: EventWriter<UpdateCellsBatchEvent>,
+    mut feedback_writer: EventWriter<SheetOperationFeedback>,
+) {
+    for event in events.read() {
+        let (category, sheet_name, col_idx) = (&event.category, &event.sheet_name, event.col_index);
+
+        let Some(sheet_data) = registry.get_sheet(category, sheet_name) else {
+            continue;
+        };
+        let column = sheet_data.metadata.as_ref().and_then(|m| m.columns.get(col_idx));
+        let rejection = match column {
+            None => Some("column not found".to_string()),
+            Some(col) if matches!(col.validator, Some(ColumnValidator::Structure)) => Some(format!(
+                "'{}' is a Structure column; edit its rows in the child table instead",
+                col.display_name()
+            )),
+            Some(_) if event.find.is_empty() => Some("nothing to find".to_string()),
+            Some(_) => None,
+        };
+        if let Some(reason) = rejection {
+            feedback_writer.write(SheetOperationFeedback {
+                message: format!("Find & replace in '{}' rejected: {}", sheet_name, reason),
+                is_error: true,
+            });
+            continue;
+        }
+
+        let edits = plan_find_replace(
+            &sheet_data.grid,
+            col_idx,
+            &event.find,
+            &event.replace,
+            event.case_sensitive,
+            event.whole_cell,
+        );
+        let column_label = column.map(|c| c.display_name().to_string()).unwrap_or_default();
+        let message = format!(
+            "Replacing '{}' with '{}' in {} cell(s) of '{}'.",
+            event.find,
+            event.replace,
+            edits.len(),
+            column_label
+        );
+        info!("{} [{:?}/{}]", message, category, sheet_name);
+        feedback_writer.write(SheetOperationFeedback {
+            message,
+            is_error: false,
+        });
+        if edits.is_empty() {
+            continue;
+        }
+        batch_writer.write(UpdateCellsBatchEvent {
+            category: category.clone(),
+            sheet_name: sheet_name.clone(),
+            edits,
+            report_result: false,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn substring_replace_respects_case_flag() {
+        assert_eq!(replace_in_cell("Red red RED", "red", "blue", true, false).as_deref(), Some("Red blue RED"));
+        assert_eq!(replace_in_cell("Red red RED", "red", "blue", false, false).as_deref(), Some("blue blue blue"));
+        assert_eq!(replace_in_cell("Größe", "GRÖ", "Kle", false, false).as_deref(), Some("Kleße"));
+        assert_eq!(replace_in_cell("green", "red", "blue", false, false), None);
+    }
+
+    #[test]
+    fn whole_cell_mode_only_matches_entire_value() {
+        assert_eq!(replace_in_cell("red", "RED", "blue", false, true).as_deref(), Some("blue"));
+        assert_eq!(replace_in_cell("red car", "red", "blue", false, true), None);
+        assert_eq!(replace_in_cell("red", "RED", "blue", true, true), None);
+    }
+
+    #[test]
+    fn empty_search_and_no_op_replacement_change_nothing() {
+        assert_eq!(replace_in_cell("abc", "", "x", true, false), None);
+        assert_eq!(replace_in_cell("abc", "b", "b", true, false), None);
+    }
+
+    #[test]
+    fn plan_only_lists_changed_cells_of_the_column() {
+        let grid = vec![
+            vec!["red".to_string(), "red".to_string()],
+            vec!["green".to_string(), "red".to_string()],
+            vec!["dark red".to_string()],
+        ];
+        let edits = plan_find_replace(&grid, 0, "red", "blue", true, false);
+        assert_eq!(
+            edits,
+            vec![
+                CellEdit { row_index: 0, col_index: 0, new_value: "blue".to_string() },
+                CellEdit { row_index: 2, col_index: 0, new_value: "dark blue".to_string() },
+            ]
+        );
+        // Rows too short for the column are skipped
+        assert_eq!(plan_find_replace(&grid, 1, "red", "blue", true, true).len(), 2);
+    }
+}
@@ -1,9 +1,11 @@
 // src/sheets/systems/logic/update_cell/mod.rs
 //! Cell update system - handles user-initiated cell value changes
 
+mod batch_update;
 mod cascade;
 mod cell_update;
 mod db_persistence;
+mod find_replace;
 mod validation;
 // Virtual sheet module deprecated - virtual structures removed in favor of real DB-backed child tables
 
@@ -13,12 +15,110 @@ use crate::sheets::{
         RequestSheetRevalidation, SheetDataModifiedInRegistryEvent, SheetOperationFeedback,
         UpdateCellEvent,
     },
-    resources::SheetRegistry,
+    resources::{CellEditRecord, EditHistory, SheetRegistry},
     systems::io::save::save_single_sheet,
+    systems::logic::enum_validator::add_enum_value,
 };
+use crate::sheets::systems::logic::row_locks::ensure_locked_rows_loaded;
 use bevy::prelude::*;
 use std::collections::HashMap;
 
+pub use batch_update::handle_cell_batch_update;
+pub use find_replace::{handle_find_replace_in_column, replace_in_cell};
+pub use validation::{parse_numeric_range, validate_new_row_value};
+
+/// Resolves an event row index to a grid index: first as a grid index, then as a DB row_index
+fn resolve_grid_row_index(
+    registry: &SheetRegistry,
+    category: &Option<String>,
+    sheet_name: &str,
+    event_row_idx: usize,
+) -> usize {
+    if let Some(sheet_data) = registry.get_sheet(category, sheet_name) {
+        if event_row_idx < sheet_data.grid.len() {
+            // Valid grid index
+            event_row_idx
+        } else {
+            // Try to find grid index by DB row_index
+            if let Some(grid_idx) = sheet_data.row_indices
+                .iter()
+                .position(|&ri| ri == event_row_idx as i64)
+            {
+                grid_idx
+            } else {
+                // Neither valid - will fail validation
+                event_row_idx
+            }
+        }
+    } else {
+        event_row_idx
+    }
+}
+
+/// Adds an applied cell change to the undo history
+fn record_edit(
+    history: &mut EditHistory,
+    category: &Option<String>,
+    sheet_name: &str,
+    row_indices: &[i64],
+    row_idx: usize,
+    col_idx: usize,
+    result: &cell_update::CellUpdateResult,
+) {
+    history.record(CellEditRecord {
+        category: category.clone(),
+        sheet_name: sheet_name.to_string(),
+        row: row_idx,
+        row_index: row_indices.get(row_idx).copied(),
+        col: col_idx,
+        old_value: result.old_value.clone().unwrap_or_default(),
+        new_value: result.final_value.clone().unwrap_or_default(),
+    });
+}
+
+/// Adds a value accepted by an auto-adding Enum column to its allowed list (registry and DB).
+/// JSON sheets are saved with the rest of the edit.
+fn extend_enum_values(
+    registry: &mut SheetRegistry,
+    category: &Option<String>,
+    sheet_name: &str,
+    col_idx: usize,
+    value: &str,
+    daemon_client: &crate::sheets::database::daemon_client::DaemonClient,
+) {
+    let Some(col_def) = registry
+        .get_sheet_mut(category, sheet_name)
+        .and_then(|s| s.metadata.as_mut())
+        .and_then(|m| m.columns.get_mut(col_idx))
+    else {
+        return;
+    };
+    if !col_def.enum_auto_add || !add_enum_value(&mut col_def.validator, value) {
+        return;
+    }
+    info!(
+        "Added '{}' to the allowed values of '{:?}/{}' column '{}'.",
+        value.trim(),
+        category,
+        sheet_name,
+        col_def.header
+    );
+    if let Some(cat) = category {
+        if let Err(e) = crate::sheets::database::persist_column_validator_by_name(
+            cat,
+            sheet_name,
+            &col_def.header,
+            col_def.data_type,
+            &col_def.validator,
+            None,
+            None,
+            daemon_client,
+        ) {
+            error!("Failed to persist allowed values of column '{}': {}", col_def.header, e);
+        }
+    }
+}
+
 /// Main system handler for cell update events
 pub fn handle_cell_update(
     mut events: EventReader<UpdateCellEvent>,
@@ -26,6 +126,7 @@ pub fn handle_cell_update(
     mut feedback_writer: EventWriter<SheetOperationFeedback>,
     mut data_modified_writer: EventWriter<SheetDataModifiedInRegistryEvent>,
     mut revalidate_writer: EventWriter<RequestSheetRevalidation>,
+    mut edit_history: ResMut<EditHistory>,
     // Virtual structure system removed - editor_state no longer needed
     daemon_client: Res<crate::sheets::database::daemon_resource::SharedDaemonClient>,
 ) {
@@ -36,46 +137,71 @@ pub fn handle_cell_update(
         let category = event.category.clone();
         let sheet_name = event.sheet_name.clone();
         let col_idx = event.col_index;
-        let new_value = &event.new_value;
 
         // Virtual structures deprecated - all sheets are now real DB-backed tables
 
         // Resolve row_idx: first try as grid index, then as DB row_index
-        let row_idx = {
-            let event_row_idx = event.row_index;
-            // Check if event.row_index is valid as grid index
-            if let Some(sheet_data) = registry.get_sheet(&category, &sheet_name) {
-                if event_row_idx < sheet_data.grid.len() {
-                    // Valid grid index
-                    event_row_idx
-                } else {
-                    // Try to find grid index by DB row_index
-                    if let Some(grid_idx) = sheet_data.row_indices
-                        .iter()
-                        .position(|&ri| ri == event_row_idx as i64)
-                    {
-                        grid_idx
-                    } else {
-                        // Neither valid - will fail validation
-                        event_row_idx
-                    }
-                }
-            } else {
-                event_row_idx
-            }
-        };
+        let row_idx = resolve_grid_row_index(registry.as_ref(), &category, &sheet_name, event.row_index);
 
-        // Validate cell location
+        // Validate cell location and row lock, normalize dates and enum values, then check numeric bounds and uniqueness
+        ensure_locked_rows_loaded(&mut registry, &category, &sheet_name);
         let validation_result = validation::validate_cell_location(
             registry.as_ref(),
             &category,
             &sheet_name,
             row_idx,
             col_idx,
-        );
+        )
+        .and_then(|()| {
+            validation::validate_row_unlocked(registry.as_ref(), &category, &sheet_name, row_idx)
+        })
+        .and_then(|()| {
+            validation::normalize_date_value(
+                registry.as_ref(),
+                &category,
+                &sheet_name,
+                col_idx,
+                &event.new_value,
+            )
+        })
+        .and_then(|new_value| {
+            validation::normalize_enum_value(
+                registry.as_ref(),
+                &category,
+                &sheet_name,
+                col_idx,
+                &new_value,
+            )
+        })
+        .and_then(|new_value| {
+            validation::validate_numeric_range(
+                registry.as_ref(),
+                &category,
+                &sheet_name,
+                col_idx,
+                &new_value,
+            )?;
+            validation::validate_unique_value(
+                registry.as_ref(),
+                &category,
+                &sheet_name,
+                row_idx,
+                col_idx,
+                &new_value,
+            )?;
+            Ok(new_value)
+        });
 
         match validation_result {
-            Ok(()) => {
+            Ok(new_value) => {
+                extend_enum_values(
+                    &mut registry,
+                    &category,
+                    &sheet_name,
+                    col_idx,
+                    &new_value,
+                    daemon_client.client(),
+                );
                 if let Some(sheet_data) = registry.get_sheet_mut(&category, &sheet_name) {
                     if let Some(row) = sheet_data.grid.get_mut(row_idx) {
                         // Extract column metadata
@@ -88,7 +214,7 @@ pub fn handle_cell_update(
                         let update_result = if let Some(cell) = row.get_mut(col_idx) {
                             cell_update::update_cell_value(
                                 cell,
-                                new_value,
+                                &new_value,
                                 &sheet_data.metadata,
                                 col_idx,
                                 row_idx,
@@ -104,6 +230,15 @@ pub fn handle_cell_update(
                         };
 
                         if update_result.changed {
+                            record_edit(
+                                &mut edit_history,
+                                &category,
+                                &sheet_name,
+                                &sheet_data.row_indices,
+                                row_idx,
+                                col_idx,
+                                &update_result,
+                            );
                             if let Some(metadata) = &sheet_data.metadata {
                                 let key = (category.clone(), sheet_name.clone());
                                 sheets_to_save.insert(key.clone(), metadata.clone());
@@ -171,6 +306,7 @@ pub fn handle_cell_update(
 
         // Virtual sheet sync removed - all structure data now persisted directly to DB
     }
+    edit_history.finish_replays();
 
     // Save sheets that were modified
     if !sheets_to_save.is_empty() {
@@ -1,7 +1,11 @@
 // src/sheets/systems/logic/update_cell/validation.rs
 //! Validation logic for cell update operations
 
+use crate::sheets::definitions::ColumnDataType;
 use crate::sheets::resources::SheetRegistry;
+use crate::sheets::systems::logic::date_values::normalize_date;
+use crate::sheets::systems::logic::enum_validator::{canonical_enum_value, enum_values};
+use crate::sheets::systems::logic::unique_validator::{find_duplicate_row, unique_settings};
 
 /// Validates that the cell location is within bounds
 pub fn validate_cell_location(
@@ -33,3 +37,232 @@ pub fn validate_cell_location(
         Err(format!("Sheet '{:?}/{}' not found.", category, sheet_name))
     }
 }
+
+/// Rejects edits to rows locked through the row context menu
+pub fn validate_row_unlocked(
+    registry: &SheetRegistry,
+    category: &Option<String>,
+    sheet_name: &str,
+    row_idx: usize,
+) -> Result<(), String> {
+    if registry.is_grid_row_locked(category, sheet_name, row_idx) {
+        Err(format!(
+            "Row {} of '{}' is locked. Unlock it from the row's context menu to edit it.",
+            row_idx + 1,
+            sheet_name
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Rejects a value that would duplicate another row's value in a `Unique` column
+pub fn validate_unique_value(
+    registry: &SheetRegistry,
+    category: &Option<String>,
+    sheet_name: &str,
+    row_idx: usize,
+    col_idx: usize,
+    new_value: &str,
+) -> Result<(), String> {
+    let Some(sheet_data) = registry.get_sheet(category, sheet_name) else {
+        return Ok(());
+    };
+    let Some(col_def) = sheet_data
+        .metadata
+        .as_ref()
+        .and_then(|m| m.columns.get(col_idx))
+    else {
+        return Ok(());
+    };
+    let Some((data_type, case_sensitive)) = unique_settings(&col_def.validator) else {
+        return Ok(());
+    };
+    match find_duplicate_row(
+        &sheet_data.grid,
+        col_idx,
+        new_value,
+        Some(row_idx),
+        data_type,
+        case_sensitive,
+    ) {
+        Some(conflict_row) => Err(format!(
+            "'{}' must be unique; '{}' is already used in row {}.",
+            col_def.display_name(),
+            new_value.trim(),
+            conflict_row + 1
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Checks a value for a row that does not exist yet (e.g. a pasted block growing the sheet):
+/// date and enum normalization, numeric bounds and uniqueness against every existing row
+pub fn validate_new_row_value(
+    registry: &SheetRegistry,
+    category: &Option<String>,
+    sheet_name: &str,
+    col_idx: usize,
+    new_value: &str,
+) -> Result<String, String> {
+    let new_value = normalize_date_value(registry, category, sheet_name, col_idx, new_value)?;
+    let new_value = normalize_enum_value(registry, category, sheet_name, col_idx, &new_value)?;
+    validate_numeric_range(registry, category, sheet_name, col_idx, &new_value)?;
+    validate_unique_value(registry, category, sheet_name, usize::MAX, col_idx, &new_value)?;
+    Ok(new_value)
+}
+
+/// Describes why `value` falls outside the inclusive `(min, max)` bounds, or `None` when it
+/// fits. Empty and non-numeric values are left to type validation.
+pub fn numeric_range_violation(value: &str, range: (Option<f64>, Option<f64>)) -> Option<String> {
+    let number: f64 = value.trim().parse().ok()?;
+    match range {
+        (Some(min), _) if number < min => Some(format!("{} is below the minimum of {}", number, min)),
+        (_, Some(max)) if number > max => Some(format!("{} is above the maximum of {}", number, max)),
+        _ => None,
+    }
+}
+
+/// Parses the lower/upper bound inputs of the column options popup. Blank inputs are
+/// open-ended; two blanks clear the range.
+pub fn parse_numeric_range(
+    min_input: &str,
+    max_input: &str,
+) -> Result<Option<(Option<f64>, Option<f64>)>, String> {
+    let parse_bound = |input: &str, label: &str| -> Result<Option<f64>, String> {
+        let trimmed = input.trim();
+        if trimmed.is_empty() {
+            return Ok(None);
+        }
+        match trimmed.parse::<f64>() {
+            Ok(value) if value.is_finite() => Ok(Some(value)),
+            _ => Err(format!("{} bound '{}' is not a number", label, trimmed)),
+        }
+    };
+    let min = parse_bound(min_input, "Lower")?;
+    let max = parse_bound(max_input, "Upper")?;
+    if let (Some(lo), Some(hi)) = (min, max) {
+        if lo > hi {
+            return Err(format!("Lower bound {} is greater than upper bound {}", lo, hi));
+        }
+    }
+    Ok((min.is_some() || max.is_some()).then_some((min, max)))
+}
+
+/// Rejects a value outside the column's numeric range (numeric columns only)
+pub fn validate_numeric_range(
+    registry: &SheetRegistry,
+    category: &Option<String>,
+    sheet_name: &str,
+    col_idx: usize,
+    new_value: &str,
+) -> Result<(), String> {
+    let Some(col_def) = registry
+        .get_sheet(category, sheet_name)
+        .and_then(|s| s.metadata.as_ref())
+        .and_then(|m| m.columns.get(col_idx))
+    else {
+        return Ok(());
+    };
+    let Some(range) = col_def.numeric_range.filter(|_| col_def.is_numeric()) else {
+        return Ok(());
+    };
+    match numeric_range_violation(new_value, range) {
+        Some(reason) => Err(format!("'{}' rejected: {}.", col_def.display_name(), reason)),
+        None => Ok(()),
+    }
+}
+
+/// Value to store for an edit: Date columns accept common spellings and store ISO-8601,
+/// rejecting anything that is not a date. Blank input clears the cell; other columns are
+/// passed through unchanged.
+pub fn normalize_date_value(
+    registry: &SheetRegistry,
+    category: &Option<String>,
+    sheet_name: &str,
+    col_idx: usize,
+    new_value: &str,
+) -> Result<String, String> {
+    let col_def = registry
+        .get_sheet(category, sheet_name)
+        .and_then(|s| s.metadata.as_ref())
+        .and_then(|m| m.columns.get(col_idx))
+        .filter(|c| c.data_type == ColumnDataType::Date);
+    let Some(col_def) = col_def else {
+        return Ok(new_value.to_string());
+    };
+    if new_value.trim().is_empty() {
+        return Ok(String::new());
+    }
+    normalize_date(new_value).ok_or_else(|| {
+        format!(
+            "'{}' expects a date (YYYY-MM-DD), got '{}'.",
+            col_def.display_name(),
+            new_value.trim()
+        )
+    })
+}
+
+/// Value to store for an edit of an Enum column: the allowed spelling of the value, or the
+/// trimmed value when the column adds unknown values (the caller extends the list). Anything
+/// else outside the allowed list is rejected. Blank input clears the cell; other columns are
+/// passed through unchanged.
+pub fn normalize_enum_value(
+    registry: &SheetRegistry,
+    category: &Option<String>,
+    sheet_name: &str,
+    col_idx: usize,
+    new_value: &str,
+) -> Result<String, String> {
+    let Some(col_def) = registry
+        .get_sheet(category, sheet_name)
+        .and_then(|s| s.metadata.as_ref())
+        .and_then(|m| m.columns.get(col_idx))
+    else {
+        return Ok(new_value.to_string());
+    };
+    let Some(allowed) = enum_values(&col_def.validator) else {
+        return Ok(new_value.to_string());
+    };
+    if new_value.trim().is_empty() {
+        return Ok(String::new());
+    }
+    match canonical_enum_value(new_value, allowed) {
+        Some(value) => Ok(value.to_string()),
+        None if col_def.enum_auto_add => Ok(new_value.trim().to_string()),
+        None => Err(format!(
+            "'{}' is not an allowed value of '{}' (allowed: {}).",
+            new_value.trim(),
+            col_def.display_name(),
+            allowed.join(", ")
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numeric_range_bounds_are_inclusive_and_optional() {
+        let range = (Some(0.0), Some(10.0));
+        assert!(numeric_range_violation("0", range).is_none());
+        assert!(numeric_range_violation("10.0", range).is_none());
+        assert!(numeric_range_violation("-1", range).unwrap().contains("minimum"));
+        assert!(numeric_range_violation("10.5", range).unwrap().contains("maximum"));
+        // Open-ended sides and non-numeric input are not range errors
+        assert!(numeric_range_violation("1e9", (Some(0.0), None)).is_none());
+        assert!(numeric_range_violation("-5", (None, Some(3.0))).is_none());
+        assert!(numeric_range_violation("", range).is_none());
+        assert!(numeric_range_violation("abc", range).is_none());
+    }
+
+    #[test]
+    fn numeric_range_inputs_parse_blank_as_open_ended() {
+        assert_eq!(parse_numeric_range("", " "), Ok(None));
+        assert_eq!(parse_numeric_range("1", ""), Ok(Some((Some(1.0), None))));
+        assert_eq!(parse_numeric_range(" -2.5 ", "4"), Ok(Some((Some(-2.5), Some(4.0)))));
+        assert!(parse_numeric_range("x", "").is_err());
+        assert!(parse_numeric_range("5", "1").is_err());
+    }
+}
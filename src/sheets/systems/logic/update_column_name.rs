@@ -137,6 +137,14 @@ pub fn handle_update_column_name(
 
         // Determine whether a real header rename is intended (vs display-only)
         let header_change_intended = !old_header.eq_ignore_ascii_case(new_name) && !header_conflict && !is_reserved;
+        // A custom display name (one that differs from the physical header) survives a physical rename
+        let has_custom_display = old_ui != old_header;
+        let sync_display_name = !header_change_intended || !has_custom_display;
+        // The AI key column is stored by header, so it follows a real rename
+        let key_column_follows = header_change_intended
+            && meta_snapshot
+                .as_ref()
+                .is_some_and(|m| m.ai_key_column.as_deref() == Some(old_header.as_str()));
 
         // --- DB-first path (when DB-backed) ---
         if db_backed {
@@ -204,7 +212,7 @@ pub fn handle_update_column_name(
             }
 
             // If a real rename was done, also update display_name in DB to match new_name (best-effort)
-            if header_change_intended {
+            if header_change_intended && sync_display_name {
                 let _ = crate::sheets::database::writer::DbWriter::update_column_display_name(
                     &conn,
                     sheet_name,
@@ -214,6 +222,21 @@ pub fn handle_update_column_name(
                     daemon_client.client(),
                 );
             }
+
+            if key_column_follows {
+                if let Err(e) = crate::sheets::database::writer::DbWriter::update_table_ai_key_column(
+                    &conn,
+                    sheet_name,
+                    Some(new_name),
+                    db_filename,
+                    daemon_client.client(),
+                ) {
+                    warn!(
+                        "Failed to persist renamed AI key column for '{}': {}",
+                        sheet_name, e
+                    );
+                }
+            }
         }
 
         // --- In-memory mutation after DB success (or JSON mode) ---
@@ -222,12 +245,17 @@ pub fn handle_update_column_name(
 
         if let Some(sheet_mut) = registry.get_sheet_mut(category, sheet_name) {
             if let Some(metadata) = &mut sheet_mut.metadata {
-                // Update display name in memory
-                metadata.columns[col_index].display_header = Some(new_name.to_string());
+                // Update display name in memory (unless a custom one should be kept)
+                if sync_display_name {
+                    metadata.columns[col_index].display_header = Some(new_name.to_string());
+                }
 
                 if header_change_intended {
                     // Update real header in memory
                     metadata.columns[col_index].header = new_name.to_string();
+                    if key_column_follows {
+                        metadata.ai_key_column = Some(new_name.to_string());
+                    }
 
                     // If Structure: plan registry child rename (old -> new)
                     if is_structure {
@@ -108,6 +108,12 @@ pub fn create_structure_technical_columns(_depth: usize) -> Vec<ColumnDefinition
             ai_include_in_send: Some(false),
             deleted: false,
             hidden: true, // row_index is always hidden
+            trim_numeric_zeros: false,
+            ai_read_only: false,
+            multiline: false,
+            enum_auto_add: false,
+            numeric_range: None,
+            default_value: None,
             width: None,
             structure_schema: None,
             structure_column_order: None,
@@ -125,6 +131,12 @@ pub fn create_structure_technical_columns(_depth: usize) -> Vec<ColumnDefinition
             ai_include_in_send: Some(true),
             deleted: false,
             hidden: false,
+            trim_numeric_zeros: false,
+            ai_read_only: false,
+            multiline: false,
+            enum_auto_add: false,
+            numeric_range: None,
+            default_value: None,
             width: None,
             structure_schema: None,
             structure_column_order: None,
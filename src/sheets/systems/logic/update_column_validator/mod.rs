@@ -8,7 +8,9 @@ mod db_operations;
 mod hierarchy;
 mod persistence;
 mod structure_conversion;
+pub mod structure_naming;
 mod structure_recreation_handler;
+pub mod structure_removal;
 mod update_column_validator_impl;
 mod validation;
 
@@ -103,8 +103,10 @@ pub fn handle_structure_conversion_to(
                     def.validator = Some(orig.clone());
                     def.data_type = match orig {
                         ColumnValidator::Basic(t) => t,
+                        ColumnValidator::Unique { data_type, .. } => data_type,
                         ColumnValidator::Linked { .. } => ColumnDataType::String,
                         ColumnValidator::Structure => ColumnDataType::String,
+                        ColumnValidator::Enum(_) => ColumnDataType::String,
                     };
                 }
                 if matches!(def.validator, Some(ColumnValidator::Structure)) {
@@ -157,6 +159,12 @@ pub fn handle_structure_conversion_to(
             ai_include_in_send: field_def.ai_include_in_send,
             deleted: false,
             hidden: false, // User-defined structure field
+            trim_numeric_zeros: false,
+            ai_read_only: false,
+            multiline: false,
+            enum_auto_add: false,
+            numeric_range: None,
+            default_value: None,
             width: None,
             structure_schema: None,
             structure_column_order: None,
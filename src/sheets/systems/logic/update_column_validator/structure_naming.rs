@@ -0,0 +1,205 @@
+// src/sheets/systems/logic/update_column_validator/structure_naming.rs
+// Collision-free naming of structure child tables.
+//
+// Child tables are always named `{parent}_{column header}` and that name is derived (not
+// stored) wherever a child is looked up. When the derived name is already claimed by another
+// sheet in the category (e.g. sheet "A_B" column "C" vs sheet "A" column "B_C", or a regular
+// sheet of that name), the column's physical header is adjusted instead, so the derived child
+// name becomes unique. The column keeps its visible name through its display name.
+
+use crate::sheets::{
+    definitions::{ColumnValidator, SheetMetadata},
+    resources::SheetRegistry,
+};
+use crate::ui::elements::editor::state::StructureTableNaming;
+
+/// Upper bound for numeric suffixes tried before giving up
+const MAX_NUMERIC_SUFFIX: usize = 999;
+
+/// Physical name of the child table backing a structure column
+pub fn structure_child_table_name(parent_sheet: &str, column_header: &str) -> String {
+    format!("{}_{}", parent_sheet, column_header)
+}
+
+/// Pick a physical header for a new structure column whose derived child table name is free.
+///
+/// `is_claimed(header)` reports whether `header` is unusable (taken by another column of the
+/// parent, or its derived child table name belongs to another sheet). Returns `None` when
+/// `header` itself is free, otherwise the first free alternative following `naming`.
+pub fn pick_structure_column_header(
+    header: &str,
+    category: Option<&str>,
+    naming: StructureTableNaming,
+    is_claimed: impl Fn(&str) -> bool,
+) -> Option<String> {
+    if !is_claimed(header) {
+        return None;
+    }
+    let base = match (naming, category) {
+        (StructureTableNaming::CategoryPrefix, Some(cat)) => {
+            let prefixed = format!("{}_{}", cat, header);
+            if !is_claimed(&prefixed) {
+                return Some(prefixed);
+            }
+            prefixed
+        }
+        _ => header.to_string(),
+    };
+    (2..=MAX_NUMERIC_SUFFIX)
+        .map(|n| format!("{}_{}", base, n))
+        .find(|candidate| !is_claimed(candidate))
+}
+
+/// Whether `child_name` is already used by something other than the structure column
+/// `col_index` of `parent_sheet`: a regular (non-structure) sheet, or the derived child
+/// name of another structure column in the same category.
+fn child_name_claimed_elsewhere(
+    registry: &SheetRegistry,
+    category: &Option<String>,
+    parent_sheet: &str,
+    col_index: usize,
+    child_name: &str,
+) -> bool {
+    let regular_sheet_exists = registry
+        .get_sheet(category, child_name)
+        .and_then(|s| s.metadata.as_ref())
+        .map_or(false, |m| {
+            !m.columns
+                .iter()
+                .any(|c| c.header.eq_ignore_ascii_case("parent_key"))
+        });
+    if regular_sheet_exists {
+        return true;
+    }
+    registry
+        .iter_sheets()
+        .filter(|(cat, _, _)| *cat == category)
+        .filter_map(|(_, name, data)| data.metadata.as_ref().map(|m| (name, m)))
+        .any(|(name, meta)| {
+            meta.columns.iter().enumerate().any(|(idx, col)| {
+                !col.deleted
+                    && matches!(col.validator, Some(ColumnValidator::Structure))
+                    && !(name == parent_sheet && idx == col_index)
+                    && structure_child_table_name(name, &col.header)
+                        .eq_ignore_ascii_case(child_name)
+            })
+        })
+}
+
+/// Physical header to use when column `col_index` of `parent_sheet` becomes a Structure,
+/// or `None` when its current header already yields a free child table name.
+pub fn resolve_structure_column_header(
+    registry: &SheetRegistry,
+    category: &Option<String>,
+    parent_sheet: &str,
+    col_index: usize,
+    naming: StructureTableNaming,
+) -> Option<String> {
+    let meta: &SheetMetadata = registry.get_sheet(category, parent_sheet)?.metadata.as_ref()?;
+    let header = meta.columns.get(col_index)?.header.clone();
+    pick_structure_column_header(&header, category.as_deref(), naming, |candidate| {
+        let taken_by_sibling = meta.columns.iter().enumerate().any(|(idx, c)| {
+            idx != col_index && !c.deleted && c.header.eq_ignore_ascii_case(candidate)
+        });
+        taken_by_sibling
+            || child_name_claimed_elsewhere(
+                registry,
+                category,
+                parent_sheet,
+                col_index,
+                &structure_child_table_name(parent_sheet, candidate),
+            )
+    })
+}
+
+/// Rename the parent's physical column in its category database ahead of structure creation.
+/// The old header is kept as the column's display name so the grid looks unchanged.
+pub fn rename_db_column_for_structure(
+    category: &str,
+    parent_sheet: &str,
+    col_index: usize,
+    old_header: &str,
+    new_header: &str,
+    display_name: &str,
+    daemon_client: &crate::sheets::database::daemon_client::DaemonClient,
+) -> Result<(), String> {
+    let db_path = crate::sheets::systems::io::get_default_data_base_path()
+        .join(format!("{}.db", category));
+    let conn = crate::sheets::database::connection::DbConnection::open_existing(&db_path)
+        .map_err(|e| format!("Cannot open database for category '{}': {}", category, e))?;
+    let db_filename = db_path.file_name().and_then(|n| n.to_str());
+    crate::sheets::database::writer::DbWriter::rename_data_column(
+        &conn,
+        parent_sheet,
+        old_header,
+        new_header,
+        db_filename,
+        daemon_client,
+    )
+    .map_err(|e| e.to_string())?;
+    crate::sheets::database::writer::DbWriter::update_column_display_name(
+        &conn,
+        parent_sheet,
+        col_index,
+        display_name,
+        db_filename,
+        daemon_client,
+    )
+    .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn free_header_is_kept() {
+        let picked = pick_structure_column_header(
+            "Items",
+            Some("Game"),
+            StructureTableNaming::NumericSuffix,
+            |_| false,
+        );
+        assert_eq!(picked, None);
+    }
+
+    #[test]
+    fn numeric_suffix_skips_claimed_candidates() {
+        let claimed = ["Items", "Items_2"];
+        let picked = pick_structure_column_header(
+            "Items",
+            Some("Game"),
+            StructureTableNaming::NumericSuffix,
+            |h| claimed.contains(&h),
+        );
+        assert_eq!(picked.as_deref(), Some("Items_3"));
+    }
+
+    #[test]
+    fn category_prefix_falls_back_to_numeric_suffix() {
+        let picked = pick_structure_column_header(
+            "Items",
+            Some("Game"),
+            StructureTableNaming::CategoryPrefix,
+            |h| h == "Items",
+        );
+        assert_eq!(picked.as_deref(), Some("Game_Items"));
+
+        let picked = pick_structure_column_header(
+            "Items",
+            Some("Game"),
+            StructureTableNaming::CategoryPrefix,
+            |h| h == "Items" || h == "Game_Items",
+        );
+        assert_eq!(picked.as_deref(), Some("Game_Items_2"));
+
+        // JSON sheets have no category to prefix with
+        let picked = pick_structure_column_header(
+            "Items",
+            None,
+            StructureTableNaming::CategoryPrefix,
+            |h| h == "Items",
+        );
+        assert_eq!(picked.as_deref(), Some("Items_2"));
+    }
+}
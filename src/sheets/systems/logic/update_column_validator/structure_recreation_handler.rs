@@ -200,6 +200,8 @@ fn create_structure_table_impl(
     let structure_metadata = crate::sheets::definitions::SheetMetadata {
         columns: struct_columns.to_vec(),
         hidden: true,
+        ai_key_column: None,
+        color: None,
         ..structure_metadata
     };
 
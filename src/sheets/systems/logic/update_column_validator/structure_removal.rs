@@ -0,0 +1,229 @@
+// src/sheets/systems/logic/update_column_validator/structure_removal.rs
+// Dropping a structure column's child table when it is converted back to a plain type
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::sheets::{
+    definitions::SheetGridData,
+    events::{CellEdit, RequestDeleteSheet, UpdateCellsBatchEvent},
+    resources::SheetRegistry,
+};
+
+/// Structure column converted away from Structure; its child table is handled after the apply loop
+pub struct StructureRemoval {
+    pub category: Option<String>,
+    pub sheet_name: String,
+    pub col_index: usize,
+    pub column_header: String,
+    pub flatten_into_cell: bool,
+}
+
+/// Whether the child table of a structure column is in the registry with its data read.
+/// The rows come in through the regular lazy load of the parent's linked and structure
+/// tables (`load_linked_target_sheets`); a stub from the category scan has no columns yet.
+pub fn child_sheet_loaded(
+    registry: &SheetRegistry,
+    category: &Option<String>,
+    child_sheet_name: &str,
+) -> bool {
+    registry
+        .get_sheet(category, child_sheet_name)
+        .and_then(|s| s.metadata.as_ref())
+        .map_or(false, |m| !m.columns.is_empty())
+}
+
+/// Serialize child rows into one readable text per parent, keyed by the child's parent_key.
+/// Rows become `header=value` pairs joined by ';', multiple rows are joined by " | "
+/// (the same shape used when inline structure JSON is flattened).
+pub fn flatten_children_by_parent(child: &SheetGridData) -> HashMap<String, String> {
+    let mut flattened: HashMap<String, Vec<String>> = HashMap::new();
+    let Some(meta) = child.metadata.as_ref() else {
+        return HashMap::new();
+    };
+    // Skip technical columns (row_index, parent_key) and deleted ones
+    let data_columns: Vec<(usize, &str)> = meta
+        .columns
+        .iter()
+        .enumerate()
+        .skip(2)
+        .filter(|(_, c)| !c.deleted)
+        .map(|(i, c)| (i, c.display_header.as_deref().unwrap_or(&c.header)))
+        .collect();
+    for row in &child.grid {
+        let Some(parent_key) = row.get(1) else {
+            continue;
+        };
+        let parts: Vec<String> = data_columns
+            .iter()
+            .map(|(i, header)| format!("{}={}", header, row.get(*i).map(String::as_str).unwrap_or("")))
+            .collect();
+        flattened
+            .entry(parent_key.trim().to_string())
+            .or_default()
+            .push(parts.join(";"));
+    }
+    flattened
+        .into_iter()
+        .map(|(k, rows)| (k, rows.join(" | ")))
+        .collect()
+}
+
+/// Cell edits writing each parent row's flattened children (or an empty cell) into the
+/// converted column. Parents are matched by row_index when the sheet has one, else by grid row.
+fn flattened_parent_edits(
+    parent: &SheetGridData,
+    col_index: usize,
+    flattened: &HashMap<String, String>,
+) -> Vec<CellEdit> {
+    let keyed_by_row_index = parent
+        .metadata
+        .as_ref()
+        .and_then(|m| m.columns.first())
+        .map_or(false, |c| c.header.eq_ignore_ascii_case("row_index"));
+    parent
+        .grid
+        .iter()
+        .enumerate()
+        .filter_map(|(row_idx, row)| {
+            let parent_key = if keyed_by_row_index {
+                row.first().map(|s| s.trim().to_string()).unwrap_or_default()
+            } else {
+                row_idx.to_string()
+            };
+            let new_value = flattened.get(&parent_key).cloned().unwrap_or_default();
+            (row.get(col_index) != Some(&new_value)).then(|| CellEdit {
+                row_index: row_idx,
+                col_index,
+                new_value,
+            })
+        })
+        .collect()
+}
+
+/// Write flattened children (or empty cells) into the converted column and drop the child table
+pub fn apply_structure_removal(
+    removal: &StructureRemoval,
+    registry: &SheetRegistry,
+    batch_writer: &mut EventWriter<UpdateCellsBatchEvent>,
+    delete_sheet_writer: &mut EventWriter<RequestDeleteSheet>,
+) {
+    let child_sheet_name = format!("{}_{}", removal.sheet_name, removal.column_header);
+    if registry.get_sheet(&removal.category, &child_sheet_name).is_none() {
+        // Legacy inline-JSON structures have no child table
+        return;
+    }
+    if removal.flatten_into_cell && !child_sheet_loaded(registry, &removal.category, &child_sheet_name) {
+        // Dropping an unread table would lose rows the user asked to keep
+        warn!(
+            "Child table '{:?}/{}' is not loaded; keeping it instead of flattening.",
+            removal.category, child_sheet_name
+        );
+        return;
+    }
+
+    let flattened = if removal.flatten_into_cell {
+        registry
+            .get_sheet(&removal.category, &child_sheet_name)
+            .map(flatten_children_by_parent)
+            .unwrap_or_default()
+    } else {
+        HashMap::new()
+    };
+
+    if let Some(parent) = registry.get_sheet(&removal.category, &removal.sheet_name) {
+        let edits = flattened_parent_edits(parent, removal.col_index, &flattened);
+        if !edits.is_empty() {
+            batch_writer.write(UpdateCellsBatchEvent {
+                category: removal.category.clone(),
+                sheet_name: removal.sheet_name.clone(),
+                edits,
+                report_result: false,
+            });
+        }
+    }
+
+    info!(
+        "Dropping child table '{:?}/{}' of former structure column '{}' (flattened: {}).",
+        removal.category, child_sheet_name, removal.column_header, removal.flatten_into_cell
+    );
+    delete_sheet_writer.write(RequestDeleteSheet {
+        category: removal.category.clone(),
+        sheet_name: child_sheet_name,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sheets::definitions::SheetMetadata;
+
+    #[test]
+    fn flattens_children_per_parent_in_column_order() {
+        let mut meta = SheetMetadata::create_generic(
+            "Game_Platforms".to_string(),
+            "Game_Platforms.json".to_string(),
+            4,
+            None,
+        );
+        for (col, header) in meta.columns.iter_mut().zip(["row_index", "parent_key", "Name", "Store"]) {
+            col.header = header.to_string();
+        }
+        let child = SheetGridData {
+            metadata: Some(meta),
+            grid: vec![
+                vec!["0".into(), "7".into(), "PC".into(), "Steam".into()],
+                vec!["1".into(), "7".into(), "PS5".into(), "PSN".into()],
+                vec!["2".into(), "9".into(), "Switch".into(), "".into()],
+            ],
+            row_indices: Vec::new(),
+        };
+
+        let flattened = flatten_children_by_parent(&child);
+        assert_eq!(flattened.len(), 2);
+        assert_eq!(flattened["7"], "Name=PC;Store=Steam | Name=PS5;Store=PSN");
+        assert_eq!(flattened["9"], "Name=Switch;Store=");
+    }
+
+    #[test]
+    fn child_rows_are_written_into_their_parent_rows() {
+        let mut child_meta = SheetMetadata::create_generic(
+            "Game_Platforms".to_string(),
+            "Game_Platforms.json".to_string(),
+            3,
+            None,
+        );
+        for (col, header) in child_meta.columns.iter_mut().zip(["row_index", "parent_key", "Name"]) {
+            col.header = header.to_string();
+        }
+        let child = SheetGridData {
+            metadata: Some(child_meta),
+            grid: vec![
+                vec!["0".into(), "7".into(), "PC".into()],
+                vec!["1".into(), "7".into(), "PS5".into()],
+            ],
+            row_indices: Vec::new(),
+        };
+        let mut parent_meta =
+            SheetMetadata::create_generic("Game".to_string(), "Game.json".to_string(), 3, None);
+        for (col, header) in parent_meta.columns.iter_mut().zip(["row_index", "Title", "Platforms"]) {
+            col.header = header.to_string();
+        }
+        let parent = SheetGridData {
+            metadata: Some(parent_meta),
+            grid: vec![
+                vec!["9".into(), "Solo".into(), "0 rows".into()],
+                vec!["7".into(), "Duo".into(), "2 rows".into()],
+            ],
+            row_indices: Vec::new(),
+        };
+
+        let edits = flattened_parent_edits(&parent, 2, &flatten_children_by_parent(&child));
+        let by_row: Vec<(usize, usize, &str)> = edits
+            .iter()
+            .map(|e| (e.row_index, e.col_index, e.new_value.as_str()))
+            .collect();
+        // Matched by row_index, not grid position; a parent without children is cleared
+        assert_eq!(by_row, vec![(0, 2, ""), (1, 2, "Name=PC | Name=PS5")]);
+    }
+}
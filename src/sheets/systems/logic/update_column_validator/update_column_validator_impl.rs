@@ -4,8 +4,8 @@
 use crate::sheets::{
     definitions::{ColumnDataType, ColumnValidator, SheetMetadata},
     events::{
-        RequestSheetRevalidation, RequestUpdateColumnValidator, SheetDataModifiedInRegistryEvent,
-        SheetOperationFeedback,
+        RequestDeleteSheet, RequestSheetRevalidation, RequestUpdateColumnValidator,
+        SheetDataModifiedInRegistryEvent, SheetOperationFeedback, UpdateCellsBatchEvent,
     },
     resources::SheetRegistry,
     systems::io::save::save_single_sheet,
@@ -22,6 +22,10 @@ use super::db_operations::create_db_structure_table;
 use super::hierarchy::calculate_hierarchy_depth;
 use super::persistence::persist_non_structure_validator;
 use super::structure_conversion::handle_structure_conversion_to;
+use super::structure_naming::{
+    rename_db_column_for_structure, resolve_structure_column_header, structure_child_table_name,
+};
+use super::structure_removal::{apply_structure_removal, StructureRemoval};
 use super::validation::validate_column_update;
 
 /// Handles requests to update the validator (and derived base data type) for a specific column.
@@ -35,6 +39,8 @@ pub fn handle_update_column_validator(
     mut data_modified_writer: EventWriter<SheetDataModifiedInRegistryEvent>,
     mut editor_state: Option<ResMut<crate::ui::elements::editor::state::EditorWindowState>>,
     daemon_client: Res<SharedDaemonClient>,
+    mut batch_update_writer: EventWriter<UpdateCellsBatchEvent>,
+    mut delete_sheet_writer: EventWriter<RequestDeleteSheet>,
 ) {
     // Track sheets whose metadata changed so we can save after loop with immutable borrow
     let mut sheets_to_save: HashMap<(Option<String>, String), SheetMetadata> = HashMap::new();
@@ -47,6 +53,8 @@ pub fn handle_update_column_validator(
         crate::sheets::definitions::ColumnDefinition,
         Vec<crate::sheets::definitions::ColumnDefinition>,
     )> = Vec::new();
+    // Structure columns converted to a plain type whose child tables must be flattened/dropped
+    let mut structure_removals: Vec<StructureRemoval> = Vec::new();
 
     for event in events.read() {
         let category = &event.category;
@@ -94,6 +102,64 @@ pub fn handle_update_column_validator(
         info!("  Child structure table will be at depth: {}", parent_depth + 1);
         info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
         
+        // A new structure column whose `{parent}_{column}` child name is taken elsewhere gets a
+        // unique physical header (visible name kept as display name) before anything is created.
+        let becomes_structure = matches!(new_validator_opt, Some(ColumnValidator::Structure))
+            && registry
+                .get_sheet(category, sheet_name)
+                .and_then(|s| s.metadata.as_ref())
+                .and_then(|m| m.columns.get(col_index))
+                .map_or(false, |c| !matches!(c.validator, Some(ColumnValidator::Structure)));
+        let structure_header_override = if becomes_structure {
+            let naming = editor_state
+                .as_ref()
+                .map(|s| s.structure_table_naming)
+                .unwrap_or_default();
+            resolve_structure_column_header(&registry, category, sheet_name, col_index, naming)
+        } else {
+            None
+        };
+        if let Some(new_header) = &structure_header_override {
+            let (old_header, display_name) = registry
+                .get_sheet(category, sheet_name)
+                .and_then(|s| s.metadata.as_ref())
+                .and_then(|m| m.columns.get(col_index))
+                .map(|c| (c.header.clone(), c.display_name().to_string()))
+                .unwrap_or_default();
+            if let Some(cat) = category {
+                if let Err(e) = rename_db_column_for_structure(
+                    cat,
+                    sheet_name,
+                    col_index,
+                    &old_header,
+                    new_header,
+                    &display_name,
+                    daemon_client.client(),
+                ) {
+                    let msg = format!(
+                        "Failed to make structure table name unique for column '{}' of '{:?}/{}': {}",
+                        display_name, category, sheet_name, e
+                    );
+                    error!("{}", msg);
+                    feedback_writer.write(SheetOperationFeedback {
+                        message: msg,
+                        is_error: true,
+                    });
+                    continue;
+                }
+            }
+            feedback_writer.write(SheetOperationFeedback {
+                message: format!(
+                    "Table '{}' is already used by another sheet; column '{}' is stored as '{}' and its structure table will be '{}'.",
+                    structure_child_table_name(sheet_name, &old_header),
+                    display_name,
+                    new_header,
+                    structure_child_table_name(sheet_name, new_header)
+                ),
+                is_error: false,
+            });
+        }
+
         // (Structure schema handled elsewhere; no indices-based sources needed)
         let sheet_data_mut = match registry.get_sheet_mut(category, sheet_name) {
             Some(data) => data,
@@ -116,6 +182,13 @@ pub fn handle_update_column_validator(
             error!("Column index out of bounds during apply phase.");
             continue;
         }
+        if let Some(new_header) = structure_header_override {
+            let col = &mut meta_mut.columns[col_index];
+            if col.display_header.is_none() {
+                col.display_header = Some(col.header.clone());
+            }
+            col.header = new_header;
+        }
 
         // Snapshot old column definition & cell values before mutating (needed if self is included as source)
                 let old_col_def_snapshot = meta_mut.columns[col_index].clone();
@@ -130,8 +203,10 @@ pub fn handle_update_column_validator(
                 // Derive data type
                 let derived_type = match &meta_mut.columns[col_index].validator {
                     Some(ColumnValidator::Basic(t)) => *t,
+                    Some(ColumnValidator::Unique { data_type, .. }) => *data_type,
                     Some(ColumnValidator::Linked { .. }) => ColumnDataType::String,
                     Some(ColumnValidator::Structure) => ColumnDataType::String,
+                    Some(ColumnValidator::Enum(_)) => ColumnDataType::String,
                     None => ColumnDataType::String,
                 };
                 meta_mut.columns[col_index].data_type = derived_type;
@@ -208,8 +283,10 @@ pub fn handle_update_column_validator(
                             }
 
                             // Mark structure sheet for creation
-                            let structure_sheet_name =
-                                format!("{}_{}", sheet_name, meta_mut.columns[col_index].header);
+                            let structure_sheet_name = structure_child_table_name(
+                                sheet_name,
+                                &meta_mut.columns[col_index].header,
+                            );
 
                             info!(
                                 "📋 Queueing structure sheet for creation: '{}'",
@@ -254,6 +331,13 @@ pub fn handle_update_column_validator(
                         &meta_mut.columns[col_index].header,
                         &mut feedback_writer,
                     );
+                    structure_removals.push(StructureRemoval {
+                        category: category.clone(),
+                        sheet_name: sheet_name.clone(),
+                        col_index,
+                        column_header: meta_mut.columns[col_index].header.clone(),
+                        flatten_into_cell: event.flatten_structure_children,
+                    });
                 }
                 // After any potential row mutations, record metadata clone for save
                 // Emit data modified event so downstream systems (structure sync) run.
@@ -309,6 +393,16 @@ pub fn handle_update_column_validator(
         }
     }
 
+    // --- Phase 2.4: Flatten/drop child tables of former structure columns ---
+    for removal in &structure_removals {
+        apply_structure_removal(
+            removal,
+            &registry,
+            &mut batch_update_writer,
+            &mut delete_sheet_writer,
+        );
+    }
+
     // --- Phase 2.5: Create structure sheets ---
     if !structure_sheets_to_create.is_empty() {
         info!(
@@ -354,6 +448,7 @@ pub fn handle_update_column_validator(
             let structure_metadata = SheetMetadata {
                 columns: struct_columns.clone(),
                 hidden: true,
+                ai_key_column: None,
                 ..structure_metadata
             };
 
@@ -34,7 +34,9 @@ pub fn validate_column_update(
     
     if let Some(v) = new_validator_opt {
         match v {
-            ColumnValidator::Basic(_) => {}
+            ColumnValidator::Basic(_)
+            | ColumnValidator::Unique { .. }
+            | ColumnValidator::Enum(_) => {}
             ColumnValidator::Linked {
                 target_sheet_name,
                 target_column_index,
@@ -1,120 +1,192 @@
 // src/sheets/systems/logic/update_column_width.rs
 use crate::sheets::{
-    definitions::SheetMetadata, // Need metadata for saving
-    events::{RequestUpdateColumnWidth, SheetOperationFeedback}, // Use the new event
+    events::{RequestUpdateColumnWidth, SheetOperationFeedback},
     resources::SheetRegistry,
     systems::io::save::save_single_sheet,
+    systems::ui_handlers::calculate_column_width,
 };
 use bevy::prelude::*;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-/// Handles requests to update the width of a specific column in a sheet's metadata.
+/// Tolerance below which a resize is treated as unchanged (avoids saving on sub-pixel jitter)
+const WIDTH_EPSILON: f32 = 0.5;
+
+/// Quiet time after the last resize before widths are written
+const WIDTH_SAVE_DELAY_SECS: f64 = 0.75;
+
+/// Column widths changed in the registry but not yet written. Several resizes in a row
+/// (or a resize repeated over a few frames) end up as one write per column.
+#[derive(Resource, Debug, Default)]
+pub struct ColumnWidthSaveQueue {
+    /// DB sheets: (category, sheet, column header) -> latest width
+    db_widths: HashMap<(String, String, String), f32>,
+    /// JSON sheets are saved whole
+    json_sheets: HashSet<(Option<String>, String)>,
+    last_change_secs: f64,
+}
+
+impl ColumnWidthSaveQueue {
+    fn record_db(&mut self, category: &str, sheet_name: &str, header: &str, width: f32, now_secs: f64) {
+        self.db_widths
+            .insert((category.to_string(), sheet_name.to_string(), header.to_string()), width);
+        self.last_change_secs = now_secs;
+    }
+
+    fn record_json(&mut self, category: &Option<String>, sheet_name: &str, now_secs: f64) {
+        self.json_sheets.insert((category.clone(), sheet_name.to_string()));
+        self.last_change_secs = now_secs;
+    }
+
+    fn is_empty(&self) -> bool {
+        self.db_widths.is_empty() && self.json_sheets.is_empty()
+    }
+
+    fn is_due(&self, now_secs: f64) -> bool {
+        !self.is_empty() && now_secs - self.last_change_secs >= WIDTH_SAVE_DELAY_SECS
+    }
+}
+
+/// Handles requests to update the width of a column in a sheet's metadata.
+///
+/// Columns are looked up by physical name rather than position, so a width
+/// recorded before a reorder can never land on a different column. The registry is
+/// updated at once; writing to disk is left to `flush_column_width_saves`.
 pub fn handle_update_column_width(
     mut events: EventReader<RequestUpdateColumnWidth>,
     mut registry: ResMut<SheetRegistry>,
-    mut feedback_writer: EventWriter<SheetOperationFeedback>,
+    mut save_queue: ResMut<ColumnWidthSaveQueue>,
+    time: Res<Time>,
 ) {
-    let mut changed_sheets: HashMap<(Option<String>, String), SheetMetadata> =
-        HashMap::new();
-
+    let now_secs = time.elapsed_secs_f64();
     for event in events.read() {
         let category = &event.category;
         let sheet_name = &event.sheet_name;
-        let col_index = event.column_index;
-        let new_width = event.new_width;
 
-        let mut success = false;
-        let mut metadata_cache: Option<SheetMetadata> = None;
+        if !event.new_width.is_finite() {
+            warn!(
+                "Ignoring invalid width {} for column '{}' in '{:?}/{}'.",
+                event.new_width, event.column_header, category, sheet_name
+            );
+            continue;
+        }
 
-        // --- Validation ---
-        // Add basic width validation if needed (e.g., ensure positive)
-        if new_width <= 0.0 {
-            feedback_writer.write(SheetOperationFeedback {
-                message: format!(
-                    "Failed column width update in '{:?}/{}': Width must be positive.",
-                    category, sheet_name
-                ),
-                is_error: true,
-            });
-            continue; // Skip invalid width
+        let Some(metadata) = registry
+            .get_sheet_mut(category, sheet_name)
+            .and_then(|s| s.metadata.as_mut())
+        else {
+            warn!(
+                "Column width update skipped: sheet '{:?}/{}' not found.",
+                category, sheet_name
+            );
+            continue;
+        };
+        let Some(column_def) = metadata
+            .columns
+            .iter_mut()
+            .find(|c| c.header == event.column_header)
+        else {
+            warn!(
+                "Column width update skipped: column '{}' no longer exists in '{:?}/{}'.",
+                event.column_header, category, sheet_name
+            );
+            continue;
+        };
+
+        // Never store less than the grid's minimum for the column type, so a column
+        // dragged all the way closed can still be grabbed and widened again
+        let (_, min_width) = calculate_column_width(column_def.validator.as_ref(), column_def.data_type);
+        let new_width = event.new_width.max(min_width);
+        if column_def
+            .width
+            .map_or(false, |w| (w - new_width).abs() <= WIDTH_EPSILON)
+        {
+            continue;
         }
+        trace!(
+            "Column '{}' width in '{:?}/{}': {:?} -> {:.1}",
+            column_def.header,
+            category,
+            sheet_name,
+            column_def.width,
+            new_width
+        );
+        column_def.width = Some(new_width);
+
+        match metadata.category.as_deref() {
+            Some(db_category) => save_queue.record_db(
+                db_category,
+                &metadata.sheet_name,
+                &event.column_header,
+                new_width,
+                now_secs,
+            ),
+            None => save_queue.record_json(category, sheet_name, now_secs),
+        }
+    }
+}
+
+/// Writes queued column widths once resizing has been quiet for a moment:
+/// DB sheets to their `_Metadata` table, JSON sheets by saving the sheet file.
+pub fn flush_column_width_saves(
+    mut save_queue: ResMut<ColumnWidthSaveQueue>,
+    registry: Res<SheetRegistry>,
+    time: Res<Time>,
+    mut feedback_writer: EventWriter<SheetOperationFeedback>,
+    daemon_client: Res<crate::sheets::database::daemon_resource::SharedDaemonClient>,
+) {
+    if !save_queue.is_due(time.elapsed_secs_f64()) {
+        return;
+    }
+    let queue = std::mem::take(&mut *save_queue);
 
-        // --- Apply Update ---
-        if let Some(sheet_data) = registry.get_sheet_mut(category, sheet_name) {
-            if let Some(metadata) = &mut sheet_data.metadata {
-                if let Some(column_def) = metadata.columns.get_mut(col_index) {
-                    // Only update if the width actually changed (handle f32 comparison carefully)
-                    let current_width = column_def.width;
-                    const EPSILON: f32 = 0.1; // Tolerance for float comparison
-                    if current_width.is_none() || (current_width.unwrap() - new_width).abs() > EPSILON {
-                        trace!(
-                            "Updating column {} width in sheet '{:?}/{}' from {:?} to {:.1}",
-                            col_index + 1,
-                            category,
-                            sheet_name,
-                            current_width,
-                            new_width
-                        );
-                        column_def.width = Some(new_width);
-                        success = true;
-                        metadata_cache = Some(metadata.clone()); // Cache metadata for saving
-                    } else {
-                         trace!(
-                             "Column {} width unchanged for sheet '{:?}/{}' (approx {:.1}). Skipping.",
-                             col_index + 1, category, sheet_name, new_width
-                         );
-                    }
-                } else {
-                    feedback_writer.write(SheetOperationFeedback {
-                        message: format!(
-                            "Failed column width update in '{:?}/{}': Index {} out of bounds ({} columns).",
-                            category,
-                            sheet_name,
-                            col_index,
-                            metadata.columns.len()
-                        ),
-                        is_error: true,
-                    });
-                }
-            } else {
-                feedback_writer.write(SheetOperationFeedback {
-                    message: format!(
-                        "Failed column width update in '{:?}/{}': Metadata missing.",
-                        category, sheet_name
-                    ),
-                    is_error: true,
-                });
-            }
-        } else {
+    for ((db_category, sheet_name, header), width) in queue.db_widths {
+        if let Err(e) = crate::sheets::database::persist_column_width(
+            &db_category,
+            &sheet_name,
+            &header,
+            Some(width),
+            daemon_client.client(),
+        ) {
+            error!(
+                "Failed to persist width of column '{}' in '{}/{}': {}",
+                header, db_category, sheet_name, e
+            );
             feedback_writer.write(SheetOperationFeedback {
-                message: format!(
-                    "Failed column width update: Sheet '{:?}/{}' not found.",
-                    category, sheet_name
-                ),
+                message: format!("Failed to save width of column '{}': {}", header, e),
                 is_error: true,
             });
         }
+    }
 
-        // If successful, mark sheet for saving
-        if success {
-            if let Some(meta) = metadata_cache {
-                let key = (category.clone(), sheet_name.clone());
-                changed_sheets.insert(key, meta);
-            }
-        }
-    } // End event loop
-
-    // --- Trigger Saves ---
-    if !changed_sheets.is_empty() {
-        let registry_immut = registry.as_ref(); // Immutable borrow for saving
-        for ((cat, name), metadata) in changed_sheets {
-            info!(
-                "Column width updated for '{:?}/{}', triggering save.",
-                cat, name
-            );
-            if metadata.category.is_none() {
-                save_single_sheet(registry_immut, &metadata); // Pass metadata
-            }
-        }
+    for (cat, name) in queue.json_sheets {
+        let Some(metadata) = registry.get_sheet(&cat, &name).and_then(|s| s.metadata.as_ref()) else {
+            continue;
+        };
+        debug!("Column width updated for '{:?}/{}', saving sheet.", cat, name);
+        save_single_sheet(registry.as_ref(), metadata);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn widths_are_written_once_resizing_settles() {
+        let mut queue = ColumnWidthSaveQueue::default();
+        assert!(!queue.is_due(10.0));
+
+        queue.record_db("Game", "Items", "Name", 140.0, 1.0);
+        queue.record_db("Game", "Items", "Name", 180.0, 1.3);
+        queue.record_json(&None, "Notes", 1.5);
+        // Each change restarts the wait
+        assert!(!queue.is_due(1.5 + WIDTH_SAVE_DELAY_SECS / 2.0));
+        assert!(queue.is_due(1.5 + WIDTH_SAVE_DELAY_SECS));
+        // Repeated resizes of one column collapse into its latest width
+        assert_eq!(queue.db_widths.len(), 1);
+        assert_eq!(
+            queue.db_widths.get(&("Game".to_string(), "Items".to_string(), "Name".to_string())),
+            Some(&180.0)
+        );
     }
-}
\ No newline at end of file
+}
@@ -17,11 +17,15 @@ use crate::{
             SheetDataModifiedInRegistryEvent,
         },
         resources::{SheetRegistry, SheetRenderCache},
+        systems::logic::column_filter::{compile_filter_regex, regex_filter_pattern},
         systems::logic::generate_structure_preview,
+        systems::logic::unique_validator::{duplicate_groups, unique_settings},
     },
     ui::{
         elements::editor::state::EditorWindowState,
-        validation::{validate_basic_cell, validate_linked_cell, ValidationState},
+        validation::{
+            validate_basic_cell, validate_enum_cell, validate_linked_cell, ValidationState,
+        },
     },
 };
 
@@ -138,6 +142,21 @@ pub fn handle_sheet_render_cache_update(
                     .map(|s| s.show_hidden_sheets)
                     .unwrap_or(false);
 
+                // Cells of Unique columns whose value also appears in another row
+                let duplicate_cells: HashSet<(usize, usize)> = metadata
+                    .columns
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(c_idx, col)| {
+                        unique_settings(&col.validator).map(|settings| (c_idx, settings))
+                    })
+                    .flat_map(|(c_idx, (data_type, case_sensitive))| {
+                        duplicate_groups(&sheet_data.grid, c_idx, data_type, case_sensitive)
+                            .into_iter()
+                            .flat_map(move |(_, rows)| rows.into_iter().map(move |r| (r, c_idx)))
+                    })
+                    .collect();
+
                 if use_lazy_resolution {
                     debug!("Using lazy resolution for large table '{:?}/{}' ({} rows)",
                            category, sheet_name, num_rows);
@@ -165,6 +184,15 @@ pub fn handle_sheet_render_cache_update(
                                         validate_basic_cell(cell_value_str, *data_type);
                                     (state, None)
                                 }
+                                Some(ColumnValidator::Unique { data_type, .. }) => {
+                                    let (state, _parse_error) =
+                                        validate_basic_cell(cell_value_str, *data_type);
+                                    if duplicate_cells.contains(&(r_idx, c_idx)) {
+                                        (ValidationState::Invalid, None)
+                                    } else {
+                                        (state, None)
+                                    }
+                                }
                                 Some(ColumnValidator::Linked {
                                     target_sheet_name,
                                     target_column_index,
@@ -181,6 +209,9 @@ pub fn handle_sheet_render_cache_update(
                                     // Treat structure cells as always valid (content is JSON string) for now
                                     (ValidationState::Valid, None)
                                 }
+                                Some(ColumnValidator::Enum(allowed)) => {
+                                    (validate_enum_cell(cell_value_str, allowed), None)
+                                }
                                 None => {
                                     // Treat as basic string if no validator
                                     let (state, _parse_error) =
@@ -275,6 +306,15 @@ pub fn handle_sheet_render_cache_update(
                                             validate_basic_cell(cell_value_str, *data_type);
                                         (state, None)
                                     }
+                                    Some(ColumnValidator::Unique { data_type, .. }) => {
+                                        let (state, _) =
+                                            validate_basic_cell(cell_value_str, *data_type);
+                                        if duplicate_cells.contains(&(r_idx, c_idx)) {
+                                            (ValidationState::Invalid, None)
+                                        } else {
+                                            (state, None)
+                                        }
+                                    }
                                     Some(ColumnValidator::Linked {
                                         target_sheet_name,
                                         target_column_index,
@@ -288,6 +328,9 @@ pub fn handle_sheet_render_cache_update(
                                     Some(ColumnValidator::Structure) => {
                                         (ValidationState::Valid, None)
                                     }
+                                    Some(ColumnValidator::Enum(allowed)) => {
+                                        (validate_enum_cell(cell_value_str, allowed), None)
+                                    }
                                     None => {
                                         let (state, _) = validate_basic_cell(
                                             cell_value_str,
@@ -320,6 +363,14 @@ pub fn handle_sheet_render_cache_update(
                     let filtered_indices: Vec<usize> = {
                         // Basic filter by metadata filters (contains OR semantics) + structure nav filter
                         let filters: Vec<Option<String>> = metadata.get_filters();
+                        // `re:` filters compiled once for the whole pass; invalid ones filter nothing out
+                        let filter_regexes: Vec<Option<regex::Regex>> = filters
+                            .iter()
+                            .map(|f| {
+                                let pattern = f.as_deref().and_then(regex_filter_pattern)?;
+                                compile_filter_regex(pattern).ok()
+                            })
+                            .collect();
                         let mut out: Vec<usize> = Vec::new();
                         // Structure navigation hidden filter
                         let nav_ctx_opt = current_editor_state.as_ref().and_then(|st| st.structure_navigation_stack.last());
@@ -334,6 +385,10 @@ pub fn handle_sheet_render_cache_update(
                             if filters.iter().enumerate().any(|(ci, f)| {
                                 if let Some(ftext) = f {
                                     if ftext.trim().is_empty() { return false; }
+                                    if regex_filter_pattern(ftext).is_some() {
+                                        let cell = sheet_data.grid.get(r_idx).and_then(|row| row.get(ci)).map(|s| s.as_str()).unwrap_or("");
+                                        return filter_regexes[ci].as_ref().map_or(false, |re| !re.is_match(cell));
+                                    }
                                     let terms: Vec<&str> = ftext.split('|').map(|s| s.trim()).filter(|s| !s.is_empty()).collect();
                                     if terms.is_empty() { return false; }
                                     let cell = sheet_data.grid.get(r_idx).and_then(|row| row.get(ci)).cloned().unwrap_or_default();
@@ -23,6 +23,8 @@ pub fn calculate_column_width(
         (_, ColumnDataType::F64) => (78.0, 36.0),
         // Link: wider to accommodate URLs and edit toggle button
         (_, ColumnDataType::Link) => (180.0, 60.0),
+        // Date: fits "YYYY-MM-DD" plus the calendar button
+        (_, ColumnDataType::Date) => (110.0, 60.0),
         // Default/text: a bit wider than base to improve readability
         _ => (120.0, 48.0),
     }
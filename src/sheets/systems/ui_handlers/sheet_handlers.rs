@@ -1,5 +1,6 @@
 // src/sheets/systems/ui_handlers/sheet_handlers.rs
 use bevy::prelude::*;
+use crate::sheets::events::SheetOperationFeedback;
 use crate::sheets::resources::SheetRegistry;
 use crate::sheets::database::daemon_client::DaemonClient;
 use crate::ui::elements::editor::state::EditorWindowState;
@@ -282,6 +283,105 @@ pub fn handle_delete_sheet_request(
     }
 }
 
+/// Handle "Reload from Database" request for the given sheet (asks for confirmation first)
+pub fn handle_reload_sheet_request(
+    state: &mut EditorWindowState,
+    category: &Option<String>,
+    sheet_name: &str,
+) {
+    state.reload_confirm_target = Some((category.clone(), sheet_name.to_string()));
+}
+
+/// Process a confirmed "Reload from Database": re-read the sheet and its structure
+/// child tables, replacing the in-memory copies and discarding unpersisted state.
+pub fn process_pending_sheet_reload(
+    state: &mut EditorWindowState,
+    registry: &mut SheetRegistry,
+    daemon_client: &DaemonClient,
+    feedback: &mut EventWriter<SheetOperationFeedback>,
+) {
+    let Some((category, sheet_name)) = state.pending_sheet_reload.take() else {
+        return;
+    };
+
+    match reload_sheet_tree_from_db(registry, daemon_client, &category, &sheet_name) {
+        Ok(tables) => {
+            state.reset_interaction_modes_and_selections();
+            state.filtered_row_indices_cache.clear();
+            state.linked_column_cache.clear();
+            state.linked_column_cache_normalized.clear();
+            state.mark_ai_included_columns_dirty();
+            state.force_filter_recalculation = true;
+            state.pending_sheet_revalidation = true;
+            feedback.write(SheetOperationFeedback {
+                message: format!(
+                    "Reloaded '{}' from database ({} table(s)).",
+                    sheet_name,
+                    tables.len()
+                ),
+                is_error: false,
+            });
+        }
+        Err(e) => {
+            error!("Reload of '{:?}/{}' from DB failed: {}", category, sheet_name, e);
+            feedback.write(SheetOperationFeedback {
+                message: format!("Failed to reload '{}' from database: {}", sheet_name, e),
+                is_error: true,
+            });
+        }
+    }
+}
+
+/// Re-read a sheet and (recursively) its structure child tables from the category database.
+/// Returns the names of the tables replaced in the registry.
+pub(crate) fn reload_sheet_tree_from_db(
+    registry: &mut SheetRegistry,
+    daemon_client: &DaemonClient,
+    category: &Option<String>,
+    sheet_name: &str,
+) -> Result<Vec<String>, String> {
+    use crate::sheets::definitions::ColumnValidator;
+
+    let Some(cat_str) = category.as_ref() else {
+        return Err("only database-backed sheets can be reloaded".to_string());
+    };
+    let db_path = crate::sheets::systems::io::get_default_data_base_path()
+        .join(format!("{}.db", cat_str));
+    if !db_path.exists() {
+        return Err(format!("database file not found: {:?}", db_path));
+    }
+    let conn = rusqlite::Connection::open(&db_path).map_err(|e| e.to_string())?;
+
+    let mut queue = vec![sheet_name.to_string()];
+    let mut reloaded = Vec::new();
+    while let Some(table_name) = queue.pop() {
+        let sheet_data = match crate::sheets::database::reader::DbReader::read_sheet(
+            &conn,
+            &table_name,
+            daemon_client,
+            Some(cat_str),
+        ) {
+            Ok(data) => data,
+            // The requested sheet must load; a missing child table is only logged
+            Err(e) if table_name == sheet_name => return Err(e.to_string()),
+            Err(e) => {
+                warn!("Skipping structure table '{}' during reload: {}", table_name, e);
+                continue;
+            }
+        };
+        if let Some(meta) = &sheet_data.metadata {
+            for col in &meta.columns {
+                if matches!(col.validator, Some(ColumnValidator::Structure)) {
+                    queue.push(format!("{}_{}", table_name, col.header));
+                }
+            }
+        }
+        registry.add_or_replace_sheet(category.clone(), table_name.clone(), sheet_data);
+        reloaded.push(table_name);
+    }
+    Ok(reloaded)
+}
+
 /// Handle new sheet request
 pub fn handle_new_sheet_request(
     state: &mut EditorWindowState,
@@ -9,16 +9,24 @@ use crate::sheets::{
     },
     resources::{ClipboardBuffer, SheetRegistry, SheetRenderCache},
     systems::logic::{
-        determine_cell_background_color, determine_effective_validation_state,
-        is_column_ai_included, is_structure_column_ai_included, prefetch_linked_column_values,
+        cell_comments::cell_comment_key, determine_cell_background_color,
+        determine_effective_validation_state, is_column_ai_included, is_structure_column_ai_included, prefetch_linked_column_values,
+        sheet_find::text_matches_find,
     },
 };
-use crate::ui::elements::editor::state::EditorWindowState;
+use crate::ui::elements::editor::state::{EditorWindowState, LongTextEditorState};
+use crate::ui::elements::editor::table_body::trim_insignificant_zeros;
 use crate::ui::validation::ValidationState;
 use crate::ui::widgets::{
-    handle_linked_column_edit, add_cell_context_menu, add_centered_checkbox, add_numeric_drag_value,
+    handle_linked_column_edit, add_cell_context_menu, add_centered_checkbox, add_date_cell,
+    add_enum_cell, add_formatted_f64_drag_value, add_long_text_cell, CellRangeAction,
+    add_numeric_drag_value,
     render_technical_column, render_structure_column,
 };
+
+/// Faint glyph shown in empty text cells when the placeholder setting is on
+const EMPTY_CELL_PLACEHOLDER: &str = "–";
+
 #[allow(clippy::too_many_arguments, unused_variables, unused_assignments)]
 pub fn edit_cell_widget(
     ui: &mut egui::Ui,
@@ -38,6 +46,18 @@ pub fn edit_cell_widget(
 ) -> Option<String> {
     let is_column_selected_for_deletion = state.selected_columns_for_deletion.contains(&col_index);
     let is_row_selected = state.ai_selected_rows.contains(&row_index);
+    // Quick add-row: focus the chosen text cell of the new row once it is drawn
+    let focus_requested = state
+        .pending_new_row_focus
+        .as_ref()
+        .map_or(false, |(cat, sheet, row, col)| {
+            cat == category && sheet == sheet_name && *row == row_index && *col == col_index
+        });
+    if focus_requested {
+        state.pending_new_row_focus = None;
+    }
+    // Faint glyph for empty text cells (egui hides the hint as soon as text is typed)
+    let empty_placeholder = if state.show_empty_cell_placeholder { EMPTY_CELL_PLACEHOLDER } else { "" };
     let current_interaction_mode = state.current_interaction_mode;
     let render_cell_data_opt =
         render_cache.get_cell_data(category, sheet_name, row_index, col_index);
@@ -54,11 +74,13 @@ pub fn edit_cell_widget(
             ("", ValidationState::default())
         }
     };
-    let basic_type = registry
+    let (basic_type, trim_numeric_zeros, long_text) = registry
         .get_sheet(category, sheet_name)
         .and_then(|sd| sd.metadata.as_ref())
         .and_then(|meta| meta.columns.get(col_index))
-        .map_or(ColumnDataType::String, |col_def| col_def.data_type);
+        .map_or((ColumnDataType::String, false, false), |col_def| {
+            (col_def.data_type, col_def.trim_numeric_zeros, col_def.multiline)
+        });
     let prefetch = prefetch_linked_column_values(validator_opt, registry, state);
     let prefetch_allowed_values = prefetch.raw_values;
     let prefetch_allowed_values_norm = prefetch.normalized_values;
@@ -85,9 +107,20 @@ pub fn edit_cell_widget(
     );
     let struct_ai_included =
         is_structure_column_ai_included(state, category, sheet_name, col_index, is_structure_column);
+    let row_locked = registry.is_grid_row_locked(category, sheet_name, row_index);
+    let is_find_match = state.show_sheet_find_bar
+        && &state.selected_category == category
+        && state.selected_sheet_name.as_deref() == Some(sheet_name)
+        && text_matches_find(
+            current_display_text,
+            &state.sheet_find_query,
+            state.sheet_find_case_sensitive,
+        );
     let bg_color = determine_cell_background_color(
         is_column_selected_for_deletion,
         is_row_selected,
+        row_locked,
+        is_find_match,
         current_interaction_mode,
         is_structure_column,
         struct_ai_included,
@@ -99,6 +132,16 @@ pub fn edit_cell_widget(
     let frame = egui::Frame::NONE
         .inner_margin(egui::Margin::symmetric(2, 1))
         .fill(bg_color);
+    let mut send_row_to_ai = false;
+    let ai_available = state.ai_available();
+    let mut edit_comment = false;
+    let mut toggle_row_lock = false;
+    let mut duplicate_row = false;
+    let has_range_anchor = state
+        .copy_range_anchor
+        .as_ref()
+        .map_or(false, |(cat, name, _, _)| cat == category && name == sheet_name);
+    let mut range_action: Option<CellRangeAction> = None;
     let inner_response = ui
         .allocate_new_ui(egui::UiBuilder::new().max_rect(frame_rect), |frame_ui| {
             frame.show(frame_ui, |widget_ui| {
@@ -202,18 +245,98 @@ pub fn edit_cell_widget(
                                     paste_events,
                                     clipboard_buffer,
                                     &mut temp_new_value,
+                                    &mut send_row_to_ai,
+                                    ai_available,
+                                    has_range_anchor,
+                                    &mut range_action,
+                                    &mut edit_comment,
+                                    row_locked,
+                                    &mut toggle_row_lock,
+                                    &mut duplicate_row,
+                                );
+                                response_opt = Some(resp);
+                            }
+                            Some(ColumnValidator::Enum(allowed)) => {
+                                let (resp, picked) = add_enum_cell(
+                                    widget_ui,
+                                    id,
+                                    current_display_text,
+                                    allowed,
+                                    empty_placeholder,
+                                );
+                                if picked.is_some() {
+                                    temp_new_value = picked;
+                                }
+                                let resp = add_cell_context_menu(
+                                    resp,
+                                    category,
+                                    sheet_name,
+                                    row_index,
+                                    col_index,
+                                    copy_events,
+                                    paste_events,
+                                    clipboard_buffer,
+                                    &mut temp_new_value,
+                                    &mut send_row_to_ai,
+                                    ai_available,
+                                    has_range_anchor,
+                                    &mut range_action,
+                                    &mut edit_comment,
+                                    row_locked,
+                                    &mut toggle_row_lock,
+                                    &mut duplicate_row,
                                 );
                                 response_opt = Some(resp);
                             }
-                            Some(ColumnValidator::Basic(_)) | None => {
+                            Some(ColumnValidator::Basic(_))
+                            | Some(ColumnValidator::Unique { .. })
+                            | None => {
                                 match basic_type {
+                                    ColumnDataType::String if long_text => {
+                                        let (resp, open_editor) =
+                                            add_long_text_cell(widget_ui, current_display_text, empty_placeholder);
+                                        if open_editor {
+                                            state.long_text_editor = Some(LongTextEditorState {
+                                                category: category.clone(),
+                                                sheet_name: sheet_name.to_string(),
+                                                row_index,
+                                                col_index,
+                                                original: current_display_text.to_string(),
+                                                text: current_display_text.to_string(),
+                                            });
+                                        }
+                                        let resp = add_cell_context_menu(
+                                            resp,
+                                            category,
+                                            sheet_name,
+                                            row_index,
+                                            col_index,
+                                            copy_events,
+                                            paste_events,
+                                            clipboard_buffer,
+                                            &mut temp_new_value,
+                                            &mut send_row_to_ai,
+                                            ai_available,
+                                            has_range_anchor,
+                                            &mut range_action,
+                                            &mut edit_comment,
+                                            row_locked,
+                                            &mut toggle_row_lock,
+                                            &mut duplicate_row,
+                                        );
+                                        response_opt = Some(resp);
+                                    }
                                     ColumnDataType::String => {
                                         let mut temp_string = current_display_text.to_string();
                                         let resp = widget_ui.add_sized(
                                             widget_ui.available_size(),
                                             egui::TextEdit::singleline(&mut temp_string)
-                                                .frame(false),
+                                                .frame(false)
+                                                .hint_text(empty_placeholder),
                                         );
+                                        if focus_requested {
+                                            resp.request_focus();
+                                        }
                                         if resp.changed() {
                                             temp_new_value = Some(temp_string);
                                         }
@@ -227,6 +350,14 @@ pub fn edit_cell_widget(
                                             paste_events,
                                             clipboard_buffer,
                                             &mut temp_new_value,
+                                            &mut send_row_to_ai,
+                                            ai_available,
+                                            has_range_anchor,
+                                            &mut range_action,
+                                            &mut edit_comment,
+                                            row_locked,
+                                            &mut toggle_row_lock,
+                                            &mut duplicate_row,
                                         );
                                         response_opt = Some(resp);
                                     }
@@ -249,6 +380,14 @@ pub fn edit_cell_widget(
                                             paste_events,
                                             clipboard_buffer,
                                             &mut temp_new_value,
+                                            &mut send_row_to_ai,
+                                            ai_available,
+                                            has_range_anchor,
+                                            &mut range_action,
+                                            &mut edit_comment,
+                                            row_locked,
+                                            &mut toggle_row_lock,
+                                            &mut duplicate_row,
                                         );
                                         response_opt = Some(resp);
                                     }
@@ -269,13 +408,30 @@ pub fn edit_cell_widget(
                                             paste_events,
                                             clipboard_buffer,
                                             &mut temp_new_value,
+                                            &mut send_row_to_ai,
+                                            ai_available,
+                                            has_range_anchor,
+                                            &mut range_action,
+                                            &mut edit_comment,
+                                            row_locked,
+                                            &mut toggle_row_lock,
+                                            &mut duplicate_row,
                                         );
                                         response_opt = Some(resp);
                                     }
                                     ColumnDataType::F64 => {
                                         let mut value_for_widget: f64 =
                                             current_display_text.parse().unwrap_or(0.0);
-                                        let resp = add_numeric_drag_value(widget_ui, &mut value_for_widget, 0.1);
+                                        let resp = if trim_numeric_zeros {
+                                            add_formatted_f64_drag_value(
+                                                widget_ui,
+                                                &mut value_for_widget,
+                                                0.1,
+                                                trim_insignificant_zeros(current_display_text),
+                                            )
+                                        } else {
+                                            add_numeric_drag_value(widget_ui, &mut value_for_widget, 0.1)
+                                        };
                                         if resp.changed() {
                                             temp_new_value = Some(value_for_widget.to_string());
                                         }
@@ -289,6 +445,41 @@ pub fn edit_cell_widget(
                                             paste_events,
                                             clipboard_buffer,
                                             &mut temp_new_value,
+                                            &mut send_row_to_ai,
+                                            ai_available,
+                                            has_range_anchor,
+                                            &mut range_action,
+                                            &mut edit_comment,
+                                            row_locked,
+                                            &mut toggle_row_lock,
+                                            &mut duplicate_row,
+                                        );
+                                        response_opt = Some(resp);
+                                    }
+                                    ColumnDataType::Date => {
+                                        let (resp, picked) =
+                                            add_date_cell(widget_ui, id, current_display_text);
+                                        if picked.is_some() {
+                                            temp_new_value = picked;
+                                        }
+                                        let resp = add_cell_context_menu(
+                                            resp,
+                                            category,
+                                            sheet_name,
+                                            row_index,
+                                            col_index,
+                                            copy_events,
+                                            paste_events,
+                                            clipboard_buffer,
+                                            &mut temp_new_value,
+                                            &mut send_row_to_ai,
+                                            ai_available,
+                                            has_range_anchor,
+                                            &mut range_action,
+                                            &mut edit_comment,
+                                            row_locked,
+                                            &mut toggle_row_lock,
+                                            &mut duplicate_row,
                                         );
                                         response_opt = Some(resp);
                                     }
@@ -357,6 +548,14 @@ pub fn edit_cell_widget(
                                             paste_events,
                                             clipboard_buffer,
                                             &mut temp_new_value,
+                                            &mut send_row_to_ai,
+                                            ai_available,
+                                            has_range_anchor,
+                                            &mut range_action,
+                                            &mut edit_comment,
+                                            row_locked,
+                                            &mut toggle_row_lock,
+                                            &mut duplicate_row,
                                         );
                                         response_opt = Some(resp);
                                     }
@@ -368,7 +567,103 @@ pub fn edit_cell_widget(
                 .inner
         })
         .inner;
-    let (_widget_resp_opt, final_new_value) = inner_response;
+    let (widget_resp_opt, final_new_value) = inner_response;
+    if state.pending_active_cell_focus && state.active_cell == Some((row_index, col_index)) {
+        if let Some(resp) = widget_resp_opt.as_ref() {
+            resp.request_focus();
+            resp.scroll_to_me(None);
+        }
+        state.pending_active_cell_focus = false;
+    }
+    if let Some(resp) = widget_resp_opt.as_ref().filter(|resp| resp.has_focus()) {
+        state.grid_active_cell = Some((
+            category.clone(),
+            sheet_name.to_string(),
+            row_index,
+            col_index,
+            resp.id,
+        ));
+        state.active_cell = Some((row_index, col_index));
+        // Arrows and Tab belong to grid navigation, not egui's own focus traversal
+        ui.memory_mut(|mem| {
+            mem.set_focus_lock_filter(
+                resp.id,
+                egui::EventFilter {
+                    tab: true,
+                    horizontal_arrows: true,
+                    vertical_arrows: true,
+                    escape: false,
+                },
+            )
+        });
+    }
+    if send_row_to_ai {
+        state.pending_single_row_ai_send = Some(row_index);
+    }
+    if toggle_row_lock {
+        state.pending_row_lock = Some((row_index, !row_locked));
+    }
+    if duplicate_row {
+        state.pending_row_duplicate = Some(row_index);
+    }
+    let comment_key = if !state.cell_comments.is_empty()
+        && state.cell_comments_sheet.as_ref().map_or(false, |(cat, name)| {
+            cat == category && name == sheet_name
+        }) {
+        registry
+            .get_sheet(category, sheet_name)
+            .and_then(|sheet| cell_comment_key(sheet, row_index, col_index))
+    } else {
+        None
+    };
+    let comment = comment_key.and_then(|key| state.cell_comments.get(&key).cloned());
+    if edit_comment {
+        state.cell_comment_target =
+            Some((category.clone(), sheet_name.to_string(), row_index, col_index));
+        state.cell_comment_input = comment.clone().unwrap_or_default();
+    }
+    if let Some(comment) = comment {
+        // Corner triangle marks a commented cell; hovering shows the note
+        let size = (frame_rect.height() * 0.35).clamp(4.0, 8.0);
+        let corner = frame_rect.right_top();
+        ui.painter().add(egui::Shape::convex_polygon(
+            vec![corner, corner + egui::vec2(0.0, size), corner - egui::vec2(size, 0.0)],
+            egui::Color32::from_rgb(230, 160, 40),
+            egui::Stroke::NONE,
+        ));
+        ui.interact(frame_rect, frame_id.with("hover_comment"), Sense::hover())
+            .on_hover_text(comment);
+    }
+    match range_action {
+        Some(CellRangeAction::MarkStart) => {
+            state.copy_range_anchor =
+                Some((category.clone(), sheet_name.to_string(), row_index, col_index));
+        }
+        Some(CellRangeAction::CopyToHere) => {
+            state.pending_range_copy = Some((row_index, col_index));
+        }
+        None => {}
+    }
+    let is_search_hit = state
+        .structure_search_highlight
+        .as_ref()
+        .is_some_and(|(cat, name, row, col)| {
+            cat == category && name == sheet_name && *row == row_index && *col == col_index
+        });
+    // The find match navigated to with Enter / the arrow buttons
+    let is_current_find_match = is_find_match
+        && state
+            .sheet_find_current
+            .and_then(|pos| state.sheet_find_matches.get(pos))
+            .is_some_and(|&(row, col)| row == row_index && col == col_index);
+    if is_search_hit || is_current_find_match {
+        ui.painter().rect_stroke(
+            frame_rect,
+            2.0,
+            egui::Stroke::new(2.0, egui::Color32::from_rgb(255, 200, 60)),
+            egui::StrokeKind::Inside,
+        );
+    }
     if effective_validation_state == ValidationState::Invalid {
         let hover_text = format!(
             "Invalid Value! '{}' is not allowed here.",
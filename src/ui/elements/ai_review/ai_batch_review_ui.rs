@@ -2,6 +2,7 @@ use crate::sheets::events::{AddSheetRowRequest, UpdateCellEvent};
 use crate::sheets::resources::SheetRegistry;
 use crate::sheets::systems::ai_review::cache_handlers::cancel_batch;
 use crate::sheets::systems::ai_review::display_context::prepare_display_context;
+use crate::sheets::systems::ai_review::review_summary::summarize_state;
 use crate::sheets::systems::ai_review::review_logic::{
     hydrate_structure_detail_if_needed, process_accept_all_normal_mode,
     process_accept_all_structure_mode, process_decline_all_structure_mode, should_auto_exit,
@@ -59,6 +60,11 @@ pub(crate) fn draw_ai_batch_review_panel(
 
     // Draw header actions (now includes navigation back button support)
     let actions = draw_header_actions(ui, state, display_ctx.show_pending_structures, registry);
+    draw_review_summary_bar(ui, state);
+
+    if !state.ai_malformed_row_notes.is_empty() && !display_ctx.in_structure_mode {
+        draw_malformed_rows_notice(ui, &state.ai_malformed_row_notes);
+    }
 
     // Process accept all action
     if actions.accept_all {
@@ -329,3 +335,64 @@ pub(crate) fn draw_ai_batch_review_panel(
         cancel_batch(state, None);
     }
 }
+
+/// Lists AI rows that came back with the wrong column count and were left unapplied
+fn draw_malformed_rows_notice(ui: &mut egui::Ui, notes: &[String]) {
+    egui::CollapsingHeader::new(
+        egui::RichText::new(format!("⚠ {} row(s) need attention", notes.len()))
+            .color(egui::Color32::from_rgb(230, 160, 40)),
+    )
+    .id_salt("ai_review_malformed_rows")
+    .show(ui, |ui| {
+        ui.small("The AI returned these rows with the wrong number of columns. They were not applied.");
+        for note in notes {
+            ui.label(note);
+        }
+    });
+}
+
+/// One-line tally of the review so far, plus the "only show changed" filter
+fn draw_review_summary_bar(ui: &mut egui::Ui, state: &mut EditorWindowState) {
+    let summary = summarize_state(state);
+    ui.horizontal_wrapped(|ui| {
+        ui.label(format!("Changed: {}", summary.changed));
+        ui.label(
+            egui::RichText::new(format!("Unchanged: {}", summary.unchanged)).weak(),
+        );
+        ui.separator();
+        ui.label(
+            egui::RichText::new(format!("Accepted: {}", summary.accepted))
+                .color(egui::Color32::from_rgb(90, 180, 90)),
+        );
+        ui.label(
+            egui::RichText::new(format!("Rejected: {}", summary.rejected))
+                .color(egui::Color32::from_rgb(200, 90, 90)),
+        );
+        ui.label(format!("Undecided: {}", summary.undecided));
+        if summary.new_rows_pending > 0 || summary.merges_pending > 0 {
+            ui.separator();
+            ui.label(format!("New rows: {}", summary.new_rows_pending));
+            if summary.merges_pending > 0 {
+                ui.label(
+                    egui::RichText::new(format!("Merge decisions pending: {}", summary.merges_pending))
+                        .color(egui::Color32::from_rgb(230, 160, 40)),
+                );
+            }
+        }
+        let structures_total =
+            summary.structures_accepted + summary.structures_rejected + summary.structures_undecided;
+        if structures_total > 0 {
+            ui.separator();
+            ui.label(format!(
+                "Structures: {} accepted, {} rejected, {} undecided",
+                summary.structures_accepted, summary.structures_rejected, summary.structures_undecided
+            ));
+        }
+        ui.separator();
+        ui.checkbox(&mut state.ai_review_only_changed, "Only show changed")
+            .on_hover_text("Hide existing rows the AI returned without changes");
+        if state.ai_review_only_changed && summary.unchanged > 0 {
+            ui.label(egui::RichText::new(format!("({} hidden)", summary.unchanged)).small().weak());
+        }
+    });
+}
@@ -22,6 +22,7 @@ pub fn decorate_context_with_type(
         ColumnDataType::I64 => Some("Integer"),
         ColumnDataType::F64 => Some("Float"),
         ColumnDataType::Link => Some("Link/URL"),
+        ColumnDataType::Date => Some("Date (YYYY-MM-DD)"),
     };
 
     if let Some(label) = type_label {
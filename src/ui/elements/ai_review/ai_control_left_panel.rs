@@ -4,12 +4,81 @@ use bevy::prelude::*;
 use bevy_egui::egui;
 
 use super::ai_panel::send_selected_rows;
-use crate::sheets::systems::ai::processor::{DirectorSession, start_director_session_v2};
+use crate::sheets::systems::ai::processor::{
+    estimate_director_tokens, preview_director_payload, start_director_session_v2,
+    DirectorSession,
+};
 use crate::{
     sheets::resources::SheetRegistry,
-    ui::elements::editor::state::{AiModeState, EditorWindowState},
+    ui::elements::editor::state::{
+        AiModeState, AiTokenEstimateKey, EditorWindowState, SheetInteractionState,
+    },
     SessionApiKey,
 };
+
+const TOKEN_WARNING_COLOR: egui::Color32 = egui::Color32::from_rgb(220, 160, 40);
+
+/// What the token estimate depends on; None when there is nothing to estimate
+fn token_estimate_key(state: &EditorWindowState, registry: &SheetRegistry) -> Option<AiTokenEstimateKey> {
+    if state.ai_selected_rows.is_empty() {
+        return None;
+    }
+    let sheet_name = state.selected_sheet_name.clone()?;
+    let sheet = registry.get_sheet(&state.selected_category, &sheet_name)?;
+    let mut rows: Vec<usize> = state.ai_selected_rows.iter().copied().collect();
+    rows.sort_unstable();
+    Some(AiTokenEstimateKey {
+        category: state.selected_category.clone(),
+        sheet_name,
+        rows,
+        row_count: sheet.grid.len(),
+        schema_group: sheet
+            .metadata
+            .as_ref()
+            .and_then(|m| m.ai_active_schema_group.clone()),
+        width_limit: state.ai_width_limit,
+        char_budget: state.ai_char_budget,
+    })
+}
+
+/// "≈N tokens" for the current selection. Preparing the payloads is not free, so the
+/// estimate is only recomputed when the selection, schema group or batching settings change.
+fn draw_token_estimate(ui: &mut egui::Ui, state: &mut EditorWindowState, registry: &SheetRegistry) {
+    let Some(key) = token_estimate_key(state, registry) else {
+        state.ai_token_estimate = None;
+        return;
+    };
+    if state.ai_token_estimate.as_ref().map(|(k, _)| k) != Some(&key) {
+        let estimate = estimate_director_tokens(state, registry);
+        state.ai_token_estimate = Some((key, estimate));
+    }
+    let Some((_, estimate)) = state.ai_token_estimate.as_ref() else {
+        return;
+    };
+    match estimate {
+        Ok(estimate) => {
+            let mut text = egui::RichText::new(format!("≈{} tokens", estimate.total_tokens));
+            let over = estimate.largest_request_tokens > state.ai_token_warning_threshold;
+            if over {
+                text = text.color(TOKEN_WARNING_COLOR);
+            }
+            let mut hover = format!(
+                "Estimated from the prepared payloads (about 4 characters per token).\n{} request(s), largest ≈{} tokens.\nStructure child requests are not included.",
+                estimate.request_parts, estimate.largest_request_tokens
+            );
+            if over {
+                hover.push_str(&format!(
+                    "\n⚠ A request exceeds the warning threshold of {} tokens (Settings); select fewer rows or lower the batch limits.",
+                    state.ai_token_warning_threshold
+                ));
+            }
+            ui.label(text).on_hover_text(hover);
+        }
+        Err(e) => {
+            ui.weak("≈? tokens").on_hover_text(format!("Token estimate unavailable: {}", e));
+        }
+    }
+}
 use bevy_tokio_tasks::TokioTasksRuntime;
 
 // Extended variant used internally when runtime/commands available for sending
@@ -92,6 +161,36 @@ pub(crate) fn draw_left_panel_impl(
         }
     }
 
+    // Same selection set as Delete mode; the grid applies it to the rows its filters show
+    if ui
+        .add_enabled(
+            state.ai_mode == AiModeState::Preparing && selected_sheet.is_some(),
+            egui::Button::new("☑ Select All Filtered"),
+        )
+        .on_hover_text("Select every row shown under the current column filters (Ctrl+A)")
+        .clicked()
+    {
+        state.pending_select_all_filtered = true;
+    }
+
+    // Dry run: build the first request's payload without calling the model
+    if ui
+        .add_enabled(
+            selection_allowed && selected_sheet.is_some() && !state.ai_selected_rows.is_empty(),
+            egui::Button::new("🔍 Preview payload"),
+        )
+        .on_hover_text("Show the exact payload of the first request without sending it")
+        .clicked()
+    {
+        match preview_director_payload(state, registry) {
+            Ok(preview) => state.ai_payload_preview = Some(preview),
+            Err(e) => state.add_ai_call_log(format!("Payload preview failed: {}", e), None, None, true),
+        }
+    }
+    if selection_allowed && selected_sheet.is_some() {
+        draw_token_estimate(ui, state, registry);
+    }
+
     let status_text = match state.ai_mode {
         AiModeState::Preparing => format!("Preparing ({} Rows)", state.ai_selected_rows.len()),
         AiModeState::Submitting => "Submitting".to_string(),
@@ -114,3 +213,55 @@ pub(crate) fn draw_left_panel_impl(
         state.show_ai_rule_popup = true;
     }
 }
+
+/// Handles a pending "Send this row to AI" request from the cell context menu.
+/// Enters AI mode with only that row selected and starts a Director session for it,
+/// so structure children follow the active schema group just like a regular send.
+pub(crate) fn process_single_row_ai_send(
+    state: &mut EditorWindowState,
+    registry: &SheetRegistry,
+    session_api_key: &SessionApiKey,
+    runtime: &TokioTasksRuntime,
+    commands: &mut bevy::prelude::Commands,
+    director_session: &mut DirectorSession,
+) {
+    let Some(row_index) = state.pending_single_row_ai_send.take() else {
+        return;
+    };
+    if !state.ai_available() {
+        warn!("Ignoring 'Send this row to AI' for row {}: AI features are disabled", row_index);
+        return;
+    }
+    let busy = matches!(state.ai_mode, AiModeState::Submitting | AiModeState::Reviewing)
+        || state.ai_batch_review_active
+        || director_session.step_in_progress;
+    if busy {
+        warn!(
+            "Ignoring 'Send this row to AI' for row {}: an AI session is already in progress",
+            row_index
+        );
+        return;
+    }
+    if session_api_key.0.is_none() {
+        state.ai_raw_output_display = "API Key not set".to_string();
+        return;
+    }
+
+    // Same exclusivity rules as the 'AI Mode' toolbar button
+    state.show_edit_mode_panel = false;
+    state.show_toybox_menu = false;
+    state.current_interaction_mode = SheetInteractionState::AiModeActive;
+    state.ai_mode = AiModeState::Preparing;
+    state.ai_selected_rows.clear();
+    state.ai_selected_rows.insert(row_index);
+
+    start_director_session_v2(
+        state,
+        registry,
+        session_api_key,
+        runtime,
+        commands,
+        director_session,
+        None,
+    );
+}
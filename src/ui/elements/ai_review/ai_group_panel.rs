@@ -4,9 +4,11 @@ use bevy_egui::egui;
 
 use crate::sheets::definitions::SheetMetadata;
 use crate::sheets::events::{
-    RequestCreateAiSchemaGroup, RequestDeleteAiSchemaGroup, RequestRenameAiSchemaGroup,
-    RequestSelectAiSchemaGroup,
+    ReorderAiSchemaGroupEvent, RequestCreateAiSchemaGroup, RequestDeleteAiSchemaGroup,
+    RequestRenameAiSchemaGroup, RequestSelectAiSchemaGroup, RequestSetAiSchemaGroupAllowRows,
+    RequestSetAiStructureSkip,
 };
+use crate::sheets::systems::ai::processor::director::structure_path_skipped;
 use crate::ui::elements::editor::state::EditorWindowState;
 
 #[allow(clippy::too_many_arguments)]
@@ -18,8 +20,11 @@ pub(crate) fn draw_group_panel(
     root_meta: Option<&SheetMetadata>,
     create_group_writer: &mut EventWriter<RequestCreateAiSchemaGroup>,
     rename_group_writer: &mut EventWriter<RequestRenameAiSchemaGroup>,
+    reorder_group_writer: &mut EventWriter<ReorderAiSchemaGroupEvent>,
     select_group_writer: &mut EventWriter<RequestSelectAiSchemaGroup>,
     delete_group_writer: &mut EventWriter<RequestDeleteAiSchemaGroup>,
+    structure_skip_writer: &mut EventWriter<RequestSetAiStructureSkip>,
+    allow_rows_writer: &mut EventWriter<RequestSetAiSchemaGroupAllowRows>,
 ) {
     let Some(meta) = root_meta else {
         return;
@@ -46,6 +51,30 @@ pub(crate) fn draw_group_panel(
             state.ai_groups_expanded = !expanded;
         }
 
+        // The active group's "allow add rows" replaces the sheet-level Add Rows setting
+        if let Some(active) = active_group_name
+            .as_ref()
+            .and_then(|name| groups.iter().find(|g| &g.name == name))
+        {
+            let mut allow = active.allow_add_rows;
+            let response = group_ui
+                .add_enabled(!sheet_for_event.is_empty(), egui::Checkbox::new(&mut allow, format!("Add Rows ({})", active.name)))
+                .on_hover_text(format!(
+                    "Allow AI to append new rows while group '{}' is active.\n\
+                     Each group has its own setting; it overrides the sheet's Add Rows \
+                     setting and is applied whenever the group is selected.",
+                    active.name
+                ));
+            if response.changed() {
+                allow_rows_writer.write(RequestSetAiSchemaGroupAllowRows {
+                    category: category_for_event.clone(),
+                    sheet_name: sheet_for_event.clone(),
+                    group_name: active.name.clone(),
+                    allow,
+                });
+            }
+        }
+
         if state.ai_groups_expanded {
             group_ui.add_space(6.0);
             // Active group rename/delete icons placed before list for quick access
@@ -72,13 +101,49 @@ pub(crate) fn draw_group_panel(
                 }
                 group_ui.add_space(8.0);
             }
-            // Group selection list
+            // Group selection list; each group has a drag handle and is a drop target
+            let can_reorder = groups.len() > 1 && !sheet_for_event.is_empty();
             for (idx, group) in groups.iter().enumerate() {
                 let is_active = active_group
                     .as_deref()
                     .map(|name| name == group.name.as_str())
                     .unwrap_or(false);
+                let handle_response = if can_reorder {
+                    Some(
+                        group_ui
+                            .dnd_drag_source(egui::Id::new(("ai_group_drag", idx)), idx, |ui| {
+                                ui.weak("⠿");
+                            })
+                            .response
+                            .on_hover_text("Drag to reorder groups"),
+                    )
+                } else {
+                    None
+                };
                 let response = group_ui.selectable_label(is_active, &group.name);
+                let drop_target = match &handle_response {
+                    Some(handle) => response.union(handle.clone()),
+                    None => response.clone(),
+                };
+                if drop_target.dnd_hover_payload::<usize>().is_some() {
+                    let rect = drop_target.rect;
+                    group_ui.painter().vline(
+                        rect.left() - 2.0,
+                        rect.y_range(),
+                        group_ui.visuals().selection.stroke,
+                    );
+                }
+                if let Some(from_index) = drop_target.dnd_release_payload::<usize>() {
+                    if *from_index != idx {
+                        reorder_group_writer.write(ReorderAiSchemaGroupEvent {
+                            category: category_for_event.clone(),
+                            sheet_name: sheet_for_event.clone(),
+                            from_index: *from_index,
+                            to_index: idx,
+                        });
+                        group_ui.ctx().request_repaint();
+                    }
+                }
                 if response.clicked() && !is_active && !sheet_for_event.is_empty() {
                     select_group_writer.write(RequestSelectAiSchemaGroup {
                         category: category_for_event.clone(),
@@ -104,6 +169,39 @@ pub(crate) fn draw_group_panel(
                 group_ui.ctx().request_repaint();
             }
 
+            // Per-structure switches of the active group; a skipped path skips its whole
+            // subtree when the Director queues child jobs
+            let structure_paths = meta.structure_table_paths();
+            if active_group.is_some() && !structure_paths.is_empty() {
+                let overrides = groups
+                    .iter()
+                    .find(|g| Some(&g.name) == active_group.as_ref())
+                    .map(|g| g.structure_row_generation_overrides.clone())
+                    .unwrap_or_default();
+                group_ui.menu_button("Structures", |menu_ui| {
+                    for path in &structure_paths {
+                        let label = meta
+                            .describe_structure_path(path)
+                            .unwrap_or_else(|| format!("{:?}", path));
+                        let ancestor_skipped =
+                            structure_path_skipped(&path[..path.len() - 1], &overrides);
+                        let mut included = !structure_path_skipped(path, &overrides);
+                        let response = menu_ui
+                            .add_enabled(!ancestor_skipped, egui::Checkbox::new(&mut included, label))
+                            .on_hover_text("Send and process this structure table");
+                        if response.changed() && !sheet_for_event.is_empty() {
+                            structure_skip_writer.write(RequestSetAiStructureSkip {
+                                category: category_for_event.clone(),
+                                sheet_name: sheet_for_event.clone(),
+                                structure_path: path.clone(),
+                                skip: !included,
+                            });
+                            state.mark_ai_included_columns_dirty();
+                        }
+                    }
+                });
+            }
+
             // (Removed text Ren/Del buttons – replaced with icons above the list)
         }
     });
@@ -7,8 +7,9 @@ use crate::{
     sheets::{
         definitions::default_ai_model_id,
         events::{
-            RequestCreateAiSchemaGroup, RequestDeleteAiSchemaGroup, RequestRenameAiSchemaGroup,
-            RequestSelectAiSchemaGroup, RequestToggleAiRowGeneration,
+            ReorderAiSchemaGroupEvent, RequestCreateAiSchemaGroup, RequestDeleteAiSchemaGroup,
+            RequestRenameAiSchemaGroup, RequestSelectAiSchemaGroup, RequestSetAiSchemaGroupAllowRows,
+            RequestSetAiStructureSkip, RequestToggleAiRowGeneration,
         },
         resources::SheetRegistry,
     },
@@ -97,9 +98,11 @@ pub fn send_selected_rows(
 
     // Grounding flag: use current metadata directly (virtual structures deprecated)
     let grounding = meta.requested_grounding_with_google_search.unwrap_or(false);
+    // Unset temperature stays out of the payload so the model default applies
+    let temperature = meta.ai_temperature;
 
-    // Row additions flag (use current metadata directly - virtual structures deprecated)
-    let allow_additions_flag = meta.ai_enable_row_generation;
+    // Row additions flag: the active schema group's setting overrides the sheet's
+    let allow_additions_flag = meta.effective_ai_allow_add_rows();
 
     // Build human-readable ancestor prefixes using programmatic lineage walking.
     // Virtual structure context deprecated; use legacy structure navigation if present.
@@ -143,6 +146,7 @@ pub fn send_selected_rows(
             column_contexts: column_contexts.clone(),
             rows_data: rows_data.clone(),
             requested_grounding_with_google_search: grounding,
+            ai_temperature: temperature,
             allow_row_additions: allow_additions_flag,
             // Do not include key_prefix_* metadata in payload
             key_prefix_count: None,
@@ -158,6 +162,7 @@ pub fn send_selected_rows(
             column_contexts: column_contexts.clone(),
             rows_data: rows_data.clone(),
             requested_grounding_with_google_search: grounding,
+            ai_temperature: temperature,
             allow_row_additions: allow_additions_flag,
             // Do not include key_prefix_* metadata in payload
             key_prefix_count: None,
@@ -239,8 +244,11 @@ pub fn draw_ai_panel(
     toggle_writer: &mut EventWriter<RequestToggleAiRowGeneration>,
     create_group_writer: &mut EventWriter<RequestCreateAiSchemaGroup>,
     rename_group_writer: &mut EventWriter<RequestRenameAiSchemaGroup>,
+    reorder_group_writer: &mut EventWriter<ReorderAiSchemaGroupEvent>,
     select_group_writer: &mut EventWriter<RequestSelectAiSchemaGroup>,
     delete_group_writer: &mut EventWriter<RequestDeleteAiSchemaGroup>,
+    group_allow_rows_writer: &mut EventWriter<RequestSetAiSchemaGroupAllowRows>,
+    structure_skip_writer: &mut EventWriter<RequestSetAiStructureSkip>,
     director_session: &mut crate::sheets::systems::ai::processor::DirectorSession,
 ) {
     // NEW: Show navigation breadcrumb with back button when in child table drill-down
@@ -311,15 +319,16 @@ pub fn draw_ai_panel(
                 .get_sheet(selected_category, &sheet_name)
                 .and_then(|s| s.metadata.as_ref())
             {
-                // Use current sheet's enable_row_generation directly
-                let allow_flag = meta.ai_enable_row_generation;
-                let mut toggle_val = allow_flag;
+                // Sheet-level flag. Once a schema group is active its own "Add Rows" (in
+                // the group row) overrides this one, so the checkbox is shown there instead.
+                let mut toggle_val = meta.ai_enable_row_generation;
                 let tooltip = "Allow AI to append new rows to this sheet";
-                let resp = ui
-                    .add_enabled(true, egui::Checkbox::new(&mut toggle_val, "Add Rows"))
-                    .on_hover_text(tooltip);
-
-                if resp.changed() {
+                if meta.ai_active_schema_group.is_none()
+                    && ui
+                        .checkbox(&mut toggle_val, "Add Rows")
+                        .on_hover_text(tooltip)
+                        .changed()
+                {
                     // Update directly for current sheet (virtual structures deprecated)
                     toggle_writer.write(RequestToggleAiRowGeneration {
                         category: selected_category.clone(),
@@ -345,8 +354,11 @@ pub fn draw_ai_panel(
                 meta_opt,
                 create_group_writer,
                 rename_group_writer,
+                reorder_group_writer,
                 select_group_writer,
                 delete_group_writer,
+                structure_skip_writer,
+                group_allow_rows_writer,
             );
         }
 
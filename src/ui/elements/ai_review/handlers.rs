@@ -1,6 +1,7 @@
 // Handlers for accepting/cancelling AI row suggestions (moved out of monolithic file)
 use crate::sheets::events::{AddSheetRowRequest, UpdateCellEvent};
 use crate::sheets::resources::SheetRegistry;
+use crate::sheets::systems::ai::row_helpers::is_ai_read_only_column;
 use crate::ui::elements::editor::state::{
     EditorWindowState, NewRowReview, ReviewChoice, StructureReviewEntry,
 };
@@ -146,6 +147,7 @@ pub fn process_existing_accept(
     for idx in sorted {
         if idx < state.ai_row_reviews.len() {
             let rr = state.ai_row_reviews.remove(idx);
+            state.ai_review_tally.accepted += 1;
 
             // Find grid index for this DB row index
             // AI reviews store DB row_index (stable index), but UpdateCellEvent expects grid index
@@ -155,7 +157,17 @@ pub fn process_existing_accept(
                 None
             };
 
-            if let Some(grid_idx) = grid_row_index {
+            // Locked rows keep their values; their suggestions are dropped with the review
+            if let Some(grid_idx) = grid_row_index.filter(|&grid_idx| {
+                registry.is_grid_row_locked(selected_category, active_sheet_name, grid_idx)
+            }) {
+                info!(
+                    "Skipping AI suggestions for locked row {} in sheet {}",
+                    grid_idx + 1,
+                    active_sheet_name
+                );
+                take_structure_entries_for_existing(state, rr.row_index);
+            } else if let Some(grid_idx) = grid_row_index {
                 for (pos, actual_col) in rr.non_structure_columns.iter().enumerate() {
                     let choice = rr
                         .choices
@@ -166,6 +178,10 @@ pub fn process_existing_accept(
                     if *actual_col == 1 {
                         continue;
                     }
+                    // AI read-only columns are context only
+                    if is_ai_read_only_column(registry, selected_category, active_sheet_name, *actual_col) {
+                        continue;
+                    }
                     if matches!(choice, ReviewChoice::AI) {
                         if let Some(ai_val) = rr.ai.get(pos).cloned() {
                             cell_update_writer.write(UpdateCellEvent {
@@ -226,6 +242,7 @@ pub fn process_existing_decline(indices: &[usize], state: &mut EditorWindowState
     for idx in sorted {
         if idx < state.ai_row_reviews.len() {
             let rr = state.ai_row_reviews.remove(idx);
+            state.ai_review_tally.rejected += 1;
             take_structure_entries_for_existing(state, rr.row_index);
             state.ai_selected_rows.remove(&rr.row_index);
         }
@@ -255,6 +272,7 @@ pub fn process_new_accept(
     for idx in sorted {
         if idx < state.ai_new_row_reviews.len() {
             let nr = state.ai_new_row_reviews.remove(idx);
+            state.ai_review_tally.accepted += 1;
 
             // Extract structure entries for this new row
             let structure_entries = take_structure_entries_for_new(state, idx);
@@ -274,6 +292,9 @@ pub fn process_new_accept(
                                     if *actual_col == 1 {
                                         continue;
                                     }
+                                    if is_ai_read_only_column(registry, selected_category, active_sheet_name, *actual_col) {
+                                        continue;
+                                    }
                                     if matches!(choice, ReviewChoice::AI) {
                                         if let Some(val) = nr.ai.get(pos).cloned() {
                                             cell_update_writer.write(UpdateCellEvent {
@@ -447,6 +468,7 @@ pub fn process_new_decline(indices: &[usize], state: &mut EditorWindowState) {
             take_structure_entries_for_new(state, idx);
             // Remove the review entry
             state.ai_new_row_reviews.remove(idx);
+            state.ai_review_tally.rejected += 1;
             // CRITICAL: Update all structure entries with higher parent_new_row_index
             // to account for the removed index shift
             adjust_parent_indices_after_removal(state, idx);
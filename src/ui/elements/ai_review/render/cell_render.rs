@@ -4,6 +4,21 @@ use crate::ui::validation::normalize_for_link_cmp;
 use bevy_egui::egui::{self, Color32, RichText};
 use std::collections::HashSet;
 
+/// Text color of a value that differs from the original (AI review, sheet comparison)
+pub const DIFF_CHANGED_COLOR: Color32 = Color32::LIGHT_YELLOW;
+/// Values only present on the new side of a comparison
+pub const DIFF_ADDED_COLOR: Color32 = Color32::from_rgb(90, 180, 90);
+/// Values only present on the old side of a comparison
+pub const DIFF_REMOVED_COLOR: Color32 = Color32::from_rgb(200, 90, 90);
+
+/// Read-only changed value: the original struck through, then the new value highlighted
+pub fn render_diff_change(ui: &mut egui::Ui, before: &str, after: &str) {
+    ui.horizontal(|ui| {
+        ui.label(RichText::new(before).strikethrough().weak());
+        ui.label(RichText::new(after).color(DIFF_CHANGED_COLOR));
+    });
+}
+
 pub fn render_review_original_cell(
     ui: &mut egui::Ui,
     original_value: &str,
@@ -38,7 +53,7 @@ pub fn render_review_ai_cell(
             egui::TextEdit::singleline(ai_cell)
                 .desired_width(min_width)
                 .text_color_opt(if is_diff {
-                    Some(Color32::LIGHT_YELLOW)
+                    Some(DIFF_CHANGED_COLOR)
                 } else {
                     None
                 }),
@@ -241,7 +256,7 @@ pub fn render_review_ai_cell_linked(
 
     // Track whether text edit or popup selection changed the value
     let text_color = if is_diff {
-        Some(Color32::LIGHT_YELLOW)
+        Some(DIFF_CHANGED_COLOR)
     } else {
         None
     };
@@ -18,7 +18,7 @@ use bevy::prelude::*;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
-/// Populate linked column options for the current view
+/// Populate linked column options (and Enum allowed values) for the current view
 pub fn populate_linked_column_options(
     state: &mut EditorWindowState,
     registry: &SheetRegistry,
@@ -33,21 +33,28 @@ pub fn populate_linked_column_options(
         for col_entry in &display_ctx.merged_columns {
             if let ColumnEntry::Regular(actual_col) = col_entry {
                 if let Some(field_def) = display_ctx.structure_schema.get(*actual_col) {
-                    if let Some(ColumnValidator::Linked {
-                        target_sheet_name,
-                        target_column_index,
-                    }) = &field_def.validator
-                    {
-                        if let CacheResult::Success { raw, .. } =
-                            linked_column_cache::get_or_populate_linked_options(
-                                &target_sheet_name,
-                                *target_column_index,
-                                registry,
-                                state,
-                            )
-                        {
-                            linked_column_options.insert(*actual_col, raw);
+                    match &field_def.validator {
+                        Some(ColumnValidator::Linked {
+                            target_sheet_name,
+                            target_column_index,
+                        }) => {
+                            if let CacheResult::Success { raw, .. } =
+                                linked_column_cache::get_or_populate_linked_options(
+                                    &target_sheet_name,
+                                    *target_column_index,
+                                    registry,
+                                    state,
+                                )
+                            {
+                                linked_column_options.insert(*actual_col, raw);
+                            }
                         }
+                        // Enum columns get the same dropdown and invalid-value highlight
+                        Some(ColumnValidator::Enum(values)) => {
+                            linked_column_options
+                                .insert(*actual_col, Arc::new(values.iter().cloned().collect()));
+                        }
+                        _ => {}
                     }
                 }
             }
@@ -57,21 +64,28 @@ pub fn populate_linked_column_options(
         for col_entry in &display_ctx.merged_columns {
             if let ColumnEntry::Regular(actual_col) = col_entry {
                 if let Some(col_def) = meta.columns.get(*actual_col) {
-                    if let Some(ColumnValidator::Linked {
-                        target_sheet_name,
-                        target_column_index,
-                    }) = &col_def.validator
-                    {
-                        if let CacheResult::Success { raw, .. } =
-                            linked_column_cache::get_or_populate_linked_options(
-                                &target_sheet_name,
-                                *target_column_index,
-                                registry,
-                                state,
-                            )
-                        {
-                            linked_column_options.insert(*actual_col, raw);
+                    match &col_def.validator {
+                        Some(ColumnValidator::Linked {
+                            target_sheet_name,
+                            target_column_index,
+                        }) => {
+                            if let CacheResult::Success { raw, .. } =
+                                linked_column_cache::get_or_populate_linked_options(
+                                    &target_sheet_name,
+                                    *target_column_index,
+                                    registry,
+                                    state,
+                                )
+                            {
+                                linked_column_options.insert(*actual_col, raw);
+                            }
+                        }
+                        // Enum columns get the same dropdown and invalid-value highlight
+                        Some(ColumnValidator::Enum(values)) => {
+                            linked_column_options
+                                .insert(*actual_col, Arc::new(values.iter().cloned().collect()));
                         }
+                        _ => {}
                     }
                 }
             }
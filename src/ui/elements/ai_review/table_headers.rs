@@ -52,6 +52,13 @@ pub(crate) fn render_table_headers(
                         .color(egui::Color32::from_rgb(0, 170, 0))
                         .strong(),
                 );
+            } else if is_read_only_entry(col_entry, display_ctx, sheet_metadata) {
+                ui.label(
+                    RichText::new(format!("{} 🔒", header_text))
+                        .color(egui::Color32::GRAY)
+                        .strong(),
+                )
+                .on_hover_text("AI read-only: suggestions for this column are not applied");
             } else {
                 ui.label(RichText::new(header_text).strong());
             }
@@ -82,7 +89,7 @@ fn get_header_text<'a>(
                 // In normal mode, use sheet metadata
                 sheet_metadata
                     .and_then(|meta| meta.columns.get(*col_idx))
-                    .map(|col| col.header.as_str())
+                    .map(|col| col.display_name())
                     .unwrap_or("?")
             }
         }
@@ -101,13 +108,27 @@ fn get_header_text<'a>(
                 // In normal mode, use sheet metadata
                 sheet_metadata
                     .and_then(|meta| meta.columns.get(*col_idx))
-                    .map(|col| col.header.as_str())
+                    .map(|col| col.display_name())
                     .unwrap_or("Structure")
             }
         }
     }
 }
 
+/// Whether a regular column of the active sheet is marked AI read-only
+fn is_read_only_entry(
+    col_entry: &ColumnEntry,
+    display_ctx: &ReviewDisplayContext,
+    sheet_metadata: Option<&SheetMetadata>,
+) -> bool {
+    match col_entry {
+        ColumnEntry::Regular(col_idx) if !display_ctx.in_structure_mode => sheet_metadata
+            .and_then(|meta| meta.columns.get(*col_idx))
+            .map_or(false, |col| col.ai_read_only),
+        _ => false,
+    }
+}
+
 /// Draws the horizontal line under header cells
 fn draw_header_underline(ui: &mut egui::Ui) {
     let rect = ui.max_rect();
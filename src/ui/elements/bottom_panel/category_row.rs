@@ -1,5 +1,6 @@
 // src/ui/elements/bottom_panel/category_row.rs
 use bevy_egui::egui;
+use crate::sheets::events::RequestSetCategoryColor;
 use crate::sheets::resources::SheetRegistry;
 use crate::sheets::systems::ui_handlers::{category_handlers, sheet_handlers};
 use crate::ui::elements::editor::state::EditorWindowState;
@@ -129,8 +130,21 @@ fn render_category_tabs(
                         let is_sel = state.selected_category.as_deref() == Some(cat.as_str());
                         let disp: String = cat.chars().take(32).collect();
                         let resp = ui_th.selectable_label(is_sel, disp).on_hover_text(cat);
+                        super::color_tags::paint_color_tint(ui_th, resp.rect, registry.category_color(cat));
                         // Right-click context menu on category tab
                         resp.context_menu(|menu_ui| {
+                            if let Some(color) = super::color_tags::color_tag_menu(
+                                menu_ui,
+                                ("category_color", cat),
+                                registry.category_color(cat),
+                            ) {
+                                event_writers.set_category_color.write(RequestSetCategoryColor {
+                                    name: cat.clone(),
+                                    color,
+                                });
+                                menu_ui.close_menu();
+                                return;
+                            }
                             if menu_ui.button("✏ Rename Category").clicked() {
                                 crate::sheets::systems::ui_handlers::category_handlers::handle_rename_category_request(state);
                                 menu_ui.close_menu();
@@ -0,0 +1,76 @@
+// src/ui/elements/bottom_panel/color_tags.rs
+use bevy_egui::egui;
+use std::hash::Hash;
+
+/// Swatches offered in the color menu before the free picker
+const PRESET_COLORS: [[u8; 3]; 8] = [
+    [220, 80, 80],
+    [230, 150, 60],
+    [220, 200, 70],
+    [100, 190, 90],
+    [70, 180, 190],
+    [80, 130, 220],
+    [150, 100, 210],
+    [210, 100, 170],
+];
+
+/// Tint a category/sheet button with its tag color. Unset colors keep the theme look.
+pub fn paint_color_tint(ui: &egui::Ui, rect: egui::Rect, color: Option<[u8; 3]>) {
+    let Some([r, g, b]) = color else {
+        return;
+    };
+    let painter = ui.painter();
+    painter.rect_filled(rect, 2.0, egui::Color32::from_rgba_unmultiplied(r, g, b, 45));
+    let bar = egui::Rect::from_min_max(egui::pos2(rect.min.x, rect.max.y - 2.0), rect.max);
+    painter.rect_filled(bar, 0.0, egui::Color32::from_rgb(r, g, b));
+}
+
+/// "Color" submenu for a context menu. Returns the new color once the user picks one
+/// (`Some(None)` when cleared); the caller persists it and closes the menu.
+pub fn color_tag_menu(
+    ui: &mut egui::Ui,
+    id_salt: impl Hash,
+    current: Option<[u8; 3]>,
+) -> Option<Option<[u8; 3]>> {
+    let mut chosen = None;
+    ui.menu_button("🎨 Color", |menu_ui| {
+        menu_ui.horizontal(|row| {
+            for [r, g, b] in PRESET_COLORS {
+                let swatch = egui::Button::new("")
+                    .fill(egui::Color32::from_rgb(r, g, b))
+                    .min_size(egui::vec2(16.0, 16.0));
+                if row.add(swatch).clicked() {
+                    chosen = Some(Some([r, g, b]));
+                }
+            }
+        });
+        menu_ui.separator();
+        // Custom color is edited in temp memory and only applied on request
+        let custom_id = menu_ui.id().with(id_salt).with("custom_color");
+        let [r, g, b] = current.unwrap_or([128, 128, 128]);
+        let mut custom = menu_ui
+            .data_mut(|d| d.get_temp(custom_id))
+            .unwrap_or(egui::Color32::from_rgb(r, g, b));
+        if egui::color_picker::color_picker_color32(
+            menu_ui,
+            &mut custom,
+            egui::color_picker::Alpha::Opaque,
+        ) {
+            menu_ui.data_mut(|d| d.insert_temp(custom_id, custom));
+        }
+        menu_ui.horizontal(|row| {
+            if row.button("Apply custom").clicked() {
+                chosen = Some(Some([custom.r(), custom.g(), custom.b()]));
+                row.data_mut(|d| d.remove::<egui::Color32>(custom_id));
+            }
+            if row
+                .add_enabled(current.is_some(), egui::Button::new("Clear color"))
+                .on_hover_text("Use the theme color")
+                .clicked()
+            {
+                chosen = Some(None);
+            }
+        });
+    });
+    chosen
+}
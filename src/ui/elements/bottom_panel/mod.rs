@@ -6,9 +6,11 @@
 
 // Internal modules
 mod category_row;
+mod color_tags;
 mod dropdowns;
 mod drop_visuals;
 mod popups;
+mod recent_row;
 mod sheet_row;
 
 // Public module
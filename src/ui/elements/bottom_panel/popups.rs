@@ -157,7 +157,10 @@ pub fn render_sheet_popup(
     popup_ui.selectable_value(&mut state.selected_sheet_name, None, "--Select--");
     
     for name in sheets_in_category.iter().filter(|n| ui_cache::matches_filter(n, &filter_text)) {
-        let truncated: String = name.chars().take(32).collect();
+        let mut truncated: String = name.chars().take(32).collect();
+        if state.is_sheet_pinned(&state.selected_category, name) {
+            truncated.insert_str(0, "📌 ");
+        }
         if popup_ui
             .selectable_label(
                 state.selected_sheet_name.as_deref() == Some(name.as_str()),
@@ -191,6 +194,12 @@ pub fn handle_sheet_context_menu(
                     meta.hidden = hidden;
                     to_save = Some(meta.clone());
                 }
+                if let Some(color) =
+                    super::color_tags::color_tag_menu(ctx_menu, ("sheet_color", sheet_name), meta.color)
+                {
+                    meta.color = color;
+                    to_save = Some(meta.clone());
+                }
             } else {
                 ctx_menu.label("No metadata available");
             }
@@ -0,0 +1,46 @@
+// src/ui/elements/bottom_panel/recent_row.rs
+use bevy_egui::egui;
+use crate::sheets::resources::SheetRegistry;
+use crate::sheets::systems::ui_handlers::{category_handlers, sheet_handlers};
+use crate::ui::elements::editor::state::EditorWindowState;
+
+/// Render the recently opened sheets as quick-select chips (hidden while the list is empty)
+pub fn show_recent_sheets(
+    ui: &mut egui::Ui,
+    state: &mut EditorWindowState,
+    registry: &SheetRegistry,
+) {
+    if state.recent_sheets.is_empty() {
+        return;
+    }
+    let mut chosen: Option<(Option<String>, String)> = None;
+    egui::ScrollArea::horizontal()
+        .id_salt("recent_sheets_chips")
+        .auto_shrink([false, true])
+        .show(ui, |scroll_ui| {
+            scroll_ui.horizontal(|row| {
+                row.label(egui::RichText::new("Recent:").small().weak());
+                for (category, name) in &state.recent_sheets {
+                    let is_sel = state.selected_category == *category
+                        && state.selected_sheet_name.as_deref() == Some(name.as_str());
+                    let disp: String = name.chars().take(24).collect();
+                    let hover = match category {
+                        Some(cat) => format!("{} (database '{}')", name, cat),
+                        None => name.clone(),
+                    };
+                    let resp = row
+                        .selectable_label(is_sel, egui::RichText::new(disp).small())
+                        .on_hover_text(hover);
+                    if resp.clicked() && !is_sel {
+                        chosen = Some((category.clone(), name.clone()));
+                    }
+                }
+            });
+        });
+
+    if let Some((category, name)) = chosen {
+        category_handlers::handle_category_selection(state, category, registry);
+        sheet_handlers::handle_sheet_selection(state, Some(name));
+        state.pending_sheet_revalidation = true;
+    }
+}
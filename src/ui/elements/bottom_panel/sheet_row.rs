@@ -13,8 +13,10 @@ pub fn show_sheet_controls<'a, 'w>(
     _event_writers: &mut super::SheetManagementEventWriters<'a, 'w>,
     daemon_client: &DaemonClient,
 ) {
-    let sheets_in_category = registry
-        .get_sheet_names_in_category_filtered(&state.selected_category, state.show_hidden_sheets);
+    let sheets_in_category = state.order_pinned_first(
+        &state.selected_category,
+        registry.get_sheet_names_in_category_filtered(&state.selected_category, state.show_hidden_sheets),
+    );
 
     let line_h = ui.text_style_height(&egui::TextStyle::Body) + ui.style().spacing.item_spacing.y;
     let row_size = egui::Vec2::new(ui.available_width(), line_h + 6.0);
@@ -96,7 +98,14 @@ fn render_sheet_tab(
     daemon_client: &DaemonClient,
 ) {
     let is_sel = state.selected_sheet_name.as_deref() == Some(name);
-    let resp = ui_th.selectable_label(is_sel, name).on_hover_text(name);
+    let pinned = state.is_sheet_pinned(&state.selected_category, name);
+    let label = if pinned { format!("📌 {}", name) } else { name.to_string() };
+    let resp = ui_th.selectable_label(is_sel, label).on_hover_text(name);
+    let color = registry
+        .get_sheet(&state.selected_category, name)
+        .and_then(|sheet| sheet.metadata.as_ref())
+        .and_then(|meta| meta.color);
+    super::color_tags::paint_color_tint(ui_th, resp.rect, color);
     // Right-click context menu on sheet tab: rename/delete and hidden toggle
     resp.context_menu(|menu_ui| {
         // Rename Sheet
@@ -105,12 +114,72 @@ fn render_sheet_tab(
             menu_ui.close_menu();
             return;
         }
+        // Duplicate Sheet (grid, metadata and structure tables)
+        if menu_ui.button("⧉ Duplicate Sheet…").clicked() {
+            state.duplicate_sheet_name_input =
+                crate::sheets::systems::logic::duplicate_sheet::suggest_duplicate_name(name, |candidate| {
+                    registry.get_sheet(&state.selected_category, candidate).is_some()
+                });
+            state.duplicate_sheet_target = Some((state.selected_category.clone(), name.to_string()));
+            menu_ui.close_menu();
+            return;
+        }
         // Delete Sheet
         if menu_ui.button("🗑 Delete Sheet").clicked() {
             crate::sheets::systems::ui_handlers::sheet_handlers::handle_delete_sheet_request(state);
             menu_ui.close_menu();
             return;
         }
+        // Reload from Database (database-backed sheets only)
+        let tab_is_db_sheet = registry
+            .get_sheet(&state.selected_category, name)
+            .and_then(|sheet| sheet.metadata.as_ref())
+            .map_or(false, |meta| meta.category.is_some());
+        if menu_ui
+            .add_enabled(tab_is_db_sheet, egui::Button::new("⟳ Reload from Database"))
+            .on_hover_text("Discard in-memory changes and re-read this sheet and its structure tables from the database")
+            .clicked()
+        {
+            let category = state.selected_category.clone();
+            crate::sheets::systems::ui_handlers::sheet_handlers::handle_reload_sheet_request(state, &category, name);
+            menu_ui.close_menu();
+            return;
+        }
+        // Revert to the last JSON export (database-backed sheets only)
+        if menu_ui
+            .add_enabled(state.selected_category.is_some(), egui::Button::new("↶ Revert to Saved JSON…"))
+            .on_hover_text("Replace this sheet's rows with those of its last JSON export")
+            .clicked()
+        {
+            state.revert_json_confirm_target = Some((state.selected_category.clone(), name.to_string()));
+            menu_ui.close_menu();
+            return;
+        }
+        if menu_ui
+            .button("🔤 Rename Headers…")
+            .on_hover_text("Find/replace or add a prefix/suffix across this sheet's column display names")
+            .clicked()
+        {
+            state.header_rename_find.clear();
+            state.header_rename_replace.clear();
+            state.header_rename_prefix.clear();
+            state.header_rename_suffix.clear();
+            state.header_rename_excluded.clear();
+            state.header_rename_target = Some((state.selected_category.clone(), name.to_string()));
+            menu_ui.close_menu();
+            return;
+        }
+        let pin_label = if pinned { "📌 Unpin Sheet" } else { "📌 Pin Sheet" };
+        if menu_ui
+            .button(pin_label)
+            .on_hover_text("Pinned sheets are listed first in this database")
+            .clicked()
+        {
+            let category = state.selected_category.clone();
+            state.toggle_sheet_pin(&category, name);
+            menu_ui.close_menu();
+            return;
+        }
         menu_ui.separator();
         // Hidden toggle from existing context menu
         super::popups::handle_sheet_context_menu(menu_ui, state, registry, name, daemon_client);
@@ -0,0 +1,84 @@
+// src/ui/elements/editor/block_paste.rs
+// Ctrl+V of a multi-cell block (e.g. copied from Excel) while a grid cell is being edited
+
+use crate::sheets::{
+    events::RequestPasteBlock, resources::SheetRegistry,
+    systems::logic::clipboard::parse_tsv_block,
+};
+use crate::ui::elements::editor::state::EditorWindowState;
+use crate::ui::elements::editor::table_body::get_filtered_row_indices_cached;
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+/// Take a tab-separated paste aimed at the focused grid cell before the cell's text field
+/// sees it, and send it as a block paste over the visible rows and columns from that cell.
+///
+/// Must run before the table is drawn; single values are left to the text field.
+pub fn intercept_block_paste(
+    ctx: &egui::Context,
+    state: &mut EditorWindowState,
+    registry: &SheetRegistry,
+    paste_block: &mut EventWriter<RequestPasteBlock>,
+) {
+    let Some((category, sheet_name, row_index, col_index, focus_id)) =
+        state.grid_active_cell.clone()
+    else {
+        return;
+    };
+    if !ctx.memory(|mem| mem.has_focus(focus_id)) {
+        state.grid_active_cell = None;
+        return;
+    }
+    if state.selected_category != category
+        || state.selected_sheet_name.as_deref() != Some(sheet_name.as_str())
+    {
+        return;
+    }
+    let values = ctx.input_mut(|input| {
+        let pos = input.events.iter().position(|event| {
+            matches!(event, egui::Event::Paste(text) if parse_tsv_block(text).is_some())
+        })?;
+        match input.events.remove(pos) {
+            egui::Event::Paste(text) => parse_tsv_block(&text),
+            _ => None,
+        }
+    });
+    let Some(values) = values else {
+        return;
+    };
+    let Some(sheet) = registry.get_sheet(&category, &sheet_name) else {
+        return;
+    };
+    let Some(metadata) = sheet.metadata.as_ref() else {
+        return;
+    };
+
+    let visible_columns = state.get_visible_column_indices(&category, &sheet_name, metadata);
+    let filtered_rows =
+        get_filtered_row_indices_cached(state, &category, &sheet_name, &sheet.grid, metadata);
+    let (Some(col_pos), Some(row_pos)) = (
+        visible_columns.iter().position(|&c| c == col_index),
+        filtered_rows.iter().position(|&r| r == row_index),
+    ) else {
+        return;
+    };
+
+    // The cell's own edit buffer must not keep the half-applied paste
+    ctx.memory_mut(|mem| mem.surrender_focus(focus_id));
+    state.grid_active_cell = None;
+    info!(
+        "Block paste of {} row(s) at [{}, {}] in '{:?}/{}'",
+        values.len(),
+        row_index,
+        col_index,
+        category,
+        sheet_name
+    );
+    paste_block.write(RequestPasteBlock {
+        category,
+        sheet_name,
+        target_rows: filtered_rows[row_pos..].to_vec(),
+        target_cols: visible_columns[col_pos..].to_vec(),
+        values,
+    });
+}
@@ -46,15 +46,21 @@ pub(super) fn process_editor_events_and_state(
             debug!("editor_event_handling: Received SheetDataModifiedInRegistryEvent for current sheet '{:?}/{}'. Forcing filter recalc.", event.category, event.sheet_name);
             state.force_filter_recalculation = true;
             state.mark_ai_included_columns_dirty();
+            state.sheet_find_scope = None;
 
             if state.request_scroll_to_new_row {
                 if let Some(sheet_data) = registry.get_sheet(&event.category, &event.sheet_name) {
-                    if !sheet_data.grid.is_empty() {
+                    let new_row = state.new_row_grid_index;
+                    if new_row == 0 && !sheet_data.grid.is_empty() {
                         state.scroll_to_row_index = Some(0);
                         debug!(
                             "Scrolling to new row at top (index 0) for sheet '{:?}/{}'.",
                             event.category, event.sheet_name
                         );
+                    } else if new_row < sheet_data.grid.len() {
+                        // Resolved against the visible rows once the sheet is drawn
+                        state.pending_scroll_to_sheet_row =
+                            Some((event.category.clone(), event.sheet_name.clone(), new_row));
                     }
                 }
                 state.request_scroll_to_new_row = false;
@@ -79,14 +85,17 @@ pub(super) fn process_editor_events_and_state(
         || initial_selected_sheet_name != &state.selected_sheet_name
     {
         debug!("Selected sheet or category changed by UI interaction.");
+        if let Some(previous_sheet) = initial_selected_sheet_name {
+            state.stash_toybox_context(initial_selected_category, previous_sheet);
+        }
         state.reset_interaction_modes_and_selections();
         state.random_picker_needs_init = true;
-        if let Some(sheet_name) = &state.selected_sheet_name {
+        if let Some(sheet_name) = state.selected_sheet_name.clone() {
             if render_cache
-                .get_cell_data(&state.selected_category, sheet_name, 0, 0)
+                .get_cell_data(&state.selected_category, &sheet_name, 0, 0)
                 .is_none()
                 && registry
-                    .get_sheet(&state.selected_category, sheet_name)
+                    .get_sheet(&state.selected_category, &sheet_name)
                     .map_or(false, |d| !d.grid.is_empty())
             {
                 sheet_writers.revalidate.write(RequestSheetRevalidation {
@@ -96,12 +105,18 @@ pub(super) fn process_editor_events_and_state(
             }
 
             // Initialize Random Picker UI from metadata or sensible defaults
-            if let Some(sheet) = registry.get_sheet(&state.selected_category, sheet_name) {
+            if let Some(sheet) = registry.get_sheet(&state.selected_category, &sheet_name) {
                 if let Some(meta) = &sheet.metadata {
                     let num_cols = meta.columns.len();
                     if let Some(rp) = &meta.random_picker {
                         state.random_picker_mode_is_complex =
                             matches!(rp.mode, RandomPickerMode::Complex);
+                        state.random_picker_draw_count = rp.draw_count.max(1);
+                        state.set_random_picker_seed(rp.seed);
+                        state.random_picker_excluded.insert(
+                            (state.selected_category.clone(), sheet_name.clone()),
+                            rp.excluded_row_indices.iter().copied().collect(),
+                        );
                         state.random_simple_result_col =
                             rp.simple_result_col_index.min(num_cols.saturating_sub(1));
                         state.random_complex_result_col =
@@ -176,12 +191,15 @@ pub(super) fn process_editor_events_and_state(
                     } else {
                         // Default: Simple with first column
                         state.random_picker_mode_is_complex = false;
+                        state.random_picker_draw_count = 1;
+                        state.set_random_picker_seed(None);
                         state.random_simple_result_col = 0.min(num_cols.saturating_sub(1));
                         state.random_complex_result_col = 0.min(num_cols.saturating_sub(1));
                         state.random_complex_weight_col = None;
                         state.random_complex_second_weight_col = None;
                     }
-                    state.random_picker_last_value.clear();
+                    let category = state.selected_category.clone();
+                    state.restore_toybox_context(&category, &sheet_name);
                 }
             }
         }
@@ -193,13 +211,19 @@ pub(super) fn process_editor_events_and_state(
         // Attempt to initialize only if registry has metadata for the selected sheet.
         // If metadata isn't loaded yet (startup race), leave the flag set so we retry next frame.
         let mut initialized = false;
-        if let Some(sheet_name) = &state.selected_sheet_name {
-            if let Some(sheet) = registry.get_sheet(&state.selected_category, sheet_name) {
+        if let Some(sheet_name) = state.selected_sheet_name.clone() {
+            if let Some(sheet) = registry.get_sheet(&state.selected_category, &sheet_name) {
                 if let Some(meta) = &sheet.metadata {
                     let num_cols = meta.columns.len();
                     if let Some(rp) = &meta.random_picker {
                         state.random_picker_mode_is_complex =
                             matches!(rp.mode, RandomPickerMode::Complex);
+                        state.random_picker_draw_count = rp.draw_count.max(1);
+                        state.set_random_picker_seed(rp.seed);
+                        state.random_picker_excluded.insert(
+                            (state.selected_category.clone(), sheet_name.clone()),
+                            rp.excluded_row_indices.iter().copied().collect(),
+                        );
                         state.random_simple_result_col =
                             rp.simple_result_col_index.min(num_cols.saturating_sub(1));
                         state.random_complex_result_col =
@@ -270,6 +294,8 @@ pub(super) fn process_editor_events_and_state(
                     } else {
                         // Default: Simple with first column
                         state.random_picker_mode_is_complex = false;
+                        state.random_picker_draw_count = 1;
+                        state.set_random_picker_seed(None);
                         state.random_simple_result_col = 0.min(num_cols.saturating_sub(1));
                         state.random_complex_result_col = 0.min(num_cols.saturating_sub(1));
                         state.random_complex_weight_col = None;
@@ -286,7 +312,8 @@ pub(super) fn process_editor_events_and_state(
                             .summarizer_selected_columns
                             .push(Some(state.random_simple_result_col));
                     }
-                    state.random_picker_last_value.clear();
+                    let category = state.selected_category.clone();
+                    state.restore_toybox_context(&category, &sheet_name);
                     initialized = true;
                     debug!(
                         "Random Picker initialized from metadata for '{:?}/{}'.",
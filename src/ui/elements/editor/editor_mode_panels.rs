@@ -47,8 +47,11 @@ pub(super) fn show_active_mode_panel(
             &mut sheet_writers.toggle_ai_row_generation,
             &mut sheet_writers.create_ai_schema_group,
             &mut sheet_writers.rename_ai_schema_group,
+            &mut sheet_writers.reorder_ai_schema_group,
             &mut sheet_writers.select_ai_schema_group,
             &mut sheet_writers.delete_ai_schema_group,
+            &mut sheet_writers.set_ai_schema_group_allow_rows,
+            &mut sheet_writers.set_ai_structure_skip,
             director_session,
         );
         panel_shown = true;
@@ -61,7 +64,6 @@ pub(super) fn show_active_mode_panel(
             state,
             crate::ui::elements::top_panel::controls::delete_mode_panel::DeleteModeEventWriters {
                 delete_rows_event_writer: &mut sheet_writers.delete_rows,
-                delete_columns_event_writer: &mut sheet_writers.delete_columns,
             },
         );
         panel_shown = true;
@@ -4,16 +4,26 @@ use crate::sheets::resources::SheetRegistry;
 use crate::sheets::database::daemon_client::DaemonClient;
 use crate::ui::elements::editor::state::EditorWindowState;
 use crate::ui::elements::popups::{
-    show_add_table_popup, show_ai_rule_popup, show_column_options_popup,
-    show_delete_category_confirm_popups, show_delete_confirm_popup, show_migration_popup,
-    show_new_category_popup, show_new_sheet_popup, show_random_picker_popup, show_rename_popup,
-    show_settings_popup, show_structure_recreation_popup, show_validator_confirm_popup,
+    show_add_table_popup, show_ai_payload_preview_popup, show_ai_rule_popup,
+    show_ai_runtime_notice_popup,
+    show_cell_comment_popup, show_column_options_popup, show_compare_sheets_popup,
+    show_delete_category_confirm_popups, show_delete_columns_confirm_popup,
+    show_delete_confirm_popup, show_duplicate_sheet_popup,
+    show_find_replace_popup,
+    show_header_rename_popup, show_long_text_editor_popup, show_merge_rows_popup,
+    show_migration_popup,
+    show_new_category_popup, show_new_sheet_popup, show_project_import_popup,
+    show_command_palette_popup, show_quick_open_popup,
+    show_random_picker_popup,
+    show_reload_confirm_popup, show_rename_popup, show_revert_json_confirm_popup,
+    show_settings_popup, show_sql_console_popup, show_structure_recreation_popup,
+    show_validator_confirm_popup,
     MigrationPopupState,
 };
 use crate::ui::UiFeedbackState;
 use crate::visual_copier::events::{
-    PickFolderRequest, QueueTopPanelCopyEvent, ReverseTopPanelFoldersEvent,
-    VisualCopierStateChanged,
+    ExportCopyTasksRequest, ImportCopyTasksRequest, PickFolderRequest, QueueTopPanelCopyEvent,
+    ReverseTopPanelFoldersEvent, VisualCopierStateChanged,
 };
 use crate::visual_copier::resources::VisualCopierManager;
 use crate::ApiKeyDisplayStatus;
@@ -36,6 +46,8 @@ pub(super) fn display_active_popups(
     queue_top_panel_copy_writer: &mut EventWriter<QueueTopPanelCopyEvent>,
     reverse_folders_writer: &mut EventWriter<ReverseTopPanelFoldersEvent>,
     state_changed_writer: &mut EventWriter<VisualCopierStateChanged>,
+    export_tasks_writer: &mut EventWriter<ExportCopyTasksRequest>,
+    import_tasks_writer: &mut EventWriter<ImportCopyTasksRequest>,
     daemon_client: &DaemonClient,
 ) {
     show_column_options_popup(
@@ -60,8 +72,27 @@ pub(super) fn display_active_popups(
         &mut sheet_writers.rename_sheet,
         &mut sheet_writers.rename_category,
         ui_feedback,
+        registry,
     );
     show_delete_confirm_popup(ctx, state, &mut sheet_writers.delete_sheet);
+    show_delete_columns_confirm_popup(ctx, state, registry, &mut sheet_writers.delete_columns);
+    show_duplicate_sheet_popup(ctx, state, registry, &mut sheet_writers.duplicate_sheet);
+    show_project_import_popup(ctx, state, &mut sheet_writers.import_project);
+    show_reload_confirm_popup(ctx, state);
+    show_revert_json_confirm_popup(ctx, state, &mut sheet_writers.revert_sheet_from_json);
+    show_header_rename_popup(ctx, state, registry, daemon_client);
+    show_find_replace_popup(ctx, state, registry, &mut sheet_writers.find_replace);
+    // Merge rows (Delete mode "Merge" button)
+    show_merge_rows_popup(ctx, state, registry, &mut sheet_writers.merge_rows);
+    // Multi-line editor of long text cells ("⤢" in the grid)
+    show_long_text_editor_popup(ctx, state, &mut sheet_writers.cell_update);
+    show_cell_comment_popup(
+        ctx,
+        state,
+        registry,
+        &mut sheet_writers.upsert_cell_comment,
+        &mut sheet_writers.delete_cell_comment,
+    );
     // Category popups
     show_new_category_popup(ctx, state, &mut sheet_writers.create_category);
     show_delete_category_confirm_popups(ctx, state, &mut sheet_writers.delete_category);
@@ -76,7 +107,28 @@ pub(super) fn display_active_popups(
         queue_top_panel_copy_writer,
         reverse_folders_writer,
         state_changed_writer,
+        export_tasks_writer,
+        import_tasks_writer,
+        &mut sheet_writers.rotate_api_key,
+        &mut sheet_writers.export_jsonl,
+        &mut sheet_writers.export_parquet,
+        &mut sheet_writers.export_project,
+        &mut sheet_writers.import_project,
+        &mut sheet_writers.compact_database,
+        &mut sheet_writers.validate_structure_links,
     );
+    // One-time notice when Python is unusable and AI was disabled at startup
+    show_ai_runtime_notice_popup(ctx, state);
+    // AI dry run: payload of the first request, opened from the AI control panel
+    show_ai_payload_preview_popup(ctx, state);
+    // Ctrl+Shift+P command palette; before quick open so Ctrl+P does not take the shortcut
+    show_command_palette_popup(ctx, state, registry, sheet_writers, daemon_client);
+    // Ctrl+P quick open overlay (fuzzy jump to any sheet)
+    show_quick_open_popup(ctx, state, registry);
+    // Read-only SQL console (enabled from Settings > Developer)
+    show_sql_console_popup(ctx, state, registry);
+    // Sheet comparison (top panel "Compare")
+    show_compare_sheets_popup(ctx, state, registry, daemon_client);
     // AI Rule (per-sheet AI Context) popup is now accessed from AI Mode via 'AI Context' button
     show_ai_rule_popup(ctx, state, registry, daemon_client);
     // Structure Recreation popup (when converting column to Structure type and table already exists)
@@ -8,8 +8,8 @@ pub mod display_table;
 
 use crate::sheets::{
     events::{
-        AddSheetRowRequest, RequestAddColumn,
-        RequestBatchUpdateColumnAiInclude, RequestCopyCell, RequestPasteCell, RequestReorderColumn,
+        AddSheetRowRequest, MoveColumnEvent, RequestAddColumn,
+        RequestBatchUpdateColumnAiInclude, RequestCopyCell, RequestPasteCell,
         RequestToggleAiRowGeneration, RequestUpdateAiSendSchema, RequestUpdateAiStructureSend,
         RequestUpdateColumnAiInclude, UpdateCellEvent,
     },
@@ -35,7 +35,7 @@ pub(super) fn show_sheet_table(
     state: &mut EditorWindowState,
     registry: &SheetRegistry,
     render_cache: &SheetRenderCache,
-    reorder_column_writer: EventWriter<RequestReorderColumn>,
+    reorder_column_writer: EventWriter<MoveColumnEvent>,
     cell_update_writer: EventWriter<UpdateCellEvent>,
     toggle_add_rows_writer: EventWriter<RequestToggleAiRowGeneration>,
     column_include_writer: EventWriter<RequestUpdateColumnAiInclude>,
@@ -169,7 +169,7 @@ fn render_sheet_with_metadata(
     metadata: &crate::sheets::definitions::SheetMetadata,
     selected_name: &str,
     current_category: &Option<String>,
-    reorder_column_writer: EventWriter<RequestReorderColumn>,
+    reorder_column_writer: EventWriter<MoveColumnEvent>,
     cell_update_writer: EventWriter<UpdateCellEvent>,
     toggle_add_rows_writer: EventWriter<RequestToggleAiRowGeneration>,
     column_include_writer: EventWriter<RequestUpdateColumnAiInclude>,
@@ -202,6 +202,18 @@ fn render_sheet_with_metadata(
         return;
     }
 
+    // In-sheet find bar (Ctrl+F); its highlights are drawn by the cells
+    super::sheet_find_bar::show_sheet_find_bar(
+        ui,
+        state,
+        registry,
+        render_cache,
+        metadata,
+        current_category,
+        selected_name,
+        &visible_columns,
+    );
+
     // Gather ancestor key columns for virtual structure sheets
     let ancestor_key_columns =
         ui_handlers::build_ancestor_key_columns(state, registry, selected_name);
@@ -1,10 +1,120 @@
 // src/ui/elements/editor/display/display_controls.rs
 // Floating button controls for Add Row and Add Column
 
-use crate::sheets::events::{AddSheetRowRequest, RequestAddColumn};
+use crate::sheets::events::{
+    AddSheetRowRequest, RequestAddColumn, SheetOperationFeedback, UpdateCellEvent,
+};
+use crate::sheets::resources::{EditHistory, SheetRegistry};
 use crate::ui::elements::editor::state::{EditorWindowState, SheetInteractionState};
 use bevy::prelude::*;
 use bevy_egui::egui;
+use std::collections::HashSet;
+
+/// Sends an add-row request for the selected sheet; the new row goes below the last
+/// row, is scrolled into view and its first text cell focused once it is rendered.
+pub fn request_quick_add_row(
+    state: &EditorWindowState,
+    add_row_writer: &mut EventWriter<AddSheetRowRequest>,
+) {
+    if let (Some(cat), Some(sheet)) = (&state.selected_category, &state.selected_sheet_name) {
+        add_row_writer.write(AddSheetRowRequest {
+            category: Some(cat.clone()),
+            sheet_name: sheet.clone(),
+            initial_values: None,
+            at_end: true,
+        });
+    }
+}
+
+/// Ctrl+Enter adds a row while the grid is in its normal editing mode
+pub fn handle_quick_add_row_shortcut(
+    ctx: &egui::Context,
+    state: &EditorWindowState,
+    add_row_writer: &mut EventWriter<AddSheetRowRequest>,
+) {
+    // Ctrl+Enter already runs or saves in text editors (SQL console, long text editor)
+    if ctx.wants_keyboard_input() {
+        return;
+    }
+    let idle = state.current_interaction_mode == SheetInteractionState::Idle
+        && !state.show_toybox_menu;
+    if idle && ctx.input(|i| i.modifiers.command && i.key_pressed(egui::Key::Enter)) {
+        request_quick_add_row(state, add_row_writer);
+    }
+}
+
+/// Ctrl+Z undoes the latest cell edit, Ctrl+Y (or Ctrl+Shift+Z) redoes it. Skipped while a
+/// text field has focus, so text editing keeps its own undo.
+pub fn handle_undo_redo_shortcuts(
+    ctx: &egui::Context,
+    registry: &SheetRegistry,
+    history: &mut EditHistory,
+    cell_update_writer: &mut EventWriter<UpdateCellEvent>,
+    feedback_writer: &mut EventWriter<SheetOperationFeedback>,
+) {
+    if ctx.wants_keyboard_input() {
+        return;
+    }
+    let (undo_pressed, redo_pressed) = ctx.input(|i| {
+        let z = i.modifiers.command && i.key_pressed(egui::Key::Z);
+        let y = i.modifiers.command && i.key_pressed(egui::Key::Y);
+        (z && !i.modifiers.shift, y || (z && i.modifiers.shift))
+    });
+    let (change, verb) = if undo_pressed {
+        (history.undo(), "Undo")
+    } else if redo_pressed {
+        (history.redo(), "Redo")
+    } else {
+        return;
+    };
+    let Some(change) = change else {
+        feedback_writer.write(SheetOperationFeedback {
+            message: format!("Nothing to {}.", verb.to_lowercase()),
+            is_error: false,
+        });
+        return;
+    };
+    // Rows may have moved since the edit; find them again by their DB row_index. A row
+    // that is gone (deleted or merged away) cannot be edited back, so its records go too.
+    let grid_row = match change.row_index {
+        Some(ri) => {
+            let found = registry
+                .get_sheet(&change.category, &change.sheet_name)
+                .and_then(|s| s.row_indices.iter().position(|&x| x == ri));
+            let Some(grid_row) = found else {
+                history.drop_row_indices(&change.category, &change.sheet_name, &HashSet::from([ri]));
+                feedback_writer.write(SheetOperationFeedback {
+                    message: format!(
+                        "{} skipped: the edited row of '{}' no longer exists.",
+                        verb, change.sheet_name
+                    ),
+                    is_error: true,
+                });
+                return;
+            };
+            grid_row
+        }
+        None => change.row,
+    };
+    cell_update_writer.write(UpdateCellEvent {
+        category: change.category.clone(),
+        sheet_name: change.sheet_name.clone(),
+        row_index: grid_row,
+        col_index: change.col,
+        new_value: change.new_value.clone(),
+    });
+    feedback_writer.write(SheetOperationFeedback {
+        message: format!(
+            "{}: '{}' row {} col {} → '{}'",
+            verb,
+            change.sheet_name,
+            grid_row + 1,
+            change.col + 1,
+            change.new_value
+        ),
+        is_error: false,
+    });
+}
 
 /// Renders floating Add Row and Add Column buttons
 pub fn render_floating_controls(
@@ -34,22 +144,13 @@ pub fn render_floating_controls(
         .order(egui::Order::Foreground)
         .fixed_pos(pos_left)
         .show(ctx, |ui_f| {
-            let btn = egui::Button::new("+");
+            let btn = egui::Button::new("+ Add Row");
             if ui_f
                 .add(btn)
-                .on_hover_text("Add a new row to the sheet")
+                .on_hover_text("Add a new row at the bottom of the sheet (Ctrl+Enter)")
                 .clicked()
             {
-                if let (Some(cat), Some(sheet)) = (
-                    &state.selected_category,
-                    &state.selected_sheet_name,
-                ) {
-                    add_row_writer.write(AddSheetRowRequest {
-                        category: Some(cat.clone()),
-                        sheet_name: sheet.clone(),
-                        initial_values: None,
-                    });
-                }
+                request_quick_add_row(state, &mut add_row_writer);
             }
         });
 
@@ -39,7 +39,9 @@ pub fn compute_visible_columns(
     state.get_visible_column_indices(category, sheet_name, metadata)
 }
 
-/// Builds table column definitions with appropriate widths
+/// Builds table column definitions with appropriate widths. The control column and the
+/// remainder filler are optional so a frozen-columns region can omit one or the other.
+#[allow(clippy::too_many_arguments)]
 pub fn build_table_columns<'a>(
     mut table_builder: egui_extras::TableBuilder<'a>,
     state: &mut EditorWindowState,
@@ -47,6 +49,8 @@ pub fn build_table_columns<'a>(
     visible_columns: &[usize],
     prefix_count: usize,
     total_cols: usize,
+    control_column: bool,
+    filler: bool,
 ) -> egui_extras::TableBuilder<'a> {
     use crate::sheets::systems::ui_handlers;
     use egui_extras::Column;
@@ -63,8 +67,10 @@ pub fn build_table_columns<'a>(
             .column(Column::remainder().resizable(false));
     } else {
         // Add fixed left control column for checkboxes/buttons
-        table_builder =
-            table_builder.column(Column::initial(26.0).at_least(26.0).resizable(false));
+        if control_column {
+            table_builder =
+                table_builder.column(Column::initial(26.0).at_least(26.0).resizable(false));
+        }
 
         // Build prefix (read-only key) columns next (if any)
         for _ in 0..prefix_count {
@@ -80,26 +86,36 @@ pub fn build_table_columns<'a>(
                 col_def.validator.as_ref(),
                 col_def.data_type,
             );
-            let col = Column::initial(init_w)
+            // Stored width belongs to the column itself, so it is still correct after a reorder
+            let col = Column::initial(col_def.width.unwrap_or(init_w).max(min_w))
                 .at_least(min_w)
                 .resizable(true)
                 .clip(true);
             table_builder = table_builder.column(col);
         }
         // Add a non-resizable remainder filler column to prevent the last data column from stretching
-        table_builder = table_builder.column(Column::remainder().resizable(false));
+        if filler {
+            table_builder = table_builder.column(Column::remainder().resizable(false));
+        }
     }
 
     table_builder
 }
 
-/// Renders the left control cell (checkbox or empty space)
+/// Renders the left control cell (checkbox, jump-to-parent button for structure rows, or empty space)
+#[allow(clippy::too_many_arguments)]
 pub fn render_control_cell(
     row: &mut egui_extras::TableRow,
     state: &mut EditorWindowState,
+    registry: &crate::sheets::resources::SheetRegistry,
+    current_category: &Option<String>,
+    selected_name: &str,
+    is_structure_child: bool,
     original_row_index: usize,
+    filtered_indices: &[usize],
     row_height: f32,
 ) {
+    use crate::sheets::systems::logic::row_selection::apply_row_click;
     use crate::ui::elements::editor::state::{AiModeState, SheetInteractionState};
     use bevy_egui::egui;
 
@@ -109,16 +125,52 @@ pub fn render_control_cell(
 
         if state.current_interaction_mode == SheetInteractionState::DeleteModeActive || ai_preparing
         {
-            let is_selected = state.ai_selected_rows.contains(&original_row_index);
-            let mut checkbox_state = is_selected;
-            let response = ui.add(egui::Checkbox::without_text(&mut checkbox_state));
-            if response.changed() {
-                // Update the HashSet to match the NEW checkbox state
-                if checkbox_state {
-                    state.ai_selected_rows.insert(original_row_index);
-                } else {
-                    state.ai_selected_rows.remove(&original_row_index);
+            let mut checkbox_state = state.ai_selected_rows.contains(&original_row_index);
+            let response = ui
+                .add(egui::Checkbox::without_text(&mut checkbox_state))
+                .on_hover_text("Click / Ctrl+Click: toggle row\nShift+Click: select range\nCtrl+Shift+Click: add range\nCtrl+A: select all filtered rows");
+            if response.clicked() {
+                // The checkbox already flipped its copy; the selection set is updated here
+                let modifiers = ui.input(|i| i.modifiers);
+                state.row_selection_anchor = apply_row_click(
+                    &mut state.ai_selected_rows,
+                    state.row_selection_anchor,
+                    original_row_index,
+                    filtered_indices,
+                    modifiers.shift,
+                    modifiers.command,
+                );
+            }
+            // Selection set operations, scoped to the rows visible under the current filter
+            response.context_menu(|menu_ui| {
+                if menu_ui.button("Select all (filtered rows)").clicked() {
+                    state.select_only_rows(filtered_indices);
+                    menu_ui.close_menu();
+                }
+                if menu_ui.button("Invert selection").clicked() {
+                    state.invert_row_selection(filtered_indices);
+                    menu_ui.close_menu();
                 }
+                if menu_ui.button("Clear selection").clicked() {
+                    state.ai_selected_rows.clear();
+                    menu_ui.close_menu();
+                }
+            });
+        } else if is_structure_child
+            && state.current_interaction_mode == SheetInteractionState::Idle
+        {
+            if ui
+                .small_button("⤴")
+                .on_hover_text("Jump to the parent row that owns this row")
+                .clicked()
+            {
+                crate::ui::elements::editor::structure_navigation::jump_to_structure_parent(
+                    state,
+                    registry,
+                    current_category,
+                    selected_name,
+                    original_row_index,
+                );
             }
         } else {
             ui.allocate_exact_size(egui::vec2(18.0, row_height), egui::Sense::hover());
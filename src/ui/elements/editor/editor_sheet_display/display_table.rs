@@ -3,8 +3,8 @@
 
 use crate::sheets::{
     events::{
-        RequestBatchUpdateColumnAiInclude,
-        RequestCopyCell, RequestPasteCell, RequestReorderColumn, RequestToggleAiRowGeneration,
+        MoveColumnEvent, RequestBatchUpdateColumnAiInclude,
+        RequestCopyCell, RequestPasteCell, RequestToggleAiRowGeneration,
         RequestUpdateAiSendSchema, RequestUpdateAiStructureSend, RequestUpdateColumnAiInclude,
         UpdateCellEvent,
     },
@@ -13,13 +13,27 @@ use crate::sheets::{
 use crate::ui::elements::editor::editor_sheet_display::display_helpers::{
     build_table_columns, render_control_cell, render_data_cell,
 };
-use crate::ui::elements::editor::state::EditorWindowState;
+use crate::ui::elements::editor::state::{AiModeState, EditorWindowState, SheetInteractionState};
 use crate::ui::elements::editor::table_body::get_filtered_row_indices_cached;
 use crate::ui::elements::editor::table_header::sheet_table_header;
 use bevy::prelude::*;
 use bevy_egui::egui;
 use egui_extras::{TableBody, TableBuilder};
 
+/// One horizontally independent part of the sheet table. Without frozen columns the whole
+/// table is a single region; with them, the pinned columns and the scrolling rest are two
+/// tables drawn side by side that share the vertical scroll offset.
+struct TableRegion<'a> {
+    id_salt: &'static str,
+    columns: &'a [usize],
+    /// The left control column (checkboxes, jump-to-parent) belongs to the leftmost region
+    control_column: bool,
+    /// Remainder filler so the last data column does not stretch
+    filler: bool,
+    scroll_offset_y: Option<f32>,
+    hide_scroll_bar: bool,
+}
+
 /// Builds and renders the table with all columns, headers, and body
 #[allow(clippy::too_many_arguments)]
 pub fn build_and_render_table(
@@ -34,98 +48,287 @@ pub fn build_and_render_table(
     current_category: &Option<String>,
     visible_columns: &[usize],
     ancestor_key_columns: &[(String, String)],
-    reorder_column_writer: EventWriter<RequestReorderColumn>,
-    cell_update_writer: EventWriter<UpdateCellEvent>,
-    toggle_add_rows_writer: EventWriter<RequestToggleAiRowGeneration>,
-    column_include_writer: EventWriter<RequestUpdateColumnAiInclude>,
-    batch_include_writer: EventWriter<RequestBatchUpdateColumnAiInclude>,
-    send_schema_writer: EventWriter<RequestUpdateAiSendSchema>,
-    structure_send_writer: EventWriter<RequestUpdateAiStructureSend>,
-    copy_writer: EventWriter<RequestCopyCell>,
-    paste_writer: EventWriter<RequestPasteCell>,
+    mut reorder_column_writer: EventWriter<MoveColumnEvent>,
+    mut cell_update_writer: EventWriter<UpdateCellEvent>,
+    mut toggle_add_rows_writer: EventWriter<RequestToggleAiRowGeneration>,
+    mut column_include_writer: EventWriter<RequestUpdateColumnAiInclude>,
+    mut batch_include_writer: EventWriter<RequestBatchUpdateColumnAiInclude>,
+    mut send_schema_writer: EventWriter<RequestUpdateAiSendSchema>,
+    mut structure_send_writer: EventWriter<RequestUpdateAiStructureSend>,
+    mut copy_writer: EventWriter<RequestCopyCell>,
+    mut paste_writer: EventWriter<RequestPasteCell>,
     clipboard_buffer: &ClipboardBuffer,
 ) -> egui::Pos2 {
     let table_start_pos = ui.next_widget_position();
-    
-    let _scroll_resp = egui::ScrollArea::both()
-        .id_salt("main_sheet_table_scroll_area")
-        .auto_shrink([false; 2])
-        .show(ui, |ui| {
-            let mut table_builder = TableBuilder::new(ui)
-                .striped(true)
-                .resizable(true)
-                .cell_layout(egui::Layout::left_to_right(egui::Align::Min))
-                .min_scrolled_height(0.0);
-
-            let prefix_count = ancestor_key_columns.len();
-            let num_visible_cols = visible_columns.len();
-            let total_cols = num_visible_cols + prefix_count;
-
-            table_builder = build_table_columns(
-                table_builder,
+
+    // Resolve a pending jump to a specific grid row into its position among the visible rows
+    let jump_targets_this_sheet = state
+        .pending_scroll_to_sheet_row
+        .as_ref()
+        .map_or(false, |(cat, name, _)| cat == current_category && name == selected_name);
+    if jump_targets_this_sheet {
+        if let (Some((_, _, target_row)), Some(sheet)) = (
+            state.pending_scroll_to_sheet_row.take(),
+            registry.get_sheet(current_category, selected_name),
+        ) {
+            let filtered = get_filtered_row_indices_cached(
                 state,
+                current_category,
+                selected_name,
+                &sheet.grid,
                 metadata,
-                visible_columns,
-                prefix_count,
-                total_cols,
             );
-
-            // Handle scroll-to-row request
-            if let Some(row_idx) = state.scroll_to_row_index {
-                if total_cols > 0 {
-                    table_builder = table_builder.scroll_to_row(row_idx, Some(egui::Align::TOP));
-                }
-                state.scroll_to_row_index = None;
+            match filtered.iter().position(|&r| r == target_row) {
+                Some(pos) => state.scroll_to_row_index = Some(pos),
+                None => info!(
+                    "Row {} of '{}' is hidden by the current filters; not scrolling.",
+                    target_row, selected_name
+                ),
             }
+        }
+    }
+
+    // Reset header anchor each frame before header render
+    state.last_header_right_edge_x = 0.0;
 
-            // Reset header anchor each frame before header render
-            state.last_header_right_edge_x = 0.0;
+    // Ancestor key columns are only supported in the single-region layout, and at least
+    // one column always stays in the scrolling region
+    let frozen = if ancestor_key_columns.is_empty() {
+        state
+            .frozen_column_count
+            .min(visible_columns.len().saturating_sub(1))
+    } else {
+        0
+    };
 
-            table_builder
-                .header(row_height, |mut header_row| {
-                    render_table_header(
-                        &mut header_row,
-                        ctx,
+    let mut render_region = |ui: &mut egui::Ui, state: &mut EditorWindowState, region: TableRegion| {
+        render_table_region(
+            ui,
+            ctx,
+            row_height,
+            state,
+            registry,
+            render_cache,
+            metadata,
+            selected_name,
+            current_category,
+            ancestor_key_columns,
+            region,
+            &mut reorder_column_writer,
+            &mut cell_update_writer,
+            &mut toggle_add_rows_writer,
+            &mut column_include_writer,
+            &mut batch_include_writer,
+            &mut send_schema_writer,
+            &mut structure_send_writer,
+            &mut copy_writer,
+            &mut paste_writer,
+            clipboard_buffer,
+        )
+    };
+
+    // Only the table bodies scroll vertically, so the header rows stay pinned above them.
+    // An outer vertical scroll would carry the headers out of view with the rows.
+    if frozen == 0 {
+        egui::ScrollArea::horizontal()
+            .id_salt("main_sheet_table_scroll_area")
+            .auto_shrink([false; 2])
+            .show(ui, |ui| {
+                render_region(
+                    ui,
+                    state,
+                    TableRegion {
+                        id_salt: "sheet_table",
+                        columns: visible_columns,
+                        control_column: true,
+                        filler: true,
+                        scroll_offset_y: None,
+                        hide_scroll_bar: false,
+                    },
+                );
+            });
+    } else {
+        let (frozen_columns, scrolling_columns) = visible_columns.split_at(frozen);
+        let shared_offset = state.frozen_scroll_offset_y;
+        // A pending scroll-to-row is applied by both regions
+        let scroll_to_row = state.scroll_to_row_index;
+        let scroll_to_active_row = state.scroll_to_active_row;
+        ui.horizontal_top(|ui| {
+            let frozen_offset = render_region(
+                ui,
+                state,
+                TableRegion {
+                    id_salt: "sheet_table_frozen",
+                    columns: frozen_columns,
+                    control_column: true,
+                    filler: false,
+                    scroll_offset_y: Some(shared_offset),
+                    hide_scroll_bar: true,
+                },
+            );
+            ui.separator();
+            state.scroll_to_row_index = scroll_to_row;
+            state.scroll_to_active_row = scroll_to_active_row;
+            let scrolling_offset = egui::ScrollArea::horizontal()
+                .id_salt("main_sheet_table_scroll_area")
+                .auto_shrink([false; 2])
+                .show(ui, |ui| {
+                    render_region(
+                        ui,
                         state,
-                        registry,
-                        metadata,
-                        selected_name,
-                        current_category,
-                        ancestor_key_columns,
-                        total_cols,
-                        reorder_column_writer,
-                        column_include_writer,
-                        batch_include_writer,
-                        send_schema_writer,
-                        structure_send_writer,
-                    );
+                        TableRegion {
+                            id_salt: "sheet_table_scrolling",
+                            columns: scrolling_columns,
+                            control_column: false,
+                            filler: true,
+                            scroll_offset_y: Some(shared_offset),
+                            hide_scroll_bar: false,
+                        },
+                    )
                 })
-                .body(|body: TableBody| {
-                    render_table_body(
-                        body,
-                        state,
-                        registry,
-                        render_cache,
-                        metadata,
-                        selected_name,
-                        current_category,
-                        visible_columns,
-                        ancestor_key_columns,
-                        row_height,
-                        prefix_count,
-                        num_visible_cols,
-                        cell_update_writer,
-                        toggle_add_rows_writer,
-                        copy_writer,
-                        paste_writer,
-                        clipboard_buffer,
-                    );
-                });
+                .inner;
+            // Whichever region the user scrolled this frame drives both on the next one
+            state.frozen_scroll_offset_y = if (frozen_offset - shared_offset).abs() > 0.5 {
+                frozen_offset
+            } else {
+                scrolling_offset
+            };
+            if state.frozen_scroll_offset_y != shared_offset {
+                ui.ctx().request_repaint();
+            }
         });
+    }
 
     table_start_pos
 }
 
+/// Renders one region's table (header and body); returns its vertical scroll offset
+#[allow(clippy::too_many_arguments)]
+fn render_table_region(
+    ui: &mut egui::Ui,
+    ctx: &egui::Context,
+    row_height: f32,
+    state: &mut EditorWindowState,
+    registry: &SheetRegistry,
+    render_cache: &SheetRenderCache,
+    metadata: &crate::sheets::definitions::SheetMetadata,
+    selected_name: &str,
+    current_category: &Option<String>,
+    ancestor_key_columns: &[(String, String)],
+    region: TableRegion,
+    reorder_column_writer: &mut EventWriter<MoveColumnEvent>,
+    cell_update_writer: &mut EventWriter<UpdateCellEvent>,
+    toggle_add_rows_writer: &mut EventWriter<RequestToggleAiRowGeneration>,
+    column_include_writer: &mut EventWriter<RequestUpdateColumnAiInclude>,
+    batch_include_writer: &mut EventWriter<RequestBatchUpdateColumnAiInclude>,
+    send_schema_writer: &mut EventWriter<RequestUpdateAiSendSchema>,
+    structure_send_writer: &mut EventWriter<RequestUpdateAiStructureSend>,
+    copy_writer: &mut EventWriter<RequestCopyCell>,
+    paste_writer: &mut EventWriter<RequestPasteCell>,
+    clipboard_buffer: &ClipboardBuffer,
+) -> f32 {
+    // egui keeps resized widths by position; salting the table with the visible
+    // column names drops that state whenever the order changes, so stored
+    // per-column widths are applied instead of the previous column's width
+    let column_identity: Vec<&str> = region
+        .columns
+        .iter()
+        .filter_map(|&i| metadata.columns.get(i).map(|c| c.header.as_str()))
+        .collect();
+    // egui_extras ids its column separators after the table's ui; widths are only saved
+    // once a drag on one of them ends, not on every click
+    let resize_handle = ui.id().with("resize_column");
+    let builder_columns = region.columns.len() + ancestor_key_columns.len() + 2;
+    let column_resized = ctx
+        .drag_stopped_id()
+        .is_some_and(|id| (0..builder_columns).any(|i| resize_handle.with(i) == id));
+    let mut table_builder = TableBuilder::new(ui)
+        .id_salt((region.id_salt, current_category, selected_name, column_identity))
+        .striped(true)
+        .resizable(true)
+        .cell_layout(egui::Layout::left_to_right(egui::Align::Min))
+        .min_scrolled_height(0.0)
+        // Fill the panel height below the sticky header instead of stopping at 800px
+        .max_scroll_height(f32::INFINITY);
+    if let Some(offset) = region.scroll_offset_y {
+        table_builder = table_builder.vertical_scroll_offset(offset);
+    }
+    if region.hide_scroll_bar {
+        table_builder =
+            table_builder.scroll_bar_visibility(egui::scroll_area::ScrollBarVisibility::AlwaysHidden);
+    }
+
+    let prefix_count = ancestor_key_columns.len();
+    let num_visible_cols = region.columns.len();
+    let total_cols = num_visible_cols + prefix_count;
+
+    table_builder = build_table_columns(
+        table_builder,
+        state,
+        metadata,
+        region.columns,
+        prefix_count,
+        total_cols,
+        region.control_column,
+        region.filler,
+    );
+
+    // Handle scroll-to-row request
+    if let Some(row_idx) = state.scroll_to_row_index {
+        if total_cols > 0 {
+            table_builder = table_builder.scroll_to_row(row_idx, Some(egui::Align::TOP));
+        }
+        state.scroll_to_row_index = None;
+    } else if let Some(row_idx) = state.scroll_to_active_row.take() {
+        if total_cols > 0 {
+            table_builder = table_builder.scroll_to_row(row_idx, None);
+        }
+    }
+
+    let output = table_builder
+        .header(row_height, |mut header_row| {
+            render_table_header(
+                &mut header_row,
+                ctx,
+                state,
+                registry,
+                metadata,
+                selected_name,
+                current_category,
+                ancestor_key_columns,
+                &region,
+                total_cols,
+                column_resized,
+                reorder_column_writer,
+                column_include_writer,
+                batch_include_writer,
+                send_schema_writer,
+                structure_send_writer,
+            );
+        })
+        .body(|body: TableBody| {
+            render_table_body(
+                body,
+                state,
+                registry,
+                render_cache,
+                metadata,
+                selected_name,
+                current_category,
+                &region,
+                ancestor_key_columns,
+                row_height,
+                prefix_count,
+                cell_update_writer,
+                toggle_add_rows_writer,
+                copy_writer,
+                paste_writer,
+                clipboard_buffer,
+            );
+        });
+    output.state.offset.y
+}
+
 /// Renders the table header row
 #[allow(clippy::too_many_arguments)]
 fn render_table_header(
@@ -137,17 +340,21 @@ fn render_table_header(
     selected_name: &str,
     current_category: &Option<String>,
     ancestor_key_columns: &[(String, String)],
+    region: &TableRegion,
     total_cols: usize,
-    reorder_column_writer: EventWriter<RequestReorderColumn>,
-    column_include_writer: EventWriter<RequestUpdateColumnAiInclude>,
-    batch_include_writer: EventWriter<RequestBatchUpdateColumnAiInclude>,
-    send_schema_writer: EventWriter<RequestUpdateAiSendSchema>,
-    structure_send_writer: EventWriter<RequestUpdateAiStructureSend>,
+    column_resized: bool,
+    reorder_column_writer: &mut EventWriter<MoveColumnEvent>,
+    column_include_writer: &mut EventWriter<RequestUpdateColumnAiInclude>,
+    batch_include_writer: &mut EventWriter<RequestBatchUpdateColumnAiInclude>,
+    send_schema_writer: &mut EventWriter<RequestUpdateAiSendSchema>,
+    structure_send_writer: &mut EventWriter<RequestUpdateAiStructureSend>,
 ) {
     // Left control header cell: keep minimal content and do NOT draw a separator
-    header_row.col(|_ui| {
-        // Intentionally empty: no line under the left control column header
-    });
+    if region.control_column {
+        header_row.col(|_ui| {
+            // Intentionally empty: no line under the left control column header
+        });
+    }
 
     // Render ancestor key headers (green, read-only)
     for (key_header, value) in ancestor_key_columns {
@@ -175,6 +382,8 @@ fn render_table_header(
         current_category,
         registry,
         state,
+        region.columns,
+        column_resized,
         reorder_column_writer,
         column_include_writer,
         batch_include_writer,
@@ -183,7 +392,7 @@ fn render_table_header(
     );
 
     // Add empty header cell for the filler remainder column appended to the builder
-    if total_cols > 0 {
+    if total_cols > 0 && region.filler {
         header_row.col(|_ui| {
             /* filler */
         });
@@ -193,22 +402,21 @@ fn render_table_header(
 /// Renders the table body with all rows
 #[allow(clippy::too_many_arguments)]
 fn render_table_body(
-    body: TableBody,
+    mut body: TableBody,
     state: &mut EditorWindowState,
     registry: &SheetRegistry,
     render_cache: &SheetRenderCache,
     metadata: &crate::sheets::definitions::SheetMetadata,
     selected_name: &str,
     current_category: &Option<String>,
-    visible_columns: &[usize],
+    region: &TableRegion,
     ancestor_key_columns: &[(String, String)],
     row_height: f32,
     prefix_count: usize,
-    num_visible_cols: usize,
-    cell_update_writer: EventWriter<UpdateCellEvent>,
-    toggle_add_rows_writer: EventWriter<RequestToggleAiRowGeneration>,
-    copy_writer: EventWriter<RequestCopyCell>,
-    paste_writer: EventWriter<RequestPasteCell>,
+    cell_update_writer: &mut EventWriter<UpdateCellEvent>,
+    toggle_add_rows_writer: &mut EventWriter<RequestToggleAiRowGeneration>,
+    copy_writer: &mut EventWriter<RequestCopyCell>,
+    paste_writer: &mut EventWriter<RequestPasteCell>,
     clipboard_buffer: &ClipboardBuffer,
 ) {
     state.ensure_ai_included_columns_cache(registry, current_category, selected_name);
@@ -221,8 +429,24 @@ fn render_table_body(
     let filtered_indices =
         get_filtered_row_indices_cached(state, current_category, selected_name, grid, metadata);
 
+    // Select all filtered rows (Ctrl+A outside text fields, or the mode panels' button)
+    if region.control_column {
+        let selecting = state.current_interaction_mode == SheetInteractionState::DeleteModeActive
+            || (state.current_interaction_mode == SheetInteractionState::AiModeActive
+                && state.ai_mode == AiModeState::Preparing);
+        let ctx = body.ui_mut().ctx().clone();
+        let shortcut = selecting
+            && !ctx.wants_keyboard_input()
+            && ctx.input_mut(|i| i.consume_key(egui::Modifiers::COMMAND, egui::Key::A));
+        if std::mem::take(&mut state.pending_select_all_filtered) || shortcut {
+            if selecting {
+                state.select_only_rows(&filtered_indices);
+            }
+        }
+    }
+
     // If there are absolutely no columns, show a friendly hint row
-    if num_visible_cols == 0 && prefix_count == 0 {
+    if region.columns.is_empty() && prefix_count == 0 {
         body.rows(row_height, 1, |mut row| {
             // control col (empty hover area to keep row height consistent)
             row.col(|ui| {
@@ -245,7 +469,7 @@ fn render_table_body(
             metadata,
             selected_name,
             current_category,
-            visible_columns,
+            region,
             &filtered_indices,
             row_height,
             cell_update_writer,
@@ -267,13 +491,13 @@ fn render_standard_body(
     metadata: &crate::sheets::definitions::SheetMetadata,
     selected_name: &str,
     current_category: &Option<String>,
-    visible_columns: &[usize],
+    region: &TableRegion,
     filtered_indices: &[usize],
     row_height: f32,
-    mut cell_update_writer: EventWriter<UpdateCellEvent>,
-    mut toggle_add_rows_writer: EventWriter<RequestToggleAiRowGeneration>,
-    mut copy_writer: EventWriter<RequestCopyCell>,
-    mut paste_writer: EventWriter<RequestPasteCell>,
+    cell_update_writer: &mut EventWriter<UpdateCellEvent>,
+    toggle_add_rows_writer: &mut EventWriter<RequestToggleAiRowGeneration>,
+    copy_writer: &mut EventWriter<RequestCopyCell>,
+    paste_writer: &mut EventWriter<RequestPasteCell>,
     clipboard_buffer: &ClipboardBuffer,
 ) {
     use crate::sheets::definitions::ColumnValidator;
@@ -286,13 +510,29 @@ fn render_standard_body(
 
     let validators: Vec<Option<ColumnValidator>> =
         metadata.columns.iter().map(|c| c.validator.clone()).collect();
+    let is_structure_child = metadata
+        .columns
+        .iter()
+        .any(|c| c.header.eq_ignore_ascii_case("parent_key"));
 
     body.rows(row_height, filtered_indices.len(), |mut row| {
         let idx_in_list = row.index();
         let original_row_index = *filtered_indices.get(idx_in_list).unwrap_or(&0);
 
         // Left control cell
-        render_control_cell(&mut row, state, original_row_index, row_height);
+        if region.control_column {
+            render_control_cell(
+                &mut row,
+                state,
+                registry,
+                current_category,
+                selected_name,
+                is_structure_child,
+                original_row_index,
+                filtered_indices,
+                row_height,
+            );
+        }
 
         if let Some(row_data) = grid.get(original_row_index) {
             if row_data.len() != num_cols {
@@ -302,7 +542,7 @@ fn render_standard_body(
                 return;
             }
 
-            for c_idx in visible_columns.iter().copied() {
+            for c_idx in region.columns.iter().copied() {
                 row.col(|ui| {
                     render_data_cell(
                         ui,
@@ -314,19 +554,21 @@ fn render_standard_body(
                         original_row_index,
                         c_idx,
                         &validators,
-                        &mut cell_update_writer,
-                        &mut toggle_add_rows_writer,
-                        &mut copy_writer,
-                        &mut paste_writer,
+                        cell_update_writer,
+                        toggle_add_rows_writer,
+                        copy_writer,
+                        paste_writer,
                         clipboard_buffer,
                     );
                 });
             }
 
             // filler remainder cell to avoid stretching
-            row.col(|ui| {
-                ui.allocate_exact_size(egui::vec2(0.0, row_height), egui::Sense::hover());
-            });
+            if region.filler {
+                row.col(|ui| {
+                    ui.allocate_exact_size(egui::vec2(0.0, row_height), egui::Sense::hover());
+                });
+            }
         } else {
             row.col(|ui| {
                 ui.colored_label(egui::Color32::RED, "Row Idx Err");
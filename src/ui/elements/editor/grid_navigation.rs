@@ -0,0 +1,124 @@
+// src/ui/elements/editor/grid_navigation.rs
+// Arrow keys, Tab and Enter move the focused grid cell like in a spreadsheet
+
+use crate::sheets::{
+    definitions::SheetMetadata,
+    resources::SheetRegistry,
+    systems::logic::grid_navigation::{next_grid_cell, GridMove},
+};
+use crate::ui::elements::editor::state::EditorWindowState;
+use crate::ui::elements::editor::table_body::get_filtered_row_indices_cached;
+use bevy_egui::egui;
+
+/// Left/Right only leave a text cell once its cursor sits at that edge of the text
+fn cursor_at_edge(ctx: &egui::Context, focus_id: egui::Id, text: &str, left: bool) -> bool {
+    let Some(range) = egui::TextEdit::load_state(ctx, focus_id).and_then(|s| s.cursor.char_range())
+    else {
+        // Not a text field (checkbox, drag value, button)
+        return true;
+    };
+    if !range.is_empty() {
+        return false;
+    }
+    if left {
+        range.primary.index == 0
+    } else {
+        range.primary.index >= text.chars().count()
+    }
+}
+
+/// Consume a navigation key aimed at the focused grid cell before the cell's widget sees it
+/// and move the active cell. The target cell is focused (and scrolled into view) when it is
+/// drawn, so typing edits it straight away; edits still commit through `UpdateCellEvent`.
+///
+/// Must run before the table is drawn.
+pub fn handle_grid_keyboard_navigation(
+    ctx: &egui::Context,
+    state: &mut EditorWindowState,
+    registry: &SheetRegistry,
+) {
+    let Some((category, sheet_name, row_index, col_index, focus_id)) =
+        state.grid_active_cell.clone()
+    else {
+        return;
+    };
+    if !ctx.memory(|mem| mem.has_focus(focus_id))
+        || state.selected_category != category
+        || state.selected_sheet_name.as_deref() != Some(sheet_name.as_str())
+    {
+        return;
+    }
+    let Some(sheet) = registry.get_sheet(&category, &sheet_name) else {
+        return;
+    };
+    let Some(metadata) = sheet.metadata.as_ref() else {
+        return;
+    };
+    let cell_text = sheet
+        .grid
+        .get(row_index)
+        .and_then(|row| row.get(col_index))
+        .map_or("", String::as_str);
+
+    // Shift variants first: plain patterns also match while Shift is held
+    let step = ctx.input_mut(|input| {
+        use egui::{Key, Modifiers};
+        if input.consume_key(Modifiers::SHIFT, Key::Tab) {
+            Some(GridMove::Previous)
+        } else if input.consume_key(Modifiers::NONE, Key::Tab) {
+            Some(GridMove::Next)
+        } else if input.consume_key(Modifiers::SHIFT, Key::Enter)
+            || input.consume_key(Modifiers::NONE, Key::ArrowUp)
+        {
+            Some(GridMove::Up)
+        } else if input.consume_key(Modifiers::NONE, Key::Enter)
+            || input.consume_key(Modifiers::NONE, Key::ArrowDown)
+        {
+            Some(GridMove::Down)
+        } else {
+            None
+        }
+    });
+    let step = step.or_else(|| {
+        let (left, right) = ctx.input(|input| {
+            (
+                input.modifiers.is_none() && input.key_pressed(egui::Key::ArrowLeft),
+                input.modifiers.is_none() && input.key_pressed(egui::Key::ArrowRight),
+            )
+        });
+        let step = if left && cursor_at_edge(ctx, focus_id, cell_text, true) {
+            (egui::Key::ArrowLeft, GridMove::Left)
+        } else if right && cursor_at_edge(ctx, focus_id, cell_text, false) {
+            (egui::Key::ArrowRight, GridMove::Right)
+        } else {
+            return None;
+        };
+        ctx.input_mut(|input| input.consume_key(egui::Modifiers::NONE, step.0));
+        Some(step.1)
+    });
+    let Some(step) = step else {
+        return;
+    };
+
+    let columns: Vec<usize> = state
+        .get_visible_column_indices(&category, &sheet_name, metadata)
+        .into_iter()
+        // Technical columns are read-only labels, so keyboard movement skips them
+        .filter(|&c| {
+            metadata
+                .columns
+                .get(c)
+                .is_some_and(|col| !SheetMetadata::is_technical_column_header(&col.header))
+        })
+        .collect();
+    let rows = get_filtered_row_indices_cached(state, &category, &sheet_name, &sheet.grid, metadata);
+    let Some(target) = next_grid_cell(&rows, &columns, (row_index, col_index), step) else {
+        return;
+    };
+
+    ctx.memory_mut(|mem| mem.surrender_focus(focus_id));
+    state.grid_active_cell = None;
+    state.active_cell = Some(target);
+    state.pending_active_cell_focus = true;
+    state.scroll_to_active_row = rows.iter().position(|&r| r == target.0);
+}
@@ -9,16 +9,19 @@ use bevy_tokio_tasks::TokioTasksRuntime;
 use super::editor_ai_log;
 use super::editor_event_handling;
 use super::editor_popups_integration;
+use super::block_paste;
+use super::grid_navigation;
 use super::editor_sheet_display;
+use super::range_copy;
 use super::state::{AiModeState, EditorWindowState, SheetInteractionState};
 use crate::sheets::{
     database::daemon_resource::SharedDaemonClient,
     events::{
-        AddSheetRowRequest, RequestAddColumn,
+        AddSheetRowRequest, MoveColumnEvent, ReorderAiSchemaGroupEvent, RequestAddColumn,
         RequestBatchUpdateColumnAiInclude, RequestCopyCell, RequestCreateAiSchemaGroup,
         RequestCreateCategory, RequestCreateNewSheet, RequestDeleteAiSchemaGroup,
         RequestDeleteCategory, RequestDeleteColumns, RequestDeleteRows, RequestDeleteSheet,
-        RequestPasteCell, RequestRenameAiSchemaGroup, RequestRenameSheet, RequestReorderColumn,
+        RequestPasteCell, RequestRenameAiSchemaGroup, RequestRenameSheet,
         RequestSelectAiSchemaGroup, RequestSheetRevalidation, RequestToggleAiRowGeneration,
         RequestUpdateAiSendSchema, RequestUpdateAiStructureSend, RequestUpdateColumnAiInclude,
         RequestUpdateColumnName, RequestUpdateColumnValidator, SheetDataModifiedInRegistryEvent,
@@ -30,8 +33,8 @@ use crate::ui::{elements::top_panel::show_top_panel_orchestrator, UiFeedbackStat
 
 use crate::visual_copier::{
     events::{
-        PickFolderRequest, QueueTopPanelCopyEvent, RequestAppExit, ReverseTopPanelFoldersEvent,
-        VisualCopierStateChanged,
+        ExportCopyTasksRequest, ImportCopyTasksRequest, PickFolderRequest, QueueTopPanelCopyEvent,
+        RequestAppExit, ReverseTopPanelFoldersEvent, VisualCopierStateChanged,
     },
     resources::VisualCopierManager,
 };
@@ -44,6 +47,7 @@ pub struct SheetEventWriters<'w> {
     pub add_column: EventWriter<'w, RequestAddColumn>,
     pub create_sheet: EventWriter<'w, RequestCreateNewSheet>,
     pub rename_sheet: EventWriter<'w, RequestRenameSheet>,
+    pub duplicate_sheet: EventWriter<'w, crate::sheets::events::DuplicateSheetEvent>,
     pub rename_category: EventWriter<'w, crate::sheets::events::RequestRenameCategory>,
     pub delete_sheet: EventWriter<'w, RequestDeleteSheet>,
     pub upload_json_to_db: EventWriter<'w, crate::sheets::events::RequestUploadJsonToCurrentDb>,
@@ -52,7 +56,17 @@ pub struct SheetEventWriters<'w> {
     pub cell_update: EventWriter<'w, UpdateCellEvent>,
     pub delete_rows: EventWriter<'w, RequestDeleteRows>,
     pub delete_columns: EventWriter<'w, RequestDeleteColumns>,
-    pub reorder_column: EventWriter<'w, RequestReorderColumn>,
+    pub reorder_column: EventWriter<'w, MoveColumnEvent>,
+    pub sort_rows: EventWriter<'w, crate::sheets::events::SortSheetByColumnEvent>,
+    pub find_replace: EventWriter<'w, crate::sheets::events::FindReplaceInColumnEvent>,
+    pub upsert_cell_comment: EventWriter<'w, crate::sheets::events::UpsertCellCommentEvent>,
+    pub delete_cell_comment: EventWriter<'w, crate::sheets::events::DeleteCellCommentEvent>,
+    pub set_row_lock: EventWriter<'w, crate::sheets::events::SetRowLockEvent>,
+    pub duplicate_row: EventWriter<'w, crate::sheets::events::DuplicateRowEvent>,
+    pub merge_rows: EventWriter<'w, crate::sheets::events::MergeRowsEvent>,
+    pub revert_sheet_from_json: EventWriter<'w, crate::sheets::events::RevertSheetFromJsonEvent>,
+    pub toggle_column_hidden: EventWriter<'w, crate::sheets::events::ToggleColumnHiddenEvent>,
+    pub column_width: EventWriter<'w, crate::sheets::events::RequestUpdateColumnWidth>,
     pub revalidate: EventWriter<'w, RequestSheetRevalidation>,
     pub toggle_ai_row_generation: EventWriter<'w, RequestToggleAiRowGeneration>,
     pub update_column_ai_include: EventWriter<'w, RequestUpdateColumnAiInclude>,
@@ -61,18 +75,34 @@ pub struct SheetEventWriters<'w> {
     pub update_ai_structure_send: EventWriter<'w, RequestUpdateAiStructureSend>,
     pub create_ai_schema_group: EventWriter<'w, RequestCreateAiSchemaGroup>,
     pub rename_ai_schema_group: EventWriter<'w, RequestRenameAiSchemaGroup>,
+    pub reorder_ai_schema_group: EventWriter<'w, ReorderAiSchemaGroupEvent>,
     pub select_ai_schema_group: EventWriter<'w, RequestSelectAiSchemaGroup>,
     pub delete_ai_schema_group: EventWriter<'w, RequestDeleteAiSchemaGroup>,
+    pub set_ai_schema_group_allow_rows:
+        EventWriter<'w, crate::sheets::events::RequestSetAiSchemaGroupAllowRows>,
+    pub set_ai_structure_skip: EventWriter<'w, crate::sheets::events::RequestSetAiStructureSkip>,
     // Category management
     pub create_category: EventWriter<'w, RequestCreateCategory>,
     pub delete_category: EventWriter<'w, RequestDeleteCategory>,
     pub move_sheet_to_category: EventWriter<'w, crate::sheets::events::RequestMoveSheetToCategory>,
+    pub set_category_color: EventWriter<'w, crate::sheets::events::RequestSetCategoryColor>,
     // Clipboard
     pub copy_cell: EventWriter<'w, RequestCopyCell>,
     pub paste_cell: EventWriter<'w, RequestPasteCell>,
+    pub paste_block: EventWriter<'w, crate::sheets::events::RequestPasteBlock>,
     // Database migration
     pub migrate_json_to_db: EventWriter<'w, crate::sheets::events::RequestMigrateJsonToDb>,
     pub feedback: EventWriter<'w, crate::sheets::events::SheetOperationFeedback>,
+    pub export_jsonl: EventWriter<'w, crate::sheets::events::RequestExportSheetToJsonl>,
+    pub export_csv: EventWriter<'w, crate::sheets::events::RequestExportSheetToCsv>,
+    pub export_parquet: EventWriter<'w, crate::sheets::events::RequestExportSheetToParquet>,
+    pub export_project: EventWriter<'w, crate::sheets::events::RequestExportProject>,
+    pub import_project: EventWriter<'w, crate::sheets::events::RequestImportProject>,
+    pub compact_database: EventWriter<'w, crate::sheets::events::RequestCompactDatabase>,
+    pub validate_structure_links: EventWriter<'w, crate::sheets::events::RequestValidateStructureLinks>,
+    pub structure_search: EventWriter<'w, crate::sheets::events::RequestStructureSearch>,
+    // API key rotation (validated before saving)
+    pub rotate_api_key: EventWriter<'w, crate::sheets::events::RequestRotateApiKey>,
     // Structure table recreation
     pub structure_recreation: EventWriter<'w, crate::sheets::events::RequestStructureTableRecreation>,
 }
@@ -84,6 +114,8 @@ pub struct CopierEventWriters<'w> {
     pub queue_top_panel_copy: EventWriter<'w, QueueTopPanelCopyEvent>,
     pub reverse_folders: EventWriter<'w, ReverseTopPanelFoldersEvent>,
     pub state_changed: EventWriter<'w, VisualCopierStateChanged>,
+    pub export_tasks: EventWriter<'w, ExportCopyTasksRequest>,
+    pub import_tasks: EventWriter<'w, ImportCopyTasksRequest>,
 }
 
 #[derive(SystemParam)]
@@ -99,6 +131,8 @@ pub struct EditorMiscParams<'w> {
     pub request_app_exit_writer: EventWriter<'w, RequestAppExit>,
     pub daemon_client: Res<'w, SharedDaemonClient>,
     pub director_session: ResMut<'w, crate::sheets::systems::ai::processor::DirectorSession>,
+    pub edit_history: ResMut<'w, crate::sheets::resources::EditHistory>,
+    pub structure_search: Res<'w, crate::sheets::systems::logic::structure_search::StructureSearch>,
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -138,6 +172,13 @@ pub fn generic_sheet_editor_ui(
         &mut misc.registry,
         misc.daemon_client.client(),
     );
+    // Explicit "Reload from Database" (confirmed in its popup)
+    crate::sheets::systems::ui_handlers::sheet_handlers::process_pending_sheet_reload(
+        &mut state,
+        &mut misc.registry,
+        misc.daemon_client.client(),
+        &mut sheet_writers.feedback,
+    );
 
     // Trigger revalidation when a sheet is opened/re-opened
     if state.pending_sheet_revalidation {
@@ -150,6 +191,10 @@ pub fn generic_sheet_editor_ui(
         }
     }
 
+    // Escape that closes an overlay must not also navigate back out of a structure sheet;
+    // the popups consume it in egui, but bevy's key state below still reports it
+    let overlay_open_at_frame_start = state.show_quick_open || state.show_command_palette;
+
     editor_popups_integration::display_active_popups(
         ctx,
         &mut state,
@@ -164,6 +209,8 @@ pub fn generic_sheet_editor_ui(
         &mut copier_writers.queue_top_panel_copy,
         &mut copier_writers.reverse_folders,
         &mut copier_writers.state_changed,
+        &mut copier_writers.export_tasks,
+        &mut copier_writers.import_tasks,
         misc.daemon_client.client(),
     );
 
@@ -176,19 +223,43 @@ pub fn generic_sheet_editor_ui(
             &mut *misc.registry,
             &mut crate::ui::elements::bottom_panel::sheet_management_bar::SheetManagementEventWriters {
                 move_sheet_to_category: &mut sheet_writers.move_sheet_to_category,
+                set_category_color: &mut sheet_writers.set_category_color,
             },
             misc.daemon_client.client(),
         );
     });
     // Draw Log panel above the category/sheet bar
     editor_ai_log::show_ai_output_log_bottom(ctx, &mut state);
+    // Structure hierarchy of the selected sheet (toggled from the top panel)
+    super::structure_tree_panel::show_structure_tree_panel(ctx, &mut state, &misc.registry);
+    // Search across the sheet and its structure child tables (Ctrl+Shift+F)
+    super::structure_search_panel::show_structure_search_panel(
+        ctx,
+        &mut state,
+        &misc.registry,
+        &misc.structure_search,
+        &mut sheet_writers.structure_search,
+    );
 
     // Render central panel (main content)
     egui::CentralPanel::default().show(ctx, |ui| {
         // Prevent general back navigation (ESC) if inside AI Review
         let is_ai_reviewing = state.ai_mode == AiModeState::Reviewing;
 
-        if !is_ai_reviewing && keys.just_pressed(KeyCode::Escape) {
+        crate::ui::elements::editor::editor_sheet_display::display_controls::handle_quick_add_row_shortcut(
+            ui.ctx(),
+            &state,
+            &mut sheet_writers.add_row,
+        );
+        crate::ui::elements::editor::editor_sheet_display::display_controls::handle_undo_redo_shortcuts(
+            ui.ctx(),
+            &misc.registry,
+            &mut misc.edit_history,
+            &mut sheet_writers.cell_update,
+            &mut sheet_writers.feedback,
+        );
+
+        if !is_ai_reviewing && !overlay_open_at_frame_start && keys.just_pressed(KeyCode::Escape) {
             if let Some(nav_ctx) = state.structure_navigation_stack.pop() {
                 // Navigate back to parent sheet in real navigation
                 state.selected_category = nav_ctx.parent_category;
@@ -221,6 +292,27 @@ pub fn generic_sheet_editor_ui(
         if !(state.current_interaction_mode == SheetInteractionState::AiModeActive
             && state.ai_mode == AiModeState::Reviewing)
         {
+            block_paste::intercept_block_paste(
+                ctx,
+                &mut state,
+                &misc.registry,
+                &mut sheet_writers.paste_block,
+            );
+            grid_navigation::handle_grid_keyboard_navigation(ctx, &mut state, &misc.registry);
+            // Ctrl+Shift+H hides the column of the focused cell
+            if ctx.input_mut(|i| {
+                i.consume_key(egui::Modifiers::COMMAND | egui::Modifiers::SHIFT, egui::Key::H)
+            }) {
+                if let Some((cat, sheet, _, col_index, _)) = state.grid_active_cell.clone() {
+                    if cat == state.selected_category
+                        && state.selected_sheet_name.as_deref() == Some(sheet.as_str())
+                    {
+                        state.pending_column_hidden_toggle = Some(col_index);
+                        // The focused cell is about to disappear with its column
+                        state.grid_active_cell = None;
+                    }
+                }
+            }
             editor_sheet_display::show_sheet_table(
                 ui,
                 ctx,
@@ -241,6 +333,53 @@ pub fn generic_sheet_editor_ui(
                 sheet_writers.paste_cell,
                 &misc.clipboard_buffer,
             );
+            let width_updates = std::mem::take(&mut state.pending_column_width_updates);
+            let sort_request = state.pending_sort_request.take();
+            let row_lock = state.pending_row_lock.take();
+            let row_duplicate = state.pending_row_duplicate.take();
+            let column_hidden_toggle = state.pending_column_hidden_toggle.take();
+            if let Some(sheet_name) = &current_sheet_name_clone {
+                if let Some((row_index, locked)) = row_lock {
+                    sheet_writers.set_row_lock.write(crate::sheets::events::SetRowLockEvent {
+                        category: current_category_clone.clone(),
+                        sheet_name: sheet_name.clone(),
+                        row_index,
+                        locked,
+                    });
+                }
+                if let Some(row_index) = row_duplicate {
+                    sheet_writers.duplicate_row.write(crate::sheets::events::DuplicateRowEvent {
+                        category: current_category_clone.clone(),
+                        sheet_name: sheet_name.clone(),
+                        row_index,
+                    });
+                }
+                if let Some(col_index) = column_hidden_toggle {
+                    sheet_writers.toggle_column_hidden.write(
+                        crate::sheets::events::ToggleColumnHiddenEvent {
+                            category: current_category_clone.clone(),
+                            sheet_name: sheet_name.clone(),
+                            col_index,
+                        },
+                    );
+                }
+                if let Some((col_index, ascending)) = sort_request {
+                    sheet_writers.sort_rows.write(crate::sheets::events::SortSheetByColumnEvent {
+                        category: current_category_clone.clone(),
+                        sheet_name: sheet_name.clone(),
+                        col_index,
+                        ascending,
+                    });
+                }
+                for (column_header, new_width) in width_updates {
+                    sheet_writers.column_width.write(crate::sheets::events::RequestUpdateColumnWidth {
+                        category: current_category_clone.clone(),
+                        sheet_name: sheet_name.clone(),
+                        column_header,
+                        new_width,
+                    });
+                }
+            }
         } else {
             // Show review panel when in AI Reviewing state
             // The review panel is self-contained and shows all necessary context (ancestor keys, row data)
@@ -261,5 +400,23 @@ pub fn generic_sheet_editor_ui(
         // AI output bottom panel rendered after main content outside this closure
     });
 
+    // Per-row "Send this row to AI" requested from the cell context menu
+    crate::ui::elements::ai_review::ai_control_left_panel::process_single_row_ai_send(
+        &mut state,
+        &misc.registry,
+        &misc.session_api_key_res,
+        &misc.runtime,
+        &mut commands,
+        &mut misc.director_session,
+    );
+
+    // "Copy range to here" requested from the cell context menu
+    range_copy::process_range_copy(
+        ctx,
+        &mut state,
+        &misc.registry,
+        &mut sheet_writers.feedback,
+    );
+
     // (panels already drawn above CentralPanel)
 }
@@ -1,9 +1,15 @@
 // src/ui/elements/editor/mod.rs
 
 // Declare the submodules for the editor components
+pub mod block_paste;
+pub mod grid_navigation;
 pub mod main_editor;
+pub mod range_copy;
 pub mod state;
 pub mod structure_navigation;
+mod sheet_find_bar;
+mod structure_search_panel;
+mod structure_tree_panel;
 pub mod table_body;
 pub mod table_header; // This is now the orchestrator
 
@@ -2,7 +2,7 @@
 use bevy::log::{error, info, warn};
 use directories_next::ProjectDirs;
 use serde::{Deserialize, Serialize};
-use std::{fs, io, path::PathBuf};
+use std::{collections::BTreeSet, fs, io, path::PathBuf};
 
 const QUALIFIER: &str = "com";
 const ORGANIZATION: &str = "BevyAppOrg";
@@ -17,6 +17,14 @@ pub struct UiPrefs {
     pub sheet_picker_expanded: bool,
     #[serde(default)]
     pub ai_groups_expanded: bool,
+    #[serde(default)]
+    pub structure_tree_expanded: bool,
+    /// Sheets floated to the top of the sheet picker, as (category, sheet name)
+    #[serde(default)]
+    pub pinned_sheets: BTreeSet<(Option<String>, String)>,
+    /// Recently opened sheets, most recent first
+    #[serde(default)]
+    pub recent_sheets: Vec<(Option<String>, String)>,
 }
 
 fn get_prefs_path() -> io::Result<PathBuf> {
@@ -0,0 +1,104 @@
+// src/ui/elements/editor/range_copy.rs
+// Copying a block of visible cells (marked via the cell context menu) to the system clipboard
+
+use crate::sheets::{
+    events::SheetOperationFeedback,
+    resources::SheetRegistry,
+    systems::logic::clipboard::{a1_range_reference, rows_to_tsv},
+};
+use crate::ui::elements::editor::state::EditorWindowState;
+use crate::ui::elements::editor::table_body::get_filtered_row_indices_cached;
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+/// Resolve a pending "Copy range to here" request against the marked range start.
+///
+/// Rows and columns are taken in their visible order (current filters, hidden columns
+/// skipped), so the A1 reference matches the sheet as exported with a header row.
+pub fn process_range_copy(
+    ctx: &egui::Context,
+    state: &mut EditorWindowState,
+    registry: &SheetRegistry,
+    feedback: &mut EventWriter<SheetOperationFeedback>,
+) {
+    let Some((end_row, end_col)) = state.pending_range_copy.take() else {
+        return;
+    };
+    let Some((category, sheet_name, start_row, start_col)) = state.copy_range_anchor.clone() else {
+        return;
+    };
+    if state.selected_category != category
+        || state.selected_sheet_name.as_deref() != Some(sheet_name.as_str())
+    {
+        return;
+    }
+    let Some(sheet) = registry.get_sheet(&category, &sheet_name) else {
+        return;
+    };
+    let Some(metadata) = sheet.metadata.as_ref() else {
+        return;
+    };
+
+    let visible_columns = state.get_visible_column_indices(&category, &sheet_name, metadata);
+    let filtered_rows =
+        get_filtered_row_indices_cached(state, &category, &sheet_name, &sheet.grid, metadata);
+
+    let col_positions = (
+        visible_columns.iter().position(|&c| c == start_col),
+        visible_columns.iter().position(|&c| c == end_col),
+    );
+    let row_positions = (
+        filtered_rows.iter().position(|&r| r == start_row),
+        filtered_rows.iter().position(|&r| r == end_row),
+    );
+    let (
+        (Some(first_col), Some(last_col)),
+        (Some(first_row), Some(last_row)),
+    ) = (col_positions, row_positions)
+    else {
+        feedback.write(SheetOperationFeedback {
+            message: "Range start is no longer visible (filtered or hidden); mark it again."
+                .to_string(),
+            is_error: true,
+        });
+        return;
+    };
+
+    let (c0, c1) = (first_col.min(last_col), first_col.max(last_col));
+    let (r0, r1) = (first_row.min(last_row), first_row.max(last_row));
+    let reference = a1_range_reference(first_col, first_row, last_col, last_row);
+
+    let message = if state.copy_formula_reference {
+        ctx.copy_text(reference.clone());
+        format!("Copied reference {}", reference)
+    } else {
+        let rows: Vec<Vec<String>> = filtered_rows[r0..=r1]
+            .iter()
+            .map(|&row_idx| {
+                visible_columns[c0..=c1]
+                    .iter()
+                    .map(|&col_idx| {
+                        sheet
+                            .grid
+                            .get(row_idx)
+                            .and_then(|row| row.get(col_idx))
+                            .cloned()
+                            .unwrap_or_default()
+                    })
+                    .collect()
+            })
+            .collect();
+        ctx.copy_text(rows_to_tsv(&rows));
+        format!(
+            "Copied {}×{} cells ({})",
+            r1 - r0 + 1,
+            c1 - c0 + 1,
+            reference
+        )
+    };
+    info!("Range copy from '{:?}/{}': {}", category, sheet_name, message);
+    feedback.write(SheetOperationFeedback {
+        message,
+        is_error: false,
+    });
+}
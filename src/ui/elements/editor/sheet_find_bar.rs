@@ -0,0 +1,204 @@
+// src/ui/elements/editor/sheet_find_bar.rs
+use bevy_egui::egui;
+
+use crate::sheets::definitions::SheetMetadata;
+use crate::sheets::resources::{SheetRegistry, SheetRenderCache};
+use crate::sheets::systems::logic::sheet_find::{find_matches, step_find_match, FIND_DEBOUNCE_SECS};
+use crate::ui::elements::editor::state::EditorWindowState;
+use crate::ui::elements::editor::table_body::get_filtered_row_indices_cached;
+
+/// Find bar above the grid (Ctrl+F). Displayed cells containing the query are highlighted
+/// once typing pauses; Enter / Shift+Enter or the arrow buttons step through the matches.
+/// Must run before the table is drawn.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn show_sheet_find_bar(
+    ui: &mut egui::Ui,
+    state: &mut EditorWindowState,
+    registry: &SheetRegistry,
+    render_cache: &SheetRenderCache,
+    metadata: &SheetMetadata,
+    category: &Option<String>,
+    sheet_name: &str,
+    visible_columns: &[usize],
+) {
+    let shortcut = ui
+        .ctx()
+        .input_mut(|i| i.consume_key(egui::Modifiers::COMMAND, egui::Key::F));
+    if shortcut {
+        state.show_sheet_find_bar = true;
+    }
+    if !state.show_sheet_find_bar {
+        return;
+    }
+
+    let now = ui.ctx().input(|i| i.time);
+    apply_find_input(state, now, false);
+    refresh_find_matches(state, registry, render_cache, metadata, category, sheet_name, visible_columns);
+
+    let mut step: Option<bool> = None;
+    let mut close = false;
+    ui.horizontal(|ui| {
+        ui.label("Find:");
+        let input = ui.add(
+            egui::TextEdit::singleline(&mut state.sheet_find_input)
+                .hint_text(format!("Find in '{}'", sheet_name))
+                .desired_width(220.0),
+        );
+        if shortcut {
+            input.request_focus();
+        }
+        if input.changed() {
+            state.sheet_find_input_changed_at = Some(now);
+        }
+        if input.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+            step = Some(!ui.input(|i| i.modifiers.shift));
+            // Keep typing / pressing Enter without clicking back into the field
+            input.request_focus();
+        }
+        if ui
+            .checkbox(&mut state.sheet_find_case_sensitive, "Match case")
+            .changed()
+        {
+            state.sheet_find_scope = None;
+        }
+        let has_matches = !state.sheet_find_matches.is_empty();
+        if ui
+            .add_enabled(has_matches, egui::Button::new("⬆"))
+            .on_hover_text("Previous match (Shift+Enter)")
+            .clicked()
+        {
+            step = Some(false);
+        }
+        if ui
+            .add_enabled(has_matches, egui::Button::new("⬇"))
+            .on_hover_text("Next match (Enter)")
+            .clicked()
+        {
+            step = Some(true);
+        }
+        if !state.sheet_find_query.is_empty() {
+            let total = state.sheet_find_matches.len();
+            match state.sheet_find_current {
+                _ if total == 0 => {
+                    ui.colored_label(egui::Color32::LIGHT_RED, "No matches");
+                }
+                Some(pos) => {
+                    ui.label(format!("{} of {}", pos + 1, total));
+                }
+                None => {
+                    ui.label(format!("{} match(es)", total));
+                }
+            }
+        }
+        if ui.small_button("✖").on_hover_text("Close find").clicked() {
+            close = true;
+        }
+    });
+
+    if close {
+        state.show_sheet_find_bar = false;
+        state.sheet_find_input.clear();
+        state.sheet_find_query.clear();
+        state.sheet_find_input_changed_at = None;
+        state.sheet_find_matches.clear();
+        state.sheet_find_current = None;
+        state.sheet_find_scope = None;
+        return;
+    }
+
+    if let Some(forward) = step {
+        // Enter right after typing jumps without waiting for the pause
+        if state.sheet_find_input_changed_at.is_some() {
+            apply_find_input(state, now, true);
+            refresh_find_matches(state, registry, render_cache, metadata, category, sheet_name, visible_columns);
+        }
+        state.sheet_find_current =
+            step_find_match(state.sheet_find_current, state.sheet_find_matches.len(), forward);
+        if let Some(&(row, _)) = state
+            .sheet_find_current
+            .and_then(|pos| state.sheet_find_matches.get(pos))
+        {
+            state.pending_scroll_to_sheet_row = Some((category.clone(), sheet_name.to_string(), row));
+        }
+    }
+
+    if let Some(changed_at) = state.sheet_find_input_changed_at {
+        let remaining = (FIND_DEBOUNCE_SECS - (now - changed_at)).max(0.0);
+        ui.ctx()
+            .request_repaint_after(std::time::Duration::from_secs_f64(remaining));
+    }
+}
+
+/// Copy the typed text into the applied query once typing has paused (or right away when
+/// `force` is set or the field was cleared, so clearing removes the highlights at once)
+fn apply_find_input(state: &mut EditorWindowState, now: f64, force: bool) {
+    let Some(changed_at) = state.sheet_find_input_changed_at else {
+        return;
+    };
+    if !force && !state.sheet_find_input.is_empty() && now - changed_at < FIND_DEBOUNCE_SECS {
+        return;
+    }
+    state.sheet_find_input_changed_at = None;
+    if state.sheet_find_query != state.sheet_find_input {
+        state.sheet_find_query = state.sheet_find_input.clone();
+        state.sheet_find_current = None;
+        state.sheet_find_scope = None;
+    }
+}
+
+/// Recollect the matches when the query, the sheet, its visible columns, its filters or its
+/// data changed since they were last collected
+fn refresh_find_matches(
+    state: &mut EditorWindowState,
+    registry: &SheetRegistry,
+    render_cache: &SheetRenderCache,
+    metadata: &SheetMetadata,
+    category: &Option<String>,
+    sheet_name: &str,
+    visible_columns: &[usize],
+) {
+    let scope = (category.clone(), sheet_name.to_string(), visible_columns.to_vec());
+    if !state.force_filter_recalculation && state.sheet_find_scope.as_ref() == Some(&scope) {
+        return;
+    }
+    let same_sheet = state
+        .sheet_find_scope
+        .as_ref()
+        .is_some_and(|(cat, name, _)| cat == category && name == sheet_name);
+    let matches = match registry.get_sheet(category, sheet_name) {
+        Some(sheet) if !state.sheet_find_query.is_empty() => {
+            let rows = get_filtered_row_indices_cached(state, category, sheet_name, &sheet.grid, metadata);
+            // Technical columns are read-only labels that are never highlighted
+            let columns: Vec<usize> = visible_columns
+                .iter()
+                .copied()
+                .filter(|&c| {
+                    metadata
+                        .columns
+                        .get(c)
+                        .is_some_and(|col| !SheetMetadata::is_technical_column_header(&col.header))
+                })
+                .collect();
+            find_matches(
+                &rows,
+                &columns,
+                &state.sheet_find_query,
+                state.sheet_find_case_sensitive,
+                |row, col| {
+                    render_cache
+                        .get_cell_data(category, sheet_name, row, col)
+                        .map(|data| data.display_text.as_str())
+                        .or_else(|| sheet.grid.get(row).and_then(|r| r.get(col)).map(String::as_str))
+                        .unwrap_or("")
+                },
+            )
+        }
+        _ => Vec::new(),
+    };
+    if !same_sheet {
+        state.sheet_find_current = None;
+    }
+    state.sheet_find_current = state.sheet_find_current.filter(|&pos| pos < matches.len());
+    state.sheet_find_matches = matches;
+    state.sheet_find_scope = Some(scope);
+}
@@ -38,6 +38,42 @@ pub struct AiCallLogEntry {
     pub is_error: bool,
 }
 
+/// Result of an AI dry run: the first request of a send, built without calling the model
+#[derive(Debug, Clone)]
+pub struct AiPayloadPreview {
+    pub table_name: String,
+    /// Pretty-printed payload exactly as it would be handed to the Python processor
+    pub payload_json: String,
+    /// Number of requests the root step is split into by the width limit and character budget
+    pub request_parts: usize,
+    /// Structure columns whose child tables are queued once the first response arrives
+    pub child_structures: Vec<String>,
+}
+
+/// What an AI token estimate was computed for; recomputed when any of it changes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AiTokenEstimateKey {
+    pub category: Option<String>,
+    pub sheet_name: String,
+    /// Selected rows, sorted
+    pub rows: Vec<usize>,
+    pub row_count: usize,
+    pub schema_group: Option<String>,
+    pub width_limit: usize,
+    pub char_budget: usize,
+}
+
+/// Estimated size of the root requests a send would make (children are not included;
+/// their payloads depend on the first response)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AiTokenEstimate {
+    /// All root requests together
+    pub total_tokens: usize,
+    /// The biggest single request, which is what has to fit the model's context window
+    pub largest_request_tokens: usize,
+    pub request_parts: usize,
+}
+
 /// Batch processing context - stored when processing AI results
 /// Fields are retained for debugging and future use even if not currently accessed
 #[allow(dead_code)]
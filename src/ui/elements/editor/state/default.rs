@@ -3,7 +3,7 @@
 
 use super::state_definitions::*;
 use crate::sheets::definitions::ColumnDataType;
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
 
 impl Default for EditorWindowState {
     fn default() -> Self {
@@ -18,18 +18,51 @@ impl Default for EditorWindowState {
             show_delete_confirm_popup: false,
             delete_target_category: None,
             delete_target_sheet: String::new(),
+            reload_confirm_target: None,
+            pending_sheet_reload: None,
+            revert_json_confirm_target: None,
+            duplicate_sheet_target: None,
+            duplicate_sheet_name_input: String::new(),
+            project_import_prompt: None,
+            cell_comment_target: None,
+            cell_comment_input: String::new(),
+            cell_comments: HashMap::new(),
+            cell_comments_sheet: None,
+            header_rename_target: None,
+            header_rename_find: String::new(),
+            header_rename_replace: String::new(),
+            header_rename_prefix: String::new(),
+            header_rename_suffix: String::new(),
+            header_rename_excluded: HashSet::new(),
+            find_replace_target: None,
+            find_replace_find: String::new(),
+            find_replace_replace: String::new(),
+            find_replace_case_sensitive: false,
+            find_replace_whole_cell: false,
             show_column_options_popup: false,
             options_column_target_category: None,
             options_column_target_sheet: String::new(),
             options_column_target_index: 0,
             column_options_popup_needs_init: false,
             options_column_rename_input: String::new(),
+            options_column_display_name_input: String::new(),
             options_column_filter_input: String::new(),
             options_column_filter_terms: vec![String::new()],
             options_column_ai_context_input: String::new(),
             options_column_hidden_input: false,
+            options_column_trim_zeros_input: false,
+            options_column_ai_read_only_input: false,
+            options_column_multiline_input: false,
+            options_column_range_min_input: String::new(),
+            options_column_range_max_input: String::new(),
+            options_column_default_value_input: String::new(),
             options_validator_type: None,
             options_basic_type_select: ColumnDataType::String,
+            options_unique_case_sensitive: false,
+            options_unique_scan_result: None,
+            options_enum_values: Vec::new(),
+            options_enum_new_value_input: String::new(),
+            options_column_enum_auto_add_input: false,
             options_link_target_sheet: None,
             options_link_target_column_index: None,
             options_structure_source_columns: vec![None],
@@ -47,9 +80,18 @@ impl Default for EditorWindowState {
             show_delete_category_double_confirm_popup: false,
             ai_mode: AiModeState::Idle,
             ai_selected_rows: HashSet::new(),
+            row_selection_anchor: None,
+            pending_select_all_filtered: false,
+            pending_single_row_ai_send: None,
+            copy_range_anchor: None,
+            pending_range_copy: None,
+            grid_active_cell: None,
+            active_cell: None,
+            pending_active_cell_focus: false,
             ai_batch_review_active: false,
             ai_row_reviews: Vec::new(),
             ai_new_row_reviews: Vec::new(),
+            ai_malformed_row_notes: Vec::new(),
             ai_structure_reviews: Vec::new(),
             ai_structure_new_row_contexts: HashMap::new(),
             ai_structure_new_row_token_counter: 0,
@@ -67,6 +109,8 @@ impl Default for EditorWindowState {
 
             ai_call_log: Vec::new(),
             ai_raw_output_display: String::new(),
+            ai_payload_preview: None,
+            ai_token_estimate: None,
             ai_output_panel_visible: false,
             ai_group_add_popup_open: false,
             ai_group_add_name_input: String::new(),
@@ -92,25 +136,45 @@ impl Default for EditorWindowState {
             ai_needs_structure_child_tables_loaded: false,
             ai_total_tasks: 0,
             ai_completed_tasks: 0,
+            show_quick_open: false,
+            quick_open_query: String::new(),
+            quick_open_highlight: 0,
+            show_command_palette: false,
+            command_palette_query: String::new(),
+            command_palette_highlight: 0,
             show_settings_popup: false,
             settings_new_api_key_input: String::new(),
+            settings_api_key_validating: false,
             was_settings_popup_open: false,
             show_ai_rule_popup: false,
             ai_rule_popup_needs_init: false,
             ai_rule_popup_last_category: None,
             ai_rule_popup_last_sheet: None,
             ai_rule_popup_grounding: None,
+            ai_rule_popup_key_column: None,
+            ai_rule_popup_temperature: None,
+            ai_rule_popup_custom_model: false,
             filtered_row_indices_cache: HashMap::new(),
+            filter_regex_cache: Default::default(),
             force_filter_recalculation: false,
             request_scroll_to_new_row: false,
+            new_row_grid_index: 0,
             force_cache_reload: false,
             scroll_to_row_index: None,
+            scroll_to_active_row: None,
+            pending_new_row_focus: None,
+            pending_scroll_to_sheet_row: None,
             parent_lineage_cache: HashMap::new(),
             category_needs_table_list_load: false,
             sheet_is_loading: false,
             current_interaction_mode: SheetInteractionState::Idle,
             selected_columns_for_deletion: HashSet::new(),
             column_drag_state: ColumnDragState::default(),
+            pending_column_width_updates: Vec::new(),
+            pending_sort_request: None,
+            pending_row_lock: None,
+            pending_row_duplicate: None,
+            pending_column_hidden_toggle: None,
             dragged_sheet: None,
 
             show_random_picker_panel: false,
@@ -122,16 +186,24 @@ impl Default for EditorWindowState {
             random_picker_weight_columns: vec![None],
             random_picker_weight_exponents: vec![1.0],
             random_picker_weight_multipliers: vec![1.0],
+            random_picker_preview_count: 10,
+            random_picker_draw_count: 1,
+            random_picker_seed: None,
+            random_picker_seed_input: String::new(),
+            random_picker_rng: None,
             random_picker_last_value: String::new(),
+            random_picker_excluded: HashMap::new(),
             random_picker_copy_status: String::new(),
             random_picker_needs_init: true,
             summarizer_selected_col: 0,
             summarizer_last_result: String::new(),
+            summarizer_stat: SummarizerStat::default(),
             summarizer_copy_status: String::new(),
             summarizer_selected_columns: vec![None],
             pending_validator_change_requires_confirmation: false,
             pending_validator_new_validator_summary: None,
             pending_validator_target_is_structure: false,
+            pending_structure_removal_preview: None,
             options_structure_key_parent_column_temp: None,
             options_existing_structure_key_parent_column: None,
             ai_context_only_prefix_count: 0,
@@ -140,19 +212,76 @@ impl Default for EditorWindowState {
             category_picker_expanded: true,
             sheet_picker_expanded: true,
             ai_groups_expanded: true,
+            structure_tree_expanded: false,
+            show_structure_search_panel: false,
+            structure_search_input: String::new(),
+            structure_search_case_sensitive: false,
+            structure_search_base_stack: Vec::new(),
+            structure_search_highlight: None,
+            show_sheet_find_bar: false,
+            sheet_find_input: String::new(),
+            sheet_find_query: String::new(),
+            sheet_find_input_changed_at: None,
+            sheet_find_case_sensitive: false,
+            sheet_find_matches: Vec::new(),
+            sheet_find_current: None,
+            sheet_find_scope: None,
+            pinned_sheets: BTreeSet::new(),
+            recent_sheets: Vec::new(),
             show_edit_mode_panel: false,
             last_ai_button_min_x: 0.0,
             last_edit_mode_button_min_x: 0.0,
             last_toybox_button_min_x: 0.0,
             show_toybox_menu: false,
             toybox_mode: ToyboxMode::Randomizer,
+            toybox_context_by_sheet: HashMap::new(),
             fps_setting: FpsSetting::default(),
             show_hidden_sheets: false,
+            copy_formula_reference: false,
+            show_empty_cell_placeholder: false,
+            show_technical_columns: false,
+            structure_table_naming: StructureTableNaming::default(),
+            collapse_structure_columns: false,
+            frozen_column_count: 0,
+            frozen_scroll_offset_y: 0.0,
             ai_depth_limit: 2,
             ai_width_limit: 32,
+            ai_char_budget: 24_000,
+            ai_max_retries: 3,
+            ai_retry_backoff_ms: 2_000,
+            ai_min_request_interval_ms: 1_000,
+            ai_token_warning_threshold: 800_000,
+            ai_features_enabled: true,
+            ai_script_path: None,
+            ai_script_path_input: String::new(),
+            ai_script_path_error: None,
+            ai_runtime_error: None,
+            show_ai_runtime_notice: false,
+            ai_usage: Default::default(),
+            ai_duplicate_fuzzy: false,
+            ai_duplicate_similarity: 0.85,
+            max_loaded_sheets: 12,
+            sql_console_enabled: false,
+            window_geometry: None,
+            always_on_top: false,
+            data_directory: None,
+            pending_data_directory_change: None,
+            data_directory_candidate: None,
+            data_directory_error: None,
+            show_sql_console: false,
+            sql_console_category: None,
+            sql_console_input: String::new(),
+            sql_console_result: None,
+            show_compare_sheets_popup: false,
+            sheet_compare: SheetCompareState::default(),
+            merge_rows: None,
+            delete_columns_confirm: None,
+            long_text_editor: None,
             ai_throttled_apply_queue: VecDeque::new(),
             ai_throttled_batch_add_queue: VecDeque::new(),
             ai_batch_has_undecided_merge: false,
+            ai_review_tally: ReviewDecisionTally::default(),
+            ai_review_only_changed: false,
             show_ai_prompt_popup: false,
             ai_prompt_input: String::new(),
             last_ai_prompt_only: false,
@@ -52,3 +52,10 @@ pub struct NewRowReview {
     /// Orphaned rows should have their ancestor text rendered in red for re-parenting
     pub is_orphan: bool,
 }
+
+/// Rows accepted/declined so far in the current AI review (decided rows leave the review lists)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReviewDecisionTally {
+    pub accepted: usize,
+    pub rejected: usize,
+}
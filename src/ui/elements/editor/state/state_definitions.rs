@@ -9,7 +9,10 @@ pub use super::ui_types::*;
 
 use crate::sheets::definitions::ColumnDataType;
 use bevy::prelude::Resource;
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+
+/// Number of sheets kept in the bottom panel's recently opened list
+pub const RECENT_SHEETS_LIMIT: usize = 8;
 
 #[derive(Resource)]
 pub struct EditorWindowState {
@@ -31,20 +34,78 @@ pub struct EditorWindowState {
     pub delete_target_category: Option<String>,
     pub delete_target_sheet: String,
 
+    /// Sheet awaiting confirmation of "Reload from Database" (popup shown while Some)
+    pub reload_confirm_target: Option<(Option<String>, String)>,
+    /// Confirmed reload, processed where the registry is mutable
+    pub pending_sheet_reload: Option<(Option<String>, String)>,
+    /// Sheet awaiting confirmation of "Revert to Saved JSON" (popup shown while Some)
+    pub revert_json_confirm_target: Option<(Option<String>, String)>,
+
+    /// Sheet being duplicated (name popup shown while Some)
+    pub duplicate_sheet_target: Option<(Option<String>, String)>,
+    pub duplicate_sheet_name_input: String,
+
+    /// Project archive awaiting a collision decision, with the "category/table" names it would replace
+    pub project_import_prompt: Option<(std::path::PathBuf, Vec<String>)>,
+
+    /// Cell whose comment is being edited: (category, sheet, grid row, column); popup shown while Some
+    pub cell_comment_target: Option<(Option<String>, String, usize, usize)>,
+    pub cell_comment_input: String,
+    /// Comments of `cell_comments_sheet` keyed by (DB row_index, column header)
+    pub cell_comments: HashMap<(i64, String), String>,
+    /// Sheet that `cell_comments` was loaded for; cleared to force a reload
+    pub cell_comments_sheet: Option<(Option<String>, String)>,
+
+    /// Sheet whose column display names are being batch-renamed (popup shown while Some)
+    pub header_rename_target: Option<(Option<String>, String)>,
+    pub header_rename_find: String,
+    pub header_rename_replace: String,
+    pub header_rename_prefix: String,
+    pub header_rename_suffix: String,
+    /// Column indices unticked in the batch rename preview
+    pub header_rename_excluded: HashSet<usize>,
+    /// Column targeted by the find & replace popup (category, sheet, column index)
+    pub find_replace_target: Option<(Option<String>, String, usize)>,
+    pub find_replace_find: String,
+    pub find_replace_replace: String,
+    pub find_replace_case_sensitive: bool,
+    pub find_replace_whole_cell: bool,
+
     pub show_column_options_popup: bool,
     pub options_column_target_category: Option<String>,
     pub options_column_target_sheet: String,
     pub options_column_target_index: usize,
     pub column_options_popup_needs_init: bool,
     pub options_column_rename_input: String,
+    /// UI-only display name; empty means "use the physical column name"
+    pub options_column_display_name_input: String,
     pub options_column_filter_input: String,
     // Multi-term OR filter terms (each term 'contains' OR). Joined when stored.
     pub options_column_filter_terms: Vec<String>,
     pub options_column_ai_context_input: String,
     /// Ephemeral hidden checkbox state for Column Options popup
     pub options_column_hidden_input: bool,
+    /// Ephemeral "trim trailing zeros" checkbox state (F64 columns only)
+    pub options_column_trim_zeros_input: bool,
+    pub options_column_ai_read_only_input: bool,
+    pub options_column_multiline_input: bool,
+    /// Ephemeral lower/upper bound inputs (numeric columns only); blank = open-ended
+    pub options_column_range_min_input: String,
+    pub options_column_range_max_input: String,
+    /// New-row default typed in the column options popup (blank = none)
+    pub options_column_default_value_input: String,
     pub options_validator_type: Option<ValidatorTypeChoice>,
     pub options_basic_type_select: ColumnDataType,
+    /// "Case sensitive" checkbox for the Unique validator (text columns only)
+    pub options_unique_case_sensitive: bool,
+    /// Result of the last "Scan for duplicates": (value, grid rows) per duplicated value
+    pub options_unique_scan_result: Option<Vec<(String, Vec<usize>)>>,
+    /// Allowed values being edited for the Enum validator, in dropdown order
+    pub options_enum_values: Vec<String>,
+    /// Text of the "add allowed value" field of the Enum validator
+    pub options_enum_new_value_input: String,
+    /// "Add unknown values" checkbox of the Enum validator
+    pub options_column_enum_auto_add_input: bool,
     pub options_link_target_sheet: Option<String>,
     pub options_link_target_column_index: Option<usize>,
     // NEW: Structure selection chain (always at least length 1 with possibly None meaning no selection yet)
@@ -72,10 +133,29 @@ pub struct EditorWindowState {
     // AI Mode specific state
     pub ai_mode: AiModeState,
     pub ai_selected_rows: HashSet<usize>,
+    /// Row of the last non-Shift checkbox click; Shift+Click selects from here
+    pub row_selection_anchor: Option<usize>,
+    /// "Select all filtered" asked for outside the grid; applied when the rows are drawn
+    pub pending_select_all_filtered: bool,
+    /// Row requested via the cell context menu "Send this row to AI"; consumed next frame
+    pub pending_single_row_ai_send: Option<usize>,
+    /// Range start marked via the cell context menu: (category, sheet, row_index, col_index)
+    pub copy_range_anchor: Option<(Option<String>, String, usize, usize)>,
+    /// Range end (row_index, col_index) chosen via "Copy range to here"; consumed after rendering
+    pub pending_range_copy: Option<(usize, usize)>,
+    /// Grid cell whose editor has keyboard focus: (category, sheet, row_index, col_index, widget id).
+    /// A multi-cell Ctrl+V while it is focused becomes a block paste.
+    pub grid_active_cell: Option<(Option<String>, String, usize, usize, bevy_egui::egui::Id)>,
+    /// Keyboard cursor of the displayed sheet: (row_index, col_index)
+    pub active_cell: Option<(usize, usize)>,
+    /// Focus `active_cell` once it is drawn (set by arrow/Tab/Enter navigation)
+    pub pending_active_cell_focus: bool,
     pub ai_batch_review_active: bool, // unified batch review flag
     // Unified snapshot model
     pub ai_row_reviews: Vec<RowReview>,
     pub ai_new_row_reviews: Vec<NewRowReview>,
+    /// AI rows returned with the wrong column count; listed as "needs attention" and never applied
+    pub ai_malformed_row_notes: Vec<String>,
     pub ai_structure_reviews: Vec<StructureReviewEntry>,
     pub ai_structure_new_row_contexts: HashMap<usize, StructureNewRowContext>,
     pub ai_structure_new_row_token_counter: usize,
@@ -105,6 +185,10 @@ pub struct EditorWindowState {
     pub ai_call_log: Vec<AiCallLogEntry>,
     // Removed dedicated structure detail view; field deleted.
     pub ai_raw_output_display: String,
+    /// Payload shown by the AI "Preview payload" dry run (None = window closed)
+    pub ai_payload_preview: Option<AiPayloadPreview>,
+    /// Cached token estimate of the current AI selection and what it was computed for
+    pub ai_token_estimate: Option<(AiTokenEstimateKey, Result<AiTokenEstimate, String>)>,
     // Bottom AI output panel visibility & context tracking
     pub ai_output_panel_visible: bool,
     pub ai_group_add_popup_open: bool,
@@ -136,9 +220,23 @@ pub struct EditorWindowState {
     /// Completed AI tasks for progress tracking
     pub ai_completed_tasks: usize,
 
+    // Quick open (Ctrl+P): fuzzy jump to any sheet
+    pub show_quick_open: bool,
+    pub quick_open_query: String,
+    /// Highlighted position in the ranked result list
+    pub quick_open_highlight: usize,
+
+    // Command palette (Ctrl+Shift+P): fuzzy-run a named action on the selected sheet
+    pub show_command_palette: bool,
+    pub command_palette_query: String,
+    /// Highlighted position in the ranked action list
+    pub command_palette_highlight: usize,
+
     // General Settings Popup
     pub show_settings_popup: bool,
     pub settings_new_api_key_input: String,
+    /// A new API key is being validated before it replaces the stored one
+    pub settings_api_key_validating: bool,
     pub was_settings_popup_open: bool, // Tracks previous state of settings popup
 
     // AI Rule (per-sheet AI Context) Popup state
@@ -148,14 +246,30 @@ pub struct EditorWindowState {
     pub ai_rule_popup_last_sheet: Option<String>,
     // Grounding toggle value while the AI Context popup is open
     pub ai_rule_popup_grounding: Option<bool>,
+    /// AI key/display column chosen in the AI rule popup (None = default first data column)
+    pub ai_rule_popup_key_column: Option<usize>,
+    /// AI temperature chosen in the AI rule popup (None = model default)
+    pub ai_rule_popup_temperature: Option<f32>,
+    /// AI rule popup shows the free-text model field instead of the known-model dropdown
+    pub ai_rule_popup_custom_model: bool,
 
     // Table rendering helpers
     pub filtered_row_indices_cache: HashMap<(Option<String>, String), FilteredRowsCacheEntry>,
+    /// Compiled `re:` column filters, shared by row filtering and the column options popup
+    pub filter_regex_cache: crate::sheets::systems::logic::column_filter::FilterRegexCache,
     pub force_filter_recalculation: bool,
     pub request_scroll_to_new_row: bool,
+    /// Grid row of the row just added (0 unless it went below the last row)
+    pub new_row_grid_index: usize,
     /// Flag to trigger cache reload from DB when switching sheets
     pub force_cache_reload: bool,
     pub scroll_to_row_index: Option<usize>,
+    /// Displayed row position to bring into view with minimal scrolling (keyboard navigation)
+    pub scroll_to_active_row: Option<usize>,
+    /// Text cell of a freshly added row to focus once rendered: (category, sheet, grid row, column)
+    pub pending_new_row_focus: Option<(Option<String>, String, usize, usize)>,
+    /// Grid row to bring into view once the given sheet is displayed (e.g. after jumping to a structure parent)
+    pub pending_scroll_to_sheet_row: Option<(Option<String>, String, usize)>,
     
     /// Parent lineage cache: (category, sheet_name, row_index) -> Vec<(table_name, display_value, row_index)>
     /// Cleared when switching databases or categories to ensure fresh data
@@ -171,6 +285,21 @@ pub struct EditorWindowState {
     pub current_interaction_mode: SheetInteractionState,
     pub selected_columns_for_deletion: HashSet<usize>,
     pub column_drag_state: ColumnDragState,
+    /// Header resizes detected this frame as (physical column name, width); sent as
+    /// `RequestUpdateColumnWidth` by the main editor after the table is drawn
+    pub pending_column_width_updates: Vec<(String, f32)>,
+    /// Sort chosen from a header's context menu as (column index, ascending); sent as
+    /// `SortSheetByColumnEvent` by the main editor after the table is drawn
+    pub pending_sort_request: Option<(usize, bool)>,
+    /// Lock change chosen from a cell's context menu as (grid row, locked); sent as
+    /// `SetRowLockEvent` by the main editor after the table is drawn
+    pub pending_row_lock: Option<(usize, bool)>,
+    /// Grid row chosen for "Clone row" in a cell's context menu; sent as
+    /// `DuplicateRowEvent` by the main editor after the table is drawn
+    pub pending_row_duplicate: Option<usize>,
+    /// Column whose eye icon was clicked (or Ctrl+Shift+H); sent as
+    /// `ToggleColumnHiddenEvent` by the main editor after the table is drawn
+    pub pending_column_hidden_toggle: Option<usize>,
     // Drag-and-drop of sheets between categories
     pub dragged_sheet: Option<(Option<String>, String)>,
 
@@ -187,7 +316,20 @@ pub struct EditorWindowState {
     pub random_picker_weight_exponents: Vec<f64>,
     // Parallel vector storing per-weight-column multipliers (applied before exponentiation)
     pub random_picker_weight_multipliers: Vec<f64>,
+    /// How many of the heaviest rows the picker popup's probability preview lists
+    pub random_picker_preview_count: usize,
+    /// How many distinct values one Refresh draws
+    pub random_picker_draw_count: usize,
+    /// Seed for reproducible draws (None = thread RNG) and the seed field's text
+    pub random_picker_seed: Option<u64>,
+    pub random_picker_seed_input: String,
+    /// Seeded RNG advanced by every Refresh; dropped to restart the sequence
+    pub random_picker_rng: Option<rand::rngs::StdRng>,
+    /// Last drawn value(s), newline-separated when more than one was drawn
     pub random_picker_last_value: String,
+    /// Rows already drawn per sheet (see `random_picker::row_pick_key`); Refresh skips them
+    /// until "Reset picks"
+    pub random_picker_excluded: HashMap<(Option<String>, String), HashSet<i64>>,
     // Transient copy status shown after user clicks to copy the value
     pub random_picker_copy_status: String,
     // Ensure RP UI initializes once per selection (also on app startup)
@@ -195,7 +337,8 @@ pub struct EditorWindowState {
 
     // NEW: Summarizer UI state (per-session, not persisted yet)
     pub summarizer_selected_col: usize,
-    pub summarizer_last_result: String, // Prefixed with Sum:/Count:
+    pub summarizer_last_result: String, // Prefixed with the stat label, e.g. "Mean:"
+    pub summarizer_stat: SummarizerStat,
     // Transient copy status for summarizer result
     pub summarizer_copy_status: String,
     // Multiple selected columns for Summarizer when edited in the shared popup
@@ -203,6 +346,7 @@ pub struct EditorWindowState {
     pub pending_validator_change_requires_confirmation: bool,
     pub pending_validator_new_validator_summary: Option<String>,
     pub pending_validator_target_is_structure: bool,
+    pub pending_structure_removal_preview: Option<StructureRemovalPreview>,
     // Key Column (context-only) selection ephemeral states
     pub options_structure_key_parent_column_temp: Option<usize>, // during initial creation
     pub options_existing_structure_key_parent_column: Option<usize>, // editing existing structure
@@ -213,6 +357,34 @@ pub struct EditorWindowState {
     pub category_picker_expanded: bool,
     pub sheet_picker_expanded: bool,
     pub ai_groups_expanded: bool,
+    /// Structure tree side panel visible (persisted in UI prefs)
+    pub structure_tree_expanded: bool,
+    /// Structure-aware search side panel (sheet plus its child tables)
+    pub show_structure_search_panel: bool,
+    pub structure_search_input: String,
+    pub structure_search_case_sensitive: bool,
+    /// Navigation stack when the search started; hits in child tables are pushed on top of it
+    pub structure_search_base_stack: Vec<StructureNavigationContext>,
+    /// Cell of the last opened search hit, outlined in the grid: (category, sheet, row, col)
+    pub structure_search_highlight: Option<(Option<String>, String, usize, usize)>,
+    /// In-sheet find bar (Ctrl+F): cells of the selected sheet containing the query are highlighted
+    pub show_sheet_find_bar: bool,
+    /// Find text as typed; copied to `sheet_find_query` once typing pauses
+    pub sheet_find_input: String,
+    /// Query the highlights use (empty: nothing highlighted)
+    pub sheet_find_query: String,
+    /// Time (egui seconds) of the latest edit of `sheet_find_input` not applied yet
+    pub sheet_find_input_changed_at: Option<f64>,
+    pub sheet_find_case_sensitive: bool,
+    /// Matching cells (grid row, column) in display order, and the one navigated to
+    pub sheet_find_matches: Vec<(usize, usize)>,
+    pub sheet_find_current: Option<usize>,
+    /// Sheet and visible columns the matches were collected for; `None` recollects them
+    pub sheet_find_scope: Option<(Option<String>, String, Vec<usize>)>,
+    /// Personal quick-access pins (category, sheet name); persisted in UI prefs
+    pub pinned_sheets: BTreeSet<(Option<String>, String)>,
+    /// Recently opened sheets (category, sheet name), most recent first; persisted in UI prefs
+    pub recent_sheets: Vec<(Option<String>, String)>,
     pub show_edit_mode_panel: bool,
 
     // UI alignment helpers (not persisted): store x positions where toggles were placed
@@ -222,16 +394,94 @@ pub struct EditorWindowState {
     // Toybox (container for Random Picker + Summarizer)
     pub show_toybox_menu: bool,
     pub toybox_mode: ToyboxMode,
+    /// Toybox mode and last results per (category, sheet), restored on sheet switch
+    pub toybox_context_by_sheet: HashMap<(Option<String>, String), ToyboxSheetContext>,
     // App-wide FPS setting controlled from Settings popup
     pub fps_setting: FpsSetting,
     pub show_hidden_sheets: bool,
+    /// Range copies put an A1 reference (e.g. "B2:D10") on the clipboard instead of the values
+    pub copy_formula_reference: bool,
+    /// Empty text cells show a faint dash (display only, hidden while typing)
+    pub show_empty_cell_placeholder: bool,
+    /// Developer view: show row_index/parent_key columns in the grid as read-only labels
+    pub show_technical_columns: bool,
+    /// Renaming scheme for new structure columns whose child table name is already taken
+    pub structure_table_naming: StructureTableNaming,
+    /// View-only: hide all structure columns from the grid (does not touch their `hidden` flag)
+    pub collapse_structure_columns: bool,
+    /// View-only: how many leading visible columns stay pinned while scrolling horizontally
+    pub frozen_column_count: usize,
+    /// Vertical scroll offset shared by the frozen and scrolling table regions
+    pub frozen_scroll_offset_y: f32,
     /// AI depth limit: how many levels of structure tables to process (default: 2)
     pub ai_depth_limit: usize,
     /// AI width limit: how many rows to send in one batch (default: 32)
     pub ai_width_limit: usize,
+    /// Character budget per AI batch, summed over the cells sent (0 = rows only; default: 24000)
+    pub ai_char_budget: usize,
+    /// Retries of an AI request failing with a rate limit, server error or timeout (default: 3)
+    pub ai_max_retries: u32,
+    /// Wait before the first retry in milliseconds, doubled per retry (default: 2000)
+    pub ai_retry_backoff_ms: u64,
+    /// Minimum milliseconds between the starts of two AI requests (default: 1000)
+    pub ai_min_request_interval_ms: u64,
+    /// Estimated tokens per request above which the estimate is shown as a warning (default: 800000)
+    pub ai_token_warning_threshold: usize,
+    /// When false, AI Mode and the API key controls are hidden (persisted in AppSettings)
+    pub ai_features_enabled: bool,
+    /// Configured location of `ai_processor.py`; `None` = next to the executable (persisted in AppSettings)
+    pub ai_script_path: Option<std::path::PathBuf>,
+    /// Text of the script path field in Settings (applied with its button)
+    pub ai_script_path_input: String,
+    /// Why the script could not be written to the chosen path; not persisted
+    pub ai_script_path_error: Option<String>,
+    /// Why AI is unavailable this session (Python or packages missing); not persisted
+    pub ai_runtime_error: Option<String>,
+    /// One-time notice explaining what to install when `ai_runtime_error` is set at startup
+    pub show_ai_runtime_notice: bool,
+    /// Approximate AI usage per sheet; loaded at startup and saved after each AI step
+    pub ai_usage: crate::sheets::systems::ai::usage_ledger::AiUsageLedger,
+    /// Match AI-suggested rows against existing ones fuzzily (punctuation, spacing, small typos)
+    pub ai_duplicate_fuzzy: bool,
+    /// Minimum similarity (0.0..=1.0) for a fuzzy duplicate match
+    pub ai_duplicate_similarity: f32,
+    /// LRU cap on DB-backed sheets whose grid data stays in memory (0 = unlimited, default: 12)
+    pub max_loaded_sheets: usize,
+    /// Developer toggle for the read-only SQL console (persisted in AppSettings)
+    pub sql_console_enabled: bool,
+    pub show_sql_console: bool,
+    /// Last main window geometry (persisted in AppSettings, restored at startup)
+    pub window_geometry: Option<crate::settings::WindowGeometry>,
+    /// Main window stays above other windows (persisted in AppSettings)
+    pub always_on_top: bool,
+    /// Data folder used from the next start; `None` = Documents/SkylineDB (persisted in AppSettings)
+    pub data_directory: Option<std::path::PathBuf>,
+    /// Data folder switch waiting for the next start (persisted in AppSettings)
+    pub pending_data_directory_change: Option<crate::settings::PendingDataDirectoryChange>,
+    /// Folder picked in Settings and found writable, waiting for "move" or "start empty"
+    pub data_directory_candidate: Option<std::path::PathBuf>,
+    /// Why the picked data folder was rejected; not persisted
+    pub data_directory_error: Option<String>,
+    /// Category whose database the console queries (None = no database selected)
+    pub sql_console_category: Option<String>,
+    pub sql_console_input: String,
+    /// Last query result: header row followed by data rows, or the error text
+    pub sql_console_result: Option<Result<Vec<Vec<String>>, String>>,
+    pub show_compare_sheets_popup: bool,
+    pub sheet_compare: SheetCompareState,
+    /// Open "Merge rows" popup (None = closed)
+    pub merge_rows: Option<MergeRowsState>,
+    /// Open column delete confirmation (None = closed)
+    pub delete_columns_confirm: Option<DeleteColumnsConfirmState>,
+    /// Open multi-line editor of a long text cell (None = closed)
+    pub long_text_editor: Option<LongTextEditorState>,
     pub ai_throttled_apply_queue: VecDeque<ThrottledAiAction>,
     pub ai_throttled_batch_add_queue: VecDeque<(Option<String>, String, Vec<Vec<(usize, String)>>)>,
     pub ai_batch_has_undecided_merge: bool,
+    /// Accept/decline counts shown in the AI review summary bar
+    pub ai_review_tally: ReviewDecisionTally,
+    /// AI review hides existing rows the AI left unchanged
+    pub ai_review_only_changed: bool,
     pub show_ai_prompt_popup: bool,
     pub ai_prompt_input: String,
     pub last_ai_prompt_only: bool,
@@ -14,6 +14,11 @@ impl EditorWindowState {
         )
     }
 
+    /// AI features are switched on and the Python runtime is usable
+    pub fn ai_available(&self) -> bool {
+        self.ai_features_enabled && self.ai_runtime_error.is_none()
+    }
+
     /// Add a new AI call log entry at the head of the log (newest first)
     pub fn add_ai_call_log(
         &mut self,
@@ -201,6 +206,8 @@ impl EditorWindowState {
     /// Respects the 'hidden' flag on columns to hide technical columns
     /// For structure tables, technical columns (row_index at 0, parent_key at 1) are hidden by default
     /// When show_hidden_sheets is true, shows ALL columns including row_index
+    /// When show_technical_columns is true, hidden technical columns are shown (read-only)
+    /// When collapse_structure_columns is true, structure columns are left out of the view
     pub fn get_visible_column_indices(
         &self,
         _category: &Option<String>,
@@ -216,14 +223,121 @@ impl EditorWindowState {
                 if col.deleted {
                     return false;
                 }
+                // Collapsed structure columns stay out of the view until expanded again
+                if self.collapse_structure_columns
+                    && matches!(col.validator, Some(ColumnValidator::Structure))
+                {
+                    return false;
+                }
                 // If show_hidden_sheets is enabled, show all non-deleted columns
                 if self.show_hidden_sheets {
                     return true;
                 }
+                // Developer view: technical columns are drawn as read-only labels
+                if self.show_technical_columns
+                    && crate::ui::widgets::technical_column_widget::is_read_only_technical_header(
+                        &col.header,
+                    )
+                {
+                    return true;
+                }
                 // Otherwise respect the hidden flag
                 !col.hidden
             })
             .map(|(idx, _)| idx)
             .collect()
     }
+
+    /// Whether a sheet is pinned to the top of the sheet picker
+    pub fn is_sheet_pinned(&self, category: &Option<String>, sheet_name: &str) -> bool {
+        self.pinned_sheets
+            .contains(&(category.clone(), sheet_name.to_string()))
+    }
+
+    /// Pin or unpin a sheet in the sheet picker
+    pub fn toggle_sheet_pin(&mut self, category: &Option<String>, sheet_name: &str) {
+        let key = (category.clone(), sheet_name.to_string());
+        if !self.pinned_sheets.remove(&key) {
+            self.pinned_sheets.insert(key);
+        }
+    }
+
+    /// Move a sheet to the front of the recently opened list, keeping at most
+    /// `RECENT_SHEETS_LIMIT` entries
+    pub fn note_recent_sheet(&mut self, category: &Option<String>, sheet_name: &str) {
+        let key = (category.clone(), sheet_name.to_string());
+        self.recent_sheets.retain(|entry| entry != &key);
+        self.recent_sheets.insert(0, key);
+        self.recent_sheets.truncate(RECENT_SHEETS_LIMIT);
+    }
+
+    /// Reorder a category's sheet list so pinned sheets come first, keeping the
+    /// regular order within the pinned and unpinned groups
+    pub fn order_pinned_first(&self, category: &Option<String>, sheets: Vec<String>) -> Vec<String> {
+        let (mut pinned, unpinned): (Vec<String>, Vec<String>) = sheets
+            .into_iter()
+            .partition(|name| self.is_sheet_pinned(category, name));
+        pinned.extend(unpinned);
+        pinned
+    }
+
+    /// Replace the row selection with exactly the given (visible, filtered) rows
+    pub fn select_only_rows(&mut self, visible_rows: &[usize]) {
+        self.ai_selected_rows = visible_rows.iter().copied().collect();
+    }
+
+    /// Invert the row selection within the given (visible, filtered) rows.
+    /// Selected rows hidden by the filter are dropped so bulk actions only touch what is shown.
+    pub fn invert_row_selection(&mut self, visible_rows: &[usize]) {
+        let previous = std::mem::take(&mut self.ai_selected_rows);
+        self.ai_selected_rows = visible_rows
+            .iter()
+            .copied()
+            .filter(|row| !previous.contains(row))
+            .collect();
+    }
+
+    /// Rows excluded from the Random Picker for a sheet, sorted for persisting
+    pub fn random_picker_excluded_rows(&self, category: &Option<String>, sheet_name: &str) -> Vec<i64> {
+        let mut rows: Vec<i64> = self
+            .random_picker_excluded
+            .get(&(category.clone(), sheet_name.to_string()))
+            .map(|set| set.iter().copied().collect())
+            .unwrap_or_default();
+        rows.sort_unstable();
+        rows
+    }
+
+    /// Use `seed` for the following Random Picker draws (None = non-deterministic).
+    /// Always restarts the seeded sequence, even when the seed is unchanged.
+    pub fn set_random_picker_seed(&mut self, seed: Option<u64>) {
+        self.random_picker_seed = seed;
+        self.random_picker_seed_input = seed.map(|s| s.to_string()).unwrap_or_default();
+        self.random_picker_rng = None;
+    }
+
+    /// Remember the current Toybox mode and results for the sheet being left
+    pub fn stash_toybox_context(&mut self, category: &Option<String>, sheet_name: &str) {
+        let context = ToyboxSheetContext {
+            mode: self.toybox_mode,
+            last_pick: self.random_picker_last_value.clone(),
+            last_summary: self.summarizer_last_result.clone(),
+        };
+        self.toybox_context_by_sheet
+            .insert((category.clone(), sheet_name.to_string()), context);
+    }
+
+    /// Restore the Toybox mode and results stored for a sheet (defaults when never visited)
+    pub fn restore_toybox_context(&mut self, category: &Option<String>, sheet_name: &str) {
+        let context = self
+            .toybox_context_by_sheet
+            .get(&(category.clone(), sheet_name.to_string()))
+            .cloned()
+            .unwrap_or_default();
+        self.toybox_mode = context.mode;
+        self.random_picker_last_value = context.last_pick;
+        self.summarizer_last_result = context.last_summary;
+        self.random_picker_copy_status.clear();
+        self.summarizer_copy_status.clear();
+    }
 }
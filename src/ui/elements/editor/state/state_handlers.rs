@@ -8,7 +8,12 @@ impl EditorWindowState {
         self.current_interaction_mode = SheetInteractionState::Idle;
         self.ai_mode = AiModeState::Idle;
         self.ai_selected_rows.clear();
+        self.row_selection_anchor = None;
+        self.pending_select_all_filtered = false;
         self.selected_columns_for_deletion.clear();
+        self.active_cell = None;
+        self.pending_active_cell_focus = false;
+        self.pending_new_row_focus = None;
         // Legacy single-row / multi-map AI review fields removed.
 
         self.column_drag_state = ColumnDragState::default();
@@ -21,6 +26,7 @@ impl EditorWindowState {
         self.pending_validator_change_requires_confirmation = false;
         self.pending_validator_new_validator_summary = None;
         self.pending_validator_target_is_structure = false;
+        self.pending_structure_removal_preview = None;
 
         // NOTE: virtual structure stack intentionally preserved so user can back out after mode changes
     // Note: structure navigation stack and filtered cache cleared only on direct sheet open
@@ -18,6 +18,8 @@ pub enum ValidatorTypeChoice {
     Basic,
     Linked,
     Structure,
+    Unique,
+    Enum,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -27,6 +29,48 @@ pub enum ToyboxMode {
     Summarizer,
 }
 
+/// Statistic the toybox Summarizer computes over the selected columns
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SummarizerStat {
+    #[default]
+    Sum,
+    Mean,
+    Median,
+    Min,
+    Max,
+    Count,
+}
+
+impl SummarizerStat {
+    pub const ALL: [SummarizerStat; 6] = [
+        SummarizerStat::Sum,
+        SummarizerStat::Mean,
+        SummarizerStat::Median,
+        SummarizerStat::Min,
+        SummarizerStat::Max,
+        SummarizerStat::Count,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SummarizerStat::Sum => "Sum",
+            SummarizerStat::Mean => "Mean",
+            SummarizerStat::Median => "Median",
+            SummarizerStat::Min => "Min",
+            SummarizerStat::Max => "Max",
+            SummarizerStat::Count => "Count",
+        }
+    }
+}
+
+/// Toybox context remembered per sheet so switching away and back restores it
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ToyboxSheetContext {
+    pub mode: ToyboxMode,
+    pub last_pick: String,
+    pub last_summary: String,
+}
+
 #[derive(Clone, Debug)]
 pub struct FilteredRowsCacheEntry {
     pub rows: Arc<Vec<usize>>,
@@ -34,11 +78,29 @@ pub struct FilteredRowsCacheEntry {
     pub total_rows: usize,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum FpsSetting {
     Thirty,
     Sixty,
     ScreenHz, // Auto
+    /// User-entered cap in Hz; see `FpsSetting::custom_framerate`
+    Custom(f32),
+}
+
+impl FpsSetting {
+    pub const CUSTOM_MIN_HZ: f32 = 10.0;
+    pub const CUSTOM_MAX_HZ: f32 = 360.0;
+    /// Suggested value when switching to Custom
+    pub const CUSTOM_DEFAULT_HZ: f32 = 90.0;
+
+    /// Framerate a custom cap applies, clamped to a sane range; `None` (no explicit
+    /// limit) for values that are not positive
+    pub fn custom_framerate(hz: f32) -> Option<f64> {
+        if hz.is_nan() || hz <= 0.0 {
+            return None;
+        }
+        Some(hz.clamp(Self::CUSTOM_MIN_HZ, Self::CUSTOM_MAX_HZ) as f64)
+    }
 }
 
 impl Default for FpsSetting {
@@ -47,6 +109,74 @@ impl Default for FpsSetting {
     }
 }
 
+/// How a new structure column is renamed when its `{parent}_{column}` child table name
+/// is already taken by another sheet in the category
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum StructureTableNaming {
+    /// Append `_2`, `_3`, ... to the column's physical name
+    #[default]
+    NumericSuffix,
+    /// Prefix the column's physical name with the category, then fall back to a numeric suffix
+    CategoryPrefix,
+}
+
+/// What converting a Structure column back to a plain type will do to its child table,
+/// shown in the validator confirmation popup before anything is changed
+#[derive(Debug, Clone)]
+pub struct StructureRemovalPreview {
+    pub child_sheet_name: String,
+    /// None when the structure has no child table (legacy inline JSON cells)
+    pub child_row_count: Option<usize>,
+    pub flatten_into_cell: bool,
+    pub target_validator: Option<crate::sheets::definitions::ColumnValidator>,
+}
+
+/// "Compare sheets" popup. Each side is read when picked; the diff is computed on demand.
+#[derive(Debug, Clone, Default)]
+pub struct SheetCompareState {
+    pub left: Option<(Option<String>, String)>,
+    pub right: Option<(Option<String>, String)>,
+    /// Compared headers and rows of each side, or why the sheet could not be read
+    pub left_data: Option<Result<(Vec<String>, Vec<Vec<String>>), String>>,
+    pub right_data: Option<Result<(Vec<String>, Vec<Vec<String>>), String>>,
+    pub key_column: String,
+    pub result: Option<Result<crate::sheets::systems::logic::sheet_diff::SheetDiff, String>>,
+    /// Hide rows that are the same in both sheets
+    pub only_differences: bool,
+}
+
+/// "Merge rows" popup opened from Delete mode: the rows to merge and the value picked
+/// for each conflicting column (column index -> value)
+#[derive(Debug, Clone, Default)]
+pub struct MergeRowsState {
+    pub category: Option<String>,
+    pub sheet_name: String,
+    /// Grid rows, ascending; the first one survives
+    pub rows: Vec<usize>,
+    pub choices: HashMap<usize, String>,
+}
+
+/// Multi-line editor of a "long text" cell; `text` is committed on Save
+#[derive(Debug, Clone, Default)]
+pub struct LongTextEditorState {
+    pub category: Option<String>,
+    pub sheet_name: String,
+    pub row_index: usize,
+    pub col_index: usize,
+    /// Cell value when the editor opened
+    pub original: String,
+    pub text: String,
+}
+
+/// Columns picked in Delete mode, waiting for confirmation
+#[derive(Debug, Clone, Default)]
+pub struct DeleteColumnsConfirmState {
+    pub category: Option<String>,
+    pub sheet_name: String,
+    /// Column indices, ascending
+    pub columns: Vec<usize>,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct ColumnDragState {
     pub source_index: Option<usize>,
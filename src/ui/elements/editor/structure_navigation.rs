@@ -5,6 +5,7 @@ use crate::sheets::definitions::{
 use crate::sheets::{
     resources::{SheetRegistry},
 };
+use crate::ui::elements::editor::state::EditorWindowState;
 use bevy::prelude::*;
 
 /// Collects ancestor keys from a parent row for structure navigation.
@@ -93,6 +94,106 @@ pub fn collect_structure_ancestors(
     (ancestor_keys, display_value)
 }
 
+/// Resolve the parent sheet of a structure child table: the metadata link when present,
+/// otherwise the `{parent}_{column}` naming convention.
+pub fn structure_parent_sheet(
+    registry: &SheetRegistry,
+    category: &Option<String>,
+    child_sheet_name: &str,
+) -> Option<(Option<String>, String)> {
+    let metadata = registry.get_sheet(category, child_sheet_name)?.metadata.as_ref()?;
+    if let Some(link) = &metadata.structure_parent {
+        return Some((link.parent_category.clone(), link.parent_sheet.clone()));
+    }
+    let (parent, _) = child_sheet_name.rsplit_once('_')?;
+    registry
+        .get_sheet(category, parent)
+        .map(|_| (category.clone(), parent.to_string()))
+}
+
+/// Find the parent row that owns a structure child row.
+///
+/// The child's `parent_key` is matched against the parent's `row_index` column (column 0);
+/// sheets without a `row_index` column fall back to treating the key as a grid position.
+/// Returns the parent sheet location and, when its rows are loaded, the parent's grid index.
+pub fn find_structure_parent_row(
+    registry: &SheetRegistry,
+    category: &Option<String>,
+    child_sheet_name: &str,
+    child_row_index: usize,
+) -> Option<(Option<String>, String, Option<usize>)> {
+    let child = registry.get_sheet(category, child_sheet_name)?;
+    let pk_col = child
+        .metadata
+        .as_ref()?
+        .columns
+        .iter()
+        .position(|c| c.header.eq_ignore_ascii_case("parent_key"))?;
+    let parent_key = child.grid.get(child_row_index)?.get(pk_col)?.trim().to_string();
+    let (parent_category, parent_sheet) =
+        structure_parent_sheet(registry, category, child_sheet_name)?;
+
+    let parent_row = registry
+        .get_sheet(&parent_category, &parent_sheet)
+        .and_then(|parent| {
+            let keyed_by_row_index = parent
+                .metadata
+                .as_ref()
+                .and_then(|m| m.columns.first())
+                .map_or(false, |c| c.header.eq_ignore_ascii_case("row_index"));
+            if keyed_by_row_index {
+                parent
+                    .grid
+                    .iter()
+                    .position(|row| row.first().map_or(false, |v| v.trim() == parent_key))
+            } else {
+                parent_key
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|idx| *idx < parent.grid.len())
+            }
+        });
+    Some((parent_category, parent_sheet, parent_row))
+}
+
+/// Leave a structure child table for the parent row owning `child_row_index`,
+/// scrolling that row into view. The inverse of drilling into a structure cell.
+pub fn jump_to_structure_parent(
+    state: &mut EditorWindowState,
+    registry: &SheetRegistry,
+    category: &Option<String>,
+    child_sheet_name: &str,
+    child_row_index: usize,
+) -> bool {
+    let Some((parent_category, parent_sheet, parent_row)) =
+        find_structure_parent_row(registry, category, child_sheet_name, child_row_index)
+    else {
+        warn!(
+            "Jump to parent: no parent row found for '{:?}/{}' row {}",
+            category, child_sheet_name, child_row_index
+        );
+        return false;
+    };
+
+    // Drop the drill-down level for this child, same as "Back"
+    let is_current_drilldown = state.structure_navigation_stack.last().map_or(false, |ctx| {
+        ctx.structure_sheet_name == child_sheet_name && &ctx.parent_category == category
+    });
+    if is_current_drilldown {
+        state.structure_navigation_stack.pop();
+    }
+
+    info!(
+        "Jumping from '{}' row {} to parent '{:?}/{}' row {:?}",
+        child_sheet_name, child_row_index, parent_category, parent_sheet, parent_row
+    );
+    state.pending_scroll_to_sheet_row =
+        parent_row.map(|row| (parent_category.clone(), parent_sheet.clone(), row));
+    state.selected_category = parent_category;
+    state.selected_sheet_name = Some(parent_sheet);
+    true
+}
+
 /// Get display value from first content column (skipping technical columns)
 /// 
 /// This is a wrapper around SheetMetadata::get_first_data_column_value for backwards compatibility
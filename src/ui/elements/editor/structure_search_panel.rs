@@ -0,0 +1,177 @@
+// src/ui/elements/editor/structure_search_panel.rs
+use bevy::prelude::EventWriter;
+use bevy_egui::egui;
+
+use crate::sheets::events::RequestStructureSearch;
+use crate::sheets::resources::SheetRegistry;
+use crate::sheets::systems::logic::structure_search::{SearchHit, StructureSearch, MAX_SEARCH_HITS};
+use crate::ui::elements::editor::state::{EditorWindowState, StructureNavigationContext};
+
+/// Right side panel searching the selected sheet and all of its structure child tables.
+/// Clicking a hit opens its table (drilling through the parent rows like a Structure cell
+/// would) and outlines the matching cell.
+pub(super) fn show_structure_search_panel(
+    ctx: &egui::Context,
+    state: &mut EditorWindowState,
+    registry: &SheetRegistry,
+    search: &StructureSearch,
+    search_writer: &mut EventWriter<RequestStructureSearch>,
+) {
+    let shortcut = ctx.input_mut(|i| {
+        i.consume_key(egui::Modifiers::COMMAND | egui::Modifiers::SHIFT, egui::Key::F)
+    });
+    if shortcut {
+        state.show_structure_search_panel = true;
+    }
+    if !state.show_structure_search_panel {
+        return;
+    }
+    let Some(sheet_name) = state.selected_sheet_name.clone() else {
+        return;
+    };
+    let category = state.selected_category.clone();
+
+    let mut opened: Option<SearchHit> = None;
+    egui::SidePanel::right("structure_search_panel")
+        .resizable(true)
+        .default_width(280.0)
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.strong("Search incl. structures");
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui.small_button("✖").on_hover_text("Close search").clicked() {
+                        state.show_structure_search_panel = false;
+                        state.structure_search_highlight = None;
+                    }
+                });
+            });
+            let input = ui.add(
+                egui::TextEdit::singleline(&mut state.structure_search_input)
+                    .hint_text(format!("Find in '{}' and child tables", sheet_name))
+                    .desired_width(f32::INFINITY),
+            );
+            if shortcut {
+                input.request_focus();
+            }
+            let submitted = input.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+            let mut start = submitted;
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut state.structure_search_case_sensitive, "Match case");
+                let has_query = !state.structure_search_input.trim().is_empty();
+                start |= ui.add_enabled(has_query, egui::Button::new("Search")).clicked();
+                if search.is_running() && ui.button("Cancel").clicked() {
+                    search.cancel();
+                }
+            });
+            if start && !state.structure_search_input.trim().is_empty() {
+                state.structure_search_base_stack = state.structure_navigation_stack.clone();
+                state.structure_search_highlight = None;
+                search_writer.write(RequestStructureSearch {
+                    category: category.clone(),
+                    sheet_name: sheet_name.clone(),
+                    query: state.structure_search_input.clone(),
+                    case_sensitive: state.structure_search_case_sensitive,
+                });
+            }
+            ui.separator();
+
+            if search.is_running() {
+                ui.horizontal(|ui| {
+                    ui.spinner();
+                    ui.label(format!("Searching for '{}'…", search.query));
+                });
+                return;
+            }
+            let Some(outcome) = &search.outcome else {
+                return;
+            };
+            let Some((searched_category, searched_sheet)) = &search.searched_sheet else {
+                return;
+            };
+            let mut summary = format!(
+                "{} hit(s) in {} table(s)",
+                outcome.hits.len(),
+                outcome.tables_scanned
+            );
+            if outcome.truncated {
+                summary.push_str(&format!(" (first {} shown)", MAX_SEARCH_HITS));
+            }
+            if outcome.cancelled {
+                summary.push_str(" (cancelled)");
+            }
+            ui.label(egui::RichText::new(summary).small());
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                for hit in &outcome.hits {
+                    let header = registry
+                        .get_sheet(searched_category, &hit.table_name)
+                        .and_then(|s| s.metadata.as_ref())
+                        .and_then(|m| m.columns.get(hit.col_index))
+                        .map_or_else(|| format!("#{}", hit.col_index + 1), |c| c.display_name().to_string());
+                    let breadcrumb: Vec<&str> = hit.path.iter().map(|a| a.display.as_str()).collect();
+                    let location = if breadcrumb.is_empty() {
+                        format!("{} · row {} · {}", hit.table_name, hit.row_index + 1, header)
+                    } else {
+                        format!("{} › {} · {}", breadcrumb.join(" › "), hit.table_name, header)
+                    };
+                    let resp = ui
+                        .add(egui::Button::new(format!("{}\n{}", hit.snippet, location)).wrap())
+                        .on_hover_text("Open this cell");
+                    if resp.clicked() {
+                        opened = Some(hit.clone());
+                    }
+                }
+            });
+            if searched_category != &category || searched_sheet != &sheet_name {
+                ui.label(
+                    egui::RichText::new(format!("Results are for '{}'", searched_sheet))
+                        .small()
+                        .weak(),
+                );
+            }
+        });
+
+    if let (Some(hit), Some((searched_category, _))) = (opened, search.searched_sheet.clone()) {
+        open_search_hit(state, searched_category, hit);
+    }
+}
+
+/// Open the hit's table, pushing one navigation level per parent row from the searched sheet
+fn open_search_hit(state: &mut EditorWindowState, category: Option<String>, hit: SearchHit) {
+    let mut stack = state.structure_search_base_stack.clone();
+    let child_tables = hit
+        .path
+        .iter()
+        .skip(1)
+        .map(|a| a.table_name.clone())
+        .chain(std::iter::once(hit.table_name.clone()));
+    for (ancestor, child_table) in hit.path.iter().zip(child_tables) {
+        let (mut ancestor_keys, mut ancestor_row_indices) = stack
+            .last()
+            .map(|nav| (nav.ancestor_keys.clone(), nav.ancestor_row_indices.clone()))
+            .unwrap_or_default();
+        ancestor_keys.push(ancestor.display.clone());
+        ancestor_row_indices.push(ancestor.row_key.clone());
+        stack.push(StructureNavigationContext {
+            structure_sheet_name: child_table,
+            parent_category: category.clone(),
+            parent_sheet_name: ancestor.table_name.clone(),
+            parent_row_key: ancestor.row_key.clone(),
+            ancestor_keys,
+            ancestor_row_indices,
+        });
+    }
+
+    state.structure_navigation_stack = stack;
+    state.selected_category = category.clone();
+    state.selected_sheet_name = Some(hit.table_name.clone());
+    state.filtered_row_indices_cache.clear();
+    state.force_filter_recalculation = true;
+    state.force_cache_reload = true;
+    state.pending_sheet_revalidation = true;
+    state.pending_scroll_to_sheet_row =
+        Some((category.clone(), hit.table_name.clone(), hit.row_index));
+    state.active_cell = Some((hit.row_index, hit.col_index));
+    state.pending_active_cell_focus = true;
+    state.structure_search_highlight =
+        Some((category, hit.table_name, hit.row_index, hit.col_index));
+}
@@ -0,0 +1,92 @@
+// src/ui/elements/editor/structure_tree_panel.rs
+use bevy_egui::egui;
+
+use crate::sheets::resources::SheetRegistry;
+use crate::sheets::systems::logic::structure_tree::{
+    build_structure_tree, structure_tree_root, StructureTreeNode,
+};
+use crate::sheets::systems::ui_handlers::{category_handlers, sheet_handlers};
+use crate::ui::elements::editor::state::EditorWindowState;
+
+/// Left side panel with the full structure hierarchy of the selected sheet. Clicking a
+/// node opens that table directly (unfiltered, unlike drilling into a Structure cell).
+pub(super) fn show_structure_tree_panel(
+    ctx: &egui::Context,
+    state: &mut EditorWindowState,
+    registry: &SheetRegistry,
+) {
+    if !state.structure_tree_expanded {
+        return;
+    }
+    let Some(sheet_name) = state.selected_sheet_name.clone() else {
+        return;
+    };
+    let current = (state.selected_category.clone(), sheet_name);
+    let (root_category, root_name) = structure_tree_root(registry, &current.0, &current.1);
+    let Some(tree) = build_structure_tree(registry, &root_category, &root_name) else {
+        return;
+    };
+
+    let mut chosen: Option<(Option<String>, String)> = None;
+    egui::SidePanel::left("structure_tree_panel")
+        .resizable(true)
+        .default_width(220.0)
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.strong("Structure");
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui.small_button("✖").on_hover_text("Hide structure tree").clicked() {
+                        state.structure_tree_expanded = false;
+                    }
+                });
+            });
+            ui.separator();
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                show_tree_node(ui, &tree, &current, &mut chosen);
+            });
+        });
+
+    if let Some((category, name)) = chosen {
+        category_handlers::handle_category_selection(state, category, registry);
+        sheet_handlers::handle_sheet_selection(state, Some(name));
+        state.pending_sheet_revalidation = true;
+    }
+}
+
+fn show_tree_node(
+    ui: &mut egui::Ui,
+    node: &StructureTreeNode,
+    current: &(Option<String>, String),
+    chosen: &mut Option<(Option<String>, String)>,
+) {
+    let is_current = node.category == current.0 && node.sheet_name == current.1;
+    let rows = node
+        .row_count
+        .map(|n| n.to_string())
+        .unwrap_or_else(|| "?".to_string());
+    let mut node_label = |ui: &mut egui::Ui| {
+        let resp = ui
+            .selectable_label(is_current, format!("{} ({})", node.sheet_name, rows))
+            .on_hover_text(if node.row_count.is_some() {
+                "Open this table"
+            } else {
+                "Open this table (row count loads when opened)"
+            });
+        if resp.clicked() && !is_current {
+            *chosen = Some((node.category.clone(), node.sheet_name.clone()));
+        }
+    };
+
+    if node.children.is_empty() {
+        node_label(ui);
+        return;
+    }
+    let id = ui.make_persistent_id(("structure_tree_node", &node.category, &node.sheet_name));
+    egui::collapsing_header::CollapsingState::load_with_default_open(ui.ctx(), id, true)
+        .show_header(ui, |ui| node_label(ui))
+        .body(|ui| {
+            for child in &node.children {
+                show_tree_node(ui, child, current, chosen);
+            }
+        });
+}
@@ -1,11 +1,12 @@
 // src/ui/elements/editor/table_body.rs
 use crate::sheets::{
-    definitions::{ColumnValidator, SheetMetadata},
+    definitions::{ColumnDataType, ColumnValidator, SheetMetadata},
     events::{
         RequestCopyCell, RequestPasteCell, RequestToggleAiRowGeneration,
         UpdateCellEvent,
     },
     resources::{ClipboardBuffer, SheetRegistry, SheetRenderCache},
+    systems::logic::column_filter::{regex_filter_pattern, FilterRegexCache},
 };
 // MODIFIED: Import SheetInteractionState
 use crate::ui::common::edit_cell_widget;
@@ -17,6 +18,7 @@ use bevy::log::{debug, error, warn};
 use bevy::prelude::*;
 use bevy_egui::egui;
 use egui_extras::{TableBody, TableRow};
+use regex::Regex;
 use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
@@ -28,18 +30,58 @@ fn calculate_filters_hash(filters: &Vec<Option<String>>) -> u64 {
 }
 
 #[allow(dead_code)]
-fn get_filtered_row_indices_internal(grid: &[Vec<String>], metadata: &SheetMetadata) -> Vec<usize> {
+fn get_filtered_row_indices_internal(
+    grid: &[Vec<String>],
+    metadata: &SheetMetadata,
+    regex_cache: &mut FilterRegexCache,
+    category: &Option<String>,
+    sheet_name: &str,
+) -> Vec<usize> {
     let filters: Vec<Option<String>> = metadata.columns.iter().map(|c| c.filter.clone()).collect();
     if filters.iter().all(Option::is_none) {
         return (0..grid.len()).collect();
     }
+    // `re:` filters; an invalid pattern is reported in the column options popup and
+    // filters nothing out
+    let column_regexes: Vec<Option<Regex>> = filters
+        .iter()
+        .enumerate()
+        .map(|(col_idx, filter_opt)| {
+            let pattern = filter_opt.as_deref().and_then(regex_filter_pattern)?;
+            match regex_cache.get_or_compile(category, sheet_name, col_idx, pattern) {
+                Ok(re) => Some(re.clone()),
+                Err(e) => {
+                    debug!(
+                        "Ignoring invalid regex filter on col {} of '{:?}/{}': {}",
+                        col_idx, category, sheet_name, e
+                    );
+                    None
+                }
+            }
+        })
+        .collect();
 
     (0..grid.len())
         .filter(|&row_idx| {
             if let Some(row) = grid.get(row_idx) {
                 filters.iter().enumerate().all(|(col_idx, filter_opt)| {
                     match filter_opt {
+                        Some(filter_text) if regex_filter_pattern(filter_text).is_some() => {
+                            column_regexes[col_idx].as_ref().map_or(true, |re| {
+                                row.get(col_idx).map_or(false, |cell_text| re.is_match(cell_text))
+                            })
+                        }
                         Some(filter_text) if !filter_text.is_empty() => {
+                            // Bool columns: a single true/false term filters by value
+                            let is_bool_column = metadata
+                                .columns
+                                .get(col_idx)
+                                .map_or(false, |c| matches!(c.data_type, ColumnDataType::Bool));
+                            if let Some(wanted) = bool_filter_value(filter_text).filter(|_| is_bool_column) {
+                                return row
+                                    .get(col_idx)
+                                    .map_or(false, |cell_text| cell_is_true(cell_text) == wanted);
+                            }
                             // OR semantics across '|' separated terms (case-insensitive)
                             let terms: Vec<&str> = filter_text
                                 .split('|')
@@ -67,6 +109,21 @@ fn get_filtered_row_indices_internal(grid: &[Vec<String>], metadata: &SheetMetad
         .collect()
 }
 
+/// Tri-state filter for Bool columns: `"true"` / `"false"` (any case) select a value,
+/// anything else is treated as a regular text filter.
+pub(crate) fn bool_filter_value(filter_text: &str) -> Option<bool> {
+    match filter_text.trim().to_lowercase().as_str() {
+        "true" => Some(true),
+        "false" => Some(false),
+        _ => None,
+    }
+}
+
+/// Boolean meaning of a Bool cell (`true`/`1`); empty or anything else counts as false
+fn cell_is_true(cell_text: &str) -> bool {
+    matches!(cell_text.trim().to_lowercase().as_str(), "true" | "1")
+}
+
 pub(crate) fn get_filtered_row_indices_cached(
     state: &mut EditorWindowState,
     category: &Option<String>,
@@ -109,7 +166,13 @@ pub(crate) fn get_filtered_row_indices_cached(
         category, sheet_name, filters_hash, state.force_filter_recalculation, structure_filter.is_some()
     );
 
-    let mut indices = get_filtered_row_indices_internal(grid, metadata);
+    let mut indices = get_filtered_row_indices_internal(
+        grid,
+        metadata,
+        &mut state.filter_regex_cache,
+        category,
+        sheet_name,
+    );
 
     // Apply hidden structure filter if present
     // **Post-Refactor (2025-10-28):**
@@ -152,10 +215,11 @@ pub(crate) fn get_filtered_row_indices_cached(
             .collect();
     }
 
-    // Rule: if a row was just added (row 0), do not filter it out until UI processes the add
-    // Include row 0 temporarily while request_scroll_to_new_row is set
-    if state.request_scroll_to_new_row && !grid.is_empty() && !indices.contains(&0) {
-        indices.insert(0, 0);
+    // Rule: if a row was just added, do not filter it out until UI processes the add
+    // Include it temporarily while request_scroll_to_new_row is set
+    let new_row = state.new_row_grid_index;
+    if state.request_scroll_to_new_row && new_row < grid.len() && !indices.contains(&new_row) {
+        indices.push(new_row);
         indices.sort_unstable();
     }
 
@@ -172,6 +236,21 @@ pub(crate) fn get_filtered_row_indices_cached(
     indices
 }
 
+/// Display-only formatting for numeric cells: drops insignificant trailing zeros
+/// (`"1.50000"` -> `"1.5"`, `"100.0"` -> `"100"`). The stored value is never changed.
+/// Text that is not a plain decimal number (or uses exponent notation) is returned as-is.
+pub(crate) fn trim_insignificant_zeros(text: &str) -> String {
+    let trimmed = text.trim();
+    if trimmed.parse::<f64>().is_err() || trimmed.contains(['e', 'E']) || !trimmed.contains('.') {
+        return text.to_string();
+    }
+    let without_zeros = trimmed.trim_end_matches('0').trim_end_matches('.');
+    match without_zeros {
+        "" | "-" | "+" | "-0" | "+0" => "0".to_string(),
+        other => other.to_string(),
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 #[allow(dead_code)]
 pub fn sheet_table_body(
@@ -314,6 +393,11 @@ pub fn sheet_table_body(
                                     menu_ui.close_menu();
                                     ui.ctx().request_repaint();
                                 }
+                                if menu_ui.button("Invert selection").clicked() {
+                                    state.invert_row_selection(&filtered_indices);
+                                    menu_ui.close_menu();
+                                    ui.ctx().request_repaint();
+                                }
                                 if menu_ui.button("Remove selection from all visible rows").clicked() {
                                     for &ri in filtered_indices.iter() { state.ai_selected_rows.remove(&ri); }
                                     menu_ui.close_menu();
@@ -363,3 +447,31 @@ pub fn sheet_table_body(
     );
     false
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{bool_filter_value, cell_is_true, trim_insignificant_zeros};
+
+    #[test]
+    fn test_trim_insignificant_zeros() {
+        assert_eq!(trim_insignificant_zeros("1.50000"), "1.5");
+        assert_eq!(trim_insignificant_zeros("100.0"), "100");
+        assert_eq!(trim_insignificant_zeros("100"), "100");
+        assert_eq!(trim_insignificant_zeros("-0.000"), "0");
+        assert_eq!(trim_insignificant_zeros(".500"), ".5");
+        assert_eq!(trim_insignificant_zeros("0.123456789"), "0.123456789");
+        assert_eq!(trim_insignificant_zeros("1.0e10"), "1.0e10");
+        assert_eq!(trim_insignificant_zeros("abc.00"), "abc.00");
+    }
+
+    #[test]
+    fn test_bool_filter_matches_by_value() {
+        assert_eq!(bool_filter_value(" True "), Some(true));
+        assert_eq!(bool_filter_value("false"), Some(false));
+        assert_eq!(bool_filter_value("tru"), None);
+        assert!(cell_is_true("1"));
+        assert!(cell_is_true("TRUE"));
+        assert!(!cell_is_true("0"));
+        assert!(!cell_is_true(""));
+    }
+}
@@ -7,30 +7,40 @@ use egui_extras::TableRow;
 use super::state::{EditorWindowState, SheetInteractionState};
 use crate::sheets::definitions::{ColumnValidator, SheetMetadata};
 use crate::sheets::events::{
-    RequestBatchUpdateColumnAiInclude, RequestReorderColumn, RequestUpdateAiSendSchema,
+    MoveColumnEvent, RequestBatchUpdateColumnAiInclude, RequestUpdateAiSendSchema,
     RequestUpdateAiStructureSend, RequestUpdateColumnAiInclude,
 };
 use crate::sheets::resources::SheetRegistry;
 use crate::sheets::systems::ui_handlers;
+/// Renders the header cells of `header_columns` (all visible columns, or one region of them
+/// when leading columns are frozen). `column_resized` is set on the frame a column
+/// separator drag ends.
+#[allow(clippy::too_many_arguments)]
 pub fn sheet_table_header(
     header_row: &mut TableRow,
     ctx: &egui::Context,
     metadata: &SheetMetadata,
     sheet_name: &str,
     category: &Option<String>,
-    _registry: &SheetRegistry,
+    registry: &SheetRegistry,
     state: &mut EditorWindowState,
-    mut reorder_writer: EventWriter<RequestReorderColumn>,
-    mut column_include_writer: EventWriter<RequestUpdateColumnAiInclude>,
-    mut batch_include_writer: EventWriter<RequestBatchUpdateColumnAiInclude>,
-    mut send_schema_writer: EventWriter<RequestUpdateAiSendSchema>,
-    mut structure_send_writer: EventWriter<RequestUpdateAiStructureSend>,
+    header_columns: &[usize],
+    column_resized: bool,
+    reorder_writer: &mut EventWriter<MoveColumnEvent>,
+    column_include_writer: &mut EventWriter<RequestUpdateColumnAiInclude>,
+    batch_include_writer: &mut EventWriter<RequestBatchUpdateColumnAiInclude>,
+    send_schema_writer: &mut EventWriter<RequestUpdateAiSendSchema>,
+    structure_send_writer: &mut EventWriter<RequestUpdateAiStructureSend>,
 ) {
     let headers = metadata.get_headers();
     let filters = metadata.get_filters();
 
     // Compute visible columns (respecting hidden flag on columns)
     let visible_columns = state.get_visible_column_indices(category, sheet_name, metadata);
+    // Sheets showing ancestor key columns are drawn without a frozen region
+    let freeze_supported =
+        crate::sheets::systems::ui_handlers::build_ancestor_key_columns(state, registry, sheet_name)
+            .is_empty();
 
     // Column drag enabled
     let is_column_mode = true;
@@ -44,7 +54,7 @@ pub fn sheet_table_header(
     // Track column rects for drop detection
     let mut column_rects: Vec<(usize, egui::Rect)> = Vec::new();
 
-    for c_idx in visible_columns.iter().copied() {
+    for c_idx in header_columns.iter().copied() {
         // Skip columns that are marked deleted in metadata
         if let Some(col_def) = metadata.columns.get(c_idx) {
             if col_def.deleted {
@@ -109,7 +119,7 @@ pub fn sheet_table_header(
                                                     sheet_name,
                                                     c_idx,
                                                     is_included,
-                                                    &mut send_schema_writer,
+                                                    send_schema_writer,
                                                 );
                                             } else {
                                                 ui_handlers::handle_ai_include_change_root(
@@ -120,7 +130,7 @@ pub fn sheet_table_header(
                                                     sheet_name,
                                                     c_idx,
                                                     is_included,
-                                                    &mut column_include_writer,
+                                                    column_include_writer,
                                                 );
                                             }
                                             ui_h.ctx().request_repaint();
@@ -136,7 +146,7 @@ pub fn sheet_table_header(
                                                         category,
                                                         sheet_name,
                                                         &visible_columns,
-                                                        &mut send_schema_writer,
+                                                        send_schema_writer,
                                                     );
                                                     ui_h.ctx().request_repaint();
                                                     menu_ui.close_menu();
@@ -149,7 +159,7 @@ pub fn sheet_table_header(
                                                         category,
                                                         sheet_name,
                                                         &visible_columns,
-                                                        &mut send_schema_writer,
+                                                        send_schema_writer,
                                                     );
                                                     ui_h.ctx().request_repaint();
                                                     menu_ui.close_menu();
@@ -163,7 +173,7 @@ pub fn sheet_table_header(
                                                         category,
                                                         sheet_name,
                                                         &visible_columns,
-                                                        &mut batch_include_writer,
+                                                        batch_include_writer,
                                                     );
                                                     ui_h.ctx().request_repaint();
                                                     menu_ui.close_menu();
@@ -176,7 +186,7 @@ pub fn sheet_table_header(
                                                         category,
                                                         sheet_name,
                                                         &visible_columns,
-                                                        &mut batch_include_writer,
+                                                        batch_include_writer,
                                                     );
                                                     ui_h.ctx().request_repaint();
                                                     menu_ui.close_menu();
@@ -196,7 +206,7 @@ pub fn sheet_table_header(
                                             sheet_name,
                                             c_idx,
                                             is_included,
-                                            &mut structure_send_writer,
+                                            structure_send_writer,
                                         );
                                         ui_h.ctx().request_repaint();
                                     }
@@ -204,6 +214,23 @@ pub fn sheet_table_header(
                                 }
                             }
                         }
+                        SheetInteractionState::Idle => {
+                            // Quick hide; technical columns have no toggle (they stay hidden)
+                            if let Some(col_def) = metadata
+                                .columns
+                                .get(c_idx)
+                                .filter(|c| !SheetMetadata::is_technical_column_header(&c.header))
+                            {
+                                let (icon, hover) = if col_def.hidden {
+                                    ("🚫", "Hidden column (shown because hidden items are visible). Click to unhide.")
+                                } else {
+                                    ("👁", "Hide this column (Ctrl+Shift+H hides the active cell's column)")
+                                };
+                                if ui_h.small_button(icon).on_hover_text(hover).clicked() {
+                                    state.pending_column_hidden_toggle = Some(c_idx);
+                                }
+                            }
+                        }
                         _ => {}
                     }
 
@@ -242,9 +269,57 @@ pub fn sheet_table_header(
                         state.column_options_popup_needs_init = true;
                         state.options_column_target_category = metadata.category.clone();
                     }
+                    header_button_response.context_menu(|menu_ui| {
+                        if menu_ui.button("▲ Sort ascending").clicked() {
+                            state.pending_sort_request = Some((c_idx, true));
+                            menu_ui.close_menu();
+                        }
+                        if menu_ui.button("▼ Sort descending").clicked() {
+                            state.pending_sort_request = Some((c_idx, false));
+                            menu_ui.close_menu();
+                        }
+                        menu_ui.separator();
+                        let is_structure = metadata
+                            .columns
+                            .get(c_idx)
+                            .map_or(false, |c| matches!(c.validator, Some(ColumnValidator::Structure)));
+                        if menu_ui
+                            .add_enabled(!is_structure, egui::Button::new("🔎 Find & Replace…"))
+                            .on_disabled_hover_text("Structure columns hold child rows; edit them in the child table")
+                            .clicked()
+                        {
+                            state.find_replace_target =
+                                Some((metadata.category.clone(), sheet_name.to_string(), c_idx));
+                            menu_ui.close_menu();
+                        }
+                        menu_ui.separator();
+                        // Freezing keeps every column up to this one pinned on the left
+                        if let Some(pos) = visible_columns
+                            .iter()
+                            .position(|&v| v == c_idx)
+                            .filter(|_| freeze_supported)
+                        {
+                            let can_freeze = pos + 1 < visible_columns.len();
+                            if menu_ui
+                                .add_enabled(can_freeze, egui::Button::new("📌 Freeze columns up to here"))
+                                .on_disabled_hover_text("At least one column must stay scrollable")
+                                .clicked()
+                            {
+                                state.frozen_column_count = pos + 1;
+                                menu_ui.close_menu();
+                            }
+                        }
+                        if freeze_supported
+                            && state.frozen_column_count > 0
+                            && menu_ui.button("Unfreeze columns").clicked()
+                        {
+                            state.frozen_column_count = 0;
+                            menu_ui.close_menu();
+                        }
+                    });
                     if can_open_options {
                         header_button_response.on_hover_text(format!(
-                            "Click for options for column '{}'",
+                            "Click for options for column '{}', right-click to sort",
                             header_text
                         ));
                     } else if !is_column_mode {
@@ -348,11 +423,11 @@ pub fn sheet_table_header(
                     
                     if source_idx != new_index {
                         info!("Dropping column {} at position {}", source_idx, new_index);
-                        reorder_writer.write(RequestReorderColumn {
+                        reorder_writer.write(MoveColumnEvent {
                             category: category.clone(),
                             sheet_name: sheet_name.to_string(),
-                            old_index: source_idx,
-                            new_index,
+                            from_index: source_idx,
+                            to_index: new_index,
                         });
                     }
                 } else {
@@ -363,4 +438,22 @@ pub fn sheet_table_header(
             state.column_drag_state.source_index = None;
         }
     }
+
+    // A resize drag just ended: queue widths that differ from the stored ones, keyed by
+    // physical column name so they survive later reorders
+    if column_resized {
+        for (col_idx, rect) in column_rects.iter() {
+            let Some(col_def) = metadata.columns.get(*col_idx) else {
+                continue;
+            };
+            let (init_w, min_w) =
+                ui_handlers::calculate_column_width(col_def.validator.as_ref(), col_def.data_type);
+            let stored_w = col_def.width.unwrap_or(init_w).max(min_w);
+            if (rect.width() - stored_w).abs() > 1.0 {
+                state
+                    .pending_column_width_updates
+                    .push((col_def.header.clone(), rect.width()));
+            }
+        }
+    }
 }
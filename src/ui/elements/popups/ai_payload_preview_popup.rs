@@ -0,0 +1,64 @@
+// src/ui/elements/popups/ai_payload_preview_popup.rs
+use bevy_egui::egui;
+
+use crate::ui::elements::editor::state::EditorWindowState;
+
+/// Shows the payload built by the AI "Preview payload" dry run (nothing was sent).
+pub fn show_ai_payload_preview_popup(ctx: &egui::Context, state: &mut EditorWindowState) {
+    let Some(preview) = state.ai_payload_preview.as_ref() else {
+        return;
+    };
+
+    let mut is_open = true;
+    let mut close = false;
+    egui::Window::new("AI Payload Preview")
+        .open(&mut is_open)
+        .collapsible(false)
+        .resizable(true)
+        .default_size([640.0, 480.0])
+        .show(ctx, |ui| {
+            ui.label(format!(
+                "First request for '{}' ({} bytes). Nothing was sent to the model.",
+                preview.table_name,
+                preview.payload_json.len()
+            ));
+            if preview.request_parts > 1 {
+                ui.label(format!(
+                    "The selection is split into {} requests by the width limit and character budget; only the first is shown.",
+                    preview.request_parts
+                ));
+            }
+            if !preview.child_structures.is_empty() {
+                ui.label(format!(
+                    "Child steps for {} are queued dynamically from the AI response and are not shown.",
+                    preview.child_structures.join(", ")
+                ));
+            }
+            ui.separator();
+            egui::ScrollArea::both()
+                .max_height((ui.available_height() - 36.0).max(120.0))
+                .auto_shrink([false, false])
+                .show(ui, |ui| {
+                    // &str keeps the text selectable but read-only
+                    let mut text = preview.payload_json.as_str();
+                    ui.add(
+                        egui::TextEdit::multiline(&mut text)
+                            .code_editor()
+                            .desired_width(f32::INFINITY),
+                    );
+                });
+            ui.separator();
+            ui.horizontal(|ui| {
+                if ui.button("📋 Copy").clicked() {
+                    ui.ctx().copy_text(preview.payload_json.clone());
+                }
+                if ui.button("Close").clicked() {
+                    close = true;
+                }
+            });
+        });
+
+    if !is_open || close {
+        state.ai_payload_preview = None;
+    }
+}
@@ -2,15 +2,25 @@
 use crate::{
     sheets::{
         database::daemon_client::DaemonClient,
-        definitions::{default_ai_model_id, SheetMetadata},
+        definitions::{default_ai_model_id, ColumnValidator, SheetMetadata},
         resources::SheetRegistry,
-        systems::io::save::save_single_sheet,
+        systems::{
+            ai::{
+                models::{find_known_model, model_supports_grounding, KNOWN_GEMINI_MODELS},
+                usage_ledger::save_usage_ledger,
+            },
+            io::save::save_single_sheet,
+        },
     },
     ui::elements::editor::EditorWindowState,
 };
 use bevy::prelude::*;
 use bevy_egui::egui;
 
+/// Starting value when a custom temperature is switched on (1.0 and 0.9 read back from
+/// JSON metadata as "unset", see sheet_metadata deserialization)
+const AI_TEMPERATURE_DEFAULT: f32 = 0.7;
+
 /// Displays the modal popup window for editing the AI Model ID, General Rule, and parameters.
 pub fn show_ai_rule_popup(
     ctx: &egui::Context,
@@ -39,11 +49,15 @@ pub fn show_ai_rule_popup(
                             .requested_grounding_with_google_search
                             .unwrap_or(false),
                     );
+                    state.ai_rule_popup_key_column = metadata.ai_key_column_override();
+                    state.ai_rule_popup_temperature = metadata.ai_temperature;
                 } else {
                     warn!("Metadata not found for sheet '{:?}/{}' during AI Config popup init. Using defaults.", state.selected_category, sheet_name);
                     state.ai_model_id_input = default_ai_model_id();
                     state.ai_general_rule_input = "".to_string();
                     state.ai_rule_popup_grounding = Some(false);
+                    state.ai_rule_popup_key_column = None;
+                    state.ai_rule_popup_temperature = None;
                 }
             } else {
                 warn!(
@@ -53,13 +67,18 @@ pub fn show_ai_rule_popup(
                 state.ai_model_id_input = default_ai_model_id();
                 state.ai_general_rule_input = "".to_string();
                 state.ai_rule_popup_grounding = Some(false);
+                state.ai_rule_popup_key_column = None;
+                state.ai_rule_popup_temperature = None;
             }
         } else {
             info!("No sheet selected for AI Config popup. Using defaults.");
             state.ai_model_id_input = default_ai_model_id();
             state.ai_general_rule_input = "".to_string();
             state.ai_rule_popup_grounding = Some(false);
+            state.ai_rule_popup_key_column = None;
+            state.ai_rule_popup_temperature = None;
         }
+        state.ai_rule_popup_custom_model = find_known_model(&state.ai_model_id_input).is_none();
         state.ai_rule_popup_needs_init = false; // Consumed the init flag
     }
     // --- END MODIFIED ---
@@ -81,6 +100,27 @@ pub fn show_ai_rule_popup(
         state.ai_rule_popup_last_sheet = state.selected_sheet_name.clone();
     }
 
+    // Columns eligible as the AI key/display column: (grid index, label)
+    let key_column_choices: Vec<(usize, String)> = state
+        .selected_sheet_name
+        .as_ref()
+        .and_then(|name| registry.get_sheet(&state.selected_category, name))
+        .and_then(|s| s.metadata.as_ref())
+        .map(|m| {
+            m.columns
+                .iter()
+                .enumerate()
+                .filter(|(_, c)| {
+                    !c.deleted
+                        && !SheetMetadata::is_technical_column_header(&c.header)
+                        && !SheetMetadata::is_metadata_column_header(&c.header)
+                        && !matches!(c.validator, Some(ColumnValidator::Structure))
+                })
+                .map(|(idx, c)| (idx, c.display_name().to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+
     let mut is_window_open = state.show_ai_rule_popup;
     let mut save_requested = false;
     let mut cancel_requested = false;
@@ -95,13 +135,60 @@ pub fn show_ai_rule_popup(
         .show(ctx, |ui| {
             ui.horizontal(|ui| {
                 ui.label("AI Model:");
-                // The TextEdit for ai_model_id_input. No validation here.
+                let known = find_known_model(&state.ai_model_id_input);
+                let selected_text = match known {
+                    Some(model) if !state.ai_rule_popup_custom_model => model.label.to_string(),
+                    _ => "Custom…".to_string(),
+                };
+                egui::ComboBox::from_id_salt("ai_rule_popup_model")
+                    .selected_text(selected_text)
+                    .show_ui(ui, |ui_c| {
+                        for model in KNOWN_GEMINI_MODELS {
+                            let selected = !state.ai_rule_popup_custom_model
+                                && known.is_some_and(|k| k.id == model.id);
+                            let mut hover = format!(
+                                "{}\nContext window: {} tokens",
+                                model.id, model.context_window
+                            );
+                            if !model.supports_grounding {
+                                hover.push_str("\nNo Google Search grounding");
+                            }
+                            if ui_c
+                                .selectable_label(selected, model.label)
+                                .on_hover_text(hover)
+                                .clicked()
+                            {
+                                state.ai_model_id_input = model.id.to_string();
+                                state.ai_rule_popup_custom_model = false;
+                            }
+                        }
+                        ui_c.separator();
+                        if ui_c
+                            .selectable_label(state.ai_rule_popup_custom_model, "Custom…")
+                            .clicked()
+                        {
+                            state.ai_rule_popup_custom_model = true;
+                        }
+                    });
+            });
+            if state.ai_rule_popup_custom_model {
+                // Free-text model ID for models missing from the curated list. No validation here.
                 ui.add(
                     egui::TextEdit::singleline(&mut state.ai_model_id_input)
                         .desired_width(f32::INFINITY)
-                        .hint_text("e.g., gemini-1.5-flash, gemini-2.5-flash-preview-04-17"),
+                        .hint_text("e.g., gemini-2.5-flash-preview-09-2025"),
                 );
-            });
+            } else if let Some(model) = find_known_model(&state.ai_model_id_input) {
+                ui.label(
+                    egui::RichText::new(format!(
+                        "{} · {}K token context",
+                        model.id,
+                        model.context_window / 1024
+                    ))
+                    .small()
+                    .weak(),
+                );
+            }
             ui.separator();
             ui.label("Sheet AI Context");
             ui.add_sized(
@@ -115,16 +202,85 @@ pub fn show_ai_rule_popup(
             ui.separator();
             // Row: AI options toggles (stacked vertically)
             ui.vertical(|ui_v| {
-                let mut grounded = state.ai_rule_popup_grounding.unwrap_or(false);
+                let grounding_supported = model_supports_grounding(&state.ai_model_id_input);
+                let mut grounded =
+                    grounding_supported && state.ai_rule_popup_grounding.unwrap_or(false);
+                let hover = if grounding_supported {
+                    "Enable Google Search grounding for AI responses"
+                } else {
+                    "The selected model does not support Google Search grounding"
+                };
                 if ui_v
-                    .checkbox(&mut grounded, "Search")
-                    .on_hover_text("Enable Google Search grounding for AI responses")
+                    .add_enabled(grounding_supported, egui::Checkbox::new(&mut grounded, "Search"))
+                    .on_hover_text(hover)
+                    .on_disabled_hover_text(hover)
                     .changed()
                 {
                     state.ai_rule_popup_grounding = Some(grounded);
                 }
             });
+            ui.horizontal(|ui| {
+                let mut custom = state.ai_rule_popup_temperature.is_some();
+                if ui
+                    .checkbox(&mut custom, "Temperature")
+                    .on_hover_text("Unchecked: the model's default temperature is used")
+                    .changed()
+                {
+                    state.ai_rule_popup_temperature = custom.then_some(AI_TEMPERATURE_DEFAULT);
+                }
+                if let Some(temperature) = state.ai_rule_popup_temperature.as_mut() {
+                    ui.add(egui::Slider::new(temperature, 0.0..=2.0).step_by(0.05))
+                        .on_hover_text("Lower is more deterministic, higher is more varied");
+                }
+            });
+            ui.separator();
+            ui.horizontal(|ui| {
+                ui.label("Key Column:");
+                let selected_text = state
+                    .ai_rule_popup_key_column
+                    .and_then(|idx| key_column_choices.iter().find(|(i, _)| *i == idx))
+                    .map(|(_, label)| label.clone())
+                    .unwrap_or_else(|| "Default (first data column)".to_string());
+                egui::ComboBox::from_id_salt("ai_rule_popup_key_column")
+                    .selected_text(selected_text)
+                    .show_ui(ui, |ui_c| {
+                        ui_c.selectable_value(
+                            &mut state.ai_rule_popup_key_column,
+                            None,
+                            "Default (first data column)",
+                        );
+                        for (idx, label) in &key_column_choices {
+                            ui_c.selectable_value(
+                                &mut state.ai_rule_popup_key_column,
+                                Some(*idx),
+                                label,
+                            );
+                        }
+                    })
+                    .response
+                    .on_hover_text("Column whose value identifies each row to the AI and matches its answers back to rows");
+            });
             ui.separator();
+            // Approximate AI usage for this sheet (all sessions)
+            if let Some(sheet_name) = state.selected_sheet_name.clone() {
+                let usage = state.ai_usage.usage(&state.selected_category, &sheet_name);
+                ui.horizontal(|ui| {
+                    ui.label(format!(
+                        "AI usage: {} request(s), {} row(s) processed, {} row(s) added",
+                        usage.requests, usage.rows_processed, usage.rows_added
+                    ))
+                    .on_hover_text("Approximate totals across sessions; structure tables are counted separately");
+                    if ui
+                        .add_enabled(usage.requests > 0, egui::Button::new("Reset"))
+                        .clicked()
+                    {
+                        let category = state.selected_category.clone();
+                        state.ai_usage.reset(&category, &sheet_name);
+                        save_usage_ledger(&state.ai_usage);
+                    }
+                });
+                ui.separator();
+            }
             ui.horizontal(|ui| {
                 // Enable save if a sheet is actually selected
                 if ui
@@ -146,6 +302,7 @@ pub fn show_ai_rule_popup(
         if let Some(sheet_name_clone) = state.selected_sheet_name.clone() {
             // Ensure a sheet is selected
             let mut meta_to_save_cloned: Option<SheetMetadata> = None;
+            let mut key_column_changed = false;
 
             if let Some(sheet_mut) =
                 registry.get_sheet_mut(&state.selected_category, &sheet_name_clone)
@@ -175,20 +332,33 @@ pub fn show_ai_rule_popup(
                         changed = true;
                     }
 
-                    // Persist Grounding toggle
+                    // Persist Grounding toggle (forced off for models that cannot ground)
                     if let Some(ground) = state.ai_rule_popup_grounding {
+                        let ground = ground && model_supports_grounding(&meta_mut.ai_model_id);
                         if meta_mut.requested_grounding_with_google_search != Some(ground) {
                             meta_mut.requested_grounding_with_google_search = Some(ground);
                             changed = true;
                         }
                     }
 
+                    if meta_mut.ai_temperature != state.ai_rule_popup_temperature {
+                        meta_mut.ai_temperature = state.ai_rule_popup_temperature;
+                        changed = true;
+                    }
+
+                    if meta_mut.ai_key_column_override() != state.ai_rule_popup_key_column {
+                        meta_mut.set_ai_key_column(state.ai_rule_popup_key_column);
+                        key_column_changed = true;
+                        changed = true;
+                    }
+
                     if changed {
                         info!(
-                            "AI Config updated for '{:?}/{}': Model ID='{}', Rule='{:?}', AddRows={}, Grounding={:?}. Triggering save.",
+                            "AI Config updated for '{:?}/{}': Model ID='{}', Rule='{:?}', AddRows={}, Grounding={:?}, Temperature={:?}, KeyColumn={:?}. Triggering save.",
                             state.selected_category, sheet_name_clone,
                             meta_mut.ai_model_id, meta_mut.ai_general_rule,
-                            meta_mut.ai_enable_row_generation, meta_mut.requested_grounding_with_google_search
+                            meta_mut.ai_enable_row_generation, meta_mut.requested_grounding_with_google_search,
+                            meta_mut.ai_temperature, meta_mut.ai_key_column
                         );
                         meta_to_save_cloned = Some(meta_mut.clone());
                     } else {
@@ -204,7 +374,7 @@ pub fn show_ai_rule_popup(
                 if meta_for_saving.category.is_none() {
                     save_single_sheet(registry_immut_save, &meta_for_saving);
                 } else {
-                    // Persist to DB: table context, model ID, and grounding flag
+                    // Persist to DB: table context, model ID, grounding flag and temperature
                     if let Some(cat) = &state.selected_category {
                         let base = crate::sheets::systems::io::get_default_data_base_path();
                         let db_path = base.join(format!("{}.db", cat));
@@ -223,9 +393,21 @@ pub fn show_ai_rule_popup(
                                     Some(&meta_for_saving.ai_model_id),
                                     None,
                                     meta_for_saving.requested_grounding_with_google_search,
+                                    Some(meta_for_saving.ai_temperature),
                                     db_path.file_name().and_then(|n| n.to_str()),
                                     daemon_client,
                                 );
+                                if key_column_changed {
+                                    if let Err(e) = crate::sheets::database::writer::DbWriter::update_table_ai_key_column(
+                                        &conn,
+                                        &meta_for_saving.sheet_name,
+                                        meta_for_saving.ai_key_column.as_deref(),
+                                        db_path.file_name().and_then(|n| n.to_str()),
+                                        daemon_client,
+                                    ) {
+                                        error!("Failed to persist AI key column for '{}': {}", meta_for_saving.sheet_name, e);
+                                    }
+                                }
                             }
                         }
                     }
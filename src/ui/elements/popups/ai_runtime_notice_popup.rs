@@ -0,0 +1,40 @@
+// src/ui/elements/popups/ai_runtime_notice_popup.rs
+// One-time notice shown when AI features were disabled because Python is unusable
+
+use crate::ui::elements::editor::state::EditorWindowState;
+use bevy_egui::egui;
+
+/// Explains why AI Mode is unavailable and what to install. Shown once per session.
+pub fn show_ai_runtime_notice_popup(ctx: &egui::Context, state: &mut EditorWindowState) {
+    if !state.show_ai_runtime_notice {
+        return;
+    }
+    let Some(reason) = state.ai_runtime_error.clone() else {
+        state.show_ai_runtime_notice = false;
+        return;
+    };
+
+    let mut is_open = true;
+    let mut dismissed = false;
+    egui::Window::new("AI Features Unavailable")
+        .open(&mut is_open)
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.set_width(460.0);
+            ui.label("AI features are turned off for this session.");
+            ui.add_space(6.0);
+            ui.label(egui::RichText::new(reason).strong());
+            ui.add_space(6.0);
+            ui.label("Everything else works normally. Restart the application after installing to use AI Mode.");
+            ui.add_space(10.0);
+            if ui.button("OK").clicked() {
+                dismissed = true;
+            }
+        });
+
+    if !is_open || dismissed {
+        state.show_ai_runtime_notice = false;
+    }
+}
@@ -0,0 +1,86 @@
+// src/ui/elements/popups/cell_comment_popup.rs
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+use crate::sheets::events::{DeleteCellCommentEvent, UpsertCellCommentEvent};
+use crate::sheets::resources::SheetRegistry;
+use crate::ui::elements::editor::EditorWindowState;
+
+/// Edits the comment attached to a cell (opened from the cell context menu).
+pub fn show_cell_comment_popup(
+    ctx: &egui::Context,
+    state: &mut EditorWindowState,
+    registry: &SheetRegistry,
+    upsert_writer: &mut EventWriter<UpsertCellCommentEvent>,
+    delete_writer: &mut EventWriter<DeleteCellCommentEvent>,
+) {
+    let Some((category, sheet_name, row_index, col_index)) = state.cell_comment_target.clone()
+    else {
+        return;
+    };
+
+    let column_label = registry
+        .get_sheet(&category, &sheet_name)
+        .and_then(|sheet| sheet.metadata.as_ref())
+        .and_then(|meta| meta.columns.get(col_index))
+        .map(|col| col.display_name().to_string())
+        .unwrap_or_else(|| format!("#{}", col_index + 1));
+
+    let mut is_open = true;
+    let mut save = false;
+    let mut delete = false;
+    let mut cancel = false;
+
+    egui::Window::new("Cell Comment")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .open(&mut is_open)
+        .show(ctx, |ui| {
+            ui.label(format!("'{}', row {}, column '{}'", sheet_name, row_index + 1, column_label));
+            ui.add(
+                egui::TextEdit::multiline(&mut state.cell_comment_input)
+                    .desired_width(300.0)
+                    .desired_rows(4),
+            );
+            ui.separator();
+            ui.horizontal(|ui| {
+                if ui
+                    .add_enabled(
+                        !state.cell_comment_input.trim().is_empty(),
+                        egui::Button::new("Save"),
+                    )
+                    .clicked()
+                {
+                    save = true;
+                }
+                if ui.button("Delete").clicked() {
+                    delete = true;
+                }
+                if ui.button("Cancel").clicked() {
+                    cancel = true;
+                }
+            });
+        });
+
+    if save {
+        upsert_writer.write(UpsertCellCommentEvent {
+            category,
+            sheet_name,
+            row_index,
+            col_index,
+            comment: state.cell_comment_input.trim().to_string(),
+        });
+    } else if delete {
+        delete_writer.write(DeleteCellCommentEvent {
+            category,
+            sheet_name,
+            row_index,
+            col_index,
+        });
+    }
+    if save || delete || cancel || !is_open {
+        state.cell_comment_target = None;
+        state.cell_comment_input.clear();
+    }
+}
@@ -19,6 +19,7 @@ pub(super) fn handle_on_close(
     state.options_column_target_sheet.clear();
     state.options_column_target_index = 0;
     state.options_column_rename_input.clear();
+    state.options_column_display_name_input.clear();
     state.options_column_filter_input.clear();
     state.options_column_ai_context_input.clear(); // NEW: Clear AI context input
     state.column_options_popup_needs_init = false; // Should already be false
@@ -3,9 +3,12 @@ use crate::ui::elements::editor::state::EditorWindowState;
 use crate::{
     sheets::{
         database::daemon_client::DaemonClient,
-        definitions::ColumnValidator,
+        definitions::{ColumnDataType, ColumnValidator},
         events::{RequestUpdateColumnName, RequestUpdateColumnValidator},
         resources::SheetRegistry,
+        systems::logic::column_filter::regex_filter_pattern,
+        systems::logic::column_defaults::parse_default_value,
+        systems::logic::update_cell::parse_numeric_range,
     },
     ui::elements::editor::state::ValidatorTypeChoice,
 };
@@ -59,26 +62,33 @@ pub fn show_column_options_popup(
         let col_index = state.options_column_target_index;
         let mut rename_sent = false;
         let mut validator_sent = false;
-        let (current_name, current_filter, current_context, current_validator, current_hidden) = {
+        let (current_name, current_display_name, current_filter, current_context, current_validator, current_hidden, current_trim_zeros, current_ai_read_only, current_multiline, current_enum_auto_add, current_numeric_range, current_default_value) = {
             let maybe_col_def = registry
                 .get_sheet(category, sheet_name)
                 .and_then(|s| s.metadata.as_ref())
                 .and_then(|m| m.columns.get(col_index));
             if let Some(col_def) = maybe_col_def {
-                let ui_name = col_def
+                let custom_display = col_def
                     .display_header
                     .as_ref()
-                    .cloned()
-                    .unwrap_or_else(|| col_def.header.clone());
+                    .filter(|d| !d.trim().is_empty() && d.as_str() != col_def.header)
+                    .cloned();
                 (
-                    Some(ui_name),
+                    Some(col_def.header.clone()),
+                    custom_display,
                     col_def.filter.clone(),
                     col_def.ai_context.clone(),
                     col_def.validator.clone(),
                     col_def.hidden,
+                    col_def.trim_numeric_zeros,
+                    col_def.ai_read_only,
+                    col_def.multiline,
+                    col_def.enum_auto_add,
+                    col_def.numeric_range,
+                    col_def.default_value.clone(),
                 )
             } else {
-                (None, None, None, None, false)
+                (None, None, None, None, None, false, false, false, false, false, None, None)
             }
         };
         if current_name.is_none() {
@@ -96,17 +106,13 @@ pub fn show_column_options_popup(
                     warn!("Column rename failed: New name empty.");
                     actions_ok = false;
                 } else {
+                    // Physical names must be unique among physical names (SQL columns)
                     let is_duplicate = registry
                         .get_sheet(category, sheet_name)
                         .and_then(|s| s.metadata.as_ref())
                         .map_or(false, |m| {
                             m.columns.iter().enumerate().any(|(i, c)| {
-                                let comp = c
-                                    .display_header
-                                    .as_ref()
-                                    .map(|s| s.as_str())
-                                    .unwrap_or(c.header.as_str());
-                                i != col_index && !c.deleted && comp.eq_ignore_ascii_case(new_name_trimmed)
+                                i != col_index && !c.deleted && c.header.eq_ignore_ascii_case(new_name_trimmed)
                             })
                         });
                     debug!("Duplicate check result: is_duplicate={}", is_duplicate);
@@ -131,6 +137,72 @@ pub fn show_column_options_popup(
                 debug!("Column rename skipped: name unchanged");
             }
         }
+        if actions_ok {
+            // Display name is UI-only; an empty value (or one equal to the physical name) clears it
+            let display_trimmed = state.options_column_display_name_input.trim();
+            let display_to_store: Option<String> = if display_trimmed.is_empty()
+                || display_trimmed == state.options_column_rename_input.trim()
+            {
+                None
+            } else {
+                Some(display_trimmed.to_string())
+            };
+            if display_to_store != current_display_name {
+                let is_duplicate = display_to_store.as_ref().map_or(false, |new_display| {
+                    registry
+                        .get_sheet(category, sheet_name)
+                        .and_then(|s| s.metadata.as_ref())
+                        .map_or(false, |m| {
+                            m.columns.iter().enumerate().any(|(i, c)| {
+                                i != col_index
+                                    && !c.deleted
+                                    && c.display_name().eq_ignore_ascii_case(new_display)
+                            })
+                        })
+                });
+                if is_duplicate {
+                    warn!(
+                        "Display name update failed: '{}' duplicates an existing column name.",
+                        display_trimmed
+                    );
+                    actions_ok = false;
+                } else if let Some(meta) = registry
+                    .get_sheet_mut(category, sheet_name)
+                    .and_then(|s| s.metadata.as_mut())
+                {
+                    if let Some(col_def) = meta.columns.get_mut(col_index) {
+                        info!(
+                            "Updating display name for col {} of '{:?}/{}': {:?} -> {:?}.",
+                            col_index + 1,
+                            category,
+                            sheet_name,
+                            current_display_name,
+                            display_to_store
+                        );
+                        col_def.display_header = display_to_store;
+                        non_event_change_occurred = true;
+                        if meta.category.is_some() {
+                            if let Some(cat) = category {
+                                // Clearing stores the physical name, which reads back as "no custom name"
+                                let persisted = col_def
+                                    .display_header
+                                    .clone()
+                                    .unwrap_or_else(|| col_def.header.clone());
+                                if let Err(e) = crate::sheets::database::persist_column_display_name(
+                                    cat,
+                                    &meta.sheet_name,
+                                    col_index,
+                                    &persisted,
+                                    daemon_client,
+                                ) {
+                                    error!("Persist column metadata (display name) failed: {}", e);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
         if actions_ok {
             let joined_terms: String = state
                 .options_column_filter_terms
@@ -156,8 +228,47 @@ pub fn show_column_options_popup(
             let filter_changed = current_filter != filter_to_store;
             let context_changed = current_context != context_to_store;
             let hidden_changed = current_hidden != state.options_column_hidden_input;
+            let trim_zeros_changed = current_trim_zeros != state.options_column_trim_zeros_input;
+            let ai_read_only_changed = current_ai_read_only != state.options_column_ai_read_only_input;
+            let multiline_changed = current_multiline != state.options_column_multiline_input;
+            let enum_auto_add_changed = current_enum_auto_add != state.options_column_enum_auto_add_input;
+            let range_to_store = match parse_numeric_range(
+                &state.options_column_range_min_input,
+                &state.options_column_range_max_input,
+            ) {
+                Ok(range) => range,
+                Err(e) => {
+                    warn!("Numeric range not updated: {}.", e);
+                    current_numeric_range
+                }
+            };
+            let range_changed = current_numeric_range != range_to_store;
+            // Checked against the type being applied (a pending type change included);
+            // Structure columns have no cell value to pre-fill
+            let default_value_type = match state.options_validator_type {
+                Some(ValidatorTypeChoice::Basic) | Some(ValidatorTypeChoice::Unique) => {
+                    Some(state.options_basic_type_select)
+                }
+                Some(ValidatorTypeChoice::Linked) | Some(ValidatorTypeChoice::Enum) => {
+                    Some(ColumnDataType::String)
+                }
+                _ => None,
+            };
+            let default_to_store = match default_value_type {
+                Some(data_type) => {
+                    match parse_default_value(data_type, &state.options_column_default_value_input) {
+                        Ok(value) => value,
+                        Err(e) => {
+                            warn!("Default value not updated: {}.", e);
+                            current_default_value.clone()
+                        }
+                    }
+                }
+                None => None,
+            };
+            let default_changed = current_default_value != default_to_store;
 
-            if filter_changed || context_changed || hidden_changed {
+            if filter_changed || context_changed || hidden_changed || trim_zeros_changed || ai_read_only_changed || multiline_changed || enum_auto_add_changed || range_changed || default_changed {
                 non_event_change_occurred = true;
                 if let Some(sheet_data) = registry.get_sheet_mut(category, sheet_name) {
                     if let Some(meta) = &mut sheet_data.metadata {
@@ -258,6 +369,156 @@ pub fn show_column_options_popup(
                                     }
                                 }
                             }
+                            if trim_zeros_changed {
+                                info!(
+                                    "Updating trim-zeros display for col {} of '{:?}/{}': {} -> {}.",
+                                    col_index + 1,
+                                    category,
+                                    sheet_name,
+                                    current_trim_zeros,
+                                    state.options_column_trim_zeros_input
+                                );
+                                col_def.trim_numeric_zeros = state.options_column_trim_zeros_input;
+                                if meta.category.is_some() {
+                                    if let Some(cat) = category {
+                                        let table_name = &meta.sheet_name;
+                                        if let Err(e) = crate::sheets::database::persist_column_display_trim_zeros(
+                                            cat,
+                                            table_name,
+                                            col_index,
+                                            col_def.trim_numeric_zeros,
+                                            daemon_client,
+                                        ) {
+                                            error!("Persist column metadata (trim zeros) failed: {}", e);
+                                        }
+                                    }
+                                }
+                            }
+                            if ai_read_only_changed {
+                                info!(
+                                    "Updating AI read-only for col {} of '{:?}/{}': {} -> {}.",
+                                    col_index + 1,
+                                    category,
+                                    sheet_name,
+                                    current_ai_read_only,
+                                    state.options_column_ai_read_only_input
+                                );
+                                col_def.ai_read_only = state.options_column_ai_read_only_input;
+                                if meta.category.is_some() {
+                                    if let Some(cat) = category {
+                                        let table_name = &meta.sheet_name;
+                                        if let Err(e) = crate::sheets::database::persist_column_ai_read_only(
+                                            cat,
+                                            table_name,
+                                            col_index,
+                                            col_def.ai_read_only,
+                                            daemon_client,
+                                        ) {
+                                            error!("Persist column metadata (AI read-only) failed: {}", e);
+                                        }
+                                    }
+                                }
+                            }
+                            if multiline_changed {
+                                info!(
+                                    "Updating long text for col {} of '{:?}/{}': {} -> {}.",
+                                    col_index + 1,
+                                    category,
+                                    sheet_name,
+                                    current_multiline,
+                                    state.options_column_multiline_input
+                                );
+                                col_def.multiline = state.options_column_multiline_input;
+                                if meta.category.is_some() {
+                                    if let Some(cat) = category {
+                                        let table_name = &meta.sheet_name;
+                                        if let Err(e) = crate::sheets::database::persist_column_multiline(
+                                            cat,
+                                            table_name,
+                                            col_index,
+                                            col_def.multiline,
+                                            daemon_client,
+                                        ) {
+                                            error!("Persist column metadata (long text) failed: {}", e);
+                                        }
+                                    }
+                                }
+                            }
+                            if enum_auto_add_changed {
+                                info!(
+                                    "Updating enum auto-add for col {} of '{:?}/{}': {} -> {}.",
+                                    col_index + 1,
+                                    category,
+                                    sheet_name,
+                                    current_enum_auto_add,
+                                    state.options_column_enum_auto_add_input
+                                );
+                                col_def.enum_auto_add = state.options_column_enum_auto_add_input;
+                                if meta.category.is_some() {
+                                    if let Some(cat) = category {
+                                        let table_name = &meta.sheet_name;
+                                        if let Err(e) = crate::sheets::database::persist_column_enum_auto_add(
+                                            cat,
+                                            table_name,
+                                            col_index,
+                                            col_def.enum_auto_add,
+                                            daemon_client,
+                                        ) {
+                                            error!("Persist column metadata (enum auto-add) failed: {}", e);
+                                        }
+                                    }
+                                }
+                            }
+                            if range_changed {
+                                info!(
+                                    "Updating numeric range for col {} of '{:?}/{}': {:?} -> {:?}.",
+                                    col_index + 1,
+                                    category,
+                                    sheet_name,
+                                    current_numeric_range,
+                                    range_to_store
+                                );
+                                col_def.numeric_range = range_to_store;
+                                if meta.category.is_some() {
+                                    if let Some(cat) = category {
+                                        let table_name = &meta.sheet_name;
+                                        if let Err(e) = crate::sheets::database::persist_column_numeric_range(
+                                            cat,
+                                            table_name,
+                                            col_index,
+                                            col_def.numeric_range,
+                                            daemon_client,
+                                        ) {
+                                            error!("Persist column metadata (numeric range) failed: {}", e);
+                                        }
+                                    }
+                                }
+                            }
+                            if default_changed {
+                                info!(
+                                    "Updating default value for col {} of '{:?}/{}': {:?} -> {:?}.",
+                                    col_index + 1,
+                                    category,
+                                    sheet_name,
+                                    current_default_value,
+                                    default_to_store
+                                );
+                                col_def.default_value = default_to_store;
+                                if meta.category.is_some() {
+                                    if let Some(cat) = category {
+                                        let table_name = &meta.sheet_name;
+                                        if let Err(e) = crate::sheets::database::persist_column_default_value(
+                                            cat,
+                                            table_name,
+                                            col_index,
+                                            col_def.default_value.as_deref(),
+                                            daemon_client,
+                                        ) {
+                                            error!("Persist column metadata (default value) failed: {}", e);
+                                        }
+                                    }
+                                }
+                            }
                         } else {
                             warn!("Filter/Context/Hidden update failed: Index out of bounds.");
                             actions_ok = false;
@@ -297,6 +558,17 @@ pub fn show_column_options_popup(
                     }
                 }
                 Some(ValidatorTypeChoice::Structure) => (Some(ColumnValidator::Structure), true),
+                Some(ValidatorTypeChoice::Unique) => (
+                    Some(ColumnValidator::Unique {
+                        data_type: state.options_basic_type_select,
+                        case_sensitive: state.options_unique_case_sensitive,
+                    }),
+                    true,
+                ),
+                Some(ValidatorTypeChoice::Enum) => (
+                    Some(ColumnValidator::Enum(state.options_enum_values.clone())),
+                    true,
+                ),
                 None => (None, false),
             };
 
@@ -334,6 +606,45 @@ pub fn show_column_options_popup(
                         current_validator, new_validator_opt
                     ));
                     state.pending_validator_target_is_structure = new_is_structure;
+                    state.pending_structure_removal_preview = if old_was_structure && !new_is_structure {
+                        let child_sheet_name = format!(
+                            "{}_{}",
+                            state.options_column_target_sheet,
+                            current_name.clone().unwrap_or_default()
+                        );
+                        let target_category = state.options_column_target_category.clone();
+                        let target_sheet = state.options_column_target_sheet.clone();
+                        let has_child_table = registry.get_sheet(&target_category, &child_sheet_name).is_some();
+                        if has_child_table
+                            && !crate::sheets::systems::logic::update_column_validator::structure_removal::child_sheet_loaded(
+                                registry,
+                                &target_category,
+                                &child_sheet_name,
+                            )
+                        {
+                            // Still a stub: read it the way opening the parent sheet does
+                            crate::sheets::systems::ui_handlers::sheet_handlers::load_linked_target_sheets(
+                                state,
+                                registry,
+                                daemon_client,
+                                &target_sheet,
+                                &target_category,
+                            );
+                        }
+                        let child_row_count = has_child_table.then(|| {
+                            registry
+                                .get_sheet(&state.options_column_target_category, &child_sheet_name)
+                                .map_or(0, |s| s.grid.len())
+                        });
+                        Some(crate::ui::elements::editor::state::StructureRemovalPreview {
+                            child_sheet_name,
+                            child_row_count,
+                            flatten_into_cell: true,
+                            target_validator: new_validator_opt.clone(),
+                        })
+                    } else {
+                        None
+                    };
                     // Do NOT apply yet. User must press Apply again after confirmation cleared.
                 } else {
                     if !apply_validator_update(state, registry_immut, column_validator_writer) {
@@ -429,6 +740,12 @@ fn initialize_popup_state(state: &mut EditorWindowState, registry: &SheetRegistr
                 ai_include_in_send: f.ai_include_in_send,
                 deleted: false,
                 hidden: false, // Synthesized from parent, not a technical column
+                trim_numeric_zeros: false,
+                ai_read_only: false,
+                multiline: false,
+                enum_auto_add: false,
+                numeric_range: None,
+                default_value: None,
                 width: None,
                 structure_schema: f.structure_schema.clone(),
                 structure_column_order: f.structure_column_order.clone(),
@@ -449,15 +766,21 @@ fn initialize_popup_state(state: &mut EditorWindowState, registry: &SheetRegistr
         };
     if let Some(_guard) = Some(()) {
         let col_def = col_def_ref;
-        state.options_column_rename_input = col_def
+        state.options_column_rename_input = col_def.header.clone();
+        // Only a display name that differs from the physical name counts as custom
+        state.options_column_display_name_input = col_def
             .display_header
             .as_ref()
+            .filter(|d| !d.trim().is_empty() && d.as_str() != col_def.header)
             .cloned()
-            .unwrap_or_else(|| col_def.header.clone());
+            .unwrap_or_default();
         state.options_column_filter_input = col_def.filter.clone().unwrap_or_default();
-        // Initialize multi-term filter vector from stored filter (split by '|')
+        // Initialize multi-term filter vector from stored filter (split by '|');
+        // a regex filter stays one term since '|' is part of its pattern
         state.options_column_filter_terms = if state.options_column_filter_input.is_empty() {
             vec![String::new()]
+        } else if regex_filter_pattern(&state.options_column_filter_input).is_some() {
+            vec![state.options_column_filter_input.trim().to_string()]
         } else {
             state
                 .options_column_filter_input
@@ -472,6 +795,18 @@ fn initialize_popup_state(state: &mut EditorWindowState, registry: &SheetRegistr
         state.options_column_ai_context_input = col_def.ai_context.clone().unwrap_or_default();
         // Initialize hidden checkbox from column definition
         state.options_column_hidden_input = col_def.hidden;
+        state.options_column_trim_zeros_input = col_def.trim_numeric_zeros;
+        state.options_column_ai_read_only_input = col_def.ai_read_only;
+        state.options_column_multiline_input = col_def.multiline;
+        state.options_column_enum_auto_add_input = col_def.enum_auto_add;
+        let (range_min, range_max) = col_def.numeric_range.unwrap_or((None, None));
+        state.options_column_range_min_input = range_min.map(|v| v.to_string()).unwrap_or_default();
+        state.options_column_range_max_input = range_max.map(|v| v.to_string()).unwrap_or_default();
+        state.options_column_default_value_input = col_def.default_value.clone().unwrap_or_default();
+        state.options_unique_case_sensitive = false;
+        state.options_unique_scan_result = None;
+        state.options_enum_values = Vec::new();
+        state.options_enum_new_value_input.clear();
 
         match &col_def.validator {
             Some(ColumnValidator::Basic(data_type)) => {
@@ -484,6 +819,29 @@ fn initialize_popup_state(state: &mut EditorWindowState, registry: &SheetRegistr
                 state.options_existing_structure_key_parent_column = None;
                 state.options_structure_key_parent_column_temp = None;
             }
+            Some(ColumnValidator::Unique {
+                data_type,
+                case_sensitive,
+            }) => {
+                state.options_validator_type = Some(ValidatorTypeChoice::Unique);
+                state.options_basic_type_select = *data_type;
+                state.options_unique_case_sensitive = *case_sensitive;
+                state.options_link_target_sheet = None;
+                state.options_link_target_column_index = None;
+                state.options_structure_source_columns = vec![None];
+                state.options_existing_structure_key_parent_column = None;
+                state.options_structure_key_parent_column_temp = None;
+            }
+            Some(ColumnValidator::Enum(values)) => {
+                state.options_validator_type = Some(ValidatorTypeChoice::Enum);
+                state.options_enum_values = values.clone();
+                state.options_basic_type_select = ColumnDataType::String;
+                state.options_link_target_sheet = None;
+                state.options_link_target_column_index = None;
+                state.options_structure_source_columns = vec![None];
+                state.options_existing_structure_key_parent_column = None;
+                state.options_structure_key_parent_column_temp = None;
+            }
             Some(ColumnValidator::Linked {
                 target_sheet_name,
                 target_column_index,
@@ -538,6 +896,7 @@ fn initialize_popup_state(state: &mut EditorWindowState, registry: &SheetRegistr
             col_index, target_category, target_sheet
         );
         state.options_column_rename_input.clear();
+        state.options_column_display_name_input.clear();
         state.options_column_filter_input.clear();
         state.options_column_filter_terms = vec![String::new()];
         state.options_column_ai_context_input.clear();
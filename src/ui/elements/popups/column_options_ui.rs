@@ -1,7 +1,10 @@
 // src/ui/elements/popups/column_options_ui.rs
 use super::column_options_validator::{is_validator_config_valid, show_validator_section};
+use crate::sheets::definitions::ColumnDataType;
 use crate::sheets::resources::SheetRegistry;
+use crate::sheets::systems::logic::column_filter::regex_filter_pattern;
 use crate::ui::elements::editor::state::EditorWindowState;
+use crate::ui::elements::editor::table_body::bool_filter_value;
 use bevy::prelude::*; // Keep bevy prelude
 use bevy_egui::egui; // Import helper
 
@@ -12,6 +15,25 @@ pub(super) struct ColumnOptionsUiResult {
     pub close_via_x: bool,
 }
 
+/// Compile error of the `re:` term among the pending filter terms, if any
+fn pending_filter_regex_error(
+    state: &mut EditorWindowState,
+    category: &Option<String>,
+    sheet_name: &str,
+) -> Option<String> {
+    let col_index = state.options_column_target_index;
+    let pattern = state
+        .options_column_filter_terms
+        .iter()
+        .find_map(|t| regex_filter_pattern(t.trim()))?
+        .to_string();
+    state
+        .filter_regex_cache
+        .get_or_compile(category, sheet_name, col_index, &pattern)
+        .err()
+        .map(str::to_string)
+}
+
 /// Renders the main UI elements for the column options popup window.
 pub(super) fn show_column_options_window_ui(
     ctx: &egui::Context,
@@ -32,172 +54,239 @@ pub(super) fn show_column_options_window_ui(
         .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
         .open(&mut popup_open) // Control opening via state flag
         .show(ctx, |ui| {
-            // Get column definition using index (unused for minimal header, kept for potential future use)
-            let _column_def_opt = registry_immut
+            // Get column definition using index (its type selects the filter UI below)
+            let column_def_opt = registry_immut
                 .get_sheet(&popup_category, &popup_sheet_name) // Use cached category/name
                 .and_then(|s| s.metadata.as_ref())
                 .and_then(|m| m.columns.get(state.options_column_target_index));
 
             // Minimal header only (no verbose subtitles)
+            let mut filter_regex_error =
+                pending_filter_regex_error(state, &popup_category, &popup_sheet_name);
 
-            // Name field
+            // Name field (physical column name used by SQL, links and structure tables)
             ui.strong("Name");
-            let rename_resp = ui.add(
-                egui::TextEdit::singleline(&mut state.options_column_rename_input)
-                    .desired_width(150.0)
-                    .lock_focus(true), // Keep focus on open
-            );
+            let rename_resp = ui
+                .add(
+                    egui::TextEdit::singleline(&mut state.options_column_rename_input)
+                        .desired_width(150.0)
+                        .lock_focus(true), // Keep focus on open
+                )
+                .on_hover_text("Physical column name used in the database and by links");
             if rename_resp.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
                 if !state.options_column_rename_input.trim().is_empty()
                     && is_validator_config_valid(state)
+                    && filter_regex_error.is_none()
                 {
                     apply_clicked = true;
                 }
             }
-            ui.separator();
 
-            // --- Filter Section (Multi-term OR) with stacking ---
-            let _filter_title = ui.add(egui::Label::new(
-                egui::RichText::new("Filter (OR)").strong(),
-            ));
-            
-            // Build stacked representation: (normalized_key, display_value, count)
-            // We preserve insertion order by using the first occurrence for display
-            let mut stacked_filters: Vec<(String, String, usize)> = Vec::new();
-            let mut seen_normalized: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
-            
-            for term in state.options_column_filter_terms.iter() {
-                let trimmed = term.trim();
-                if trimmed.is_empty() {
-                    continue;
+            // Display name field (UI-only header)
+            ui.strong("Display Name");
+            let display_resp = ui
+                .add(
+                    egui::TextEdit::singleline(&mut state.options_column_display_name_input)
+                        .desired_width(150.0)
+                        .hint_text(state.options_column_rename_input.trim()),
+                )
+                .on_hover_text(
+                    "Header shown in the editor. Leave empty to show the column name.\nRenaming here never changes the database column.",
+                );
+            if display_resp.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                if !state.options_column_rename_input.trim().is_empty()
+                    && is_validator_config_valid(state)
+                    && filter_regex_error.is_none()
+                {
+                    apply_clicked = true;
                 }
-                let normalized = trimmed.to_lowercase();
-                if let Some(&idx) = seen_normalized.get(&normalized) {
-                    // Increment count for existing entry
-                    stacked_filters[idx].2 += 1;
-                } else {
-                    // Add new entry
-                    seen_normalized.insert(normalized.clone(), stacked_filters.len());
-                    stacked_filters.push((normalized, trimmed.to_string(), 1));
+            }
+            ui.separator();
+
+            // --- Bool columns: tri-state value filter instead of free-text terms ---
+            let is_bool_column = column_def_opt
+                .map_or(false, |c| matches!(c.data_type, ColumnDataType::Bool));
+            if is_bool_column {
+                ui.strong("Filter");
+                let current = state
+                    .options_column_filter_terms
+                    .iter()
+                    .map(|t| t.trim())
+                    .find(|t| !t.is_empty())
+                    .and_then(bool_filter_value);
+                let mut selected = current;
+                ui.horizontal(|ui_h| {
+                    ui_h.radio_value(&mut selected, None, "All");
+                    ui_h.radio_value(&mut selected, Some(true), "Only true");
+                    ui_h.radio_value(&mut selected, Some(false), "Only false");
+                });
+                if selected != current {
+                    state.options_column_filter_terms =
+                        vec![selected.map(|v| v.to_string()).unwrap_or_default()];
                 }
+                ui.separator();
             }
-            
-            // Sort by display name (alphabetically) for easier finding
-            stacked_filters.sort_by(|a, b| a.1.to_lowercase().cmp(&b.1.to_lowercase()));
-            
-            // Track changes to apply back
-            let mut term_to_add: Option<String> = None;
-            let mut entry_to_reduce: Option<String> = None;
-            let mut entry_to_remove: Option<String> = None;
-            
-            // Scrollable area for filter entries
-            const FILTER_TERM_MAX_WIDTH: f32 = 180.0;
-            
-            egui::ScrollArea::vertical()
-                .id_salt("filter_scroll")
-                .max_height(150.0)
-                .show(ui, |scroll_ui| {
-                    for (normalized, display, count) in stacked_filters.iter() {
-                        scroll_ui.horizontal(|row_ui| {
-                            // Fixed-width area for filter term with text wrapping
-                            row_ui.allocate_ui_with_layout(
-                                egui::vec2(FILTER_TERM_MAX_WIDTH, 0.0),
-                                egui::Layout::left_to_right(egui::Align::Center).with_main_wrap(true),
-                                |term_ui| {
-                                    term_ui.label(display.as_str());
-                                },
-                            );
-                            
-                            // Show count if 2+
-                            if *count >= 2 {
-                                row_ui.label(format!("({})", count));
-                                // Show "-" button to reduce count
-                                if row_ui.small_button("-").on_hover_text("Reduce count by 1").clicked() {
-                                    entry_to_reduce = Some(normalized.clone());
+
+            // --- Filter Section (Multi-term OR) with stacking ---
+            if !is_bool_column {
+                let _filter_title = ui.add(egui::Label::new(
+                    egui::RichText::new("Filter (OR)").strong(),
+                ));
+
+                // Build stacked representation: (normalized_key, display_value, count)
+                // We preserve insertion order by using the first occurrence for display
+                let mut stacked_filters: Vec<(String, String, usize)> = Vec::new();
+                let mut seen_normalized: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
+
+                for term in state.options_column_filter_terms.iter() {
+                    let trimmed = term.trim();
+                    if trimmed.is_empty() {
+                        continue;
+                    }
+                    let normalized = trimmed.to_lowercase();
+                    if let Some(&idx) = seen_normalized.get(&normalized) {
+                        // Increment count for existing entry
+                        stacked_filters[idx].2 += 1;
+                    } else {
+                        // Add new entry
+                        seen_normalized.insert(normalized.clone(), stacked_filters.len());
+                        stacked_filters.push((normalized, trimmed.to_string(), 1));
+                    }
+                }
+
+                // Sort by display name (alphabetically) for easier finding
+                stacked_filters.sort_by(|a, b| a.1.to_lowercase().cmp(&b.1.to_lowercase()));
+
+                // Track changes to apply back
+                let mut term_to_add: Option<String> = None;
+                let mut entry_to_reduce: Option<String> = None;
+                let mut entry_to_remove: Option<String> = None;
+
+                // Scrollable area for filter entries
+                const FILTER_TERM_MAX_WIDTH: f32 = 180.0;
+
+                egui::ScrollArea::vertical()
+                    .id_salt("filter_scroll")
+                    .max_height(150.0)
+                    .show(ui, |scroll_ui| {
+                        for (normalized, display, count) in stacked_filters.iter() {
+                            scroll_ui.horizontal(|row_ui| {
+                                // Fixed-width area for filter term with text wrapping
+                                row_ui.allocate_ui_with_layout(
+                                    egui::vec2(FILTER_TERM_MAX_WIDTH, 0.0),
+                                    egui::Layout::left_to_right(egui::Align::Center).with_main_wrap(true),
+                                    |term_ui| {
+                                        term_ui.label(display.as_str());
+                                    },
+                                );
+
+                                // Show count if 2+
+                                if *count >= 2 {
+                                    row_ui.label(format!("({})", count));
+                                    // Show "-" button to reduce count
+                                    if row_ui.small_button("-").on_hover_text("Reduce count by 1").clicked() {
+                                        entry_to_reduce = Some(normalized.clone());
+                                    }
                                 }
+
+                                // Always show x button to remove entirely
+                                if row_ui.small_button("x").on_hover_text("Remove all").clicked() {
+                                    entry_to_remove = Some(normalized.clone());
+                                }
+                            });
+                        }
+                    });
+
+                // Input field for adding new filter terms
+                ui.horizontal(|ui_h| {
+                    // Create a temp string for input - we'll add it when Enter is pressed
+                    let input_id = egui::Id::new("filter_new_term_input");
+                    let mut input_text = ui_h.memory(|mem| {
+                        mem.data.get_temp::<String>(input_id).unwrap_or_default()
+                    });
+
+                    let resp = ui_h.add(
+                        egui::TextEdit::singleline(&mut input_text)
+                            .desired_width(150.0)
+                            .hint_text("add filter term (re: for regex)"),
+                    );
+
+                    ui_h.memory_mut(|mem| {
+                        mem.data.insert_temp(input_id, input_text.clone());
+                    });
+
+                    // Add on Enter or button click
+                    let add_clicked = ui_h
+                        .small_button("+")
+                        .on_hover_text("Add filter term. A term starting with re: is a regular expression and replaces the other terms.")
+                        .clicked();
+                    if (resp.lost_focus() && ui_h.input(|inp| inp.key_pressed(egui::Key::Enter))) || add_clicked {
+                        let trimmed = input_text.trim();
+                        if !trimmed.is_empty() {
+                            term_to_add = Some(trimmed.to_string());
+                            // Clear the input
+                            ui_h.memory_mut(|mem| {
+                                mem.data.insert_temp::<String>(input_id, String::new());
+                            });
+                        } else if resp.lost_focus() && ui_h.input(|inp| inp.key_pressed(egui::Key::Enter)) {
+                            // Enter on valid filter state triggers apply
+                            if is_validator_config_valid(state) && filter_regex_error.is_none() {
+                                apply_clicked = true;
                             }
-                            
-                            // Always show x button to remove entirely
-                            if row_ui.small_button("x").on_hover_text("Remove all").clicked() {
-                                entry_to_remove = Some(normalized.clone());
-                            }
-                        });
+                        }
                     }
                 });
-            
-            // Input field for adding new filter terms
-            ui.horizontal(|ui_h| {
-                // Create a temp string for input - we'll add it when Enter is pressed
-                let input_id = egui::Id::new("filter_new_term_input");
-                let mut input_text = ui_h.memory(|mem| {
-                    mem.data.get_temp::<String>(input_id).unwrap_or_default()
-                });
-                
-                let resp = ui_h.add(
-                    egui::TextEdit::singleline(&mut input_text)
-                        .desired_width(150.0)
-                        .hint_text("add filter term"),
-                );
-                
-                ui_h.memory_mut(|mem| {
-                    mem.data.insert_temp(input_id, input_text.clone());
-                });
-                
-                // Add on Enter or button click
-                let add_clicked = ui_h.small_button("+").on_hover_text("Add filter term").clicked();
-                if (resp.lost_focus() && ui_h.input(|inp| inp.key_pressed(egui::Key::Enter))) || add_clicked {
-                    let trimmed = input_text.trim();
-                    if !trimmed.is_empty() {
-                        term_to_add = Some(trimmed.to_string());
-                        // Clear the input
-                        ui_h.memory_mut(|mem| {
-                            mem.data.insert_temp::<String>(input_id, String::new());
-                        });
-                    } else if resp.lost_focus() && ui_h.input(|inp| inp.key_pressed(egui::Key::Enter)) {
-                        // Enter on valid filter state triggers apply
-                        if is_validator_config_valid(state) {
-                            apply_clicked = true;
-                        }
+
+                // Apply changes
+                if let Some(term) = term_to_add {
+                    // A regex filter is the column's only term ('|' belongs to its pattern)
+                    if regex_filter_pattern(&term).is_some() {
+                        state.options_column_filter_terms = vec![term];
+                    } else {
+                        state
+                            .options_column_filter_terms
+                            .retain(|t| regex_filter_pattern(t).is_none());
+                        state.options_column_filter_terms.push(term);
                     }
                 }
-            });
-            
-            // Apply changes
-            if let Some(term) = term_to_add {
-                state.options_column_filter_terms.push(term);
-            }
-            
-            if let Some(normalized_to_reduce) = entry_to_reduce {
-                // Remove one occurrence of this normalized term
-                let mut found = false;
-                state.options_column_filter_terms.retain(|t| {
-                    if !found && t.trim().to_lowercase() == normalized_to_reduce {
-                        found = true;
-                        false // Remove this one
-                    } else {
-                        true
+
+                if let Some(normalized_to_reduce) = entry_to_reduce {
+                    // Remove one occurrence of this normalized term
+                    let mut found = false;
+                    state.options_column_filter_terms.retain(|t| {
+                        if !found && t.trim().to_lowercase() == normalized_to_reduce {
+                            found = true;
+                            false // Remove this one
+                        } else {
+                            true
+                        }
+                    });
+                }
+
+                if let Some(normalized_to_remove) = entry_to_remove {
+                    // Remove all occurrences of this normalized term
+                    state.options_column_filter_terms.retain(|t| {
+                        t.trim().to_lowercase() != normalized_to_remove
+                    });
+                }
+
+                // Ensure at least one empty slot if all removed
+                if state.options_column_filter_terms.is_empty() {
+                    state.options_column_filter_terms.push(String::new());
+                }
+
+                ui.horizontal(|ui_h| {
+                    if ui_h.button("Clear All").clicked() {
+                        state.options_column_filter_terms = vec![String::new()];
                     }
                 });
-            }
-            
-            if let Some(normalized_to_remove) = entry_to_remove {
-                // Remove all occurrences of this normalized term
-                state.options_column_filter_terms.retain(|t| {
-                    t.trim().to_lowercase() != normalized_to_remove
-                });
-            }
-            
-            // Ensure at least one empty slot if all removed
-            if state.options_column_filter_terms.is_empty() {
-                state.options_column_filter_terms.push(String::new());
-            }
-            
-            ui.horizontal(|ui_h| {
-                if ui_h.button("Clear All").clicked() {
-                    state.options_column_filter_terms = vec![String::new()];
+                filter_regex_error =
+                    pending_filter_regex_error(state, &popup_category, &popup_sheet_name);
+                if let Some(err) = &filter_regex_error {
+                    ui.colored_label(egui::Color32::RED, format!("Invalid regex: {}", err));
                 }
-            });
-            ui.separator();
+                ui.separator();
+            }
 
             // AI Context Section - starts at 2 rows, grows to max 5 based on content
             ui.strong("AI Context");
@@ -218,6 +307,8 @@ pub(super) fn show_column_options_window_ui(
                             .desired_rows(display_rows as usize),
                     );
                 });
+            ui.checkbox(&mut state.options_column_ai_read_only_input, "AI read-only")
+                .on_hover_text("Send this column to the AI as context, but never apply its suggested changes");
             ui.separator();
 
             // --- Hidden Column Checkbox (only for non-structure columns) ---
@@ -235,6 +326,107 @@ pub(super) fn show_column_options_window_ui(
                 ui.separator();
             }
 
+            // --- Numeric display option (F64 only, display-only) ---
+            let is_f64_column = matches!(
+                state.options_validator_type,
+                Some(crate::ui::elements::editor::state::ValidatorTypeChoice::Basic)
+                    | Some(crate::ui::elements::editor::state::ValidatorTypeChoice::Unique)
+            ) && state.options_basic_type_select == crate::sheets::definitions::ColumnDataType::F64;
+            if is_f64_column {
+                ui.horizontal(|ui_h| {
+                    ui_h.checkbox(&mut state.options_column_trim_zeros_input, "Trim trailing zeros")
+                        .on_hover_text("Show 1.50000 as 1.5 and 100.0 as 100. Stored values keep full precision.");
+                });
+                ui.separator();
+            }
+
+            // --- Long text (String only): preview in the grid, edited in a dialog ---
+            let is_string_column = matches!(
+                state.options_validator_type,
+                Some(crate::ui::elements::editor::state::ValidatorTypeChoice::Basic)
+                    | Some(crate::ui::elements::editor::state::ValidatorTypeChoice::Unique)
+            ) && state.options_basic_type_select == crate::sheets::definitions::ColumnDataType::String;
+            if is_string_column {
+                ui.horizontal(|ui_h| {
+                    ui_h.checkbox(&mut state.options_column_multiline_input, "Long text (multi-line)")
+                        .on_hover_text("Cells show the first line as a preview; ⤢ opens a resizable editor where Enter starts a new line.");
+                });
+                ui.separator();
+            }
+
+            // --- Numeric range (I64/F64), enforced on cell edits ---
+            let is_numeric_column = matches!(
+                state.options_validator_type,
+                Some(crate::ui::elements::editor::state::ValidatorTypeChoice::Basic)
+                    | Some(crate::ui::elements::editor::state::ValidatorTypeChoice::Unique)
+            ) && matches!(
+                state.options_basic_type_select,
+                crate::sheets::definitions::ColumnDataType::I64
+                    | crate::sheets::definitions::ColumnDataType::F64
+            );
+            if is_numeric_column {
+                ui.horizontal(|ui_h| {
+                    ui_h.label("Allowed range:");
+                    ui_h.add(
+                        egui::TextEdit::singleline(&mut state.options_column_range_min_input)
+                            .hint_text("min")
+                            .desired_width(70.0),
+                    );
+                    ui_h.label("to");
+                    ui_h.add(
+                        egui::TextEdit::singleline(&mut state.options_column_range_max_input)
+                            .hint_text("max")
+                            .desired_width(70.0),
+                    );
+                })
+                .response
+                .on_hover_text("Inclusive bounds; leave a side blank for no limit. Out-of-range edits are rejected.");
+                if let Err(e) = crate::sheets::systems::logic::update_cell::parse_numeric_range(
+                    &state.options_column_range_min_input,
+                    &state.options_column_range_max_input,
+                ) {
+                    ui.colored_label(egui::Color32::YELLOW, e);
+                }
+                ui.separator();
+            }
+
+            // --- Default value pre-filled into new rows (checked against the column type) ---
+            let default_value_type = match state.options_validator_type {
+                Some(crate::ui::elements::editor::state::ValidatorTypeChoice::Basic)
+                | Some(crate::ui::elements::editor::state::ValidatorTypeChoice::Unique) => {
+                    Some(state.options_basic_type_select)
+                }
+                Some(crate::ui::elements::editor::state::ValidatorTypeChoice::Linked)
+                | Some(crate::ui::elements::editor::state::ValidatorTypeChoice::Enum) => {
+                    Some(crate::sheets::definitions::ColumnDataType::String)
+                }
+                _ => None,
+            };
+            if let Some(data_type) = default_value_type {
+                let is_date = data_type == crate::sheets::definitions::ColumnDataType::Date;
+                ui.horizontal(|ui_h| {
+                    ui_h.label("Default value:");
+                    ui_h.add(
+                        egui::TextEdit::singleline(&mut state.options_column_default_value_input)
+                            .hint_text(if is_date { "e.g. @today" } else { "none" })
+                            .desired_width(150.0),
+                    );
+                })
+                .response
+                .on_hover_text(if is_date {
+                    "Pre-filled into new rows. @today inserts the date the row is added."
+                } else {
+                    "Pre-filled into new rows; leave blank for none."
+                });
+                if let Err(e) = crate::sheets::systems::logic::column_defaults::parse_default_value(
+                    data_type,
+                    &state.options_column_default_value_input,
+                ) {
+                    ui.colored_label(egui::Color32::YELLOW, e);
+                }
+                ui.separator();
+            }
+
             // --- Validator Section (using helper) ---
             show_validator_section(ui, state, registry_immut);
             ui.separator();
@@ -245,6 +437,7 @@ pub(super) fn show_column_options_window_ui(
             ui.horizontal(|ui| {
                 let apply_enabled = !state.options_column_rename_input.trim().is_empty()
                     && is_validator_config_valid(state)
+                    && filter_regex_error.is_none()
                     && !state.pending_validator_change_requires_confirmation; // disable while awaiting confirm
                 if ui
                     .add_enabled(apply_enabled, egui::Button::new("Apply"))
@@ -51,6 +51,17 @@ pub fn apply_validator_update(
             }
         }
         Some(ValidatorTypeChoice::Structure) => (Some(ColumnValidator::Structure), true),
+        Some(ValidatorTypeChoice::Unique) => (
+            Some(ColumnValidator::Unique {
+                data_type: state.options_basic_type_select,
+                case_sensitive: state.options_unique_case_sensitive,
+            }),
+            true,
+        ),
+        Some(ValidatorTypeChoice::Enum) => (
+            Some(ColumnValidator::Enum(state.options_enum_values.clone())),
+            true,
+        ),
         None => {
             warn!("Validator update failed: Invalid internal state.");
             (None, false) // Action failed
@@ -106,6 +117,7 @@ pub fn apply_validator_update(
             } else {
                 None
             },
+            flatten_structure_children: false,
         });
         // NOTE: key_parent_col captured; actual persistence must be handled by downstream event handler updating metadata, which should look at pending_structure_key_apply or similar. If such mechanism exists, it can be extended; for now we rely on separate pending_structure_key_apply logic elsewhere if needed.
     } else {
@@ -5,6 +5,11 @@ use crate::sheets::{
     definitions::{ColumnDataType, ColumnValidator},
     resources::SheetRegistry,
 };
+use crate::sheets::systems::logic::enum_validator::canonical_enum_value;
+use crate::sheets::systems::logic::unique_validator::duplicate_groups;
+use crate::sheets::systems::logic::update_column_validator::structure_naming::{
+    resolve_structure_column_header, structure_child_table_name,
+};
 use crate::ui::elements::editor::state::{EditorWindowState, ValidatorTypeChoice};
 use bevy_egui::egui;
 use std::collections::HashSet;
@@ -45,6 +50,8 @@ pub fn show_validator_section(
             ui.radio_value(&mut choice, ValidatorTypeChoice::Basic, "Basic Type");
             ui.radio_value(&mut choice, ValidatorTypeChoice::Linked, "Linked Column");
             ui.radio_value(&mut choice, ValidatorTypeChoice::Structure, "Structure");
+            ui.radio_value(&mut choice, ValidatorTypeChoice::Unique, "Unique");
+            ui.radio_value(&mut choice, ValidatorTypeChoice::Enum, "Enum");
         });
         state.options_validator_type = Some(choice); // Update state
 
@@ -58,6 +65,13 @@ pub fn show_validator_section(
             ValidatorTypeChoice::Structure => {
                 show_structure_validator_ui(ui, state, registry_immut, existing_is_structure);
             }
+            ValidatorTypeChoice::Unique => {
+                show_basic_type_selector(ui, state);
+                show_unique_validator_ui(ui, state, registry_immut);
+            }
+            ValidatorTypeChoice::Enum => {
+                show_enum_validator_ui(ui, state, registry_immut);
+            }
         }
     } else {
         ui.colored_label(egui::Color32::RED, "Error loading validator options.");
@@ -72,7 +86,7 @@ fn show_basic_type_selector(ui: &mut egui::Ui, state: &mut EditorWindowState) {
             .selected_text(format!("{:?}", state.options_basic_type_select))
             .show_ui(ui, |ui| {
                 use ColumnDataType::*;
-                let all_types = [String, Bool, I64, F64, Link];
+                let all_types = [String, Bool, I64, F64, Link, Date];
                 for t in all_types.iter() {
                     ui.selectable_value(
                         &mut state.options_basic_type_select,
@@ -84,6 +98,163 @@ fn show_basic_type_selector(ui: &mut egui::Ui, state: &mut EditorWindowState) {
     });
 }
 
+/// Renders the Unique validator options and the duplicate scan of the column's current values
+fn show_unique_validator_ui(
+    ui: &mut egui::Ui,
+    state: &mut EditorWindowState,
+    registry_immut: &SheetRegistry,
+) {
+    let is_text = matches!(
+        state.options_basic_type_select,
+        ColumnDataType::String | ColumnDataType::Link
+    );
+    ui.add_enabled_ui(is_text, |ui| {
+        ui.checkbox(&mut state.options_unique_case_sensitive, "Case sensitive")
+            .on_hover_text("When off, 'Sword' and 'sword' count as the same value");
+    });
+    ui.horizontal(|ui| {
+        if ui
+            .button("Scan for duplicates")
+            .on_hover_text("List values that already appear in more than one row")
+            .clicked()
+        {
+            state.options_unique_scan_result = registry_immut
+                .get_sheet(
+                    &state.options_column_target_category,
+                    &state.options_column_target_sheet,
+                )
+                .map(|sheet| {
+                    duplicate_groups(
+                        &sheet.grid,
+                        state.options_column_target_index,
+                        state.options_basic_type_select,
+                        state.options_unique_case_sensitive,
+                    )
+                });
+        }
+        if state.options_unique_scan_result.is_some() && ui.small_button("Clear").clicked() {
+            state.options_unique_scan_result = None;
+        }
+    });
+    match &state.options_unique_scan_result {
+        Some(groups) if groups.is_empty() => {
+            ui.label("No duplicates found.");
+        }
+        Some(groups) => {
+            ui.colored_label(
+                egui::Color32::from_rgb(220, 120, 40),
+                format!("{} duplicated value(s):", groups.len()),
+            );
+            egui::ScrollArea::vertical()
+                .id_salt("unique_scan_results")
+                .max_height(120.0)
+                .show(ui, |ui| {
+                    for (value, rows) in groups {
+                        let row_list = rows
+                            .iter()
+                            .map(|r| (r + 1).to_string())
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        ui.label(format!("'{}' in rows {}", value, row_list));
+                    }
+                });
+        }
+        None => {}
+    }
+}
+
+/// Renders the editable list of allowed values of the Enum validator
+fn show_enum_validator_ui(
+    ui: &mut egui::Ui,
+    state: &mut EditorWindowState,
+    registry_immut: &SheetRegistry,
+) {
+    ui.label("Allowed values (dropdown order):");
+    let count = state.options_enum_values.len();
+    // Index of a value to swap with the one above it
+    let mut swap_with_previous: Option<usize> = None;
+    let mut remove: Option<usize> = None;
+    egui::ScrollArea::vertical()
+        .id_salt("enum_values_list")
+        .max_height(160.0)
+        .show(ui, |ui| {
+            for (idx, value) in state.options_enum_values.iter().enumerate() {
+                ui.horizontal(|ui| {
+                    if ui.add_enabled(idx > 0, egui::Button::new("⬆").small()).clicked() {
+                        swap_with_previous = Some(idx);
+                    }
+                    if ui.add_enabled(idx + 1 < count, egui::Button::new("⬇").small()).clicked() {
+                        swap_with_previous = Some(idx + 1);
+                    }
+                    if ui.small_button("✖").on_hover_text("Remove").clicked() {
+                        remove = Some(idx);
+                    }
+                    ui.label(value);
+                });
+            }
+        });
+    if let Some(idx) = swap_with_previous {
+        state.options_enum_values.swap(idx - 1, idx);
+    }
+    if let Some(idx) = remove {
+        state.options_enum_values.remove(idx);
+    }
+
+    ui.horizontal(|ui| {
+        let resp = ui.add(
+            egui::TextEdit::singleline(&mut state.options_enum_new_value_input)
+                .hint_text("New value")
+                .desired_width(160.0),
+        );
+        let entered = resp.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+        let new_value = state.options_enum_new_value_input.trim().to_string();
+        let already_listed = canonical_enum_value(&new_value, &state.options_enum_values).is_some();
+        let can_add = !new_value.is_empty() && !already_listed;
+        let add_clicked = ui.add_enabled(can_add, egui::Button::new("Add")).clicked();
+        if can_add && (add_clicked || entered) {
+            state.options_enum_values.push(new_value);
+            state.options_enum_new_value_input.clear();
+            resp.request_focus();
+        }
+        if already_listed {
+            ui.weak("already listed");
+        }
+    });
+
+    if ui
+        .button("Add values from column")
+        .on_hover_text("Add every distinct value already used in this column")
+        .clicked()
+    {
+        if let Some(sheet) = registry_immut.get_sheet(
+            &state.options_column_target_category,
+            &state.options_column_target_sheet,
+        ) {
+            for row in &sheet.grid {
+                let Some(cell) = row.get(state.options_column_target_index) else {
+                    continue;
+                };
+                let value = cell.trim();
+                if !value.is_empty() && canonical_enum_value(value, &state.options_enum_values).is_none() {
+                    state.options_enum_values.push(value.to_string());
+                }
+            }
+        }
+    }
+
+    ui.checkbox(
+        &mut state.options_column_enum_auto_add_input,
+        "Add unknown values automatically",
+    )
+    .on_hover_text("When on, a value outside the list is added to it instead of being rejected");
+    if state.options_enum_values.is_empty() && !state.options_column_enum_auto_add_input {
+        ui.colored_label(
+            egui::Color32::YELLOW,
+            "Add at least one value (or allow unknown values).",
+        );
+    }
+}
+
 /// Renders the linked column target sheet and column selectors
 fn show_linked_column_selectors(
     ui: &mut egui::Ui,
@@ -200,6 +371,7 @@ fn show_structure_validator_ui(
         )
         .and_then(|s| s.metadata.as_ref());
 
+    show_structure_table_name(ui, state, registry_immut, existing_is_structure);
     if existing_is_structure {
         show_existing_structure_ui(ui, state, meta_opt, registry_immut);
     } else {
@@ -207,6 +379,48 @@ fn show_structure_validator_ui(
     }
 }
 
+/// Shows the physical child table name, and the renamed column when the default name is taken
+fn show_structure_table_name(
+    ui: &mut egui::Ui,
+    state: &EditorWindowState,
+    registry_immut: &SheetRegistry,
+    existing_is_structure: bool,
+) {
+    let sheet_name = &state.options_column_target_sheet;
+    let Some(header) = registry_immut
+        .get_sheet(&state.options_column_target_category, sheet_name)
+        .and_then(|s| s.metadata.as_ref())
+        .and_then(|m| m.columns.get(state.options_column_target_index))
+        .map(|c| c.header.clone())
+    else {
+        return;
+    };
+    let override_header = if existing_is_structure {
+        None
+    } else {
+        resolve_structure_column_header(
+            registry_immut,
+            &state.options_column_target_category,
+            sheet_name,
+            state.options_column_target_index,
+            state.structure_table_naming,
+        )
+    };
+    let table_name =
+        structure_child_table_name(sheet_name, override_header.as_deref().unwrap_or(&header));
+    ui.label(format!("Child table: {}", table_name));
+    if let Some(new_header) = override_header {
+        ui.colored_label(
+            egui::Color32::YELLOW,
+            format!(
+                "'{}' is already used by another sheet; this column will be stored as '{}' (its visible name is kept).",
+                structure_child_table_name(sheet_name, &header),
+                new_header
+            ),
+        );
+    }
+}
+
 /// Renders UI for editing an existing structure validator
 fn show_existing_structure_ui(
     ui: &mut egui::Ui,
@@ -12,6 +12,11 @@ pub fn is_validator_config_valid(state: &EditorWindowState) -> bool {
         }
         Some(ValidatorTypeChoice::Structure) => true, // Always valid; confirmation handles risk
         Some(ValidatorTypeChoice::Basic) => true,     // Always valid
+        Some(ValidatorTypeChoice::Unique) => true,    // Existing duplicates are reported, not blocked
+        // An empty list only makes sense when unknown values are added automatically
+        Some(ValidatorTypeChoice::Enum) => {
+            !state.options_enum_values.is_empty() || state.options_column_enum_auto_add_input
+        }
         None => false,
     }
 }
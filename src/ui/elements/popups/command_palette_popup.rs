@@ -0,0 +1,339 @@
+// src/ui/elements/popups/command_palette_popup.rs
+use std::sync::OnceLock;
+
+use bevy_egui::egui;
+
+use crate::sheets::database::daemon_client::DaemonClient;
+use crate::sheets::events::{
+    RequestAddColumn, RequestCompactDatabase, RequestExportSheetToCsv, RequestExportSheetToJsonl,
+    RequestExportSheetToParquet, RequestValidateStructureLinks, SheetOperationFeedback,
+};
+use crate::sheets::resources::SheetRegistry;
+use crate::sheets::systems::io::{get_default_data_base_path, metadata_persistence};
+use crate::sheets::systems::logic::fuzzy_match::rank_fuzzy_matches;
+use crate::sheets::systems::ui_handlers::sheet_handlers;
+use crate::ui::elements::editor::editor_sheet_display::display_controls::request_quick_add_row;
+use crate::ui::elements::editor::main_editor::SheetEventWriters;
+use crate::ui::elements::editor::state::{AiModeState, SheetInteractionState};
+use crate::ui::elements::editor::EditorWindowState;
+
+/// Most actions listed at once; refine the query to reach the rest
+const MAX_PALETTE_RESULTS: usize = 14;
+
+/// What an action may touch. Targets come from the current selection in `state`.
+pub struct PaletteContext<'a, 'w> {
+    pub state: &'a mut EditorWindowState,
+    pub registry: &'a mut SheetRegistry,
+    pub writers: &'a mut SheetEventWriters<'w>,
+    pub daemon_client: &'a DaemonClient,
+}
+
+/// Runs an action; an `Err` is shown as operation feedback
+pub type PaletteActionFn = fn(&mut PaletteContext<'_, '_>) -> Result<(), String>;
+
+/// Pairs a name with its action (lets each closure coerce to `PaletteActionFn`)
+fn action(name: &'static str, run: PaletteActionFn) -> (&'static str, PaletteActionFn) {
+    (name, run)
+}
+
+/// Selected (category, sheet), or an error naming what the action needs
+fn selected_sheet(state: &EditorWindowState) -> Result<(Option<String>, String), String> {
+    state
+        .selected_sheet_name
+        .clone()
+        .map(|sheet| (state.selected_category.clone(), sheet))
+        .ok_or_else(|| "Select a sheet first.".to_string())
+}
+
+/// Selected sheet of a category database, for actions that work on the database file
+fn selected_db_sheet(state: &EditorWindowState) -> Result<(String, String), String> {
+    match selected_sheet(state)? {
+        (Some(category), sheet) => Ok((category, sheet)),
+        (None, _) => Err("This action needs a sheet stored in a category database.".to_string()),
+    }
+}
+
+fn selected_db_path(state: &EditorWindowState) -> Result<std::path::PathBuf, String> {
+    let category = state
+        .selected_category
+        .clone()
+        .ok_or_else(|| "Select a category database first.".to_string())?;
+    Ok(get_default_data_base_path().join(format!("{}.db", category)))
+}
+
+/// The palette's actions, built on first use
+pub fn palette_actions() -> &'static [(&'static str, PaletteActionFn)] {
+    static ACTIONS: OnceLock<Vec<(&'static str, PaletteActionFn)>> = OnceLock::new();
+    ACTIONS.get_or_init(|| {
+        vec![
+            action("Add Row", |cx| {
+                selected_db_sheet(cx.state)?;
+                request_quick_add_row(cx.state, &mut cx.writers.add_row);
+                Ok(())
+            }),
+            action("Add Column", |cx| {
+                let (category, sheet_name) = selected_db_sheet(cx.state)?;
+                cx.writers.add_column.write(RequestAddColumn {
+                    category: Some(category),
+                    sheet_name,
+                });
+                Ok(())
+            }),
+            action("Find in Sheet", |cx| {
+                selected_sheet(cx.state)?;
+                cx.state.show_sheet_find_bar = true;
+                Ok(())
+            }),
+            action("Go to Sheet…", |cx| {
+                cx.state.show_quick_open = true;
+                cx.state.quick_open_query.clear();
+                cx.state.quick_open_highlight = 0;
+                Ok(())
+            }),
+            action("Run AI (AI Mode)", |cx| {
+                selected_sheet(cx.state)?;
+                if !cx.state.ai_available() {
+                    return Err("AI features are disabled or unavailable.".to_string());
+                }
+                cx.state.show_edit_mode_panel = false;
+                cx.state.show_toybox_menu = false;
+                cx.state.current_interaction_mode = SheetInteractionState::AiModeActive;
+                cx.state.ai_mode = AiModeState::Preparing;
+                cx.state.ai_selected_rows.clear();
+                Ok(())
+            }),
+            action("Delete Mode", |cx| {
+                selected_sheet(cx.state)?;
+                cx.state.show_toybox_menu = false;
+                cx.state.show_edit_mode_panel = true;
+                cx.state.current_interaction_mode = SheetInteractionState::DeleteModeActive;
+                Ok(())
+            }),
+            action("Exit Mode", |cx| {
+                cx.state.reset_interaction_modes_and_selections();
+                cx.state.show_edit_mode_panel = false;
+                Ok(())
+            }),
+            action("Rename Sheet…", |cx| {
+                selected_sheet(cx.state)?;
+                sheet_handlers::handle_rename_sheet_request(cx.state);
+                Ok(())
+            }),
+            action("Duplicate Sheet…", |cx| {
+                let (category, sheet_name) = selected_sheet(cx.state)?;
+                let registry = &*cx.registry;
+                cx.state.duplicate_sheet_name_input =
+                    crate::sheets::systems::logic::duplicate_sheet::suggest_duplicate_name(&sheet_name, |candidate| {
+                        registry.get_sheet(&category, candidate).is_some()
+                    });
+                cx.state.duplicate_sheet_target = Some((category, sheet_name));
+                Ok(())
+            }),
+            action("Delete Sheet…", |cx| {
+                selected_sheet(cx.state)?;
+                sheet_handlers::handle_delete_sheet_request(cx.state);
+                Ok(())
+            }),
+            action("Toggle Sheet Hidden", |cx| {
+                let (category, sheet_name) = selected_sheet(cx.state)?;
+                let metadata = cx
+                    .registry
+                    .get_sheet_mut(&category, &sheet_name)
+                    .and_then(|sheet| sheet.metadata.as_mut())
+                    .map(|meta| {
+                        meta.hidden = !meta.hidden;
+                        meta.clone()
+                    })
+                    .ok_or_else(|| format!("No metadata for '{}'.", sheet_name))?;
+                metadata_persistence::save_sheet_metadata(cx.registry, &metadata, category, cx.daemon_client);
+                cx.state.force_filter_recalculation = true;
+                Ok(())
+            }),
+            action("Show/Hide Hidden Sheets", |cx| {
+                cx.state.show_hidden_sheets = !cx.state.show_hidden_sheets;
+                Ok(())
+            }),
+            action("Reload Sheet from Database…", |cx| {
+                let (category, sheet_name) = selected_db_sheet(cx.state)?;
+                sheet_handlers::handle_reload_sheet_request(cx.state, &Some(category), &sheet_name);
+                Ok(())
+            }),
+            action("Revert Sheet to Saved JSON…", |cx| {
+                let (category, sheet_name) = selected_db_sheet(cx.state)?;
+                cx.state.revert_json_confirm_target = Some((Some(category), sheet_name));
+                Ok(())
+            }),
+            action("Export Sheet as JSONL…", |cx| {
+                let (category, table_name) = selected_db_sheet(cx.state)?;
+                if let Some(output_folder) = rfd::FileDialog::new().pick_folder() {
+                    cx.writers.export_jsonl.write(RequestExportSheetToJsonl {
+                        db_path: get_default_data_base_path().join(format!("{}.db", category)),
+                        table_name,
+                        output_folder,
+                    });
+                }
+                Ok(())
+            }),
+            action("Export Sheet as CSV…", |cx| {
+                let (category, table_name) = selected_db_sheet(cx.state)?;
+                if let Some(output_folder) = rfd::FileDialog::new().pick_folder() {
+                    cx.writers.export_csv.write(RequestExportSheetToCsv {
+                        db_path: get_default_data_base_path().join(format!("{}.db", category)),
+                        table_name,
+                        output_folder,
+                    });
+                }
+                Ok(())
+            }),
+            action("Export Sheet as Parquet…", |cx| {
+                let (category, table_name) = selected_db_sheet(cx.state)?;
+                if let Some(output_folder) = rfd::FileDialog::new().pick_folder() {
+                    cx.writers.export_parquet.write(RequestExportSheetToParquet {
+                        db_path: get_default_data_base_path().join(format!("{}.db", category)),
+                        table_name,
+                        output_folder,
+                    });
+                }
+                Ok(())
+            }),
+            action("Compact Database", |cx| {
+                let db_path = selected_db_path(cx.state)?;
+                cx.writers.compact_database.write(RequestCompactDatabase { db_path });
+                Ok(())
+            }),
+            action("Validate Structure Links", |cx| {
+                let db_path = selected_db_path(cx.state)?;
+                cx.writers
+                    .validate_structure_links
+                    .write(RequestValidateStructureLinks { db_path });
+                Ok(())
+            }),
+            action("Settings", |cx| {
+                cx.state.show_settings_popup = true;
+                Ok(())
+            }),
+        ]
+    })
+}
+
+/// Ctrl+Shift+P overlay that fuzzy-matches the palette's actions and runs the chosen one
+/// on the current selection. Arrow keys move the highlight, Enter runs it, Escape closes.
+/// Must run before the Ctrl+P quick open, which would otherwise take the shortcut.
+pub fn show_command_palette_popup(
+    ctx: &egui::Context,
+    state: &mut EditorWindowState,
+    registry: &mut SheetRegistry,
+    writers: &mut SheetEventWriters,
+    daemon_client: &DaemonClient,
+) {
+    let shortcut = egui::Modifiers::COMMAND | egui::Modifiers::SHIFT;
+    if ctx.input_mut(|i| i.consume_key(shortcut, egui::Key::P)) {
+        state.show_command_palette = !state.show_command_palette;
+        state.command_palette_query.clear();
+        state.command_palette_highlight = 0;
+        state.show_quick_open = false;
+    }
+    if !state.show_command_palette {
+        return;
+    }
+
+    let (escape, enter, down, up) = ctx.input_mut(|i| {
+        (
+            i.consume_key(egui::Modifiers::NONE, egui::Key::Escape),
+            i.consume_key(egui::Modifiers::NONE, egui::Key::Enter),
+            i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowDown),
+            i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowUp),
+        )
+    });
+    if escape {
+        state.show_command_palette = false;
+        return;
+    }
+
+    let actions = palette_actions();
+    let names: Vec<&str> = actions.iter().map(|(name, _)| *name).collect();
+    let ranked: Vec<usize> = rank_fuzzy_matches(&state.command_palette_query, &names)
+        .into_iter()
+        .take(MAX_PALETTE_RESULTS)
+        .collect();
+
+    if ranked.is_empty() {
+        state.command_palette_highlight = 0;
+    } else {
+        if down {
+            state.command_palette_highlight = (state.command_palette_highlight + 1) % ranked.len();
+        }
+        if up {
+            state.command_palette_highlight =
+                (state.command_palette_highlight + ranked.len() - 1) % ranked.len();
+        }
+        state.command_palette_highlight = state.command_palette_highlight.min(ranked.len() - 1);
+    }
+
+    let mut chosen: Option<usize> = enter
+        .then(|| ranked.get(state.command_palette_highlight).copied())
+        .flatten();
+    let mut is_open = true;
+    let target = match (&state.selected_category, &state.selected_sheet_name) {
+        (_, None) => "No sheet selected".to_string(),
+        (Some(cat), Some(sheet)) => format!("{}  —  {}", sheet, cat),
+        (None, Some(sheet)) => sheet.clone(),
+    };
+
+    egui::Window::new("Command Palette")
+        .collapsible(false)
+        .resizable(false)
+        .title_bar(false)
+        .anchor(egui::Align2::CENTER_TOP, [0.0, 60.0])
+        .fixed_size([360.0, 0.0])
+        .show(ctx, |ui| {
+            let previous_query = state.command_palette_query.clone();
+            let input = ui.add(
+                egui::TextEdit::singleline(&mut state.command_palette_query)
+                    .hint_text("Type an action…")
+                    .desired_width(f32::INFINITY),
+            );
+            input.request_focus();
+            if state.command_palette_query != previous_query {
+                state.command_palette_highlight = 0;
+            }
+            ui.weak(target);
+            ui.separator();
+            if ranked.is_empty() {
+                ui.weak("No matching actions.");
+            }
+            for (pos, &action_idx) in ranked.iter().enumerate() {
+                if ui
+                    .selectable_label(pos == state.command_palette_highlight, actions[action_idx].0)
+                    .clicked()
+                {
+                    chosen = Some(action_idx);
+                }
+            }
+            if ui.input(|i| i.pointer.any_click()) && !ui.ui_contains_pointer() {
+                is_open = false;
+            }
+        });
+
+    if let Some(action_idx) = chosen {
+        // Close first so an action may open another overlay (e.g. Go to Sheet)
+        state.show_command_palette = false;
+        let (name, run) = actions[action_idx];
+        let mut cx = PaletteContext {
+            state,
+            registry,
+            writers,
+            daemon_client,
+        };
+        if let Err(message) = run(&mut cx) {
+            cx.writers.feedback.write(SheetOperationFeedback {
+                message: format!("{}: {}", name, message),
+                is_error: true,
+            });
+        }
+        return;
+    }
+    if !is_open {
+        state.show_command_palette = false;
+    }
+}
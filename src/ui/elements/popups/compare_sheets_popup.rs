@@ -0,0 +1,256 @@
+// src/ui/elements/popups/compare_sheets_popup.rs
+use bevy_egui::egui::{self, RichText};
+
+use crate::sheets::{
+    database::{connection::DbConnection, daemon_client::DaemonClient, reader::DbReader},
+    resources::SheetRegistry,
+    systems::logic::sheet_diff::{
+        diff_input, diff_sheets, CellDiff, ColumnPresence, RowDiffKind, SheetDiff,
+    },
+};
+use crate::ui::elements::ai_review::render::cell_render::{
+    render_diff_change, DIFF_ADDED_COLOR, DIFF_CHANGED_COLOR, DIFF_REMOVED_COLOR,
+};
+use crate::ui::elements::editor::EditorWindowState;
+
+type CompareSide = Result<(Vec<String>, Vec<Vec<String>>), String>;
+
+/// Headers and rows of a sheet as compared. Database sheets are read straight from their
+/// file so sheets that were never opened (registry stubs) can be compared too.
+fn load_compare_side(
+    registry: &SheetRegistry,
+    daemon_client: &DaemonClient,
+    category: &Option<String>,
+    sheet_name: &str,
+) -> CompareSide {
+    if let Some(cat) = category {
+        let db_path = crate::sheets::systems::io::get_default_data_base_path().join(format!("{}.db", cat));
+        if db_path.exists() {
+            let conn = DbConnection::open_existing(&db_path).map_err(|e| e.to_string())?;
+            let sheet = DbReader::read_sheet(&conn, sheet_name, daemon_client, Some(cat))
+                .map_err(|e| e.to_string())?;
+            return Ok(diff_input(&sheet));
+        }
+    }
+    registry
+        .get_sheet(category, sheet_name)
+        .map(diff_input)
+        .ok_or_else(|| format!("sheet '{}' not found", sheet_name))
+}
+
+fn side_label(side: &Option<(Option<String>, String)>) -> String {
+    match side {
+        Some((Some(cat), name)) => format!("{} / {}", cat, name),
+        Some((None, name)) => name.clone(),
+        None => "--Select--".to_string(),
+    }
+}
+
+/// Sheet picker for one side; returns true when the selection changed
+fn sheet_picker(
+    ui: &mut egui::Ui,
+    id: &str,
+    side: &mut Option<(Option<String>, String)>,
+    candidates: &[(Option<String>, String)],
+) -> bool {
+    let before = side.clone();
+    egui::ComboBox::from_id_salt(id)
+        .selected_text(side_label(side))
+        .width(260.0)
+        .show_ui(ui, |ui| {
+            for candidate in candidates {
+                let label = side_label(&Some(candidate.clone()));
+                ui.selectable_value(side, Some(candidate.clone()), label);
+            }
+        });
+    *side != before
+}
+
+fn render_cell(ui: &mut egui::Ui, cell: &CellDiff) {
+    match cell {
+        CellDiff::Same(value) => {
+            ui.label(value);
+        }
+        CellDiff::Changed { before, after } => render_diff_change(ui, before, after),
+        CellDiff::Added(value) => {
+            ui.label(RichText::new(value).color(DIFF_ADDED_COLOR));
+        }
+        CellDiff::Removed(value) => {
+            ui.label(RichText::new(value).color(DIFF_REMOVED_COLOR).strikethrough());
+        }
+    }
+}
+
+fn render_diff(ui: &mut egui::Ui, diff: &SheetDiff, only_differences: bool) {
+    ui.horizontal_wrapped(|ui| {
+        ui.label(
+            RichText::new(format!("~ {} changed", diff.count(RowDiffKind::Changed)))
+                .color(DIFF_CHANGED_COLOR),
+        );
+        ui.label(
+            RichText::new(format!("+ {} added", diff.count(RowDiffKind::Added)))
+                .color(DIFF_ADDED_COLOR),
+        );
+        ui.label(
+            RichText::new(format!("− {} removed", diff.count(RowDiffKind::Removed)))
+                .color(DIFF_REMOVED_COLOR),
+        );
+        ui.weak(format!("{} unchanged", diff.count(RowDiffKind::Unchanged)));
+    });
+
+    egui::ScrollArea::both()
+        .id_salt("compare_sheets_results")
+        .auto_shrink([false, false])
+        .show(ui, |ui| {
+            egui::Grid::new("compare_sheets_grid")
+                .striped(true)
+                .num_columns(diff.columns.len() + 1)
+                .show(ui, |ui| {
+                    ui.strong("");
+                    for column in &diff.columns {
+                        match column.presence {
+                            ColumnPresence::Both => ui.strong(&column.header),
+                            ColumnPresence::Added => ui
+                                .label(RichText::new(format!("+ {}", column.header)).strong().color(DIFF_ADDED_COLOR))
+                                .on_hover_text("Only in the right sheet"),
+                            ColumnPresence::Removed => ui
+                                .label(RichText::new(format!("− {}", column.header)).strong().color(DIFF_REMOVED_COLOR))
+                                .on_hover_text("Only in the left sheet"),
+                        };
+                    }
+                    ui.end_row();
+                    for row in &diff.rows {
+                        if only_differences && row.kind == RowDiffKind::Unchanged {
+                            continue;
+                        }
+                        match row.kind {
+                            RowDiffKind::Unchanged => ui.label(" "),
+                            RowDiffKind::Changed => ui.label(RichText::new("~").color(DIFF_CHANGED_COLOR)),
+                            RowDiffKind::Added => ui.label(RichText::new("+").color(DIFF_ADDED_COLOR)),
+                            RowDiffKind::Removed => ui.label(RichText::new("−").color(DIFF_REMOVED_COLOR)),
+                        };
+                        for cell in &row.cells {
+                            render_cell(ui, cell);
+                        }
+                        ui.end_row();
+                    }
+                });
+        });
+}
+
+/// "Compare sheets" tool: pick an old (left) and new (right) sheet and a key column they
+/// share; rows are matched by key and shown as added, removed or changed per cell.
+pub fn show_compare_sheets_popup(
+    ctx: &egui::Context,
+    state: &mut EditorWindowState,
+    registry: &SheetRegistry,
+    daemon_client: &DaemonClient,
+) {
+    if !state.show_compare_sheets_popup {
+        return;
+    }
+    let candidates: Vec<(Option<String>, String)> = registry
+        .get_categories()
+        .into_iter()
+        .flat_map(|category| {
+            registry
+                .get_sheet_names_in_category_filtered(&category, state.show_hidden_sheets)
+                .into_iter()
+                .map(move |name| (category.clone(), name))
+        })
+        .collect();
+
+    let mut is_open = true;
+    let compare = &mut state.sheet_compare;
+    // Sides are read once when picked (or pre-filled from the current sheet)
+    if let (Some((cat, name)), true) = (&compare.left, compare.left_data.is_none()) {
+        compare.left_data = Some(load_compare_side(registry, daemon_client, cat, name));
+    }
+    if let (Some((cat, name)), true) = (&compare.right, compare.right_data.is_none()) {
+        compare.right_data = Some(load_compare_side(registry, daemon_client, cat, name));
+    }
+    egui::Window::new("Compare Sheets")
+        .collapsible(true)
+        .resizable(true)
+        .default_size([760.0, 480.0])
+        .open(&mut is_open)
+        .show(ctx, |ui| {
+            egui::Grid::new("compare_sheets_pickers").num_columns(3).show(ui, |ui| {
+                ui.label("Left (old):");
+                if sheet_picker(ui, "compare_left_sheet", &mut compare.left, &candidates) {
+                    compare.left_data = None;
+                    compare.result = None;
+                }
+                if let Some(Err(e)) = &compare.left_data {
+                    ui.colored_label(egui::Color32::RED, e);
+                }
+                ui.end_row();
+                ui.label("Right (new):");
+                if sheet_picker(ui, "compare_right_sheet", &mut compare.right, &candidates) {
+                    compare.right_data = None;
+                    compare.result = None;
+                }
+                if let Some(Err(e)) = &compare.right_data {
+                    ui.colored_label(egui::Color32::RED, e);
+                }
+                ui.end_row();
+            });
+
+            let (Some(Ok((left_headers, left_rows))), Some(Ok((right_headers, right_rows)))) =
+                (&compare.left_data, &compare.right_data)
+            else {
+                ui.weak("Pick two sheets to compare.");
+                return;
+            };
+            let shared: Vec<&String> = left_headers
+                .iter()
+                .filter(|h| right_headers.iter().any(|r| r.eq_ignore_ascii_case(h)))
+                .collect();
+            if shared.is_empty() {
+                ui.colored_label(egui::Color32::RED, "The sheets have no column in common to match rows by.");
+                return;
+            }
+            if !shared.iter().any(|h| h.eq_ignore_ascii_case(&compare.key_column)) {
+                compare.key_column = shared[0].clone();
+                compare.result = None;
+            }
+
+            ui.horizontal(|ui| {
+                ui.label("Key column:");
+                let before = compare.key_column.clone();
+                egui::ComboBox::from_id_salt("compare_key_column")
+                    .selected_text(compare.key_column.clone())
+                    .show_ui(ui, |ui| {
+                        for header in &shared {
+                            ui.selectable_value(&mut compare.key_column, (*header).clone(), header.as_str());
+                        }
+                    });
+                if compare.key_column != before {
+                    compare.result = None;
+                }
+                if ui.button("⇄ Compare").clicked() {
+                    compare.result = Some(diff_sheets(
+                        left_headers,
+                        left_rows,
+                        right_headers,
+                        right_rows,
+                        &compare.key_column,
+                    ));
+                }
+                ui.checkbox(&mut compare.only_differences, "Only differences");
+            });
+            ui.separator();
+
+            match &compare.result {
+                None => {}
+                Some(Err(e)) => {
+                    ui.colored_label(egui::Color32::RED, e);
+                }
+                Some(Ok(diff)) => render_diff(ui, diff, compare.only_differences),
+            }
+        });
+
+    if !is_open {
+        state.show_compare_sheets_popup = false;
+    }
+}
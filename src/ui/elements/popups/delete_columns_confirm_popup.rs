@@ -0,0 +1,101 @@
+// src/ui/elements/popups/delete_columns_confirm_popup.rs
+use bevy::prelude::*;
+use bevy_egui::egui;
+use std::collections::HashSet;
+
+use crate::sheets::{
+    definitions::ColumnValidator, events::RequestDeleteColumns, resources::SheetRegistry,
+    systems::logic::delete_columns::blocked_key_columns,
+};
+use crate::ui::elements::editor::EditorWindowState;
+
+/// One confirmation for all columns selected in Delete mode. Structure columns are flagged
+/// (their child tables go too); parent keys of structure columns that stay block the delete.
+pub fn show_delete_columns_confirm_popup(
+    ctx: &egui::Context,
+    state: &mut EditorWindowState,
+    registry: &SheetRegistry,
+    writer: &mut EventWriter<RequestDeleteColumns>,
+) {
+    let Some(confirm) = state.delete_columns_confirm.as_ref() else {
+        return;
+    };
+    let Some(metadata) = registry
+        .get_sheet(&confirm.category, &confirm.sheet_name)
+        .and_then(|s| s.metadata.as_ref())
+    else {
+        state.delete_columns_confirm = None;
+        return;
+    };
+    let indices: HashSet<usize> = confirm.columns.iter().copied().collect();
+    let blocked = blocked_key_columns(metadata, &indices);
+
+    let mut is_open = true;
+    let mut delete_clicked = false;
+    let mut cancel_clicked = false;
+
+    egui::Window::new("Confirm Column Delete")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .open(&mut is_open)
+        .show(ctx, |ui| {
+            ui.label(format!(
+                "Permanently delete {} column(s) from '{}'?",
+                confirm.columns.len(),
+                confirm.sheet_name
+            ));
+            egui::ScrollArea::vertical()
+                .id_salt("delete_columns_confirm_list")
+                .max_height(200.0)
+                .show(ui, |ui| {
+                    for &idx in &confirm.columns {
+                        let Some(column) = metadata.columns.get(idx) else {
+                            continue;
+                        };
+                        if matches!(column.validator, Some(ColumnValidator::Structure)) {
+                            ui.label(format!("• {} (structure: its table is deleted too)", column.display_name()));
+                        } else {
+                            ui.label(format!("• {}", column.display_name()));
+                        }
+                    }
+                });
+            for &(key, structure) in &blocked {
+                ui.colored_label(
+                    egui::Color32::RED,
+                    format!(
+                        "'{}' is the key column of structure '{}'; select that structure column as well to delete it.",
+                        metadata.columns[key].display_name(),
+                        metadata.columns[structure].display_name()
+                    ),
+                );
+            }
+            ui.colored_label(egui::Color32::YELLOW, "This action cannot be undone.");
+            ui.separator();
+            ui.horizontal(|ui| {
+                if ui
+                    .add_enabled(
+                        blocked.is_empty(),
+                        egui::Button::new("DELETE").fill(egui::Color32::DARK_RED),
+                    )
+                    .clicked()
+                {
+                    delete_clicked = true;
+                }
+                if ui.button("Cancel").clicked() {
+                    cancel_clicked = true;
+                }
+            });
+        });
+
+    if delete_clicked {
+        writer.write(RequestDeleteColumns {
+            category: confirm.category.clone(),
+            sheet_name: confirm.sheet_name.clone(),
+            column_indices: indices,
+        });
+    }
+    if delete_clicked || cancel_clicked || !is_open {
+        state.delete_columns_confirm = None;
+    }
+}
@@ -0,0 +1,75 @@
+// src/ui/elements/popups/duplicate_sheet_popup.rs
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+use crate::sheets::events::DuplicateSheetEvent;
+use crate::sheets::resources::SheetRegistry;
+use crate::ui::elements::editor::EditorWindowState;
+
+/// Asks for the name of a sheet copy (opened from the sheet tab context menu).
+pub fn show_duplicate_sheet_popup(
+    ctx: &egui::Context,
+    state: &mut EditorWindowState,
+    registry: &SheetRegistry,
+    duplicate_writer: &mut EventWriter<DuplicateSheetEvent>,
+) {
+    let Some((category, source_sheet)) = state.duplicate_sheet_target.clone() else {
+        return;
+    };
+
+    let mut is_open = true;
+    let mut confirm = false;
+    let mut cancel = false;
+    let trimmed = state.duplicate_sheet_name_input.trim().to_string();
+    let name_taken = registry.get_sheet(&category, &trimmed).is_some();
+
+    egui::Window::new("Duplicate Sheet")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .open(&mut is_open)
+        .show(ctx, |ui| {
+            ui.label(format!("Copy '{}' with all rows and structure tables.", source_sheet));
+            ui.horizontal(|ui| {
+                ui.label("New name:");
+                let response = ui.add(
+                    egui::TextEdit::singleline(&mut state.duplicate_sheet_name_input)
+                        .desired_width(200.0),
+                );
+                if response.lost_focus()
+                    && ui.input(|i| i.key_pressed(egui::Key::Enter))
+                    && !trimmed.is_empty()
+                    && !name_taken
+                {
+                    confirm = true;
+                }
+            });
+            if name_taken {
+                ui.colored_label(egui::Color32::YELLOW, "A sheet with this name already exists.");
+            }
+            ui.separator();
+            ui.horizontal(|ui| {
+                if ui
+                    .add_enabled(!trimmed.is_empty() && !name_taken, egui::Button::new("Duplicate"))
+                    .clicked()
+                {
+                    confirm = true;
+                }
+                if ui.button("Cancel").clicked() {
+                    cancel = true;
+                }
+            });
+        });
+
+    if confirm {
+        duplicate_writer.write(DuplicateSheetEvent {
+            category,
+            source_sheet,
+            new_sheet_name: trimmed,
+        });
+    }
+    if confirm || cancel || !is_open {
+        state.duplicate_sheet_target = None;
+        state.duplicate_sheet_name_input.clear();
+    }
+}
@@ -0,0 +1,110 @@
+// src/ui/elements/popups/find_replace_popup.rs
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+use crate::sheets::{
+    events::FindReplaceInColumnEvent, resources::SheetRegistry,
+    systems::logic::update_cell::replace_in_cell,
+};
+use crate::ui::elements::editor::EditorWindowState;
+
+/// Find & replace over every cell of one column, with a live count of affected cells.
+/// The replacement itself runs in `handle_find_replace_in_column`.
+pub fn show_find_replace_popup(
+    ctx: &egui::Context,
+    state: &mut EditorWindowState,
+    registry: &SheetRegistry,
+    writer: &mut EventWriter<FindReplaceInColumnEvent>,
+) {
+    let Some((category, sheet_name, col_index)) = state.find_replace_target.clone() else {
+        return;
+    };
+    let Some(sheet) = registry.get_sheet(&category, &sheet_name) else {
+        state.find_replace_target = None;
+        return;
+    };
+    let Some(column_label) = sheet
+        .metadata
+        .as_ref()
+        .and_then(|m| m.columns.get(col_index))
+        .map(|c| c.display_name().to_string())
+    else {
+        state.find_replace_target = None;
+        return;
+    };
+
+    let match_count = sheet
+        .grid
+        .iter()
+        .filter_map(|row| row.get(col_index))
+        .filter(|cell| {
+            replace_in_cell(
+                cell,
+                &state.find_replace_find,
+                &state.find_replace_replace,
+                state.find_replace_case_sensitive,
+                state.find_replace_whole_cell,
+            )
+            .is_some()
+        })
+        .count();
+
+    let mut is_open = true;
+    let mut apply_clicked = false;
+    let mut cancel_clicked = false;
+
+    egui::Window::new(format!("Find & Replace — {} / {}", sheet_name, column_label))
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .open(&mut is_open)
+        .show(ctx, |ui| {
+            egui::Grid::new("find_replace_inputs")
+                .num_columns(2)
+                .show(ui, |ui| {
+                    ui.label("Find:");
+                    ui.text_edit_singleline(&mut state.find_replace_find);
+                    ui.end_row();
+                    ui.label("Replace with:");
+                    ui.text_edit_singleline(&mut state.find_replace_replace);
+                    ui.end_row();
+                });
+            ui.checkbox(&mut state.find_replace_case_sensitive, "Case sensitive");
+            ui.checkbox(&mut state.find_replace_whole_cell, "Whole cell only")
+                .on_hover_text("Only replace cells whose entire value equals the search text");
+            ui.separator();
+
+            if state.find_replace_find.is_empty() {
+                ui.weak("Enter the text to find.");
+            } else {
+                ui.label(format!("{} cell(s) will change.", match_count));
+            }
+            ui.horizontal(|ui| {
+                if ui
+                    .add_enabled(match_count > 0, egui::Button::new("Replace All"))
+                    .clicked()
+                {
+                    apply_clicked = true;
+                }
+                if ui.button("Cancel").clicked() {
+                    cancel_clicked = true;
+                }
+            });
+        });
+
+    if apply_clicked {
+        writer.write(FindReplaceInColumnEvent {
+            category: category.clone(),
+            sheet_name: sheet_name.clone(),
+            col_index,
+            find: state.find_replace_find.clone(),
+            replace: state.find_replace_replace.clone(),
+            case_sensitive: state.find_replace_case_sensitive,
+            whole_cell: state.find_replace_whole_cell,
+        });
+        state.force_filter_recalculation = true;
+    }
+    if apply_clicked || cancel_clicked || !is_open {
+        state.find_replace_target = None;
+    }
+}
@@ -0,0 +1,176 @@
+// src/ui/elements/popups/header_rename_popup.rs
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+use crate::sheets::{
+    database::daemon_client::DaemonClient,
+    resources::SheetRegistry,
+    systems::io::save::save_single_sheet,
+    systems::logic::header_rename::{preview_header_renames, HeaderTransform},
+};
+use crate::ui::elements::editor::EditorWindowState;
+
+/// Batch rename of column display names: find/replace plus prefix/suffix, with a
+/// per-column preview. Only display names change; physical column names are kept.
+pub fn show_header_rename_popup(
+    ctx: &egui::Context,
+    state: &mut EditorWindowState,
+    registry: &mut SheetRegistry,
+    daemon_client: &DaemonClient,
+) {
+    let Some((category, sheet_name)) = state.header_rename_target.clone() else {
+        return;
+    };
+    let Some(metadata) = registry
+        .get_sheet(&category, &sheet_name)
+        .and_then(|s| s.metadata.as_ref())
+    else {
+        state.header_rename_target = None;
+        return;
+    };
+
+    let transform = HeaderTransform {
+        find: state.header_rename_find.clone(),
+        replace: state.header_rename_replace.clone(),
+        prefix: state.header_rename_prefix.clone(),
+        suffix: state.header_rename_suffix.clone(),
+    };
+    let preview = preview_header_renames(metadata, &transform, &state.header_rename_excluded);
+    let change_count = preview.iter().filter(|p| p.is_change()).count();
+    let has_conflict = preview.iter().any(|p| p.conflict);
+
+    let mut is_open = true;
+    let mut apply_clicked = false;
+    let mut cancel_clicked = false;
+
+    egui::Window::new(format!("Rename Headers — {}", sheet_name))
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .open(&mut is_open)
+        .show(ctx, |ui| {
+            egui::Grid::new("header_rename_inputs")
+                .num_columns(2)
+                .show(ui, |ui| {
+                    ui.label("Find:");
+                    ui.text_edit_singleline(&mut state.header_rename_find);
+                    ui.end_row();
+                    ui.label("Replace with:");
+                    ui.text_edit_singleline(&mut state.header_rename_replace);
+                    ui.end_row();
+                    ui.label("Prefix:");
+                    ui.text_edit_singleline(&mut state.header_rename_prefix);
+                    ui.end_row();
+                    ui.label("Suffix:");
+                    ui.text_edit_singleline(&mut state.header_rename_suffix);
+                    ui.end_row();
+                });
+            ui.small("Technical columns (row_index, parent_key, timestamps) are never renamed.");
+            ui.separator();
+
+            egui::ScrollArea::vertical()
+                .max_height(300.0)
+                .show(ui, |ui| {
+                    egui::Grid::new("header_rename_preview")
+                        .num_columns(3)
+                        .striped(true)
+                        .show(ui, |ui| {
+                            for entry in &preview {
+                                let mut included =
+                                    !state.header_rename_excluded.contains(&entry.col_index);
+                                if ui.checkbox(&mut included, "").changed() {
+                                    if included {
+                                        state.header_rename_excluded.remove(&entry.col_index);
+                                    } else {
+                                        state.header_rename_excluded.insert(entry.col_index);
+                                    }
+                                }
+                                ui.label(&entry.old_name);
+                                let new_text = format!("→ {}", entry.new_name);
+                                if entry.conflict {
+                                    ui.colored_label(egui::Color32::RED, new_text)
+                                        .on_hover_text("Empty or duplicate column name");
+                                } else if entry.is_change() {
+                                    ui.strong(new_text);
+                                } else {
+                                    ui.weak(new_text);
+                                }
+                                ui.end_row();
+                            }
+                        });
+                });
+
+            ui.separator();
+            if has_conflict {
+                ui.colored_label(
+                    egui::Color32::RED,
+                    "Resolve empty or duplicate names before applying.",
+                );
+            }
+            ui.horizontal(|ui| {
+                let label = format!("Apply ({} column(s))", change_count);
+                if ui
+                    .add_enabled(change_count > 0 && !has_conflict, egui::Button::new(label))
+                    .clicked()
+                {
+                    apply_clicked = true;
+                }
+                if ui.button("Cancel").clicked() {
+                    cancel_clicked = true;
+                }
+            });
+        });
+
+    if apply_clicked {
+        let mut renamed = 0usize;
+        if let Some(meta) = registry
+            .get_sheet_mut(&category, &sheet_name)
+            .and_then(|s| s.metadata.as_mut())
+        {
+            for entry in preview.iter().filter(|p| p.is_change()) {
+                let Some(col_def) = meta.columns.get_mut(entry.col_index) else {
+                    continue;
+                };
+                let new_name = entry.new_name.trim().to_string();
+                col_def.display_header = if new_name == col_def.header {
+                    None
+                } else {
+                    Some(new_name)
+                };
+                renamed += 1;
+                if let Some(cat) = meta.category.as_deref() {
+                    // Clearing stores the physical name, which reads back as "no custom name"
+                    let persisted = col_def
+                        .display_header
+                        .clone()
+                        .unwrap_or_else(|| col_def.header.clone());
+                    if let Err(e) = crate::sheets::database::persist_column_display_name(
+                        cat,
+                        &meta.sheet_name,
+                        entry.col_index,
+                        &persisted,
+                        daemon_client,
+                    ) {
+                        error!("Persist column metadata (display name) failed: {}", e);
+                    }
+                }
+            }
+        }
+        if category.is_none() {
+            if let Some(meta) = registry
+                .get_sheet(&category, &sheet_name)
+                .and_then(|s| s.metadata.clone())
+            {
+                save_single_sheet(registry, &meta);
+            }
+        }
+        info!(
+            "Batch renamed {} column header(s) of '{:?}/{}'.",
+            renamed, category, sheet_name
+        );
+        state.force_filter_recalculation = true;
+    }
+    if apply_clicked || cancel_clicked || !is_open {
+        state.header_rename_target = None;
+    }
+}
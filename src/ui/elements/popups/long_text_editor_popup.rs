@@ -0,0 +1,71 @@
+// src/ui/elements/popups/long_text_editor_popup.rs
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+use crate::sheets::events::UpdateCellEvent;
+use crate::ui::elements::editor::EditorWindowState;
+
+/// Resizable multi-line editor for cells of "long text" columns (opened with the cell's
+/// "⤢" button). Enter inserts a line break; Save commits through `UpdateCellEvent`.
+pub fn show_long_text_editor_popup(
+    ctx: &egui::Context,
+    state: &mut EditorWindowState,
+    writer: &mut EventWriter<UpdateCellEvent>,
+) {
+    let Some(editor) = state.long_text_editor.as_mut() else {
+        return;
+    };
+
+    let mut is_open = true;
+    let mut save_clicked = false;
+    let mut cancel_clicked = false;
+
+    egui::Window::new(format!("{} — row {}", editor.sheet_name, editor.row_index + 1))
+        .id(egui::Id::new("long_text_editor_popup"))
+        .collapsible(false)
+        .resizable(true)
+        .default_size([520.0, 320.0])
+        .open(&mut is_open)
+        .show(ctx, |ui| {
+            let buttons_height = ui.spacing().interact_size.y + ui.spacing().item_spacing.y * 2.0;
+            egui::ScrollArea::vertical()
+                .id_salt("long_text_editor_scroll")
+                .max_height((ui.available_height() - buttons_height).max(80.0))
+                .auto_shrink([false, false])
+                .show(ui, |ui| {
+                    ui.add_sized(
+                        ui.available_size(),
+                        egui::TextEdit::multiline(&mut editor.text).desired_width(f32::INFINITY),
+                    );
+                });
+            ui.separator();
+            ui.horizontal(|ui| {
+                let changed = editor.text != editor.original;
+                if ui
+                    .add_enabled(changed, egui::Button::new("Save"))
+                    .on_hover_text("Ctrl+Enter")
+                    .clicked()
+                    || (changed && ui.input_mut(|i| i.consume_key(egui::Modifiers::COMMAND, egui::Key::Enter)))
+                {
+                    save_clicked = true;
+                }
+                if ui.button("Cancel").clicked() {
+                    cancel_clicked = true;
+                }
+                ui.weak(format!("{} line(s), {} characters", editor.text.lines().count().max(1), editor.text.chars().count()));
+            });
+        });
+
+    if save_clicked {
+        writer.write(UpdateCellEvent {
+            category: editor.category.clone(),
+            sheet_name: editor.sheet_name.clone(),
+            row_index: editor.row_index,
+            col_index: editor.col_index,
+            new_value: editor.text.clone(),
+        });
+    }
+    if save_clicked || cancel_clicked || !is_open {
+        state.long_text_editor = None;
+    }
+}
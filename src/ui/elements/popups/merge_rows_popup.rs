@@ -0,0 +1,144 @@
+// src/ui/elements/popups/merge_rows_popup.rs
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+use crate::sheets::{
+    events::MergeRowsEvent,
+    resources::SheetRegistry,
+    systems::logic::merge_rows::{mergeable_columns, merge_conflicts, merge_row_values},
+};
+use crate::ui::elements::editor::EditorWindowState;
+
+/// "Merge rows": lists the columns whose rows disagree and lets the user pick the value to
+/// keep (defaults to the first non-empty one), with a preview of the merged row.
+/// The merge itself runs in `handle_merge_rows_request`.
+pub fn show_merge_rows_popup(
+    ctx: &egui::Context,
+    state: &mut EditorWindowState,
+    registry: &SheetRegistry,
+    writer: &mut EventWriter<MergeRowsEvent>,
+) {
+    let Some(merge) = state.merge_rows.as_mut() else {
+        return;
+    };
+    let Some(sheet) = registry.get_sheet(&merge.category, &merge.sheet_name) else {
+        state.merge_rows = None;
+        return;
+    };
+    let Some(metadata) = sheet.metadata.as_ref() else {
+        state.merge_rows = None;
+        return;
+    };
+    let Some(rows) = merge
+        .rows
+        .iter()
+        .map(|&idx| sheet.grid.get(idx).cloned())
+        .collect::<Option<Vec<Vec<String>>>>()
+    else {
+        state.merge_rows = None;
+        return;
+    };
+
+    let columns = mergeable_columns(metadata);
+    let conflicts = merge_conflicts(&rows, &columns);
+    merge
+        .choices
+        .retain(|col, _| conflicts.iter().any(|c| c.col_index == *col));
+    for conflict in &conflicts {
+        let choice = merge.choices.entry(conflict.col_index).or_default();
+        if !conflict.values.contains(choice) {
+            *choice = conflict.values[0].clone();
+        }
+    }
+    let merged = merge_row_values(&rows, &columns, &merge.choices);
+    let column_label = |idx: usize| {
+        metadata
+            .columns
+            .get(idx)
+            .map(|c| c.display_name().to_string())
+            .unwrap_or_default()
+    };
+
+    let mut is_open = true;
+    let mut merge_clicked = false;
+    let mut cancel_clicked = false;
+
+    egui::Window::new(format!("Merge {} Rows — {}", rows.len(), merge.sheet_name))
+        .collapsible(false)
+        .resizable(true)
+        .default_width(420.0)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .open(&mut is_open)
+        .show(ctx, |ui| {
+            ui.label(format!(
+                "Rows are merged into row {}; the other {} row(s) are deleted. Empty cells take the first non-empty value.",
+                merge.rows[0] + 1,
+                rows.len() - 1
+            ));
+            if metadata.category.is_some() {
+                ui.weak("Structure rows of the merged rows move under the remaining row.");
+            }
+            ui.separator();
+
+            if conflicts.is_empty() {
+                ui.weak("No conflicting values.");
+            } else {
+                ui.strong("Pick the value to keep:");
+                egui::ScrollArea::vertical()
+                    .id_salt("merge_rows_conflicts")
+                    .max_height(260.0)
+                    .show(ui, |ui| {
+                        egui::Grid::new("merge_rows_conflicts_grid")
+                            .num_columns(2)
+                            .striped(true)
+                            .show(ui, |ui| {
+                                for conflict in &conflicts {
+                                    ui.label(column_label(conflict.col_index));
+                                    ui.vertical(|ui| {
+                                        if let Some(choice) = merge.choices.get_mut(&conflict.col_index) {
+                                            for value in &conflict.values {
+                                                ui.radio_value(choice, value.clone(), value.as_str());
+                                            }
+                                        }
+                                    });
+                                    ui.end_row();
+                                }
+                            });
+                    });
+            }
+            ui.separator();
+
+            ui.collapsing("Merged row", |ui| {
+                egui::Grid::new("merge_rows_preview").num_columns(2).show(ui, |ui| {
+                    for &idx in &columns {
+                        ui.label(column_label(idx));
+                        ui.label(merged.get(idx).map(String::as_str).unwrap_or_default());
+                        ui.end_row();
+                    }
+                });
+            });
+            ui.horizontal(|ui| {
+                if ui.button("Merge").clicked() {
+                    merge_clicked = true;
+                }
+                if ui.button("Cancel").clicked() {
+                    cancel_clicked = true;
+                }
+            });
+        });
+
+    if merge_clicked {
+        writer.write(MergeRowsEvent {
+            category: merge.category.clone(),
+            sheet_name: merge.sheet_name.clone(),
+            row_indices: merge.rows.clone(),
+            column_choices: merge.choices.clone(),
+        });
+        state.reset_interaction_modes_and_selections();
+        state.show_edit_mode_panel = false;
+        state.force_filter_recalculation = true;
+    }
+    if merge_clicked || cancel_clicked || !is_open {
+        state.merge_rows = None;
+    }
+}
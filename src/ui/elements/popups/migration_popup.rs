@@ -18,6 +18,11 @@ pub struct MigrationPopupState {
     pub progress_total: usize,
     pub progress_completed: usize,
     pub progress_message: String,
+    /// Rows written / expected for the table currently being migrated
+    pub table_rows_done: usize,
+    pub table_rows_total: usize,
+    /// Set by the cancel button; forwarded to the migration thread by the poller
+    pub cancel_requested: bool,
 }
 
 pub fn show_migration_popup(
@@ -154,34 +159,52 @@ pub fn show_migration_popup(
 
             ui.add_space(15.0);
 
+            if state.migration_in_progress {
+                let total = state.progress_total;
+                let completed = state.progress_completed.min(total);
+                if total > 0 {
+                    ui.add(
+                        egui::ProgressBar::new(completed as f32 / total as f32)
+                            .text(format!("Tables: {} / {}", completed, total)),
+                    );
+                }
+                if state.table_rows_total > 0 {
+                    let done = state.table_rows_done.min(state.table_rows_total);
+                    ui.add(
+                        egui::ProgressBar::new(done as f32 / state.table_rows_total as f32)
+                            .text(format!("Rows: {} / {}", done, state.table_rows_total)),
+                    );
+                }
+                ui.horizontal(|ui| {
+                    ui.spinner();
+                    if state.cancel_requested {
+                        ui.label("Cancelling after the current chunk...");
+                    } else {
+                        ui.label(state.progress_message.clone());
+                    }
+                });
+                ui.add_space(10.0);
+            }
+
             // Action buttons
             ui.horizontal(|ui| {
                 let can_migrate = state.source_folder.is_some()
                     && state.target_db.is_some()
                     && !state.migration_in_progress;
 
-                if state.migration_in_progress {
-                    ui.spinner();
-                    let total = state.progress_total;
-                    let completed = state.progress_completed.min(total);
-                    let ratio = if total > 0 {
-                        completed as f32 / total as f32
-                    } else {
-                        0.0
-                    };
-                    if total > 0 {
-                        ui.add(
-                            egui::ProgressBar::new(ratio)
-                                .show_percentage()
-                                .text(state.progress_message.clone()),
-                        );
-                    } else {
-                        ui.label(state.progress_message.clone());
-                    }
-                }
-
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                    if ui.button("Cancel").clicked() {
+                    if state.migration_in_progress {
+                        if ui.button("Hide").clicked() {
+                            state.show = false;
+                        }
+                        if ui
+                            .add_enabled(!state.cancel_requested, egui::Button::new("⏹ Stop Migration"))
+                            .on_hover_text("Stop after the current chunk; the table being migrated is removed")
+                            .clicked()
+                        {
+                            state.cancel_requested = true;
+                        }
+                    } else if ui.button("Cancel").clicked() {
                         state.show = false;
                     }
 
@@ -200,6 +223,9 @@ pub fn show_migration_popup(
                                 state.progress_total = 0;
                                 state.progress_completed = 0;
                                 state.progress_message = "Starting migration...".into();
+                                state.table_rows_done = 0;
+                                state.table_rows_total = 0;
+                                state.cancel_requested = false;
 
                                 feedback_writer.write(SheetOperationFeedback {
                                     message: "Migration started...".to_string(),
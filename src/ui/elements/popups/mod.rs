@@ -6,16 +6,32 @@ pub use structure_recreation_popup::show_structure_recreation_popup;
 
 // Declare the individual popup modules
 pub mod column_options_popup;
+pub mod delete_columns_confirm_popup;
 pub mod delete_confirm_popup;
+pub mod duplicate_sheet_popup;
 // NEW: Declare new_sheet_popup module
 pub mod add_table_popup;
+pub mod ai_payload_preview_popup;
 pub mod ai_prompt_popup;
+pub mod ai_runtime_notice_popup;
 pub mod ai_rule_popup;
 pub mod category_popups;
+pub mod cell_comment_popup;
+pub mod command_palette_popup;
+pub mod compare_sheets_popup;
+pub mod find_replace_popup;
+pub mod header_rename_popup;
+pub mod long_text_editor_popup;
+pub mod merge_rows_popup;
 pub mod migration_popup;
 pub mod new_sheet_popup;
+pub mod project_import_popup;
+pub mod quick_open_popup;
+pub mod reload_confirm_popup;
+pub mod revert_json_confirm_popup;
 pub mod rename_popup;
 pub mod settings_popup;
+pub mod sql_console_popup;
 
 // Declare the refactored modules for column options
 mod column_options_on_close;
@@ -25,15 +41,31 @@ mod random_picker_popup;
 mod random_picker_ui;
 
 // Re-export the main popup functions for easier access
+pub use cell_comment_popup::show_cell_comment_popup;
 pub use column_options_popup::show_column_options_popup;
+pub use command_palette_popup::show_command_palette_popup;
+pub use compare_sheets_popup::show_compare_sheets_popup;
+pub use delete_columns_confirm_popup::show_delete_columns_confirm_popup;
 pub use delete_confirm_popup::show_delete_confirm_popup;
+pub use duplicate_sheet_popup::show_duplicate_sheet_popup;
+pub use project_import_popup::show_project_import_popup;
 // NEW: Re-export new_sheet_popup function
 pub use add_table_popup::show_add_table_popup;
+pub use find_replace_popup::show_find_replace_popup;
+pub use header_rename_popup::show_header_rename_popup;
+pub use long_text_editor_popup::show_long_text_editor_popup;
+pub use merge_rows_popup::show_merge_rows_popup;
+pub use ai_payload_preview_popup::show_ai_payload_preview_popup;
 pub use ai_rule_popup::show_ai_rule_popup;
+pub use ai_runtime_notice_popup::show_ai_runtime_notice_popup;
 pub use migration_popup::{show_migration_popup, MigrationPopupState};
 pub use new_sheet_popup::show_new_sheet_popup;
+pub use quick_open_popup::show_quick_open_popup;
+pub use reload_confirm_popup::show_reload_confirm_popup;
+pub use revert_json_confirm_popup::show_revert_json_confirm_popup;
 pub use rename_popup::show_rename_popup;
 pub use settings_popup::show_settings_popup;
+pub use sql_console_popup::show_sql_console_popup;
 // Note: show_ai_prompt_popup is invoked from AI control panel directly
 pub use category_popups::{show_delete_category_confirm_popups, show_new_category_popup};
 pub use random_picker_popup::show_random_picker_popup;
@@ -62,7 +62,7 @@ pub fn show_new_sheet_popup(
                     if let Some(w) = upload_json_writer {
                         // Only show upload button if we have a target database (category)
                         if let Some(ref target_db) = state.new_sheet_target_category {
-                            if ui_r.button("⬆ Upload JSON").on_hover_text("Import a JSON file as a table in this database").clicked() {
+                            if ui_r.button("⬆ Upload JSON/CSV").on_hover_text("Import a JSON or CSV/TSV file as a table in this database").clicked() {
                                 w.write(RequestUploadJsonToCurrentDb {
                                     target_db_name: target_db.clone(),
                                 });
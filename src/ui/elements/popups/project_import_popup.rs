@@ -0,0 +1,72 @@
+// src/ui/elements/popups/project_import_popup.rs
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+use crate::sheets::events::{ImportCollisionPolicy, RequestImportProject};
+use crate::ui::elements::editor::EditorWindowState;
+
+/// Asks how to handle tables of a project archive that already exist (opened from Settings).
+pub fn show_project_import_popup(
+    ctx: &egui::Context,
+    state: &mut EditorWindowState,
+    import_writer: &mut EventWriter<RequestImportProject>,
+) {
+    let Some((archive_path, collisions)) = state.project_import_prompt.clone() else {
+        return;
+    };
+
+    let mut is_open = true;
+    let mut choice: Option<ImportCollisionPolicy> = None;
+    let mut cancel = false;
+
+    egui::Window::new("Import Project")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .open(&mut is_open)
+        .show(ctx, |ui| {
+            ui.label(format!(
+                "{} table(s) in '{}' already exist:",
+                collisions.len(),
+                archive_path
+                    .file_name()
+                    .map(|n| n.to_string_lossy().into_owned())
+                    .unwrap_or_default()
+            ));
+            egui::ScrollArea::vertical().max_height(200.0).show(ui, |ui| {
+                for name in &collisions {
+                    ui.monospace(name);
+                }
+            });
+            ui.separator();
+            ui.horizontal(|ui| {
+                if ui
+                    .button("Overwrite")
+                    .on_hover_text("Replace the existing tables and their structure tables")
+                    .clicked()
+                {
+                    choice = Some(ImportCollisionPolicy::Overwrite);
+                }
+                if ui
+                    .button("Keep both")
+                    .on_hover_text("Import colliding tables as '<name>_imported'")
+                    .clicked()
+                {
+                    choice = Some(ImportCollisionPolicy::Suffix);
+                }
+                if ui.button("Cancel").clicked() {
+                    cancel = true;
+                }
+            });
+        });
+
+    if let Some(collision_policy) = choice {
+        import_writer.write(RequestImportProject {
+            archive_path,
+            collision_policy,
+        });
+    }
+    if choice.is_some() || cancel || !is_open {
+        state.project_import_prompt = None;
+    }
+}
@@ -0,0 +1,126 @@
+// src/ui/elements/popups/quick_open_popup.rs
+use bevy_egui::egui;
+
+use crate::sheets::{
+    resources::SheetRegistry,
+    systems::logic::fuzzy_match::rank_fuzzy_matches,
+    systems::ui_handlers::{category_handlers, sheet_handlers},
+};
+use crate::ui::elements::editor::EditorWindowState;
+
+/// Most results listed at once; refine the query to reach the rest
+const MAX_QUICK_OPEN_RESULTS: usize = 20;
+
+/// Ctrl+P overlay that fuzzy-matches every visible sheet name and opens the chosen one.
+/// Arrow keys move the highlight, Enter opens it, Escape closes without changing selection.
+pub fn show_quick_open_popup(
+    ctx: &egui::Context,
+    state: &mut EditorWindowState,
+    registry: &SheetRegistry,
+) {
+    if ctx.input_mut(|i| i.consume_key(egui::Modifiers::COMMAND, egui::Key::P)) {
+        state.show_quick_open = !state.show_quick_open;
+        state.quick_open_query.clear();
+        state.quick_open_highlight = 0;
+    }
+    if !state.show_quick_open {
+        return;
+    }
+
+    let (escape, enter, down, up) = ctx.input_mut(|i| {
+        (
+            i.consume_key(egui::Modifiers::NONE, egui::Key::Escape),
+            i.consume_key(egui::Modifiers::NONE, egui::Key::Enter),
+            i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowDown),
+            i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowUp),
+        )
+    });
+    if escape {
+        state.show_quick_open = false;
+        return;
+    }
+
+    let candidates: Vec<(Option<String>, String)> = registry
+        .get_categories()
+        .into_iter()
+        .flat_map(|category| {
+            registry
+                .get_sheet_names_in_category_filtered(&category, state.show_hidden_sheets)
+                .into_iter()
+                .map(move |name| (category.clone(), name))
+        })
+        .collect();
+    let names: Vec<&str> = candidates.iter().map(|(_, name)| name.as_str()).collect();
+    let ranked: Vec<usize> = rank_fuzzy_matches(&state.quick_open_query, &names)
+        .into_iter()
+        .take(MAX_QUICK_OPEN_RESULTS)
+        .collect();
+
+    if ranked.is_empty() {
+        state.quick_open_highlight = 0;
+    } else {
+        if down {
+            state.quick_open_highlight = (state.quick_open_highlight + 1) % ranked.len();
+        }
+        if up {
+            state.quick_open_highlight =
+                (state.quick_open_highlight + ranked.len() - 1) % ranked.len();
+        }
+        state.quick_open_highlight = state.quick_open_highlight.min(ranked.len() - 1);
+    }
+
+    let mut chosen: Option<usize> = enter
+        .then(|| ranked.get(state.quick_open_highlight).copied())
+        .flatten();
+    let mut is_open = true;
+
+    egui::Window::new("Go to Sheet")
+        .collapsible(false)
+        .resizable(false)
+        .title_bar(false)
+        .anchor(egui::Align2::CENTER_TOP, [0.0, 60.0])
+        .fixed_size([360.0, 0.0])
+        .show(ctx, |ui| {
+            let previous_query = state.quick_open_query.clone();
+            let input = ui.add(
+                egui::TextEdit::singleline(&mut state.quick_open_query)
+                    .hint_text("Type a sheet name…")
+                    .desired_width(f32::INFINITY),
+            );
+            input.request_focus();
+            if state.quick_open_query != previous_query {
+                state.quick_open_highlight = 0;
+            }
+            ui.separator();
+            if ranked.is_empty() {
+                ui.weak("No matching sheets.");
+            }
+            for (pos, &candidate_idx) in ranked.iter().enumerate() {
+                let (category, name) = &candidates[candidate_idx];
+                let label = match category {
+                    Some(cat) => format!("{}  —  {}", name, cat),
+                    None => name.clone(),
+                };
+                if ui
+                    .selectable_label(pos == state.quick_open_highlight, label)
+                    .clicked()
+                {
+                    chosen = Some(candidate_idx);
+                }
+            }
+            if ui.input(|i| i.pointer.any_click()) && !ui.ui_contains_pointer() {
+                is_open = false;
+            }
+        });
+
+    if let Some(candidate_idx) = chosen {
+        let (category, name) = candidates[candidate_idx].clone();
+        category_handlers::handle_category_selection(state, category, registry);
+        sheet_handlers::handle_sheet_selection(state, Some(name));
+        state.pending_sheet_revalidation = true;
+        is_open = false;
+    }
+    if !is_open {
+        state.show_quick_open = false;
+    }
+}
@@ -69,6 +69,12 @@ pub fn show_random_picker_popup(
                             .take(weight_cols.len())
                             .collect(),
                         summarizer_columns: summ_cols.clone(),
+                        draw_count: state.random_picker_draw_count,
+                        excluded_row_indices: state.random_picker_excluded_rows(
+                            &state.options_column_target_category,
+                            &sheet_name,
+                        ),
+                        seed: state.random_picker_seed,
                     };
                     meta.random_picker = Some(settings.clone());
                     let meta_clone = meta.clone();
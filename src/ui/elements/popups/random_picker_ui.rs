@@ -1,4 +1,5 @@
 use crate::sheets::resources::SheetRegistry;
+use crate::sheets::systems::logic::random_picker::{top_pick_probabilities, weighted_candidates};
 use crate::ui::elements::editor::state::{EditorWindowState, ToyboxMode};
 use crate::ui::validation::normalize_for_link_cmp;
 use bevy::prelude::*; // Keep bevy prelude
@@ -35,7 +36,7 @@ pub(super) fn show_random_picker_window_ui(
             // Build header list
             let headers: Vec<String> = registry_immut.get_sheet(&popup_category, &popup_sheet_name)
                 .and_then(|s| s.metadata.as_ref())
-                .map(|m| m.columns.iter().map(|c| c.header.clone()).collect())
+                .map(|m| m.columns.iter().map(|c| c.display_name().to_string()).collect())
                 .unwrap_or_default();
 
             if headers.is_empty() {
@@ -186,6 +187,40 @@ pub(super) fn show_random_picker_window_ui(
                     state.random_picker_weight_multipliers = compacted_mults;
                     if state.random_picker_weight_columns.is_empty() { state.random_picker_weight_columns.push(None); state.random_picker_weight_exponents.push(1.0); state.random_picker_weight_multipliers.push(1.0); }
                     if !state.random_picker_weight_columns.last().map(|o| o.is_none()).unwrap_or(false) { state.random_picker_weight_columns.push(None); state.random_picker_weight_exponents.push(1.0); state.random_picker_weight_multipliers.push(1.0); }
+
+                    ui.horizontal(|ui_h| {
+                        ui_h.label("Values per draw:");
+                        ui_h.add(egui::DragValue::new(&mut state.random_picker_draw_count).range(1..=50))
+                            .on_hover_text("Refresh draws this many distinct values by weight (no repeats)");
+                    });
+                    ui.horizontal(|ui_h| {
+                        ui_h.label("Seed:");
+                        ui_h.add(
+                            egui::TextEdit::singleline(&mut state.random_picker_seed_input)
+                                .desired_width(120.0)
+                                .hint_text("random"),
+                        )
+                        .on_hover_text("With a seed, Refresh replays the same sequence of draws for the same data");
+                        let parsed = state.random_picker_seed_input.trim().parse::<u64>().ok();
+                        if ui_h
+                            .add_enabled(parsed.is_some(), egui::Button::new("Set"))
+                            .on_hover_text("Use this seed and restart its sequence")
+                            .on_disabled_hover_text("Enter a whole number (0 to 18446744073709551615)")
+                            .clicked()
+                        {
+                            state.set_random_picker_seed(parsed);
+                        }
+                        if ui_h
+                            .add_enabled(state.random_picker_seed.is_some(), egui::Button::new("Clear seed"))
+                            .on_hover_text("Go back to non-deterministic draws")
+                            .clicked()
+                        {
+                            state.set_random_picker_seed(None);
+                        }
+                    });
+
+                    ui.separator();
+                    show_pick_probability_preview(ui, state, registry_immut, &popup_category, &popup_sheet_name);
                 }
 
                 // Summarizer section only when Summarizer picked
@@ -264,3 +299,55 @@ pub(super) fn show_random_picker_window_ui(
         close_via_x,
     }
 }
+
+/// What-if preview: selection probability of the heaviest rows under the settings being edited
+fn show_pick_probability_preview(
+    ui: &mut egui::Ui,
+    state: &mut EditorWindowState,
+    registry_immut: &SheetRegistry,
+    category: &Option<String>,
+    sheet_name: &str,
+) {
+    egui::CollapsingHeader::new("Preview probabilities")
+        .id_salt(format!("rp_preview_{}", sheet_name))
+        .show(ui, |ui| {
+            let Some(sheet) = registry_immut.get_sheet(category, sheet_name) else {
+                ui.label("Sheet not loaded");
+                return;
+            };
+            let result_col = state.random_simple_result_col;
+            let weight_cols: Vec<usize> = state.random_picker_weight_columns.iter().filter_map(|o| *o).collect();
+            let candidates = weighted_candidates(
+                &sheet.grid,
+                result_col,
+                &weight_cols,
+                &state.random_picker_weight_exponents,
+                &state.random_picker_weight_multipliers,
+            );
+            if candidates.is_empty() {
+                ui.label("No rows with a value in the data column.");
+                return;
+            }
+            ui.horizontal(|ui_h| {
+                ui_h.label("Show top");
+                ui_h.add(egui::DragValue::new(&mut state.random_picker_preview_count).range(1..=100));
+                ui_h.label(format!("of {} candidate rows", candidates.len()));
+            });
+            let top = top_pick_probabilities(&candidates, state.random_picker_preview_count);
+            let covered: f64 = top.iter().map(|(_, p)| p).sum();
+            egui::ScrollArea::vertical().max_height(220.0).show(ui, |ui_s| {
+                egui::Grid::new(format!("rp_preview_grid_{}", sheet_name))
+                    .num_columns(2)
+                    .striped(true)
+                    .show(ui_s, |ui_g| {
+                        for (row_idx, probability) in &top {
+                            let value = sheet.grid[*row_idx].get(result_col).map(String::as_str).unwrap_or_default();
+                            ui_g.label(value);
+                            ui_g.label(format!("{:.2}%", probability * 100.0));
+                            ui_g.end_row();
+                        }
+                    });
+            });
+            ui.small(format!("Shown rows cover {:.1}% of picks.", covered * 100.0));
+        });
+}
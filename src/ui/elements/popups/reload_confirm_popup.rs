@@ -0,0 +1,49 @@
+// src/ui/elements/popups/reload_confirm_popup.rs
+use bevy_egui::egui;
+
+use crate::ui::elements::editor::EditorWindowState;
+
+/// Confirmation for "Reload from Database"; on confirm the reload is queued in
+/// `pending_sheet_reload` and processed by the editor system.
+pub fn show_reload_confirm_popup(ctx: &egui::Context, state: &mut EditorWindowState) {
+    let Some((category, sheet_name)) = state.reload_confirm_target.clone() else {
+        return;
+    };
+
+    let mut is_open = true;
+    let mut reload_clicked = false;
+    let mut cancel_clicked = false;
+
+    egui::Window::new("Reload from Database")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .open(&mut is_open)
+        .show(ctx, |ui| {
+            ui.label(format!(
+                "Reload sheet '{}/{}' and its structure tables from the database?",
+                category.as_deref().unwrap_or("root"),
+                sheet_name
+            ));
+            ui.colored_label(
+                egui::Color32::YELLOW,
+                "Any in-memory state not yet saved to the database will be discarded.",
+            );
+            ui.separator();
+            ui.horizontal(|ui| {
+                if ui.button("Reload").clicked() {
+                    reload_clicked = true;
+                }
+                if ui.button("Cancel").clicked() {
+                    cancel_clicked = true;
+                }
+            });
+        });
+
+    if reload_clicked {
+        state.pending_sheet_reload = Some((category, sheet_name));
+    }
+    if reload_clicked || cancel_clicked || !is_open {
+        state.reload_confirm_target = None;
+    }
+}
@@ -4,6 +4,7 @@ use bevy::prelude::*;
 use bevy_egui::egui;
 
 use crate::sheets::events::{RequestRenameCategory, RequestRenameSheet};
+use crate::sheets::resources::SheetRegistry;
 use crate::ui::elements::editor::EditorWindowState;
 use crate::ui::UiFeedbackState;
 
@@ -13,6 +14,7 @@ pub fn show_rename_popup(
     rename_sheet_writer: &mut EventWriter<RequestRenameSheet>,
     rename_category_writer: &mut EventWriter<RequestRenameCategory>,
     ui_feedback: &UiFeedbackState,
+    registry: &SheetRegistry,
 ) {
     // Only proceed if the popup should be shown according to the state
     if !state.show_rename_popup {
@@ -70,6 +72,15 @@ pub fn show_rename_popup(
                 ui.colored_label(egui::Color32::RED, &ui_feedback.last_message);
             }
 
+            if !renaming_category {
+                show_storage_details(
+                    ui,
+                    registry,
+                    &state.rename_target_category,
+                    &state.rename_target_sheet,
+                );
+            }
+
             ui.separator();
             ui.horizontal(|ui| {
                 if ui
@@ -131,3 +142,92 @@ pub fn show_rename_popup(
         state.show_rename_popup = rename_popup_open;
     }
 }
+
+/// Read-only storage layout of a sheet (physical tables, database file, structure parent),
+/// selectable so the names can be copied into CLI commands.
+fn show_storage_details(
+    ui: &mut egui::Ui,
+    registry: &SheetRegistry,
+    category: &Option<String>,
+    sheet_name: &str,
+) {
+    let Some(metadata) = registry
+        .get_sheet(category, sheet_name)
+        .and_then(|s| s.metadata.as_ref())
+    else {
+        return;
+    };
+
+    let mut rows: Vec<(&str, String)> = Vec::new();
+    match category {
+        Some(cat) => {
+            rows.push(("Table", metadata.sheet_name.clone()));
+            rows.push(("Metadata table", format!("{}_Metadata", metadata.sheet_name)));
+            let db_path = crate::sheets::systems::io::get_default_data_base_path()
+                .join(format!("{}.db", cat));
+            rows.push(("Database file", db_path.display().to_string()));
+        }
+        None => {
+            rows.push(("Data file", metadata.data_filename.clone()));
+        }
+    }
+
+    let parent = metadata
+        .structure_parent
+        .as_ref()
+        .map(|link| {
+            let column = registry
+                .get_sheet(&link.parent_category, &link.parent_sheet)
+                .and_then(|s| s.metadata.as_ref())
+                .and_then(|m| m.columns.get(link.parent_column_index))
+                .map(|c| c.header.clone())
+                .unwrap_or_else(|| format!("#{}", link.parent_column_index));
+            (link.parent_sheet.clone(), column)
+        })
+        .or_else(|| {
+            let has_parent_key = metadata
+                .columns
+                .iter()
+                .any(|c| c.header.eq_ignore_ascii_case("parent_key"));
+            if !has_parent_key {
+                return None;
+            }
+            let (_, parent_sheet) =
+                crate::ui::elements::editor::structure_navigation::structure_parent_sheet(
+                    registry, category, sheet_name,
+                )?;
+            let column = sheet_name
+                .strip_prefix(&format!("{}_", parent_sheet))
+                .unwrap_or_default()
+                .to_string();
+            Some((parent_sheet, column))
+        });
+    if let Some((parent_sheet, column)) = parent {
+        rows.push((
+            "Structure parent",
+            format!("{}.{} (parent_key -> {}.row_index)", parent_sheet, column, parent_sheet),
+        ));
+    }
+
+    ui.separator();
+    egui::CollapsingHeader::new("Storage")
+        .id_salt("rename_popup_storage_details")
+        .default_open(true)
+        .show(ui, |ui| {
+            egui::Grid::new("rename_popup_storage_grid")
+                .num_columns(2)
+                .spacing([8.0, 4.0])
+                .show(ui, |ui| {
+                    for (label, value) in rows {
+                        ui.label(label);
+                        let mut text = value.as_str();
+                        ui.add(
+                            egui::TextEdit::singleline(&mut text)
+                                .font(egui::TextStyle::Monospace)
+                                .desired_width(320.0),
+                        );
+                        ui.end_row();
+                    }
+                });
+        });
+}
@@ -0,0 +1,60 @@
+// src/ui/elements/popups/revert_json_confirm_popup.rs
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+use crate::sheets::events::RevertSheetFromJsonEvent;
+use crate::ui::elements::editor::EditorWindowState;
+
+/// Confirmation for "Revert to Saved JSON"; on confirm a `RevertSheetFromJsonEvent` is sent.
+pub fn show_revert_json_confirm_popup(
+    ctx: &egui::Context,
+    state: &mut EditorWindowState,
+    writer: &mut EventWriter<RevertSheetFromJsonEvent>,
+) {
+    let Some((category, sheet_name)) = state.revert_json_confirm_target.clone() else {
+        return;
+    };
+
+    let mut is_open = true;
+    let mut revert_clicked = false;
+    let mut cancel_clicked = false;
+
+    egui::Window::new("Revert to Saved JSON")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .open(&mut is_open)
+        .show(ctx, |ui| {
+            ui.label(format!(
+                "Replace the rows of '{:?}/{}' with those of its last JSON export?",
+                category, sheet_name
+            ));
+            ui.label("Structure tables and locked rows keep their current rows.");
+            ui.colored_label(
+                egui::Color32::YELLOW,
+                "Changes made since the export will be lost. This cannot be undone.",
+            );
+            ui.separator();
+            ui.horizontal(|ui| {
+                if ui
+                    .add(egui::Button::new("Revert").fill(egui::Color32::DARK_RED))
+                    .clicked()
+                {
+                    revert_clicked = true;
+                }
+                if ui.button("Cancel").clicked() {
+                    cancel_clicked = true;
+                }
+            });
+        });
+
+    if revert_clicked {
+        writer.write(RevertSheetFromJsonEvent {
+            category,
+            sheet_name,
+        });
+    }
+    if revert_clicked || cancel_clicked || !is_open {
+        state.revert_json_confirm_target = None;
+    }
+}
@@ -1,4 +1,10 @@
 // src/ui/elements/popups/settings_popup.rs
+use crate::sheets::database::migration::ProjectArchive;
+use crate::sheets::events::{
+    ImportCollisionPolicy, RequestCompactDatabase, RequestExportProject, RequestExportSheetToJsonl,
+    RequestExportSheetToParquet, RequestImportProject, RequestRotateApiKey,
+    RequestValidateStructureLinks,
+};
 use crate::sheets::resources::SheetRegistry;
 use crate::ui::elements::editor::EditorWindowState;
 use crate::ApiKeyDisplayStatus;
@@ -7,12 +13,14 @@ use bevy::log::info;
 use bevy_egui::egui;
 // Removed: use bevy::prelude::ResMut;
 use crate::settings::io::{load_settings_from_file, save_settings_to_file};
-use crate::settings::AppSettings;
+use crate::settings::{AppSettings, PendingDataDirectoryChange};
+use crate::sheets::systems::io::data_dir;
 use crate::visual_copier::events::{
-    PickFolderRequest, QueueTopPanelCopyEvent, ReverseTopPanelFoldersEvent,
-    VisualCopierStateChanged,
+    ExportCopyTasksRequest, ImportCopyTasksRequest, PickFolderRequest, QueueTopPanelCopyEvent,
+    ReverseTopPanelFoldersEvent, VisualCopierStateChanged,
 };
-use crate::visual_copier::resources::VisualCopierManager;
+use crate::visual_copier::executers::scan_source_columns;
+use crate::visual_copier::resources::{ConflictPolicy, SyncDirection, VisualCopierManager};
 use bevy::prelude::EventWriter;
 use whoami;
 
@@ -25,18 +33,28 @@ pub fn show_settings_popup(
     // Registry retained for potential future settings, currently unused
     _registry: &mut SheetRegistry,
     // NEW: Quick Copy settings (Copy on Exit)
-    _copier_manager: &mut VisualCopierManager,
+    copier_manager: &mut VisualCopierManager,
     // Event writers to drive Quick Copy actions inside Settings
     _pick_folder_writer: &mut EventWriter<PickFolderRequest>,
     _queue_top_panel_copy_writer: &mut EventWriter<QueueTopPanelCopyEvent>,
     _reverse_folders_writer: &mut EventWriter<ReverseTopPanelFoldersEvent>,
-    _state_changed_writer: &mut EventWriter<VisualCopierStateChanged>,
+    state_changed_writer: &mut EventWriter<VisualCopierStateChanged>,
+    export_tasks_writer: &mut EventWriter<ExportCopyTasksRequest>,
+    import_tasks_writer: &mut EventWriter<ImportCopyTasksRequest>,
+    rotate_api_key_writer: &mut EventWriter<RequestRotateApiKey>,
+    export_jsonl_writer: &mut EventWriter<RequestExportSheetToJsonl>,
+    export_parquet_writer: &mut EventWriter<RequestExportSheetToParquet>,
+    export_project_writer: &mut EventWriter<RequestExportProject>,
+    import_project_writer: &mut EventWriter<RequestImportProject>,
+    compact_database_writer: &mut EventWriter<RequestCompactDatabase>,
+    validate_structure_links_writer: &mut EventWriter<RequestValidateStructureLinks>,
 ) {
     // --- END MODIFIED ---
     if state.show_settings_popup {
         // Only check keyring when popup is first opened
         let popup_just_opened = state.show_settings_popup && !state.was_settings_popup_open;
-        if popup_just_opened {
+        // Key store access is skipped entirely when AI features are disabled
+        if popup_just_opened && state.ai_available() {
             let username = whoami::username();
             info!("[DEBUG] (Popup just opened) Checking key status for username: {username}");
             let keyring_status = keyring::Entry::new("GoogleGeminiAPI", username.as_str())
@@ -60,12 +78,11 @@ pub fn show_settings_popup(
                 session_api_key.0 = None;
                 api_key_status.status = "No Key Set".to_string();
             }
+        }
+        if popup_just_opened {
             // Also load persisted AppSettings into the UI state (best-effort)
             if let Ok(loaded) = load_settings_from_file::<AppSettings>() {
-                state.fps_setting = loaded.fps_setting;
-                state.show_hidden_sheets = loaded.show_hidden_sheets;
-                state.ai_depth_limit = loaded.ai_depth_limit;
-                state.ai_width_limit = loaded.ai_width_limit;
+                loaded.apply_to_state(state);
             }
         }
     }
@@ -79,75 +96,57 @@ pub fn show_settings_popup(
         .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
         .open(&mut is_window_open)
         .show(ctx, |ui| {
-            ui.horizontal_wrapped(|ui_h| {
-                ui_h.label("API Key:");
-                let _key_input_response = ui_h.add(
-                    egui::TextEdit::singleline(&mut state.settings_new_api_key_input)
-                        .password(true)
-                        .desired_width(280.0),
-                );
-                ui_h.label(api_key_status.status.as_str());
-                if ui_h.button("Set Key").clicked() {
-                let username = whoami::username();
-                info!("[DEBUG] Saving key for username: {username}");
-                // Clone the input before any mutable borrow
-                let trimmed_key_owned = state.settings_new_api_key_input.trim().to_string();
-                let trimmed_key = trimmed_key_owned.as_str();
-                if !trimmed_key.is_empty() {
-                    // Save to Windows Credential Manager for Python access using keyring
-                    match keyring::Entry::new("GoogleGeminiAPI", username.as_str())
-                        .and_then(|entry| entry.set_password(trimmed_key))
+            if state.ai_available() {
+                ui.horizontal_wrapped(|ui_h| {
+                    ui_h.label("API Key:");
+                    let _key_input_response = ui_h.add(
+                        egui::TextEdit::singleline(&mut state.settings_new_api_key_input)
+                            .password(true)
+                            .desired_width(280.0),
+                    );
+                    ui_h.label(api_key_status.status.as_str());
+                    let trimmed_key = state.settings_new_api_key_input.trim().to_string();
+                    if state.settings_api_key_validating {
+                        ui_h.spinner();
+                    } else if ui_h
+                        .add_enabled(!trimmed_key.is_empty(), egui::Button::new("Set Key"))
+                        .on_hover_text("Validate the key with the provider, then save it. The current key is kept if validation fails.")
+                        .clicked()
                     {
-                        Ok(_) => {
-                            info!("API Key saved to Windows Credential Manager for cross-language access.");
-                            // Try to read it back immediately
+                        info!("Requesting validation of new API key");
+                        state.settings_api_key_validating = true;
+                        rotate_api_key_writer.write(RequestRotateApiKey {
+                            new_key: trimmed_key,
+                        });
+                    }
+                    ui_h.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui_r| {
+                        if ui_r.button("Clear Key").clicked() {
+                            let username = whoami::username();
+                            info!("[DEBUG] Clearing key for username: {username}");
+                            session_api_key.0 = None;
+                            info!("API Key cleared from session.");
+                            match keyring::Entry::new("GoogleGeminiAPI", username.as_str()) {
+                                Ok(entry) => {
+                                    match entry.delete_credential() {
+                                        Ok(_) => info!("API Key removed from Windows Credential Manager."),
+                                        Err(e) => info!("Failed to remove API Key from Windows Credential Manager: {e}"),
+                                    }
+                                }
+                                Err(e) => info!("Failed to open keyring entry for deletion: {e}"),
+                            }
+                            // Try to read it back to confirm deletion
                             match keyring::Entry::new("GoogleGeminiAPI", username.as_str())
                                 .and_then(|entry| entry.get_password())
                             {
-                                Ok(loaded_key) => info!("[DEBUG] After save, loaded key: '{}', len={}", loaded_key, loaded_key.len()),
-                                Err(e) => info!("[DEBUG] After save, failed to load key: {e}"),
+                                Ok(loaded_key) => info!("[DEBUG] After clear, loaded key: '{}', len={}", loaded_key, loaded_key.len()),
+                                Err(e) => info!("[DEBUG] After clear, failed to load key: {e}"),
                             }
-                            session_api_key.0 = Some(trimmed_key.to_string());
-                            api_key_status.status = "Key Set".to_string();
-                            state.settings_new_api_key_input.clear();
-                        }
-                        Err(e) => {
-                            info!("Failed to save API Key to Windows Credential Manager: {e}");
-                            session_api_key.0 = None;
                             api_key_status.status = "No Key Set".to_string();
                         }
-                    }
-                } else {
-                    info!("API Key input was empty.");
-                }
-                }
-                ui_h.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui_r| {
-                    if ui_r.button("Clear Key").clicked() {
-                let username = whoami::username();
-                info!("[DEBUG] Clearing key for username: {username}");
-                session_api_key.0 = None;
-                info!("API Key cleared from session.");
-                match keyring::Entry::new("GoogleGeminiAPI", username.as_str()) {
-                    Ok(entry) => {
-                        match entry.delete_credential() {
-                            Ok(_) => info!("API Key removed from Windows Credential Manager."),
-                            Err(e) => info!("Failed to remove API Key from Windows Credential Manager: {e}"),
-                        }
-                    }
-                    Err(e) => info!("Failed to open keyring entry for deletion: {e}"),
-                }
-                // Try to read it back to confirm deletion
-                match keyring::Entry::new("GoogleGeminiAPI", username.as_str())
-                    .and_then(|entry| entry.get_password())
-                {
-                    Ok(loaded_key) => info!("[DEBUG] After clear, loaded key: '{}', len={}", loaded_key, loaded_key.len()),
-                    Err(e) => info!("[DEBUG] After clear, failed to load key: {e}"),
-                }
-                api_key_status.status = "No Key Set".to_string();
-                    }
+                    });
                 });
-            });
-            ui.separator();
+                ui.separator();
+            }
                     ui.heading("Performance");
                     ui.horizontal_wrapped(|ui_h| {
                         ui_h.label("Frame rate:");
@@ -158,21 +157,53 @@ pub fn show_settings_popup(
                                 crate::ui::elements::editor::state::FpsSetting::Thirty => "30",
                                 crate::ui::elements::editor::state::FpsSetting::Sixty => "60",
                                 crate::ui::elements::editor::state::FpsSetting::ScreenHz => "Screen Hz (Auto)",
+                                crate::ui::elements::editor::state::FpsSetting::Custom(_) => "Custom",
                             })
                             .show_ui(ui_h, |ui_cb| {
                                 ui_cb.selectable_value(&mut fps_choice, crate::ui::elements::editor::state::FpsSetting::Thirty, "30");
                                 ui_cb.selectable_value(&mut fps_choice, crate::ui::elements::editor::state::FpsSetting::Sixty, "60");
                                 ui_cb.selectable_value(&mut fps_choice, crate::ui::elements::editor::state::FpsSetting::ScreenHz, "Screen Hz (Auto)");
+                                let is_custom = matches!(fps_choice, crate::ui::elements::editor::state::FpsSetting::Custom(_));
+                                if ui_cb.selectable_label(is_custom, "Custom").clicked() && !is_custom {
+                                    fps_choice = crate::ui::elements::editor::state::FpsSetting::Custom(
+                                        crate::ui::elements::editor::state::FpsSetting::CUSTOM_DEFAULT_HZ,
+                                    );
+                                }
                             });
+                        if let crate::ui::elements::editor::state::FpsSetting::Custom(hz) = &mut fps_choice {
+                            ui_h.add(
+                                egui::DragValue::new(hz)
+                                    .range(
+                                        crate::ui::elements::editor::state::FpsSetting::CUSTOM_MIN_HZ
+                                            ..=crate::ui::elements::editor::state::FpsSetting::CUSTOM_MAX_HZ,
+                                    )
+                                    .speed(1.0)
+                                    .suffix(" Hz"),
+                            );
+                        }
                         if fps_choice != state.fps_setting {
                             state.fps_setting = fps_choice;
                             // Persist the change (and current structure sheets toggle)
-                            let settings_to_save = AppSettings { fps_setting: state.fps_setting, show_hidden_sheets: state.show_hidden_sheets, ai_depth_limit: state.ai_depth_limit, ai_width_limit: state.ai_width_limit };
+                            let settings_to_save = AppSettings::from_state(state);
                             if let Err(e) = save_settings_to_file(&settings_to_save) {
                                 info!("Failed to save AppSettings: {}", e);
                             }
                         }
                     });
+            ui.horizontal_wrapped(|ui_h| {
+                let mut on_top = state.always_on_top;
+                if ui_h
+                    .checkbox(&mut on_top, "Keep window on top")
+                    .on_hover_text("Keep SkylineDB above other windows, e.g. while copying data from a browser")
+                    .changed()
+                {
+                    state.always_on_top = on_top;
+                    let settings_to_save = AppSettings::from_state(state);
+                    if let Err(e) = save_settings_to_file(&settings_to_save) {
+                        info!("Failed to save AppSettings: {}", e);
+                    }
+                }
+            });
             ui.separator();
             ui.heading("Database Views");
             ui.horizontal_wrapped(|ui_h| {
@@ -180,39 +211,535 @@ pub fn show_settings_popup(
                 if ui_h.checkbox(&mut show_hidden, "Show hidden sheets").on_hover_text("Temporarily show all sheets regardless of their hidden flag").changed() {
                     state.show_hidden_sheets = show_hidden;
                     // Persist both settings together
-                    let settings_to_save = AppSettings { fps_setting: state.fps_setting, show_hidden_sheets: state.show_hidden_sheets, ai_depth_limit: state.ai_depth_limit, ai_width_limit: state.ai_width_limit };
+                    let settings_to_save = AppSettings::from_state(state);
                     if let Err(e) = save_settings_to_file(&settings_to_save) {
                         info!("Failed to save AppSettings: {}", e);
                     }
                 }
                     });
+            ui.horizontal_wrapped(|ui_h| {
+                let mut placeholder = state.show_empty_cell_placeholder;
+                if ui_h
+                    .checkbox(&mut placeholder, "Show placeholder in empty cells")
+                    .on_hover_text("Empty text cells show a faint dash so gaps in the data stand out. The dash is never stored.")
+                    .changed()
+                {
+                    state.show_empty_cell_placeholder = placeholder;
+                    let settings_to_save = AppSettings::from_state(state);
+                    if let Err(e) = save_settings_to_file(&settings_to_save) {
+                        info!("Failed to save AppSettings: {}", e);
+                    }
+                }
+            });
+            ui.horizontal_wrapped(|ui_h| {
+                use crate::ui::elements::editor::state::StructureTableNaming;
+                ui_h.label("Structure table name clash:").on_hover_text(
+                    "Structure child tables are named {sheet}_{column}. When that name is already used by another sheet, the new structure column is stored under an adjusted name (its visible name is kept).",
+                );
+                let mut naming = state.structure_table_naming;
+                egui::ComboBox::from_id_salt("structure_table_naming_combo")
+                    .selected_text(match naming {
+                        StructureTableNaming::NumericSuffix => "Numeric suffix",
+                        StructureTableNaming::CategoryPrefix => "Category prefix",
+                    })
+                    .show_ui(ui_h, |ui_cb| {
+                        ui_cb.selectable_value(&mut naming, StructureTableNaming::NumericSuffix, "Numeric suffix");
+                        ui_cb.selectable_value(&mut naming, StructureTableNaming::CategoryPrefix, "Category prefix");
+                    });
+                if naming != state.structure_table_naming {
+                    state.structure_table_naming = naming;
+                    let settings_to_save = AppSettings::from_state(state);
+                    if let Err(e) = save_settings_to_file(&settings_to_save) {
+                        info!("Failed to save AppSettings: {}", e);
+                    }
+                }
+            });
             ui.separator();
-            ui.heading("AI Settings");
+            ui.heading("Developer");
             ui.horizontal_wrapped(|ui_h| {
-                ui_h.label("Depth limit:");
-                let mut depth = state.ai_depth_limit;
-                let depth_drag = egui::DragValue::new(&mut depth).range(1..=10).speed(0.1);
-                if ui_h.add(depth_drag).on_hover_text("How many levels of structure tables to process (default: 2)").changed() {
-                    state.ai_depth_limit = depth;
-                    let settings_to_save = AppSettings { fps_setting: state.fps_setting, show_hidden_sheets: state.show_hidden_sheets, ai_depth_limit: state.ai_depth_limit, ai_width_limit: state.ai_width_limit };
+                let mut sql_console = state.sql_console_enabled;
+                if ui_h
+                    .checkbox(&mut sql_console, "Enable SQL console")
+                    .on_hover_text("Run read-only SELECT queries against a category database for quick inspection.")
+                    .changed()
+                {
+                    state.sql_console_enabled = sql_console;
+                    if !sql_console {
+                        state.show_sql_console = false;
+                    }
+                    let settings_to_save = AppSettings::from_state(state);
                     if let Err(e) = save_settings_to_file(&settings_to_save) {
                         info!("Failed to save AppSettings: {}", e);
                     }
                 }
+                if state.sql_console_enabled && ui_h.button("Open SQL Console").clicked() {
+                    state.show_sql_console = true;
+                }
             });
             ui.horizontal_wrapped(|ui_h| {
-                ui_h.label("Width limit (rows per batch):");
-                let mut width = state.ai_width_limit;
-                let width_drag = egui::DragValue::new(&mut width).range(1..=256).speed(1.0);
-                if ui_h.add(width_drag).on_hover_text("How many rows to send in one AI batch (default: 32)").changed() {
-                    state.ai_width_limit = width;
-                    let settings_to_save = AppSettings { fps_setting: state.fps_setting, show_hidden_sheets: state.show_hidden_sheets, ai_depth_limit: state.ai_depth_limit, ai_width_limit: state.ai_width_limit };
+                let mut show_technical = state.show_technical_columns;
+                if ui_h
+                    .checkbox(&mut show_technical, "Show technical columns")
+                    .on_hover_text("Show row_index and parent_key columns in the grid. They stay read-only; parent_key shows its parent lineage.")
+                    .changed()
+                {
+                    state.show_technical_columns = show_technical;
+                    let settings_to_save = AppSettings::from_state(state);
                     if let Err(e) = save_settings_to_file(&settings_to_save) {
                         info!("Failed to save AppSettings: {}", e);
                     }
                 }
             });
-            // Quick Copy section hidden in DB-focused mode.
+            ui.separator();
+            ui.heading("Clipboard");
+            ui.horizontal_wrapped(|ui_h| {
+                let mut formula_reference = state.copy_formula_reference;
+                if ui_h
+                    .checkbox(&mut formula_reference, "Copy ranges as formula reference")
+                    .on_hover_text("\"Copy range to here\" puts an A1 reference like B2:D10 on the clipboard instead of the values. Columns follow the visible column order and row 1 is the header, as in an exported sheet.")
+                    .changed()
+                {
+                    state.copy_formula_reference = formula_reference;
+                    let settings_to_save = AppSettings::from_state(state);
+                    if let Err(e) = save_settings_to_file(&settings_to_save) {
+                        info!("Failed to save AppSettings: {}", e);
+                    }
+                }
+            });
+            ui.separator();
+            ui.heading("AI Settings");
+            let mut ai_enabled = state.ai_features_enabled;
+            if ui
+                .checkbox(&mut ai_enabled, "Enable AI features")
+                .on_hover_text("When off, AI Mode is hidden and the Python runtime and key store are not touched at startup")
+                .changed()
+            {
+                state.ai_features_enabled = ai_enabled;
+                if ai_enabled {
+                    state.ai_runtime_error = crate::prepare_ai_runtime().err();
+                } else if state.current_interaction_mode
+                    == crate::ui::elements::editor::state::SheetInteractionState::AiModeActive
+                {
+                    state.reset_interaction_modes_and_selections();
+                }
+                let settings_to_save = AppSettings::from_state(state);
+                if let Err(e) = save_settings_to_file(&settings_to_save) {
+                    info!("Failed to save AppSettings: {}", e);
+                }
+            }
+            if let (true, Some(reason)) = (state.ai_features_enabled, &state.ai_runtime_error) {
+                ui.colored_label(
+                    egui::Color32::from_rgb(220, 160, 40),
+                    format!("⚠ AI unavailable this session: {}", reason),
+                );
+            }
+            if state.ai_features_enabled {
+                ui.horizontal_wrapped(|ui_h| {
+                    ui_h.label("Script file:");
+                    ui_h.add(
+                        egui::TextEdit::singleline(&mut state.ai_script_path_input)
+                            .hint_text("automatic")
+                            .desired_width(260.0),
+                    )
+                    .on_hover_text("Where ai_processor.py is written and loaded from. Leave empty to use script/ next to the executable (or the user data folder when that is read-only).");
+                    let trimmed = state.ai_script_path_input.trim();
+                    let requested = (!trimmed.is_empty()).then(|| std::path::PathBuf::from(trimmed));
+                    if ui_h
+                        .add_enabled(requested != state.ai_script_path, egui::Button::new("Apply"))
+                        .clicked()
+                    {
+                        crate::sheets::systems::ai::script_file::set_configured_script_path(requested.clone());
+                        state.ai_script_path_error =
+                            crate::sheets::systems::ai::script_file::ensure_ai_script().err();
+                        state.ai_script_path = requested;
+                        let settings_to_save = AppSettings::from_state(state);
+                        if let Err(e) = save_settings_to_file(&settings_to_save) {
+                            info!("Failed to save AppSettings: {}", e);
+                        }
+                    }
+                });
+                ui.weak(format!(
+                    "In use: {}",
+                    crate::sheets::systems::ai::script_file::ai_script_path().display()
+                ));
+                if let Some(e) = &state.ai_script_path_error {
+                    ui.colored_label(egui::Color32::RED, e);
+                }
+            }
+            if state.ai_available() {
+                ui.horizontal_wrapped(|ui_h| {
+                    ui_h.label("Depth limit:");
+                    let mut depth = state.ai_depth_limit;
+                    let depth_drag = egui::DragValue::new(&mut depth).range(1..=10).speed(0.1);
+                    if ui_h.add(depth_drag).on_hover_text("How many levels of structure tables to process (default: 2)").changed() {
+                        state.ai_depth_limit = depth;
+                        let settings_to_save = AppSettings::from_state(state);
+                        if let Err(e) = save_settings_to_file(&settings_to_save) {
+                            info!("Failed to save AppSettings: {}", e);
+                        }
+                    }
+                });
+                ui.horizontal_wrapped(|ui_h| {
+                    ui_h.label("Width limit (rows per batch):");
+                    let mut width = state.ai_width_limit;
+                    let width_drag = egui::DragValue::new(&mut width).range(1..=256).speed(1.0);
+                    if ui_h.add(width_drag).on_hover_text("How many rows to send in one AI batch (default: 32)").changed() {
+                        state.ai_width_limit = width;
+                        let settings_to_save = AppSettings::from_state(state);
+                        if let Err(e) = save_settings_to_file(&settings_to_save) {
+                            info!("Failed to save AppSettings: {}", e);
+                        }
+                    }
+                });
+                ui.horizontal_wrapped(|ui_h| {
+                    ui_h.label("Character budget per batch:");
+                    let mut budget = state.ai_char_budget;
+                    let budget_drag = egui::DragValue::new(&mut budget).range(0..=1_000_000).speed(100.0);
+                    if ui_h.add(budget_drag).on_hover_text("Split batches so the cell text sent stays under this many characters; a single larger row is sent alone (0 = rows only, default: 24000)").changed() {
+                        state.ai_char_budget = budget;
+                        let settings_to_save = AppSettings::from_state(state);
+                        if let Err(e) = save_settings_to_file(&settings_to_save) {
+                            info!("Failed to save AppSettings: {}", e);
+                        }
+                    }
+                });
+                ui.horizontal_wrapped(|ui_h| {
+                    ui_h.label("Retries:");
+                    let mut retries = state.ai_max_retries;
+                    let retries_changed = ui_h
+                        .add(egui::DragValue::new(&mut retries).range(0..=10).speed(0.1))
+                        .on_hover_text("Retry requests failing with a rate limit (429), server error (5xx) or timeout; bad requests and key errors are not retried (default: 3)")
+                        .changed();
+                    ui_h.label("first wait (ms):");
+                    let mut backoff = state.ai_retry_backoff_ms;
+                    let backoff_changed = ui_h
+                        .add(egui::DragValue::new(&mut backoff).range(100..=60_000).speed(50.0))
+                        .on_hover_text("Wait before the first retry; doubled for every further retry, at most 60 s (default: 2000)")
+                        .changed();
+                    ui_h.label("Min. interval (ms):");
+                    let mut interval = state.ai_min_request_interval_ms;
+                    let interval_changed = ui_h
+                        .add(egui::DragValue::new(&mut interval).range(0..=60_000).speed(50.0))
+                        .on_hover_text("Minimum time between the starts of two AI requests, to stay under rate limits (default: 1000)")
+                        .changed();
+                    if retries_changed || backoff_changed || interval_changed {
+                        state.ai_max_retries = retries;
+                        state.ai_retry_backoff_ms = backoff;
+                        state.ai_min_request_interval_ms = interval;
+                        let settings_to_save = AppSettings::from_state(state);
+                        if let Err(e) = save_settings_to_file(&settings_to_save) {
+                            info!("Failed to save AppSettings: {}", e);
+                        }
+                    }
+                });
+                ui.horizontal_wrapped(|ui_h| {
+                    ui_h.label("Token warning per request:");
+                    let mut threshold = state.ai_token_warning_threshold;
+                    let threshold_drag = egui::DragValue::new(&mut threshold).range(1_000..=10_000_000).speed(1_000.0);
+                    if ui_h.add(threshold_drag).on_hover_text("The AI panel shows the estimated token count in warning colors once one request would exceed this; keep it a little under the model's context window (default: 800000)").changed() {
+                        state.ai_token_warning_threshold = threshold;
+                        let settings_to_save = AppSettings::from_state(state);
+                        if let Err(e) = save_settings_to_file(&settings_to_save) {
+                            info!("Failed to save AppSettings: {}", e);
+                        }
+                    }
+                });
+                ui.horizontal_wrapped(|ui_h| {
+                    let mut fuzzy = state.ai_duplicate_fuzzy;
+                    let fuzzy_changed = ui_h
+                        .checkbox(&mut fuzzy, "Fuzzy duplicate matching")
+                        .on_hover_text("Also flag near-duplicates of existing rows, e.g. \"F-16C\" vs \"F16C\" or small typos")
+                        .changed();
+                    let mut similarity = state.ai_duplicate_similarity;
+                    let similarity_changed = ui_h
+                        .add_enabled(
+                            fuzzy,
+                            egui::Slider::new(&mut similarity, 0.5..=1.0).text("similarity"),
+                        )
+                        .on_hover_text("Minimum similarity for a fuzzy match; 1.0 only ignores case, spacing and punctuation (default: 0.85)")
+                        .changed();
+                    if fuzzy_changed || similarity_changed {
+                        state.ai_duplicate_fuzzy = fuzzy;
+                        state.ai_duplicate_similarity = similarity;
+                        let settings_to_save = AppSettings::from_state(state);
+                        if let Err(e) = save_settings_to_file(&settings_to_save) {
+                            info!("Failed to save AppSettings: {}", e);
+                        }
+                    }
+                });
+            }
+            ui.separator();
+            ui.heading("Memory");
+            ui.horizontal_wrapped(|ui_h| {
+                ui_h.label("Max loaded sheets:");
+                let mut max_loaded = state.max_loaded_sheets;
+                let max_drag = egui::DragValue::new(&mut max_loaded).range(0..=500).speed(1.0);
+                if ui_h
+                    .add(max_drag)
+                    .on_hover_text("Keep this many recently viewed database sheets in memory; older ones are reloaded from the database when opened again (0 = unlimited, default: 12)")
+                    .changed()
+                {
+                    state.max_loaded_sheets = max_loaded;
+                    let settings_to_save = AppSettings::from_state(state);
+                    if let Err(e) = save_settings_to_file(&settings_to_save) {
+                        info!("Failed to save AppSettings: {}", e);
+                    }
+                }
+            });
+            // Quick Copy section hidden in DB-focused mode; only the task list transfer is exposed.
+            ui.separator();
+            ui.heading("Export / Import");
+            ui.horizontal_wrapped(|ui_h| {
+                // JSONL/Parquet export reads from the category database, so JSON-only sheets are excluded
+                let export_target = state
+                    .selected_category
+                    .clone()
+                    .zip(state.selected_sheet_name.clone());
+                let hover = match &export_target {
+                    Some((_, sheet)) => format!("Write '{}' as newline-delimited JSON (one object per row)", sheet),
+                    None => "Select a sheet stored in a category database first".to_string(),
+                };
+                if ui_h
+                    .add_enabled(export_target.is_some(), egui::Button::new("Export as JSONL…"))
+                    .on_hover_text(hover)
+                    .clicked()
+                {
+                    if let Some((category, table_name)) = export_target.clone() {
+                        if let Some(output_folder) = rfd::FileDialog::new().pick_folder() {
+                            let db_path = crate::sheets::systems::io::get_default_data_base_path()
+                                .join(format!("{}.db", category));
+                            export_jsonl_writer.write(RequestExportSheetToJsonl {
+                                db_path,
+                                table_name,
+                                output_folder,
+                            });
+                        }
+                    }
+                }
+                let parquet_hover = match &export_target {
+                    Some((_, sheet)) => format!("Write '{}' as Parquet with typed numeric columns (for pandas/Polars)", sheet),
+                    None => "Select a sheet stored in a category database first".to_string(),
+                };
+                if ui_h
+                    .add_enabled(export_target.is_some(), egui::Button::new("Export as Parquet…"))
+                    .on_hover_text(parquet_hover)
+                    .clicked()
+                {
+                    if let Some((category, table_name)) = export_target {
+                        if let Some(output_folder) = rfd::FileDialog::new().pick_folder() {
+                            let db_path = crate::sheets::systems::io::get_default_data_base_path()
+                                .join(format!("{}.db", category));
+                            export_parquet_writer.write(RequestExportSheetToParquet {
+                                db_path,
+                                table_name,
+                                output_folder,
+                            });
+                        }
+                    }
+                }
+                if ui_h
+                    .button("Export project…")
+                    .on_hover_text("Write every category database into one .skdb.zip archive (JSON tables plus manifest.json)")
+                    .clicked()
+                {
+                    if let Some(archive_path) = rfd::FileDialog::new()
+                        .add_filter("SkylineDB project archive", &["zip"])
+                        .set_file_name(format!(
+                            "project.{}",
+                            crate::sheets::database::migration::project_archive::PROJECT_ARCHIVE_EXTENSION
+                        ))
+                        .save_file()
+                    {
+                        export_project_writer.write(RequestExportProject { archive_path });
+                    }
+                }
+                if ui_h
+                    .button("Import project…")
+                    .on_hover_text("Load every table of a .skdb.zip project archive into the data folder")
+                    .clicked()
+                {
+                    if let Some(archive_path) = rfd::FileDialog::new()
+                        .add_filter("SkylineDB project archive", &["zip"])
+                        .pick_file()
+                    {
+                        // Unreadable archives go straight through; the import reports the error
+                        let collisions = ProjectArchive::read_manifest(&archive_path)
+                            .map(|manifest| {
+                                ProjectArchive::find_collisions(
+                                    &manifest,
+                                    &crate::sheets::systems::io::get_default_data_base_path(),
+                                )
+                            })
+                            .unwrap_or_default();
+                        if collisions.is_empty() {
+                            import_project_writer.write(RequestImportProject {
+                                archive_path,
+                                collision_policy: ImportCollisionPolicy::Suffix,
+                            });
+                        } else {
+                            state.project_import_prompt = Some((archive_path, collisions));
+                        }
+                    }
+                }
+            });
+            ui.separator();
+            ui.heading("Data Folder");
+            show_data_folder_settings(ui, state);
+            ui.separator();
+            ui.heading("Maintenance");
+            ui.horizontal_wrapped(|ui_h| {
+                let compact_target = state.selected_category.clone();
+                let hover = match &compact_target {
+                    Some(category) => format!(
+                        "Run VACUUM and REINDEX on '{}.db' to reclaim space left by deleted rows and tables",
+                        category
+                    ),
+                    None => "Select a category database first".to_string(),
+                };
+                if ui_h
+                    .add_enabled(compact_target.is_some(), egui::Button::new("Compact database"))
+                    .on_hover_text(hover)
+                    .clicked()
+                {
+                    if let Some(category) = compact_target {
+                        let db_path = crate::sheets::systems::io::get_default_data_base_path()
+                            .join(format!("{}.db", category));
+                        compact_database_writer.write(RequestCompactDatabase { db_path });
+                    }
+                }
+                if ui_h
+                    .add_enabled(compact_target.is_some(), egui::Button::new("Check structure links"))
+                    .on_hover_text("List structure rows whose parent row no longer exists (orphans); nothing is changed")
+                    .clicked()
+                {
+                    if let Some(category) = &compact_target {
+                        let db_path = crate::sheets::systems::io::get_default_data_base_path()
+                            .join(format!("{}.db", category));
+                        validate_structure_links_writer.write(RequestValidateStructureLinks { db_path });
+                    }
+                }
+            });
+            ui.separator();
+            ui.heading("Quick Copy tasks");
+            ui.horizontal_wrapped(|ui_h| {
+                ui_h.label(format!("{} task(s)", copier_manager.copy_tasks.len()));
+                if ui_h
+                    .add_enabled(!copier_manager.copy_tasks.is_empty(), egui::Button::new("Export tasks…"))
+                    .on_hover_text("Save the copy task list to a JSON file")
+                    .clicked()
+                {
+                    export_tasks_writer.write(ExportCopyTasksRequest);
+                }
+                if ui_h
+                    .button("Import tasks…")
+                    .on_hover_text("Add tasks from an exported JSON file. Tasks whose source folder does not exist here are skipped.")
+                    .clicked()
+                {
+                    import_tasks_writer.write(ImportCopyTasksRequest);
+                }
+            });
+            if !copier_manager.task_transfer_status.is_empty() {
+                ui.label(egui::RichText::new(&copier_manager.task_transfer_status).small());
+            }
+            // Per-task sync direction and column whitelist (only these columns of JSON sheets reach the destination)
+            let scanned_columns = &mut copier_manager.scanned_task_columns;
+            let mut task_options_changed = false;
+            for task in copier_manager.copy_tasks.iter_mut() {
+                let columns_label = match &task.column_whitelist {
+                    Some(columns) => format!("{} column(s)", columns.len()),
+                    None => "all columns".to_string(),
+                };
+                let title = format!("Task {}: {}, {}", task.id, task.direction.label(), columns_label);
+                egui::CollapsingHeader::new(title)
+                    .id_salt(("copy_task_options", task.id))
+                    .show(ui, |ui_c| {
+                        ui_c.horizontal(|ui_h| {
+                            ui_h.label("Direction:");
+                            egui::ComboBox::from_id_salt(("copy_task_direction", task.id))
+                                .selected_text(task.direction.label())
+                                .show_ui(ui_h, |ui_cb| {
+                                    for direction in SyncDirection::ALL {
+                                        if ui_cb
+                                            .selectable_value(&mut task.direction, direction, direction.label())
+                                            .changed()
+                                        {
+                                            task_options_changed = true;
+                                        }
+                                    }
+                                });
+                        });
+                        if task.direction == SyncDirection::Bidirectional {
+                            ui_c.horizontal(|ui_h| {
+                                ui_h.label("On conflict:")
+                                    .on_hover_text("Rows are matched by the sheet's key column. A conflict is a row changed differently in both folders since the last sync; conflicts are written to a log.");
+                                egui::ComboBox::from_id_salt(("copy_task_conflicts", task.id))
+                                    .selected_text(task.conflict_policy.label())
+                                    .show_ui(ui_h, |ui_cb| {
+                                        for policy in ConflictPolicy::ALL {
+                                            if ui_cb
+                                                .selectable_value(&mut task.conflict_policy, policy, policy.label())
+                                                .changed()
+                                            {
+                                                task_options_changed = true;
+                                            }
+                                        }
+                                    });
+                            });
+                            ui_c.label(egui::RichText::new("The column whitelist does not apply when syncing both ways.").small());
+                        }
+                        ui_c.horizontal(|ui_h| {
+                            if ui_h
+                                .add_enabled(task.start_folder.is_some(), egui::Button::new("Scan source columns"))
+                                .on_hover_text("List the column headers of the JSON sheets in the start folder")
+                                .clicked()
+                            {
+                                if let Some(start) = &task.start_folder {
+                                    scanned_columns.insert(task.id, scan_source_columns(start));
+                                }
+                            }
+                            if ui_h
+                                .add_enabled(task.column_whitelist.is_some(), egui::Button::new("All columns"))
+                                .on_hover_text("Copy every column")
+                                .clicked()
+                            {
+                                task.column_whitelist = None;
+                                task_options_changed = true;
+                            }
+                        });
+                        // Selected headers stay listed even if the latest scan no longer finds them
+                        let mut headers = scanned_columns.get(&task.id).cloned().unwrap_or_default();
+                        for selected in task.column_whitelist.iter().flatten() {
+                            if !headers.contains(selected) {
+                                headers.push(selected.clone());
+                            }
+                        }
+                        if headers.is_empty() {
+                            ui_c.label(egui::RichText::new("Scan the source folder to pick columns.").small());
+                        }
+                        for header in headers {
+                            let mut checked = task
+                                .column_whitelist
+                                .as_ref()
+                                .map_or(false, |columns| columns.contains(&header));
+                            if ui_c.checkbox(&mut checked, &header).changed() {
+                                let columns = task.column_whitelist.get_or_insert_with(Vec::new);
+                                if checked {
+                                    columns.push(header);
+                                } else {
+                                    columns.retain(|c| c != &header);
+                                }
+                                if columns.is_empty() {
+                                    task.column_whitelist = None;
+                                }
+                                task_options_changed = true;
+                            }
+                        }
+                    });
+            }
+            if task_options_changed {
+                state_changed_writer.write(VisualCopierStateChanged);
+            }
+            ui.separator();
              if ui.button("Close").clicked(){
                   close_requested = true;
              }
@@ -224,3 +751,105 @@ pub fn show_settings_popup(
     // At the end of the function, update the tracker
     state.was_settings_popup_open = state.show_settings_popup;
 }
+
+/// Where databases and JSON sheets are stored. A new folder must be writable; its data is
+/// either moved over or started empty, and either happens at the next start (the daemon
+/// keeps the folder it was started with).
+fn show_data_folder_settings(ui: &mut egui::Ui, state: &mut EditorWindowState) {
+    let in_use = crate::sheets::systems::io::get_default_data_base_path();
+    ui.label(format!("In use: {}", in_use.display()));
+    if let Some(change) = &state.pending_data_directory_change {
+        let next = state
+            .data_directory
+            .clone()
+            .unwrap_or_else(data_dir::default_data_dir);
+        ui.colored_label(
+            egui::Color32::YELLOW,
+            format!(
+                "After restarting SkylineDB: {} ({})",
+                next.display(),
+                if change.move_contents { "existing data moved there" } else { "started empty" }
+            ),
+        );
+    }
+
+    let mut picked: Option<std::path::PathBuf> = None;
+    ui.horizontal_wrapped(|ui_h| {
+        if ui_h
+            .button("Choose folder…")
+            .on_hover_text("Store databases and JSON sheets elsewhere, e.g. on a local drive when Documents is on network storage")
+            .clicked()
+        {
+            picked = rfd::FileDialog::new().set_directory(&in_use).pick_folder();
+        }
+        let customized = state.data_directory.is_some();
+        if ui_h
+            .add_enabled(customized, egui::Button::new("Use default"))
+            .on_hover_text(format!("Go back to {}", data_dir::default_data_dir().display()))
+            .clicked()
+        {
+            picked = Some(data_dir::default_data_dir());
+        }
+    });
+
+    if let Some(folder) = picked {
+        state.data_directory_candidate = None;
+        state.data_directory_error = None;
+        if folder == in_use {
+            // Back to the folder in use: drop any switch waiting for the next start
+            state.data_directory = data_dir::configured_data_dir();
+            state.pending_data_directory_change = None;
+            save_app_settings(state);
+        } else {
+            match data_dir::validate_data_dir(&folder) {
+                Ok(()) => state.data_directory_candidate = Some(folder),
+                Err(e) => state.data_directory_error = Some(e),
+            }
+        }
+    }
+
+    if let Some(candidate) = state.data_directory_candidate.clone() {
+        ui.label(format!("Switch to {}?", candidate.display()));
+        let mut choice: Option<bool> = None;
+        ui.horizontal_wrapped(|ui_h| {
+            let nested = candidate.starts_with(&in_use);
+            if ui_h
+                .add_enabled(!nested, egui::Button::new("Move existing data"))
+                .on_hover_text("Move everything in the current folder to the new one at the next start; nothing is moved if a name already exists there")
+                .on_disabled_hover_text("The new folder is inside the current one")
+                .clicked()
+            {
+                choice = Some(true);
+            }
+            if ui_h
+                .button("Start empty")
+                .on_hover_text("Use the new folder as it is; the current folder is left untouched")
+                .clicked()
+            {
+                choice = Some(false);
+            }
+            if ui_h.button("Cancel").clicked() {
+                state.data_directory_candidate = None;
+            }
+        });
+        if let Some(move_contents) = choice {
+            state.data_directory = (candidate != data_dir::default_data_dir()).then_some(candidate);
+            state.pending_data_directory_change = Some(PendingDataDirectoryChange {
+                from: in_use,
+                move_contents,
+            });
+            state.data_directory_candidate = None;
+            save_app_settings(state);
+        }
+    }
+    if let Some(e) = &state.data_directory_error {
+        ui.colored_label(egui::Color32::RED, e);
+    }
+}
+
+fn save_app_settings(state: &EditorWindowState) {
+    let settings_to_save = AppSettings::from_state(state);
+    if let Err(e) = save_settings_to_file(&settings_to_save) {
+        info!("Failed to save AppSettings: {}", e);
+    }
+}
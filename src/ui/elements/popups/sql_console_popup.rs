@@ -0,0 +1,122 @@
+// src/ui/elements/popups/sql_console_popup.rs
+use bevy_egui::egui;
+
+use crate::sheets::{database::query_readonly, resources::SheetRegistry};
+use crate::ui::elements::editor::EditorWindowState;
+
+/// Read-only SQL console (Settings > Developer). Runs one SELECT against the chosen
+/// category database and shows the result as a table.
+pub fn show_sql_console_popup(
+    ctx: &egui::Context,
+    state: &mut EditorWindowState,
+    registry: &SheetRegistry,
+) {
+    if !state.sql_console_enabled || !state.show_sql_console {
+        return;
+    }
+    if state.sql_console_category.is_none() {
+        state.sql_console_category = state.selected_category.clone();
+    }
+    let categories: Vec<String> = registry.get_categories().into_iter().flatten().collect();
+
+    let mut is_open = true;
+    let mut run_clicked = false;
+
+    egui::Window::new("SQL Console (read-only)")
+        .collapsible(true)
+        .resizable(true)
+        .default_size([640.0, 420.0])
+        .open(&mut is_open)
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.label("Database:");
+                egui::ComboBox::from_id_salt("sql_console_db")
+                    .selected_text(
+                        state
+                            .sql_console_category
+                            .as_deref()
+                            .map(|c| format!("{}.db", c))
+                            .unwrap_or_else(|| "--Select--".to_string()),
+                    )
+                    .show_ui(ui, |ui| {
+                        for cat in &categories {
+                            ui.selectable_value(
+                                &mut state.sql_console_category,
+                                Some(cat.clone()),
+                                format!("{}.db", cat),
+                            );
+                        }
+                    });
+            });
+            let input = ui.add(
+                egui::TextEdit::multiline(&mut state.sql_console_input)
+                    .code_editor()
+                    .desired_rows(4)
+                    .desired_width(f32::INFINITY)
+                    .hint_text("SELECT * FROM \"Items\" LIMIT 50"),
+            );
+            let ctrl_enter = input.has_focus()
+                && ui.input(|i| i.modifiers.command && i.key_pressed(egui::Key::Enter));
+            ui.horizontal(|ui| {
+                let can_run = state.sql_console_category.is_some()
+                    && !state.sql_console_input.trim().is_empty();
+                if ui
+                    .add_enabled(can_run, egui::Button::new("▶ Run"))
+                    .on_hover_text("Ctrl+Enter")
+                    .clicked()
+                    || (can_run && ctrl_enter)
+                {
+                    run_clicked = true;
+                }
+                ui.weak("Only a single SELECT statement is allowed.");
+            });
+            ui.separator();
+
+            match &state.sql_console_result {
+                None => {}
+                Some(Err(e)) => {
+                    ui.colored_label(egui::Color32::RED, e);
+                }
+                Some(Ok(rows)) => {
+                    let Some((header, data)) = rows.split_first() else {
+                        return;
+                    };
+                    ui.label(format!("{} row(s)", data.len()));
+                    egui::ScrollArea::both()
+                        .id_salt("sql_console_results")
+                        .auto_shrink([false, false])
+                        .show(ui, |ui| {
+                            egui::Grid::new("sql_console_grid")
+                                .striped(true)
+                                .num_columns(header.len())
+                                .show(ui, |ui| {
+                                    for name in header {
+                                        ui.strong(name);
+                                    }
+                                    ui.end_row();
+                                    for row in data {
+                                        for value in row {
+                                            ui.label(value);
+                                        }
+                                        ui.end_row();
+                                    }
+                                });
+                        });
+                }
+            }
+        });
+
+    if run_clicked {
+        let db_filename = state
+            .sql_console_category
+            .as_ref()
+            .map(|c| format!("{}.db", c));
+        state.sql_console_result = Some(
+            query_readonly(&state.sql_console_input, db_filename.as_deref())
+                .map_err(|e| e.to_string()),
+        );
+    }
+    if !is_open {
+        state.show_sql_console = false;
+    }
+}
@@ -43,6 +43,30 @@ pub fn show_validator_confirm_popup(
             if let Some(summary) = &state.pending_validator_new_validator_summary {
                 ui.label(format!("Change: {}", summary));
             }
+            if let Some(preview) = state.pending_structure_removal_preview.as_mut() {
+                ui.separator();
+                match preview.child_row_count {
+                    Some(rows) => {
+                        ui.label(format!(
+                            "The child table '{}' will be dropped. {} child row(s) are affected.",
+                            preview.child_sheet_name, rows
+                        ));
+                        ui.radio_value(
+                            &mut preview.flatten_into_cell,
+                            true,
+                            "Flatten child rows into the cell text, then drop the table",
+                        );
+                        ui.radio_value(
+                            &mut preview.flatten_into_cell,
+                            false,
+                            "Drop the child rows (cells are cleared)",
+                        );
+                    }
+                    None => {
+                        ui.label("No child table found; JSON structure cells are flattened into text, other cell values are kept.");
+                    }
+                }
+            }
             ui.separator();
             ui.horizontal(|ui_h| {
                 if ui_h.button("Confirm").clicked() {
@@ -52,6 +76,7 @@ pub fn show_validator_confirm_popup(
                     let col_index = state.options_column_target_index;
                     // Determine if target after confirmation should be Structure or revert (based on flag)
                     let target_is_structure = state.pending_validator_target_is_structure;
+                    let removal_preview = state.pending_structure_removal_preview.take();
 
                     // Gather and filter source indices (exclude self to avoid circular dependency)
                     let structure_sources: Vec<usize> = state
@@ -60,16 +85,8 @@ pub fn show_validator_confirm_popup(
                         .filter_map(|o| *o)
                         .collect(); // allow self column now
 
-                    if !target_is_structure {
-                        // Reverting away from structure: keep current text but clear validator (actual flattening handled in system)
-                        if let Some(sheet_data) = registry.get_sheet_mut(&cat, &sheet) {
-                            if let Some(meta) = &mut sheet_data.metadata {
-                                if col_index < meta.columns.len() {
-                                    meta.columns[col_index].validator = None;
-                                }
-                            }
-                        }
-                    }
+                    // Reverting away from structure is left entirely to the validator system:
+                    // it needs the old Structure validator to flatten/drop the child table.
 
                     if let (Some(vw), true) = (validator_writer.as_deref_mut(), true) {
                         // Capture original validator BEFORE conversion for self inclusion fix
@@ -85,7 +102,7 @@ pub fn show_validator_confirm_popup(
                             new_validator: if target_is_structure {
                                 Some(ColumnValidator::Structure)
                             } else {
-                                None
+                                removal_preview.as_ref().and_then(|p| p.target_validator.clone())
                             },
                             structure_source_columns: if target_is_structure
                                 && !structure_sources.is_empty()
@@ -100,6 +117,9 @@ pub fn show_validator_confirm_popup(
                                 None
                             },
                             original_self_validator,
+                            flatten_structure_children: removal_preview
+                                .as_ref()
+                                .map_or(false, |p| p.flatten_into_cell),
                         });
                     }
                     if let Some(fw) = feedback_writer.as_deref_mut() {
@@ -122,11 +142,13 @@ pub fn show_validator_confirm_popup(
                     state.pending_validator_change_requires_confirmation = false;
                     state.pending_validator_new_validator_summary = None;
                     state.pending_validator_target_is_structure = false;
+                    state.pending_structure_removal_preview = None;
                 }
             });
         });
     if !open {
         // user closed with X
         state.pending_validator_change_requires_confirmation = false;
+        state.pending_structure_removal_preview = None;
     }
 }
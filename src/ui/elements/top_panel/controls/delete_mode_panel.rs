@@ -2,13 +2,14 @@
 use bevy::prelude::*;
 use bevy_egui::egui;
 
-use crate::sheets::events::{RequestDeleteColumns, RequestDeleteRows};
-use crate::ui::elements::editor::state::EditorWindowState;
+use crate::sheets::events::RequestDeleteRows;
+use crate::ui::elements::editor::state::{
+    DeleteColumnsConfirmState, EditorWindowState, MergeRowsState,
+};
 
 // MODIFIED: Helper struct generic over borrow lifetime 'a, and EventWriter world lifetime 'w
 pub(crate) struct DeleteModeEventWriters<'a, 'w> {
     pub delete_rows_event_writer: &'a mut EventWriter<'w, RequestDeleteRows>,
-    pub delete_columns_event_writer: &'a mut EventWriter<'w, RequestDeleteColumns>,
 }
 
 // MODIFIED: Function generic over 'a and 'w. Make it `pub` to be callable from main_editor.
@@ -44,6 +45,14 @@ pub fn show_delete_mode_active_controls<'a, 'w>(
             button_text = format!("Delete {} Col(s)", cols_selected_count);
         }
 
+        if ui
+            .add_enabled(is_sheet_selected, egui::Button::new("☑ Select All Filtered"))
+            .on_hover_text("Select every row shown under the current column filters (Ctrl+A)")
+            .clicked()
+        {
+            state.pending_select_all_filtered = true;
+        }
+
         if ui
             .add_enabled(can_delete_anything, egui::Button::new(button_text))
             .clicked()
@@ -60,20 +69,49 @@ pub fn show_delete_mode_active_controls<'a, 'w>(
                             row_indices: state.ai_selected_rows.clone(),
                         });
                 }
-                if cols_selected_count > 0 {
-                    event_writers
-                        .delete_columns_event_writer
-                        .write(RequestDeleteColumns {
-                            category: state.selected_category.clone(),
-                            sheet_name: effective_sheet_name.clone(),
-                            column_indices: state.selected_columns_for_deletion.clone(),
-                        });
-                }
+                // Columns are deleted together after one confirmation
+                let confirm_columns = (cols_selected_count > 0).then(|| {
+                    let mut columns: Vec<usize> =
+                        state.selected_columns_for_deletion.iter().copied().collect();
+                    columns.sort_unstable();
+                    DeleteColumnsConfirmState {
+                        category: state.selected_category.clone(),
+                        sheet_name: effective_sheet_name.clone(),
+                        columns,
+                    }
+                });
 
                 // Always exit Delete Mode after a delete action is performed
                 state.reset_interaction_modes_and_selections();
                 state.show_edit_mode_panel = false;
                 state.force_filter_recalculation = true;
+                state.delete_columns_confirm = confirm_columns;
+            }
+        }
+
+        // Merge the selected rows into one (opens the conflict picker)
+        let merge_text = if rows_selected_count >= 2 {
+            format!("⇲ Merge {} Rows", rows_selected_count)
+        } else {
+            "⇲ Merge Rows".to_string()
+        };
+        if ui
+            .add_enabled(
+                is_sheet_selected && rows_selected_count >= 2,
+                egui::Button::new(merge_text),
+            )
+            .on_hover_text("Combine the selected rows into the topmost one; empty cells take the first non-empty value and conflicting values can be picked")
+            .clicked()
+        {
+            if let Some(sheet_name) = state.selected_sheet_name.clone() {
+                let mut rows: Vec<usize> = state.ai_selected_rows.iter().copied().collect();
+                rows.sort_unstable();
+                state.merge_rows = Some(MergeRowsState {
+                    category: state.selected_category.clone(),
+                    sheet_name,
+                    rows,
+                    choices: Default::default(),
+                });
             }
         }
     });
@@ -4,14 +4,19 @@ use bevy_egui::egui;
 use bevy_tokio_tasks::TokioTasksRuntime;
 
 use crate::sheets::resources::SheetRegistry;
+use crate::sheets::systems::logic::date_values::parse_date;
+use crate::sheets::systems::logic::summarizer;
 use crate::ui::elements::editor::state::{
-    AiModeState, EditorWindowState, SheetInteractionState, ToyboxMode,
+    AiModeState, EditorWindowState, SheetInteractionState, SummarizerStat, ToyboxMode,
 };
 // Import the SheetEventWriters SystemParam struct
 use crate::ui::elements::ai_review::ai_panel::draw_ai_panel;
 use crate::ui::elements::editor::main_editor::SheetEventWriters;
 use crate::visual_copier::events::RequestAppExit;
 
+/// Random Picker result shown once every candidate row has been drawn
+const ALL_PICKED_LABEL: &str = "<all picked>";
+
 // Declare sub-modules
 // sheet_management_bar moved to bottom_panel
 // quick_copy_bar removed: Quick Copy UI now lives inside Settings popup
@@ -138,52 +143,45 @@ mod orchestrator {
                                             let result_col = state.random_simple_result_col;
                                             // Collect per-row weight as f64 by summing numeric parses across configured weight columns
                                             let weight_cols: Vec<usize> = state.random_picker_weight_columns.iter().filter_map(|o| *o).collect();
-                                            let mut candidates: Vec<(usize, f64, &str)> = Vec::new();
-                                            for (r_idx, row) in sheet.grid.iter().enumerate() {
-                                                if let Some(val) = row.get(result_col) {
-                                                    if val.trim().is_empty() { continue; }
-                                                    // compute weight for this row
-                                                    let mut wsum = 0.0f64;
-                                                    for (wi, &wc) in weight_cols.iter().enumerate() {
-                                                        if let Some(cell) = row.get(wc) {
-                                                            if let Ok(n) = cell.trim().parse::<f64>() {
-                                                                // apply exponent if configured, default 1.0
-                                                                let exp = state.random_picker_weight_exponents.get(wi).cloned().unwrap_or(1.0);
-                                                                   // apply multiplier if configured, default 1.0
-                                                                   let mult = state.random_picker_weight_multipliers.get(wi).cloned().unwrap_or(1.0);
-                                                                // Interpret exponent semantics: stored value map directly to power to apply to abs(n).
-                                                                // For negative exponents we want sqrt-like behavior as described by the user: use 1/(n.powf(-exp))?
-                                                                // Following user's description: when exponent = -2 => x^-2 = x^(1/2). We'll map stored e so that -2 -> 0.5.
-                                                                // Use transform: applied_power = if exp < 0.0 { 1.0 / ( -exp ) } else { exp } ???
-                                                                // Simpler: follow user's specification literally: exponent value 'e' maps to applied_power = if e<0 { 1.0/(-e) } else { e }
-                                                                let applied_power = if exp < 0.0 { 1.0 / (-exp) } else { exp };
-                                                                    let mut v = n.abs() * mult;
-                                                                // guard for negative/zero
-                                                                if v == 0.0 { v = 0.0; }
-                                                                let weighted = v.powf(applied_power);
-                                                                wsum += weighted;
-                                                            }
-                                                        }
-                                                    }
-                                                    // if no weight columns configured or all zeros, fallback to uniform weight 1
-                                                    if weight_cols.is_empty() || wsum == 0.0 { wsum = 1.0; }
-                                                    candidates.push((r_idx, wsum, val.as_str()));
-                                                }
-                                            }
-                                            if candidates.is_empty() { state.random_picker_last_value.clear(); state.random_picker_copy_status.clear(); }
-                                            else {
-                                                // weighted random selection
-                                                let total: f64 = candidates.iter().map(|c| c.1).sum();
-                                                let mut target = rand::random::<f64>() * total;
-                                                let mut chosen = None;
-                                                for ( _r, w, v) in candidates.iter() {
-                                                    if target <= *w { chosen = Some(*v); break; }
-                                                    target -= *w;
+                                            let all_candidates = crate::sheets::systems::logic::random_picker::weighted_candidates(
+                                                &sheet.grid,
+                                                result_col,
+                                                &weight_cols,
+                                                &state.random_picker_weight_exponents,
+                                                &state.random_picker_weight_multipliers,
+                                            );
+                                            // Rows drawn earlier stay out until picks are reset
+                                            let sheet_key = (active_cat.clone(), sheet_name.clone());
+                                            let excluded = state.random_picker_excluded.entry(sheet_key).or_default();
+                                            let pick_key = |r_idx: usize| crate::sheets::systems::logic::random_picker::row_pick_key(&sheet.row_indices, r_idx);
+                                            let candidates: Vec<(usize, f64, String)> = all_candidates
+                                                .iter()
+                                                .filter(|(r_idx, _)| !excluded.contains(&pick_key(*r_idx)))
+                                                .map(|&(r_idx, w)| (r_idx, w, sheet.grid[r_idx][result_col].clone()))
+                                                .collect();
+                                            // weighted random selection; several draws never repeat a row.
+                                            // A seeded picker keeps advancing one RNG so the sequence replays.
+                                            let draw_count = state.random_picker_draw_count.max(1);
+                                            let drawn = match state.random_picker_seed {
+                                                Some(seed) => {
+                                                    let rng = state.random_picker_rng.get_or_insert_with(|| {
+                                                        <rand::rngs::StdRng as rand::SeedableRng>::seed_from_u64(seed)
+                                                    });
+                                                    crate::sheets::systems::logic::random_picker::draw_weighted_positions(
+                                                        &candidates, draw_count, false, rng,
+                                                    )
                                                 }
-                                                if chosen.is_none() { chosen = Some(candidates.last().unwrap().2); }
-                                                state.random_picker_last_value = chosen.unwrap().to_string();
-                                                state.random_picker_copy_status.clear();
-                                            }
+                                                None => crate::sheets::systems::logic::random_picker::draw_weighted_positions(
+                                                    &candidates, draw_count, false, &mut rand::rng(),
+                                                ),
+                                            };
+                                            excluded.extend(drawn.iter().map(|&pos| pick_key(candidates[pos].0)));
+                                            state.random_picker_last_value = if candidates.is_empty() && !all_candidates.is_empty() {
+                                                ALL_PICKED_LABEL.to_string()
+                                            } else {
+                                                drawn.iter().map(|&pos| candidates[pos].2.as_str()).collect::<Vec<_>>().join("\n")
+                                            };
+                                            state.random_picker_copy_status.clear();
                                             random_settings_changed = true;
                                         }
                                     }
@@ -191,11 +189,34 @@ mod orchestrator {
 
                                 // show value to the right of Refresh
                                 let rp_value = state.random_picker_last_value.clone();
-                                if !rp_value.is_empty() {
-                                    let rp_resp = ui_h.add(egui::SelectableLabel::new(false, rp_value.clone()));
+                                if rp_value == ALL_PICKED_LABEL {
+                                    ui_h.weak(ALL_PICKED_LABEL).on_hover_text("Every row has been picked; reset picks to draw again");
+                                } else if !rp_value.is_empty() {
+                                    // Several draws are listed compactly on one line; copying keeps one value per line
+                                    let rp_resp = if rp_value.contains('\n') {
+                                        let numbered: Vec<String> = rp_value.lines().enumerate().map(|(i, v)| format!("{}. {}", i + 1, v)).collect();
+                                        ui_h.add(egui::SelectableLabel::new(false, rp_value.lines().collect::<Vec<_>>().join(" · ")))
+                                            .on_hover_text(numbered.join("\n"))
+                                    } else {
+                                        ui_h.add(egui::SelectableLabel::new(false, rp_value.clone()))
+                                    };
                                     if rp_resp.clicked() { ui_h.ctx().copy_text(rp_value.clone()); state.random_picker_copy_status = "Copied".to_string(); }
                                     if !state.random_picker_copy_status.is_empty() { ui_h.label(format!(" ({})", state.random_picker_copy_status)); }
                                 } else { ui_h.label("<empty>"); }
+                                if let Some(sheet_name) = &active_sheet_opt {
+                                    let sheet_key = (active_cat.clone(), sheet_name.clone());
+                                    let picked = state.random_picker_excluded.get(&sheet_key).map_or(0, |set| set.len());
+                                    if picked > 0
+                                        && ui_h.small_button(format!("Reset picks ({})", picked))
+                                            .on_hover_text("Allow already picked rows to be drawn again")
+                                            .clicked()
+                                    {
+                                        state.random_picker_excluded.remove(&sheet_key);
+                                        state.random_picker_last_value.clear();
+                                        state.random_picker_copy_status.clear();
+                                        random_settings_changed = true;
+                                    }
+                                }
 
                             } else {
                                 // Summarizer controls inline on the same row (no extra label)
@@ -204,38 +225,61 @@ mod orchestrator {
                             if matches!(state.toybox_mode, ToyboxMode::Summarizer) {
                                 // Build header map excluding Structure columns with data types
                                 let mut header_map2: Vec<(usize, String, crate::sheets::definitions::ColumnDataType)> = Vec::new();
-                                if let Some(sheet_name) = &active_sheet_opt { if let Some(sheet) = registry.get_sheet(&active_cat, sheet_name) { if let Some(meta) = &sheet.metadata { for (i,c) in meta.columns.iter().enumerate() { if !c.deleted && !matches!(c.validator, Some(crate::sheets::definitions::ColumnValidator::Structure)) { header_map2.push((i, c.header.clone(), c.data_type)); } } } } }
+                                if let Some(sheet_name) = &active_sheet_opt { if let Some(sheet) = registry.get_sheet(&active_cat, sheet_name) { if let Some(meta) = &sheet.metadata { for (i,c) in meta.columns.iter().enumerate() { if !c.deleted && !matches!(c.validator, Some(crate::sheets::definitions::ColumnValidator::Structure)) { header_map2.push((i, c.display_name().to_string(), c.data_type)); } } } } }
                                 if header_map2.is_empty() { ui_h.label("<no columns>"); return; }
                                 let sel_idx = header_map2.iter().position(|(actual,_,_)| *actual == state.summarizer_selected_col).unwrap_or(0);
                                 // Show selected column as a label; editing moved to popup
                                 let selected_label = header_map2.get(sel_idx).map(|(_,h,_)| h.clone()).unwrap_or_else(|| "<no columns>".to_string());
                                 ui_h.label(selected_label);
                                 state.summarizer_selected_col = header_map2[sel_idx].0; // store actual index
+                                let mut selected_stat = state.summarizer_stat;
+                                egui::ComboBox::from_id_salt("summarizer_stat_picker")
+                                    .selected_text(selected_stat.label())
+                                    .show_ui(ui_h, |ui| {
+                                        for stat in SummarizerStat::ALL {
+                                            ui.selectable_value(&mut selected_stat, stat, stat.label());
+                                        }
+                                    });
+                                state.summarizer_stat = selected_stat;
                                 // Compute button placed before result per UX
                                 if ui_h.add_enabled(active_sheet_opt.is_some(), egui::Button::new("∑ Compute")).clicked() {
                                     state.summarizer_last_result.clear();
                                     if let Some(sheet_name) = &active_sheet_opt {
                                         if let Some(sheet) = registry.get_sheet(&active_cat, sheet_name) {
-                                            // Combined sum across all selected summarizer columns
+                                            // Combined statistic across all selected summarizer columns
                                             let sel_cols: Vec<usize> = state.summarizer_selected_columns.iter().filter_map(|o| *o).collect();
                                             if sel_cols.is_empty() {
                                                 state.summarizer_last_result = "<no columns>".to_string();
+                                            } else if sel_cols.iter().all(|&c| header_map2.iter().any(|(i, _, t)| *i == c && *t == crate::sheets::definitions::ColumnDataType::Date)) {
+                                                // Date columns: collect parsed dates; anything else counts as invalid
+                                                let mut dates = Vec::new();
+                                                let mut invalid = 0usize;
+                                                for row in &sheet.grid {
+                                                    for &col_index in sel_cols.iter() {
+                                                        let Some(val) = row.get(col_index) else { continue; };
+                                                        if val.trim().is_empty() { continue; }
+                                                        match parse_date(val) { Some(d) => dates.push(d), None => invalid += 1 }
+                                                    }
+                                                }
+                                                state.summarizer_last_result = summarizer::format_date_stat_result(state.summarizer_stat, &dates, invalid);
+                                                state.summarizer_copy_status.clear();
                                             } else {
-                                                // Sum numeric columns (I64 or F64) and count numeric values; non-numeric are skipped
-                                                let mut total_f64 = 0.0f64;
-                                                let mut total_count = 0usize;
+                                                // Collect numeric values (I64 or F64); non-numeric are skipped and counted as invalid
+                                                let mut values: Vec<f64> = Vec::new();
                                                 let mut invalid = 0usize;
                                                 for row in &sheet.grid {
                                                     for &col_index in sel_cols.iter() {
                                                         if let Some(val) = row.get(col_index) {
                                                             let s = val.trim(); if s.is_empty() { continue; }
                                                             // Try parse as f64 first
-                                                            if let Ok(vf) = s.parse::<f64>() { total_f64 += vf; total_count += 1; }
+                                                            if let Ok(vf) = s.parse::<f64>() { values.push(vf); }
                                                             else { invalid += 1; }
                                                         }
                                                     }
                                                 }
-                                                state.summarizer_last_result = format!("Sum: {:.4} (values: {}, invalid: {})", total_f64, total_count, invalid);
+                                                let stat = state.summarizer_stat;
+                                                let result = summarizer::compute_stat(&values, stat);
+                                                state.summarizer_last_result = summarizer::format_stat_result(stat, result, values.len(), invalid);
                                                 state.summarizer_copy_status.clear();
                                             }
                                         }
@@ -272,8 +316,11 @@ mod orchestrator {
                             &mut sheet_writers.toggle_ai_row_generation,
                             &mut sheet_writers.create_ai_schema_group,
                             &mut sheet_writers.rename_ai_schema_group,
+                            &mut sheet_writers.reorder_ai_schema_group,
                             &mut sheet_writers.select_ai_schema_group,
                             &mut sheet_writers.delete_ai_schema_group,
+                            &mut sheet_writers.set_ai_schema_group_allow_rows,
+                            &mut sheet_writers.set_ai_structure_skip,
                             director_session,
                         );
                     }
@@ -283,7 +330,6 @@ mod orchestrator {
                             state,
                             controls::delete_mode_panel::DeleteModeEventWriters {
                                 delete_rows_event_writer: &mut sheet_writers.delete_rows,
-                                delete_columns_event_writer: &mut sheet_writers.delete_columns,
                             }
                         );
                     }
@@ -319,6 +365,9 @@ mod orchestrator {
                                             weight_exponents: weight_exps.clone(),
                                             weight_multipliers: state.random_picker_weight_multipliers.iter().cloned().take(weight_cols.len()).collect(),
                                             summarizer_columns: summarizer_cols.clone(),
+                                            draw_count: state.random_picker_draw_count,
+                                            excluded_row_indices: state.random_picker_excluded_rows(&active_cat, sel),
+                                            seed: state.random_picker_seed,
                                         }
                                     } else {
                                         RandomPickerSettings {
@@ -331,6 +380,9 @@ mod orchestrator {
                                             weight_exponents: weight_exps.clone(),
                                             weight_multipliers: state.random_picker_weight_multipliers.iter().cloned().take(weight_cols.len()).collect(),
                                             summarizer_columns: summarizer_cols.clone(),
+                                            draw_count: state.random_picker_draw_count,
+                                            excluded_row_indices: state.random_picker_excluded_rows(&active_cat, sel),
+                                            seed: state.random_picker_seed,
                                         }
                                     };
                                     meta.random_picker = Some(settings.clone());
@@ -360,6 +412,112 @@ mod orchestrator {
                             {
                                 state.show_settings_popup = true;
                             }
+                            r.add_space(6.0);
+                            if r
+                                .button("⇄ Compare")
+                                .on_hover_text("Compare two sheets row by row using a shared key column")
+                                .clicked()
+                            {
+                                state.show_compare_sheets_popup = true;
+                                if state.sheet_compare.left.is_none() {
+                                    if let Some(s) = &active_sheet_opt {
+                                        state.sheet_compare.left = Some((active_cat.clone(), s.clone()));
+                                        state.sheet_compare.left_data = None;
+                                    }
+                                }
+                            }
+                            // Collapse / expand all structure columns of the current sheet (view only)
+                            let structure_col_count = active_sheet_opt
+                                .as_ref()
+                                .and_then(|s| registry.get_sheet(&active_cat, s))
+                                .and_then(|sheet| sheet.metadata.as_ref())
+                                .map_or(0, |m| {
+                                    m.columns
+                                        .iter()
+                                        .filter(|c| {
+                                            !c.deleted
+                                                && matches!(
+                                                    c.validator,
+                                                    Some(crate::sheets::definitions::ColumnValidator::Structure)
+                                                )
+                                        })
+                                        .count()
+                                });
+                            if structure_col_count > 0 {
+                                r.add_space(6.0);
+                                let (label, hover) = if state.collapse_structure_columns {
+                                    (
+                                        format!("⊞ Structures ({})", structure_col_count),
+                                        format!("{} structure column(s) collapsed. Click to expand all.", structure_col_count),
+                                    )
+                                } else {
+                                    (
+                                        "⊟ Structures".to_string(),
+                                        "Collapse all structure columns to focus on regular data columns".to_string(),
+                                    )
+                                };
+                                if r
+                                    .selectable_label(state.collapse_structure_columns, label)
+                                    .on_hover_text(hover)
+                                    .clicked()
+                                {
+                                    state.collapse_structure_columns = !state.collapse_structure_columns;
+                                }
+                            }
+                            // Unhide every hidden data column; technical columns stay hidden
+                            let hidden_cols: Vec<usize> = active_sheet_opt
+                                .as_ref()
+                                .and_then(|s| registry.get_sheet(&active_cat, s))
+                                .and_then(|sheet| sheet.metadata.as_ref())
+                                .map_or_else(Vec::new, |m| {
+                                    m.columns
+                                        .iter()
+                                        .enumerate()
+                                        .filter(|(_, c)| {
+                                            c.hidden
+                                                && !c.deleted
+                                                && !crate::sheets::definitions::SheetMetadata::is_technical_column_header(&c.header)
+                                        })
+                                        .map(|(idx, _)| idx)
+                                        .collect()
+                                });
+                            if !hidden_cols.is_empty() {
+                                r.add_space(6.0);
+                                if r
+                                    .button(format!("👁 Show hidden ({})", hidden_cols.len()))
+                                    .on_hover_text("Show all hidden columns of this sheet")
+                                    .clicked()
+                                {
+                                    if let Some(sheet_name) = active_sheet_opt.as_ref() {
+                                        for col_index in hidden_cols {
+                                            sheet_writers.toggle_column_hidden.write(
+                                                crate::sheets::events::ToggleColumnHiddenEvent {
+                                                    category: active_cat.clone(),
+                                                    sheet_name: sheet_name.clone(),
+                                                    col_index,
+                                                },
+                                            );
+                                        }
+                                    }
+                                }
+                            }
+                            // Sheets showing ancestor key columns are drawn without a frozen region
+                            let can_freeze = active_sheet_opt.as_ref().is_some_and(|s| {
+                                crate::sheets::systems::ui_handlers::build_ancestor_key_columns(
+                                    state, &*registry, s,
+                                )
+                                .is_empty()
+                            });
+                            if can_freeze {
+                                r.add_space(6.0);
+                                r.add(
+                                    egui::DragValue::new(&mut state.frozen_column_count)
+                                        .range(0..=20)
+                                        .speed(0.1),
+                                )
+                                .on_hover_text("Keep this many leading columns pinned while scrolling horizontally (0 = none). Also available from a column header's right-click menu.");
+                                r.label("📌 Frozen:");
+                            }
                         },
                     );
                 });
@@ -21,8 +21,8 @@ pub(super) fn show_sheet_interaction_mode_buttons<'a, 'w>(
         if ui.button("❌ Exit AI").clicked() {
             state.reset_interaction_modes_and_selections();
         }
-    } else {
-        // Always show 'AI Mode' button when not in AI mode
+    } else if state.ai_available() {
+        // Show 'AI Mode' button when not in AI mode (unless AI is disabled in Settings)
         let ai_btn = ui
             .add_enabled(is_sheet_selected, egui::Button::new("✨ AI Mode"))
             .on_hover_text("Enable row selection and AI controls");
@@ -87,4 +87,12 @@ pub(super) fn show_sheet_interaction_mode_buttons<'a, 'w>(
         }
         // Sub-panels render on the same line as their UI below
     }
+
+    // Structure tree side panel (independent of the modes above)
+    ui.add_enabled_ui(is_sheet_selected, |ui| {
+        ui.toggle_value(&mut state.structure_tree_expanded, "🌳 Tree")
+            .on_hover_text("Show the structure hierarchy of this sheet");
+        ui.toggle_value(&mut state.show_structure_search_panel, "🔎 Search")
+            .on_hover_text("Search this sheet and all of its structure child tables (Ctrl+Shift+F)");
+    });
 }
@@ -21,6 +21,7 @@ use systems::clear_ui_feedback_on_sheet_change;
 use systems::handle_ui_feedback;
 // Import child table loader system
 use crate::sheets::systems::ai_review::child_table_loader::load_structure_child_tables_system;
+use crate::sheets::resources::SheetRegistry;
 
 #[derive(Resource, Default, Debug, Clone)]
 pub struct UiFeedbackState {
@@ -39,13 +40,15 @@ impl Plugin for EditorUiPlugin {
             .init_resource::<elements::popups::MigrationPopupState>()
             // Load UI prefs on startup
             .add_systems(Startup, load_ui_prefs_startup)
+            .add_systems(Startup, load_ai_usage_startup)
             // --- END MODIFIED ---
             // Load structure child tables once when AI Review starts (before UI)
             .add_systems(Update, load_structure_child_tables_system)
             // Ensure we clear transient feedback on sheet changes before processing new feedback events
             .add_systems(Update, clear_ui_feedback_on_sheet_change)
             // Persist UI prefs when toggled
-            .add_systems(Update, persist_ui_prefs_if_changed)
+            .add_systems(Update, track_recent_sheets)
+            .add_systems(Update, persist_ui_prefs_if_changed.after(track_recent_sheets))
             .add_systems(Update, handle_ui_feedback)
             .add_systems(EguiContextPass, generic_sheet_editor_ui);
 
@@ -58,6 +61,47 @@ fn load_ui_prefs_startup(mut state: ResMut<EditorWindowState>) {
     state.category_picker_expanded = prefs.category_picker_expanded;
     state.sheet_picker_expanded = prefs.sheet_picker_expanded;
     state.ai_groups_expanded = prefs.ai_groups_expanded;
+    state.structure_tree_expanded = prefs.structure_tree_expanded;
+    state.pinned_sheets = prefs.pinned_sheets;
+    state.recent_sheets = prefs.recent_sheets;
+}
+
+fn load_ai_usage_startup(mut state: ResMut<EditorWindowState>) {
+    state.ai_usage = crate::sheets::systems::ai::usage_ledger::load_usage_ledger();
+}
+
+/// Records sheet selections in the recently opened list and drops entries whose sheet is
+/// gone. Database categories load their tables lazily, so an entry is only dropped once its
+/// category is missing or has its tables loaded without that sheet.
+fn track_recent_sheets(
+    mut state: ResMut<EditorWindowState>,
+    registry: Res<SheetRegistry>,
+    mut last_selected: Local<Option<(Option<String>, String)>>,
+) {
+    let selected = state
+        .selected_sheet_name
+        .clone()
+        .map(|name| (state.selected_category.clone(), name));
+    if selected != *last_selected {
+        if let Some((category, name)) = &selected {
+            if registry.get_sheet(category, name).is_some() {
+                state.note_recent_sheet(category, name);
+            }
+        }
+        *last_selected = selected;
+    }
+
+    let categories = registry.get_categories();
+    let is_stale = |(category, name): &(Option<String>, String)| {
+        if !categories.contains(category) {
+            return true;
+        }
+        let sheets = registry.get_sheet_names_in_category(category);
+        !sheets.is_empty() && !sheets.contains(name)
+    };
+    if state.recent_sheets.iter().any(is_stale) {
+        state.recent_sheets.retain(|entry| !is_stale(entry));
+    }
 }
 
 fn persist_ui_prefs_if_changed(state: Res<EditorWindowState>, mut last: Local<Option<UiPrefs>>) {
@@ -67,6 +111,9 @@ fn persist_ui_prefs_if_changed(state: Res<EditorWindowState>, mut last: Local<Op
             category_picker_expanded: state.category_picker_expanded,
             sheet_picker_expanded: state.sheet_picker_expanded,
             ai_groups_expanded: state.ai_groups_expanded,
+            structure_tree_expanded: state.structure_tree_expanded,
+            pinned_sheets: state.pinned_sheets.clone(),
+            recent_sheets: state.recent_sheets.clone(),
         });
         return;
     }
@@ -74,6 +121,9 @@ fn persist_ui_prefs_if_changed(state: Res<EditorWindowState>, mut last: Local<Op
         category_picker_expanded: state.category_picker_expanded,
         sheet_picker_expanded: state.sheet_picker_expanded,
         ai_groups_expanded: state.ai_groups_expanded,
+        structure_tree_expanded: state.structure_tree_expanded,
+        pinned_sheets: state.pinned_sheets.clone(),
+        recent_sheets: state.recent_sheets.clone(),
     };
     if last.as_ref().map(|p| p != &cur).unwrap_or(true) {
         save_prefs(&cur);
@@ -6,6 +6,8 @@ use std::sync::Arc;
 use unicode_normalization::UnicodeNormalization;
 
 use crate::sheets::{definitions::ColumnDataType, resources::SheetRegistry};
+use crate::sheets::systems::logic::date_values::ISO_DATE_FORMAT;
+use crate::sheets::systems::logic::enum_validator::canonical_enum_value;
 // IMPORTANT: EditorWindowState is needed here ONLY for the linked cache access
 // If we refactor cache access later, this dependency might be removed from validation itself.
 use crate::sheets::systems::ai::cache::linked_column_cache::{self, CacheResult};
@@ -103,6 +105,12 @@ pub(crate) fn validate_basic_cell(
                 parse_error = true;
             }
         }
+        ColumnDataType::Date => {
+            // Stored values are always ISO; anything else predates the column type change
+            if chrono::NaiveDate::parse_from_str(current_cell_string, ISO_DATE_FORMAT).is_err() {
+                parse_error = true;
+            }
+        }
     }
 
     let state = if parse_error {
@@ -113,6 +121,18 @@ pub(crate) fn validate_basic_cell(
     (state, parse_error)
 }
 
+/// Validates a cell value against the allowed values of an enum column.
+/// Empty cells are allowed; other values must match an allowed value (ignoring case).
+pub(crate) fn validate_enum_cell(current_cell_string: &str, allowed: &[String]) -> ValidationState {
+    if current_cell_string.trim().is_empty() {
+        ValidationState::Empty
+    } else if canonical_enum_value(current_cell_string, allowed).is_some() {
+        ValidationState::Valid
+    } else {
+        ValidationState::Invalid
+    }
+}
+
 /// Validates a cell value based on a linked column validator.
 /// Returns the validation state and optionally a reference to the allowed values from the cache.
 pub(crate) fn validate_linked_cell(
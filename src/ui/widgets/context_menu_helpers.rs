@@ -7,7 +7,16 @@ use crate::sheets::{
     resources::ClipboardBuffer,
 };
 
-/// Adds a standard cell context menu with Copy, Paste, and Clear operations.
+/// Range copy step chosen from the cell context menu
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CellRangeAction {
+    /// Remember this cell as one corner of the range
+    MarkStart,
+    /// Copy the block between the marked cell and this cell
+    CopyToHere,
+}
+
+/// Adds a standard cell context menu with Copy, Paste, Clear and "Send this row to AI" operations.
 ///
 /// This helper provides consistent context menu behavior across all cell types.
 /// Returns the original response to maintain the call chain.
@@ -22,6 +31,15 @@ use crate::sheets::{
 /// * `paste_events` - Event writer for paste operations
 /// * `clipboard_buffer` - Clipboard buffer resource to check if paste is available
 /// * `temp_new_value` - Mutable reference to set new value on clear
+/// * `send_row_to_ai` - Set to true when the user asks to send this cell's row to AI
+/// * `ai_available` - Whether AI features are enabled; hides the AI entry otherwise
+/// * `has_range_anchor` - Whether a range start is marked on this sheet
+/// * `range_action` - Set when the user marks a range start or copies a range
+/// * `edit_comment` - Set to true when the user wants to edit this cell's comment
+/// * `row_locked` - Whether this cell's row is currently locked
+/// * `toggle_row_lock` - Set to true when the user locks or unlocks this cell's row
+/// * `duplicate_row` - Set to true when the user clones this cell's row
+#[allow(clippy::too_many_arguments)]
 pub fn add_cell_context_menu(
     response: egui::Response,
     category: &Option<String>,
@@ -32,6 +50,14 @@ pub fn add_cell_context_menu(
     paste_events: &mut EventWriter<RequestPasteCell>,
     clipboard_buffer: &ClipboardBuffer,
     temp_new_value: &mut Option<String>,
+    send_row_to_ai: &mut bool,
+    ai_available: bool,
+    has_range_anchor: bool,
+    range_action: &mut Option<CellRangeAction>,
+    edit_comment: &mut bool,
+    row_locked: bool,
+    toggle_row_lock: &mut bool,
+    duplicate_row: &mut bool,
 ) -> egui::Response {
     let _ = response.context_menu(|menu_ui| {
         if menu_ui.button("📋 Copy").clicked() {
@@ -60,6 +86,61 @@ pub fn add_cell_context_menu(
             *temp_new_value = Some(String::new());
             menu_ui.close_menu();
         }
+        menu_ui.separator();
+        if menu_ui
+            .button("⬚ Mark range start")
+            .on_hover_text("Use this cell as one corner of a range to copy")
+            .clicked()
+        {
+            *range_action = Some(CellRangeAction::MarkStart);
+            menu_ui.close_menu();
+        }
+        if menu_ui
+            .add_enabled(has_range_anchor, egui::Button::new("📋 Copy range to here"))
+            .on_hover_text("Copy the visible cells between the marked cell and this one")
+            .clicked()
+        {
+            *range_action = Some(CellRangeAction::CopyToHere);
+            menu_ui.close_menu();
+        }
+        if menu_ui
+            .add_enabled(category.is_some(), egui::Button::new("💬 Comment…"))
+            .on_hover_text("Attach a note to this cell")
+            .on_disabled_hover_text("Comments are only available for database sheets")
+            .clicked()
+        {
+            *edit_comment = true;
+            menu_ui.close_menu();
+        }
+        let lock_label = if row_locked { "🔓 Unlock row" } else { "🔒 Lock row" };
+        if menu_ui
+            .add_enabled(category.is_some(), egui::Button::new(lock_label))
+            .on_hover_text("Locked rows reject edits, including AI suggestions")
+            .on_disabled_hover_text("Row locks are only available for database sheets")
+            .clicked()
+        {
+            *toggle_row_lock = true;
+            menu_ui.close_menu();
+        }
+        if menu_ui
+            .button("⧉ Clone row")
+            .on_hover_text("Insert a copy of this row right above it, including its structure rows")
+            .clicked()
+        {
+            *duplicate_row = true;
+            menu_ui.close_menu();
+        }
+        if ai_available {
+            menu_ui.separator();
+            if menu_ui
+                .button("🤖 Send this row to AI")
+                .on_hover_text("Start an AI session for this row only (uses the active schema group)")
+                .clicked()
+            {
+                *send_row_to_ai = true;
+                menu_ui.close_menu();
+            }
+        }
     });
     response
 }
@@ -0,0 +1,126 @@
+// src/ui/widgets/date_widget.rs
+use bevy_egui::egui;
+use chrono::{Datelike, Months, NaiveDate};
+
+use crate::sheets::systems::logic::date_values::{parse_date, ISO_DATE_FORMAT};
+
+const WEEKDAY_LABELS: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+
+/// Date cell: a text field committed on Enter/focus loss (the update handler normalizes
+/// or rejects the input) plus a calendar popup. Returns the text field's response and the
+/// value to write, if any.
+pub(crate) fn add_date_cell(
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    current: &str,
+) -> (egui::Response, Option<String>) {
+    let mut new_value = None;
+    let buffer_id = id.with("date_buffer");
+    let popup_id = id.with("date_calendar");
+
+    let resp = ui
+        .horizontal(|ui_h| {
+            let button_width = ui_h.style().spacing.interact_size.y + 4.0;
+            // Partial input lives in temp memory so half-typed dates are not sent per keystroke
+            let mut buffer = ui_h
+                .data_mut(|d| d.get_temp::<String>(buffer_id))
+                .unwrap_or_else(|| current.to_string());
+            let text_resp = ui_h.add_sized(
+                egui::vec2(
+                    (ui_h.available_width() - button_width).max(0.0),
+                    ui_h.style().spacing.interact_size.y,
+                ),
+                egui::TextEdit::singleline(&mut buffer)
+                    .frame(false)
+                    .hint_text("YYYY-MM-DD"),
+            );
+            if text_resp.has_focus() {
+                ui_h.data_mut(|d| d.insert_temp(buffer_id, buffer));
+            } else {
+                if text_resp.lost_focus() && buffer.trim() != current {
+                    new_value = Some(buffer.trim().to_string());
+                }
+                ui_h.data_mut(|d| d.remove::<String>(buffer_id));
+            }
+
+            let button = ui_h.small_button("📅").on_hover_text("Pick a date");
+            if button.clicked() {
+                ui_h.memory_mut(|mem| mem.toggle_popup(popup_id));
+            }
+            egui::containers::popup::popup_below_widget(
+                ui_h,
+                popup_id,
+                &button,
+                egui::containers::popup::PopupCloseBehavior::CloseOnClickOutside,
+                |popup_ui| {
+                    if let Some(picked) = show_calendar(popup_ui, id, parse_date(current)) {
+                        new_value = Some(picked.format(ISO_DATE_FORMAT).to_string());
+                        popup_ui.memory_mut(|mem| mem.close_popup());
+                    }
+                },
+            );
+            text_resp
+        })
+        .inner;
+    (resp, new_value)
+}
+
+/// Month grid starting on Monday. The shown month is kept in temp memory so the
+/// arrows work across frames; it opens on the cell's date (or today).
+fn show_calendar(ui: &mut egui::Ui, id: egui::Id, selected: Option<NaiveDate>) -> Option<NaiveDate> {
+    let month_id = id.with("date_calendar_month");
+    let today = chrono::Local::now().date_naive();
+    let mut month_start = ui
+        .data_mut(|d| d.get_temp::<NaiveDate>(month_id))
+        .unwrap_or_else(|| {
+            let base = selected.unwrap_or(today);
+            base.with_day(1).unwrap_or(base)
+        });
+    let mut picked = None;
+
+    ui.set_min_width(200.0);
+    ui.horizontal(|ui| {
+        if ui.small_button("◀").clicked() {
+            month_start = month_start.checked_sub_months(Months::new(1)).unwrap_or(month_start);
+        }
+        ui.label(month_start.format("%B %Y").to_string());
+        if ui.small_button("▶").clicked() {
+            month_start = month_start.checked_add_months(Months::new(1)).unwrap_or(month_start);
+        }
+        if ui.small_button("Today").clicked() {
+            picked = Some(today);
+        }
+    });
+    ui.data_mut(|d| d.insert_temp(month_id, month_start));
+
+    let leading_blanks = month_start.weekday().num_days_from_monday() as usize;
+    let days_in_month = month_start
+        .checked_add_months(Months::new(1))
+        .and_then(|next| next.pred_opt())
+        .map_or(28, |last| last.day());
+    egui::Grid::new(id.with("date_calendar_grid"))
+        .spacing([2.0, 2.0])
+        .show(ui, |grid| {
+            for label in WEEKDAY_LABELS {
+                grid.label(egui::RichText::new(label).weak());
+            }
+            grid.end_row();
+            for cell in 0..leading_blanks + days_in_month as usize {
+                if cell < leading_blanks {
+                    grid.label("");
+                } else {
+                    let day = (cell - leading_blanks + 1) as u32;
+                    let date = month_start.with_day(day).unwrap_or(month_start);
+                    let text = egui::RichText::new(format!("{:>2}", day));
+                    let text = if date == today { text.underline() } else { text };
+                    if grid.selectable_label(Some(date) == selected, text).clicked() {
+                        picked = Some(date);
+                    }
+                }
+                if cell % 7 == 6 {
+                    grid.end_row();
+                }
+            }
+        });
+    picked
+}
@@ -0,0 +1,36 @@
+// src/ui/widgets/enum_widget.rs
+use bevy_egui::egui;
+
+/// Grid cell of an Enum column: a dropdown of the allowed values plus an empty entry that
+/// clears the cell. A stored value outside the list is still shown (the cell is flagged
+/// invalid). Returns the dropdown's response and the value to write, if any.
+pub(crate) fn add_enum_cell(
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    current: &str,
+    allowed: &[String],
+    placeholder: &str,
+) -> (egui::Response, Option<String>) {
+    let mut new_value = None;
+    let selected_text = if current.is_empty() {
+        egui::RichText::new(placeholder).weak()
+    } else {
+        egui::RichText::new(current)
+    };
+    let inner = egui::ComboBox::from_id_salt(id.with("enum_cell"))
+        .selected_text(selected_text)
+        .width(ui.available_width())
+        .show_ui(ui, |ui_c| {
+            if ui_c.selectable_label(current.is_empty(), "—").on_hover_text("Clear").clicked()
+                && !current.is_empty()
+            {
+                new_value = Some(String::new());
+            }
+            for value in allowed {
+                if ui_c.selectable_label(value == current, value).clicked() && value != current {
+                    new_value = Some(value.clone());
+                }
+            }
+        });
+    (inner.response, new_value)
+}
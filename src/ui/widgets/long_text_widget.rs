@@ -0,0 +1,48 @@
+// src/ui/widgets/long_text_widget.rs
+use bevy_egui::egui;
+
+/// First line of `text`, with an ellipsis when more lines follow
+fn first_line_preview(text: &str) -> String {
+    let mut lines = text.lines();
+    let first = lines.next().unwrap_or_default();
+    if lines.next().is_some() {
+        format!("{} …", first)
+    } else {
+        first.to_string()
+    }
+}
+
+/// Grid cell of a "long text" column: a one-line preview (full text on hover) and a
+/// "⤢" button. Returns the preview's response and whether the editor should open
+/// (button clicked or preview double-clicked).
+pub(crate) fn add_long_text_cell(
+    ui: &mut egui::Ui,
+    current: &str,
+    placeholder: &str,
+) -> (egui::Response, bool) {
+    ui.horizontal(|ui_h| {
+        let button_width = ui_h.style().spacing.interact_size.y + 4.0;
+        let preview = if current.is_empty() {
+            egui::RichText::new(placeholder).weak()
+        } else {
+            egui::RichText::new(first_line_preview(current))
+        };
+        let mut preview_resp = ui_h.add_sized(
+            egui::vec2(
+                (ui_h.available_width() - button_width).max(0.0),
+                ui_h.style().spacing.interact_size.y,
+            ),
+            egui::Label::new(preview).truncate().sense(egui::Sense::click()),
+        );
+        if current.contains('\n') {
+            preview_resp = preview_resp.on_hover_text(current);
+        }
+        let open_clicked = ui_h
+            .small_button("⤢")
+            .on_hover_text("Edit in a multi-line editor")
+            .clicked();
+        let open = open_clicked || preview_resp.double_clicked();
+        (preview_resp, open)
+    })
+    .inner
+}
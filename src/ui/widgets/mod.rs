@@ -6,6 +6,9 @@ pub(crate) mod linked_column_handler;
 pub(crate) mod linked_column_visualization;
 pub(crate) mod option_widgets;
 pub(crate) mod context_menu_helpers;
+pub(crate) mod date_widget;
+pub(crate) mod enum_widget;
+pub(crate) mod long_text_widget;
 pub(crate) mod technical_column_widget;
 pub(crate) mod structure_column_widget;
 
@@ -13,10 +16,21 @@ pub(crate) mod structure_column_widget;
 pub(crate) use linked_column_handler::handle_linked_column_edit;
 
 // Re-export context menu helper
-pub(crate) use context_menu_helpers::add_cell_context_menu;
+pub(crate) use context_menu_helpers::{add_cell_context_menu, CellRangeAction};
 
 // Re-export option widget helpers
-pub(crate) use option_widgets::{add_centered_checkbox, add_numeric_drag_value};
+pub(crate) use option_widgets::{
+    add_centered_checkbox, add_formatted_f64_drag_value, add_numeric_drag_value,
+};
+
+// Re-export date cell widget
+pub(crate) use date_widget::add_date_cell;
+
+// Re-export enum dropdown cell widget
+pub(crate) use enum_widget::add_enum_cell;
+
+// Re-export long text cell widget
+pub(crate) use long_text_widget::add_long_text_cell;
 
 // Re-export technical column widget helpers
 pub(crate) use technical_column_widget::render_technical_column;
@@ -21,6 +21,36 @@ pub(crate) fn add_numeric_drag_value<T>(
 where
     T: egui::emath::Numeric,
 {
+    add_styled_drag_value(ui, egui::DragValue::new(value).speed(speed))
+}
+
+/// Adds an `f64` DragValue whose idle text comes from `display_text` instead of
+/// egui's rounded formatting (used for display options such as trimmed zeros).
+///
+/// The stored value is untouched; only the rendered text differs.
+pub(crate) fn add_formatted_f64_drag_value(
+    ui: &mut egui::Ui,
+    value: &mut f64,
+    speed: f64,
+    display_text: String,
+) -> egui::Response {
+    let original = *value;
+    add_styled_drag_value(
+        ui,
+        egui::DragValue::new(value)
+            .speed(speed)
+            .custom_formatter(move |v, _| {
+                // Once the user drags the value, fall back to plain formatting
+                if v == original {
+                    display_text.clone()
+                } else {
+                    v.to_string()
+                }
+            }),
+    )
+}
+
+fn add_styled_drag_value(ui: &mut egui::Ui, drag_value: egui::DragValue<'_>) -> egui::Response {
     let size = egui::vec2(ui.available_width(), ui.style().spacing.interact_size.y);
     ui.scope(|ui_num| {
         let dark = egui::Color32::from_rgb(45, 45, 45);
@@ -31,7 +61,7 @@ where
         visuals.widgets.hovered.bg_fill = dark;
         visuals.widgets.active.weak_bg_fill = dark;
         visuals.widgets.active.bg_fill = dark;
-        ui_num.add_sized(size, egui::DragValue::new(value).speed(speed))
+        ui_num.add_sized(size, drag_value)
     })
     .inner
 }
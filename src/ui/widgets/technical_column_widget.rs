@@ -8,8 +8,17 @@ use crate::sheets::{
 };
 use crate::ui::elements::editor::state::EditorWindowState;
 
+/// Headers of the technical columns the grid draws as green read-only labels
+pub(crate) fn is_read_only_technical_header(header: &str) -> bool {
+    header.eq_ignore_ascii_case("row_index")
+        || header.eq_ignore_ascii_case("parent_key")
+        || header.eq_ignore_ascii_case("temp_new_row_index")
+        || header.eq_ignore_ascii_case("_obsolete_temp_new_row_index")
+}
+
 /// Checks if a column is a technical column (row_index, parent_key, temp_new_row_index, etc.)
-/// that should be displayed as green read-only when structure technical columns are hidden.
+/// that should be displayed as green read-only when structure technical columns are hidden
+/// or the "Show technical columns" developer setting is on.
 ///
 /// # Arguments
 /// * `registry` - The sheet registry
@@ -27,7 +36,9 @@ pub fn is_technical_column(
     col_index: usize,
     state: &EditorWindowState,
 ) -> bool {
-    if !state.should_hide_structure_technical_columns(category, sheet_name) {
+    if !state.show_technical_columns
+        && !state.should_hide_structure_technical_columns(category, sheet_name)
+    {
         return false;
     }
 
@@ -35,12 +46,7 @@ pub fn is_technical_column(
         .get_sheet(category, sheet_name)
         .and_then(|sd| sd.metadata.as_ref())
         .and_then(|meta| meta.columns.get(col_index))
-        .map(|col_def| {
-            col_def.header.eq_ignore_ascii_case("row_index")
-                || col_def.header.eq_ignore_ascii_case("parent_key")
-                || col_def.header.eq_ignore_ascii_case("temp_new_row_index")
-                || col_def.header.eq_ignore_ascii_case("_obsolete_temp_new_row_index")
-        })
+        .map(|col_def| is_read_only_technical_header(&col_def.header))
         .unwrap_or(false)
 }
 
@@ -76,6 +76,14 @@ pub struct CopyOperationResultEvent {
     pub result: Result<String, CopyError>,
 }
 
+/// Event to export the copy task list to a JSON file chosen by the user.
+#[derive(Event, Debug)]
+pub struct ExportCopyTasksRequest;
+
+/// Event to import copy tasks from a JSON file chosen by the user.
+#[derive(Event, Debug)]
+pub struct ImportCopyTasksRequest;
+
 /// Event sent when the VisualCopierManager state (paths, copy_on_exit flag) has changed and should be persisted.
 #[derive(Event, Debug, Clone)]
 pub struct VisualCopierStateChanged;
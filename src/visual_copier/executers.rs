@@ -3,15 +3,22 @@
 use bevy::prelude::*;
 use chrono::Local;
 use fs_extra;
-use std::path::PathBuf; // Ensure fs_extra is a dependency in Cargo.toml
+use std::collections::HashSet;
+use std::path::{Path, PathBuf}; // Ensure fs_extra is a dependency in Cargo.toml
 
-use super::resources::CopyError;
+use super::resources::{ConflictPolicy, CopyError, SyncDirection};
+use crate::sheets::database::migration::io_helpers::IoHelpers;
+use crate::sheets::definitions::SheetMetadata;
+
+const META_SUFFIX: &str = ".meta.json";
 
 /// Helper function to execute a single copy operation (blocking).
+/// With a non-empty `column_whitelist`, JSON sheets in the destination keep only those columns.
 pub(crate) fn execute_single_copy_operation(
     from_path: &PathBuf,
     to_path: &PathBuf,
     operation_label: &str,
+    column_whitelist: Option<&[String]>,
 ) -> Result<String, CopyError> {
     debug!(
         "Executing copy: {} -> {} for {}",
@@ -42,11 +49,253 @@ pub(crate) fn execute_single_copy_operation(
     // fs_extra::dir::copy returns a Result, map its error to your CopyError type.
     fs_extra::dir::copy(from_path, to_path, &options).map_err(CopyError::from)?;
 
-    let success_msg = format!(
+    let mut skipped_columns = 0;
+    if let Some(whitelist) = column_whitelist.filter(|w| !w.is_empty()) {
+        skipped_columns = apply_column_whitelist(from_path, to_path, whitelist)?;
+    }
+
+    let mut success_msg = format!(
         "{} copied successfully at {}",
         operation_label,
         Local::now().format("%H:%M:%S")
     );
+    if skipped_columns > 0 {
+        success_msg.push_str(&format!(
+            " ({} whitelisted column(s) missing from source, see log)",
+            skipped_columns
+        ));
+    }
     info!("VisualCopier: {}", success_msg);
     Ok(success_msg)
 }
+
+/// Runs a copy task in its configured direction (blocking).
+pub(crate) fn execute_copy_task(
+    start_folder: &PathBuf,
+    end_folder: &PathBuf,
+    operation_label: &str,
+    column_whitelist: Option<&[String]>,
+    direction: SyncDirection,
+    conflict_policy: ConflictPolicy,
+) -> Result<String, CopyError> {
+    match direction {
+        SyncDirection::SourceToTarget => execute_single_copy_operation(
+            start_folder,
+            end_folder,
+            operation_label,
+            column_whitelist,
+        ),
+        SyncDirection::TargetToSource => execute_single_copy_operation(
+            end_folder,
+            start_folder,
+            operation_label,
+            column_whitelist,
+        ),
+        SyncDirection::Bidirectional => {
+            if column_whitelist.is_some_and(|w| !w.is_empty()) {
+                warn!(
+                    "VisualCopier: {} ignores its column whitelist in bidirectional mode",
+                    operation_label
+                );
+            }
+            let base_dir = super::io::sync_base_dir(start_folder, end_folder)?;
+            let report =
+                super::sync::sync_folders(start_folder, end_folder, &base_dir, conflict_policy)?;
+            let mut msg = format!(
+                "{} synced at {}: {} added, {} updated, {} deleted",
+                operation_label,
+                Local::now().format("%H:%M:%S"),
+                report.added,
+                report.updated,
+                report.deleted
+            );
+            if !report.conflicts.is_empty() {
+                msg.push_str(&format!(
+                    ", {} conflict(s) logged to {}",
+                    report.conflicts.len(),
+                    base_dir.display()
+                ));
+            }
+            if !report.skipped_databases.is_empty() {
+                msg.push_str(&format!(
+                    ", {} database file(s) skipped (only JSON sheets sync)",
+                    report.skipped_databases.len()
+                ));
+            }
+            info!("VisualCopier: {}", msg);
+            Ok(msg)
+        }
+    }
+}
+
+/// Rewrites the JSON sheets copied from `from_path` that have whitelisted columns so only
+/// those remain; sheets without any are copied whole. Whitelisted headers no sheet has are
+/// logged and skipped; returns how many.
+fn apply_column_whitelist(
+    from_path: &Path,
+    to_path: &Path,
+    whitelist: &[String],
+) -> Result<usize, CopyError> {
+    let mut found: HashSet<&String> = HashSet::new();
+    for meta_path in find_sheet_metadata_files(from_path) {
+        let Ok(relative) = meta_path.strip_prefix(from_path) else {
+            continue;
+        };
+        let data_path = sheet_data_path(&meta_path);
+        let (mut metadata, mut grid) = match (
+            IoHelpers::load_metadata(&meta_path),
+            IoHelpers::load_grid_data(&data_path),
+        ) {
+            (Ok(metadata), Ok(grid)) => (metadata, grid),
+            (Err(e), _) | (_, Err(e)) => {
+                warn!(
+                    "VisualCopier: Copied '{}' unfiltered, it could not be read as a sheet: {}",
+                    meta_path.display(),
+                    e
+                );
+                continue;
+            }
+        };
+
+        found.extend(
+            whitelist
+                .iter()
+                .filter(|header| metadata.columns.iter().any(|col| &col.header == *header)),
+        );
+        if !project_sheet_columns(&mut metadata, &mut grid, whitelist) {
+            continue;
+        }
+
+        let target_meta = to_path.join(relative);
+        let target_data = sheet_data_path(&target_meta);
+        let meta_json =
+            serde_json::to_string_pretty(&metadata).map_err(|e| CopyError::Io(e.to_string()))?;
+        let data_json =
+            serde_json::to_string_pretty(&grid).map_err(|e| CopyError::Io(e.to_string()))?;
+        std::fs::write(&target_meta, meta_json)?;
+        std::fs::write(&target_data, data_json)?;
+    }
+
+    let missing: Vec<&String> = whitelist.iter().filter(|header| !found.contains(header)).collect();
+    if !missing.is_empty() {
+        warn!(
+            "VisualCopier: Columns {:?} not found in any sheet of '{}', skipped",
+            missing,
+            from_path.display()
+        );
+    }
+    Ok(missing.len())
+}
+
+/// Keeps only the columns whose header is whitelisted, in their original order. A sheet
+/// with none of the whitelisted columns is left as is; returns whether it was projected.
+pub(crate) fn project_sheet_columns(
+    metadata: &mut SheetMetadata,
+    grid: &mut Vec<Vec<String>>,
+    whitelist: &[String],
+) -> bool {
+    let kept: Vec<usize> = metadata
+        .columns
+        .iter()
+        .enumerate()
+        .filter(|(_, col)| whitelist.contains(&col.header))
+        .map(|(idx, _)| idx)
+        .collect();
+    if kept.is_empty() {
+        return false;
+    }
+
+    if metadata
+        .ai_key_column
+        .as_ref()
+        .is_some_and(|header| !whitelist.contains(header))
+    {
+        metadata.ai_key_column = None;
+    }
+    metadata.columns = kept
+        .iter()
+        .map(|&idx| metadata.columns[idx].clone())
+        .collect();
+    for row in grid.iter_mut() {
+        *row = kept
+            .iter()
+            .map(|&idx| row.get(idx).cloned().unwrap_or_default())
+            .collect();
+    }
+    true
+}
+
+/// Unique column headers of the JSON sheets under `folder`, sorted, for the whitelist picker.
+pub(crate) fn scan_source_columns(folder: &Path) -> Vec<String> {
+    let mut headers: Vec<String> = find_sheet_metadata_files(folder)
+        .into_iter()
+        .filter_map(|path| IoHelpers::load_metadata(&path).ok())
+        .flat_map(|meta| meta.columns.into_iter().map(|col| col.header))
+        .collect();
+    headers.sort();
+    headers.dedup();
+    headers
+}
+
+pub(super) fn find_sheet_metadata_files(folder: &Path) -> Vec<PathBuf> {
+    walkdir::WalkDir::new(folder)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .filter(|entry| entry.file_name().to_string_lossy().ends_with(META_SUFFIX))
+        .map(|entry| entry.into_path())
+        .collect()
+}
+
+/// `Items.meta.json` -> `Items.json` next to it.
+pub(super) fn sheet_data_path(meta_path: &Path) -> PathBuf {
+    let file_name = meta_path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let stem = file_name.strip_suffix(META_SUFFIX).unwrap_or(&file_name);
+    meta_path.with_file_name(format!("{}.json", stem))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn projection_keeps_whitelisted_columns_only_in_sheets_that_have_them() {
+        let mut metadata =
+            SheetMetadata::create_generic("Items".to_string(), "Items.json".to_string(), 3, None);
+        metadata.set_ai_key_column(Some(2));
+        let mut grid = vec![
+            vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            vec!["d".to_string()],
+        ];
+        let whitelist = vec![
+            "Column 3".to_string(),
+            "Price".to_string(),
+            "Column 1".to_string(),
+        ];
+
+        assert!(project_sheet_columns(&mut metadata, &mut grid, &whitelist));
+
+        let headers: Vec<&str> = metadata.columns.iter().map(|c| c.header.as_str()).collect();
+        assert_eq!(headers, vec!["Column 1", "Column 3"]);
+        assert_eq!(metadata.ai_key_column_override(), Some(1));
+        assert_eq!(
+            grid,
+            vec![
+                vec!["a".to_string(), "c".to_string()],
+                vec!["d".to_string(), String::new()],
+            ]
+        );
+
+        // A sheet with none of the whitelisted columns is copied whole
+        let mut other =
+            SheetMetadata::create_generic("Units".to_string(), "Units.json".to_string(), 2, None);
+        let mut other_grid = vec![vec!["x".to_string(), "y".to_string()]];
+        let whitelist = vec!["Price".to_string()];
+        assert!(!project_sheet_columns(&mut other, &mut other_grid, &whitelist));
+        assert_eq!(other.columns.len(), 2);
+        assert_eq!(other_grid, vec![vec!["x".to_string(), "y".to_string()]]);
+    }
+}
@@ -4,7 +4,10 @@ use bevy::prelude::*;
 use rfd::FileDialog;
 
 use super::events::*;
-use super::io::save_copier_manager_to_file; // Keep save import
+use super::io::{
+    export_copy_tasks_to_file, merge_imported_copy_tasks, read_copy_tasks_file,
+    save_copier_manager_to_file,
+};
 use super::resources::VisualCopierManager; // Imports all events from events.rs
 
 /// Handles the `AddNewCopyTaskEvent` to add a new task to the manager.
@@ -435,3 +438,60 @@ pub(crate) fn handle_visual_copier_state_change_and_save_system(
         }
     }
 }
+
+/// Handles `ExportCopyTasksRequest`: asks for a target file and writes the task list to it.
+pub(crate) fn handle_export_copy_tasks_request_system(
+    mut events: EventReader<ExportCopyTasksRequest>,
+    mut manager: ResMut<VisualCopierManager>,
+) {
+    if events.read().next().is_none() {
+        return;
+    }
+    let Some(path) = FileDialog::new()
+        .set_file_name("copy_tasks.json")
+        .add_filter("JSON", &["json"])
+        .save_file()
+    else {
+        return;
+    };
+    manager.task_transfer_status = match export_copy_tasks_to_file(&manager, &path) {
+        Ok(count) => format!("Exported {} task(s) to {}", count, path.display()),
+        Err(e) => {
+            error!("VisualCopier: Failed to export tasks to {:?}: {}", path, e);
+            format!("Export failed: {}", e)
+        }
+    };
+}
+
+/// Handles `ImportCopyTasksRequest`: reads an exported task list and appends its tasks,
+/// reporting folders that do not exist on this machine instead of adding broken tasks.
+pub(crate) fn handle_import_copy_tasks_request_system(
+    mut events: EventReader<ImportCopyTasksRequest>,
+    mut manager: ResMut<VisualCopierManager>,
+    mut state_changed_writer: EventWriter<VisualCopierStateChanged>,
+) {
+    if events.read().next().is_none() {
+        return;
+    }
+    let Some(path) = FileDialog::new().add_filter("JSON", &["json"]).pick_file() else {
+        return;
+    };
+    let imported = match read_copy_tasks_file(&path) {
+        Ok(tasks) => tasks,
+        Err(e) => {
+            error!("VisualCopier: Failed to import tasks from {:?}: {}", path, e);
+            manager.task_transfer_status = format!("Import failed: {}", e);
+            return;
+        }
+    };
+    let report = merge_imported_copy_tasks(&mut manager, imported, |p| p.is_dir());
+    if !report.skipped_missing_source.is_empty() || !report.missing_destinations.is_empty() {
+        warn!("VisualCopier: Task import from {:?}: {:?}", path, report);
+    } else {
+        info!("VisualCopier: Imported {} tasks from {:?}", report.added, path);
+    }
+    manager.task_transfer_status = report.summary();
+    if report.added > 0 {
+        state_changed_writer.write(VisualCopierStateChanged);
+    }
+}
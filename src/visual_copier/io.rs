@@ -1,10 +1,11 @@
 // src/visual_copier/io.rs
 
-use super::resources::VisualCopierManager;
+use super::resources::{ConflictPolicy, CopyTask, SyncDirection, VisualCopierManager};
 use directories_next::ProjectDirs;
+use serde::{Deserialize, Serialize};
 use std::fs;
 use std::io::{self, BufReader, BufWriter, ErrorKind};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 // --- MODIFIED: Use bevy::log::* ---
 use bevy::log::{debug, error, info};
 // --- END MODIFIED ---
@@ -81,6 +82,30 @@ pub fn load_copier_manager_from_file() -> io::Result<VisualCopierManager> {
     }
 }
 
+/// Folder holding the last synced rows of a bidirectional task, used as the common base of
+/// the next sync. Keyed by the folder pair so changing either folder starts from scratch.
+pub fn sync_base_dir(start: &Path, end: &Path) -> io::Result<PathBuf> {
+    let config_file = get_config_path()?;
+    let dir = config_file
+        .with_file_name("sync_base")
+        .join(format!("{:016x}", folder_pair_hash(start, end)));
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+/// FNV-1a over both paths. Unlike `DefaultHasher` the value is the same across Rust
+/// versions, so an upgrade does not lose the sync base.
+fn folder_pair_hash(start: &Path, end: &Path) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    let start = start.to_string_lossy();
+    let end = end.to_string_lossy();
+    for byte in start.bytes().chain([0]).chain(end.bytes()) {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    hash
+}
+
 /// Saves the VisualCopierManager state to the configuration file.
 pub fn save_copier_manager_to_file(manager: &VisualCopierManager) -> io::Result<()> {
     let config_file = get_config_path()?;
@@ -111,3 +136,209 @@ pub fn save_copier_manager_to_file(manager: &VisualCopierManager) -> io::Result<
 
     Ok(())
 }
+
+/// Portable copy task list written by "Export tasks" (ids and statuses are machine-local and left out).
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CopyTaskExport {
+    pub version: u32,
+    pub tasks: Vec<ExportedCopyTask>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExportedCopyTask {
+    pub start_folder: Option<PathBuf>,
+    pub end_folder: Option<PathBuf>,
+    /// See `CopyTask::column_whitelist`; missing in exports made before it existed
+    #[serde(default)]
+    pub column_whitelist: Option<Vec<String>>,
+    /// See `CopyTask::direction` / `conflict_policy`; exports made before them read as defaults
+    #[serde(default)]
+    pub direction: SyncDirection,
+    #[serde(default)]
+    pub conflict_policy: ConflictPolicy,
+}
+
+const COPY_TASK_EXPORT_VERSION: u32 = 1;
+
+/// Writes the current copy tasks to `path` as pretty JSON.
+pub fn export_copy_tasks_to_file(manager: &VisualCopierManager, path: &Path) -> io::Result<usize> {
+    let export = CopyTaskExport {
+        version: COPY_TASK_EXPORT_VERSION,
+        tasks: manager
+            .copy_tasks
+            .iter()
+            .map(|t| ExportedCopyTask {
+                start_folder: t.start_folder.clone(),
+                end_folder: t.end_folder.clone(),
+                column_whitelist: t.column_whitelist.clone(),
+                direction: t.direction,
+                conflict_policy: t.conflict_policy,
+            })
+            .collect(),
+    };
+    let writer = BufWriter::new(fs::File::create(path)?);
+    serde_json::to_writer_pretty(writer, &export).map_err(|e| io::Error::new(ErrorKind::Other, e))?;
+    info!("VisualCopier: Exported {} tasks to {:?}", export.tasks.len(), path);
+    Ok(export.tasks.len())
+}
+
+/// Reads an exported copy task list.
+pub fn read_copy_tasks_file(path: &Path) -> io::Result<Vec<ExportedCopyTask>> {
+    let reader = BufReader::new(fs::File::open(path)?);
+    let export: CopyTaskExport = serde_json::from_reader(reader).map_err(|e| {
+        io::Error::new(
+            ErrorKind::InvalidData,
+            format!("Not a copy task export: {}", e),
+        )
+    })?;
+    if export.version > COPY_TASK_EXPORT_VERSION {
+        return Err(io::Error::new(
+            ErrorKind::InvalidData,
+            format!("Unsupported copy task export version {}", export.version),
+        ));
+    }
+    Ok(export.tasks)
+}
+
+/// Result of merging imported tasks into the manager.
+#[derive(Debug, Default, PartialEq)]
+pub struct CopyTaskImportReport {
+    pub added: usize,
+    pub duplicates: usize,
+    /// Tasks skipped because their source folder does not exist on this machine
+    pub skipped_missing_source: Vec<PathBuf>,
+    /// Imported tasks whose destination folder does not exist yet (created on first copy)
+    pub missing_destinations: Vec<PathBuf>,
+}
+
+impl CopyTaskImportReport {
+    pub fn summary(&self) -> String {
+        let mut msg = format!("Imported {} task(s)", self.added);
+        if self.duplicates > 0 {
+            msg.push_str(&format!(", {} already present", self.duplicates));
+        }
+        if !self.skipped_missing_source.is_empty() {
+            msg.push_str(&format!(
+                ". Skipped {} with missing source folder: {}",
+                self.skipped_missing_source.len(),
+                join_paths(&self.skipped_missing_source)
+            ));
+        }
+        if !self.missing_destinations.is_empty() {
+            msg.push_str(&format!(
+                ". Destination not found (will be created on copy): {}",
+                join_paths(&self.missing_destinations)
+            ));
+        }
+        msg
+    }
+}
+
+fn join_paths(paths: &[PathBuf]) -> String {
+    paths
+        .iter()
+        .map(|p| p.display().to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Appends imported tasks to the manager. Tasks already present (same folders) are ignored and
+/// tasks whose source folder does not exist here are reported instead of being added.
+pub fn merge_imported_copy_tasks(
+    manager: &mut VisualCopierManager,
+    imported: Vec<ExportedCopyTask>,
+    folder_exists: impl Fn(&Path) -> bool,
+) -> CopyTaskImportReport {
+    let mut report = CopyTaskImportReport::default();
+    for task in imported {
+        let already_present = manager
+            .copy_tasks
+            .iter()
+            .any(|t| t.start_folder == task.start_folder && t.end_folder == task.end_folder);
+        if already_present {
+            report.duplicates += 1;
+            continue;
+        }
+        if let Some(start) = task.start_folder.as_ref().filter(|p| !folder_exists(p)) {
+            report.skipped_missing_source.push(start.clone());
+            continue;
+        }
+        let mut status = "Idle".to_string();
+        if let Some(end) = task.end_folder.as_ref().filter(|p| !folder_exists(p)) {
+            report.missing_destinations.push(end.clone());
+            status = "Destination folder not found; it will be created on copy".to_string();
+        }
+        let id = manager.get_next_id();
+        manager.copy_tasks.push(CopyTask {
+            id,
+            start_folder: task.start_folder,
+            end_folder: task.end_folder,
+            status,
+            column_whitelist: task.column_whitelist,
+            direction: task.direction,
+            conflict_policy: task.conflict_policy,
+        });
+        report.added += 1;
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn import_skips_duplicates_and_missing_sources() {
+        let mut manager = VisualCopierManager::default();
+        manager.copy_tasks.push(CopyTask {
+            id: manager.get_next_id(),
+            start_folder: Some(PathBuf::from("/a")),
+            end_folder: Some(PathBuf::from("/b")),
+            status: "Idle".to_string(),
+            column_whitelist: None,
+            direction: Default::default(),
+            conflict_policy: Default::default(),
+        });
+        let imported = vec![
+            ExportedCopyTask { start_folder: Some("/a".into()), end_folder: Some("/b".into()), ..Default::default() },
+            ExportedCopyTask { start_folder: Some("/gone".into()), end_folder: Some("/b".into()), ..Default::default() },
+            ExportedCopyTask {
+                start_folder: Some("/a".into()),
+                end_folder: Some("/new".into()),
+                column_whitelist: Some(vec!["Name".to_string()]),
+                direction: SyncDirection::Bidirectional,
+                conflict_policy: ConflictPolicy::PreferTarget,
+            },
+        ];
+
+        let report = merge_imported_copy_tasks(&mut manager, imported, |p| p != Path::new("/gone") && p != Path::new("/new"));
+
+        assert_eq!(report.added, 1);
+        assert_eq!(report.duplicates, 1);
+        assert_eq!(report.skipped_missing_source, vec![PathBuf::from("/gone")]);
+        assert_eq!(report.missing_destinations, vec![PathBuf::from("/new")]);
+        assert_eq!(manager.copy_tasks.len(), 2);
+        assert_eq!(manager.copy_tasks[1].id, 1);
+        assert_eq!(manager.copy_tasks[1].column_whitelist, Some(vec!["Name".to_string()]));
+        assert_eq!(manager.copy_tasks[1].direction, SyncDirection::Bidirectional);
+        assert_eq!(manager.copy_tasks[1].conflict_policy, ConflictPolicy::PreferTarget);
+    }
+
+    #[test]
+    fn sync_base_hash_is_pinned_and_ordered() {
+        assert_eq!(folder_pair_hash(Path::new("/a"), Path::new("/b")), 0x2fdb_8b45_ef81_db9e);
+        assert_ne!(
+            folder_pair_hash(Path::new("/a"), Path::new("/b")),
+            folder_pair_hash(Path::new("/b"), Path::new("/a"))
+        );
+    }
+
+    #[test]
+    fn exports_without_task_options_still_read() {
+        let task: ExportedCopyTask =
+            serde_json::from_str(r#"{"start_folder": "/a", "end_folder": "/b"}"#).unwrap();
+        assert_eq!(task.column_whitelist, None);
+        assert_eq!(task.direction, SyncDirection::SourceToTarget);
+        assert_eq!(task.conflict_policy, ConflictPolicy::Skip);
+    }
+}
@@ -11,6 +11,7 @@ pub mod io; // For loading/saving copy task configurations
 pub mod executers;
 pub mod handler;
 pub mod processes;
+pub mod sync;
 
 // Re-export the plugin for easy use in main.rs
 pub use plugin::VisualCopierPlugin;
@@ -13,7 +13,8 @@ use super::handler::{
     apply_task_end_folder_update_system, apply_task_start_folder_update_system,
     apply_top_panel_from_folder_update_system, apply_top_panel_to_folder_update_system,
     handle_add_new_copy_task_event_system, handle_copy_operation_result_event_system,
-    handle_folder_picked_event_system, handle_pick_folder_request_system,
+    handle_export_copy_tasks_request_system, handle_folder_picked_event_system,
+    handle_import_copy_tasks_request_system, handle_pick_folder_request_system,
     handle_queue_all_copy_tasks_event_system, handle_queue_copy_task_event_system,
     handle_queue_top_panel_copy_event_system, handle_remove_copy_task_event_system,
     handle_reverse_top_panel_folders_event_system,
@@ -42,6 +43,8 @@ impl Plugin for VisualCopierPlugin {
             .add_event::<QueueAllCopyTasksEvent>()
             .add_event::<ReverseTopPanelFoldersEvent>()
             .add_event::<CopyOperationResultEvent>()
+            .add_event::<ExportCopyTasksRequest>()
+            .add_event::<ImportCopyTasksRequest>()
             // --- MODIFIED: Register new events ---
             .add_event::<VisualCopierStateChanged>()
             .add_event::<RequestAppExit>();
@@ -67,12 +70,16 @@ impl Plugin for VisualCopierPlugin {
                 handle_add_new_copy_task_event_system,
                 handle_remove_copy_task_event_system,
                 handle_reverse_top_panel_folders_event_system,
+                // Task list export/import (file dialogs)
+                handle_export_copy_tasks_request_system,
+                handle_import_copy_tasks_request_system,
                 // Handle immediate save
                 handle_visual_copier_state_change_and_save_system
                     .after(apply_top_panel_to_folder_update_system) // Example ordering
                     .after(handle_add_new_copy_task_event_system)
                     .after(handle_remove_copy_task_event_system)
-                    .after(handle_reverse_top_panel_folders_event_system),
+                    .after(handle_reverse_top_panel_folders_event_system)
+                    .after(handle_import_copy_tasks_request_system),
                 // Queueing and processing copies (asynchronous)
                 handle_queue_copy_task_event_system,
                 handle_queue_top_panel_copy_event_system,
@@ -155,6 +162,7 @@ fn custom_exit_handler_system(
                     from,
                     to,
                     "Sync Copy on Exit",
+                    None,
                 ) {
                     Ok(msg) => {
                         info!("VisualCopier: Sync copy on exit successful: {}", msg);
@@ -4,7 +4,7 @@ use bevy::prelude::*;
 use bevy_tokio_tasks::TokioTasksRuntime;
 
 use super::events::CopyOperationResultEvent;
-use super::executers::execute_single_copy_operation;
+use super::executers::{execute_copy_task, execute_single_copy_operation};
 use super::resources::VisualCopierManager; // Import from executers
 
 /// System to process queued copy operations asynchronously using bevy_tokio_tasks.
@@ -26,7 +26,7 @@ pub(crate) fn process_copy_operations_system(
             runtime.spawn_background_task(move |mut ctx| async move {
                 // Execute the copy operation.
                 // The result is a Result<String, CopyError>
-                let result = execute_single_copy_operation(&from, &to, "Top Panel");
+                let result = execute_single_copy_operation(&from, &to, "Top Panel", None);
 
                 // Send the result back to the main thread using an event.
                 // The event will be handled by `handle_copy_operation_result_event_system`.
@@ -52,11 +52,19 @@ pub(crate) fn process_copy_operations_system(
             if let (Some(from), Some(to)) = (task.start_folder.clone(), task.end_folder.clone()) {
                 task.status = "Copying...".to_string(); // Update status before spawning
                 let task_id = task.id;
+                let column_whitelist = task.column_whitelist.clone();
+                let (direction, conflict_policy) = (task.direction, task.conflict_policy);
                 info!("VisualCopier: Spawning async task for Task {}...", task_id);
 
                 runtime.spawn_background_task(move |mut ctx| async move {
-                    let result =
-                        execute_single_copy_operation(&from, &to, &format!("Task {}", task_id));
+                    let result = execute_copy_task(
+                        &from,
+                        &to,
+                        &format!("Task {}", task_id),
+                        column_whitelist.as_deref(),
+                        direction,
+                        conflict_policy,
+                    );
 
                     ctx.run_on_main_thread(move |ctx| {
                         ctx.world.send_event(CopyOperationResultEvent {
@@ -2,9 +2,64 @@
 
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::PathBuf;
 use thiserror::Error;
 
+/// Which way a copy task moves data.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Reflect)]
+pub enum SyncDirection {
+    /// Copy the start folder over the end folder (the original behavior).
+    #[default]
+    SourceToTarget,
+    /// Copy the end folder back over the start folder.
+    TargetToSource,
+    /// Merge JSON sheet rows both ways, keyed by the sheet's key column.
+    Bidirectional,
+}
+
+impl SyncDirection {
+    pub const ALL: [SyncDirection; 3] = [
+        SyncDirection::SourceToTarget,
+        SyncDirection::TargetToSource,
+        SyncDirection::Bidirectional,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SyncDirection::SourceToTarget => "Start → End",
+            SyncDirection::TargetToSource => "End → Start",
+            SyncDirection::Bidirectional => "Both ways",
+        }
+    }
+}
+
+/// How a bidirectional sync settles a row changed differently on both sides.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Reflect)]
+pub enum ConflictPolicy {
+    PreferSource,
+    PreferTarget,
+    /// Leave both sides as they are and only report the conflict.
+    #[default]
+    Skip,
+}
+
+impl ConflictPolicy {
+    pub const ALL: [ConflictPolicy; 3] = [
+        ConflictPolicy::PreferSource,
+        ConflictPolicy::PreferTarget,
+        ConflictPolicy::Skip,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ConflictPolicy::PreferSource => "Prefer start folder",
+            ConflictPolicy::PreferTarget => "Prefer end folder",
+            ConflictPolicy::Skip => "Skip (report only)",
+        }
+    }
+}
+
 /// Represents a single copy task configuration.
 #[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
 #[reflect(Default, Serialize, Deserialize)]
@@ -13,6 +68,13 @@ pub struct CopyTask {
     pub start_folder: Option<PathBuf>,
     pub end_folder: Option<PathBuf>,
     pub status: String,
+    /// Sheet columns (by header) written to the destination. `None` copies every column.
+    #[serde(default)]
+    pub column_whitelist: Option<Vec<String>>,
+    #[serde(default)]
+    pub direction: SyncDirection,
+    #[serde(default)]
+    pub conflict_policy: ConflictPolicy,
 }
 
 impl Default for CopyTask {
@@ -22,6 +84,9 @@ impl Default for CopyTask {
             start_folder: None,
             end_folder: None,
             status: "Idle".to_string(),
+            column_whitelist: None,
+            direction: SyncDirection::default(),
+            conflict_policy: ConflictPolicy::default(),
         }
     }
 }
@@ -33,6 +98,9 @@ impl CopyTask {
             start_folder: None,
             end_folder: None,
             status: "Idle".to_string(),
+            column_whitelist: None,
+            direction: SyncDirection::default(),
+            conflict_policy: ConflictPolicy::default(),
         }
     }
 }
@@ -90,6 +158,14 @@ pub struct VisualCopierManager {
     #[serde(skip)]
     #[reflect(skip_serializing)]
     pub is_saving_on_exit: bool,
+    /// Outcome of the last task export/import, shown next to the buttons
+    #[serde(skip)]
+    #[reflect(skip_serializing)]
+    pub task_transfer_status: String,
+    /// Column headers found in each task's source folder by "Scan source columns"
+    #[serde(skip)]
+    #[reflect(skip_serializing)]
+    pub scanned_task_columns: HashMap<usize, Vec<String>>,
 }
 
 fn default_status_string() -> String {
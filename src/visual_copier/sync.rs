@@ -0,0 +1,434 @@
+// src/visual_copier/sync.rs
+// Bidirectional sync of JSON sheets: rows are matched by key column and compared against
+// the rows of the previous sync (the base) to tell which side changed them.
+
+use bevy::prelude::*;
+use chrono::Local;
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use super::executers::{find_sheet_metadata_files, sheet_data_path};
+use super::resources::{ConflictPolicy, CopyError};
+use crate::sheets::database::migration::io_helpers::IoHelpers;
+
+const CONFLICT_LOG_FILE: &str = "conflicts.log";
+
+type Row = Vec<String>;
+
+/// What happened to one row key since the last sync.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RowChange {
+    Unchanged,
+    SourceAdded,
+    TargetAdded,
+    SourceUpdated,
+    TargetUpdated,
+    SourceDeleted,
+    TargetDeleted,
+    /// Both sides changed the row, and not in the same way
+    Conflict,
+}
+
+/// Rows of one sheet side indexed by key, keeping the grid order.
+#[derive(Debug, Default)]
+pub struct KeyedRows {
+    order: Vec<String>,
+    rows: HashMap<String, Row>,
+}
+
+impl KeyedRows {
+    /// Indexes `grid` by `key_column`. Fails with the first duplicated key, since such
+    /// rows cannot be matched across sides.
+    pub fn from_grid(grid: Vec<Row>, key_column: usize) -> Result<Self, String> {
+        let mut keyed = KeyedRows::default();
+        for row in grid {
+            let key = row.get(key_column).cloned().unwrap_or_default();
+            if keyed.rows.contains_key(&key) {
+                return Err(key);
+            }
+            keyed.order.push(key.clone());
+            keyed.rows.insert(key, row);
+        }
+        Ok(keyed)
+    }
+
+    fn get(&self, key: &str) -> Option<&Row> {
+        self.rows.get(key)
+    }
+}
+
+/// Classifies a row key from its base, source and target versions.
+pub fn classify_row(base: Option<&Row>, source: Option<&Row>, target: Option<&Row>) -> RowChange {
+    if source == target {
+        return RowChange::Unchanged;
+    }
+    let Some(base) = base else {
+        return match (source, target) {
+            (Some(_), None) => RowChange::SourceAdded,
+            (None, Some(_)) => RowChange::TargetAdded,
+            _ => RowChange::Conflict,
+        };
+    };
+    let source_changed = source != Some(base);
+    let target_changed = target != Some(base);
+    match (source_changed, target_changed) {
+        (true, false) if source.is_some() => RowChange::SourceUpdated,
+        (true, false) => RowChange::SourceDeleted,
+        (false, true) if target.is_some() => RowChange::TargetUpdated,
+        (false, true) => RowChange::TargetDeleted,
+        _ => RowChange::Conflict,
+    }
+}
+
+/// Counts and conflicts of a bidirectional sync.
+#[derive(Debug, Default)]
+pub struct SyncReport {
+    pub added: usize,
+    pub updated: usize,
+    pub deleted: usize,
+    /// One line per conflicting row or sheet that could not be merged
+    pub conflicts: Vec<String>,
+    /// Database files found in either folder; only JSON sheets are synced
+    pub skipped_databases: Vec<PathBuf>,
+}
+
+impl SyncReport {
+    fn record(&mut self, change: RowChange) {
+        match change {
+            RowChange::SourceAdded | RowChange::TargetAdded => self.added += 1,
+            RowChange::SourceUpdated | RowChange::TargetUpdated => self.updated += 1,
+            RowChange::SourceDeleted | RowChange::TargetDeleted => self.deleted += 1,
+            RowChange::Unchanged | RowChange::Conflict => {}
+        }
+    }
+}
+
+/// Merged grids of one sheet: what each side and the next base should contain.
+#[derive(Debug, PartialEq)]
+pub struct MergedSheet {
+    pub source: Vec<Row>,
+    pub target: Vec<Row>,
+    pub base: Vec<Row>,
+}
+
+/// Merges one sheet. Keys keep the source order, followed by keys only the target has.
+/// Conflicts settled by `Skip` keep each side's own row and the old base row, so they are
+/// reported again on the next sync until resolved by hand.
+pub fn merge_sheet(
+    sheet_label: &str,
+    base: &KeyedRows,
+    source: &KeyedRows,
+    target: &KeyedRows,
+    policy: ConflictPolicy,
+    report: &mut SyncReport,
+) -> MergedSheet {
+    let mut seen = HashSet::new();
+    let keys: Vec<&String> = source
+        .order
+        .iter()
+        .chain(target.order.iter())
+        .chain(base.order.iter())
+        .filter(|key| seen.insert(key.as_str()))
+        .collect();
+
+    let mut merged = MergedSheet {
+        source: Vec::new(),
+        target: Vec::new(),
+        base: Vec::new(),
+    };
+    for key in keys {
+        let (b, s, t) = (base.get(key), source.get(key), target.get(key));
+        let change = classify_row(b, s, t);
+        report.record(change);
+        let resolved = match change {
+            RowChange::Unchanged
+            | RowChange::SourceAdded
+            | RowChange::SourceUpdated
+            | RowChange::SourceDeleted => Some(s),
+            RowChange::TargetAdded | RowChange::TargetUpdated | RowChange::TargetDeleted => Some(t),
+            RowChange::Conflict => {
+                report.conflicts.push(format!(
+                    "{}: row '{}' changed on both sides ({})",
+                    sheet_label,
+                    key,
+                    match policy {
+                        ConflictPolicy::PreferSource => "kept start folder version",
+                        ConflictPolicy::PreferTarget => "kept end folder version",
+                        ConflictPolicy::Skip => "left unchanged",
+                    }
+                ));
+                match policy {
+                    ConflictPolicy::PreferSource => Some(s),
+                    ConflictPolicy::PreferTarget => Some(t),
+                    ConflictPolicy::Skip => None,
+                }
+            }
+        };
+        let (source_row, target_row, base_row) = match resolved {
+            Some(row) => (row, row, row),
+            None => (s, t, b),
+        };
+        merged.source.extend(source_row.cloned());
+        merged.target.extend(target_row.cloned());
+        merged.base.extend(base_row.cloned());
+    }
+    merged
+}
+
+/// Syncs the JSON sheets of two folders both ways. Sheets present on one side only are
+/// copied to the other; other files are left alone, and `.db` files are listed in the
+/// report as skipped. Conflicts are logged and appended to `conflicts.log` in `base_dir`.
+pub fn sync_folders(
+    source_dir: &Path,
+    target_dir: &Path,
+    base_dir: &Path,
+    policy: ConflictPolicy,
+) -> Result<SyncReport, CopyError> {
+    if !source_dir.is_dir() {
+        return Err(CopyError::SourceDoesNotExist(source_dir.to_path_buf()));
+    }
+    std::fs::create_dir_all(target_dir)
+        .map_err(|_| CopyError::EndPathInvalid(target_dir.to_path_buf()))?;
+
+    let mut report = SyncReport::default();
+    let relative_metas = |dir: &Path| -> HashSet<PathBuf> {
+        find_sheet_metadata_files(dir)
+            .into_iter()
+            .filter_map(|p| p.strip_prefix(dir).ok().map(Path::to_path_buf))
+            .collect()
+    };
+    let source_sheets = relative_metas(source_dir);
+    let target_sheets = relative_metas(target_dir);
+    let mut databases: Vec<PathBuf> = find_database_files(source_dir)
+        .into_iter()
+        .chain(find_database_files(target_dir))
+        .collect();
+    databases.sort();
+    databases.dedup();
+    for database in &databases {
+        warn!(
+            "VisualCopier: Sync skips '{}'; only JSON sheets are synced",
+            database.display()
+        );
+    }
+    report.skipped_databases = databases;
+
+    for relative in source_sheets.union(&target_sheets) {
+        let (in_source, in_target) = (
+            source_sheets.contains(relative),
+            target_sheets.contains(relative),
+        );
+        if !(in_source && in_target) {
+            let (from, to) = if in_source {
+                (source_dir, target_dir)
+            } else {
+                (target_dir, source_dir)
+            };
+            copy_sheet_files(&from.join(relative), &to.join(relative))?;
+            report.added += 1;
+            continue;
+        }
+        if let Err(reason) = sync_sheet(
+            source_dir,
+            target_dir,
+            base_dir,
+            relative,
+            policy,
+            &mut report,
+        ) {
+            report
+                .conflicts
+                .push(format!("{}: not synced, {}", relative.display(), reason));
+        }
+    }
+
+    if !report.conflicts.is_empty() {
+        for conflict in &report.conflicts {
+            warn!("VisualCopier: Sync conflict: {}", conflict);
+        }
+        if let Err(e) = append_conflict_log(base_dir, source_dir, target_dir, &report.conflicts) {
+            warn!("VisualCopier: Failed to write the conflict log: {}", e);
+        }
+    }
+    Ok(report)
+}
+
+fn sync_sheet(
+    source_dir: &Path,
+    target_dir: &Path,
+    base_dir: &Path,
+    relative: &Path,
+    policy: ConflictPolicy,
+    report: &mut SyncReport,
+) -> Result<(), String> {
+    let source_meta =
+        IoHelpers::load_metadata(&source_dir.join(relative)).map_err(|e| e.to_string())?;
+    let target_meta =
+        IoHelpers::load_metadata(&target_dir.join(relative)).map_err(|e| e.to_string())?;
+    if source_meta.get_headers() != target_meta.get_headers() {
+        return Err("the columns differ between the two folders".to_string());
+    }
+    let key_column = source_meta
+        .ai_key_column_override()
+        .ok_or_else(|| "the sheet has no key column set".to_string())?;
+
+    let source_data = sheet_data_path(&source_dir.join(relative));
+    let target_data = sheet_data_path(&target_dir.join(relative));
+    let base_data = sheet_data_path(&base_dir.join(relative));
+    let source_grid = IoHelpers::load_grid_data(&source_data).map_err(|e| e.to_string())?;
+    let target_grid = IoHelpers::load_grid_data(&target_data).map_err(|e| e.to_string())?;
+    // No base yet (first sync): rows differing between the sides are conflicts
+    let base_grid = IoHelpers::load_grid_data(&base_data).unwrap_or_default();
+
+    let keyed = |grid: Vec<Row>| {
+        KeyedRows::from_grid(grid, key_column)
+            .map_err(|key| format!("key '{}' appears on more than one row", key))
+    };
+    let merged = merge_sheet(
+        &relative.display().to_string(),
+        &keyed(base_grid)?,
+        &keyed(source_grid.clone())?,
+        &keyed(target_grid.clone())?,
+        policy,
+        report,
+    );
+
+    let write = |path: &Path, grid: &Vec<Row>| -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        let json = serde_json::to_string_pretty(grid).map_err(|e| e.to_string())?;
+        std::fs::write(path, json).map_err(|e| e.to_string())
+    };
+    if merged.source != source_grid {
+        write(&source_data, &merged.source)?;
+    }
+    if merged.target != target_grid {
+        write(&target_data, &merged.target)?;
+    }
+    write(&base_data, &merged.base)
+}
+
+/// `.db` files under `dir`, relative to it.
+fn find_database_files(dir: &Path) -> Vec<PathBuf> {
+    walkdir::WalkDir::new(dir)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "db"))
+        .filter_map(|entry| entry.path().strip_prefix(dir).ok().map(Path::to_path_buf))
+        .collect()
+}
+
+fn copy_sheet_files(from_meta: &Path, to_meta: &Path) -> Result<(), CopyError> {
+    if let Some(parent) = to_meta.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::copy(from_meta, to_meta)?;
+    let from_data = sheet_data_path(from_meta);
+    if from_data.exists() {
+        std::fs::copy(&from_data, sheet_data_path(to_meta))?;
+    }
+    Ok(())
+}
+
+fn append_conflict_log(
+    base_dir: &Path,
+    source_dir: &Path,
+    target_dir: &Path,
+    conflicts: &[String],
+) -> std::io::Result<()> {
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(base_dir.join(CONFLICT_LOG_FILE))?;
+    writeln!(
+        file,
+        "[{}] {} <-> {}",
+        Local::now().format("%Y-%m-%d %H:%M:%S"),
+        source_dir.display(),
+        target_dir.display()
+    )?;
+    for conflict in conflicts {
+        writeln!(file, "  {}", conflict)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(data: &[(&str, &str)]) -> KeyedRows {
+        let grid = data
+            .iter()
+            .map(|(k, v)| vec![k.to_string(), v.to_string()])
+            .collect();
+        KeyedRows::from_grid(grid, 0).unwrap()
+    }
+
+    #[test]
+    fn classifies_changes_on_both_sides_and_one_conflict() {
+        let base = rows(&[("a", "1"), ("b", "1"), ("c", "1"), ("d", "1"), ("e", "1")]);
+        // a: updated in source, b: deleted in target, c: conflict, d: untouched, f: added in target
+        let source = rows(&[
+            ("a", "2"),
+            ("b", "1"),
+            ("c", "source"),
+            ("d", "1"),
+            ("e", "1"),
+        ]);
+        let target = rows(&[
+            ("a", "1"),
+            ("c", "target"),
+            ("d", "1"),
+            ("e", "1"),
+            ("f", "new"),
+        ]);
+
+        let classify = |key: &str| classify_row(base.get(key), source.get(key), target.get(key));
+        assert_eq!(classify("a"), RowChange::SourceUpdated);
+        assert_eq!(classify("b"), RowChange::TargetDeleted);
+        assert_eq!(classify("c"), RowChange::Conflict);
+        assert_eq!(classify("d"), RowChange::Unchanged);
+        assert_eq!(classify("f"), RowChange::TargetAdded);
+
+        let mut report = SyncReport::default();
+        let merged = merge_sheet(
+            "Items",
+            &base,
+            &source,
+            &target,
+            ConflictPolicy::Skip,
+            &mut report,
+        );
+
+        assert_eq!((report.added, report.updated, report.deleted), (1, 1, 1));
+        assert_eq!(report.conflicts.len(), 1);
+        assert!(report.conflicts[0].contains("'c'"));
+        let value_of =
+            |grid: &Vec<Row>, key: &str| grid.iter().find(|r| r[0] == key).map(|r| r[1].clone());
+        // Skipped conflict: each side keeps its own row, the base keeps the old one
+        assert_eq!(value_of(&merged.source, "c").as_deref(), Some("source"));
+        assert_eq!(value_of(&merged.target, "c").as_deref(), Some("target"));
+        assert_eq!(value_of(&merged.base, "c").as_deref(), Some("1"));
+        for grid in [&merged.source, &merged.target] {
+            assert_eq!(value_of(grid, "a").as_deref(), Some("2"));
+            assert_eq!(value_of(grid, "b"), None);
+            assert_eq!(value_of(grid, "f").as_deref(), Some("new"));
+        }
+
+        let mut report = SyncReport::default();
+        let merged = merge_sheet(
+            "Items",
+            &base,
+            &source,
+            &target,
+            ConflictPolicy::PreferTarget,
+            &mut report,
+        );
+        assert_eq!(value_of(&merged.source, "c").as_deref(), Some("target"));
+        assert_eq!(merged.source, merged.target);
+    }
+}